@@ -4,22 +4,35 @@ import os
 from torch import Tensor
 import torch
 import torch.nn as nn
+import torch.nn.functional as F
 
 from .common import tensor_json, params_json
 
 
 def gen_lstm_test_case(
-    module: nn.Module, inputs: Tensor, initial: tuple[Tensor, Tensor] | None = None
+    module: nn.Module,
+    inputs: Tensor,
+    initial: tuple[Tensor, Tensor] | None = None,
+    seq_lens: list[int] | None = None,
 ) -> dict:
-    output, (last_hidden, last_cell) = module(x, initial)
+    if seq_lens is not None:
+        packed_inputs = nn.utils.rnn.pack_padded_sequence(inputs, seq_lens)
+        packed_output, (last_hidden, last_cell) = module(packed_inputs, initial)
+        output, _ = nn.utils.rnn.pad_packed_sequence(
+            packed_output, total_length=inputs.shape[0]
+        )
+    else:
+        output, (last_hidden, last_cell) = module(inputs, initial)
     case = {
-        "input": tensor_json(x),
+        "input": tensor_json(inputs),
         "output": tensor_json(output),
         "params": params_json(module),
     }
     if initial:
         case["initial_hidden"] = tensor_json(initial[0])
         case["initial_cell"] = tensor_json(initial[1])
+    if seq_lens is not None:
+        case["sequence_lens"] = seq_lens
     return case
 
 
@@ -29,9 +42,9 @@ def gen_gru_test_case(
     (
         output,
         last_hidden,
-    ) = module(x, initial)
+    ) = module(inputs, initial)
     case = {
-        "input": tensor_json(x),
+        "input": tensor_json(inputs),
         "output": tensor_json(output),
         "params": params_json(module),
     }
@@ -40,6 +53,102 @@ def gen_gru_test_case(
     return case
 
 
+def hard_sigmoid(x: Tensor) -> Tensor:
+    """
+    ONNX HardSigmoid activation with the default `alpha` and `beta` values.
+    """
+    return (0.2 * x + 0.5).clamp(0.0, 1.0)
+
+
+def onnx_lstm(
+    module: nn.LSTM,
+    inputs: Tensor,
+    peephole: Tensor | None = None,
+    clip: float | None = None,
+    activations=(torch.sigmoid, torch.tanh, torch.tanh),
+) -> Tensor:
+    """
+    Evaluate a forwards LSTM using the equations from the ONNX LSTM spec.
+
+    This supports options which `nn.LSTM` does not. `peephole` has shape
+    `[3 * hidden_size]` and uses the ONNX gate order (input, output, forget).
+    """
+    act_f, act_g, act_h = activations
+
+    def clip_fn(x: Tensor) -> Tensor:
+        return x.clamp(-clip, clip) if clip is not None else x
+
+    hidden = torch.zeros(module.hidden_size)
+    cell = torch.zeros(module.hidden_size)
+    if peephole is None:
+        peephole = torch.zeros(3 * module.hidden_size)
+    p_i, p_o, p_f = peephole.chunk(3)
+
+    outputs = []
+    for x_t in inputs:
+        gates = (
+            x_t @ module.weight_ih_l0.T
+            + module.bias_ih_l0
+            + hidden @ module.weight_hh_l0.T
+            + module.bias_hh_l0
+        )
+        # PyTorch weights use the gate order (input, forget, cell, output).
+        i, f, c, o = gates.chunk(4)
+        i = act_f(clip_fn(i + p_i * cell))
+        f = act_f(clip_fn(f + p_f * cell))
+        c = act_g(clip_fn(c))
+        cell = f * cell + i * c
+        o = act_f(clip_fn(o + p_o * cell))
+        hidden = o * act_h(cell)
+        outputs.append(hidden)
+    return torch.stack(outputs)
+
+
+def onnx_gru(module: nn.GRU, inputs: Tensor, linear_before_reset: bool) -> Tensor:
+    """
+    Evaluate a forwards GRU using the equations from the ONNX GRU spec.
+
+    `nn.GRU` is equivalent to `linear_before_reset=True`.
+    """
+    w_hr, w_hz, w_hn = module.weight_hh_l0.chunk(3)
+    b_hr, b_hz, b_hn = module.bias_hh_l0.chunk(3)
+    hidden = torch.zeros(module.hidden_size)
+
+    outputs = []
+    for x_t in inputs:
+        x_r, x_z, x_n = (x_t @ module.weight_ih_l0.T + module.bias_ih_l0).chunk(3)
+        r = torch.sigmoid(x_r + hidden @ w_hr.T + b_hr)
+        z = torch.sigmoid(x_z + hidden @ w_hz.T + b_hz)
+        if linear_before_reset:
+            n = torch.tanh(x_n + r * (hidden @ w_hn.T + b_hn))
+        else:
+            n = torch.tanh(x_n + (r * hidden) @ w_hn.T + b_hn)
+        hidden = (1 - z) * n + z * hidden
+        outputs.append(hidden)
+    return torch.stack(outputs)
+
+
+def gen_onnx_lstm_test_case(
+    module: nn.LSTM, inputs: Tensor, peephole: Tensor | None = None, **kwargs
+) -> dict:
+    case = {
+        "input": tensor_json(inputs),
+        "output": tensor_json(onnx_lstm(module, inputs, peephole, **kwargs)),
+        "params": params_json(module),
+    }
+    if peephole is not None:
+        case["peephole"] = tensor_json(peephole)
+    return case
+
+
+def gen_onnx_gru_test_case(module: nn.GRU, inputs: Tensor, **kwargs) -> dict:
+    return {
+        "input": tensor_json(inputs),
+        "output": tensor_json(onnx_gru(module, inputs, **kwargs)),
+        "params": params_json(module),
+    }
+
+
 # Ensure we get the same output on every run.
 torch.manual_seed(1234)
 
@@ -61,6 +170,23 @@ gru_bidirectional = nn.GRU(
     input_size=input_features, hidden_size=hidden_size, bidirectional=True
 )
 
+# New modules and inputs are created after the ones above, so that adding
+# them does not change the values used by existing test cases.
+rnn = nn.RNN(input_size=input_features, hidden_size=hidden_size)
+rnn_relu_bidirectional = nn.RNN(
+    input_size=input_features,
+    hidden_size=hidden_size,
+    nonlinearity="relu",
+    bidirectional=True,
+)
+
+peephole = torch.rand((3 * hidden_size,))
+
+# Batch of sequences with lengths in decreasing order, as required by
+# `pack_padded_sequence`.
+seq_lens = [seq_len, 4, 1]
+x_batch = torch.rand((seq_len, len(seq_lens), input_features))
+
 test_cases = {
     "__comment__": f"Generated with {os.path.basename(__file__)}",
     "lstm_forwards": gen_lstm_test_case(lstm, x),
@@ -69,6 +195,23 @@ test_cases = {
     "gru_forwards": gen_gru_test_case(gru, x),
     "gru_bidirectional": gen_gru_test_case(gru_bidirectional, x),
     "gru_initial": gen_gru_test_case(gru, x, initial_hidden),
+    # `nn.RNN` has the same interface as `nn.GRU`.
+    "rnn_forwards": gen_gru_test_case(rnn, x),
+    "rnn_initial": gen_gru_test_case(rnn, x, initial_hidden),
+    "rnn_relu_bidirectional": gen_gru_test_case(rnn_relu_bidirectional, x),
+    # Cases for ONNX operator options which PyTorch's modules don't support.
+    # The options other than input tensors are set by the Rust tests.
+    "lstm_peephole": gen_onnx_lstm_test_case(lstm, x, peephole),
+    "lstm_clip": gen_onnx_lstm_test_case(lstm, x, clip=0.25),
+    "lstm_activations": gen_onnx_lstm_test_case(
+        lstm, x, activations=(hard_sigmoid, F.softsign, F.elu)
+    ),
+    "gru_linear_before_reset_0": gen_onnx_gru_test_case(
+        gru, x, linear_before_reset=False
+    ),
+    "lstm_sequence_lens": gen_lstm_test_case(
+        lstm_bidirectional, x_batch, seq_lens=seq_lens
+    ),
 }
 
 script_dir = os.path.dirname(__file__)
@@ -2,7 +2,9 @@ use std::collections::VecDeque;
 use std::error::Error;
 use std::time::Instant;
 
-use rten::{Dimension, InputOrOutput, Model, ModelMetadata, NodeId, Output, RunOptions};
+use rten::{
+    Dimension, InputOrOutput, Model, ModelMetadata, ModelOptions, NodeId, Output, RunOptions,
+};
 use rten_tensor::prelude::*;
 use rten_tensor::Tensor;
 
@@ -21,6 +23,18 @@ struct Args {
 
     /// Number of times to run model.
     n_iters: u32,
+
+    /// Enable graph optimizations.
+    optimize: bool,
+
+    /// Names of optimization passes to disable.
+    disabled_passes: Vec<String>,
+
+    /// Print the rewrites applied by graph optimizations.
+    rewrites: bool,
+
+    /// Prepack weights when the model is loaded.
+    prepack_weights: bool,
 }
 
 /// Specifies the size for a dynamic input dimension.
@@ -100,6 +114,10 @@ fn parse_args() -> Result<Args, lexopt::Error> {
     let mut timing = false;
     let mut verbose = false;
     let mut input_sizes = Vec::new();
+    let mut optimize = true;
+    let mut disabled_passes = Vec::new();
+    let mut rewrites = false;
+    let mut prepack_weights = false;
 
     let mut parser = lexopt::Parser::from_env();
     while let Some(arg) = parser.next()? {
@@ -112,6 +130,12 @@ fn parse_args() -> Result<Args, lexopt::Error> {
                     .map_err(|_| "Unable to parse `n_iters`".to_string())?;
             }
             Short('v') | Long("verbose") => verbose = true,
+            Long("no-optimize") => optimize = false,
+            Long("disable-pass") => {
+                disabled_passes.push(parser.value()?.string()?);
+            }
+            Long("rewrites") => rewrites = true,
+            Long("prepack") => prepack_weights = true,
             Short('V') | Long("version") => {
                 println!("rten {}", env!("CARGO_PKG_VERSION"));
                 std::process::exit(0);
@@ -138,6 +162,17 @@ Options:
   -n, --n_iters <n>
                  Number of times to evaluate model
 
+  --no-optimize  Disable graph optimizations
+
+  --disable-pass <name>
+                 Disable a graph optimization pass by name (eg. `fuse_gelu`).
+                 Can be repeated.
+
+  --rewrites     Print the rewrites applied by graph optimizations
+
+  --prepack      Prepack weights of matrix multiplications when the model is
+                 loaded
+
   -t, --timing   Output timing info
 
   -s, --size <spec>
@@ -163,6 +198,10 @@ Options:
         timing,
         verbose,
         input_sizes,
+        optimize,
+        disabled_passes,
+        rewrites,
+        prepack_weights,
     })
 }
 
@@ -366,7 +405,13 @@ fn print_input_output_list(model: &Model, node_ids: &[NodeId]) {
 /// running. See `docs/profiling.md`.
 fn main() -> Result<(), Box<dyn Error>> {
     let args = parse_args()?;
-    let model = Model::load_file(args.model)?;
+    let mut model_opts = ModelOptions::with_all_ops();
+    model_opts.enable_optimization(args.optimize);
+    model_opts.prepack_weights(args.prepack_weights);
+    for name in &args.disabled_passes {
+        model_opts.disable_optimization_pass(name);
+    }
+    let model = model_opts.load_file(args.model)?;
 
     println!(
         "Model summary: {} inputs, {} outputs, {} params",
@@ -386,6 +431,14 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     print_metadata(model.metadata());
 
+    if args.rewrites {
+        println!();
+        println!("Optimizations:");
+        for rewrite in model.optimization_log() {
+            println!("  {}", rewrite);
+        }
+    }
+
     println!();
     println!("Running model with random inputs...");
     run_with_random_input(
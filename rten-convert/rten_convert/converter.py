@@ -355,6 +355,10 @@ class ONNXOperatorReader:
             case "ints":
                 shape = [len(attr_val)]
                 data = np.array([attr_val]).astype(np.int32)
+
+            case "floats":
+                shape = [len(attr_val)]
+                data = np.array([attr_val]).astype(np.float32)
             case _:
                 raise ValueError(
                     f'Unable to generate input from "{attr_name}" attribute of type "{attr_type}"'
@@ -409,16 +413,21 @@ class ONNXOperatorReader:
         ]
 
 
-def check_ints_length(name: str, ints: list[int], allowed_length: int):
+def check_ints_length(
+    name: str, ints: list[int], allowed_length: int | tuple[int, ...]
+):
     """
-    Check that an ints attribute has a fixed length.
+    Check that an ints attribute has a fixed length, or one of a set of lengths.
 
     Various ONNX operators allow for a wider range of dimensions and per-axis
     values (eg. for strides, dilations, padding...) than this library currently
     supports.
     """
-    if len(ints) != allowed_length:
-        raise Exception(f'Attribute "{name}" must have {allowed_length} values')
+    if isinstance(allowed_length, int):
+        allowed_length = (allowed_length,)
+    if len(ints) not in allowed_length:
+        lengths = " or ".join(str(n) for n in allowed_length)
+        raise Exception(f'Attribute "{name}" must have {lengths} values')
 
 
 def constant_node_from_onnx_initializer(
@@ -497,12 +506,23 @@ class PadAttrs(Protocol):
     pads: list[int]
 
 
-def read_pads(op_reader: ONNXOperatorReader, attrs: PadAttrs) -> None:
+def read_pads(
+    op_reader: ONNXOperatorReader,
+    attrs: PadAttrs,
+    spatial_dims: int = 2,
+    min_spatial_dims: int = 2,
+) -> None:
     """
     Update the padding attributes for an operator.
 
     Reads padding attributes from an ONNX operator and updates the attributes
     for an RTen operator.
+
+    :param spatial_dims: Number of spatial dimensions of the operator input,
+        used to determine the default padding.
+    :param min_spatial_dims: Minimum number of spatial dimensions in the
+        default padding. Convolutions handle 1D inputs as 2D, whereas pooling
+        operators expect padding to match the input exactly.
     """
 
     auto_pad_attr = op_reader.get_attr("auto_pad", "string", "NOTSET")
@@ -514,9 +534,11 @@ def read_pads(op_reader: ONNXOperatorReader, attrs: PadAttrs) -> None:
             pads = []
         case "NOTSET":
             auto_pad = sg.AutoPad.NotSet
-            pads = op_reader.get_attr("pads", "ints", [0, 0, 0, 0])
-            if len(pads) not in [2, 4]:
-                raise Exception('"padding" attribute must have 2 or 4 values')
+            pads = op_reader.get_attr(
+                "pads", "ints", [0] * 2 * max(spatial_dims, min_spatial_dims)
+            )
+            if len(pads) not in [2, 4, 6]:
+                raise Exception('"padding" attribute must have 2, 4 or 6 values')
         case other:
             raise Exception(f"Unsupported auto_pad value {other}")
 
@@ -527,34 +549,146 @@ def read_pads(op_reader: ONNXOperatorReader, attrs: PadAttrs) -> None:
 
 
 def read_strides(
-    op_reader: ONNXOperatorReader,
+    op_reader: ONNXOperatorReader, spatial_dims: int = 2, min_spatial_dims: int = 2
 ):
     """
     Read a stride specification from an ONNX operator.
     """
-    strides = op_reader.get_attr("strides", "ints", [1, 1])
-    if len(strides) not in [1, 2]:
-        raise Exception('"strides" attribute must have 1 or 2 values')
+    strides = op_reader.get_attr(
+        "strides", "ints", [1] * max(spatial_dims, min_spatial_dims)
+    )
+    if len(strides) not in [1, 2, 3]:
+        raise Exception('"strides" attribute must have 1, 2 or 3 values')
     return strides
 
 
 def read_dilations(
-    op_reader: ONNXOperatorReader,
+    op_reader: ONNXOperatorReader, spatial_dims: int = 2, min_spatial_dims: int = 2
 ):
     """
     Read a dilation specification from an ONNX operator.
     """
-    dilations = op_reader.get_attr("dilations", "ints", [1, 1])
-    if len(dilations) not in [1, 2]:
-        raise Exception('"dilations" attribute must have 1 or 2 values')
+    dilations = op_reader.get_attr(
+        "dilations", "ints", [1] * max(spatial_dims, min_spatial_dims)
+    )
+    if len(dilations) not in [1, 2, 3]:
+        raise Exception('"dilations" attribute must have 1, 2 or 3 values')
     return dilations
 
 
+class PoolAttrs(PadAttrs, Protocol):
+    kernelSize: list[int]
+    strides: list[int]
+    dilations: list[int]
+    ceilMode: bool
+
+
+def read_pool_attrs(op_reader: ONNXOperatorReader, attrs: PoolAttrs) -> None:
+    """
+    Read the kernel shape, padding, strides, dilations and ceil mode attributes
+    shared by the windowed pooling operators.
+    """
+    kernel_shape = op_reader.require_attr("kernel_shape", "ints")
+    check_ints_length("kernel_shape", kernel_shape, (1, 2, 3))
+    spatial_dims = len(kernel_shape)
+
+    attrs.kernelSize = kernel_shape
+    read_pads(op_reader, attrs, spatial_dims, min_spatial_dims=1)
+    attrs.strides = read_strides(op_reader, spatial_dims, min_spatial_dims=1)
+    attrs.dilations = read_dilations(op_reader, spatial_dims, min_spatial_dims=1)
+    attrs.ceilMode = op_reader.get_bool_attr("ceil_mode", False)
+
+
+class RNNAttrs(Protocol):
+    direction: int
+    hiddenSize: int
+    activations: list[sg.RNNActivationFnT] | None
+    clip: float | None
+    batchFirst: bool
+
+
+# Map of ONNX RNN activation name to (enum value, default alpha and beta).
+#
+# Activations with parameters consume values from the `activation_alpha` and
+# `activation_beta` attributes in order. The defaults are those used by ONNX
+# Runtime when these attributes are missing.
+RNN_ACTIVATIONS = {
+    "affine": (sg.RNNActivation.Affine, [1.0, 0.0]),
+    "elu": (sg.RNNActivation.Elu, [1.0]),
+    "hardsigmoid": (sg.RNNActivation.HardSigmoid, [0.2, 0.5]),
+    "leakyrelu": (sg.RNNActivation.LeakyRelu, [0.01]),
+    "relu": (sg.RNNActivation.Relu, []),
+    "scaledtanh": (sg.RNNActivation.ScaledTanh, [1.0, 1.0]),
+    "sigmoid": (sg.RNNActivation.Sigmoid, []),
+    "softplus": (sg.RNNActivation.Softplus, []),
+    "softsign": (sg.RNNActivation.Softsign, []),
+    "tanh": (sg.RNNActivation.Tanh, []),
+    "thresholdedrelu": (sg.RNNActivation.ThresholdedRelu, [1.0]),
+}
+
+
+def read_rnn_attrs(op_reader: ONNXOperatorReader, attrs: RNNAttrs) -> None:
+    """
+    Read the attributes shared by the GRU, LSTM and RNN operators.
+    """
+    attrs.direction = op_reader.get_enum_attr("direction", sg.RNNDirection, "forward")
+    attrs.hiddenSize = op_reader.require_attr("hidden_size", "int")
+
+    activations = op_reader.get_attr("activations", "strings", [])
+    alphas = list(op_reader.get_attr("activation_alpha", "floats", []))
+    betas = list(op_reader.get_attr("activation_beta", "floats", []))
+    if activations:
+        attrs.activations = []
+    for name in activations:
+        name = name.decode()
+        if name.lower() not in RNN_ACTIVATIONS:
+            raise ValueError(f'Unsupported RNN activation "{name}"')
+        kind, defaults = RNN_ACTIVATIONS[name.lower()]
+
+        act = sg.RNNActivationFnT()
+        act.kind = kind
+        if len(defaults) > 0:
+            act.alpha = alphas.pop(0) if alphas else defaults[0]
+        if len(defaults) > 1:
+            act.beta = betas.pop(0) if betas else defaults[1]
+        attrs.activations.append(act)
+
+    attrs.clip = op_reader.get_attr("clip", "float", None)
+
+    layout = op_reader.get_attr("layout", "int", 0)
+    if layout not in [0, 1]:
+        raise ValueError(f"Unsupported RNN layout {layout}")
+    attrs.batchFirst = layout == 1
+
+
+def conv_spatial_dims(
+    op_reader: ONNXOperatorReader, constant_nodes: dict[str, ConstantNode]
+) -> int:
+    """
+    Get the number of spatial dimensions for a Conv or ConvTranspose operator.
+
+    This uses the optional `kernel_shape` attribute if present, or otherwise
+    the rank of the weight input if it is a constant. Falls back to 2D if
+    neither is available.
+    """
+    kernel_shape = op_reader.get_attr("kernel_shape", "ints", None)
+    if kernel_shape is not None:
+        return len(kernel_shape)
+
+    inputs = op_reader.onnx_op.input
+    weight = constant_nodes.get(inputs[1]) if len(inputs) > 1 else None
+    if weight is not None:
+        return len(weight.shape) - 2
+
+    return 2
+
+
 def op_node_from_onnx_operator(
     onnx_op: onnx.OperatorProto,
     node_index_from_name: dict[str, int],
     constant_nodes: dict[str, ConstantNode],
     add_node: Callable[[Node], int],
+    opset_version: int,
 ) -> OperatorNode:
     """
     Map an ONNX operator to the equivalent operator in this library.
@@ -569,6 +703,9 @@ def op_node_from_onnx_operator(
     :param add_node: Function that adds a new node to the graph and returns its
       node ID. This is called if an operator attribute needs to be converted
       to a constant input.
+    :param opset_version: Version of the default ONNX operator set used by
+      the model. This determines the semantics of operators whose behavior
+      changed across versions.
     """
     input_indexes = []
     for input_name in onnx_op.input:
@@ -611,17 +748,11 @@ def op_node_from_onnx_operator(
             attrs = sg.ArgMaxAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", None)
             attrs.keepDims = bool(op_reader.get_attr("keepdims", "int", 1))
-            op_reader.check_attr("select_last_index", "int", 0)
+            attrs.selectLastIndex = op_reader.get_bool_attr("select_last_index", False)
 
         case "AveragePool":
-            kernel_shape = op_reader.require_attr("kernel_shape", "ints")
-            check_ints_length("kernel_shape", kernel_shape, 2)
-            op_reader.check_attr("ceil_mode", "int", 0)
-
             attrs = sg.AveragePoolAttrsT()
-            attrs.kernelSize = kernel_shape
-            read_pads(op_reader, attrs)
-            attrs.strides = read_strides(op_reader)
+            read_pool_attrs(op_reader, attrs)
             attrs.countIncludePad = op_reader.get_bool_attr("count_include_pad", False)
 
         case "BatchNormalization":
@@ -633,6 +764,42 @@ def op_node_from_onnx_operator(
             # is unsupported.
             op_reader.ignore_attr("momentum")
 
+        case "Bernoulli":
+            attrs = sg.BernoulliAttrsT()
+            attrs.seed = op_reader.get_attr("seed", "float", None)
+            dtype = op_reader.get_attr("dtype", "int", TensorProto.DataType.FLOAT)  # type:ignore[attr-defined]
+            match dtype:
+                case (
+                    TensorProto.DataType.FLOAT  # type:ignore[attr-defined]
+                    | TensorProto.DataType.DOUBLE  # type:ignore[attr-defined]
+                    | TensorProto.DataType.FLOAT16  # type:ignore[attr-defined]
+                ):
+                    attrs.dtype = sg.DataType.Float
+                case (
+                    TensorProto.DataType.BOOL  # type:ignore[attr-defined]
+                    | TensorProto.DataType.INT32  # type:ignore[attr-defined]
+                    | TensorProto.DataType.INT64  # type:ignore[attr-defined]
+                ):
+                    attrs.dtype = sg.DataType.Int32
+                case _:
+                    raise Exception(f"Unsupported output type for Bernoulli {dtype}")
+
+        case "BitShift":
+            attrs = sg.BitShiftAttrsT()
+            direction = op_reader.require_attr("direction", "string")
+            match direction:
+                case "LEFT":
+                    attrs.direction = sg.BitShiftDirection.Left
+                case "RIGHT":
+                    attrs.direction = sg.BitShiftDirection.Right
+                case other:
+                    raise Exception(f"Unsupported BitShift direction {other}")
+
+        case "BlackmanWindow" | "HammingWindow" | "HannWindow":
+            attrs = sg.WindowAttrsT()
+            attrs.periodic = bool(op_reader.get_attr("periodic", "int", 1))
+            op_reader.check_attr("output_datatype", "int", 1)
+
         case "Cast":
             attrs = sg.CastAttrsT()
             to = op_reader.get_attr("to", "int", TensorProto.DataType.FLOAT)  # type:ignore[attr-defined]
@@ -648,10 +815,22 @@ def op_node_from_onnx_operator(
                 case _:
                     raise Exception(f"Unsupported target type for cast {to}")
 
+        case "Celu":
+            attrs = sg.EluAttrsT()
+            attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
+
+        case "CenterCropPad":
+            attrs = sg.CenterCropPadAttrsT()
+            attrs.axes = op_reader.get_attr("axes", "ints", None)
+
         case "Clip":
             op_reader.generate_input_from_attr(1, "min", "float")
             op_reader.generate_input_from_attr(2, "max", "float")
 
+        case "Compress":
+            attrs = sg.CompressAttrsT()
+            attrs.axis = op_reader.get_attr("axis", "int", None)
+
         case "Concat":
             attrs = sg.ConcatAttrsT()
             attrs.axis = op_reader.require_attr("axis", "int")
@@ -684,31 +863,72 @@ def op_node_from_onnx_operator(
             attrs.value = scalar
 
         case "Conv":
+            # The kernel shape is inferred at runtime from the input weight
+            # tensor, but determines the defaults for other attributes.
+            spatial_dims = conv_spatial_dims(op_reader, constant_nodes)
+
             attrs = sg.ConvAttrsT()
-            attrs.dilations = read_dilations(op_reader)
+            attrs.dilations = read_dilations(op_reader, spatial_dims)
             attrs.groups = op_reader.get_attr("group", "int", 1)
-            read_pads(op_reader, attrs)
-            attrs.strides = read_strides(op_reader)
-
-            # The kernel shape is inferred at runtime from the input weight tensor.
-            op_reader.ignore_attr("kernel_shape")
+            read_pads(op_reader, attrs, spatial_dims)
+            attrs.strides = read_strides(op_reader, spatial_dims)
 
         case "ConvTranspose":
-            attrs = sg.ConvTransposeAttrsT()
-            attrs.strides = read_strides(op_reader)
+            # The kernel shape is inferred at runtime from the input weight
+            # tensor, but determines the defaults for other attributes.
+            spatial_dims = conv_spatial_dims(op_reader, constant_nodes)
 
-            op_reader.check_attr("dilations", "ints", ([1], [1, 1]))
-            op_reader.check_attr("group", "int", 1)
-
-            # The kernel shape is inferred at runtime from the input weight tensor.
-            op_reader.ignore_attr("kernel_shape")
+            attrs = sg.ConvTransposeAttrsT()
+            attrs.dilations = read_dilations(op_reader, spatial_dims)
+            attrs.groups = op_reader.get_attr("group", "int", 1)
+            attrs.strides = read_strides(op_reader, spatial_dims)
 
-            op_reader.check_attr("output_padding", "ints", [0, 0, 0, 0])
-            read_pads(op_reader, attrs)
+            op_reader.check_attr(
+                "output_padding", "ints", ([0, 0, 0, 0], [0, 0, 0, 0, 0, 0])
+            )
+            read_pads(op_reader, attrs, spatial_dims)
 
         case "CumSum":
-            op_reader.check_attr("exclusive", "int", 0)
-            op_reader.check_attr("reverse", "int", 0)
+            attrs = sg.CumSumAttrsT()
+            attrs.exclusive = op_reader.get_bool_attr("exclusive", False)
+            attrs.reverse = op_reader.get_bool_attr("reverse", False)
+
+        case "DepthToSpace":
+            attrs = sg.DepthToSpaceAttrsT()
+            attrs.blockSize = op_reader.require_attr("blocksize", "int")
+            attrs.mode = op_reader.get_enum_attr("mode", sg.DepthToSpaceMode, "DCR")
+
+        case "DFT":
+            attrs = sg.DFTAttrsT()
+            # In opset 17 the axis is an attribute which defaults to 1. In
+            # opset 20+ it is an optional input which defaults to -2. The
+            # runtime uses the attribute value when the input is absent.
+            default_axis = 1 if opset_version < 20 else -2
+            attrs.axis = op_reader.get_attr("axis", "int", default_axis)
+            attrs.inverse = bool(op_reader.get_attr("inverse", "int", 0))
+            attrs.onesided = bool(op_reader.get_attr("onesided", "int", 0))
+
+        case "Dropout":
+            # Dropout is a no-op at inference time. Training mode, which
+            # randomly zeroes elements, is not supported.
+            op_type = "Identity"
+            op_reader.ignore_attr("is_test")
+            op_reader.ignore_attr("ratio")
+            op_reader.ignore_attr("seed")
+
+            if len(onnx_op.input) > 2 and onnx_op.input[2]:
+                training_mode = constant_nodes.get(onnx_op.input[2])
+                if training_mode is None:
+                    warn_once(
+                        "Dropout training_mode input is not a constant. Assuming inference mode."
+                    )
+                elif training_mode.data.any():
+                    raise ValueError("Dropout in training mode is not supported")
+            op_reader.input_indexes = op_reader.input_indexes[:1]
+
+            if len(output_indexes) > 1:
+                warn_once("Dropout mask output is not supported and will be omitted")
+                output_indexes = output_indexes[:1]
 
         case "Einsum":
             attrs = sg.EinsumAttrsT()
@@ -742,12 +962,13 @@ def op_node_from_onnx_operator(
             attrs.transposeA = bool(op_reader.get_attr("transA", "int", 0))
             attrs.transposeB = bool(op_reader.get_attr("transB", "int", 0))
 
+        case "GlobalLpPool":
+            attrs = sg.GlobalLpPoolAttrsT()
+            attrs.p = op_reader.get_attr("p", "int", 2)
+
         case "GRU":
             attrs = sg.GRUAttrsT()
-            attrs.direction = op_reader.get_enum_attr(
-                "direction", sg.RNNDirection, "forward"
-            )
-            attrs.hiddenSize = op_reader.require_attr("hidden_size", "int")
+            read_rnn_attrs(op_reader, attrs)
             attrs.linearBeforeReset = bool(
                 op_reader.get_attr("linear_before_reset", "int", 0)
             )
@@ -757,10 +978,25 @@ def op_node_from_onnx_operator(
             attrs.alpha = op_reader.get_attr("alpha", "float", 0.2)
             attrs.beta = op_reader.get_attr("beta", "float", 0.5)
 
+        case "Hardmax" | "LogSoftmax" | "Softmax":
+            attrs = sg.SoftmaxAttrsT()
+            if opset_version < 13:
+                # Before opset 13, the input is coerced to a 2D matrix by
+                # flattening the dimensions before and after `axis`.
+                attrs.axis = op_reader.get_attr("axis", "int", 1)
+                attrs.flatten = True
+            else:
+                attrs.axis = op_reader.get_attr("axis", "int", -1)
+
         case "InstanceNormalization":
             attrs = sg.BatchNormalizationAttrsT()
             attrs.epsilon = op_reader.get_attr("epsilon", "float", 1e-5)
 
+        case "IsInf":
+            attrs = sg.IsInfAttrsT()
+            attrs.detectNegative = op_reader.get_bool_attr("detect_negative", True)
+            attrs.detectPositive = op_reader.get_bool_attr("detect_positive", True)
+
         case "LayerNormalization":
             attrs = sg.LayerNormalizationAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", -1)
@@ -770,23 +1006,15 @@ def op_node_from_onnx_operator(
             attrs = sg.LeakyReluAttrsT()
             attrs.alpha = op_reader.get_attr("alpha", "float", 0.01)
 
-        case "LogSoftmax":
-            attrs = sg.SoftmaxAttrsT()
-            attrs.axis = op_reader.get_attr("axis", "int", 0)
+        case "LpPool":
+            attrs = sg.LpPoolAttrsT()
+            read_pool_attrs(op_reader, attrs)
+            attrs.p = op_reader.get_attr("p", "int", 2)
 
         case "LSTM":
             attrs = sg.LSTMAttrsT()
-            attrs.direction = op_reader.get_enum_attr(
-                "direction", sg.RNNDirection, "forward"
-            )
-            attrs.hiddenSize = op_reader.require_attr("hidden_size", "int")
-
-            op_reader.check_attr("activation_alpha", "floats", [])
-            op_reader.check_attr("activation_beta", "floats", [])
-            op_reader.check_attr("activations", "strings", [])
-            op_reader.check_attr("clip", "float", 0.0)
-            op_reader.check_attr("input_forget", "int", 0)
-            op_reader.check_attr("layout", "int", 0)
+            read_rnn_attrs(op_reader, attrs)
+            attrs.inputForget = op_reader.get_bool_attr("input_forget", False)
 
         case "MatMul":
             b = constant_nodes.get(onnx_op.input[-1])
@@ -796,20 +1024,41 @@ def op_node_from_onnx_operator(
 
         case "MaxPool":
             attrs = sg.MaxPoolAttrsT()
+            read_pool_attrs(op_reader, attrs)
+
+            # The optional second output contains the indices of the max
+            # values.
+            attrs.returnIndices = len(onnx_op.output) > 1 and onnx_op.output[1] != ""
+            op_reader.check_attr("storage_order", "int", 0)
+
+        case "MaxUnpool":
+            attrs = sg.MaxUnpoolAttrsT()
             kernel_shape = op_reader.require_attr("kernel_shape", "ints")
-            check_ints_length("kernel_shape", kernel_shape, 2)
+            check_ints_length("kernel_shape", kernel_shape, (1, 2, 3))
             attrs.kernelSize = kernel_shape
-            read_pads(op_reader, attrs)
-            attrs.strides = read_strides(op_reader)
+            read_pads(op_reader, attrs, len(kernel_shape), min_spatial_dims=1)
+            attrs.strides = read_strides(
+                op_reader, len(kernel_shape), min_spatial_dims=1
+            )
 
-            op_reader.check_attr("ceil_mode", "int", 0)
-            op_reader.check_attr("dilations", "ints", ([1], [1, 1]))
-            op_reader.check_attr("storage_order", "int", 0)
+        case "MelWeightMatrix":
+            op_reader.check_attr("output_datatype", "int", 1)
 
         case "Mod":
             attrs = sg.ModAttrsT()
             attrs.fmod = bool(op_reader.get_attr("fmod", "int", 0))
 
+        case "Multinomial":
+            attrs = sg.MultinomialAttrsT()
+            attrs.sampleSize = op_reader.get_attr("sample_size", "int", 1)
+            attrs.seed = op_reader.get_attr("seed", "float", None)
+            dtype = op_reader.get_attr("dtype", "int", TensorProto.DataType.INT32)  # type:ignore[attr-defined]
+            if dtype not in [
+                TensorProto.DataType.INT32,  # type:ignore[attr-defined]
+                TensorProto.DataType.INT64,  # type:ignore[attr-defined]
+            ]:
+                raise Exception(f"Unsupported output type for Multinomial {dtype}")
+
         case "NonMaxSuppression":
             attrs = sg.NonMaxSuppressionAttrsT()
             center_point_box = op_reader.get_attr("center_point_box", "int", 0)
@@ -849,7 +1098,10 @@ def op_node_from_onnx_operator(
             attrs.high = op_reader.get_attr("high", "float", 1.0)
 
         case (
-            "ReduceL2"
+            "ReduceL1"
+            | "ReduceL2"
+            | "ReduceLogSum"
+            | "ReduceLogSumExp"
             | "ReduceMax"
             | "ReduceMean"
             | "ReduceMin"
@@ -873,13 +1125,21 @@ def op_node_from_onnx_operator(
                 "mode", sg.ResizeMode, "nearest", fallback="linear"
             )
 
+            # Before opset 11, the inputs were `(X, scales)` and the coordinate
+            # mapping matched the `Upsample` operator.
+            legacy_resize = opset_version < 11
+            if legacy_resize:
+                op_reader.input_indexes.insert(1, None)
+
             op_reader.check_attr("antialias", "int", 0)
 
             # We only support resizing HW dimensions of NCHW tensor
             op_reader.check_attr("axes", "ints", [2, 3])
 
             attrs.coordMode = op_reader.get_enum_attr(
-                "coordinate_transformation_mode", sg.CoordTransformMode, "half_pixel"
+                "coordinate_transformation_mode",
+                sg.CoordTransformMode,
+                "asymmetric" if legacy_resize else "half_pixel",
             )
 
             op_reader.check_attr("cubic_coeff_a", "float", -0.75, on_mismatch="warn")
@@ -888,12 +1148,63 @@ def op_node_from_onnx_operator(
             op_reader.check_attr("keep_aspect_ratio_policy", "string", "stretch")
 
             attrs.nearestMode = op_reader.get_enum_attr(
-                "nearest_mode", sg.NearestMode, "round_prefer_floor"
+                "nearest_mode",
+                sg.NearestMode,
+                "floor" if legacy_resize else "round_prefer_floor",
             )
 
         case "Pad":
             op_reader.check_attr("mode", "string", "constant")
 
+        case "ReverseSequence":
+            attrs = sg.ReverseSequenceAttrsT()
+            attrs.batchAxis = op_reader.get_attr("batch_axis", "int", 1)
+            attrs.timeAxis = op_reader.get_attr("time_axis", "int", 0)
+
+        case "RNN":
+            attrs = sg.RNNAttrsT()
+            read_rnn_attrs(op_reader, attrs)
+
+        case "RMSNormalization" | "SimplifiedLayerNormalization":
+            # ONNX Runtime's `SimplifiedLayerNormalization` is equivalent to
+            # the standard `RMSNormalization` operator.
+            op_type = "RMSNormalization"
+            attrs = sg.LayerNormalizationAttrsT()
+            attrs.axis = op_reader.get_attr("axis", "int", -1)
+            attrs.epsilon = op_reader.get_attr("epsilon", "float", 1e-5)
+            op_reader.ignore_attr("stash_type")
+
+        case "RoiAlign":
+            attrs = sg.RoiAlignAttrsT()
+            attrs.coordMode = op_reader.get_enum_attr(
+                "coordinate_transformation_mode", sg.RoiAlignCoordMode, "half_pixel"
+            )
+            attrs.mode = op_reader.get_enum_attr("mode", sg.RoiAlignMode, "avg")
+            attrs.outputHeight = op_reader.get_attr("output_height", "int", 1)
+            attrs.outputWidth = op_reader.get_attr("output_width", "int", 1)
+            attrs.samplingRatio = op_reader.get_attr("sampling_ratio", "int", 0)
+            attrs.spatialScale = op_reader.get_attr("spatial_scale", "float", 1.0)
+
+        case "RotaryEmbedding":
+            attrs = sg.RotaryEmbeddingAttrsT()
+            attrs.interleaved = bool(op_reader.get_attr("interleaved", "int", 0))
+            attrs.numHeads = op_reader.get_attr("num_heads", "int", 0)
+            attrs.rotaryEmbeddingDim = op_reader.get_attr(
+                "rotary_embedding_dim", "int", 0
+            )
+            op_reader.check_attr("is_packed_batching", "int", 0)
+            op_reader.check_attr("scale", "float", 1.0)
+
+            # RTen uses the input order of the `com.microsoft` operator. The
+            # standard ONNX operator takes `(X, cos_cache, sin_cache,
+            # position_ids?)`.
+            if onnx_op.domain != "com.microsoft":
+                inputs = op_reader.input_indexes + [None] * (
+                    4 - len(op_reader.input_indexes)
+                )
+                x, cos_cache, sin_cache, position_ids = inputs[:4]
+                op_reader.input_indexes = [x, position_ids, cos_cache, sin_cache]
+
         case "ScatterElements":
             attrs = sg.ScatterElementsAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 0)
@@ -907,23 +1218,47 @@ def op_node_from_onnx_operator(
                 "reduction", sg.ScatterReduction, "none"
             )
 
+        case "Selu":
+            attrs = sg.SeluAttrsT()
+            attrs.alpha = op_reader.get_attr("alpha", "float", 1.67326319217681884765625)
+            attrs.gamma = op_reader.get_attr("gamma", "float", 1.05070102214813232421875)
+
         case "Shape":
             op_reader.check_attr("end", "int", 0)
             op_reader.check_attr("start", "int", 0)
 
-        case "Softmax":
-            attrs = sg.SoftmaxAttrsT()
-            attrs.axis = op_reader.get_attr("axis", "int", 0)
+        case "Shrink":
+            attrs = sg.ShrinkAttrsT()
+            attrs.bias = op_reader.get_attr("bias", "float", 0.0)
+            attrs.lambd = op_reader.get_attr("lambd", "float", 0.5)
+
+        case "SpaceToDepth":
+            attrs = sg.SpaceToDepthAttrsT()
+            attrs.blockSize = op_reader.require_attr("blocksize", "int")
 
         case "Split":
             attrs = sg.SplitAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", 0)
-            op_reader.check_attr("num_outputs", "int", 0)
             op_reader.generate_input_from_attr(1, "split", "ints")
 
+            # If split sizes are not specified, the input is split into equal
+            # parts. The number of parts is given by the `num_outputs`
+            # attribute in opset 18+ and by the output count in earlier opsets.
+            attrs.numOutputs = op_reader.get_attr(
+                "num_outputs", "int", len(onnx_op.output)
+            )
+
         case "Squeeze":
             op_reader.generate_input_from_attr(1, "axes", "ints")
 
+        case "STFT":
+            attrs = sg.STFTAttrsT()
+            attrs.onesided = bool(op_reader.get_attr("onesided", "int", 1))
+
+        case "ThresholdedRelu":
+            attrs = sg.EluAttrsT()
+            attrs.alpha = op_reader.get_attr("alpha", "float", 1.0)
+
         case "TopK":
             attrs = sg.TopKAttrsT()
             attrs.axis = op_reader.get_attr("axis", "int", -1)
@@ -938,9 +1273,31 @@ def op_node_from_onnx_operator(
             attrs = sg.TriluAttrsT()
             attrs.upper = bool(op_reader.get_attr("upper", "int", 1))
 
+        case "Unique":
+            attrs = sg.UniqueAttrsT()
+            attrs.axis = op_reader.get_attr("axis", "int", None)
+            attrs.sorted = bool(op_reader.get_attr("sorted", "int", 1))
+
         case "Unsqueeze":
             op_reader.generate_input_from_attr(1, "axes", "ints")
 
+        case "Upsample":
+            # Upsample was replaced by Resize in opset 10. Resize takes inputs
+            # `(X, roi, scales, sizes)`, whereas Upsample takes `(X, scales)`
+            # in opset 9 and has a `scales` attribute in earlier versions.
+            op_type = "Resize"
+            attrs = sg.ResizeAttrsT()
+            attrs.mode = op_reader.get_enum_attr(
+                "mode", sg.ResizeMode, "nearest", fallback="linear"
+            )
+            attrs.coordMode = sg.CoordTransformMode.Asymmetric
+            attrs.nearestMode = sg.NearestMode.Floor
+
+            if len(op_reader.input_indexes) > 1:
+                op_reader.input_indexes.insert(1, None)
+            else:
+                op_reader.generate_input_from_attr(2, "scales", "floats")
+
     if not hasattr(sg.OperatorType, op_type):
         raise Exception(f"Unsupported operator {op_type}")
 
@@ -972,9 +1329,26 @@ def duplicate_node_names(nodes: list[onnx.ValueInfoProto]) -> list[str]:
     return dupes
 
 
-def graph_from_onnx_graph(onnx_graph: onnx.GraphProto) -> Graph:
+def onnx_opset_version(model: onnx.ModelProto) -> int:
+    """
+    Return the version of the default ONNX operator set imported by a model.
+
+    If the model does not specify a version, the latest version supported by
+    the installed `onnx` package is assumed.
+    """
+    for opset in model.opset_import:
+        if opset.domain in ("", "ai.onnx"):
+            return opset.version
+    return onnx.defs.onnx_opset_version()
+
+
+def graph_from_onnx_graph(onnx_graph: onnx.GraphProto, opset_version: int) -> Graph:
     """
     Parse an ONNX model into a graph representation compatible with this library.
+
+    :param onnx_graph: The ONNX graph to convert
+    :param opset_version: Version of the default ONNX operator set used by the
+      model
     """
 
     nodes: list[Node] = []
@@ -1059,7 +1433,11 @@ def graph_from_onnx_graph(onnx_graph: onnx.GraphProto) -> Graph:
 
         try:
             op_node = op_node_from_onnx_operator(
-                operator, value_name_to_index, constant_map, add_node=add_node
+                operator,
+                value_name_to_index,
+                constant_map,
+                add_node=add_node,
+                opset_version=opset_version,
             )
             add_node(op_node)
         except Exception as ex:
@@ -1436,7 +1814,7 @@ def main():
         tensor_data = None
 
     model = onnx.load(args.model)
-    graph = graph_from_onnx_graph(model.graph)
+    graph = graph_from_onnx_graph(model.graph, onnx_opset_version(model))
     metadata = generate_metadata(args.model, args.metadata)
 
     try:
@@ -111,6 +111,46 @@ class OperatorType(object):
     GatherND = 101
     Gelu = 102
     Einsum = 103
+    DepthToSpace = 104
+    SpaceToDepth = 105
+    RoiAlign = 106
+    DFT = 107
+    STFT = 108
+    HannWindow = 109
+    HammingWindow = 110
+    BlackmanWindow = 111
+    MelWeightMatrix = 112
+    RMSNormalization = 113
+    RotaryEmbedding = 114
+    GlobalMaxPool = 115
+    LpPool = 116
+    GlobalLpPool = 117
+    MaxUnpool = 118
+    ReduceL1 = 119
+    ReduceLogSum = 120
+    ReduceLogSumExp = 121
+    Celu = 122
+    Hardmax = 123
+    Mish = 124
+    PRelu = 125
+    Selu = 126
+    Shrink = 127
+    Softsign = 128
+    ThresholdedRelu = 129
+    BitShift = 130
+    BitwiseAnd = 131
+    BitwiseNot = 132
+    BitwiseOr = 133
+    BitwiseXor = 134
+    IsInf = 135
+    IsNaN = 136
+    CenterCropPad = 137
+    Compress = 138
+    ReverseSequence = 139
+    Unique = 140
+    RNN = 141
+    Bernoulli = 142
+    Multinomial = 143
 
 
 class RNNDirection(object):
@@ -119,11 +159,30 @@ class RNNDirection(object):
     Bidirectional = 2
 
 
+class RNNActivation(object):
+    Affine = 0
+    Elu = 1
+    HardSigmoid = 2
+    LeakyRelu = 3
+    Relu = 4
+    ScaledTanh = 5
+    Sigmoid = 6
+    Softplus = 7
+    Softsign = 8
+    Tanh = 9
+    ThresholdedRelu = 10
+
+
 class AutoPad(object):
     Same = 0
     NotSet = 1
 
 
+class BitShiftDirection(object):
+    Left = 0
+    Right = 1
+
+
 class DataType(object):
     Int32 = 0
     Float = 1
@@ -187,6 +246,28 @@ class OperatorAttrs(object):
     GatherNDAttrs = 36
     GeluAttrs = 37
     EinsumAttrs = 38
+    DepthToSpaceAttrs = 39
+    SpaceToDepthAttrs = 40
+    RoiAlignAttrs = 41
+    DFTAttrs = 42
+    STFTAttrs = 43
+    WindowAttrs = 44
+    RotaryEmbeddingAttrs = 45
+    LpPoolAttrs = 46
+    GlobalLpPoolAttrs = 47
+    MaxUnpoolAttrs = 48
+    CumSumAttrs = 49
+    SeluAttrs = 50
+    ShrinkAttrs = 51
+    BitShiftAttrs = 52
+    IsInfAttrs = 53
+    CenterCropPadAttrs = 54
+    CompressAttrs = 55
+    ReverseSequenceAttrs = 56
+    UniqueAttrs = 57
+    RNNAttrs = 58
+    BernoulliAttrs = 59
+    MultinomialAttrs = 60
 
 def OperatorAttrsCreator(unionType, table):
     from flatbuffers.table import Table
@@ -268,6 +349,50 @@ def OperatorAttrsCreator(unionType, table):
         return GeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
     if unionType == OperatorAttrs().EinsumAttrs:
         return EinsumAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().DepthToSpaceAttrs:
+        return DepthToSpaceAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().SpaceToDepthAttrs:
+        return SpaceToDepthAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().RoiAlignAttrs:
+        return RoiAlignAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().DFTAttrs:
+        return DFTAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().STFTAttrs:
+        return STFTAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().WindowAttrs:
+        return WindowAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().RotaryEmbeddingAttrs:
+        return RotaryEmbeddingAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().LpPoolAttrs:
+        return LpPoolAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().GlobalLpPoolAttrs:
+        return GlobalLpPoolAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().MaxUnpoolAttrs:
+        return MaxUnpoolAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().CumSumAttrs:
+        return CumSumAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().SeluAttrs:
+        return SeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ShrinkAttrs:
+        return ShrinkAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().BitShiftAttrs:
+        return BitShiftAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().IsInfAttrs:
+        return IsInfAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().CenterCropPadAttrs:
+        return CenterCropPadAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().CompressAttrs:
+        return CompressAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().ReverseSequenceAttrs:
+        return ReverseSequenceAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().UniqueAttrs:
+        return UniqueAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().RNNAttrs:
+        return RNNAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().BernoulliAttrs:
+        return BernoulliAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs().MultinomialAttrs:
+        return MultinomialAttrsT.InitFromBuf(table.Bytes, table.Pos)
     return None
 
 
@@ -287,11 +412,26 @@ def ScalarCreator(unionType, table):
     return None
 
 
+class DepthToSpaceMode(object):
+    DCR = 0
+    CRD = 1
+
+
 class NMSBoxOrder(object):
     TopLeftBottomRight = 0
     CenterWidthHeight = 1
 
 
+class RoiAlignMode(object):
+    Avg = 0
+    Max = 1
+
+
+class RoiAlignCoordMode(object):
+    HalfPixel = 0
+    OutputHalfPixel = 1
+
+
 class ScatterReduction(object):
     None_ = 0
     Add = 1
@@ -376,8 +516,15 @@ class ArgMaxAttrs(object):
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
+    # ArgMaxAttrs
+    def SelectLastIndex(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
 def ArgMaxAttrsStart(builder):
-    builder.StartObject(2)
+    builder.StartObject(3)
 
 def ArgMaxAttrsAddAxis(builder, axis):
     builder.PrependInt32Slot(0, axis, 0)
@@ -385,6 +532,9 @@ def ArgMaxAttrsAddAxis(builder, axis):
 def ArgMaxAttrsAddKeepDims(builder, keepDims):
     builder.PrependBoolSlot(1, keepDims, 0)
 
+def ArgMaxAttrsAddSelectLastIndex(builder, selectLastIndex):
+    builder.PrependBoolSlot(2, selectLastIndex, 0)
+
 def ArgMaxAttrsEnd(builder):
     return builder.EndObject()
 
@@ -396,6 +546,7 @@ class ArgMaxAttrsT(object):
     def __init__(self):
         self.axis = 0  # type: int
         self.keepDims = False  # type: bool
+        self.selectLastIndex = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -420,12 +571,14 @@ class ArgMaxAttrsT(object):
             return
         self.axis = argMaxAttrs.Axis()
         self.keepDims = argMaxAttrs.KeepDims()
+        self.selectLastIndex = argMaxAttrs.SelectLastIndex()
 
     # ArgMaxAttrsT
     def Pack(self, builder):
         ArgMaxAttrsStart(builder)
         ArgMaxAttrsAddAxis(builder, self.axis)
         ArgMaxAttrsAddKeepDims(builder, self.keepDims)
+        ArgMaxAttrsAddSelectLastIndex(builder, self.selectLastIndex)
         argMaxAttrs = ArgMaxAttrsEnd(builder)
         return argMaxAttrs
 
@@ -547,8 +700,42 @@ class AveragePoolAttrs(object):
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
+    # AveragePoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # AveragePoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # AveragePoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        return o == 0
+
 def AveragePoolAttrsStart(builder):
-    builder.StartObject(5)
+    builder.StartObject(7)
 
 def AveragePoolAttrsAddKernelSize(builder, kernelSize):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
@@ -574,6 +761,15 @@ def AveragePoolAttrsStartStridesVector(builder, numElems):
 def AveragePoolAttrsAddCountIncludePad(builder, countIncludePad):
     builder.PrependBoolSlot(4, countIncludePad, 0)
 
+def AveragePoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(5, ceilMode, 0)
+
+def AveragePoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(6, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def AveragePoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
 def AveragePoolAttrsEnd(builder):
     return builder.EndObject()
 
@@ -592,6 +788,8 @@ class AveragePoolAttrsT(object):
         self.pads = None  # type: List[int]
         self.strides = None  # type: List[int]
         self.countIncludePad = False  # type: bool
+        self.ceilMode = False  # type: bool
+        self.dilations = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -637,6 +835,14 @@ class AveragePoolAttrsT(object):
             else:
                 self.strides = averagePoolAttrs.StridesAsNumpy()
         self.countIncludePad = averagePoolAttrs.CountIncludePad()
+        self.ceilMode = averagePoolAttrs.CeilMode()
+        if not averagePoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(averagePoolAttrs.DilationsLength()):
+                    self.dilations.append(averagePoolAttrs.Dilations(i))
+            else:
+                self.dilations = averagePoolAttrs.DilationsAsNumpy()
 
     # AveragePoolAttrsT
     def Pack(self, builder):
@@ -664,6 +870,14 @@ class AveragePoolAttrsT(object):
                 for i in reversed(range(len(self.strides))):
                     builder.PrependUint32(self.strides[i])
                 strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                AveragePoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
         AveragePoolAttrsStart(builder)
         if self.kernelSize is not None:
             AveragePoolAttrsAddKernelSize(builder, kernelSize)
@@ -673,6 +887,9 @@ class AveragePoolAttrsT(object):
         if self.strides is not None:
             AveragePoolAttrsAddStrides(builder, strides)
         AveragePoolAttrsAddCountIncludePad(builder, self.countIncludePad)
+        AveragePoolAttrsAddCeilMode(builder, self.ceilMode)
+        if self.dilations is not None:
+            AveragePoolAttrsAddDilations(builder, dilations)
         averagePoolAttrs = AveragePoolAttrsEnd(builder)
         return averagePoolAttrs
 
@@ -754,57 +971,68 @@ class BatchNormalizationAttrsT(object):
         return batchNormalizationAttrs
 
 
-class CastAttrs(object):
+class BernoulliAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = CastAttrs()
+        x = BernoulliAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsCastAttrs(cls, buf, offset=0):
+    def GetRootAsBernoulliAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def CastAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def BernoulliAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # CastAttrs
+    # BernoulliAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # CastAttrs
-    def To(self):
+    # BernoulliAttrs
+    def Dtype(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+        return 1
 
-def CastAttrsStart(builder):
-    builder.StartObject(1)
+    # BernoulliAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
 
-def CastAttrsAddTo(builder, to):
-    builder.PrependUint8Slot(0, to, 0)
+def BernoulliAttrsStart(builder):
+    builder.StartObject(2)
 
-def CastAttrsEnd(builder):
+def BernoulliAttrsAddDtype(builder, dtype):
+    builder.PrependUint8Slot(0, dtype, 1)
+
+def BernoulliAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(1, seed, None)
+
+def BernoulliAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class CastAttrsT(object):
+class BernoulliAttrsT(object):
 
-    # CastAttrsT
+    # BernoulliAttrsT
     def __init__(self):
-        self.to = 0  # type: int
+        self.dtype = 1  # type: int
+        self.seed = None  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        castAttrs = CastAttrs()
-        castAttrs.Init(buf, pos)
-        return cls.InitFromObj(castAttrs)
+        bernoulliAttrs = BernoulliAttrs()
+        bernoulliAttrs.Init(buf, pos)
+        return cls.InitFromObj(bernoulliAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -812,76 +1040,78 @@ class CastAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, castAttrs):
-        x = CastAttrsT()
-        x._UnPack(castAttrs)
+    def InitFromObj(cls, bernoulliAttrs):
+        x = BernoulliAttrsT()
+        x._UnPack(bernoulliAttrs)
         return x
 
-    # CastAttrsT
-    def _UnPack(self, castAttrs):
-        if castAttrs is None:
+    # BernoulliAttrsT
+    def _UnPack(self, bernoulliAttrs):
+        if bernoulliAttrs is None:
             return
-        self.to = castAttrs.To()
+        self.dtype = bernoulliAttrs.Dtype()
+        self.seed = bernoulliAttrs.Seed()
 
-    # CastAttrsT
+    # BernoulliAttrsT
     def Pack(self, builder):
-        CastAttrsStart(builder)
-        CastAttrsAddTo(builder, self.to)
-        castAttrs = CastAttrsEnd(builder)
-        return castAttrs
+        BernoulliAttrsStart(builder)
+        BernoulliAttrsAddDtype(builder, self.dtype)
+        BernoulliAttrsAddSeed(builder, self.seed)
+        bernoulliAttrs = BernoulliAttrsEnd(builder)
+        return bernoulliAttrs
 
 
-class ConcatAttrs(object):
+class BitShiftAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConcatAttrs()
+        x = BitShiftAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConcatAttrs(cls, buf, offset=0):
+    def GetRootAsBitShiftAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConcatAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def BitShiftAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConcatAttrs
+    # BitShiftAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConcatAttrs
-    def Axis(self):
+    # BitShiftAttrs
+    def Direction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-def ConcatAttrsStart(builder):
+def BitShiftAttrsStart(builder):
     builder.StartObject(1)
 
-def ConcatAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def BitShiftAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
 
-def ConcatAttrsEnd(builder):
+def BitShiftAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ConcatAttrsT(object):
+class BitShiftAttrsT(object):
 
-    # ConcatAttrsT
+    # BitShiftAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
+        self.direction = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        concatAttrs = ConcatAttrs()
-        concatAttrs.Init(buf, pos)
-        return cls.InitFromObj(concatAttrs)
+        bitShiftAttrs = BitShiftAttrs()
+        bitShiftAttrs.Init(buf, pos)
+        return cls.InitFromObj(bitShiftAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -889,76 +1119,76 @@ class ConcatAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, concatAttrs):
-        x = ConcatAttrsT()
-        x._UnPack(concatAttrs)
+    def InitFromObj(cls, bitShiftAttrs):
+        x = BitShiftAttrsT()
+        x._UnPack(bitShiftAttrs)
         return x
 
-    # ConcatAttrsT
-    def _UnPack(self, concatAttrs):
-        if concatAttrs is None:
+    # BitShiftAttrsT
+    def _UnPack(self, bitShiftAttrs):
+        if bitShiftAttrs is None:
             return
-        self.axis = concatAttrs.Axis()
+        self.direction = bitShiftAttrs.Direction()
 
-    # ConcatAttrsT
+    # BitShiftAttrsT
     def Pack(self, builder):
-        ConcatAttrsStart(builder)
-        ConcatAttrsAddAxis(builder, self.axis)
-        concatAttrs = ConcatAttrsEnd(builder)
-        return concatAttrs
+        BitShiftAttrsStart(builder)
+        BitShiftAttrsAddDirection(builder, self.direction)
+        bitShiftAttrs = BitShiftAttrsEnd(builder)
+        return bitShiftAttrs
 
 
-class IntScalar(object):
+class CastAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = IntScalar()
+        x = CastAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsIntScalar(cls, buf, offset=0):
+    def GetRootAsCastAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def IntScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CastAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # IntScalar
+    # CastAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # IntScalar
-    def Value(self):
+    # CastAttrs
+    def To(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-def IntScalarStart(builder):
+def CastAttrsStart(builder):
     builder.StartObject(1)
 
-def IntScalarAddValue(builder, value):
-    builder.PrependInt32Slot(0, value, 0)
+def CastAttrsAddTo(builder, to):
+    builder.PrependUint8Slot(0, to, 0)
 
-def IntScalarEnd(builder):
+def CastAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class IntScalarT(object):
+class CastAttrsT(object):
 
-    # IntScalarT
+    # CastAttrsT
     def __init__(self):
-        self.value = 0  # type: int
+        self.to = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        intScalar = IntScalar()
-        intScalar.Init(buf, pos)
-        return cls.InitFromObj(intScalar)
+        castAttrs = CastAttrs()
+        castAttrs.Init(buf, pos)
+        return cls.InitFromObj(castAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -966,76 +1196,103 @@ class IntScalarT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, intScalar):
-        x = IntScalarT()
-        x._UnPack(intScalar)
+    def InitFromObj(cls, castAttrs):
+        x = CastAttrsT()
+        x._UnPack(castAttrs)
         return x
 
-    # IntScalarT
-    def _UnPack(self, intScalar):
-        if intScalar is None:
+    # CastAttrsT
+    def _UnPack(self, castAttrs):
+        if castAttrs is None:
             return
-        self.value = intScalar.Value()
+        self.to = castAttrs.To()
 
-    # IntScalarT
+    # CastAttrsT
     def Pack(self, builder):
-        IntScalarStart(builder)
-        IntScalarAddValue(builder, self.value)
-        intScalar = IntScalarEnd(builder)
-        return intScalar
+        CastAttrsStart(builder)
+        CastAttrsAddTo(builder, self.to)
+        castAttrs = CastAttrsEnd(builder)
+        return castAttrs
 
 
-class FloatScalar(object):
+class CenterCropPadAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = FloatScalar()
+        x = CenterCropPadAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsFloatScalar(cls, buf, offset=0):
+    def GetRootAsCenterCropPadAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def FloatScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CenterCropPadAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # FloatScalar
+    # CenterCropPadAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # FloatScalar
-    def Value(self):
+    # CenterCropPadAttrs
+    def Axes(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def FloatScalarStart(builder):
+    # CenterCropPadAttrs
+    def AxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # CenterCropPadAttrs
+    def AxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # CenterCropPadAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def CenterCropPadAttrsStart(builder):
     builder.StartObject(1)
 
-def FloatScalarAddValue(builder, value):
-    builder.PrependFloat32Slot(0, value, 0.0)
+def CenterCropPadAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
 
-def FloatScalarEnd(builder):
+def CenterCropPadAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def CenterCropPadAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class FloatScalarT(object):
+class CenterCropPadAttrsT(object):
 
-    # FloatScalarT
+    # CenterCropPadAttrsT
     def __init__(self):
-        self.value = 0.0  # type: float
+        self.axes = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        floatScalar = FloatScalar()
-        floatScalar.Init(buf, pos)
-        return cls.InitFromObj(floatScalar)
+        centerCropPadAttrs = CenterCropPadAttrs()
+        centerCropPadAttrs.Init(buf, pos)
+        return cls.InitFromObj(centerCropPadAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1043,94 +1300,91 @@ class FloatScalarT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, floatScalar):
-        x = FloatScalarT()
-        x._UnPack(floatScalar)
+    def InitFromObj(cls, centerCropPadAttrs):
+        x = CenterCropPadAttrsT()
+        x._UnPack(centerCropPadAttrs)
         return x
 
-    # FloatScalarT
-    def _UnPack(self, floatScalar):
-        if floatScalar is None:
+    # CenterCropPadAttrsT
+    def _UnPack(self, centerCropPadAttrs):
+        if centerCropPadAttrs is None:
             return
-        self.value = floatScalar.Value()
+        if not centerCropPadAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(centerCropPadAttrs.AxesLength()):
+                    self.axes.append(centerCropPadAttrs.Axes(i))
+            else:
+                self.axes = centerCropPadAttrs.AxesAsNumpy()
 
-    # FloatScalarT
+    # CenterCropPadAttrsT
     def Pack(self, builder):
-        FloatScalarStart(builder)
-        FloatScalarAddValue(builder, self.value)
-        floatScalar = FloatScalarEnd(builder)
-        return floatScalar
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                CenterCropPadAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        CenterCropPadAttrsStart(builder)
+        if self.axes is not None:
+            CenterCropPadAttrsAddAxes(builder, axes)
+        centerCropPadAttrs = CenterCropPadAttrsEnd(builder)
+        return centerCropPadAttrs
 
 
-class ConstantOfShapeAttrs(object):
+class CompressAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConstantOfShapeAttrs()
+        x = CompressAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConstantOfShapeAttrs(cls, buf, offset=0):
+    def GetRootAsCompressAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConstantOfShapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CompressAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConstantOfShapeAttrs
+    # CompressAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConstantOfShapeAttrs
-    def ValueType(self):
+    # CompressAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # ConstantOfShapeAttrs
-    def Value(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            from flatbuffers.table import Table
-            obj = Table(bytearray(), 0)
-            self._tab.Union(obj, o)
-            return obj
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return None
 
-def ConstantOfShapeAttrsStart(builder):
-    builder.StartObject(2)
-
-def ConstantOfShapeAttrsAddValueType(builder, valueType):
-    builder.PrependUint8Slot(0, valueType, 0)
+def CompressAttrsStart(builder):
+    builder.StartObject(1)
 
-def ConstantOfShapeAttrsAddValue(builder, value):
-    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(value), 0)
+def CompressAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, None)
 
-def ConstantOfShapeAttrsEnd(builder):
+def CompressAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import Union
-except:
-    pass
 
-class ConstantOfShapeAttrsT(object):
+class CompressAttrsT(object):
 
-    # ConstantOfShapeAttrsT
+    # CompressAttrsT
     def __init__(self):
-        self.valueType = 0  # type: int
-        self.value = None  # type: Union[None, IntScalarT, FloatScalarT]
+        self.axis = None  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        constantOfShapeAttrs = ConstantOfShapeAttrs()
-        constantOfShapeAttrs.Init(buf, pos)
-        return cls.InitFromObj(constantOfShapeAttrs)
+        compressAttrs = CompressAttrs()
+        compressAttrs.Init(buf, pos)
+        return cls.InitFromObj(compressAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1138,198 +1392,153 @@ class ConstantOfShapeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, constantOfShapeAttrs):
-        x = ConstantOfShapeAttrsT()
-        x._UnPack(constantOfShapeAttrs)
+    def InitFromObj(cls, compressAttrs):
+        x = CompressAttrsT()
+        x._UnPack(compressAttrs)
         return x
 
-    # ConstantOfShapeAttrsT
-    def _UnPack(self, constantOfShapeAttrs):
-        if constantOfShapeAttrs is None:
+    # CompressAttrsT
+    def _UnPack(self, compressAttrs):
+        if compressAttrs is None:
             return
-        self.valueType = constantOfShapeAttrs.ValueType()
-        self.value = ScalarCreator(self.valueType, constantOfShapeAttrs.Value())
+        self.axis = compressAttrs.Axis()
 
-    # ConstantOfShapeAttrsT
+    # CompressAttrsT
     def Pack(self, builder):
-        if self.value is not None:
-            value = self.value.Pack(builder)
-        ConstantOfShapeAttrsStart(builder)
-        ConstantOfShapeAttrsAddValueType(builder, self.valueType)
-        if self.value is not None:
-            ConstantOfShapeAttrsAddValue(builder, value)
-        constantOfShapeAttrs = ConstantOfShapeAttrsEnd(builder)
-        return constantOfShapeAttrs
+        CompressAttrsStart(builder)
+        CompressAttrsAddAxis(builder, self.axis)
+        compressAttrs = CompressAttrsEnd(builder)
+        return compressAttrs
 
 
-class ConvAttrs(object):
+class ConcatAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConvAttrs()
+        x = ConcatAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConvAttrs(cls, buf, offset=0):
+    def GetRootAsConcatAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConvAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ConcatAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConvAttrs
+    # ConcatAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConvAttrs
-    def AutoPad(self):
+    # ConcatAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # ConvAttrs
-    def Pads(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+def ConcatAttrsStart(builder):
+    builder.StartObject(1)
 
-    # ConvAttrs
-    def PadsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+def ConcatAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-    # ConvAttrs
-    def PadsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+def ConcatAttrsEnd(builder):
+    return builder.EndObject()
 
-    # ConvAttrs
-    def PadsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        return o == 0
 
-    # ConvAttrs
-    def Groups(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 0
 
-    # ConvAttrs
-    def Strides(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+class ConcatAttrsT(object):
 
-    # ConvAttrs
-    def StridesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+    # ConcatAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
 
-    # ConvAttrs
-    def StridesLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        concatAttrs = ConcatAttrs()
+        concatAttrs.Init(buf, pos)
+        return cls.InitFromObj(concatAttrs)
 
-    # ConvAttrs
-    def StridesIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        return o == 0
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
 
-    # ConvAttrs
-    def Dilations(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+    @classmethod
+    def InitFromObj(cls, concatAttrs):
+        x = ConcatAttrsT()
+        x._UnPack(concatAttrs)
+        return x
 
-    # ConvAttrs
-    def DilationsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+    # ConcatAttrsT
+    def _UnPack(self, concatAttrs):
+        if concatAttrs is None:
+            return
+        self.axis = concatAttrs.Axis()
 
-    # ConvAttrs
-    def DilationsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+    # ConcatAttrsT
+    def Pack(self, builder):
+        ConcatAttrsStart(builder)
+        ConcatAttrsAddAxis(builder, self.axis)
+        concatAttrs = ConcatAttrsEnd(builder)
+        return concatAttrs
 
-    # ConvAttrs
-    def DilationsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        return o == 0
 
-def ConvAttrsStart(builder):
-    builder.StartObject(5)
+class IntScalar(object):
+    __slots__ = ['_tab']
 
-def ConvAttrsAddAutoPad(builder, autoPad):
-    builder.PrependUint8Slot(0, autoPad, 0)
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = IntScalar()
+        x.Init(buf, n + offset)
+        return x
 
-def ConvAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+    @classmethod
+    def GetRootAsIntScalar(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def IntScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-def ConvAttrsStartPadsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # IntScalar
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
 
-def ConvAttrsAddGroups(builder, groups):
-    builder.PrependUint32Slot(2, groups, 0)
+    # IntScalar
+    def Value(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-def ConvAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+def IntScalarStart(builder):
+    builder.StartObject(1)
 
-def ConvAttrsStartStridesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def IntScalarAddValue(builder, value):
+    builder.PrependInt32Slot(0, value, 0)
 
-def ConvAttrsAddDilations(builder, dilations):
-    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+def IntScalarEnd(builder):
+    return builder.EndObject()
 
-def ConvAttrsStartDilationsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
 
-def ConvAttrsEnd(builder):
-    return builder.EndObject()
 
+class IntScalarT(object):
 
-try:
-    from typing import List
-except:
-    pass
-
-class ConvAttrsT(object):
-
-    # ConvAttrsT
+    # IntScalarT
     def __init__(self):
-        self.autoPad = 0  # type: int
-        self.pads = None  # type: List[int]
-        self.groups = 0  # type: int
-        self.strides = None  # type: List[int]
-        self.dilations = None  # type: List[int]
+        self.value = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        convAttrs = ConvAttrs()
-        convAttrs.Init(buf, pos)
-        return cls.InitFromObj(convAttrs)
+        intScalar = IntScalar()
+        intScalar.Init(buf, pos)
+        return cls.InitFromObj(intScalar)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1337,201 +1546,76 @@ class ConvAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, convAttrs):
-        x = ConvAttrsT()
-        x._UnPack(convAttrs)
+    def InitFromObj(cls, intScalar):
+        x = IntScalarT()
+        x._UnPack(intScalar)
         return x
 
-    # ConvAttrsT
-    def _UnPack(self, convAttrs):
-        if convAttrs is None:
+    # IntScalarT
+    def _UnPack(self, intScalar):
+        if intScalar is None:
             return
-        self.autoPad = convAttrs.AutoPad()
-        if not convAttrs.PadsIsNone():
-            if np is None:
-                self.pads = []
-                for i in range(convAttrs.PadsLength()):
-                    self.pads.append(convAttrs.Pads(i))
-            else:
-                self.pads = convAttrs.PadsAsNumpy()
-        self.groups = convAttrs.Groups()
-        if not convAttrs.StridesIsNone():
-            if np is None:
-                self.strides = []
-                for i in range(convAttrs.StridesLength()):
-                    self.strides.append(convAttrs.Strides(i))
-            else:
-                self.strides = convAttrs.StridesAsNumpy()
-        if not convAttrs.DilationsIsNone():
-            if np is None:
-                self.dilations = []
-                for i in range(convAttrs.DilationsLength()):
-                    self.dilations.append(convAttrs.Dilations(i))
-            else:
-                self.dilations = convAttrs.DilationsAsNumpy()
+        self.value = intScalar.Value()
 
-    # ConvAttrsT
+    # IntScalarT
     def Pack(self, builder):
-        if self.pads is not None:
-            if np is not None and type(self.pads) is np.ndarray:
-                pads = builder.CreateNumpyVector(self.pads)
-            else:
-                ConvAttrsStartPadsVector(builder, len(self.pads))
-                for i in reversed(range(len(self.pads))):
-                    builder.PrependUint32(self.pads[i])
-                pads = builder.EndVector()
-        if self.strides is not None:
-            if np is not None and type(self.strides) is np.ndarray:
-                strides = builder.CreateNumpyVector(self.strides)
-            else:
-                ConvAttrsStartStridesVector(builder, len(self.strides))
-                for i in reversed(range(len(self.strides))):
-                    builder.PrependUint32(self.strides[i])
-                strides = builder.EndVector()
-        if self.dilations is not None:
-            if np is not None and type(self.dilations) is np.ndarray:
-                dilations = builder.CreateNumpyVector(self.dilations)
-            else:
-                ConvAttrsStartDilationsVector(builder, len(self.dilations))
-                for i in reversed(range(len(self.dilations))):
-                    builder.PrependUint32(self.dilations[i])
-                dilations = builder.EndVector()
-        ConvAttrsStart(builder)
-        ConvAttrsAddAutoPad(builder, self.autoPad)
-        if self.pads is not None:
-            ConvAttrsAddPads(builder, pads)
-        ConvAttrsAddGroups(builder, self.groups)
-        if self.strides is not None:
-            ConvAttrsAddStrides(builder, strides)
-        if self.dilations is not None:
-            ConvAttrsAddDilations(builder, dilations)
-        convAttrs = ConvAttrsEnd(builder)
-        return convAttrs
+        IntScalarStart(builder)
+        IntScalarAddValue(builder, self.value)
+        intScalar = IntScalarEnd(builder)
+        return intScalar
 
 
-class ConvTransposeAttrs(object):
+class FloatScalar(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConvTransposeAttrs()
+        x = FloatScalar()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConvTransposeAttrs(cls, buf, offset=0):
+    def GetRootAsFloatScalar(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConvTransposeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def FloatScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConvTransposeAttrs
+    # FloatScalar
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConvTransposeAttrs
-    def Strides(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # ConvTransposeAttrs
-    def StridesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # ConvTransposeAttrs
-    def StridesLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # ConvTransposeAttrs
-    def StridesIsNone(self):
+    # FloatScalar
+    def Value(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
-
-    # ConvTransposeAttrs
-    def AutoPad(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 1
-
-    # ConvTransposeAttrs
-    def Pads(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # ConvTransposeAttrs
-    def PadsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # ConvTransposeAttrs
-    def PadsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # ConvTransposeAttrs
-    def PadsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        return o == 0
-
-def ConvTransposeAttrsStart(builder):
-    builder.StartObject(3)
-
-def ConvTransposeAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
-
-def ConvTransposeAttrsStartStridesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
-
-def ConvTransposeAttrsAddAutoPad(builder, autoPad):
-    builder.PrependUint8Slot(1, autoPad, 1)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def ConvTransposeAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+def FloatScalarStart(builder):
+    builder.StartObject(1)
 
-def ConvTransposeAttrsStartPadsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def FloatScalarAddValue(builder, value):
+    builder.PrependFloat32Slot(0, value, 0.0)
 
-def ConvTransposeAttrsEnd(builder):
+def FloatScalarEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import List
-except:
-    pass
 
-class ConvTransposeAttrsT(object):
+class FloatScalarT(object):
 
-    # ConvTransposeAttrsT
+    # FloatScalarT
     def __init__(self):
-        self.strides = None  # type: List[int]
-        self.autoPad = 1  # type: int
-        self.pads = None  # type: List[int]
+        self.value = 0.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        convTransposeAttrs = ConvTransposeAttrs()
-        convTransposeAttrs.Init(buf, pos)
-        return cls.InitFromObj(convTransposeAttrs)
+        floatScalar = FloatScalar()
+        floatScalar.Init(buf, pos)
+        return cls.InitFromObj(floatScalar)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1539,110 +1623,94 @@ class ConvTransposeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, convTransposeAttrs):
-        x = ConvTransposeAttrsT()
-        x._UnPack(convTransposeAttrs)
+    def InitFromObj(cls, floatScalar):
+        x = FloatScalarT()
+        x._UnPack(floatScalar)
         return x
 
-    # ConvTransposeAttrsT
-    def _UnPack(self, convTransposeAttrs):
-        if convTransposeAttrs is None:
+    # FloatScalarT
+    def _UnPack(self, floatScalar):
+        if floatScalar is None:
             return
-        if not convTransposeAttrs.StridesIsNone():
-            if np is None:
-                self.strides = []
-                for i in range(convTransposeAttrs.StridesLength()):
-                    self.strides.append(convTransposeAttrs.Strides(i))
-            else:
-                self.strides = convTransposeAttrs.StridesAsNumpy()
-        self.autoPad = convTransposeAttrs.AutoPad()
-        if not convTransposeAttrs.PadsIsNone():
-            if np is None:
-                self.pads = []
-                for i in range(convTransposeAttrs.PadsLength()):
-                    self.pads.append(convTransposeAttrs.Pads(i))
-            else:
-                self.pads = convTransposeAttrs.PadsAsNumpy()
+        self.value = floatScalar.Value()
 
-    # ConvTransposeAttrsT
+    # FloatScalarT
     def Pack(self, builder):
-        if self.strides is not None:
-            if np is not None and type(self.strides) is np.ndarray:
-                strides = builder.CreateNumpyVector(self.strides)
-            else:
-                ConvTransposeAttrsStartStridesVector(builder, len(self.strides))
-                for i in reversed(range(len(self.strides))):
-                    builder.PrependUint32(self.strides[i])
-                strides = builder.EndVector()
-        if self.pads is not None:
-            if np is not None and type(self.pads) is np.ndarray:
-                pads = builder.CreateNumpyVector(self.pads)
-            else:
-                ConvTransposeAttrsStartPadsVector(builder, len(self.pads))
-                for i in reversed(range(len(self.pads))):
-                    builder.PrependUint32(self.pads[i])
-                pads = builder.EndVector()
-        ConvTransposeAttrsStart(builder)
-        if self.strides is not None:
-            ConvTransposeAttrsAddStrides(builder, strides)
-        ConvTransposeAttrsAddAutoPad(builder, self.autoPad)
-        if self.pads is not None:
-            ConvTransposeAttrsAddPads(builder, pads)
-        convTransposeAttrs = ConvTransposeAttrsEnd(builder)
-        return convTransposeAttrs
+        FloatScalarStart(builder)
+        FloatScalarAddValue(builder, self.value)
+        floatScalar = FloatScalarEnd(builder)
+        return floatScalar
 
 
-class EinsumAttrs(object):
+class ConstantOfShapeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = EinsumAttrs()
+        x = ConstantOfShapeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsEinsumAttrs(cls, buf, offset=0):
+    def GetRootAsConstantOfShapeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def EinsumAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ConstantOfShapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # EinsumAttrs
+    # ConstantOfShapeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # EinsumAttrs
-    def Equation(self):
+    # ConstantOfShapeAttrs
+    def ValueType(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.String(o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ConstantOfShapeAttrs
+    def Value(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            from flatbuffers.table import Table
+            obj = Table(bytearray(), 0)
+            self._tab.Union(obj, o)
+            return obj
         return None
 
-def EinsumAttrsStart(builder):
-    builder.StartObject(1)
+def ConstantOfShapeAttrsStart(builder):
+    builder.StartObject(2)
 
-def EinsumAttrsAddEquation(builder, equation):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(equation), 0)
+def ConstantOfShapeAttrsAddValueType(builder, valueType):
+    builder.PrependUint8Slot(0, valueType, 0)
 
-def EinsumAttrsEnd(builder):
+def ConstantOfShapeAttrsAddValue(builder, value):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(value), 0)
+
+def ConstantOfShapeAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import Union
+except:
+    pass
 
-class EinsumAttrsT(object):
+class ConstantOfShapeAttrsT(object):
 
-    # EinsumAttrsT
+    # ConstantOfShapeAttrsT
     def __init__(self):
-        self.equation = None  # type: str
+        self.valueType = 0  # type: int
+        self.value = None  # type: Union[None, IntScalarT, FloatScalarT]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        einsumAttrs = EinsumAttrs()
-        einsumAttrs.Init(buf, pos)
-        return cls.InitFromObj(einsumAttrs)
+        constantOfShapeAttrs = ConstantOfShapeAttrs()
+        constantOfShapeAttrs.Init(buf, pos)
+        return cls.InitFromObj(constantOfShapeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1650,79 +1718,198 @@ class EinsumAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, einsumAttrs):
-        x = EinsumAttrsT()
-        x._UnPack(einsumAttrs)
+    def InitFromObj(cls, constantOfShapeAttrs):
+        x = ConstantOfShapeAttrsT()
+        x._UnPack(constantOfShapeAttrs)
         return x
 
-    # EinsumAttrsT
-    def _UnPack(self, einsumAttrs):
-        if einsumAttrs is None:
+    # ConstantOfShapeAttrsT
+    def _UnPack(self, constantOfShapeAttrs):
+        if constantOfShapeAttrs is None:
             return
-        self.equation = einsumAttrs.Equation()
+        self.valueType = constantOfShapeAttrs.ValueType()
+        self.value = ScalarCreator(self.valueType, constantOfShapeAttrs.Value())
 
-    # EinsumAttrsT
+    # ConstantOfShapeAttrsT
     def Pack(self, builder):
-        if self.equation is not None:
-            equation = builder.CreateString(self.equation)
-        EinsumAttrsStart(builder)
-        if self.equation is not None:
-            EinsumAttrsAddEquation(builder, equation)
-        einsumAttrs = EinsumAttrsEnd(builder)
-        return einsumAttrs
+        if self.value is not None:
+            value = self.value.Pack(builder)
+        ConstantOfShapeAttrsStart(builder)
+        ConstantOfShapeAttrsAddValueType(builder, self.valueType)
+        if self.value is not None:
+            ConstantOfShapeAttrsAddValue(builder, value)
+        constantOfShapeAttrs = ConstantOfShapeAttrsEnd(builder)
+        return constantOfShapeAttrs
 
 
-class EluAttrs(object):
+class ConvAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = EluAttrs()
+        x = ConvAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsEluAttrs(cls, buf, offset=0):
+    def GetRootAsConvAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def EluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ConvAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # EluAttrs
+    # ConvAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # EluAttrs
-    def Alpha(self):
+    # ConvAttrs
+    def AutoPad(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def EluAttrsStart(builder):
-    builder.StartObject(1)
+    # ConvAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def EluAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+    # ConvAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def EluAttrsEnd(builder):
+    # ConvAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
+    # ConvAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # ConvAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # ConvAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+def ConvAttrsStart(builder):
+    builder.StartObject(5)
+
+def ConvAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(0, autoPad, 0)
+
+def ConvAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def ConvAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(2, groups, 0)
+
+def ConvAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def ConvAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def ConvAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class EluAttrsT(object):
+class ConvAttrsT(object):
 
-    # EluAttrsT
+    # ConvAttrsT
     def __init__(self):
-        self.alpha = 0.0  # type: float
+        self.autoPad = 0  # type: int
+        self.pads = None  # type: List[int]
+        self.groups = 0  # type: int
+        self.strides = None  # type: List[int]
+        self.dilations = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        eluAttrs = EluAttrs()
-        eluAttrs.Init(buf, pos)
-        return cls.InitFromObj(eluAttrs)
+        convAttrs = ConvAttrs()
+        convAttrs.Init(buf, pos)
+        return cls.InitFromObj(convAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1730,76 +1917,246 @@ class EluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, eluAttrs):
-        x = EluAttrsT()
-        x._UnPack(eluAttrs)
+    def InitFromObj(cls, convAttrs):
+        x = ConvAttrsT()
+        x._UnPack(convAttrs)
         return x
 
-    # EluAttrsT
-    def _UnPack(self, eluAttrs):
-        if eluAttrs is None:
+    # ConvAttrsT
+    def _UnPack(self, convAttrs):
+        if convAttrs is None:
             return
-        self.alpha = eluAttrs.Alpha()
+        self.autoPad = convAttrs.AutoPad()
+        if not convAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(convAttrs.PadsLength()):
+                    self.pads.append(convAttrs.Pads(i))
+            else:
+                self.pads = convAttrs.PadsAsNumpy()
+        self.groups = convAttrs.Groups()
+        if not convAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(convAttrs.StridesLength()):
+                    self.strides.append(convAttrs.Strides(i))
+            else:
+                self.strides = convAttrs.StridesAsNumpy()
+        if not convAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(convAttrs.DilationsLength()):
+                    self.dilations.append(convAttrs.Dilations(i))
+            else:
+                self.dilations = convAttrs.DilationsAsNumpy()
 
-    # EluAttrsT
+    # ConvAttrsT
     def Pack(self, builder):
-        EluAttrsStart(builder)
-        EluAttrsAddAlpha(builder, self.alpha)
-        eluAttrs = EluAttrsEnd(builder)
-        return eluAttrs
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                ConvAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                ConvAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                ConvAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        ConvAttrsStart(builder)
+        ConvAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            ConvAttrsAddPads(builder, pads)
+        ConvAttrsAddGroups(builder, self.groups)
+        if self.strides is not None:
+            ConvAttrsAddStrides(builder, strides)
+        if self.dilations is not None:
+            ConvAttrsAddDilations(builder, dilations)
+        convAttrs = ConvAttrsEnd(builder)
+        return convAttrs
 
 
-class FlattenAttrs(object):
+class ConvTransposeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = FlattenAttrs()
+        x = ConvTransposeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsFlattenAttrs(cls, buf, offset=0):
+    def GetRootAsConvTransposeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def FlattenAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ConvTransposeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # FlattenAttrs
+    # ConvTransposeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # FlattenAttrs
-    def Axis(self):
+    # ConvTransposeAttrs
+    def Strides(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-def FlattenAttrsStart(builder):
-    builder.StartObject(1)
+    # ConvTransposeAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def FlattenAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+    # ConvTransposeAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
 
-def FlattenAttrsEnd(builder):
+    # ConvTransposeAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def AutoPad(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 1
+
+    # ConvTransposeAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 1
+
+    # ConvTransposeAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+def ConvTransposeAttrsStart(builder):
+    builder.StartObject(5)
+
+def ConvTransposeAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def ConvTransposeAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(1, autoPad, 1)
+
+def ConvTransposeAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def ConvTransposeAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(3, groups, 1)
+
+def ConvTransposeAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def ConvTransposeAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class FlattenAttrsT(object):
+class ConvTransposeAttrsT(object):
 
-    # FlattenAttrsT
+    # ConvTransposeAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
+        self.strides = None  # type: List[int]
+        self.autoPad = 1  # type: int
+        self.pads = None  # type: List[int]
+        self.groups = 1  # type: int
+        self.dilations = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        flattenAttrs = FlattenAttrs()
-        flattenAttrs.Init(buf, pos)
-        return cls.InitFromObj(flattenAttrs)
+        convTransposeAttrs = ConvTransposeAttrs()
+        convTransposeAttrs.Init(buf, pos)
+        return cls.InitFromObj(convTransposeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1807,87 +2164,2486 @@ class FlattenAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, flattenAttrs):
-        x = FlattenAttrsT()
-        x._UnPack(flattenAttrs)
+    def InitFromObj(cls, convTransposeAttrs):
+        x = ConvTransposeAttrsT()
+        x._UnPack(convTransposeAttrs)
         return x
 
-    # FlattenAttrsT
-    def _UnPack(self, flattenAttrs):
-        if flattenAttrs is None:
+    # ConvTransposeAttrsT
+    def _UnPack(self, convTransposeAttrs):
+        if convTransposeAttrs is None:
             return
-        self.axis = flattenAttrs.Axis()
-
-    # FlattenAttrsT
-    def Pack(self, builder):
-        FlattenAttrsStart(builder)
-        FlattenAttrsAddAxis(builder, self.axis)
-        flattenAttrs = FlattenAttrsEnd(builder)
-        return flattenAttrs
+        if not convTransposeAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(convTransposeAttrs.StridesLength()):
+                    self.strides.append(convTransposeAttrs.Strides(i))
+            else:
+                self.strides = convTransposeAttrs.StridesAsNumpy()
+        self.autoPad = convTransposeAttrs.AutoPad()
+        if not convTransposeAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(convTransposeAttrs.PadsLength()):
+                    self.pads.append(convTransposeAttrs.Pads(i))
+            else:
+                self.pads = convTransposeAttrs.PadsAsNumpy()
+        self.groups = convTransposeAttrs.Groups()
+        if not convTransposeAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(convTransposeAttrs.DilationsLength()):
+                    self.dilations.append(convTransposeAttrs.Dilations(i))
+            else:
+                self.dilations = convTransposeAttrs.DilationsAsNumpy()
+
+    # ConvTransposeAttrsT
+    def Pack(self, builder):
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                ConvTransposeAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                ConvTransposeAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                ConvTransposeAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        ConvTransposeAttrsStart(builder)
+        if self.strides is not None:
+            ConvTransposeAttrsAddStrides(builder, strides)
+        ConvTransposeAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            ConvTransposeAttrsAddPads(builder, pads)
+        ConvTransposeAttrsAddGroups(builder, self.groups)
+        if self.dilations is not None:
+            ConvTransposeAttrsAddDilations(builder, dilations)
+        convTransposeAttrs = ConvTransposeAttrsEnd(builder)
+        return convTransposeAttrs
+
+
+class CumSumAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = CumSumAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsCumSumAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def CumSumAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # CumSumAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # CumSumAttrs
+    def Exclusive(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # CumSumAttrs
+    def Reverse(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def CumSumAttrsStart(builder):
+    builder.StartObject(2)
+
+def CumSumAttrsAddExclusive(builder, exclusive):
+    builder.PrependBoolSlot(0, exclusive, 0)
+
+def CumSumAttrsAddReverse(builder, reverse):
+    builder.PrependBoolSlot(1, reverse, 0)
+
+def CumSumAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class CumSumAttrsT(object):
+
+    # CumSumAttrsT
+    def __init__(self):
+        self.exclusive = False  # type: bool
+        self.reverse = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        cumSumAttrs = CumSumAttrs()
+        cumSumAttrs.Init(buf, pos)
+        return cls.InitFromObj(cumSumAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, cumSumAttrs):
+        x = CumSumAttrsT()
+        x._UnPack(cumSumAttrs)
+        return x
+
+    # CumSumAttrsT
+    def _UnPack(self, cumSumAttrs):
+        if cumSumAttrs is None:
+            return
+        self.exclusive = cumSumAttrs.Exclusive()
+        self.reverse = cumSumAttrs.Reverse()
+
+    # CumSumAttrsT
+    def Pack(self, builder):
+        CumSumAttrsStart(builder)
+        CumSumAttrsAddExclusive(builder, self.exclusive)
+        CumSumAttrsAddReverse(builder, self.reverse)
+        cumSumAttrs = CumSumAttrsEnd(builder)
+        return cumSumAttrs
+
+
+class DepthToSpaceAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = DepthToSpaceAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsDepthToSpaceAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def DepthToSpaceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # DepthToSpaceAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # DepthToSpaceAttrs
+    def Mode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # DepthToSpaceAttrs
+    def BlockSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+def DepthToSpaceAttrsStart(builder):
+    builder.StartObject(2)
+
+def DepthToSpaceAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def DepthToSpaceAttrsAddBlockSize(builder, blockSize):
+    builder.PrependUint32Slot(1, blockSize, 0)
+
+def DepthToSpaceAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class DepthToSpaceAttrsT(object):
+
+    # DepthToSpaceAttrsT
+    def __init__(self):
+        self.mode = 0  # type: int
+        self.blockSize = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        depthToSpaceAttrs = DepthToSpaceAttrs()
+        depthToSpaceAttrs.Init(buf, pos)
+        return cls.InitFromObj(depthToSpaceAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, depthToSpaceAttrs):
+        x = DepthToSpaceAttrsT()
+        x._UnPack(depthToSpaceAttrs)
+        return x
+
+    # DepthToSpaceAttrsT
+    def _UnPack(self, depthToSpaceAttrs):
+        if depthToSpaceAttrs is None:
+            return
+        self.mode = depthToSpaceAttrs.Mode()
+        self.blockSize = depthToSpaceAttrs.BlockSize()
+
+    # DepthToSpaceAttrsT
+    def Pack(self, builder):
+        DepthToSpaceAttrsStart(builder)
+        DepthToSpaceAttrsAddMode(builder, self.mode)
+        DepthToSpaceAttrsAddBlockSize(builder, self.blockSize)
+        depthToSpaceAttrs = DepthToSpaceAttrsEnd(builder)
+        return depthToSpaceAttrs
+
+
+class DFTAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = DFTAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsDFTAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def DFTAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # DFTAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # DFTAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # DFTAttrs
+    def Inverse(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # DFTAttrs
+    def Onesided(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def DFTAttrsStart(builder):
+    builder.StartObject(3)
+
+def DFTAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def DFTAttrsAddInverse(builder, inverse):
+    builder.PrependBoolSlot(1, inverse, 0)
+
+def DFTAttrsAddOnesided(builder, onesided):
+    builder.PrependBoolSlot(2, onesided, 0)
+
+def DFTAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class DFTAttrsT(object):
+
+    # DFTAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
+        self.inverse = False  # type: bool
+        self.onesided = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        dftattrs = DFTAttrs()
+        dftattrs.Init(buf, pos)
+        return cls.InitFromObj(dftattrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, dftattrs):
+        x = DFTAttrsT()
+        x._UnPack(dftattrs)
+        return x
+
+    # DFTAttrsT
+    def _UnPack(self, dftattrs):
+        if dftattrs is None:
+            return
+        self.axis = dftattrs.Axis()
+        self.inverse = dftattrs.Inverse()
+        self.onesided = dftattrs.Onesided()
+
+    # DFTAttrsT
+    def Pack(self, builder):
+        DFTAttrsStart(builder)
+        DFTAttrsAddAxis(builder, self.axis)
+        DFTAttrsAddInverse(builder, self.inverse)
+        DFTAttrsAddOnesided(builder, self.onesided)
+        dftattrs = DFTAttrsEnd(builder)
+        return dftattrs
+
+
+class EinsumAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = EinsumAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsEinsumAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def EinsumAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # EinsumAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # EinsumAttrs
+    def Equation(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def EinsumAttrsStart(builder):
+    builder.StartObject(1)
+
+def EinsumAttrsAddEquation(builder, equation):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(equation), 0)
+
+def EinsumAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class EinsumAttrsT(object):
+
+    # EinsumAttrsT
+    def __init__(self):
+        self.equation = None  # type: str
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        einsumAttrs = EinsumAttrs()
+        einsumAttrs.Init(buf, pos)
+        return cls.InitFromObj(einsumAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, einsumAttrs):
+        x = EinsumAttrsT()
+        x._UnPack(einsumAttrs)
+        return x
+
+    # EinsumAttrsT
+    def _UnPack(self, einsumAttrs):
+        if einsumAttrs is None:
+            return
+        self.equation = einsumAttrs.Equation()
+
+    # EinsumAttrsT
+    def Pack(self, builder):
+        if self.equation is not None:
+            equation = builder.CreateString(self.equation)
+        EinsumAttrsStart(builder)
+        if self.equation is not None:
+            EinsumAttrsAddEquation(builder, equation)
+        einsumAttrs = EinsumAttrsEnd(builder)
+        return einsumAttrs
+
+
+class EluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = EluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsEluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def EluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # EluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # EluAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def EluAttrsStart(builder):
+    builder.StartObject(1)
+
+def EluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def EluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class EluAttrsT(object):
+
+    # EluAttrsT
+    def __init__(self):
+        self.alpha = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        eluAttrs = EluAttrs()
+        eluAttrs.Init(buf, pos)
+        return cls.InitFromObj(eluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, eluAttrs):
+        x = EluAttrsT()
+        x._UnPack(eluAttrs)
+        return x
+
+    # EluAttrsT
+    def _UnPack(self, eluAttrs):
+        if eluAttrs is None:
+            return
+        self.alpha = eluAttrs.Alpha()
+
+    # EluAttrsT
+    def Pack(self, builder):
+        EluAttrsStart(builder)
+        EluAttrsAddAlpha(builder, self.alpha)
+        eluAttrs = EluAttrsEnd(builder)
+        return eluAttrs
+
+
+class FlattenAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = FlattenAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsFlattenAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def FlattenAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # FlattenAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # FlattenAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def FlattenAttrsStart(builder):
+    builder.StartObject(1)
+
+def FlattenAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def FlattenAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class FlattenAttrsT(object):
+
+    # FlattenAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        flattenAttrs = FlattenAttrs()
+        flattenAttrs.Init(buf, pos)
+        return cls.InitFromObj(flattenAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, flattenAttrs):
+        x = FlattenAttrsT()
+        x._UnPack(flattenAttrs)
+        return x
+
+    # FlattenAttrsT
+    def _UnPack(self, flattenAttrs):
+        if flattenAttrs is None:
+            return
+        self.axis = flattenAttrs.Axis()
+
+    # FlattenAttrsT
+    def Pack(self, builder):
+        FlattenAttrsStart(builder)
+        FlattenAttrsAddAxis(builder, self.axis)
+        flattenAttrs = FlattenAttrsEnd(builder)
+        return flattenAttrs
+
+
+class LayerNormalizationAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LayerNormalizationAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLayerNormalizationAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LayerNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LayerNormalizationAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LayerNormalizationAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # LayerNormalizationAttrs
+    def Epsilon(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def LayerNormalizationAttrsStart(builder):
+    builder.StartObject(2)
+
+def LayerNormalizationAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def LayerNormalizationAttrsAddEpsilon(builder, epsilon):
+    builder.PrependFloat32Slot(1, epsilon, 0.0)
+
+def LayerNormalizationAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class LayerNormalizationAttrsT(object):
+
+    # LayerNormalizationAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
+        self.epsilon = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        layerNormalizationAttrs = LayerNormalizationAttrs()
+        layerNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(layerNormalizationAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, layerNormalizationAttrs):
+        x = LayerNormalizationAttrsT()
+        x._UnPack(layerNormalizationAttrs)
+        return x
+
+    # LayerNormalizationAttrsT
+    def _UnPack(self, layerNormalizationAttrs):
+        if layerNormalizationAttrs is None:
+            return
+        self.axis = layerNormalizationAttrs.Axis()
+        self.epsilon = layerNormalizationAttrs.Epsilon()
+
+    # LayerNormalizationAttrsT
+    def Pack(self, builder):
+        LayerNormalizationAttrsStart(builder)
+        LayerNormalizationAttrsAddAxis(builder, self.axis)
+        LayerNormalizationAttrsAddEpsilon(builder, self.epsilon)
+        layerNormalizationAttrs = LayerNormalizationAttrsEnd(builder)
+        return layerNormalizationAttrs
+
+
+class GatherAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GatherAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGatherAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GatherAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GatherAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GatherAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def GatherAttrsStart(builder):
+    builder.StartObject(1)
+
+def GatherAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def GatherAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GatherAttrsT(object):
+
+    # GatherAttrsT
+    def __init__(self):
+        self.axis = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gatherAttrs = GatherAttrs()
+        gatherAttrs.Init(buf, pos)
+        return cls.InitFromObj(gatherAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gatherAttrs):
+        x = GatherAttrsT()
+        x._UnPack(gatherAttrs)
+        return x
+
+    # GatherAttrsT
+    def _UnPack(self, gatherAttrs):
+        if gatherAttrs is None:
+            return
+        self.axis = gatherAttrs.Axis()
+
+    # GatherAttrsT
+    def Pack(self, builder):
+        GatherAttrsStart(builder)
+        GatherAttrsAddAxis(builder, self.axis)
+        gatherAttrs = GatherAttrsEnd(builder)
+        return gatherAttrs
+
+
+class GatherNDAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GatherNDAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGatherNDAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GatherNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GatherNDAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GatherNDAttrs
+    def BatchDims(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def GatherNDAttrsStart(builder):
+    builder.StartObject(1)
+
+def GatherNDAttrsAddBatchDims(builder, batchDims):
+    builder.PrependInt32Slot(0, batchDims, 0)
+
+def GatherNDAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GatherNDAttrsT(object):
+
+    # GatherNDAttrsT
+    def __init__(self):
+        self.batchDims = 0  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gatherNdattrs = GatherNDAttrs()
+        gatherNdattrs.Init(buf, pos)
+        return cls.InitFromObj(gatherNdattrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gatherNdattrs):
+        x = GatherNDAttrsT()
+        x._UnPack(gatherNdattrs)
+        return x
+
+    # GatherNDAttrsT
+    def _UnPack(self, gatherNdattrs):
+        if gatherNdattrs is None:
+            return
+        self.batchDims = gatherNdattrs.BatchDims()
+
+    # GatherNDAttrsT
+    def Pack(self, builder):
+        GatherNDAttrsStart(builder)
+        GatherNDAttrsAddBatchDims(builder, self.batchDims)
+        gatherNdattrs = GatherNDAttrsEnd(builder)
+        return gatherNdattrs
+
+
+class GeluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GeluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGeluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GeluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+def GeluAttrsStart(builder):
+    builder.StartObject(0)
+
+def GeluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GeluAttrsT(object):
+
+    # GeluAttrsT
+    def __init__(self):
+        pass
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        geluAttrs = GeluAttrs()
+        geluAttrs.Init(buf, pos)
+        return cls.InitFromObj(geluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, geluAttrs):
+        x = GeluAttrsT()
+        x._UnPack(geluAttrs)
+        return x
+
+    # GeluAttrsT
+    def _UnPack(self, geluAttrs):
+        if geluAttrs is None:
+            return
+
+    # GeluAttrsT
+    def Pack(self, builder):
+        GeluAttrsStart(builder)
+        geluAttrs = GeluAttrsEnd(builder)
+        return geluAttrs
+
+
+class GemmAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GemmAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGemmAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GemmAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GemmAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GemmAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # GemmAttrs
+    def Beta(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # GemmAttrs
+    def TransposeA(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # GemmAttrs
+    def TransposeB(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GemmAttrsStart(builder):
+    builder.StartObject(4)
+
+def GemmAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def GemmAttrsAddBeta(builder, beta):
+    builder.PrependFloat32Slot(1, beta, 0.0)
+
+def GemmAttrsAddTransposeA(builder, transposeA):
+    builder.PrependBoolSlot(2, transposeA, 0)
+
+def GemmAttrsAddTransposeB(builder, transposeB):
+    builder.PrependBoolSlot(3, transposeB, 0)
+
+def GemmAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GemmAttrsT(object):
+
+    # GemmAttrsT
+    def __init__(self):
+        self.alpha = 0.0  # type: float
+        self.beta = 0.0  # type: float
+        self.transposeA = False  # type: bool
+        self.transposeB = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gemmAttrs = GemmAttrs()
+        gemmAttrs.Init(buf, pos)
+        return cls.InitFromObj(gemmAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gemmAttrs):
+        x = GemmAttrsT()
+        x._UnPack(gemmAttrs)
+        return x
+
+    # GemmAttrsT
+    def _UnPack(self, gemmAttrs):
+        if gemmAttrs is None:
+            return
+        self.alpha = gemmAttrs.Alpha()
+        self.beta = gemmAttrs.Beta()
+        self.transposeA = gemmAttrs.TransposeA()
+        self.transposeB = gemmAttrs.TransposeB()
+
+    # GemmAttrsT
+    def Pack(self, builder):
+        GemmAttrsStart(builder)
+        GemmAttrsAddAlpha(builder, self.alpha)
+        GemmAttrsAddBeta(builder, self.beta)
+        GemmAttrsAddTransposeA(builder, self.transposeA)
+        GemmAttrsAddTransposeB(builder, self.transposeB)
+        gemmAttrs = GemmAttrsEnd(builder)
+        return gemmAttrs
+
+
+class GlobalLpPoolAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GlobalLpPoolAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGlobalLpPoolAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GlobalLpPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GlobalLpPoolAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GlobalLpPoolAttrs
+    def P(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 2
+
+def GlobalLpPoolAttrsStart(builder):
+    builder.StartObject(1)
+
+def GlobalLpPoolAttrsAddP(builder, p):
+    builder.PrependUint32Slot(0, p, 2)
+
+def GlobalLpPoolAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GlobalLpPoolAttrsT(object):
+
+    # GlobalLpPoolAttrsT
+    def __init__(self):
+        self.p = 2  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        globalLpPoolAttrs = GlobalLpPoolAttrs()
+        globalLpPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(globalLpPoolAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, globalLpPoolAttrs):
+        x = GlobalLpPoolAttrsT()
+        x._UnPack(globalLpPoolAttrs)
+        return x
+
+    # GlobalLpPoolAttrsT
+    def _UnPack(self, globalLpPoolAttrs):
+        if globalLpPoolAttrs is None:
+            return
+        self.p = globalLpPoolAttrs.P()
+
+    # GlobalLpPoolAttrsT
+    def Pack(self, builder):
+        GlobalLpPoolAttrsStart(builder)
+        GlobalLpPoolAttrsAddP(builder, self.p)
+        globalLpPoolAttrs = GlobalLpPoolAttrsEnd(builder)
+        return globalLpPoolAttrs
+
+
+class GRUAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GRUAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGRUAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GRUAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GRUAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GRUAttrs
+    def Direction(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # GRUAttrs
+    def HiddenSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # GRUAttrs
+    def LinearBeforeReset(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # GRUAttrs
+    def Activations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = RNNActivationFn()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # GRUAttrs
+    def ActivationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # GRUAttrs
+    def ActivationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # GRUAttrs
+    def Clip(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # GRUAttrs
+    def BatchFirst(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GRUAttrsStart(builder):
+    builder.StartObject(6)
+
+def GRUAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def GRUAttrsAddHiddenSize(builder, hiddenSize):
+    builder.PrependUint32Slot(1, hiddenSize, 0)
+
+def GRUAttrsAddLinearBeforeReset(builder, linearBeforeReset):
+    builder.PrependBoolSlot(2, linearBeforeReset, 0)
+
+def GRUAttrsAddActivations(builder, activations):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(activations), 0)
+
+def GRUAttrsStartActivationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def GRUAttrsAddClip(builder, clip):
+    builder.PrependFloat32Slot(4, clip, None)
+
+def GRUAttrsAddBatchFirst(builder, batchFirst):
+    builder.PrependBoolSlot(5, batchFirst, 0)
+
+def GRUAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class GRUAttrsT(object):
+
+    # GRUAttrsT
+    def __init__(self):
+        self.direction = 0  # type: int
+        self.hiddenSize = 0  # type: int
+        self.linearBeforeReset = False  # type: bool
+        self.activations = None  # type: List[RNNActivationFnT]
+        self.clip = None  # type: Optional[float]
+        self.batchFirst = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gruattrs = GRUAttrs()
+        gruattrs.Init(buf, pos)
+        return cls.InitFromObj(gruattrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gruattrs):
+        x = GRUAttrsT()
+        x._UnPack(gruattrs)
+        return x
+
+    # GRUAttrsT
+    def _UnPack(self, gruattrs):
+        if gruattrs is None:
+            return
+        self.direction = gruattrs.Direction()
+        self.hiddenSize = gruattrs.HiddenSize()
+        self.linearBeforeReset = gruattrs.LinearBeforeReset()
+        if not gruattrs.ActivationsIsNone():
+            self.activations = []
+            for i in range(gruattrs.ActivationsLength()):
+                if gruattrs.Activations(i) is None:
+                    self.activations.append(None)
+                else:
+                    rNNActivationFn_ = RNNActivationFnT.InitFromObj(gruattrs.Activations(i))
+                    self.activations.append(rNNActivationFn_)
+        self.clip = gruattrs.Clip()
+        self.batchFirst = gruattrs.BatchFirst()
+
+    # GRUAttrsT
+    def Pack(self, builder):
+        if self.activations is not None:
+            activationslist = []
+            for i in range(len(self.activations)):
+                activationslist.append(self.activations[i].Pack(builder))
+            GRUAttrsStartActivationsVector(builder, len(self.activations))
+            for i in reversed(range(len(self.activations))):
+                builder.PrependUOffsetTRelative(activationslist[i])
+            activations = builder.EndVector()
+        GRUAttrsStart(builder)
+        GRUAttrsAddDirection(builder, self.direction)
+        GRUAttrsAddHiddenSize(builder, self.hiddenSize)
+        GRUAttrsAddLinearBeforeReset(builder, self.linearBeforeReset)
+        if self.activations is not None:
+            GRUAttrsAddActivations(builder, activations)
+        GRUAttrsAddClip(builder, self.clip)
+        GRUAttrsAddBatchFirst(builder, self.batchFirst)
+        gruattrs = GRUAttrsEnd(builder)
+        return gruattrs
+
+
+class HardSigmoidAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = HardSigmoidAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsHardSigmoidAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def HardSigmoidAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # HardSigmoidAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # HardSigmoidAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # HardSigmoidAttrs
+    def Beta(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def HardSigmoidAttrsStart(builder):
+    builder.StartObject(2)
+
+def HardSigmoidAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def HardSigmoidAttrsAddBeta(builder, beta):
+    builder.PrependFloat32Slot(1, beta, 0.0)
+
+def HardSigmoidAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class HardSigmoidAttrsT(object):
+
+    # HardSigmoidAttrsT
+    def __init__(self):
+        self.alpha = 0.0  # type: float
+        self.beta = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        hardSigmoidAttrs = HardSigmoidAttrs()
+        hardSigmoidAttrs.Init(buf, pos)
+        return cls.InitFromObj(hardSigmoidAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, hardSigmoidAttrs):
+        x = HardSigmoidAttrsT()
+        x._UnPack(hardSigmoidAttrs)
+        return x
+
+    # HardSigmoidAttrsT
+    def _UnPack(self, hardSigmoidAttrs):
+        if hardSigmoidAttrs is None:
+            return
+        self.alpha = hardSigmoidAttrs.Alpha()
+        self.beta = hardSigmoidAttrs.Beta()
+
+    # HardSigmoidAttrsT
+    def Pack(self, builder):
+        HardSigmoidAttrsStart(builder)
+        HardSigmoidAttrsAddAlpha(builder, self.alpha)
+        HardSigmoidAttrsAddBeta(builder, self.beta)
+        hardSigmoidAttrs = HardSigmoidAttrsEnd(builder)
+        return hardSigmoidAttrs
+
+
+class IsInfAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = IsInfAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsIsInfAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def IsInfAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # IsInfAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # IsInfAttrs
+    def DetectNegative(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return True
+
+    # IsInfAttrs
+    def DetectPositive(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return True
+
+def IsInfAttrsStart(builder):
+    builder.StartObject(2)
+
+def IsInfAttrsAddDetectNegative(builder, detectNegative):
+    builder.PrependBoolSlot(0, detectNegative, 1)
+
+def IsInfAttrsAddDetectPositive(builder, detectPositive):
+    builder.PrependBoolSlot(1, detectPositive, 1)
+
+def IsInfAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class IsInfAttrsT(object):
+
+    # IsInfAttrsT
+    def __init__(self):
+        self.detectNegative = True  # type: bool
+        self.detectPositive = True  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        isInfAttrs = IsInfAttrs()
+        isInfAttrs.Init(buf, pos)
+        return cls.InitFromObj(isInfAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, isInfAttrs):
+        x = IsInfAttrsT()
+        x._UnPack(isInfAttrs)
+        return x
+
+    # IsInfAttrsT
+    def _UnPack(self, isInfAttrs):
+        if isInfAttrs is None:
+            return
+        self.detectNegative = isInfAttrs.DetectNegative()
+        self.detectPositive = isInfAttrs.DetectPositive()
+
+    # IsInfAttrsT
+    def Pack(self, builder):
+        IsInfAttrsStart(builder)
+        IsInfAttrsAddDetectNegative(builder, self.detectNegative)
+        IsInfAttrsAddDetectPositive(builder, self.detectPositive)
+        isInfAttrs = IsInfAttrsEnd(builder)
+        return isInfAttrs
+
+
+class LeakyReluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LeakyReluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLeakyReluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LeakyReluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LeakyReluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LeakyReluAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def LeakyReluAttrsStart(builder):
+    builder.StartObject(1)
+
+def LeakyReluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def LeakyReluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class LeakyReluAttrsT(object):
+
+    # LeakyReluAttrsT
+    def __init__(self):
+        self.alpha = 0.0  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        leakyReluAttrs = LeakyReluAttrs()
+        leakyReluAttrs.Init(buf, pos)
+        return cls.InitFromObj(leakyReluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, leakyReluAttrs):
+        x = LeakyReluAttrsT()
+        x._UnPack(leakyReluAttrs)
+        return x
+
+    # LeakyReluAttrsT
+    def _UnPack(self, leakyReluAttrs):
+        if leakyReluAttrs is None:
+            return
+        self.alpha = leakyReluAttrs.Alpha()
+
+    # LeakyReluAttrsT
+    def Pack(self, builder):
+        LeakyReluAttrsStart(builder)
+        LeakyReluAttrsAddAlpha(builder, self.alpha)
+        leakyReluAttrs = LeakyReluAttrsEnd(builder)
+        return leakyReluAttrs
+
+
+class LpPoolAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LpPoolAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLpPoolAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LpPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LpPoolAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LpPoolAttrs
+    def KernelSize(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # LpPoolAttrs
+    def AutoPad(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # LpPoolAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # LpPoolAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # LpPoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+    # LpPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # LpPoolAttrs
+    def P(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 2
+
+def LpPoolAttrsStart(builder):
+    builder.StartObject(7)
+
+def LpPoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def LpPoolAttrsStartKernelSizeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(1, autoPad, 0)
+
+def LpPoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def LpPoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def LpPoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def LpPoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(5, ceilMode, 0)
+
+def LpPoolAttrsAddP(builder, p):
+    builder.PrependUint32Slot(6, p, 2)
+
+def LpPoolAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class LpPoolAttrsT(object):
+
+    # LpPoolAttrsT
+    def __init__(self):
+        self.kernelSize = None  # type: List[int]
+        self.autoPad = 0  # type: int
+        self.pads = None  # type: List[int]
+        self.strides = None  # type: List[int]
+        self.dilations = None  # type: List[int]
+        self.ceilMode = False  # type: bool
+        self.p = 2  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        lpPoolAttrs = LpPoolAttrs()
+        lpPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(lpPoolAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, lpPoolAttrs):
+        x = LpPoolAttrsT()
+        x._UnPack(lpPoolAttrs)
+        return x
+
+    # LpPoolAttrsT
+    def _UnPack(self, lpPoolAttrs):
+        if lpPoolAttrs is None:
+            return
+        if not lpPoolAttrs.KernelSizeIsNone():
+            if np is None:
+                self.kernelSize = []
+                for i in range(lpPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(lpPoolAttrs.KernelSize(i))
+            else:
+                self.kernelSize = lpPoolAttrs.KernelSizeAsNumpy()
+        self.autoPad = lpPoolAttrs.AutoPad()
+        if not lpPoolAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(lpPoolAttrs.PadsLength()):
+                    self.pads.append(lpPoolAttrs.Pads(i))
+            else:
+                self.pads = lpPoolAttrs.PadsAsNumpy()
+        if not lpPoolAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(lpPoolAttrs.StridesLength()):
+                    self.strides.append(lpPoolAttrs.Strides(i))
+            else:
+                self.strides = lpPoolAttrs.StridesAsNumpy()
+        if not lpPoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(lpPoolAttrs.DilationsLength()):
+                    self.dilations.append(lpPoolAttrs.Dilations(i))
+            else:
+                self.dilations = lpPoolAttrs.DilationsAsNumpy()
+        self.ceilMode = lpPoolAttrs.CeilMode()
+        self.p = lpPoolAttrs.P()
+
+    # LpPoolAttrsT
+    def Pack(self, builder):
+        if self.kernelSize is not None:
+            if np is not None and type(self.kernelSize) is np.ndarray:
+                kernelSize = builder.CreateNumpyVector(self.kernelSize)
+            else:
+                LpPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                for i in reversed(range(len(self.kernelSize))):
+                    builder.PrependUint32(self.kernelSize[i])
+                kernelSize = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                LpPoolAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                LpPoolAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                LpPoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        LpPoolAttrsStart(builder)
+        if self.kernelSize is not None:
+            LpPoolAttrsAddKernelSize(builder, kernelSize)
+        LpPoolAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            LpPoolAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            LpPoolAttrsAddStrides(builder, strides)
+        if self.dilations is not None:
+            LpPoolAttrsAddDilations(builder, dilations)
+        LpPoolAttrsAddCeilMode(builder, self.ceilMode)
+        LpPoolAttrsAddP(builder, self.p)
+        lpPoolAttrs = LpPoolAttrsEnd(builder)
+        return lpPoolAttrs
+
+
+class LSTMAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LSTMAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLSTMAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LSTMAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LSTMAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LSTMAttrs
+    def Direction(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # LSTMAttrs
+    def HiddenSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # LSTMAttrs
+    def Activations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = RNNActivationFn()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # LSTMAttrs
+    def ActivationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LSTMAttrs
+    def ActivationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # LSTMAttrs
+    def Clip(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # LSTMAttrs
+    def BatchFirst(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # LSTMAttrs
+    def InputForget(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def LSTMAttrsStart(builder):
+    builder.StartObject(6)
+
+def LSTMAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def LSTMAttrsAddHiddenSize(builder, hiddenSize):
+    builder.PrependUint32Slot(1, hiddenSize, 0)
+
+def LSTMAttrsAddActivations(builder, activations):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(activations), 0)
+
+def LSTMAttrsStartActivationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LSTMAttrsAddClip(builder, clip):
+    builder.PrependFloat32Slot(3, clip, None)
+
+def LSTMAttrsAddBatchFirst(builder, batchFirst):
+    builder.PrependBoolSlot(4, batchFirst, 0)
+
+def LSTMAttrsAddInputForget(builder, inputForget):
+    builder.PrependBoolSlot(5, inputForget, 0)
+
+def LSTMAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class LSTMAttrsT(object):
+
+    # LSTMAttrsT
+    def __init__(self):
+        self.direction = 0  # type: int
+        self.hiddenSize = 0  # type: int
+        self.activations = None  # type: List[RNNActivationFnT]
+        self.clip = None  # type: Optional[float]
+        self.batchFirst = False  # type: bool
+        self.inputForget = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        lstmattrs = LSTMAttrs()
+        lstmattrs.Init(buf, pos)
+        return cls.InitFromObj(lstmattrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, lstmattrs):
+        x = LSTMAttrsT()
+        x._UnPack(lstmattrs)
+        return x
+
+    # LSTMAttrsT
+    def _UnPack(self, lstmattrs):
+        if lstmattrs is None:
+            return
+        self.direction = lstmattrs.Direction()
+        self.hiddenSize = lstmattrs.HiddenSize()
+        if not lstmattrs.ActivationsIsNone():
+            self.activations = []
+            for i in range(lstmattrs.ActivationsLength()):
+                if lstmattrs.Activations(i) is None:
+                    self.activations.append(None)
+                else:
+                    rNNActivationFn_ = RNNActivationFnT.InitFromObj(lstmattrs.Activations(i))
+                    self.activations.append(rNNActivationFn_)
+        self.clip = lstmattrs.Clip()
+        self.batchFirst = lstmattrs.BatchFirst()
+        self.inputForget = lstmattrs.InputForget()
+
+    # LSTMAttrsT
+    def Pack(self, builder):
+        if self.activations is not None:
+            activationslist = []
+            for i in range(len(self.activations)):
+                activationslist.append(self.activations[i].Pack(builder))
+            LSTMAttrsStartActivationsVector(builder, len(self.activations))
+            for i in reversed(range(len(self.activations))):
+                builder.PrependUOffsetTRelative(activationslist[i])
+            activations = builder.EndVector()
+        LSTMAttrsStart(builder)
+        LSTMAttrsAddDirection(builder, self.direction)
+        LSTMAttrsAddHiddenSize(builder, self.hiddenSize)
+        if self.activations is not None:
+            LSTMAttrsAddActivations(builder, activations)
+        LSTMAttrsAddClip(builder, self.clip)
+        LSTMAttrsAddBatchFirst(builder, self.batchFirst)
+        LSTMAttrsAddInputForget(builder, self.inputForget)
+        lstmattrs = LSTMAttrsEnd(builder)
+        return lstmattrs
+
+
+class MaxPoolAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = MaxPoolAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # MaxPoolAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # MaxPoolAttrs
+    def KernelSize(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # MaxPoolAttrs
+    def AutoPad(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # MaxPoolAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # MaxPoolAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # MaxPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # MaxPoolAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        return o == 0
+
+    # MaxPoolAttrs
+    def ReturnIndices(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def MaxPoolAttrsStart(builder):
+    builder.StartObject(7)
+
+def MaxPoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(1, autoPad, 0)
+
+def MaxPoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def MaxPoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def MaxPoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(4, ceilMode, 0)
+
+def MaxPoolAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(5, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def MaxPoolAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddReturnIndices(builder, returnIndices):
+    builder.PrependBoolSlot(6, returnIndices, 0)
+
+def MaxPoolAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class MaxPoolAttrsT(object):
+
+    # MaxPoolAttrsT
+    def __init__(self):
+        self.kernelSize = None  # type: List[int]
+        self.autoPad = 0  # type: int
+        self.pads = None  # type: List[int]
+        self.strides = None  # type: List[int]
+        self.ceilMode = False  # type: bool
+        self.dilations = None  # type: List[int]
+        self.returnIndices = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        maxPoolAttrs = MaxPoolAttrs()
+        maxPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(maxPoolAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, maxPoolAttrs):
+        x = MaxPoolAttrsT()
+        x._UnPack(maxPoolAttrs)
+        return x
+
+    # MaxPoolAttrsT
+    def _UnPack(self, maxPoolAttrs):
+        if maxPoolAttrs is None:
+            return
+        if not maxPoolAttrs.KernelSizeIsNone():
+            if np is None:
+                self.kernelSize = []
+                for i in range(maxPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
+            else:
+                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
+        self.autoPad = maxPoolAttrs.AutoPad()
+        if not maxPoolAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(maxPoolAttrs.PadsLength()):
+                    self.pads.append(maxPoolAttrs.Pads(i))
+            else:
+                self.pads = maxPoolAttrs.PadsAsNumpy()
+        if not maxPoolAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(maxPoolAttrs.StridesLength()):
+                    self.strides.append(maxPoolAttrs.Strides(i))
+            else:
+                self.strides = maxPoolAttrs.StridesAsNumpy()
+        self.ceilMode = maxPoolAttrs.CeilMode()
+        if not maxPoolAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(maxPoolAttrs.DilationsLength()):
+                    self.dilations.append(maxPoolAttrs.Dilations(i))
+            else:
+                self.dilations = maxPoolAttrs.DilationsAsNumpy()
+        self.returnIndices = maxPoolAttrs.ReturnIndices()
+
+    # MaxPoolAttrsT
+    def Pack(self, builder):
+        if self.kernelSize is not None:
+            if np is not None and type(self.kernelSize) is np.ndarray:
+                kernelSize = builder.CreateNumpyVector(self.kernelSize)
+            else:
+                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                for i in reversed(range(len(self.kernelSize))):
+                    builder.PrependUint32(self.kernelSize[i])
+                kernelSize = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                MaxPoolAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        MaxPoolAttrsStart(builder)
+        if self.kernelSize is not None:
+            MaxPoolAttrsAddKernelSize(builder, kernelSize)
+        MaxPoolAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            MaxPoolAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            MaxPoolAttrsAddStrides(builder, strides)
+        MaxPoolAttrsAddCeilMode(builder, self.ceilMode)
+        if self.dilations is not None:
+            MaxPoolAttrsAddDilations(builder, dilations)
+        MaxPoolAttrsAddReturnIndices(builder, self.returnIndices)
+        maxPoolAttrs = MaxPoolAttrsEnd(builder)
+        return maxPoolAttrs
 
 
-class LayerNormalizationAttrs(object):
+class MaxUnpoolAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LayerNormalizationAttrs()
+        x = MaxUnpoolAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLayerNormalizationAttrs(cls, buf, offset=0):
+    def GetRootAsMaxUnpoolAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LayerNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MaxUnpoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LayerNormalizationAttrs
+    # MaxUnpoolAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LayerNormalizationAttrs
-    def Axis(self):
+    # MaxUnpoolAttrs
+    def KernelSize(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # LayerNormalizationAttrs
-    def Epsilon(self):
+    # MaxUnpoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxUnpoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxUnpoolAttrs
+    def KernelSizeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # MaxUnpoolAttrs
+    def Pads(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def LayerNormalizationAttrsStart(builder):
-    builder.StartObject(2)
+    # MaxUnpoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def LayerNormalizationAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+    # MaxUnpoolAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
 
-def LayerNormalizationAttrsAddEpsilon(builder, epsilon):
-    builder.PrependFloat32Slot(1, epsilon, 0.0)
+    # MaxUnpoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
 
-def LayerNormalizationAttrsEnd(builder):
+    # MaxUnpoolAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxUnpoolAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxUnpoolAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxUnpoolAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+def MaxUnpoolAttrsStart(builder):
+    builder.StartObject(3)
+
+def MaxUnpoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def MaxUnpoolAttrsStartKernelSizeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxUnpoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def MaxUnpoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxUnpoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def MaxUnpoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxUnpoolAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class LayerNormalizationAttrsT(object):
+class MaxUnpoolAttrsT(object):
 
-    # LayerNormalizationAttrsT
+    # MaxUnpoolAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
-        self.epsilon = 0.0  # type: float
+        self.kernelSize = None  # type: List[int]
+        self.pads = None  # type: List[int]
+        self.strides = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        layerNormalizationAttrs = LayerNormalizationAttrs()
-        layerNormalizationAttrs.Init(buf, pos)
-        return cls.InitFromObj(layerNormalizationAttrs)
+        maxUnpoolAttrs = MaxUnpoolAttrs()
+        maxUnpoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(maxUnpoolAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1895,78 +4651,125 @@ class LayerNormalizationAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, layerNormalizationAttrs):
-        x = LayerNormalizationAttrsT()
-        x._UnPack(layerNormalizationAttrs)
+    def InitFromObj(cls, maxUnpoolAttrs):
+        x = MaxUnpoolAttrsT()
+        x._UnPack(maxUnpoolAttrs)
         return x
 
-    # LayerNormalizationAttrsT
-    def _UnPack(self, layerNormalizationAttrs):
-        if layerNormalizationAttrs is None:
+    # MaxUnpoolAttrsT
+    def _UnPack(self, maxUnpoolAttrs):
+        if maxUnpoolAttrs is None:
             return
-        self.axis = layerNormalizationAttrs.Axis()
-        self.epsilon = layerNormalizationAttrs.Epsilon()
+        if not maxUnpoolAttrs.KernelSizeIsNone():
+            if np is None:
+                self.kernelSize = []
+                for i in range(maxUnpoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(maxUnpoolAttrs.KernelSize(i))
+            else:
+                self.kernelSize = maxUnpoolAttrs.KernelSizeAsNumpy()
+        if not maxUnpoolAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(maxUnpoolAttrs.PadsLength()):
+                    self.pads.append(maxUnpoolAttrs.Pads(i))
+            else:
+                self.pads = maxUnpoolAttrs.PadsAsNumpy()
+        if not maxUnpoolAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(maxUnpoolAttrs.StridesLength()):
+                    self.strides.append(maxUnpoolAttrs.Strides(i))
+            else:
+                self.strides = maxUnpoolAttrs.StridesAsNumpy()
 
-    # LayerNormalizationAttrsT
+    # MaxUnpoolAttrsT
     def Pack(self, builder):
-        LayerNormalizationAttrsStart(builder)
-        LayerNormalizationAttrsAddAxis(builder, self.axis)
-        LayerNormalizationAttrsAddEpsilon(builder, self.epsilon)
-        layerNormalizationAttrs = LayerNormalizationAttrsEnd(builder)
-        return layerNormalizationAttrs
+        if self.kernelSize is not None:
+            if np is not None and type(self.kernelSize) is np.ndarray:
+                kernelSize = builder.CreateNumpyVector(self.kernelSize)
+            else:
+                MaxUnpoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                for i in reversed(range(len(self.kernelSize))):
+                    builder.PrependUint32(self.kernelSize[i])
+                kernelSize = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                MaxUnpoolAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                MaxUnpoolAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        MaxUnpoolAttrsStart(builder)
+        if self.kernelSize is not None:
+            MaxUnpoolAttrsAddKernelSize(builder, kernelSize)
+        if self.pads is not None:
+            MaxUnpoolAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            MaxUnpoolAttrsAddStrides(builder, strides)
+        maxUnpoolAttrs = MaxUnpoolAttrsEnd(builder)
+        return maxUnpoolAttrs
 
 
-class GatherAttrs(object):
+class ModAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GatherAttrs()
+        x = ModAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGatherAttrs(cls, buf, offset=0):
+    def GetRootAsModAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GatherAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ModAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GatherAttrs
+    # ModAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GatherAttrs
-    def Axis(self):
+    # ModAttrs
+    def Fmod(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def GatherAttrsStart(builder):
+def ModAttrsStart(builder):
     builder.StartObject(1)
 
-def GatherAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def ModAttrsAddFmod(builder, fmod):
+    builder.PrependBoolSlot(0, fmod, 0)
 
-def GatherAttrsEnd(builder):
+def ModAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GatherAttrsT(object):
+class ModAttrsT(object):
 
-    # GatherAttrsT
+    # ModAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
+        self.fmod = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gatherAttrs = GatherAttrs()
-        gatherAttrs.Init(buf, pos)
-        return cls.InitFromObj(gatherAttrs)
+        modAttrs = ModAttrs()
+        modAttrs.Init(buf, pos)
+        return cls.InitFromObj(modAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1974,76 +4777,87 @@ class GatherAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gatherAttrs):
-        x = GatherAttrsT()
-        x._UnPack(gatherAttrs)
+    def InitFromObj(cls, modAttrs):
+        x = ModAttrsT()
+        x._UnPack(modAttrs)
         return x
 
-    # GatherAttrsT
-    def _UnPack(self, gatherAttrs):
-        if gatherAttrs is None:
+    # ModAttrsT
+    def _UnPack(self, modAttrs):
+        if modAttrs is None:
             return
-        self.axis = gatherAttrs.Axis()
+        self.fmod = modAttrs.Fmod()
 
-    # GatherAttrsT
+    # ModAttrsT
     def Pack(self, builder):
-        GatherAttrsStart(builder)
-        GatherAttrsAddAxis(builder, self.axis)
-        gatherAttrs = GatherAttrsEnd(builder)
-        return gatherAttrs
+        ModAttrsStart(builder)
+        ModAttrsAddFmod(builder, self.fmod)
+        modAttrs = ModAttrsEnd(builder)
+        return modAttrs
 
 
-class GatherNDAttrs(object):
+class MultinomialAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GatherNDAttrs()
+        x = MultinomialAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGatherNDAttrs(cls, buf, offset=0):
+    def GetRootAsMultinomialAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GatherNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MultinomialAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GatherNDAttrs
+    # MultinomialAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GatherNDAttrs
-    def BatchDims(self):
+    # MultinomialAttrs
+    def SampleSize(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 1
+
+    # MultinomialAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def MultinomialAttrsStart(builder):
+    builder.StartObject(2)
 
-def GatherNDAttrsStart(builder):
-    builder.StartObject(1)
+def MultinomialAttrsAddSampleSize(builder, sampleSize):
+    builder.PrependUint32Slot(0, sampleSize, 1)
 
-def GatherNDAttrsAddBatchDims(builder, batchDims):
-    builder.PrependInt32Slot(0, batchDims, 0)
+def MultinomialAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(1, seed, None)
 
-def GatherNDAttrsEnd(builder):
+def MultinomialAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GatherNDAttrsT(object):
+class MultinomialAttrsT(object):
 
-    # GatherNDAttrsT
+    # MultinomialAttrsT
     def __init__(self):
-        self.batchDims = 0  # type: int
+        self.sampleSize = 1  # type: int
+        self.seed = None  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gatherNdattrs = GatherNDAttrs()
-        gatherNdattrs.Init(buf, pos)
-        return cls.InitFromObj(gatherNdattrs)
+        multinomialAttrs = MultinomialAttrs()
+        multinomialAttrs.Init(buf, pos)
+        return cls.InitFromObj(multinomialAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2051,66 +4865,78 @@ class GatherNDAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gatherNdattrs):
-        x = GatherNDAttrsT()
-        x._UnPack(gatherNdattrs)
+    def InitFromObj(cls, multinomialAttrs):
+        x = MultinomialAttrsT()
+        x._UnPack(multinomialAttrs)
         return x
 
-    # GatherNDAttrsT
-    def _UnPack(self, gatherNdattrs):
-        if gatherNdattrs is None:
+    # MultinomialAttrsT
+    def _UnPack(self, multinomialAttrs):
+        if multinomialAttrs is None:
             return
-        self.batchDims = gatherNdattrs.BatchDims()
+        self.sampleSize = multinomialAttrs.SampleSize()
+        self.seed = multinomialAttrs.Seed()
 
-    # GatherNDAttrsT
+    # MultinomialAttrsT
     def Pack(self, builder):
-        GatherNDAttrsStart(builder)
-        GatherNDAttrsAddBatchDims(builder, self.batchDims)
-        gatherNdattrs = GatherNDAttrsEnd(builder)
-        return gatherNdattrs
+        MultinomialAttrsStart(builder)
+        MultinomialAttrsAddSampleSize(builder, self.sampleSize)
+        MultinomialAttrsAddSeed(builder, self.seed)
+        multinomialAttrs = MultinomialAttrsEnd(builder)
+        return multinomialAttrs
 
 
-class GeluAttrs(object):
+class NonMaxSuppressionAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GeluAttrs()
+        x = NonMaxSuppressionAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGeluAttrs(cls, buf, offset=0):
+    def GetRootAsNonMaxSuppressionAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def NonMaxSuppressionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GeluAttrs
+    # NonMaxSuppressionAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-def GeluAttrsStart(builder):
-    builder.StartObject(0)
+    # NonMaxSuppressionAttrs
+    def BoxOrder(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def GeluAttrsEnd(builder):
+def NonMaxSuppressionAttrsStart(builder):
+    builder.StartObject(1)
+
+def NonMaxSuppressionAttrsAddBoxOrder(builder, boxOrder):
+    builder.PrependUint8Slot(0, boxOrder, 0)
+
+def NonMaxSuppressionAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GeluAttrsT(object):
+class NonMaxSuppressionAttrsT(object):
 
-    # GeluAttrsT
+    # NonMaxSuppressionAttrsT
     def __init__(self):
-        pass
+        self.boxOrder = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        geluAttrs = GeluAttrs()
-        geluAttrs.Init(buf, pos)
-        return cls.InitFromObj(geluAttrs)
+        nonMaxSuppressionAttrs = NonMaxSuppressionAttrs()
+        nonMaxSuppressionAttrs.Init(buf, pos)
+        return cls.InitFromObj(nonMaxSuppressionAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2118,107 +4944,76 @@ class GeluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, geluAttrs):
-        x = GeluAttrsT()
-        x._UnPack(geluAttrs)
+    def InitFromObj(cls, nonMaxSuppressionAttrs):
+        x = NonMaxSuppressionAttrsT()
+        x._UnPack(nonMaxSuppressionAttrs)
         return x
 
-    # GeluAttrsT
-    def _UnPack(self, geluAttrs):
-        if geluAttrs is None:
+    # NonMaxSuppressionAttrsT
+    def _UnPack(self, nonMaxSuppressionAttrs):
+        if nonMaxSuppressionAttrs is None:
             return
+        self.boxOrder = nonMaxSuppressionAttrs.BoxOrder()
 
-    # GeluAttrsT
+    # NonMaxSuppressionAttrsT
     def Pack(self, builder):
-        GeluAttrsStart(builder)
-        geluAttrs = GeluAttrsEnd(builder)
-        return geluAttrs
+        NonMaxSuppressionAttrsStart(builder)
+        NonMaxSuppressionAttrsAddBoxOrder(builder, self.boxOrder)
+        nonMaxSuppressionAttrs = NonMaxSuppressionAttrsEnd(builder)
+        return nonMaxSuppressionAttrs
 
 
-class GemmAttrs(object):
+class OneHotAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GemmAttrs()
+        x = OneHotAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGemmAttrs(cls, buf, offset=0):
+    def GetRootAsOneHotAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GemmAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def OneHotAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GemmAttrs
+    # OneHotAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GemmAttrs
-    def Alpha(self):
+    # OneHotAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # GemmAttrs
-    def Beta(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # GemmAttrs
-    def TransposeA(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-    # GemmAttrs
-    def TransposeB(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-def GemmAttrsStart(builder):
-    builder.StartObject(4)
-
-def GemmAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
-
-def GemmAttrsAddBeta(builder, beta):
-    builder.PrependFloat32Slot(1, beta, 0.0)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-def GemmAttrsAddTransposeA(builder, transposeA):
-    builder.PrependBoolSlot(2, transposeA, 0)
+def OneHotAttrsStart(builder):
+    builder.StartObject(1)
 
-def GemmAttrsAddTransposeB(builder, transposeB):
-    builder.PrependBoolSlot(3, transposeB, 0)
+def OneHotAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def GemmAttrsEnd(builder):
+def OneHotAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GemmAttrsT(object):
+class OneHotAttrsT(object):
 
-    # GemmAttrsT
+    # OneHotAttrsT
     def __init__(self):
-        self.alpha = 0.0  # type: float
-        self.beta = 0.0  # type: float
-        self.transposeA = False  # type: bool
-        self.transposeB = False  # type: bool
+        self.axis = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gemmAttrs = GemmAttrs()
-        gemmAttrs.Init(buf, pos)
-        return cls.InitFromObj(gemmAttrs)
+        oneHotAttrs = OneHotAttrs()
+        oneHotAttrs.Init(buf, pos)
+        return cls.InitFromObj(oneHotAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2226,104 +5021,136 @@ class GemmAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gemmAttrs):
-        x = GemmAttrsT()
-        x._UnPack(gemmAttrs)
+    def InitFromObj(cls, oneHotAttrs):
+        x = OneHotAttrsT()
+        x._UnPack(oneHotAttrs)
         return x
 
-    # GemmAttrsT
-    def _UnPack(self, gemmAttrs):
-        if gemmAttrs is None:
+    # OneHotAttrsT
+    def _UnPack(self, oneHotAttrs):
+        if oneHotAttrs is None:
             return
-        self.alpha = gemmAttrs.Alpha()
-        self.beta = gemmAttrs.Beta()
-        self.transposeA = gemmAttrs.TransposeA()
-        self.transposeB = gemmAttrs.TransposeB()
+        self.axis = oneHotAttrs.Axis()
 
-    # GemmAttrsT
+    # OneHotAttrsT
     def Pack(self, builder):
-        GemmAttrsStart(builder)
-        GemmAttrsAddAlpha(builder, self.alpha)
-        GemmAttrsAddBeta(builder, self.beta)
-        GemmAttrsAddTransposeA(builder, self.transposeA)
-        GemmAttrsAddTransposeB(builder, self.transposeB)
-        gemmAttrs = GemmAttrsEnd(builder)
-        return gemmAttrs
+        OneHotAttrsStart(builder)
+        OneHotAttrsAddAxis(builder, self.axis)
+        oneHotAttrs = OneHotAttrsEnd(builder)
+        return oneHotAttrs
 
 
-class GRUAttrs(object):
+class RandomNormalAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GRUAttrs()
+        x = RandomNormalAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGRUAttrs(cls, buf, offset=0):
+    def GetRootAsRandomNormalAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GRUAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomNormalAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GRUAttrs
+    # RandomNormalAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GRUAttrs
-    def Direction(self):
+    # RandomNormalAttrs
+    def Mean(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # GRUAttrs
-    def HiddenSize(self):
+    # RandomNormalAttrs
+    def Scale(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # GRUAttrs
-    def LinearBeforeReset(self):
+    # RandomNormalAttrs
+    def Seed(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
 
-def GRUAttrsStart(builder):
-    builder.StartObject(3)
+    # RandomNormalAttrs
+    def Shape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # RandomNormalAttrs
+    def ShapeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # RandomNormalAttrs
+    def ShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # RandomNormalAttrs
+    def ShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+def RandomNormalAttrsStart(builder):
+    builder.StartObject(4)
+
+def RandomNormalAttrsAddMean(builder, mean):
+    builder.PrependFloat32Slot(0, mean, 0.0)
 
-def GRUAttrsAddDirection(builder, direction):
-    builder.PrependUint8Slot(0, direction, 0)
+def RandomNormalAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(1, scale, 0.0)
 
-def GRUAttrsAddHiddenSize(builder, hiddenSize):
-    builder.PrependUint32Slot(1, hiddenSize, 0)
+def RandomNormalAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
 
-def GRUAttrsAddLinearBeforeReset(builder, linearBeforeReset):
-    builder.PrependBoolSlot(2, linearBeforeReset, 0)
+def RandomNormalAttrsAddShape(builder, shape):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
 
-def GRUAttrsEnd(builder):
+def RandomNormalAttrsStartShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def RandomNormalAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class GRUAttrsT(object):
+class RandomNormalAttrsT(object):
 
-    # GRUAttrsT
+    # RandomNormalAttrsT
     def __init__(self):
-        self.direction = 0  # type: int
-        self.hiddenSize = 0  # type: int
-        self.linearBeforeReset = False  # type: bool
+        self.mean = 0.0  # type: float
+        self.scale = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
+        self.shape = None  # type: List[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gruattrs = GRUAttrs()
-        gruattrs.Init(buf, pos)
-        return cls.InitFromObj(gruattrs)
+        randomNormalAttrs = RandomNormalAttrs()
+        randomNormalAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomNormalAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2331,91 +5158,119 @@ class GRUAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gruattrs):
-        x = GRUAttrsT()
-        x._UnPack(gruattrs)
+    def InitFromObj(cls, randomNormalAttrs):
+        x = RandomNormalAttrsT()
+        x._UnPack(randomNormalAttrs)
         return x
 
-    # GRUAttrsT
-    def _UnPack(self, gruattrs):
-        if gruattrs is None:
+    # RandomNormalAttrsT
+    def _UnPack(self, randomNormalAttrs):
+        if randomNormalAttrs is None:
             return
-        self.direction = gruattrs.Direction()
-        self.hiddenSize = gruattrs.HiddenSize()
-        self.linearBeforeReset = gruattrs.LinearBeforeReset()
+        self.mean = randomNormalAttrs.Mean()
+        self.scale = randomNormalAttrs.Scale()
+        self.seed = randomNormalAttrs.Seed()
+        if not randomNormalAttrs.ShapeIsNone():
+            if np is None:
+                self.shape = []
+                for i in range(randomNormalAttrs.ShapeLength()):
+                    self.shape.append(randomNormalAttrs.Shape(i))
+            else:
+                self.shape = randomNormalAttrs.ShapeAsNumpy()
 
-    # GRUAttrsT
+    # RandomNormalAttrsT
     def Pack(self, builder):
-        GRUAttrsStart(builder)
-        GRUAttrsAddDirection(builder, self.direction)
-        GRUAttrsAddHiddenSize(builder, self.hiddenSize)
-        GRUAttrsAddLinearBeforeReset(builder, self.linearBeforeReset)
-        gruattrs = GRUAttrsEnd(builder)
-        return gruattrs
+        if self.shape is not None:
+            if np is not None and type(self.shape) is np.ndarray:
+                shape = builder.CreateNumpyVector(self.shape)
+            else:
+                RandomNormalAttrsStartShapeVector(builder, len(self.shape))
+                for i in reversed(range(len(self.shape))):
+                    builder.PrependUint32(self.shape[i])
+                shape = builder.EndVector()
+        RandomNormalAttrsStart(builder)
+        RandomNormalAttrsAddMean(builder, self.mean)
+        RandomNormalAttrsAddScale(builder, self.scale)
+        RandomNormalAttrsAddSeed(builder, self.seed)
+        if self.shape is not None:
+            RandomNormalAttrsAddShape(builder, shape)
+        randomNormalAttrs = RandomNormalAttrsEnd(builder)
+        return randomNormalAttrs
 
 
-class HardSigmoidAttrs(object):
+class RandomNormalLikeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = HardSigmoidAttrs()
+        x = RandomNormalLikeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsHardSigmoidAttrs(cls, buf, offset=0):
+    def GetRootAsRandomNormalLikeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def HardSigmoidAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomNormalLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # HardSigmoidAttrs
+    # RandomNormalLikeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # HardSigmoidAttrs
-    def Alpha(self):
+    # RandomNormalLikeAttrs
+    def Mean(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-    # HardSigmoidAttrs
-    def Beta(self):
+    # RandomNormalLikeAttrs
+    def Scale(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-def HardSigmoidAttrsStart(builder):
-    builder.StartObject(2)
+    # RandomNormalLikeAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
 
-def HardSigmoidAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+def RandomNormalLikeAttrsStart(builder):
+    builder.StartObject(3)
 
-def HardSigmoidAttrsAddBeta(builder, beta):
-    builder.PrependFloat32Slot(1, beta, 0.0)
+def RandomNormalLikeAttrsAddMean(builder, mean):
+    builder.PrependFloat32Slot(0, mean, 0.0)
 
-def HardSigmoidAttrsEnd(builder):
+def RandomNormalLikeAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(1, scale, 0.0)
+
+def RandomNormalLikeAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
+
+def RandomNormalLikeAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class HardSigmoidAttrsT(object):
+class RandomNormalLikeAttrsT(object):
 
-    # HardSigmoidAttrsT
+    # RandomNormalLikeAttrsT
     def __init__(self):
-        self.alpha = 0.0  # type: float
-        self.beta = 0.0  # type: float
+        self.mean = 0.0  # type: float
+        self.scale = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        hardSigmoidAttrs = HardSigmoidAttrs()
-        hardSigmoidAttrs.Init(buf, pos)
-        return cls.InitFromObj(hardSigmoidAttrs)
+        randomNormalLikeAttrs = RandomNormalLikeAttrs()
+        randomNormalLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomNormalLikeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2423,78 +5278,140 @@ class HardSigmoidAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, hardSigmoidAttrs):
-        x = HardSigmoidAttrsT()
-        x._UnPack(hardSigmoidAttrs)
+    def InitFromObj(cls, randomNormalLikeAttrs):
+        x = RandomNormalLikeAttrsT()
+        x._UnPack(randomNormalLikeAttrs)
         return x
 
-    # HardSigmoidAttrsT
-    def _UnPack(self, hardSigmoidAttrs):
-        if hardSigmoidAttrs is None:
+    # RandomNormalLikeAttrsT
+    def _UnPack(self, randomNormalLikeAttrs):
+        if randomNormalLikeAttrs is None:
             return
-        self.alpha = hardSigmoidAttrs.Alpha()
-        self.beta = hardSigmoidAttrs.Beta()
+        self.mean = randomNormalLikeAttrs.Mean()
+        self.scale = randomNormalLikeAttrs.Scale()
+        self.seed = randomNormalLikeAttrs.Seed()
 
-    # HardSigmoidAttrsT
+    # RandomNormalLikeAttrsT
     def Pack(self, builder):
-        HardSigmoidAttrsStart(builder)
-        HardSigmoidAttrsAddAlpha(builder, self.alpha)
-        HardSigmoidAttrsAddBeta(builder, self.beta)
-        hardSigmoidAttrs = HardSigmoidAttrsEnd(builder)
-        return hardSigmoidAttrs
+        RandomNormalLikeAttrsStart(builder)
+        RandomNormalLikeAttrsAddMean(builder, self.mean)
+        RandomNormalLikeAttrsAddScale(builder, self.scale)
+        RandomNormalLikeAttrsAddSeed(builder, self.seed)
+        randomNormalLikeAttrs = RandomNormalLikeAttrsEnd(builder)
+        return randomNormalLikeAttrs
 
 
-class LeakyReluAttrs(object):
+class RandomUniformAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LeakyReluAttrs()
+        x = RandomUniformAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLeakyReluAttrs(cls, buf, offset=0):
+    def GetRootAsRandomUniformAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LeakyReluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomUniformAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LeakyReluAttrs
+    # RandomUniformAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LeakyReluAttrs
-    def Alpha(self):
+    # RandomUniformAttrs
+    def Shape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # RandomUniformAttrs
+    def High(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-def LeakyReluAttrsStart(builder):
-    builder.StartObject(1)
+    # RandomUniformAttrs
+    def Low(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def LeakyReluAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+    # RandomUniformAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
 
-def LeakyReluAttrsEnd(builder):
+def RandomUniformAttrsStart(builder):
+    builder.StartObject(4)
+
+def RandomUniformAttrsAddShape(builder, shape):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+
+def RandomUniformAttrsStartShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def RandomUniformAttrsAddHigh(builder, high):
+    builder.PrependFloat32Slot(1, high, 0.0)
+
+def RandomUniformAttrsAddLow(builder, low):
+    builder.PrependFloat32Slot(2, low, 0.0)
+
+def RandomUniformAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(3, seed, None)
+
+def RandomUniformAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class LeakyReluAttrsT(object):
+class RandomUniformAttrsT(object):
 
-    # LeakyReluAttrsT
+    # RandomUniformAttrsT
     def __init__(self):
-        self.alpha = 0.0  # type: float
+        self.shape = None  # type: List[int]
+        self.high = 0.0  # type: float
+        self.low = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        leakyReluAttrs = LeakyReluAttrs()
-        leakyReluAttrs.Init(buf, pos)
-        return cls.InitFromObj(leakyReluAttrs)
+        randomUniformAttrs = RandomUniformAttrs()
+        randomUniformAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomUniformAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2502,87 +5419,119 @@ class LeakyReluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, leakyReluAttrs):
-        x = LeakyReluAttrsT()
-        x._UnPack(leakyReluAttrs)
+    def InitFromObj(cls, randomUniformAttrs):
+        x = RandomUniformAttrsT()
+        x._UnPack(randomUniformAttrs)
         return x
 
-    # LeakyReluAttrsT
-    def _UnPack(self, leakyReluAttrs):
-        if leakyReluAttrs is None:
+    # RandomUniformAttrsT
+    def _UnPack(self, randomUniformAttrs):
+        if randomUniformAttrs is None:
             return
-        self.alpha = leakyReluAttrs.Alpha()
+        if not randomUniformAttrs.ShapeIsNone():
+            if np is None:
+                self.shape = []
+                for i in range(randomUniformAttrs.ShapeLength()):
+                    self.shape.append(randomUniformAttrs.Shape(i))
+            else:
+                self.shape = randomUniformAttrs.ShapeAsNumpy()
+        self.high = randomUniformAttrs.High()
+        self.low = randomUniformAttrs.Low()
+        self.seed = randomUniformAttrs.Seed()
 
-    # LeakyReluAttrsT
+    # RandomUniformAttrsT
     def Pack(self, builder):
-        LeakyReluAttrsStart(builder)
-        LeakyReluAttrsAddAlpha(builder, self.alpha)
-        leakyReluAttrs = LeakyReluAttrsEnd(builder)
-        return leakyReluAttrs
+        if self.shape is not None:
+            if np is not None and type(self.shape) is np.ndarray:
+                shape = builder.CreateNumpyVector(self.shape)
+            else:
+                RandomUniformAttrsStartShapeVector(builder, len(self.shape))
+                for i in reversed(range(len(self.shape))):
+                    builder.PrependUint32(self.shape[i])
+                shape = builder.EndVector()
+        RandomUniformAttrsStart(builder)
+        if self.shape is not None:
+            RandomUniformAttrsAddShape(builder, shape)
+        RandomUniformAttrsAddHigh(builder, self.high)
+        RandomUniformAttrsAddLow(builder, self.low)
+        RandomUniformAttrsAddSeed(builder, self.seed)
+        randomUniformAttrs = RandomUniformAttrsEnd(builder)
+        return randomUniformAttrs
 
 
-class LSTMAttrs(object):
+class RandomUniformLikeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LSTMAttrs()
+        x = RandomUniformLikeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLSTMAttrs(cls, buf, offset=0):
+    def GetRootAsRandomUniformLikeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LSTMAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomUniformLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LSTMAttrs
+    # RandomUniformLikeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LSTMAttrs
-    def Direction(self):
+    # RandomUniformLikeAttrs
+    def High(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # LSTMAttrs
-    def HiddenSize(self):
+    # RandomUniformLikeAttrs
+    def Low(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def LSTMAttrsStart(builder):
-    builder.StartObject(2)
+    # RandomUniformLikeAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
 
-def LSTMAttrsAddDirection(builder, direction):
-    builder.PrependUint8Slot(0, direction, 0)
+def RandomUniformLikeAttrsStart(builder):
+    builder.StartObject(3)
 
-def LSTMAttrsAddHiddenSize(builder, hiddenSize):
-    builder.PrependUint32Slot(1, hiddenSize, 0)
+def RandomUniformLikeAttrsAddHigh(builder, high):
+    builder.PrependFloat32Slot(0, high, 0.0)
 
-def LSTMAttrsEnd(builder):
+def RandomUniformLikeAttrsAddLow(builder, low):
+    builder.PrependFloat32Slot(1, low, 0.0)
+
+def RandomUniformLikeAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
+
+def RandomUniformLikeAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class LSTMAttrsT(object):
+class RandomUniformLikeAttrsT(object):
 
-    # LSTMAttrsT
+    # RandomUniformLikeAttrsT
     def __init__(self):
-        self.direction = 0  # type: int
-        self.hiddenSize = 0  # type: int
+        self.high = 0.0  # type: float
+        self.low = 0.0  # type: float
+        self.seed = None  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        lstmattrs = LSTMAttrs()
-        lstmattrs.Init(buf, pos)
-        return cls.InitFromObj(lstmattrs)
+        randomUniformLikeAttrs = RandomUniformLikeAttrs()
+        randomUniformLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomUniformLikeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2590,162 +5539,98 @@ class LSTMAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, lstmattrs):
-        x = LSTMAttrsT()
-        x._UnPack(lstmattrs)
+    def InitFromObj(cls, randomUniformLikeAttrs):
+        x = RandomUniformLikeAttrsT()
+        x._UnPack(randomUniformLikeAttrs)
         return x
 
-    # LSTMAttrsT
-    def _UnPack(self, lstmattrs):
-        if lstmattrs is None:
+    # RandomUniformLikeAttrsT
+    def _UnPack(self, randomUniformLikeAttrs):
+        if randomUniformLikeAttrs is None:
             return
-        self.direction = lstmattrs.Direction()
-        self.hiddenSize = lstmattrs.HiddenSize()
+        self.high = randomUniformLikeAttrs.High()
+        self.low = randomUniformLikeAttrs.Low()
+        self.seed = randomUniformLikeAttrs.Seed()
 
-    # LSTMAttrsT
+    # RandomUniformLikeAttrsT
     def Pack(self, builder):
-        LSTMAttrsStart(builder)
-        LSTMAttrsAddDirection(builder, self.direction)
-        LSTMAttrsAddHiddenSize(builder, self.hiddenSize)
-        lstmattrs = LSTMAttrsEnd(builder)
-        return lstmattrs
+        RandomUniformLikeAttrsStart(builder)
+        RandomUniformLikeAttrsAddHigh(builder, self.high)
+        RandomUniformLikeAttrsAddLow(builder, self.low)
+        RandomUniformLikeAttrsAddSeed(builder, self.seed)
+        randomUniformLikeAttrs = RandomUniformLikeAttrsEnd(builder)
+        return randomUniformLikeAttrs
 
 
-class MaxPoolAttrs(object):
+class ReduceMeanAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = MaxPoolAttrs()
+        x = ReduceMeanAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
+    def GetRootAsReduceMeanAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ReduceMeanAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # MaxPoolAttrs
+    # ReduceMeanAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # MaxPoolAttrs
-    def KernelSize(self, j):
+    # ReduceMeanAttrs
+    def Axes(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # MaxPoolAttrs
-    def KernelSizeAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # MaxPoolAttrs
-    def KernelSizeLength(self):
+    # ReduceMeanAttrs
+    def AxesAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.VectorLen(o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
         return 0
 
-    # MaxPoolAttrs
-    def KernelSizeIsNone(self):
+    # ReduceMeanAttrs
+    def AxesLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
-
-    # MaxPoolAttrs
-    def AutoPad(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # MaxPoolAttrs
-    def Pads(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # MaxPoolAttrs
-    def PadsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # MaxPoolAttrs
-    def PadsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # MaxPoolAttrs
-    def PadsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        return o == 0
-
-    # MaxPoolAttrs
-    def Strides(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # MaxPoolAttrs
-    def StridesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # MaxPoolAttrs
-    def StridesLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         if o != 0:
             return self._tab.VectorLen(o)
-        return 0
-
-    # MaxPoolAttrs
-    def StridesIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        return o == 0
-
-def MaxPoolAttrsStart(builder):
-    builder.StartObject(4)
-
-def MaxPoolAttrsAddKernelSize(builder, kernelSize):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
-
-def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+        return 0
 
-def MaxPoolAttrsAddAutoPad(builder, autoPad):
-    builder.PrependUint8Slot(1, autoPad, 0)
+    # ReduceMeanAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
 
-def MaxPoolAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+    # ReduceMeanAttrs
+    def KeepDims(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def MaxPoolAttrsStartPadsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def ReduceMeanAttrsStart(builder):
+    builder.StartObject(2)
 
-def MaxPoolAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+def ReduceMeanAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
 
-def MaxPoolAttrsStartStridesVector(builder, numElems):
+def ReduceMeanAttrsStartAxesVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
-def MaxPoolAttrsEnd(builder):
+def ReduceMeanAttrsAddKeepDims(builder, keepDims):
+    builder.PrependBoolSlot(1, keepDims, 0)
+
+def ReduceMeanAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -2754,20 +5639,18 @@ try:
 except:
     pass
 
-class MaxPoolAttrsT(object):
+class ReduceMeanAttrsT(object):
 
-    # MaxPoolAttrsT
+    # ReduceMeanAttrsT
     def __init__(self):
-        self.kernelSize = None  # type: List[int]
-        self.autoPad = 0  # type: int
-        self.pads = None  # type: List[int]
-        self.strides = None  # type: List[int]
+        self.axes = None  # type: List[int]
+        self.keepDims = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        maxPoolAttrs = MaxPoolAttrs()
-        maxPoolAttrs.Init(buf, pos)
-        return cls.InitFromObj(maxPoolAttrs)
+        reduceMeanAttrs = ReduceMeanAttrs()
+        reduceMeanAttrs.Init(buf, pos)
+        return cls.InitFromObj(reduceMeanAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2775,127 +5658,192 @@ class MaxPoolAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, maxPoolAttrs):
-        x = MaxPoolAttrsT()
-        x._UnPack(maxPoolAttrs)
+    def InitFromObj(cls, reduceMeanAttrs):
+        x = ReduceMeanAttrsT()
+        x._UnPack(reduceMeanAttrs)
         return x
 
-    # MaxPoolAttrsT
-    def _UnPack(self, maxPoolAttrs):
-        if maxPoolAttrs is None:
+    # ReduceMeanAttrsT
+    def _UnPack(self, reduceMeanAttrs):
+        if reduceMeanAttrs is None:
             return
-        if not maxPoolAttrs.KernelSizeIsNone():
-            if np is None:
-                self.kernelSize = []
-                for i in range(maxPoolAttrs.KernelSizeLength()):
-                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
-            else:
-                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
-        self.autoPad = maxPoolAttrs.AutoPad()
-        if not maxPoolAttrs.PadsIsNone():
-            if np is None:
-                self.pads = []
-                for i in range(maxPoolAttrs.PadsLength()):
-                    self.pads.append(maxPoolAttrs.Pads(i))
-            else:
-                self.pads = maxPoolAttrs.PadsAsNumpy()
-        if not maxPoolAttrs.StridesIsNone():
+        if not reduceMeanAttrs.AxesIsNone():
             if np is None:
-                self.strides = []
-                for i in range(maxPoolAttrs.StridesLength()):
-                    self.strides.append(maxPoolAttrs.Strides(i))
+                self.axes = []
+                for i in range(reduceMeanAttrs.AxesLength()):
+                    self.axes.append(reduceMeanAttrs.Axes(i))
             else:
-                self.strides = maxPoolAttrs.StridesAsNumpy()
+                self.axes = reduceMeanAttrs.AxesAsNumpy()
+        self.keepDims = reduceMeanAttrs.KeepDims()
 
-    # MaxPoolAttrsT
+    # ReduceMeanAttrsT
     def Pack(self, builder):
-        if self.kernelSize is not None:
-            if np is not None and type(self.kernelSize) is np.ndarray:
-                kernelSize = builder.CreateNumpyVector(self.kernelSize)
-            else:
-                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
-                for i in reversed(range(len(self.kernelSize))):
-                    builder.PrependUint32(self.kernelSize[i])
-                kernelSize = builder.EndVector()
-        if self.pads is not None:
-            if np is not None and type(self.pads) is np.ndarray:
-                pads = builder.CreateNumpyVector(self.pads)
-            else:
-                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
-                for i in reversed(range(len(self.pads))):
-                    builder.PrependUint32(self.pads[i])
-                pads = builder.EndVector()
-        if self.strides is not None:
-            if np is not None and type(self.strides) is np.ndarray:
-                strides = builder.CreateNumpyVector(self.strides)
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
             else:
-                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
-                for i in reversed(range(len(self.strides))):
-                    builder.PrependUint32(self.strides[i])
-                strides = builder.EndVector()
-        MaxPoolAttrsStart(builder)
-        if self.kernelSize is not None:
-            MaxPoolAttrsAddKernelSize(builder, kernelSize)
-        MaxPoolAttrsAddAutoPad(builder, self.autoPad)
-        if self.pads is not None:
-            MaxPoolAttrsAddPads(builder, pads)
-        if self.strides is not None:
-            MaxPoolAttrsAddStrides(builder, strides)
-        maxPoolAttrs = MaxPoolAttrsEnd(builder)
-        return maxPoolAttrs
+                ReduceMeanAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        ReduceMeanAttrsStart(builder)
+        if self.axes is not None:
+            ReduceMeanAttrsAddAxes(builder, axes)
+        ReduceMeanAttrsAddKeepDims(builder, self.keepDims)
+        reduceMeanAttrs = ReduceMeanAttrsEnd(builder)
+        return reduceMeanAttrs
 
 
-class ModAttrs(object):
+class ReshapeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ModAttrs()
+        x = ReshapeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsModAttrs(cls, buf, offset=0):
+    def GetRootAsReshapeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ModAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ReshapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ModAttrs
+    # ReshapeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ModAttrs
-    def Fmod(self):
+    # ReshapeAttrs
+    def AllowZero(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
-def ModAttrsStart(builder):
+def ReshapeAttrsStart(builder):
     builder.StartObject(1)
 
-def ModAttrsAddFmod(builder, fmod):
-    builder.PrependBoolSlot(0, fmod, 0)
+def ReshapeAttrsAddAllowZero(builder, allowZero):
+    builder.PrependBoolSlot(0, allowZero, 0)
 
-def ModAttrsEnd(builder):
+def ReshapeAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ModAttrsT(object):
+class ReshapeAttrsT(object):
 
-    # ModAttrsT
+    # ReshapeAttrsT
     def __init__(self):
-        self.fmod = False  # type: bool
+        self.allowZero = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        reshapeAttrs = ReshapeAttrs()
+        reshapeAttrs.Init(buf, pos)
+        return cls.InitFromObj(reshapeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, reshapeAttrs):
+        x = ReshapeAttrsT()
+        x._UnPack(reshapeAttrs)
+        return x
+
+    # ReshapeAttrsT
+    def _UnPack(self, reshapeAttrs):
+        if reshapeAttrs is None:
+            return
+        self.allowZero = reshapeAttrs.AllowZero()
+
+    # ReshapeAttrsT
+    def Pack(self, builder):
+        ReshapeAttrsStart(builder)
+        ReshapeAttrsAddAllowZero(builder, self.allowZero)
+        reshapeAttrs = ReshapeAttrsEnd(builder)
+        return reshapeAttrs
+
+
+class ResizeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ResizeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsResizeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ResizeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ResizeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ResizeAttrs
+    def Mode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ResizeAttrs
+    def CoordMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ResizeAttrs
+    def NearestMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def ResizeAttrsStart(builder):
+    builder.StartObject(3)
+
+def ResizeAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def ResizeAttrsAddCoordMode(builder, coordMode):
+    builder.PrependUint8Slot(1, coordMode, 0)
+
+def ResizeAttrsAddNearestMode(builder, nearestMode):
+    builder.PrependUint8Slot(2, nearestMode, 0)
+
+def ResizeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class ResizeAttrsT(object):
+
+    # ResizeAttrsT
+    def __init__(self):
+        self.mode = 0  # type: int
+        self.coordMode = 0  # type: int
+        self.nearestMode = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        modAttrs = ModAttrs()
-        modAttrs.Init(buf, pos)
-        return cls.InitFromObj(modAttrs)
+        resizeAttrs = ResizeAttrs()
+        resizeAttrs.Init(buf, pos)
+        return cls.InitFromObj(resizeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2903,76 +5851,91 @@ class ModAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, modAttrs):
-        x = ModAttrsT()
-        x._UnPack(modAttrs)
+    def InitFromObj(cls, resizeAttrs):
+        x = ResizeAttrsT()
+        x._UnPack(resizeAttrs)
         return x
 
-    # ModAttrsT
-    def _UnPack(self, modAttrs):
-        if modAttrs is None:
+    # ResizeAttrsT
+    def _UnPack(self, resizeAttrs):
+        if resizeAttrs is None:
             return
-        self.fmod = modAttrs.Fmod()
+        self.mode = resizeAttrs.Mode()
+        self.coordMode = resizeAttrs.CoordMode()
+        self.nearestMode = resizeAttrs.NearestMode()
 
-    # ModAttrsT
+    # ResizeAttrsT
     def Pack(self, builder):
-        ModAttrsStart(builder)
-        ModAttrsAddFmod(builder, self.fmod)
-        modAttrs = ModAttrsEnd(builder)
-        return modAttrs
+        ResizeAttrsStart(builder)
+        ResizeAttrsAddMode(builder, self.mode)
+        ResizeAttrsAddCoordMode(builder, self.coordMode)
+        ResizeAttrsAddNearestMode(builder, self.nearestMode)
+        resizeAttrs = ResizeAttrsEnd(builder)
+        return resizeAttrs
 
 
-class NonMaxSuppressionAttrs(object):
+class ReverseSequenceAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = NonMaxSuppressionAttrs()
+        x = ReverseSequenceAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsNonMaxSuppressionAttrs(cls, buf, offset=0):
+    def GetRootAsReverseSequenceAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def NonMaxSuppressionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ReverseSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # NonMaxSuppressionAttrs
+    # ReverseSequenceAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # NonMaxSuppressionAttrs
-    def BoxOrder(self):
+    # ReverseSequenceAttrs
+    def BatchAxis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 1
+
+    # ReverseSequenceAttrs
+    def TimeAxis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return 0
 
-def NonMaxSuppressionAttrsStart(builder):
-    builder.StartObject(1)
+def ReverseSequenceAttrsStart(builder):
+    builder.StartObject(2)
 
-def NonMaxSuppressionAttrsAddBoxOrder(builder, boxOrder):
-    builder.PrependUint8Slot(0, boxOrder, 0)
+def ReverseSequenceAttrsAddBatchAxis(builder, batchAxis):
+    builder.PrependUint32Slot(0, batchAxis, 1)
 
-def NonMaxSuppressionAttrsEnd(builder):
+def ReverseSequenceAttrsAddTimeAxis(builder, timeAxis):
+    builder.PrependUint32Slot(1, timeAxis, 0)
+
+def ReverseSequenceAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class NonMaxSuppressionAttrsT(object):
+class ReverseSequenceAttrsT(object):
 
-    # NonMaxSuppressionAttrsT
+    # ReverseSequenceAttrsT
     def __init__(self):
-        self.boxOrder = 0  # type: int
+        self.batchAxis = 1  # type: int
+        self.timeAxis = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        nonMaxSuppressionAttrs = NonMaxSuppressionAttrs()
-        nonMaxSuppressionAttrs.Init(buf, pos)
-        return cls.InitFromObj(nonMaxSuppressionAttrs)
+        reverseSequenceAttrs = ReverseSequenceAttrs()
+        reverseSequenceAttrs.Init(buf, pos)
+        return cls.InitFromObj(reverseSequenceAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2980,76 +5943,146 @@ class NonMaxSuppressionAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, nonMaxSuppressionAttrs):
-        x = NonMaxSuppressionAttrsT()
-        x._UnPack(nonMaxSuppressionAttrs)
+    def InitFromObj(cls, reverseSequenceAttrs):
+        x = ReverseSequenceAttrsT()
+        x._UnPack(reverseSequenceAttrs)
         return x
 
-    # NonMaxSuppressionAttrsT
-    def _UnPack(self, nonMaxSuppressionAttrs):
-        if nonMaxSuppressionAttrs is None:
+    # ReverseSequenceAttrsT
+    def _UnPack(self, reverseSequenceAttrs):
+        if reverseSequenceAttrs is None:
             return
-        self.boxOrder = nonMaxSuppressionAttrs.BoxOrder()
+        self.batchAxis = reverseSequenceAttrs.BatchAxis()
+        self.timeAxis = reverseSequenceAttrs.TimeAxis()
 
-    # NonMaxSuppressionAttrsT
+    # ReverseSequenceAttrsT
     def Pack(self, builder):
-        NonMaxSuppressionAttrsStart(builder)
-        NonMaxSuppressionAttrsAddBoxOrder(builder, self.boxOrder)
-        nonMaxSuppressionAttrs = NonMaxSuppressionAttrsEnd(builder)
-        return nonMaxSuppressionAttrs
+        ReverseSequenceAttrsStart(builder)
+        ReverseSequenceAttrsAddBatchAxis(builder, self.batchAxis)
+        ReverseSequenceAttrsAddTimeAxis(builder, self.timeAxis)
+        reverseSequenceAttrs = ReverseSequenceAttrsEnd(builder)
+        return reverseSequenceAttrs
 
 
-class OneHotAttrs(object):
+class RNNAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = OneHotAttrs()
+        x = RNNAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsOneHotAttrs(cls, buf, offset=0):
+    def GetRootAsRNNAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def OneHotAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RNNAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # OneHotAttrs
+    # RNNAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # OneHotAttrs
-    def Axis(self):
+    # RNNAttrs
+    def Direction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-def OneHotAttrsStart(builder):
-    builder.StartObject(1)
+    # RNNAttrs
+    def HiddenSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def OneHotAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+    # RNNAttrs
+    def Activations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = RNNActivationFn()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
 
-def OneHotAttrsEnd(builder):
+    # RNNAttrs
+    def ActivationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # RNNAttrs
+    def ActivationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # RNNAttrs
+    def Clip(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # RNNAttrs
+    def BatchFirst(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def RNNAttrsStart(builder):
+    builder.StartObject(5)
+
+def RNNAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def RNNAttrsAddHiddenSize(builder, hiddenSize):
+    builder.PrependUint32Slot(1, hiddenSize, 0)
+
+def RNNAttrsAddActivations(builder, activations):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(activations), 0)
+
+def RNNAttrsStartActivationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def RNNAttrsAddClip(builder, clip):
+    builder.PrependFloat32Slot(3, clip, None)
+
+def RNNAttrsAddBatchFirst(builder, batchFirst):
+    builder.PrependBoolSlot(4, batchFirst, 0)
+
+def RNNAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class OneHotAttrsT(object):
+class RNNAttrsT(object):
 
-    # OneHotAttrsT
+    # RNNAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
+        self.direction = 0  # type: int
+        self.hiddenSize = 0  # type: int
+        self.activations = None  # type: List[RNNActivationFnT]
+        self.clip = None  # type: Optional[float]
+        self.batchFirst = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        oneHotAttrs = OneHotAttrs()
-        oneHotAttrs.Init(buf, pos)
-        return cls.InitFromObj(oneHotAttrs)
+        rnnattrs = RNNAttrs()
+        rnnattrs.Init(buf, pos)
+        return cls.InitFromObj(rnnattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3057,136 +6090,122 @@ class OneHotAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, oneHotAttrs):
-        x = OneHotAttrsT()
-        x._UnPack(oneHotAttrs)
+    def InitFromObj(cls, rnnattrs):
+        x = RNNAttrsT()
+        x._UnPack(rnnattrs)
         return x
 
-    # OneHotAttrsT
-    def _UnPack(self, oneHotAttrs):
-        if oneHotAttrs is None:
+    # RNNAttrsT
+    def _UnPack(self, rnnattrs):
+        if rnnattrs is None:
             return
-        self.axis = oneHotAttrs.Axis()
+        self.direction = rnnattrs.Direction()
+        self.hiddenSize = rnnattrs.HiddenSize()
+        if not rnnattrs.ActivationsIsNone():
+            self.activations = []
+            for i in range(rnnattrs.ActivationsLength()):
+                if rnnattrs.Activations(i) is None:
+                    self.activations.append(None)
+                else:
+                    rNNActivationFn_ = RNNActivationFnT.InitFromObj(rnnattrs.Activations(i))
+                    self.activations.append(rNNActivationFn_)
+        self.clip = rnnattrs.Clip()
+        self.batchFirst = rnnattrs.BatchFirst()
 
-    # OneHotAttrsT
+    # RNNAttrsT
     def Pack(self, builder):
-        OneHotAttrsStart(builder)
-        OneHotAttrsAddAxis(builder, self.axis)
-        oneHotAttrs = OneHotAttrsEnd(builder)
-        return oneHotAttrs
-
-
-class RandomNormalAttrs(object):
+        if self.activations is not None:
+            activationslist = []
+            for i in range(len(self.activations)):
+                activationslist.append(self.activations[i].Pack(builder))
+            RNNAttrsStartActivationsVector(builder, len(self.activations))
+            for i in reversed(range(len(self.activations))):
+                builder.PrependUOffsetTRelative(activationslist[i])
+            activations = builder.EndVector()
+        RNNAttrsStart(builder)
+        RNNAttrsAddDirection(builder, self.direction)
+        RNNAttrsAddHiddenSize(builder, self.hiddenSize)
+        if self.activations is not None:
+            RNNAttrsAddActivations(builder, activations)
+        RNNAttrsAddClip(builder, self.clip)
+        RNNAttrsAddBatchFirst(builder, self.batchFirst)
+        rnnattrs = RNNAttrsEnd(builder)
+        return rnnattrs
+
+
+class RNNActivationFn(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomNormalAttrs()
+        x = RNNActivationFn()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomNormalAttrs(cls, buf, offset=0):
+    def GetRootAsRNNActivationFn(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomNormalAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RNNActivationFnBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomNormalAttrs
+    # RNNActivationFn
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomNormalAttrs
-    def Mean(self):
+    # RNNActivationFn
+    def Kind(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-    # RandomNormalAttrs
-    def Scale(self):
+    # RNNActivationFn
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-    # RandomNormalAttrs
-    def Seed(self):
+    # RNNActivationFn
+    def Beta(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
-
-    # RandomNormalAttrs
-    def Shape(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # RandomNormalAttrs
-    def ShapeAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # RandomNormalAttrs
-    def ShapeLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # RandomNormalAttrs
-    def ShapeIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        return o == 0
-
-def RandomNormalAttrsStart(builder):
-    builder.StartObject(4)
-
-def RandomNormalAttrsAddMean(builder, mean):
-    builder.PrependFloat32Slot(0, mean, 0.0)
+        return 0.0
 
-def RandomNormalAttrsAddScale(builder, scale):
-    builder.PrependFloat32Slot(1, scale, 0.0)
+def RNNActivationFnStart(builder):
+    builder.StartObject(3)
 
-def RandomNormalAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+def RNNActivationFnAddKind(builder, kind):
+    builder.PrependUint8Slot(0, kind, 0)
 
-def RandomNormalAttrsAddShape(builder, shape):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+def RNNActivationFnAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(1, alpha, 0.0)
 
-def RandomNormalAttrsStartShapeVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def RNNActivationFnAddBeta(builder, beta):
+    builder.PrependFloat32Slot(2, beta, 0.0)
 
-def RandomNormalAttrsEnd(builder):
+def RNNActivationFnEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import List
-except:
-    pass
 
-class RandomNormalAttrsT(object):
+class RNNActivationFnT(object):
 
-    # RandomNormalAttrsT
+    # RNNActivationFnT
     def __init__(self):
-        self.mean = 0.0  # type: float
-        self.scale = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
-        self.shape = None  # type: List[int]
+        self.kind = 0  # type: int
+        self.alpha = 0.0  # type: float
+        self.beta = 0.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomNormalAttrs = RandomNormalAttrs()
-        randomNormalAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomNormalAttrs)
+        rnnactivationFn = RNNActivationFn()
+        rnnactivationFn.Init(buf, pos)
+        return cls.InitFromObj(rnnactivationFn)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3194,119 +6213,135 @@ class RandomNormalAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomNormalAttrs):
-        x = RandomNormalAttrsT()
-        x._UnPack(randomNormalAttrs)
-        return x
-
-    # RandomNormalAttrsT
-    def _UnPack(self, randomNormalAttrs):
-        if randomNormalAttrs is None:
-            return
-        self.mean = randomNormalAttrs.Mean()
-        self.scale = randomNormalAttrs.Scale()
-        self.seed = randomNormalAttrs.Seed()
-        if not randomNormalAttrs.ShapeIsNone():
-            if np is None:
-                self.shape = []
-                for i in range(randomNormalAttrs.ShapeLength()):
-                    self.shape.append(randomNormalAttrs.Shape(i))
-            else:
-                self.shape = randomNormalAttrs.ShapeAsNumpy()
+    def InitFromObj(cls, rnnactivationFn):
+        x = RNNActivationFnT()
+        x._UnPack(rnnactivationFn)
+        return x
 
-    # RandomNormalAttrsT
+    # RNNActivationFnT
+    def _UnPack(self, rnnactivationFn):
+        if rnnactivationFn is None:
+            return
+        self.kind = rnnactivationFn.Kind()
+        self.alpha = rnnactivationFn.Alpha()
+        self.beta = rnnactivationFn.Beta()
+
+    # RNNActivationFnT
     def Pack(self, builder):
-        if self.shape is not None:
-            if np is not None and type(self.shape) is np.ndarray:
-                shape = builder.CreateNumpyVector(self.shape)
-            else:
-                RandomNormalAttrsStartShapeVector(builder, len(self.shape))
-                for i in reversed(range(len(self.shape))):
-                    builder.PrependUint32(self.shape[i])
-                shape = builder.EndVector()
-        RandomNormalAttrsStart(builder)
-        RandomNormalAttrsAddMean(builder, self.mean)
-        RandomNormalAttrsAddScale(builder, self.scale)
-        RandomNormalAttrsAddSeed(builder, self.seed)
-        if self.shape is not None:
-            RandomNormalAttrsAddShape(builder, shape)
-        randomNormalAttrs = RandomNormalAttrsEnd(builder)
-        return randomNormalAttrs
+        RNNActivationFnStart(builder)
+        RNNActivationFnAddKind(builder, self.kind)
+        RNNActivationFnAddAlpha(builder, self.alpha)
+        RNNActivationFnAddBeta(builder, self.beta)
+        rnnactivationFn = RNNActivationFnEnd(builder)
+        return rnnactivationFn
 
 
-class RandomNormalLikeAttrs(object):
+class RoiAlignAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomNormalLikeAttrs()
+        x = RoiAlignAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomNormalLikeAttrs(cls, buf, offset=0):
+    def GetRootAsRoiAlignAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomNormalLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RoiAlignAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomNormalLikeAttrs
+    # RoiAlignAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomNormalLikeAttrs
-    def Mean(self):
+    # RoiAlignAttrs
+    def Mode(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-    # RandomNormalLikeAttrs
-    def Scale(self):
+    # RoiAlignAttrs
+    def CoordMode(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-    # RandomNormalLikeAttrs
-    def Seed(self):
+    # RoiAlignAttrs
+    def OutputHeight(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # RoiAlignAttrs
+    def OutputWidth(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # RoiAlignAttrs
+    def SamplingRatio(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # RoiAlignAttrs
+    def SpatialScale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
+        return 1.0
 
-def RandomNormalLikeAttrsStart(builder):
-    builder.StartObject(3)
+def RoiAlignAttrsStart(builder):
+    builder.StartObject(6)
 
-def RandomNormalLikeAttrsAddMean(builder, mean):
-    builder.PrependFloat32Slot(0, mean, 0.0)
+def RoiAlignAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
 
-def RandomNormalLikeAttrsAddScale(builder, scale):
-    builder.PrependFloat32Slot(1, scale, 0.0)
+def RoiAlignAttrsAddCoordMode(builder, coordMode):
+    builder.PrependUint8Slot(1, coordMode, 0)
 
-def RandomNormalLikeAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+def RoiAlignAttrsAddOutputHeight(builder, outputHeight):
+    builder.PrependUint32Slot(2, outputHeight, 0)
 
-def RandomNormalLikeAttrsEnd(builder):
+def RoiAlignAttrsAddOutputWidth(builder, outputWidth):
+    builder.PrependUint32Slot(3, outputWidth, 0)
+
+def RoiAlignAttrsAddSamplingRatio(builder, samplingRatio):
+    builder.PrependUint32Slot(4, samplingRatio, 0)
+
+def RoiAlignAttrsAddSpatialScale(builder, spatialScale):
+    builder.PrependFloat32Slot(5, spatialScale, 1.0)
+
+def RoiAlignAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class RandomNormalLikeAttrsT(object):
+class RoiAlignAttrsT(object):
 
-    # RandomNormalLikeAttrsT
+    # RoiAlignAttrsT
     def __init__(self):
-        self.mean = 0.0  # type: float
-        self.scale = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
+        self.mode = 0  # type: int
+        self.coordMode = 0  # type: int
+        self.outputHeight = 0  # type: int
+        self.outputWidth = 0  # type: int
+        self.samplingRatio = 0  # type: int
+        self.spatialScale = 1.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomNormalLikeAttrs = RandomNormalLikeAttrs()
-        randomNormalLikeAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomNormalLikeAttrs)
+        roiAlignAttrs = RoiAlignAttrs()
+        roiAlignAttrs.Init(buf, pos)
+        return cls.InitFromObj(roiAlignAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3314,140 +6349,108 @@ class RandomNormalLikeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomNormalLikeAttrs):
-        x = RandomNormalLikeAttrsT()
-        x._UnPack(randomNormalLikeAttrs)
+    def InitFromObj(cls, roiAlignAttrs):
+        x = RoiAlignAttrsT()
+        x._UnPack(roiAlignAttrs)
         return x
 
-    # RandomNormalLikeAttrsT
-    def _UnPack(self, randomNormalLikeAttrs):
-        if randomNormalLikeAttrs is None:
+    # RoiAlignAttrsT
+    def _UnPack(self, roiAlignAttrs):
+        if roiAlignAttrs is None:
             return
-        self.mean = randomNormalLikeAttrs.Mean()
-        self.scale = randomNormalLikeAttrs.Scale()
-        self.seed = randomNormalLikeAttrs.Seed()
-
-    # RandomNormalLikeAttrsT
+        self.mode = roiAlignAttrs.Mode()
+        self.coordMode = roiAlignAttrs.CoordMode()
+        self.outputHeight = roiAlignAttrs.OutputHeight()
+        self.outputWidth = roiAlignAttrs.OutputWidth()
+        self.samplingRatio = roiAlignAttrs.SamplingRatio()
+        self.spatialScale = roiAlignAttrs.SpatialScale()
+
+    # RoiAlignAttrsT
     def Pack(self, builder):
-        RandomNormalLikeAttrsStart(builder)
-        RandomNormalLikeAttrsAddMean(builder, self.mean)
-        RandomNormalLikeAttrsAddScale(builder, self.scale)
-        RandomNormalLikeAttrsAddSeed(builder, self.seed)
-        randomNormalLikeAttrs = RandomNormalLikeAttrsEnd(builder)
-        return randomNormalLikeAttrs
-
-
-class RandomUniformAttrs(object):
+        RoiAlignAttrsStart(builder)
+        RoiAlignAttrsAddMode(builder, self.mode)
+        RoiAlignAttrsAddCoordMode(builder, self.coordMode)
+        RoiAlignAttrsAddOutputHeight(builder, self.outputHeight)
+        RoiAlignAttrsAddOutputWidth(builder, self.outputWidth)
+        RoiAlignAttrsAddSamplingRatio(builder, self.samplingRatio)
+        RoiAlignAttrsAddSpatialScale(builder, self.spatialScale)
+        roiAlignAttrs = RoiAlignAttrsEnd(builder)
+        return roiAlignAttrs
+
+
+class RotaryEmbeddingAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomUniformAttrs()
+        x = RotaryEmbeddingAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomUniformAttrs(cls, buf, offset=0):
+    def GetRootAsRotaryEmbeddingAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomUniformAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RotaryEmbeddingAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomUniformAttrs
+    # RotaryEmbeddingAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomUniformAttrs
-    def Shape(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # RandomUniformAttrs
-    def ShapeAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # RandomUniformAttrs
-    def ShapeLength(self):
+    # RotaryEmbeddingAttrs
+    def Interleaved(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # RandomUniformAttrs
-    def ShapeIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-    # RandomUniformAttrs
-    def High(self):
+    # RotaryEmbeddingAttrs
+    def NumHeads(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-    # RandomUniformAttrs
-    def Low(self):
+    # RotaryEmbeddingAttrs
+    def RotaryEmbeddingDim(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomUniformAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
-
-def RandomUniformAttrsStart(builder):
-    builder.StartObject(4)
-
-def RandomUniformAttrsAddShape(builder, shape):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def RandomUniformAttrsStartShapeVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def RotaryEmbeddingAttrsStart(builder):
+    builder.StartObject(3)
 
-def RandomUniformAttrsAddHigh(builder, high):
-    builder.PrependFloat32Slot(1, high, 0.0)
+def RotaryEmbeddingAttrsAddInterleaved(builder, interleaved):
+    builder.PrependBoolSlot(0, interleaved, 0)
 
-def RandomUniformAttrsAddLow(builder, low):
-    builder.PrependFloat32Slot(2, low, 0.0)
+def RotaryEmbeddingAttrsAddNumHeads(builder, numHeads):
+    builder.PrependUint32Slot(1, numHeads, 0)
 
-def RandomUniformAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(3, seed, None)
+def RotaryEmbeddingAttrsAddRotaryEmbeddingDim(builder, rotaryEmbeddingDim):
+    builder.PrependUint32Slot(2, rotaryEmbeddingDim, 0)
 
-def RandomUniformAttrsEnd(builder):
+def RotaryEmbeddingAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import List
-except:
-    pass
 
-class RandomUniformAttrsT(object):
+class RotaryEmbeddingAttrsT(object):
 
-    # RandomUniformAttrsT
+    # RotaryEmbeddingAttrsT
     def __init__(self):
-        self.shape = None  # type: List[int]
-        self.high = 0.0  # type: float
-        self.low = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
+        self.interleaved = False  # type: bool
+        self.numHeads = 0  # type: int
+        self.rotaryEmbeddingDim = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomUniformAttrs = RandomUniformAttrs()
-        randomUniformAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomUniformAttrs)
+        rotaryEmbeddingAttrs = RotaryEmbeddingAttrs()
+        rotaryEmbeddingAttrs.Init(buf, pos)
+        return cls.InitFromObj(rotaryEmbeddingAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3455,119 +6458,91 @@ class RandomUniformAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomUniformAttrs):
-        x = RandomUniformAttrsT()
-        x._UnPack(randomUniformAttrs)
+    def InitFromObj(cls, rotaryEmbeddingAttrs):
+        x = RotaryEmbeddingAttrsT()
+        x._UnPack(rotaryEmbeddingAttrs)
         return x
 
-    # RandomUniformAttrsT
-    def _UnPack(self, randomUniformAttrs):
-        if randomUniformAttrs is None:
+    # RotaryEmbeddingAttrsT
+    def _UnPack(self, rotaryEmbeddingAttrs):
+        if rotaryEmbeddingAttrs is None:
             return
-        if not randomUniformAttrs.ShapeIsNone():
-            if np is None:
-                self.shape = []
-                for i in range(randomUniformAttrs.ShapeLength()):
-                    self.shape.append(randomUniformAttrs.Shape(i))
-            else:
-                self.shape = randomUniformAttrs.ShapeAsNumpy()
-        self.high = randomUniformAttrs.High()
-        self.low = randomUniformAttrs.Low()
-        self.seed = randomUniformAttrs.Seed()
+        self.interleaved = rotaryEmbeddingAttrs.Interleaved()
+        self.numHeads = rotaryEmbeddingAttrs.NumHeads()
+        self.rotaryEmbeddingDim = rotaryEmbeddingAttrs.RotaryEmbeddingDim()
 
-    # RandomUniformAttrsT
+    # RotaryEmbeddingAttrsT
     def Pack(self, builder):
-        if self.shape is not None:
-            if np is not None and type(self.shape) is np.ndarray:
-                shape = builder.CreateNumpyVector(self.shape)
-            else:
-                RandomUniformAttrsStartShapeVector(builder, len(self.shape))
-                for i in reversed(range(len(self.shape))):
-                    builder.PrependUint32(self.shape[i])
-                shape = builder.EndVector()
-        RandomUniformAttrsStart(builder)
-        if self.shape is not None:
-            RandomUniformAttrsAddShape(builder, shape)
-        RandomUniformAttrsAddHigh(builder, self.high)
-        RandomUniformAttrsAddLow(builder, self.low)
-        RandomUniformAttrsAddSeed(builder, self.seed)
-        randomUniformAttrs = RandomUniformAttrsEnd(builder)
-        return randomUniformAttrs
+        RotaryEmbeddingAttrsStart(builder)
+        RotaryEmbeddingAttrsAddInterleaved(builder, self.interleaved)
+        RotaryEmbeddingAttrsAddNumHeads(builder, self.numHeads)
+        RotaryEmbeddingAttrsAddRotaryEmbeddingDim(builder, self.rotaryEmbeddingDim)
+        rotaryEmbeddingAttrs = RotaryEmbeddingAttrsEnd(builder)
+        return rotaryEmbeddingAttrs
 
 
-class RandomUniformLikeAttrs(object):
+class ScatterElementsAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomUniformLikeAttrs()
+        x = ScatterElementsAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomUniformLikeAttrs(cls, buf, offset=0):
+    def GetRootAsScatterElementsAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomUniformLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ScatterElementsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomUniformLikeAttrs
+    # ScatterElementsAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomUniformLikeAttrs
-    def High(self):
+    # ScatterElementsAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-    # RandomUniformLikeAttrs
-    def Low(self):
+    # ScatterElementsAttrs
+    def Reduction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomUniformLikeAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
-
-def RandomUniformLikeAttrsStart(builder):
-    builder.StartObject(3)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def RandomUniformLikeAttrsAddHigh(builder, high):
-    builder.PrependFloat32Slot(0, high, 0.0)
+def ScatterElementsAttrsStart(builder):
+    builder.StartObject(2)
 
-def RandomUniformLikeAttrsAddLow(builder, low):
-    builder.PrependFloat32Slot(1, low, 0.0)
+def ScatterElementsAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def RandomUniformLikeAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+def ScatterElementsAttrsAddReduction(builder, reduction):
+    builder.PrependUint8Slot(1, reduction, 0)
 
-def RandomUniformLikeAttrsEnd(builder):
+def ScatterElementsAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class RandomUniformLikeAttrsT(object):
+class ScatterElementsAttrsT(object):
 
-    # RandomUniformLikeAttrsT
+    # ScatterElementsAttrsT
     def __init__(self):
-        self.high = 0.0  # type: float
-        self.low = 0.0  # type: float
-        self.seed = None  # type: Optional[float]
+        self.axis = 0  # type: int
+        self.reduction = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomUniformLikeAttrs = RandomUniformLikeAttrs()
-        randomUniformLikeAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomUniformLikeAttrs)
+        scatterElementsAttrs = ScatterElementsAttrs()
+        scatterElementsAttrs.Init(buf, pos)
+        return cls.InitFromObj(scatterElementsAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3575,118 +6550,78 @@ class RandomUniformLikeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomUniformLikeAttrs):
-        x = RandomUniformLikeAttrsT()
-        x._UnPack(randomUniformLikeAttrs)
+    def InitFromObj(cls, scatterElementsAttrs):
+        x = ScatterElementsAttrsT()
+        x._UnPack(scatterElementsAttrs)
         return x
 
-    # RandomUniformLikeAttrsT
-    def _UnPack(self, randomUniformLikeAttrs):
-        if randomUniformLikeAttrs is None:
+    # ScatterElementsAttrsT
+    def _UnPack(self, scatterElementsAttrs):
+        if scatterElementsAttrs is None:
             return
-        self.high = randomUniformLikeAttrs.High()
-        self.low = randomUniformLikeAttrs.Low()
-        self.seed = randomUniformLikeAttrs.Seed()
+        self.axis = scatterElementsAttrs.Axis()
+        self.reduction = scatterElementsAttrs.Reduction()
 
-    # RandomUniformLikeAttrsT
+    # ScatterElementsAttrsT
     def Pack(self, builder):
-        RandomUniformLikeAttrsStart(builder)
-        RandomUniformLikeAttrsAddHigh(builder, self.high)
-        RandomUniformLikeAttrsAddLow(builder, self.low)
-        RandomUniformLikeAttrsAddSeed(builder, self.seed)
-        randomUniformLikeAttrs = RandomUniformLikeAttrsEnd(builder)
-        return randomUniformLikeAttrs
+        ScatterElementsAttrsStart(builder)
+        ScatterElementsAttrsAddAxis(builder, self.axis)
+        ScatterElementsAttrsAddReduction(builder, self.reduction)
+        scatterElementsAttrs = ScatterElementsAttrsEnd(builder)
+        return scatterElementsAttrs
 
 
-class ReduceMeanAttrs(object):
+class ScatterNDAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ReduceMeanAttrs()
+        x = ScatterNDAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsReduceMeanAttrs(cls, buf, offset=0):
+    def GetRootAsScatterNDAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ReduceMeanAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ScatterNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ReduceMeanAttrs
+    # ScatterNDAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ReduceMeanAttrs
-    def Axes(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesLength(self):
+    # ScatterNDAttrs
+    def Reduction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.VectorLen(o)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # ReduceMeanAttrs
-    def AxesIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
-
-    # ReduceMeanAttrs
-    def KeepDims(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-def ReduceMeanAttrsStart(builder):
-    builder.StartObject(2)
-
-def ReduceMeanAttrsAddAxes(builder, axes):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
-
-def ReduceMeanAttrsStartAxesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def ScatterNDAttrsStart(builder):
+    builder.StartObject(1)
 
-def ReduceMeanAttrsAddKeepDims(builder, keepDims):
-    builder.PrependBoolSlot(1, keepDims, 0)
+def ScatterNDAttrsAddReduction(builder, reduction):
+    builder.PrependUint8Slot(0, reduction, 0)
 
-def ReduceMeanAttrsEnd(builder):
+def ScatterNDAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import List
-except:
-    pass
 
-class ReduceMeanAttrsT(object):
+class ScatterNDAttrsT(object):
 
-    # ReduceMeanAttrsT
+    # ScatterNDAttrsT
     def __init__(self):
-        self.axes = None  # type: List[int]
-        self.keepDims = False  # type: bool
+        self.reduction = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        reduceMeanAttrs = ReduceMeanAttrs()
-        reduceMeanAttrs.Init(buf, pos)
-        return cls.InitFromObj(reduceMeanAttrs)
+        scatterNdattrs = ScatterNDAttrs()
+        scatterNdattrs.Init(buf, pos)
+        return cls.InitFromObj(scatterNdattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3694,93 +6629,87 @@ class ReduceMeanAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, reduceMeanAttrs):
-        x = ReduceMeanAttrsT()
-        x._UnPack(reduceMeanAttrs)
+    def InitFromObj(cls, scatterNdattrs):
+        x = ScatterNDAttrsT()
+        x._UnPack(scatterNdattrs)
         return x
 
-    # ReduceMeanAttrsT
-    def _UnPack(self, reduceMeanAttrs):
-        if reduceMeanAttrs is None:
+    # ScatterNDAttrsT
+    def _UnPack(self, scatterNdattrs):
+        if scatterNdattrs is None:
             return
-        if not reduceMeanAttrs.AxesIsNone():
-            if np is None:
-                self.axes = []
-                for i in range(reduceMeanAttrs.AxesLength()):
-                    self.axes.append(reduceMeanAttrs.Axes(i))
-            else:
-                self.axes = reduceMeanAttrs.AxesAsNumpy()
-        self.keepDims = reduceMeanAttrs.KeepDims()
+        self.reduction = scatterNdattrs.Reduction()
 
-    # ReduceMeanAttrsT
+    # ScatterNDAttrsT
     def Pack(self, builder):
-        if self.axes is not None:
-            if np is not None and type(self.axes) is np.ndarray:
-                axes = builder.CreateNumpyVector(self.axes)
-            else:
-                ReduceMeanAttrsStartAxesVector(builder, len(self.axes))
-                for i in reversed(range(len(self.axes))):
-                    builder.PrependInt32(self.axes[i])
-                axes = builder.EndVector()
-        ReduceMeanAttrsStart(builder)
-        if self.axes is not None:
-            ReduceMeanAttrsAddAxes(builder, axes)
-        ReduceMeanAttrsAddKeepDims(builder, self.keepDims)
-        reduceMeanAttrs = ReduceMeanAttrsEnd(builder)
-        return reduceMeanAttrs
+        ScatterNDAttrsStart(builder)
+        ScatterNDAttrsAddReduction(builder, self.reduction)
+        scatterNdattrs = ScatterNDAttrsEnd(builder)
+        return scatterNdattrs
 
 
-class ReshapeAttrs(object):
+class SeluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ReshapeAttrs()
+        x = SeluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsReshapeAttrs(cls, buf, offset=0):
+    def GetRootAsSeluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ReshapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ReshapeAttrs
+    # SeluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ReshapeAttrs
-    def AllowZero(self):
+    # SeluAttrs
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def ReshapeAttrsStart(builder):
-    builder.StartObject(1)
+    # SeluAttrs
+    def Gamma(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def ReshapeAttrsAddAllowZero(builder, allowZero):
-    builder.PrependBoolSlot(0, allowZero, 0)
+def SeluAttrsStart(builder):
+    builder.StartObject(2)
 
-def ReshapeAttrsEnd(builder):
+def SeluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def SeluAttrsAddGamma(builder, gamma):
+    builder.PrependFloat32Slot(1, gamma, 0.0)
+
+def SeluAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ReshapeAttrsT(object):
+class SeluAttrsT(object):
 
-    # ReshapeAttrsT
+    # SeluAttrsT
     def __init__(self):
-        self.allowZero = False  # type: bool
+        self.alpha = 0.0  # type: float
+        self.gamma = 0.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        reshapeAttrs = ReshapeAttrs()
-        reshapeAttrs.Init(buf, pos)
-        return cls.InitFromObj(reshapeAttrs)
+        seluAttrs = SeluAttrs()
+        seluAttrs.Init(buf, pos)
+        return cls.InitFromObj(seluAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3788,98 +6717,89 @@ class ReshapeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, reshapeAttrs):
-        x = ReshapeAttrsT()
-        x._UnPack(reshapeAttrs)
+    def InitFromObj(cls, seluAttrs):
+        x = SeluAttrsT()
+        x._UnPack(seluAttrs)
         return x
 
-    # ReshapeAttrsT
-    def _UnPack(self, reshapeAttrs):
-        if reshapeAttrs is None:
+    # SeluAttrsT
+    def _UnPack(self, seluAttrs):
+        if seluAttrs is None:
             return
-        self.allowZero = reshapeAttrs.AllowZero()
+        self.alpha = seluAttrs.Alpha()
+        self.gamma = seluAttrs.Gamma()
 
-    # ReshapeAttrsT
+    # SeluAttrsT
     def Pack(self, builder):
-        ReshapeAttrsStart(builder)
-        ReshapeAttrsAddAllowZero(builder, self.allowZero)
-        reshapeAttrs = ReshapeAttrsEnd(builder)
-        return reshapeAttrs
+        SeluAttrsStart(builder)
+        SeluAttrsAddAlpha(builder, self.alpha)
+        SeluAttrsAddGamma(builder, self.gamma)
+        seluAttrs = SeluAttrsEnd(builder)
+        return seluAttrs
 
 
-class ResizeAttrs(object):
+class ShrinkAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ResizeAttrs()
+        x = ShrinkAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsResizeAttrs(cls, buf, offset=0):
+    def GetRootAsShrinkAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ResizeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ShrinkAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ResizeAttrs
+    # ShrinkAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ResizeAttrs
-    def Mode(self):
+    # ShrinkAttrs
+    def Bias(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # ResizeAttrs
-    def CoordMode(self):
+    # ShrinkAttrs
+    def Lambd(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # ResizeAttrs
-    def NearestMode(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-def ResizeAttrsStart(builder):
-    builder.StartObject(3)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def ResizeAttrsAddMode(builder, mode):
-    builder.PrependUint8Slot(0, mode, 0)
+def ShrinkAttrsStart(builder):
+    builder.StartObject(2)
 
-def ResizeAttrsAddCoordMode(builder, coordMode):
-    builder.PrependUint8Slot(1, coordMode, 0)
+def ShrinkAttrsAddBias(builder, bias):
+    builder.PrependFloat32Slot(0, bias, 0.0)
 
-def ResizeAttrsAddNearestMode(builder, nearestMode):
-    builder.PrependUint8Slot(2, nearestMode, 0)
+def ShrinkAttrsAddLambd(builder, lambd):
+    builder.PrependFloat32Slot(1, lambd, 0.0)
 
-def ResizeAttrsEnd(builder):
+def ShrinkAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ResizeAttrsT(object):
+class ShrinkAttrsT(object):
 
-    # ResizeAttrsT
+    # ShrinkAttrsT
     def __init__(self):
-        self.mode = 0  # type: int
-        self.coordMode = 0  # type: int
-        self.nearestMode = 0  # type: int
+        self.bias = 0.0  # type: float
+        self.lambd = 0.0  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        resizeAttrs = ResizeAttrs()
-        resizeAttrs.Init(buf, pos)
-        return cls.InitFromObj(resizeAttrs)
+        shrinkAttrs = ShrinkAttrs()
+        shrinkAttrs.Init(buf, pos)
+        return cls.InitFromObj(shrinkAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3887,91 +6807,89 @@ class ResizeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, resizeAttrs):
-        x = ResizeAttrsT()
-        x._UnPack(resizeAttrs)
+    def InitFromObj(cls, shrinkAttrs):
+        x = ShrinkAttrsT()
+        x._UnPack(shrinkAttrs)
         return x
 
-    # ResizeAttrsT
-    def _UnPack(self, resizeAttrs):
-        if resizeAttrs is None:
+    # ShrinkAttrsT
+    def _UnPack(self, shrinkAttrs):
+        if shrinkAttrs is None:
             return
-        self.mode = resizeAttrs.Mode()
-        self.coordMode = resizeAttrs.CoordMode()
-        self.nearestMode = resizeAttrs.NearestMode()
+        self.bias = shrinkAttrs.Bias()
+        self.lambd = shrinkAttrs.Lambd()
 
-    # ResizeAttrsT
+    # ShrinkAttrsT
     def Pack(self, builder):
-        ResizeAttrsStart(builder)
-        ResizeAttrsAddMode(builder, self.mode)
-        ResizeAttrsAddCoordMode(builder, self.coordMode)
-        ResizeAttrsAddNearestMode(builder, self.nearestMode)
-        resizeAttrs = ResizeAttrsEnd(builder)
-        return resizeAttrs
+        ShrinkAttrsStart(builder)
+        ShrinkAttrsAddBias(builder, self.bias)
+        ShrinkAttrsAddLambd(builder, self.lambd)
+        shrinkAttrs = ShrinkAttrsEnd(builder)
+        return shrinkAttrs
 
 
-class ScatterElementsAttrs(object):
+class SoftmaxAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ScatterElementsAttrs()
+        x = SoftmaxAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsScatterElementsAttrs(cls, buf, offset=0):
+    def GetRootAsSoftmaxAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ScatterElementsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SoftmaxAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ScatterElementsAttrs
+    # SoftmaxAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ScatterElementsAttrs
+    # SoftmaxAttrs
     def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # ScatterElementsAttrs
-    def Reduction(self):
+    # SoftmaxAttrs
+    def Flatten(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def ScatterElementsAttrsStart(builder):
+def SoftmaxAttrsStart(builder):
     builder.StartObject(2)
 
-def ScatterElementsAttrsAddAxis(builder, axis):
+def SoftmaxAttrsAddAxis(builder, axis):
     builder.PrependInt32Slot(0, axis, 0)
 
-def ScatterElementsAttrsAddReduction(builder, reduction):
-    builder.PrependUint8Slot(1, reduction, 0)
+def SoftmaxAttrsAddFlatten(builder, flatten):
+    builder.PrependBoolSlot(1, flatten, 0)
 
-def ScatterElementsAttrsEnd(builder):
+def SoftmaxAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ScatterElementsAttrsT(object):
+class SoftmaxAttrsT(object):
 
-    # ScatterElementsAttrsT
+    # SoftmaxAttrsT
     def __init__(self):
         self.axis = 0  # type: int
-        self.reduction = 0  # type: int
+        self.flatten = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        scatterElementsAttrs = ScatterElementsAttrs()
-        scatterElementsAttrs.Init(buf, pos)
-        return cls.InitFromObj(scatterElementsAttrs)
+        softmaxAttrs = SoftmaxAttrs()
+        softmaxAttrs.Init(buf, pos)
+        return cls.InitFromObj(softmaxAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3979,78 +6897,78 @@ class ScatterElementsAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, scatterElementsAttrs):
-        x = ScatterElementsAttrsT()
-        x._UnPack(scatterElementsAttrs)
+    def InitFromObj(cls, softmaxAttrs):
+        x = SoftmaxAttrsT()
+        x._UnPack(softmaxAttrs)
         return x
 
-    # ScatterElementsAttrsT
-    def _UnPack(self, scatterElementsAttrs):
-        if scatterElementsAttrs is None:
+    # SoftmaxAttrsT
+    def _UnPack(self, softmaxAttrs):
+        if softmaxAttrs is None:
             return
-        self.axis = scatterElementsAttrs.Axis()
-        self.reduction = scatterElementsAttrs.Reduction()
+        self.axis = softmaxAttrs.Axis()
+        self.flatten = softmaxAttrs.Flatten()
 
-    # ScatterElementsAttrsT
+    # SoftmaxAttrsT
     def Pack(self, builder):
-        ScatterElementsAttrsStart(builder)
-        ScatterElementsAttrsAddAxis(builder, self.axis)
-        ScatterElementsAttrsAddReduction(builder, self.reduction)
-        scatterElementsAttrs = ScatterElementsAttrsEnd(builder)
-        return scatterElementsAttrs
+        SoftmaxAttrsStart(builder)
+        SoftmaxAttrsAddAxis(builder, self.axis)
+        SoftmaxAttrsAddFlatten(builder, self.flatten)
+        softmaxAttrs = SoftmaxAttrsEnd(builder)
+        return softmaxAttrs
 
 
-class ScatterNDAttrs(object):
+class STFTAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ScatterNDAttrs()
+        x = STFTAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsScatterNDAttrs(cls, buf, offset=0):
+    def GetRootAsSTFTAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ScatterNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def STFTAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ScatterNDAttrs
+    # STFTAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ScatterNDAttrs
-    def Reduction(self):
+    # STFTAttrs
+    def Onesided(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def ScatterNDAttrsStart(builder):
+def STFTAttrsStart(builder):
     builder.StartObject(1)
 
-def ScatterNDAttrsAddReduction(builder, reduction):
-    builder.PrependUint8Slot(0, reduction, 0)
+def STFTAttrsAddOnesided(builder, onesided):
+    builder.PrependBoolSlot(0, onesided, 0)
 
-def ScatterNDAttrsEnd(builder):
+def STFTAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ScatterNDAttrsT(object):
+class STFTAttrsT(object):
 
-    # ScatterNDAttrsT
+    # STFTAttrsT
     def __init__(self):
-        self.reduction = 0  # type: int
+        self.onesided = False  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        scatterNdattrs = ScatterNDAttrs()
-        scatterNdattrs.Init(buf, pos)
-        return cls.InitFromObj(scatterNdattrs)
+        stftattrs = STFTAttrs()
+        stftattrs.Init(buf, pos)
+        return cls.InitFromObj(stftattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4058,76 +6976,76 @@ class ScatterNDAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, scatterNdattrs):
-        x = ScatterNDAttrsT()
-        x._UnPack(scatterNdattrs)
+    def InitFromObj(cls, stftattrs):
+        x = STFTAttrsT()
+        x._UnPack(stftattrs)
         return x
 
-    # ScatterNDAttrsT
-    def _UnPack(self, scatterNdattrs):
-        if scatterNdattrs is None:
+    # STFTAttrsT
+    def _UnPack(self, stftattrs):
+        if stftattrs is None:
             return
-        self.reduction = scatterNdattrs.Reduction()
+        self.onesided = stftattrs.Onesided()
 
-    # ScatterNDAttrsT
+    # STFTAttrsT
     def Pack(self, builder):
-        ScatterNDAttrsStart(builder)
-        ScatterNDAttrsAddReduction(builder, self.reduction)
-        scatterNdattrs = ScatterNDAttrsEnd(builder)
-        return scatterNdattrs
+        STFTAttrsStart(builder)
+        STFTAttrsAddOnesided(builder, self.onesided)
+        stftattrs = STFTAttrsEnd(builder)
+        return stftattrs
 
 
-class SoftmaxAttrs(object):
+class SpaceToDepthAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = SoftmaxAttrs()
+        x = SpaceToDepthAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSoftmaxAttrs(cls, buf, offset=0):
+    def GetRootAsSpaceToDepthAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def SoftmaxAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SpaceToDepthAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # SoftmaxAttrs
+    # SpaceToDepthAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # SoftmaxAttrs
-    def Axis(self):
+    # SpaceToDepthAttrs
+    def BlockSize(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return 0
 
-def SoftmaxAttrsStart(builder):
+def SpaceToDepthAttrsStart(builder):
     builder.StartObject(1)
 
-def SoftmaxAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def SpaceToDepthAttrsAddBlockSize(builder, blockSize):
+    builder.PrependUint32Slot(0, blockSize, 0)
 
-def SoftmaxAttrsEnd(builder):
+def SpaceToDepthAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class SoftmaxAttrsT(object):
+class SpaceToDepthAttrsT(object):
 
-    # SoftmaxAttrsT
+    # SpaceToDepthAttrsT
     def __init__(self):
-        self.axis = 0  # type: int
+        self.blockSize = 0  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        softmaxAttrs = SoftmaxAttrs()
-        softmaxAttrs.Init(buf, pos)
-        return cls.InitFromObj(softmaxAttrs)
+        spaceToDepthAttrs = SpaceToDepthAttrs()
+        spaceToDepthAttrs.Init(buf, pos)
+        return cls.InitFromObj(spaceToDepthAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4135,23 +7053,23 @@ class SoftmaxAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, softmaxAttrs):
-        x = SoftmaxAttrsT()
-        x._UnPack(softmaxAttrs)
+    def InitFromObj(cls, spaceToDepthAttrs):
+        x = SpaceToDepthAttrsT()
+        x._UnPack(spaceToDepthAttrs)
         return x
 
-    # SoftmaxAttrsT
-    def _UnPack(self, softmaxAttrs):
-        if softmaxAttrs is None:
+    # SpaceToDepthAttrsT
+    def _UnPack(self, spaceToDepthAttrs):
+        if spaceToDepthAttrs is None:
             return
-        self.axis = softmaxAttrs.Axis()
+        self.blockSize = spaceToDepthAttrs.BlockSize()
 
-    # SoftmaxAttrsT
+    # SpaceToDepthAttrsT
     def Pack(self, builder):
-        SoftmaxAttrsStart(builder)
-        SoftmaxAttrsAddAxis(builder, self.axis)
-        softmaxAttrs = SoftmaxAttrsEnd(builder)
-        return softmaxAttrs
+        SpaceToDepthAttrsStart(builder)
+        SpaceToDepthAttrsAddBlockSize(builder, self.blockSize)
+        spaceToDepthAttrs = SpaceToDepthAttrsEnd(builder)
+        return spaceToDepthAttrs
 
 
 class SplitAttrs(object):
@@ -4183,12 +7101,22 @@ class SplitAttrs(object):
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
+    # SplitAttrs
+    def NumOutputs(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return None
+
 def SplitAttrsStart(builder):
-    builder.StartObject(1)
+    builder.StartObject(2)
 
 def SplitAttrsAddAxis(builder, axis):
     builder.PrependInt32Slot(0, axis, 0)
 
+def SplitAttrsAddNumOutputs(builder, numOutputs):
+    builder.PrependUint32Slot(1, numOutputs, None)
+
 def SplitAttrsEnd(builder):
     return builder.EndObject()
 
@@ -4199,6 +7127,7 @@ class SplitAttrsT(object):
     # SplitAttrsT
     def __init__(self):
         self.axis = 0  # type: int
+        self.numOutputs = None  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -4222,11 +7151,13 @@ class SplitAttrsT(object):
         if splitAttrs is None:
             return
         self.axis = splitAttrs.Axis()
+        self.numOutputs = splitAttrs.NumOutputs()
 
     # SplitAttrsT
     def Pack(self, builder):
         SplitAttrsStart(builder)
         SplitAttrsAddAxis(builder, self.axis)
+        SplitAttrsAddNumOutputs(builder, self.numOutputs)
         splitAttrs = SplitAttrsEnd(builder)
         return splitAttrs
 
@@ -4530,6 +7461,173 @@ class TriluAttrsT(object):
         return triluAttrs
 
 
+class UniqueAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = UniqueAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsUniqueAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def UniqueAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # UniqueAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # UniqueAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
+
+    # UniqueAttrs
+    def Sorted(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return True
+
+def UniqueAttrsStart(builder):
+    builder.StartObject(2)
+
+def UniqueAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, None)
+
+def UniqueAttrsAddSorted(builder, sorted):
+    builder.PrependBoolSlot(1, sorted, 1)
+
+def UniqueAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class UniqueAttrsT(object):
+
+    # UniqueAttrsT
+    def __init__(self):
+        self.axis = None  # type: Optional[int]
+        self.sorted = True  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        uniqueAttrs = UniqueAttrs()
+        uniqueAttrs.Init(buf, pos)
+        return cls.InitFromObj(uniqueAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, uniqueAttrs):
+        x = UniqueAttrsT()
+        x._UnPack(uniqueAttrs)
+        return x
+
+    # UniqueAttrsT
+    def _UnPack(self, uniqueAttrs):
+        if uniqueAttrs is None:
+            return
+        self.axis = uniqueAttrs.Axis()
+        self.sorted = uniqueAttrs.Sorted()
+
+    # UniqueAttrsT
+    def Pack(self, builder):
+        UniqueAttrsStart(builder)
+        UniqueAttrsAddAxis(builder, self.axis)
+        UniqueAttrsAddSorted(builder, self.sorted)
+        uniqueAttrs = UniqueAttrsEnd(builder)
+        return uniqueAttrs
+
+
+class WindowAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = WindowAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsWindowAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def WindowAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # WindowAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # WindowAttrs
+    def Periodic(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def WindowAttrsStart(builder):
+    builder.StartObject(1)
+
+def WindowAttrsAddPeriodic(builder, periodic):
+    builder.PrependBoolSlot(0, periodic, 0)
+
+def WindowAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class WindowAttrsT(object):
+
+    # WindowAttrsT
+    def __init__(self):
+        self.periodic = False  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        windowAttrs = WindowAttrs()
+        windowAttrs.Init(buf, pos)
+        return cls.InitFromObj(windowAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, windowAttrs):
+        x = WindowAttrsT()
+        x._UnPack(windowAttrs)
+        return x
+
+    # WindowAttrsT
+    def _UnPack(self, windowAttrs):
+        if windowAttrs is None:
+            return
+        self.periodic = windowAttrs.Periodic()
+
+    # WindowAttrsT
+    def Pack(self, builder):
+        WindowAttrsStart(builder)
+        WindowAttrsAddPeriodic(builder, self.periodic)
+        windowAttrs = WindowAttrsEnd(builder)
+        return windowAttrs
+
+
 class OperatorNode(object):
     __slots__ = ['_tab']
 
@@ -4669,7 +7767,7 @@ class OperatorNodeT(object):
     def __init__(self):
         self.type = 0  # type: int
         self.attrsType = 0  # type: int
-        self.attrs = None  # type: Union[None, ArgMaxAttrsT, AveragePoolAttrsT, BatchNormalizationAttrsT, CastAttrsT, ConcatAttrsT, ConstantOfShapeAttrsT, ConvAttrsT, ConvTransposeAttrsT, FlattenAttrsT, GatherAttrsT, GemmAttrsT, GRUAttrsT, LeakyReluAttrsT, LSTMAttrsT, MaxPoolAttrsT, ReduceMeanAttrsT, ReshapeAttrsT, ResizeAttrsT, SplitAttrsT, SoftmaxAttrsT, TransposeAttrsT, ModAttrsT, ScatterElementsAttrsT, OneHotAttrsT, TopKAttrsT, HardSigmoidAttrsT, TriluAttrsT, ScatterNDAttrsT, NonMaxSuppressionAttrsT, LayerNormalizationAttrsT, RandomUniformAttrsT, EluAttrsT, RandomUniformLikeAttrsT, RandomNormalAttrsT, RandomNormalLikeAttrsT, GatherNDAttrsT, GeluAttrsT, EinsumAttrsT]
+        self.attrs = None  # type: Union[None, ArgMaxAttrsT, AveragePoolAttrsT, BatchNormalizationAttrsT, CastAttrsT, ConcatAttrsT, ConstantOfShapeAttrsT, ConvAttrsT, ConvTransposeAttrsT, FlattenAttrsT, GatherAttrsT, GemmAttrsT, GRUAttrsT, LeakyReluAttrsT, LSTMAttrsT, MaxPoolAttrsT, ReduceMeanAttrsT, ReshapeAttrsT, ResizeAttrsT, SplitAttrsT, SoftmaxAttrsT, TransposeAttrsT, ModAttrsT, ScatterElementsAttrsT, OneHotAttrsT, TopKAttrsT, HardSigmoidAttrsT, TriluAttrsT, ScatterNDAttrsT, NonMaxSuppressionAttrsT, LayerNormalizationAttrsT, RandomUniformAttrsT, EluAttrsT, RandomUniformLikeAttrsT, RandomNormalAttrsT, RandomNormalLikeAttrsT, GatherNDAttrsT, GeluAttrsT, EinsumAttrsT, DepthToSpaceAttrsT, SpaceToDepthAttrsT, RoiAlignAttrsT, DFTAttrsT, STFTAttrsT, WindowAttrsT, RotaryEmbeddingAttrsT, LpPoolAttrsT, GlobalLpPoolAttrsT, MaxUnpoolAttrsT, CumSumAttrsT, SeluAttrsT, ShrinkAttrsT, BitShiftAttrsT, IsInfAttrsT, CenterCropPadAttrsT, CompressAttrsT, ReverseSequenceAttrsT, UniqueAttrsT, RNNAttrsT, BernoulliAttrsT, MultinomialAttrsT]
         self.inputs = None  # type: List[int]
         self.outputs = None  # type: List[int]
 
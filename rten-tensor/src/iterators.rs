@@ -517,6 +517,8 @@ pub struct Lane<'a, T> {
     data: ViewData<'a, T>,
     index: usize,
     stride: usize,
+
+    /// End of the range of indices which have not yet been yielded.
     size: usize,
 }
 
@@ -537,7 +539,22 @@ impl<'a, T> Iterator for Lane<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.size, Some(self.size))
+        let len = self.size - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Lane<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.size {
+            self.size -= 1;
+
+            // Safety: See comments in Storage trait.
+            unsafe { self.data.get(self.size * self.stride) }
+        } else {
+            None
+        }
     }
 }
 
@@ -615,6 +632,8 @@ pub struct LaneMut<'a, T> {
     data: ViewMutData<'a, T>,
     index: usize,
     stride: usize,
+
+    /// End of the range of indices which have not yet been yielded.
     size: usize,
 }
 
@@ -640,7 +659,27 @@ impl<'a, T> Iterator for LaneMut<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.size, Some(self.size))
+        let len = self.size - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for LaneMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.size {
+            self.size -= 1;
+            unsafe {
+                // Safety: See comments in Storage trait.
+                let item = self.data.get_mut(self.size * self.stride);
+
+                // Transmute to preserve lifetime of data. This is safe as we
+                // yield each element only once.
+                transmute::<Option<&mut T>, Option<Self::Item>>(item)
+            }
+        } else {
+            None
+        }
     }
 }
 
@@ -1112,4 +1151,27 @@ mod tests {
         assert!(LanesMut::new(x.mut_view_ref(), 0).next().is_none());
         assert!(LanesMut::new(x.mut_view_ref(), 1).next().is_none());
     }
+
+    #[test]
+    fn test_lane_double_ended() {
+        let mut x = Tensor::from([[1, 2, 3], [4, 5, 6]]);
+
+        let lane = Lanes::new(x.view().view_ref(), 1).next().unwrap();
+        assert_eq!(lane.rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+        // Iterate from both ends.
+        let mut lane = Lanes::new(x.view().view_ref(), 1).next().unwrap();
+        assert_eq!(lane.next(), Some(&1));
+        assert_eq!(lane.next_back(), Some(&3));
+        assert_eq!(lane.len(), 1);
+        assert_eq!(lane.next_back(), Some(&2));
+        assert_eq!(lane.next(), None);
+        assert_eq!(lane.next_back(), None);
+
+        let lane = LanesMut::new(x.mut_view_ref(), 0).next().unwrap();
+        for (i, item) in lane.rev().enumerate() {
+            *item = i as i32;
+        }
+        assert_eq!(x.to_vec(), [1, 2, 3, 0, 5, 6]);
+    }
 }
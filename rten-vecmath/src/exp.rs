@@ -218,6 +218,156 @@ pub fn vec_silu_in_place(xs: &mut [f32]) {
     dispatch_map_op_in_place(xs, SimdSilu {});
 }
 
+/// Compute `exp(x) - 1` for all lanes in `x`.
+///
+/// For small `|x|` this uses a Taylor series to avoid the loss of precision
+/// from subtracting nearly equal values.
+#[inline(always)]
+unsafe fn simd_exp_m1<S: SimdFloat>(x: S) -> S {
+    let x_small = x.abs().le(S::splat(0.5));
+
+    // Taylor series `x + x^2/2! + ... + x^8/8!`.
+    let y_small = x.poly_eval(&[
+        S::one(),
+        S::splat(1. / 2.),
+        S::splat(1. / 6.),
+        S::splat(1. / 24.),
+        S::splat(1. / 120.),
+        S::splat(1. / 720.),
+        S::splat(1. / 5040.),
+        S::splat(1. / 40320.),
+    ]);
+
+    let y = simd_exp(x).sub(S::one());
+    y.blend(y_small, x_small)
+}
+
+/// Compute the Mish function.
+///
+/// This computes `x * tanh(softplus(x))` for all lanes in `x`, using the
+/// identity `tanh(ln(1 + e^x)) = n / (n + 2)` where `n = e^x * (e^x + 2)`.
+#[inline(always)]
+unsafe fn simd_mish<S: SimdFloat>(x: S) -> S {
+    // Threshold above which `tanh(softplus(x))` rounds to 1 and `n` may
+    // overflow.
+    let x_large = x.ge(S::splat(20.));
+
+    let two = S::splat(2.);
+    let e = simd_exp(x);
+    let n = e.mul(e.add(two));
+    let y = x.mul(n.div(n.add(two)));
+    y.blend(x, x_large)
+}
+
+/// Mish function. This computes `x * tanh(softplus(x))`.
+///
+/// See <https://arxiv.org/abs/1908.08681>.
+pub fn mish(x: f32) -> f32 {
+    // Safety: f32 is available on all systems
+    unsafe { simd_mish(x) }
+}
+
+struct SimdMish {}
+impl SimdUnaryOp for SimdMish {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, x: S) -> S {
+        simd_mish(x)
+    }
+}
+
+/// Vectorized Mish function.
+///
+/// This computes `x * tanh(softplus(x))` for each element.
+pub fn vec_mish(xs: &[f32], out: &mut [MaybeUninit<f32>]) {
+    dispatch_map_op(xs, out, SimdMish {});
+}
+
+/// Variant of [vec_mish] that modifies elements in-place.
+pub fn vec_mish_in_place(xs: &mut [f32]) {
+    dispatch_map_op_in_place(xs, SimdMish {});
+}
+
+/// Compute the Scaled Exponential Linear Unit (SELU) function.
+///
+/// This computes `gamma * (x if x > 0 else alpha * (exp(x) - 1))`.
+#[inline(always)]
+unsafe fn simd_selu<S: SimdFloat>(x: S, alpha: f32, gamma: f32) -> S {
+    let x_neg = S::splat(alpha).mul(simd_exp_m1(x));
+    let y = x_neg.blend(x, S::zero().lt(x));
+    S::splat(gamma).mul(y)
+}
+
+/// Scaled Exponential Linear Unit (SELU) function.
+///
+/// This computes `gamma * (x if x > 0 else alpha * (exp(x) - 1))`.
+pub fn selu(x: f32, alpha: f32, gamma: f32) -> f32 {
+    // Safety: f32 is available on all systems
+    unsafe { simd_selu(x, alpha, gamma) }
+}
+
+struct SimdSelu {
+    alpha: f32,
+    gamma: f32,
+}
+
+impl SimdUnaryOp for SimdSelu {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, x: S) -> S {
+        simd_selu(x, self.alpha, self.gamma)
+    }
+}
+
+/// Vectorized Scaled Exponential Linear Unit (SELU) function.
+pub fn vec_selu(xs: &[f32], out: &mut [MaybeUninit<f32>], alpha: f32, gamma: f32) {
+    dispatch_map_op(xs, out, SimdSelu { alpha, gamma });
+}
+
+/// Variant of [vec_selu] that modifies elements in-place.
+pub fn vec_selu_in_place(xs: &mut [f32], alpha: f32, gamma: f32) {
+    dispatch_map_op_in_place(xs, SimdSelu { alpha, gamma });
+}
+
+/// Compute the Continuously Differentiable Exponential Linear Unit (CELU)
+/// function.
+///
+/// This computes `max(0, x) + min(0, alpha * (exp(x / alpha) - 1))`.
+#[inline(always)]
+unsafe fn simd_celu<S: SimdFloat>(x: S, alpha: f32) -> S {
+    let alpha = S::splat(alpha);
+    let x_neg = alpha.mul(simd_exp_m1(x.div(alpha)));
+    x_neg.blend(x, S::zero().lt(x))
+}
+
+/// Continuously Differentiable Exponential Linear Unit (CELU) function.
+///
+/// This computes `max(0, x) + min(0, alpha * (exp(x / alpha) - 1))`.
+pub fn celu(x: f32, alpha: f32) -> f32 {
+    // Safety: f32 is available on all systems
+    unsafe { simd_celu(x, alpha) }
+}
+
+struct SimdCelu {
+    alpha: f32,
+}
+
+impl SimdUnaryOp for SimdCelu {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self, x: S) -> S {
+        simd_celu(x, self.alpha)
+    }
+}
+
+/// Vectorized Continuously Differentiable Exponential Linear Unit (CELU)
+/// function.
+pub fn vec_celu(xs: &[f32], out: &mut [MaybeUninit<f32>], alpha: f32) {
+    dispatch_map_op(xs, out, SimdCelu { alpha });
+}
+
+/// Variant of [vec_celu] that modifies elements in-place.
+pub fn vec_celu_in_place(xs: &mut [f32], alpha: f32) {
+    dispatch_map_op_in_place(xs, SimdCelu { alpha });
+}
+
 struct SimdExp {}
 impl SimdUnaryOp for SimdExp {
     #[inline(always)]
@@ -249,7 +399,7 @@ mod tests {
     use crate::testing::{
         arange, benchmark_op, check_f32s_are_equal_ulps, check_with_all_f32s, AsUninit,
     };
-    use crate::{exp, vec_exp, vec_sigmoid, vec_silu};
+    use crate::{exp, vec_celu, vec_exp, vec_mish, vec_selu, vec_sigmoid, vec_silu};
 
     // Maximum error of `vec_expf` compared to Rust standard library
     // implementation.
@@ -259,6 +409,13 @@ mod tests {
     // below.
     const MAX_SIGMOID_ERROR_ULPS: f32 = 4.0;
 
+    // Maximum error of `vec_mish` compared to reference implementation below.
+    const MAX_MISH_ERROR_ULPS: f32 = 4.0;
+
+    // Maximum error of `vec_selu` and `vec_celu` compared to reference
+    // implementation below.
+    const MAX_ELU_ERROR_ULPS: f32 = 4.0;
+
     fn reference_sigmoid(x: f32) -> f32 {
         1. / (1. + (-x).exp())
     }
@@ -267,6 +424,18 @@ mod tests {
         x * reference_sigmoid(x)
     }
 
+    fn reference_mish(x: f32) -> f32 {
+        x * x.exp().ln_1p().tanh()
+    }
+
+    fn reference_elu(x: f32, alpha: f32) -> f32 {
+        if x > 0. {
+            x
+        } else {
+            alpha * x.exp_m1()
+        }
+    }
+
     /// Check the results of a SIMD implementation of a unary operator against
     /// a reference implementation.
     fn check_simd_vs_reference<
@@ -372,6 +541,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mish() {
+        check_simd_vs_reference(
+            vec_mish,
+            reference_mish,
+            MAX_MISH_ERROR_ULPS,
+            arange(-6., 30., 0.001f32),
+        );
+    }
+
+    #[test]
+    fn test_selu() {
+        let (alpha, gamma) = (1.6732632, 1.050701);
+        check_simd_vs_reference(
+            |xs, out| vec_selu(xs, out, alpha, gamma),
+            |x| gamma * reference_elu(x, alpha),
+            MAX_ELU_ERROR_ULPS,
+            arange(-6., 6., 0.001f32),
+        );
+    }
+
+    #[test]
+    fn test_celu() {
+        for alpha in [0.5, 1., 2.] {
+            check_simd_vs_reference(
+                |xs, out| vec_celu(xs, out, alpha),
+                |x| alpha * reference_elu(x / alpha, 1.),
+                MAX_ELU_ERROR_ULPS,
+                arange(-6., 6., 0.001f32),
+            );
+        }
+    }
+
     #[test]
     #[ignore]
     fn bench_expf() {
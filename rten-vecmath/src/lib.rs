@@ -19,6 +19,7 @@
 
 mod erf;
 mod exp;
+mod norm;
 mod softmax;
 mod tanh;
 
@@ -30,8 +31,10 @@ mod testing;
 
 pub use erf::{erf, gelu, vec_erf, vec_erf_in_place, vec_gelu, vec_gelu_in_place};
 pub use exp::{
-    exp, sigmoid, silu, vec_exp, vec_exp_in_place, vec_sigmoid, vec_sigmoid_in_place, vec_silu,
-    vec_silu_in_place,
+    celu, exp, mish, selu, sigmoid, silu, vec_celu, vec_celu_in_place, vec_exp, vec_exp_in_place,
+    vec_mish, vec_mish_in_place, vec_selu, vec_selu_in_place, vec_sigmoid, vec_sigmoid_in_place,
+    vec_silu, vec_silu_in_place,
 };
+pub use norm::vec_rms_norm_in_place;
 pub use softmax::{vec_softmax, vec_softmax_in_place};
 pub use tanh::{tanh, vec_tanh, vec_tanh_in_place};
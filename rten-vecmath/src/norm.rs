@@ -0,0 +1,108 @@
+use rten_simd::dispatch::{SimdDispatcher, SimdOp};
+use rten_simd::functional::simd_fold;
+use rten_simd::span::{MutPtrLen, PtrLen};
+use rten_simd::SimdFloat;
+
+/// Divide elements of `data` by their root-mean-square and multiply by the
+/// corresponding element of `scale`.
+#[inline(always)]
+unsafe fn simd_rms_norm<S: SimdFloat>(data: MutPtrLen<f32>, scale: PtrLen<f32>, epsilon: f32) {
+    let sum_square = simd_fold(
+        data.into(),
+        S::zero(),
+        #[inline(always)]
+        |sum, x| x.mul_add(x, sum),
+        0., /* pad */
+    )
+    .sum();
+    let mean_square = sum_square / data.len() as f32;
+    let inv_rms = 1. / (mean_square + epsilon).sqrt();
+    let inv_rms_vec = S::splat(inv_rms);
+
+    let mut n = data.len();
+    let mut x_ptr = data.ptr();
+    let mut scale_ptr = scale.ptr();
+
+    while n >= S::LEN {
+        let x = S::load(x_ptr);
+        let scale = S::load(scale_ptr);
+        x.mul(inv_rms_vec).mul(scale).store(x_ptr);
+
+        n -= S::LEN;
+        x_ptr = x_ptr.add(S::LEN);
+        scale_ptr = scale_ptr.add(S::LEN);
+    }
+
+    for i in 0..n {
+        *x_ptr.add(i) *= inv_rms * *scale_ptr.add(i);
+    }
+}
+
+struct SimdRmsNorm {
+    data: MutPtrLen<f32>,
+    scale: PtrLen<f32>,
+    epsilon: f32,
+}
+
+impl SimdOp for SimdRmsNorm {
+    #[inline(always)]
+    unsafe fn eval<S: SimdFloat>(&self) {
+        simd_rms_norm::<S>(self.data, self.scale, self.epsilon)
+    }
+}
+
+/// Apply [RMS normalization][rms_norm] to a slice of floats.
+///
+/// This computes `x / sqrt(mean(x^2) + epsilon) * scale` for each element,
+/// where the mean is taken over all elements of `xs`.
+///
+/// Panics if `xs` and `scale` have different lengths.
+///
+/// [rms_norm]: https://arxiv.org/abs/1910.07467
+pub fn vec_rms_norm_in_place(xs: &mut [f32], scale: &[f32], epsilon: f32) {
+    assert_eq!(xs.len(), scale.len());
+    if xs.is_empty() {
+        return;
+    }
+
+    let op = SimdRmsNorm {
+        data: xs.into(),
+        scale: scale.into(),
+        epsilon,
+    };
+    let dispatcher = SimdDispatcher::default();
+    dispatcher.dispatch(op);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::vec_rms_norm_in_place;
+
+    fn reference_rms_norm(xs: &mut [f32], scale: &[f32], epsilon: f32) {
+        let mean_square = xs.iter().map(|x| x * x).sum::<f32>() / xs.len() as f32;
+        let inv_rms = 1. / (mean_square + epsilon).sqrt();
+        for (x, s) in xs.iter_mut().zip(scale) {
+            *x *= inv_rms * s;
+        }
+    }
+
+    #[test]
+    fn test_vec_rms_norm_in_place() {
+        // Test lengths which are smaller than, equal to and larger than a
+        // SIMD vector, and not a multiple of the vector size.
+        for len in [1, 3, 16, 35] {
+            let xs: Vec<f32> = (0..len).map(|i| (i as f32 - 10.) * 0.3).collect();
+            let scale: Vec<f32> = (0..len).map(|i| 1. + i as f32 * 0.1).collect();
+
+            let mut expected = xs.clone();
+            reference_rms_norm(&mut expected, &scale, 1e-5);
+
+            let mut actual = xs.clone();
+            vec_rms_norm_in_place(&mut actual, &scale, 1e-5);
+
+            for (x, y) in actual.iter().zip(&expected) {
+                assert!((x - y).abs() <= 1e-5, "{} != {}", x, y);
+            }
+        }
+    }
+}
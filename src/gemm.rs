@@ -12,6 +12,7 @@ use std::ops::Range;
 use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::{Alloc, GlobalAlloc, Matrix, MatrixLayout, MatrixMut, NdTensorView};
+use rten_vecmath::vec_sigmoid_in_place;
 
 use crate::iter_util::{range_chunks, MaybeParIter};
 use crate::tensor_pool::ExtractBuffer;
@@ -21,6 +22,67 @@ mod packing;
 
 use kernels::{BaseKernel, Kernel};
 
+/// Elementwise activation function that can be applied to the output of a
+/// matrix multiplication as part of the same operation.
+///
+/// Applying the activation while the output tile is still in cache avoids a
+/// separate pass over the output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Activation {
+    Relu,
+    LeakyRelu {
+        alpha: f32,
+    },
+    /// Clamp values to `[min, max]`. `min` must be <= `max`.
+    Clip {
+        min: f32,
+        max: f32,
+    },
+    Sigmoid,
+}
+
+impl Activation {
+    /// Apply the activation function to each element of `xs`.
+    pub fn apply(&self, xs: &mut [f32]) {
+        match *self {
+            Activation::Relu => xs.iter_mut().for_each(|x| *x = x.max(0.)),
+            Activation::LeakyRelu { alpha } => xs.iter_mut().for_each(|x| {
+                if *x < 0. {
+                    *x *= alpha;
+                }
+            }),
+            Activation::Clip { min, max } => xs.iter_mut().for_each(|x| *x = x.clamp(min, max)),
+            Activation::Sigmoid => vec_sigmoid_in_place(xs),
+        }
+    }
+}
+
+/// Bias vector that is added to the output of a matrix multiplication.
+#[derive(Clone, Copy, Debug)]
+pub enum BiasVector<'a> {
+    /// Column vector whose length matches the rows of the output. Each element
+    /// is added to every entry in the corresponding row.
+    Column(&'a [f32]),
+
+    /// Row vector whose length matches the columns of the output. Each element
+    /// is added to every entry in the corresponding column.
+    Row(&'a [f32]),
+}
+
+impl BiasVector<'_> {
+    /// Return the bias value for a given output element.
+    ///
+    /// Safety: `row` or `col` (depending on the variant) must be a valid index
+    /// into the bias vector.
+    #[inline(always)]
+    unsafe fn get_unchecked(&self, row: usize, col: usize) -> f32 {
+        match self {
+            BiasVector::Column(bias) => *bias.get_unchecked(row),
+            BiasVector::Row(bias) => *bias.get_unchecked(col),
+        }
+    }
+}
+
 /// Left-hand or "A" GEMM input that has been pre-packed.
 #[derive(Clone)]
 pub struct PackedAMatrix {
@@ -38,6 +100,9 @@ pub struct PackedAMatrix {
 
     /// Number of columns in the unpacked matrix.
     cols: usize,
+
+    /// Name of the kernel which packed the matrix.
+    kernel_name: &'static str,
 }
 
 impl PackedAMatrix {
@@ -73,6 +138,9 @@ pub struct PackedBMatrix {
 
     /// Number of columns in the unpacked matrix.
     cols: usize,
+
+    /// Name of the kernel which packed the matrix.
+    kernel_name: &'static str,
 }
 
 impl PackedBMatrix {
@@ -327,7 +395,6 @@ impl GemmExecutor {
     }
 
     /// Prepack a matrix for use as the left-hand or "A" input.
-    #[allow(unused)]
     pub fn prepack_a(&self, a: Matrix) -> PackedAMatrix {
         self.prepack_a_in(GlobalAlloc::new(), a)
     }
@@ -375,6 +442,7 @@ impl GemmExecutor {
             cols: a.cols(),
             panel_len,
             row_blocks,
+            kernel_name: self.kernel.name(),
         }
     }
 
@@ -386,7 +454,6 @@ impl GemmExecutor {
     }
 
     /// Prepack a matrix for use as the right-hand or "B" matrix input.
-    #[allow(unused)]
     pub fn prepack_b(&self, b: Matrix) -> PackedBMatrix {
         self.prepack_b_in(GlobalAlloc::new(), b)
     }
@@ -434,6 +501,7 @@ impl GemmExecutor {
             cols: b.cols(),
             depth_blocks,
             panel_len,
+            kernel_name: self.kernel.name(),
         }
     }
 
@@ -463,6 +531,7 @@ impl GemmExecutor {
             alpha,
             beta,
             None,
+            None,
         )
     }
 
@@ -481,14 +550,14 @@ impl GemmExecutor {
         self.gemm_uninit_bias(out_data, out_row_stride, a, b, alpha, None);
     }
 
-    /// Perform a matrix multiplication with fused bias vector addition.
+    /// Perform a matrix multiplication with fused bias vector addition and
+    /// activation.
     ///
-    /// This computes `output = alpha * (a @ b) + beta * output + bias` where
-    /// `@` is matrix multiplication.
+    /// This computes `output = activation(alpha * (a @ b) + beta * output + bias)`
+    /// where `@` is matrix multiplication.
     ///
-    /// If `bias` is present, it is treated as a column vector whose length
-    /// must match the rows of `a`.
-    #[allow(unused)]
+    /// If `bias` is present, its length must match the rows (for a
+    /// [BiasVector::Column]) or columns (for a [BiasVector::Row]) of the output.
     pub fn gemm_bias(
         &self,
         out_data: &mut [f32],
@@ -497,7 +566,8 @@ impl GemmExecutor {
         b: GemmInputB,
         alpha: f32,
         beta: f32,
-        bias: Option<&[f32]>,
+        bias: Option<BiasVector>,
+        activation: Option<Activation>,
     ) {
         gemm_impl(
             &*self.kernel,
@@ -508,6 +578,7 @@ impl GemmExecutor {
             alpha,
             beta,
             bias,
+            activation,
         )
     }
 
@@ -516,8 +587,8 @@ impl GemmExecutor {
     /// This computes `output = alpha * (a @ b) + bias` where
     /// `@` is matrix multiplication.
     ///
-    /// If `bias` is present, it is treated as a column vector whose length
-    /// must match the rows of `a`.
+    /// If `bias` is present, its length must match the rows (for a
+    /// [BiasVector::Column]) or columns (for a [BiasVector::Row]) of the output.
     pub fn gemm_uninit_bias(
         &self,
         out_data: &mut [MaybeUninit<f32>],
@@ -525,7 +596,29 @@ impl GemmExecutor {
         a: GemmInputA,
         b: GemmInputB,
         alpha: f32,
-        bias: Option<&[f32]>,
+        bias: Option<BiasVector>,
+    ) {
+        self.gemm_uninit_bias_activation(out_data, out_row_stride, a, b, alpha, bias, None);
+    }
+
+    /// Perform a matrix multiplication with fused bias vector addition and
+    /// activation.
+    ///
+    /// This computes `output = activation(alpha * (a @ b) + bias)` where `@`
+    /// is matrix multiplication. The activation is applied to each output
+    /// tile after its final update.
+    ///
+    /// If `bias` is present, its length must match the rows (for a
+    /// [BiasVector::Column]) or columns (for a [BiasVector::Row]) of the output.
+    pub fn gemm_uninit_bias_activation(
+        &self,
+        out_data: &mut [MaybeUninit<f32>],
+        out_row_stride: usize,
+        a: GemmInputA,
+        b: GemmInputB,
+        alpha: f32,
+        bias: Option<BiasVector>,
+        activation: Option<Activation>,
     ) {
         gemm_impl(
             &*self.kernel,
@@ -538,6 +631,7 @@ impl GemmExecutor {
             alpha,
             0., /* beta */
             bias,
+            activation,
         )
     }
 }
@@ -655,7 +749,8 @@ fn gemv(
     mut output_mat: MatrixMut,
     alpha: f32,
     beta: f32,
-    bias: Option<f32>,
+    bias: Option<BiasVector>,
+    activation: Option<Activation>,
 ) {
     assert!(output_mat.is_contiguous());
 
@@ -695,10 +790,23 @@ fn gemv(
                 effective_beta = 1.0;
             }
 
-            if let Some(bias) = bias {
-                for x in out_chunk {
-                    *x += bias;
+            match bias {
+                // nb. Bias length was checked at start of `gemm_impl`.
+                Some(BiasVector::Column(bias)) => {
+                    for x in out_chunk.iter_mut() {
+                        *x += bias[0];
+                    }
+                }
+                Some(BiasVector::Row(bias)) => {
+                    for (x, bias) in out_chunk.iter_mut().zip(&bias[col_block]) {
+                        *x += bias;
+                    }
                 }
+                None => {}
+            }
+
+            if let Some(activation) = activation {
+                activation.apply(out_chunk);
             }
         });
 }
@@ -738,16 +846,36 @@ fn gemm_impl(
     b: GemmInputB,
     alpha: f32,
     beta: f32,
-    bias: Option<&[f32]>,
+    bias: Option<BiasVector>,
+    activation: Option<Activation>,
 ) {
     assert!(
         a.cols() == b.rows(),
         "Columns of matrix `a` must match rows of matrix `b`"
     );
-    assert!(
-        bias.map(|b| b.len()).unwrap_or(a.rows()) == a.rows(),
-        "Bias vector length must match rows of matrix `a`"
-    );
+    if let GemmInputA::Packed(pm) = a {
+        assert!(
+            pm.kernel_name == kernel.name(),
+            "Matrix `a` was packed for a different kernel"
+        );
+    }
+    if let GemmInputB::Packed(pm) = b {
+        assert!(
+            pm.kernel_name == kernel.name(),
+            "Matrix `b` was packed for a different kernel"
+        );
+    }
+    match bias {
+        Some(BiasVector::Column(bias)) => assert!(
+            bias.len() == a.rows(),
+            "Bias vector length must match rows of matrix `a`"
+        ),
+        Some(BiasVector::Row(bias)) => assert!(
+            bias.len() == b.cols(),
+            "Bias vector length must match columns of matrix `b`"
+        ),
+        None => {}
+    }
 
     // Handle case where output is empty.
     if a.rows() == 0 || b.cols() == 0 {
@@ -757,9 +885,23 @@ fn gemm_impl(
     // Handle case where depth is zero. We still need to initialize the output
     // in this case.
     if a.cols() == 0 {
-        for x in out_data {
-            let tmp = if beta == 0. { 0. } else { *x };
-            *x = beta * tmp;
+        for (row, out_row) in out_data
+            .chunks_mut(out_row_stride)
+            .take(a.rows())
+            .enumerate()
+        {
+            let out_row = &mut out_row[..b.cols()];
+            for (col, x) in out_row.iter_mut().enumerate() {
+                let tmp = if beta == 0. { 0. } else { *x };
+                // Safety: Bias length was checked above.
+                *x = beta * tmp
+                    + bias
+                        .map(|b| unsafe { b.get_unchecked(row, col) })
+                        .unwrap_or(0.);
+            }
+            if let Some(activation) = activation {
+                activation.apply(out_row);
+            }
         }
         return;
     }
@@ -781,8 +923,8 @@ fn gemm_impl(
             output_mat.view_mut(),
             alpha,
             beta,
-            // nb. We checked above that, if present, the bias length matches `a.rows()`.
-            bias.map(|b| b[0]),
+            bias,
+            activation,
         );
         return;
     }
@@ -909,12 +1051,14 @@ fn gemm_impl(
                             col_start / nr..col_end.div_ceil(nr),
                             row_start / mr..row_end.div_ceil(mr),
                             depth_range.start == 0,
+                            depth_range.end == a.cols(),
                             packed_a,
                             packed_b,
                             panel_length,
                             alpha,
                             effective_beta,
                             bias,
+                            activation,
                         );
 
                         if let Some(packed_a) = thread_local_packed_a {
@@ -936,20 +1080,23 @@ fn gemm_impl(
 /// `packed_a` and `packed_b` are the corresponding packed inputs. `panel_length`
 /// is the size of panels along the depth/K dimension.
 ///
-/// `is_first` indicates whether this is the first write to the output tiles
-/// in this block during the current GEMM operation.
+/// `first_update` indicates whether this is the first write to the output
+/// tiles in this block during the current GEMM operation, and `last_update`
+/// whether it is the final write. `activation` is applied on the final write.
 fn gemm_block(
     kernel: &dyn Kernel,
     output: &OutputTiles,
     col_tiles: Range<usize>,
     row_tiles: Range<usize>,
     first_update: bool,
+    last_update: bool,
     packed_a: &[f32],
     packed_b: &[f32],
     panel_length: usize,
     alpha: f32,
     beta: f32,
-    bias: Option<&[f32]>,
+    bias: Option<BiasVector>,
+    activation: Option<Activation>,
 ) {
     // Maximum tile size of all supported kernels.
     const MAX_MR: usize = 8;
@@ -1040,11 +1187,27 @@ fn gemm_block(
                             //  - Bias length was checked at start of `gemm_impl`
                             unsafe {
                                 *out_tile.ptr.add(row * out_tile.row_stride + col) +=
-                                    *bias.get_unchecked(row_tile * mr + row);
+                                    bias.get_unchecked(row_tile * mr + row, col_tile * nr + col);
                             }
                         }
                     }
                 }
+
+                // Apply activation on final write to an output tile.
+                if let (Some(activation), true) = (activation, last_update) {
+                    for row in 0..out_tile.used_rows {
+                        // Safety:
+                        //  - Row index and column count are valid for current tile
+                        //  - This tile is only operated on by the current thread
+                        let out_row = unsafe {
+                            std::slice::from_raw_parts_mut(
+                                out_tile.ptr.add(row * out_tile.row_stride),
+                                out_tile.used_cols,
+                            )
+                        };
+                        activation.apply(out_row);
+                    }
+                }
             }
         });
 }
@@ -1059,10 +1222,13 @@ mod tests {
     use rten_bench::run_bench;
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
-    use rten_tensor::test_util::expect_equal;
+    use rten_tensor::test_util::{expect_equal, expect_equal_with_tolerance};
     use rten_tensor::{Matrix, MatrixLayout, NdTensor, Tensor};
 
-    use super::{gemm, GemmExecutor, GemmInputA, GemmInputB, KernelType, VirtualMatrix};
+    use super::{
+        gemm, Activation, BiasVector, GemmExecutor, GemmInputA, GemmInputB, KernelType,
+        VirtualMatrix,
+    };
 
     fn reference_matmul_alpha_beta(a: &Tensor, b: &Tensor, alpha: f32, beta: f32) -> Tensor {
         let [a_rows, _a_cols]: [usize; 2] = a.shape().try_into().expect("input should be a matrix");
@@ -1098,7 +1264,7 @@ mod tests {
         b: &Tensor,
         alpha: f32,
         beta: f32,
-        bias: Option<&[f32]>,
+        bias: Option<BiasVector>,
         kernel: Option<KernelType>,
     ) {
         let out_row_stride = output.stride(0);
@@ -1116,6 +1282,7 @@ mod tests {
             alpha,
             beta,
             bias,
+            None, /* activation */
         );
     }
 
@@ -1129,7 +1296,7 @@ mod tests {
         b: &Tensor,
         alpha: f32,
         beta: f32,
-        bias: Option<&[f32]>,
+        bias: Option<BiasVector>,
     ) {
         let [a_rows, a_cols]: [usize; 2] = a.shape().try_into().expect("input should be a matrix");
         let [_b_rows, b_cols]: [usize; 2] = b.shape().try_into().expect("input should be a matrix");
@@ -1140,8 +1307,12 @@ mod tests {
                 for k in 0..a_cols {
                     accum += a[[r, k]] * b[[k, c]];
                 }
-                output[[r, c]] =
-                    alpha * accum + beta * output[[r, c]] + bias.map(|b| b[r]).unwrap_or(0.);
+                let bias = match bias {
+                    Some(BiasVector::Column(b)) => b[r],
+                    Some(BiasVector::Row(b)) => b[c],
+                    None => 0.,
+                };
+                output[[r, c]] = alpha * accum + beta * output[[r, c]] + bias;
             }
         }
     }
@@ -1392,17 +1563,82 @@ mod tests {
 
         let a = Tensor::rand(&[10, 5], &mut rng);
         let b = Tensor::rand(&[5, 15], &mut rng);
-        let bias: Vec<f32> = (0..a.shape()[0]).map(|b| b as f32).collect();
+        let col_bias: Vec<f32> = (0..a.shape()[0]).map(|b| b as f32).collect();
+        let row_bias: Vec<f32> = (0..b.shape()[1]).map(|b| b as f32).collect();
 
-        let mut result = Tensor::zeros(&[10, 15]);
-        let mut expected = result.clone();
+        for bias in [BiasVector::Column(&col_bias), BiasVector::Row(&row_bias)] {
+            let mut result = Tensor::zeros(&[10, 15]);
+            let mut expected = result.clone();
 
-        for kernel in [None, Some(KernelType::Base)] {
-            run_gemm(&mut result, &a, &b, 1., 0., Some(&bias), kernel);
-            reference_gemm(&mut expected, &a, &b, 1., 0., Some(&bias));
+            for kernel in [None, Some(KernelType::Base)] {
+                run_gemm(&mut result, &a, &b, 1., 0., Some(bias), kernel);
+                reference_gemm(&mut expected, &a, &b, 1., 0., Some(bias));
+            }
+
+            expect_equal(&result, &expected)?;
         }
 
-        expect_equal(&result, &expected)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_gemm_bias_activation() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        // Cases include a depth which uses multiple depth blocks, to verify
+        // the activation is only applied after the final update, a single
+        // row to test the vector-matrix path and a zero depth, where the
+        // output is just the bias.
+        for [m, k, n] in [
+            [10, 5, 15],
+            [10, DEPTH_BLOCK_SIZE + 10, 15],
+            [1, 20, 30],
+            [5, 0, 3],
+        ] {
+            let mut a = Tensor::rand(&[m, k], &mut rng);
+            a.apply(|x| x - 0.5);
+            let b = Tensor::rand(&[k, n], &mut rng);
+            let col_bias: Vec<f32> = (0..m).map(|i| i as f32 - 2.).collect();
+            let row_bias: Vec<f32> = (0..n).map(|i| i as f32 - 2.).collect();
+
+            let activations = [
+                Activation::Relu,
+                Activation::LeakyRelu { alpha: 0.5 },
+                Activation::Clip { min: -1., max: 1. },
+                Activation::Sigmoid,
+            ];
+            let biases = [BiasVector::Column(&col_bias), BiasVector::Row(&row_bias)];
+
+            for (bias, activation) in biases
+                .into_iter()
+                .flat_map(|bias| activations.map(|act| (bias, act)))
+            {
+                let mut expected = Tensor::zeros(&[m, n]);
+                reference_gemm(&mut expected, &a, &b, 1., 0., Some(bias));
+                activation.apply(expected.data_mut().unwrap());
+
+                for kernel in [None, Some(KernelType::Base)] {
+                    let gemm = if let Some(kernel) = kernel {
+                        GemmExecutor::with_kernel(kernel).expect("kernel not available")
+                    } else {
+                        GemmExecutor::new()
+                    };
+                    let mut result = Tensor::uninit(&[m, n]);
+                    gemm.gemm_uninit_bias_activation(
+                        result.data_mut().unwrap(),
+                        n,
+                        GemmInputA::Unpacked(a.nd_view()),
+                        GemmInputB::Unpacked(b.nd_view()),
+                        1.,
+                        Some(bias),
+                        Some(activation),
+                    );
+                    let result = unsafe { result.assume_init() };
+
+                    expect_equal_with_tolerance(&result, &expected, 1e-4, 0.)?;
+                }
+            }
+        }
 
         Ok(())
     }
@@ -1764,7 +2000,9 @@ mod tests {
                 &b,
                 alpha,
                 beta,
-                bias_array.as_ref().map(|b| b.as_slice()),
+                bias_array
+                    .as_ref()
+                    .map(|b| BiasVector::Column(b.as_slice())),
                 None,
             );
 
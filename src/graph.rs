@@ -17,7 +17,9 @@ use smallvec::SmallVec;
 
 use crate::constant_storage::ArcTensorView;
 use crate::env::env_flag;
-use crate::ops::{Input, InputList, InputOrOutput, OpError, Operator, Output, OutputList};
+use crate::ops::{
+    Input, InputList, InputOrOutput, OpError, Operator, Output, OutputList, PrepackedInput,
+};
 use crate::tensor_pool::TensorPool;
 use crate::threading;
 use crate::timing::{InputShape, Instant, RunTiming, TimingRecord, TimingSort};
@@ -40,6 +42,10 @@ pub struct OperatorNode {
     inputs: Vec<Option<NodeId>>,
     outputs: Vec<Option<NodeId>>,
     operator: Arc<dyn Operator + Send + Sync>,
+
+    /// Prepacked versions of constant inputs, indexed by input position.
+    /// See [`Graph::prepack_weights`].
+    prepacked: Vec<Option<PrepackedInput>>,
 }
 
 impl OperatorNode {
@@ -74,13 +80,23 @@ impl OperatorNode {
         self.operator.clone()
     }
 
-    pub fn replace_input(&mut self, old_id: NodeId, new_id: NodeId) {
-        for input_id in self.inputs.iter_mut() {
+    pub(crate) fn replace_input(&mut self, old_id: NodeId, new_id: NodeId) {
+        for (index, input_id) in self.inputs.iter_mut().enumerate() {
             if *input_id == Some(old_id) {
                 *input_id = Some(new_id);
+
+                // Prepacked data is only valid for the original input.
+                if let Some(prepacked) = self.prepacked.get_mut(index) {
+                    *prepacked = None;
+                }
             }
         }
     }
+
+    /// Return true if the input at `index` has been prepacked.
+    pub fn is_prepacked(&self, index: usize) -> bool {
+        self.prepacked.get(index).is_some_and(|p| p.is_some())
+    }
 }
 
 pub struct ValueNode {
@@ -92,6 +108,11 @@ impl ValueNode {
     fn name(&self) -> Option<&str> {
         self.name.as_deref()
     }
+
+    /// Set the expected shape of this value at runtime.
+    pub fn set_shape(&mut self, shape: Option<Vec<Dimension>>) {
+        self.shape = shape;
+    }
 }
 
 /// Data for a constant node (ie. model weights) in a [Graph].
@@ -267,6 +288,10 @@ pub enum RunError {
     /// The output of a graph operator did not match expectations (eg. the
     /// count, types or shapes of outputs did not match what was expected.)
     OutputMismatch(&'static str),
+
+    /// An input did not match what the graph expects (eg. its shape differs
+    /// from the shape the graph was specialized for).
+    InputMismatch(String),
 }
 
 impl fmt::Display for RunError {
@@ -280,6 +305,7 @@ impl fmt::Display for RunError {
                 error: ref err,
             } => write!(f, "operator \"{}\" failed: {:?}", name, err),
             RunError::OutputMismatch(err) => write!(f, "output mismatch {:?}", err),
+            RunError::InputMismatch(err) => write!(f, "input mismatch: {}", err),
         }
     }
 }
@@ -293,6 +319,18 @@ fn all_unique<T, F: Fn(&T, &T) -> bool>(xs: &[T], eq: F) -> bool {
         .all(|x| xs.iter().filter(|y| eq(x, y)).count() == 1)
 }
 
+/// Derive the seed for a random operator from the seed for a graph run.
+///
+/// This mixes the operator's node ID into the run seed so that each operator
+/// gets an independent random number stream.
+fn op_seed(run_seed: u64, op_node_id: NodeId) -> u64 {
+    // Finalizer from the SplitMix64 generator.
+    let mut z = run_seed ^ (op_node_id as u64).wrapping_mul(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
 /// Options for creating a graph execution plan.
 #[derive(Default)]
 struct PlanOptions {
@@ -415,6 +453,15 @@ pub struct RunOptions {
     /// including input shapes and execution time. This will slow down
     /// execution.
     pub verbose: bool,
+
+    /// Seed for operators which generate random numbers.
+    ///
+    /// If set, each random operator in the graph derives its random number
+    /// generator from this seed and the operator's node ID, instead of using
+    /// the `seed` attribute of the operator or a random seed. Runs of the
+    /// same graph with the same seed and inputs will produce the same
+    /// outputs.
+    pub seed: Option<u64>,
 }
 
 /// A graph defines how to produce output values from a set of dynamic input
@@ -517,12 +564,26 @@ impl Graph {
         op: Box<dyn Operator + Send + Sync>,
         inputs: &[Option<NodeId>],
         outputs: &[Option<NodeId>],
+    ) -> NodeId {
+        self.add_shared_op(name, Arc::from(op), inputs, outputs)
+    }
+
+    /// Variant of [`add_op`](Self::add_op) which takes a shared reference to
+    /// an operator, such as one returned by
+    /// [`OperatorNode::clone_operator`].
+    pub(crate) fn add_shared_op(
+        &mut self,
+        name: Option<&str>,
+        op: Arc<dyn Operator + Send + Sync>,
+        inputs: &[Option<NodeId>],
+        outputs: &[Option<NodeId>],
     ) -> NodeId {
         let op_id = self.add_node(Node::Operator(OperatorNode {
             name: name.map(|s| s.to_owned()),
             inputs: Vec::from(inputs),
             outputs: Vec::from(outputs),
-            operator: Arc::from(op),
+            operator: op,
+            prepacked: Vec::new(),
         }));
 
         for output_id in outputs.iter().flatten() {
@@ -625,6 +686,112 @@ impl Graph {
         self.nodes.get_mut(id)
     }
 
+    /// Prepack constant inputs of operators which support it, such as the
+    /// weights of matrix multiplications and convolutions.
+    ///
+    /// This trades the extra memory used to store the packed weights for
+    /// removing the cost of packing from each run. It should be done after
+    /// the graph has been optimized. See [`Operator::prepack`].
+    ///
+    /// Returns the number of inputs that were prepacked.
+    pub fn prepack_weights(&mut self) -> usize {
+        let mut prepacked = Vec::new();
+        for (op_id, node) in self.iter() {
+            let Node::Operator(op_node) = node else {
+                continue;
+            };
+            for &index in op_node.operator.prepack_inputs() {
+                let Some(Some(input_id)) = op_node.inputs.get(index) else {
+                    continue;
+                };
+                let Some(Node::Constant(constant)) = self.get_node(*input_id) else {
+                    continue;
+                };
+                if let Some(packed) = op_node.operator.prepack(index, constant.as_input()) {
+                    prepacked.push((op_id, index, packed));
+                }
+            }
+        }
+
+        let n_prepacked = prepacked.len();
+        for (op_id, index, packed) in prepacked {
+            let Some(Node::Operator(op_node)) = self.nodes.get_mut(op_id) else {
+                unreachable!();
+            };
+            if op_node.prepacked.len() <= index {
+                op_node.prepacked.resize_with(index + 1, || None);
+            }
+            op_node.prepacked[index] = Some(packed);
+        }
+        n_prepacked
+    }
+
+    /// Remove nodes which are not needed to compute the graph's outputs.
+    ///
+    /// Graph inputs are always retained, as are all outputs of operators that
+    /// are retained. The remaining nodes keep their relative order, but their
+    /// IDs will change, so any IDs obtained before calling this method are
+    /// invalidated.
+    pub fn remove_unused_nodes(&mut self) {
+        let mut used = vec![false; self.nodes.len()];
+        let mut stack: Vec<NodeId> = self
+            .output_ids
+            .iter()
+            .chain(&self.input_ids)
+            .copied()
+            .collect();
+        while let Some(id) = stack.pop() {
+            if used[id] {
+                continue;
+            }
+            used[id] = true;
+
+            if let Some(&op_id) = self.source_ids.get(&id) {
+                stack.push(op_id);
+            }
+            if let Node::Operator(op_node) = &self.nodes[id] {
+                stack.extend(op_node.inputs.iter().flatten());
+                stack.extend(op_node.outputs.iter().flatten());
+            }
+        }
+
+        if used.iter().all(|used| *used) {
+            return;
+        }
+
+        let mut new_ids: Vec<Option<NodeId>> = Vec::with_capacity(self.nodes.len());
+        let mut next_id = 0;
+        for &used in &used {
+            new_ids.push(used.then_some(next_id));
+            next_id += used as usize;
+        }
+        let remap = |id: NodeId| new_ids[id].expect("used node references removed node");
+
+        let nodes = std::mem::take(&mut self.nodes);
+        self.source_ids.clear();
+        self.node_id_from_name.clear();
+        for (mut node, _) in nodes.into_iter().zip(&used).filter(|(_, used)| **used) {
+            if let Node::Operator(op_node) = &mut node {
+                for id in op_node
+                    .inputs
+                    .iter_mut()
+                    .chain(&mut op_node.outputs)
+                    .flatten()
+                {
+                    *id = remap(*id);
+                }
+                for output_id in op_node.outputs.iter().flatten() {
+                    self.source_ids.insert(*output_id, self.nodes.len());
+                }
+            }
+            self.add_node(node);
+        }
+
+        self.input_ids = self.input_ids.iter().map(|id| remap(*id)).collect();
+        self.output_ids = self.output_ids.iter().map(|id| remap(*id)).collect();
+        *self.cached_plan.lock().unwrap() = None;
+    }
+
     /// Return the total number of parameters in all constant nodes in the graph.
     pub fn total_params(&self) -> usize {
         self.nodes
@@ -841,15 +1008,25 @@ impl Graph {
             };
 
             // Run the operation.
+            //
+            // Prepacked inputs are not used for in-place runs, as their
+            // positions don't account for the removed in-place input.
             let op_result = if let Some(input) = in_place_input {
                 op_node
                     .operator
                     .run_in_place(&pool, input, InputList::from_optional(&op_inputs))
                     .map(|out| [out].into())
+            } else if let (Some(seed), false) = (opts.seed, op_node.operator.is_deterministic()) {
+                op_node.operator.run_with_seed(
+                    &pool,
+                    InputList::from_optional(&op_inputs).with_prepacked(&op_node.prepacked),
+                    op_seed(seed, op_node_id),
+                )
             } else {
-                op_node
-                    .operator
-                    .run(&pool, InputList::from_optional(&op_inputs))
+                op_node.operator.run(
+                    &pool,
+                    InputList::from_optional(&op_inputs).with_prepacked(&op_node.prepacked),
+                )
             };
             std::mem::drop(op_inputs);
 
@@ -1200,16 +1377,17 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::{expect_equal, expect_equal_with_tolerance};
     use rten_tensor::{Tensor, TensorView};
 
     use smallvec::smallvec;
 
     use super::CachedPlan;
-    use crate::graph::{Dimension, Graph, Node, RunError, TypedConstant};
+    use crate::graph::{Dimension, Graph, Node, RunError, RunOptions, TypedConstant};
     use crate::ops::{
-        Add, Concat, Conv, InputList, IntoOpResult, OpError, Operator, Output, OutputList, Relu,
-        Shape,
+        Add, Concat, Conv, ConvNhwc, Direction, Gemm, InputList, IntoOpResult, MatMul, OpError,
+        Operator, Output, OutputList, Padding, Relu, RnnOptions, Shape, LSTM,
     };
     use crate::tensor_pool::TensorPool;
 
@@ -1563,6 +1741,192 @@ mod tests {
         assert_eq!(g.total_params(), 200);
     }
 
+    #[test]
+    fn test_remove_unused_nodes() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+
+        let input_id = g.add_value(Some("input"), None);
+        let unused_input_id = g.add_value(Some("unused_input"), None);
+        g.add_constant(Some("unused_const"), Tensor::from(1.));
+        g.add_simple_op("unused_relu", Relu {}, &[input_id]);
+        let one = g.add_constant(Some("one"), Tensor::from(1.));
+        let (_, output_id) = g.add_simple_op("add", Add {}, &[input_id, one]);
+        g.set_input_ids(&[input_id, unused_input_id]);
+        g.set_output_ids(&[output_id]);
+
+        g.remove_unused_nodes();
+
+        // Operators and constants that don't contribute to outputs are
+        // removed. Graph inputs are retained even if unused.
+        for name in ["unused_const", "unused_relu", "unused_relu_out"] {
+            assert!(g.get_node_id(name).is_none());
+        }
+        assert_eq!(g.iter().count(), 5);
+
+        let input_id = g.get_node_id("input").unwrap();
+        assert_eq!(
+            g.input_ids(),
+            [input_id, g.get_node_id("unused_input").unwrap()]
+        );
+        assert_eq!(g.output_ids(), [g.get_node_id("add_out").unwrap()]);
+
+        let input = Tensor::from([1., 2., 3.]);
+        let results = g.run(vec![(input_id, input.view().into())], g.output_ids(), None)?;
+        expect_equal(
+            results[0].as_float_ref().unwrap(),
+            &Tensor::from([2., 3., 4.]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepack_weights() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            op: Box<dyn Operator + Send + Sync>,
+            input: Tensor,
+            weights: Vec<Tensor>,
+            n_outputs: usize,
+            n_prepacked: usize,
+        }
+
+        let mut rng = XorShiftRng::new(1234);
+        let conv = |groups| Conv {
+            groups,
+            dilations: vec![1, 1],
+            padding: Padding::Fixed(smallvec![1, 1, 1, 1]),
+            strides: vec![1, 1],
+        };
+
+        let cases = [
+            Case {
+                op: Box::new(MatMul {}),
+                input: Tensor::rand(&[2, 3, 4], &mut rng),
+                weights: vec![Tensor::rand(&[4, 5], &mut rng)],
+                n_outputs: 1,
+                n_prepacked: 1,
+            },
+            Case {
+                op: Box::new(Gemm {
+                    alpha: 0.5,
+                    beta: 1.,
+                    transpose_a: false,
+                    transpose_b: true,
+                }),
+                input: Tensor::rand(&[3, 4], &mut rng),
+                weights: vec![
+                    Tensor::rand(&[5, 4], &mut rng),
+                    Tensor::rand(&[5], &mut rng),
+                ],
+                n_outputs: 1,
+                n_prepacked: 1,
+            },
+            // Grouped convolution
+            Case {
+                op: Box::new(conv(2)),
+                input: Tensor::rand(&[2, 4, 5, 5], &mut rng),
+                weights: vec![Tensor::rand(&[6, 2, 3, 3], &mut rng)],
+                n_outputs: 1,
+                n_prepacked: 1,
+            },
+            // Depthwise convolutions are not computed using a GEMM, so the
+            // weights are not prepacked.
+            Case {
+                op: Box::new(conv(4)),
+                input: Tensor::rand(&[2, 4, 5, 5], &mut rng),
+                weights: vec![Tensor::rand(&[4, 1, 3, 3], &mut rng)],
+                n_outputs: 1,
+                n_prepacked: 0,
+            },
+            Case {
+                op: Box::new(ConvNhwc {
+                    conv: conv(1),
+                    activation: None,
+                }),
+                input: Tensor::rand(&[2, 5, 5, 4], &mut rng),
+                weights: vec![Tensor::rand(&[3, 3, 4, 6], &mut rng)],
+                n_outputs: 1,
+                n_prepacked: 1,
+            },
+            // Convolutions computed using the Winograd algorithm, where the
+            // transformed weights are prepacked.
+            Case {
+                op: Box::new(conv(1)),
+                input: Tensor::rand(&[1, 16, 6, 6], &mut rng),
+                weights: vec![Tensor::rand(&[16, 16, 3, 3], &mut rng)],
+                n_outputs: 1,
+                n_prepacked: 1,
+            },
+            Case {
+                op: Box::new(ConvNhwc {
+                    conv: conv(1),
+                    activation: None,
+                }),
+                input: Tensor::rand(&[1, 6, 6, 16], &mut rng),
+                weights: vec![Tensor::rand(&[3, 3, 16, 16], &mut rng)],
+                n_outputs: 1,
+                n_prepacked: 1,
+            },
+            Case {
+                op: Box::new(LSTM {
+                    direction: Direction::Bidirectional,
+                    hidden_size: 3,
+                    input_forget: false,
+                    options: RnnOptions::default(),
+                }),
+                input: Tensor::rand(&[4, 2, 5], &mut rng),
+                weights: vec![
+                    Tensor::rand(&[2, 12, 5], &mut rng),
+                    Tensor::rand(&[2, 12, 3], &mut rng),
+                ],
+                n_outputs: 3,
+                n_prepacked: 2,
+            },
+        ];
+
+        for Case {
+            op,
+            input,
+            weights,
+            n_outputs,
+            n_prepacked,
+        } in cases
+        {
+            let mut g = Graph::new();
+            let input_id = g.add_value(Some("input"), None);
+            let mut op_inputs = vec![Some(input_id)];
+            for weight in weights {
+                op_inputs.push(Some(g.add_constant(None, weight)));
+            }
+            let output_ids: Vec<_> = (0..n_outputs)
+                .map(|i| Some(g.add_value(Some(&format!("output_{}", i)), None)))
+                .collect();
+            let output_id = output_ids[0].unwrap();
+            let op_id = g.add_op(Some("op"), op, &op_inputs, &output_ids);
+
+            let run = |g: &Graph| {
+                g.run(vec![(input_id, input.view().into())], &[output_id], None)
+                    .map(|mut outputs| outputs.remove(0).into_float().unwrap())
+            };
+            let expected = run(&g)?;
+
+            assert_eq!(g.prepack_weights(), n_prepacked);
+            let actual = run(&g)?;
+            expect_equal_with_tolerance(&actual, &expected, 1e-5, 1e-5)?;
+
+            // Prepacked weights should be discarded if the input is replaced.
+            let Some(Node::Operator(op_node)) = g.get_node_mut(op_id) else {
+                panic!("operator node not found");
+            };
+            assert_eq!(op_node.is_prepacked(1), n_prepacked > 0);
+            let weight_id = op_node.input_ids()[1].unwrap();
+            op_node.replace_input(weight_id, input_id);
+            assert!(!op_node.is_prepacked(1));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_no_outputs() {
         let g = Graph::new();
@@ -1953,6 +2317,65 @@ mod tests {
         Ok(())
     }
 
+    /// Non-deterministic operator which outputs the seed it was run with, or
+    /// -1 if it was run without a seed.
+    #[derive(Debug)]
+    struct SeedOutput {}
+
+    impl Operator for SeedOutput {
+        fn name(&self) -> &str {
+            "SeedOutput"
+        }
+
+        fn is_deterministic(&self) -> bool {
+            false
+        }
+
+        fn run(&self, _pool: &TensorPool, _inputs: InputList) -> Result<OutputList, OpError> {
+            Ok([Tensor::from(-1).into()].into())
+        }
+
+        fn run_with_seed(
+            &self,
+            _pool: &TensorPool,
+            _inputs: InputList,
+            seed: u64,
+        ) -> Result<OutputList, OpError> {
+            Ok([Tensor::from((seed >> 33) as i32).into()].into())
+        }
+    }
+
+    #[test]
+    fn test_run_with_seed() -> Result<(), Box<dyn Error>> {
+        let mut g = Graph::new();
+        let (_, op_a_out) = g.add_simple_op("op_a", SeedOutput {}, &[]);
+        let (_, op_b_out) = g.add_simple_op("op_b", SeedOutput {}, &[]);
+
+        let run = |seed: Option<u64>| -> Result<Vec<i32>, RunError> {
+            let opts = RunOptions {
+                seed,
+                ..Default::default()
+            };
+            let outputs = g.run(vec![], &[op_a_out, op_b_out], Some(opts))?;
+            Ok(outputs
+                .into_iter()
+                .map(|output| output.into_int().unwrap().item().copied().unwrap())
+                .collect())
+        };
+
+        // Without a run seed, ops are run without a seed.
+        assert_eq!(run(None)?, [-1, -1]);
+
+        // With a run seed, each op gets a different seed derived from it, and
+        // the derived seeds are the same across runs.
+        let seeds = run(Some(1234))?;
+        assert_ne!(seeds[0], seeds[1]);
+        assert_eq!(run(Some(1234))?, seeds);
+        assert_ne!(run(Some(5678))?, seeds);
+
+        Ok(())
+    }
+
     #[test]
     fn test_cached_plan_matches() {
         let input_ids = &[3, 1, 2];
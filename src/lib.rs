@@ -104,7 +104,6 @@ mod model;
 mod model_metadata;
 mod number;
 mod op_registry;
-mod optimize;
 mod slice_reductions;
 mod tensor_pool;
 mod threading;
@@ -119,8 +118,10 @@ pub mod ctc;
 
 pub mod ops;
 
+pub mod optimize;
+
 pub use graph::{Dimension, NodeId, RunError, RunOptions};
-pub use model::{Model, ModelLoadError, ModelOptions, NodeInfo};
+pub use model::{InputShapes, Model, ModelLoadError, ModelOptions, NodeInfo};
 pub use model_metadata::ModelMetadata;
 pub use op_registry::{OpRegistry, ReadOp, ReadOpError};
 pub use ops::{FloatOperators, Input, InputOrOutput, Operators, Output};
@@ -11,7 +11,7 @@ use std::fs::File;
 #[cfg(feature = "mmap")]
 use memmap2::Mmap;
 
-use rten_tensor::Tensor;
+use rten_tensor::{Layout, Tensor};
 
 use crate::constant_storage::{ArcSlice, ArcTensorView, ConstantStorage};
 use crate::env::str_as_bool;
@@ -21,7 +21,7 @@ use crate::model_metadata::ModelMetadata;
 use crate::number::{LeBytes, Pod};
 use crate::op_registry::{OpRegistry, ReadOpError};
 use crate::ops::{InputOrOutput, Output};
-use crate::optimize::GraphOptimizer;
+use crate::optimize::{GraphOptimizer, OptimizePass, Rewrite};
 use crate::schema_generated as sg;
 use crate::schema_generated::root_as_model;
 use crate::timing::TimingSort;
@@ -100,6 +100,13 @@ use crate::timing::TimingSort;
 pub struct Model {
     graph: Graph,
     metadata: ModelMetadata,
+
+    /// True if the model was specialized for particular input shapes, in
+    /// which case inputs are checked against the expected shapes.
+    specialized: bool,
+
+    /// Rewrites applied by the optimizer when the model was loaded.
+    rewrites: Vec<Rewrite>,
 }
 
 /// Provides access to metadata about a graph node.
@@ -155,6 +162,91 @@ fn parse_timing_config(config: &str, opts: &mut RunOptions) {
 pub struct ModelOptions {
     registry: OpRegistry,
     optimize: bool,
+    input_shapes: Option<InputShapes>,
+    optimization_passes: Vec<Arc<dyn OptimizePass>>,
+    disabled_passes: Vec<String>,
+    prepack_weights: bool,
+}
+
+/// Concrete sizes for model inputs, used to specialize a model.
+///
+/// Sizes can be specified either for symbolic dimensions, which applies to all
+/// inputs that use the dimension name (eg. `batch_size`), or for the complete
+/// shape of a named input. See [`ModelOptions::specialize`].
+#[derive(Clone, Debug, Default)]
+pub struct InputShapes {
+    dims: HashMap<String, usize>,
+    shapes: HashMap<String, Vec<usize>>,
+}
+
+impl InputShapes {
+    pub fn new() -> InputShapes {
+        Self::default()
+    }
+
+    /// Set the size of a symbolic dimension.
+    pub fn dim(&mut self, name: &str, size: usize) -> &mut Self {
+        self.dims.insert(name.to_string(), size);
+        self
+    }
+
+    /// Set the full shape of a named input.
+    ///
+    /// This takes precedence over sizes specified for symbolic dimensions
+    /// using [`dim`](Self::dim).
+    pub fn input(&mut self, name: &str, shape: &[usize]) -> &mut Self {
+        self.shapes.insert(name.to_string(), shape.to_vec());
+        self
+    }
+
+    /// Return the specialized shape for an input with a given name and
+    /// expected shape.
+    ///
+    /// Returns an error if an explicitly specified shape is incompatible with
+    /// the input's expected shape.
+    fn resolve(
+        &self,
+        name: Option<&str>,
+        dims: Option<Vec<Dimension>>,
+    ) -> Result<Option<Vec<Dimension>>, String> {
+        let input_name = name.unwrap_or("(unnamed)");
+
+        if let Some(shape) = name.and_then(|name| self.shapes.get(name)) {
+            if let Some(dims) = &dims {
+                if dims.len() != shape.len() {
+                    return Err(format!(
+                        "input \"{}\" has {} dims but specialized shape has {}",
+                        input_name,
+                        dims.len(),
+                        shape.len()
+                    ));
+                }
+                for (dim, &size) in dims.iter().zip(shape) {
+                    if matches!(dim, Dimension::Fixed(fixed) if *fixed != size) {
+                        return Err(format!(
+                            "specialized shape {:?} is incompatible with shape of input \"{}\"",
+                            shape, input_name
+                        ));
+                    }
+                }
+            }
+            let fixed_dims = shape.iter().copied().map(Dimension::Fixed).collect();
+            return Ok(Some(fixed_dims));
+        }
+
+        let dims = dims.map(|dims| {
+            dims.into_iter()
+                .map(|dim| match dim {
+                    Dimension::Symbolic(ref name) => match self.dims.get(name) {
+                        Some(&size) => Dimension::Fixed(size),
+                        None => dim,
+                    },
+                    fixed => fixed,
+                })
+                .collect()
+        });
+        Ok(dims)
+    }
 }
 
 impl ModelOptions {
@@ -171,6 +263,10 @@ impl ModelOptions {
         ModelOptions {
             registry: ops,
             optimize: true,
+            input_shapes: None,
+            optimization_passes: Vec::new(),
+            disabled_passes: Vec::new(),
+            prepack_weights: false,
         }
     }
 
@@ -180,6 +276,55 @@ impl ModelOptions {
         self
     }
 
+    /// Add a custom graph optimization pass.
+    ///
+    /// Custom passes are run in the order they are added, after the passes
+    /// which simplify the graph and before the built-in operator fusions. This
+    /// allows custom fusions to match subgraphs that the built-in fusions would
+    /// otherwise replace. They are only run if optimization is enabled.
+    pub fn add_optimization_pass<P: OptimizePass + 'static>(&mut self, pass: P) -> &mut Self {
+        self.optimization_passes.push(Arc::new(pass));
+        self
+    }
+
+    /// Disable a built-in or custom optimization pass.
+    ///
+    /// This is useful to diagnose problems caused by a particular pass. The
+    /// names of built-in passes are returned by
+    /// [`GraphOptimizer::builtin_passes`]. Loading the model will fail if
+    /// `name` does not match any pass.
+    pub fn disable_optimization_pass(&mut self, name: &str) -> &mut Self {
+        self.disabled_passes.push(name.to_string());
+        self
+    }
+
+    /// Set whether constant weights are prepacked when the model is loaded.
+    ///
+    /// Operators such as `MatMul`, `Gemm`, `Conv` and `LSTM` normally pack
+    /// their weights into the layout used by the matrix multiplication
+    /// kernel each time they are run. Prepacking does this once at load time
+    /// instead, which speeds up inference at the cost of storing a packed
+    /// copy of the weights alongside the originals. This is disabled by
+    /// default.
+    pub fn prepack_weights(&mut self, enable: bool) -> &mut Self {
+        self.prepack_weights = enable;
+        self
+    }
+
+    /// Specialize the model for particular input sizes.
+    ///
+    /// The fixed sizes replace the symbolic dimensions in the shapes of
+    /// model inputs. This enables the optimizer to replace computations that
+    /// depend only on input shapes with constants, producing a model that is
+    /// faster to run but only accepts inputs of the given sizes. Running the
+    /// model with inputs whose shapes differ from those specified will fail.
+    ///
+    /// Shape computations are only folded if optimization is enabled.
+    pub fn specialize(&mut self, shapes: InputShapes) -> &mut Self {
+        self.input_shapes = Some(shapes);
+        self
+    }
+
     /// Load the model from a file. See [`Model::load_file`].
     pub fn load_file<P: AsRef<Path>>(&self, path: P) -> Result<Model, ModelLoadError> {
         let data = std::fs::read(path).map_err(ModelLoadError::ReadFailed)?;
@@ -300,12 +445,12 @@ impl Model {
         }
 
         let tensor_data_offset = header.as_ref().map(|h| h.tensor_data_offset);
-        let graph = Self::load_graph(
+        let (graph, rewrites) = Self::load_graph(
             model.graph(),
             registry,
             storage.clone(),
             tensor_data_offset,
-            options.optimize,
+            options,
         )?;
 
         let metadata = model
@@ -313,7 +458,12 @@ impl Model {
             .map(ModelMetadata::deserialize)
             .unwrap_or_default();
 
-        let model = Model { graph, metadata };
+        let model = Model {
+            graph,
+            metadata,
+            specialized: options.input_shapes.is_some(),
+            rewrites,
+        };
         Ok(model)
     }
 
@@ -322,8 +472,8 @@ impl Model {
         registry: &OpRegistry,
         storage: Arc<ConstantStorage>,
         tensor_data_offset: Option<u64>,
-        optimize: bool,
-    ) -> Result<Graph, ModelLoadError> {
+        options: &ModelOptions,
+    ) -> Result<(Graph, Vec<Rewrite>), ModelLoadError> {
         let node_count = serialized_graph.nodes().map(|ns| ns.len()).unwrap_or(0);
 
         // Map of model node index to graph node ID
@@ -370,14 +520,97 @@ impl Model {
             }
         }
 
-        if optimize {
-            let optimizer = GraphOptimizer::new();
+        if let Some(input_shapes) = &options.input_shapes {
+            Self::specialize_inputs(&mut graph, input_shapes)?;
+        }
+
+        let (mut graph, rewrites) = if options.optimize {
+            let mut optimizer = GraphOptimizer::new();
+            for pass in &options.optimization_passes {
+                optimizer.add_pass(pass.clone());
+            }
+            for name in &options.disabled_passes {
+                optimizer.disable_pass(name);
+            }
             optimizer
-                .optimize(graph)
-                .map_err(|err| ModelLoadError::OptimizeError(Box::new(err)))
+                .optimize_with_log(graph, options.input_shapes.is_some())
+                .map_err(|err| ModelLoadError::OptimizeError(Box::new(err)))?
         } else {
-            Ok(graph)
+            (graph, Vec::new())
+        };
+
+        // Prepacking is done last, as packed weights are discarded if an
+        // operator's inputs are replaced.
+        if options.prepack_weights {
+            graph.prepack_weights();
+        }
+
+        Ok((graph, rewrites))
+    }
+
+    /// Replace the shapes of graph inputs with the specialized shapes from
+    /// `input_shapes`.
+    fn specialize_inputs(
+        graph: &mut Graph,
+        input_shapes: &InputShapes,
+    ) -> Result<(), ModelLoadError> {
+        for name in input_shapes.shapes.keys() {
+            let is_input = graph
+                .get_node_id(name)
+                .is_some_and(|id| graph.input_ids().contains(&id));
+            if !is_input {
+                return Err(ModelLoadError::GraphError(format!(
+                    "specialized shape given for unknown input \"{}\"",
+                    name
+                )));
+            }
+        }
+
+        for input_id in graph.input_ids().to_vec() {
+            let Some(node @ Node::Value(_)) = graph.get_node(input_id) else {
+                continue;
+            };
+            let shape = input_shapes
+                .resolve(node.name(), node.shape())
+                .map_err(ModelLoadError::GraphError)?;
+            if let Some(Node::Value(value)) = graph.get_node_mut(input_id) {
+                value.set_shape(shape);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that inputs match the shapes the model was specialized for.
+    fn check_input_shapes(&self, inputs: &[(NodeId, InputOrOutput)]) -> Result<(), RunError> {
+        if !self.specialized {
+            return Ok(());
         }
+        for (id, input) in inputs {
+            let Some(dims) = self.graph.get_node(*id).and_then(|node| match node {
+                Node::Value(_) => node.shape(),
+                _ => None,
+            }) else {
+                continue;
+            };
+            let matches = dims.len() == input.ndim()
+                && dims
+                    .iter()
+                    .zip(input.shape())
+                    .all(|(dim, &size)| match dim {
+                        Dimension::Fixed(fixed) => *fixed == size,
+                        Dimension::Symbolic(_) => true,
+                    });
+            if !matches {
+                return Err(RunError::InputMismatch(format!(
+                    "input \"{}\" has shape {:?} but model was specialized for {:?}",
+                    self.graph.node_name(*id),
+                    input.shape(),
+                    dims
+                )));
+            }
+        }
+        Ok(())
     }
 
     fn add_graph_operator(
@@ -543,6 +776,14 @@ impl Model {
         &self.metadata
     }
 
+    /// Return the log of rewrites applied by graph optimizations when the
+    /// model was loaded.
+    ///
+    /// This is empty if optimization was disabled.
+    pub fn optimization_log(&self) -> &[Rewrite] {
+        &self.rewrites
+    }
+
     /// Return the IDs of input nodes.
     pub fn input_ids(&self) -> &[NodeId] {
         self.graph.input_ids()
@@ -586,6 +827,7 @@ impl Model {
             let timing_var = timing_var.to_string_lossy();
             parse_timing_config(&timing_var, &mut opts);
         }
+        self.check_input_shapes(&inputs)?;
         self.graph.run(inputs, outputs, Some(opts))
     }
 
@@ -640,6 +882,7 @@ impl Model {
         outputs: &[NodeId],
         opts: Option<RunOptions>,
     ) -> Result<Vec<(NodeId, Output)>, RunError> {
+        self.check_input_shapes(&inputs)?;
         self.graph.partial_run(inputs, outputs, opts)
     }
 }
@@ -787,13 +1030,16 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::Tensor;
 
-    use crate::graph::{Dimension, RunError};
-    use crate::model::{Model, ModelOptions};
+    use crate::graph::{Dimension, Node, RunError};
+    use crate::model::{InputShapes, Model, ModelOptions};
     use crate::model_builder::{GraphBuilder, MetadataArgs, ModelBuilder, ModelFormat, OpType};
     use crate::ops;
     use crate::ops::{
-        BoxOrder, CoordTransformMode, NearestMode, OpError, Output, ResizeMode, Scalar,
+        BoxOrder, CoordTransformMode, NearestMode, OpError, Output, ResizeMode, RoiAlignCoordMode,
+        RoiAlignMode, Scalar,
     };
+    use crate::optimize::pattern_matcher::{symbol, unary_op};
+    use crate::optimize::{PatternFusion, Rewrite};
     use crate::{ModelLoadError, OpRegistry, ReadOpError};
 
     fn generate_model_buffer(format: ModelFormat) -> Vec<u8> {
@@ -1091,6 +1337,251 @@ mod tests {
         );
     }
 
+    /// Generate a model which reshapes an input of shape `[batch, 4]` to
+    /// `[batch, -1]`, with the batch size computed from the input's shape.
+    fn generate_reshape_model() -> Vec<u8> {
+        let mut builder = ModelBuilder::new(ModelFormat::V2);
+        let mut graph_builder = builder.graph_builder();
+
+        let input_shape = [
+            Dimension::Symbolic("batch".to_string()),
+            Dimension::Fixed(4),
+        ];
+        let input_node = graph_builder.add_value("input", Some(&input_shape));
+        let output_node = graph_builder.add_value("output", None);
+        graph_builder.add_input(input_node);
+        graph_builder.add_output(output_node);
+
+        let shape_out = graph_builder.add_value("shape_out", None);
+        graph_builder.add_operator("shape", OpType::Shape, &[Some(input_node)], &[shape_out]);
+
+        let index = graph_builder.add_constant(Tensor::from(0).view());
+        let gather_out = graph_builder.add_value("gather_out", None);
+        graph_builder.add_operator(
+            "gather",
+            OpType::Gather(ops::Gather { axis: 0 }),
+            &[shape_out, index].map(Some),
+            &[gather_out],
+        );
+
+        let axes = graph_builder.add_constant(Tensor::from([0]).view());
+        let unsqueeze_out = graph_builder.add_value("unsqueeze_out", None);
+        graph_builder.add_operator(
+            "unsqueeze",
+            OpType::Unsqueeze,
+            &[gather_out, axes].map(Some),
+            &[unsqueeze_out],
+        );
+
+        let rest = graph_builder.add_constant(Tensor::from([-1]).view());
+        let concat_out = graph_builder.add_value("concat_out", None);
+        graph_builder.add_operator(
+            "concat",
+            OpType::Concat(ops::Concat { axis: 0 }),
+            &[unsqueeze_out, rest].map(Some),
+            &[concat_out],
+        );
+
+        graph_builder.add_operator(
+            "reshape",
+            OpType::Reshape(ops::Reshape { allow_zero: false }),
+            &[input_node, concat_out].map(Some),
+            &[output_node],
+        );
+
+        let graph = graph_builder.finish();
+        builder.set_graph(graph);
+        builder.finish()
+    }
+
+    #[test]
+    fn test_specialize() {
+        let mut shapes = InputShapes::new();
+        shapes.dim("batch", 2);
+        let model = ModelOptions::with_all_ops()
+            .specialize(shapes)
+            .load(generate_reshape_model())
+            .unwrap();
+
+        assert_eq!(
+            model.input_shape(0),
+            Some(vec![Dimension::Fixed(2), Dimension::Fixed(4)])
+        );
+
+        // The reshape's target shape should have been replaced with a constant.
+        let output_id = model.output_ids()[0];
+        let (_, reshape_op) = model.graph.get_source_node(output_id).unwrap();
+        let shape_id = reshape_op.input_ids()[1].unwrap();
+        assert!(matches!(
+            model.graph.get_node(shape_id),
+            Some(Node::Constant(_))
+        ));
+
+        let input: Tensor<f32> = Tensor::zeros(&[2, 4]);
+        let output = model.run_one(input.into(), None).unwrap();
+        assert_eq!(output.shape(), &[2, 4]);
+
+        // Inputs which don't match the specialized shape should be rejected.
+        let input: Tensor<f32> = Tensor::zeros(&[3, 4]);
+        let result = model.run_one(input.into(), None);
+        assert!(matches!(result, Err(RunError::InputMismatch(_))));
+    }
+
+    #[test]
+    fn test_specialize_input_shape() {
+        let mut shapes = InputShapes::new();
+        shapes.input("input", &[3, 4]);
+        let model = ModelOptions::with_all_ops()
+            .specialize(shapes)
+            .load(generate_reshape_model())
+            .unwrap();
+        assert_eq!(
+            model.input_shape(0),
+            Some(vec![Dimension::Fixed(3), Dimension::Fixed(4)])
+        );
+
+        // Shapes which are incompatible with the model are rejected.
+        for (name, shape) in [
+            ("input", [3, 5].as_slice()),
+            ("input", &[3]),
+            ("other", &[3]),
+        ] {
+            let mut shapes = InputShapes::new();
+            shapes.input(name, shape);
+            let result = ModelOptions::with_all_ops()
+                .specialize(shapes)
+                .load(generate_reshape_model());
+            assert!(matches!(result, Err(ModelLoadError::GraphError(_))));
+        }
+    }
+
+    /// Create a custom optimization pass for models created by
+    /// `generate_model_buffer`.
+    fn relu_to_leaky_relu() -> PatternFusion {
+        PatternFusion::new(
+            "leaky_relu",
+            unary_op("Relu", symbol("x")),
+            &["x"],
+            |_, _| Some(Box::new(ops::LeakyRelu { alpha: 0. })),
+        )
+    }
+
+    /// Return the operator type which produces the first output of a model.
+    fn output_op_name(model: &Model) -> String {
+        let (_, op) = model.graph.get_source_node(model.output_ids()[0]).unwrap();
+        op.operator().name().to_string()
+    }
+
+    #[test]
+    fn test_custom_optimization_pass() {
+        let model = ModelOptions::with_all_ops()
+            .add_optimization_pass(relu_to_leaky_relu())
+            .load(generate_model_buffer(ModelFormat::V2))
+            .unwrap();
+        assert_eq!(output_op_name(&model), "LeakyRelu");
+
+        let input_id = model.input_ids()[0];
+        let output_id = model.output_ids()[0];
+        let result = model
+            .run(
+                vec![(input_id, generate_input().into())],
+                &[output_id],
+                None,
+            )
+            .unwrap();
+        check_output(result);
+
+        // Custom passes are not run if optimization is disabled.
+        let model = ModelOptions::with_all_ops()
+            .add_optimization_pass(relu_to_leaky_relu())
+            .enable_optimization(false)
+            .load(generate_model_buffer(ModelFormat::V2))
+            .unwrap();
+        assert_eq!(output_op_name(&model), "Relu");
+    }
+
+    #[test]
+    fn test_optimization_log() {
+        let model = ModelOptions::with_all_ops()
+            .add_optimization_pass(relu_to_leaky_relu())
+            .load(generate_model_buffer(ModelFormat::V2))
+            .unwrap();
+        assert_eq!(
+            model.optimization_log(),
+            &[Rewrite {
+                pass: "leaky_relu".to_string(),
+                replaced_ops: vec!["relu".to_string()],
+                fused_op: Some("LeakyRelu".to_string()),
+            }]
+        );
+
+        // Optimization log should be empty if optimization is disabled.
+        let model = ModelOptions::with_all_ops()
+            .add_optimization_pass(relu_to_leaky_relu())
+            .enable_optimization(false)
+            .load(generate_model_buffer(ModelFormat::V2))
+            .unwrap();
+        assert!(model.optimization_log().is_empty());
+    }
+
+    #[test]
+    fn test_disable_optimization_pass() {
+        let model = ModelOptions::with_all_ops()
+            .add_optimization_pass(relu_to_leaky_relu())
+            .disable_optimization_pass("leaky_relu")
+            .load(generate_model_buffer(ModelFormat::V2))
+            .unwrap();
+        assert_eq!(output_op_name(&model), "Relu");
+        assert!(model.optimization_log().is_empty());
+
+        let result = ModelOptions::with_all_ops()
+            .disable_optimization_pass("no_such_pass")
+            .load(generate_model_buffer(ModelFormat::V2));
+        assert!(matches!(result, Err(ModelLoadError::OptimizeError(_))));
+    }
+
+    #[test]
+    fn test_prepack_weights() {
+        let mut builder = ModelBuilder::new(ModelFormat::V2);
+        let mut graph_builder = builder.graph_builder();
+
+        let weight = Tensor::from([[0.5, -1.], [2., 0.25], [-0.5, 1.5]]);
+        let weight_node = graph_builder.add_constant(weight.view());
+        let input_node = graph_builder.add_value("input", None);
+        let output_node = graph_builder.add_value("output", None);
+        graph_builder.add_input(input_node);
+        graph_builder.add_output(output_node);
+        graph_builder.add_operator(
+            "matmul",
+            OpType::MatMul,
+            &[Some(input_node), Some(weight_node)],
+            &[output_node],
+        );
+        let graph = graph_builder.finish();
+        builder.set_graph(graph);
+        let buffer = builder.finish();
+
+        let input = Tensor::from([[1., 2., 3.], [4., 5., 6.]]);
+        let expected = Tensor::from([[3., 4.], [9., 6.25]]);
+
+        for prepack in [false, true] {
+            let model = ModelOptions::with_all_ops()
+                .prepack_weights(prepack)
+                .load(buffer.clone())
+                .unwrap();
+
+            let (_, op) = model.graph.get_source_node(model.output_ids()[0]).unwrap();
+            assert_eq!(op.is_prepacked(1), prepack);
+
+            let result: Tensor<f32> = model
+                .run_one(input.view().into(), None)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
     // This test exercises basic execution of all operators. It doesn't check
     // the results of operators, it just makes sure they can be deserialized and
     // executed successfully.
@@ -1110,7 +1601,8 @@ mod tests {
         let kernel_val = Tensor::from_data(&[1, 1, 1, 1], vec![0.5]);
         let kernel = graph_builder.add_constant(kernel_val.view());
 
-        // Names of all operator output nodes.
+        // Names of all operator output nodes. These are marked as model
+        // outputs so they are not removed by the optimizer.
         let mut op_outputs = Vec::new();
 
         let mut add_operator =
@@ -1118,10 +1610,44 @@ mod tests {
                 let output_name = format!("{}_out", name);
                 let op_output_node = builder.add_value(&output_name, None);
                 builder.add_operator(name, op, input_nodes, &[op_output_node]);
+                builder.add_output(op_output_node);
                 op_outputs.push(output_name);
                 op_output_node
             };
 
+        // Names of output nodes of recurrent operators. These operators use
+        // constant inputs with a [seq, batch, input] shape.
+        let mut rnn_outputs = Vec::new();
+        let rnn_input_val = Tensor::full(&[2, 1, 3], 0.5);
+        let rnn_input = graph_builder.add_constant(rnn_input_val.view());
+        let rnn_seq_lens = graph_builder.add_constant(Tensor::from([2]).view());
+        let hidden_size = 2;
+
+        let mut add_rnn_operator = |builder: &mut GraphBuilder,
+                                    name: &str,
+                                    op: OpType,
+                                    [weights, recurrent_weights, bias]: [Tensor; 3],
+                                    n_outputs: usize| {
+            let weights = builder.add_constant(weights.view());
+            let recurrent_weights = builder.add_constant(recurrent_weights.view());
+            let bias = builder.add_constant(bias.view());
+            let outputs: Vec<_> = (0..n_outputs)
+                .map(|i| {
+                    let output_name = format!("{}_out_{}", name, i);
+                    let output = builder.add_value(&output_name, None);
+                    builder.add_output(output);
+                    rnn_outputs.push(output_name);
+                    output
+                })
+                .collect();
+            builder.add_operator(
+                name,
+                op,
+                &[rnn_input, weights, recurrent_weights, bias, rnn_seq_lens].map(Some),
+                &outputs,
+            );
+        };
+
         // Add a new operator node and associated output value node to the model.
         //
         // Returns the node ID of the output node.
@@ -1149,15 +1675,25 @@ mod tests {
         add_operator!(Acos, [input_node]);
         add_operator!(Add, [input_node, input_node]);
         add_operator!(And, [input_bool, input_bool]);
-        add_operator!(ArgMax, [input_node], { axis: 3, keep_dims: false });
-        add_operator!(ArgMin, [input_node], { axis: 3, keep_dims: false });
+        add_operator!(ArgMax, [input_node], {
+            axis: 3,
+            keep_dims: false,
+            select_last_index: false,
+        });
+        add_operator!(ArgMin, [input_node], {
+            axis: 3,
+            keep_dims: false,
+            select_last_index: true,
+        });
         add_operator!(Asin, [input_node]);
         add_operator!(Atan, [input_node]);
         add_operator!(AveragePool, [input_node], {
-            kernel_size: [2, 2],
-            strides: [2, 2],
+            kernel_size: [2, 2].into(),
+            strides: [2, 2].into(),
             padding: [0, 0, 0, 0].into(),
             count_include_pad: false,
+            dilations: [1, 1].into(),
+            ceil_mode: false,
         });
 
         // Dummy value for BatchNormalization inputs which are vectors with
@@ -1176,12 +1712,36 @@ mod tests {
             { epsilon: 1e-5 }
         );
 
+        add_operator!(Bernoulli, [input_node], {
+            dtype: ops::DataType::Float,
+            seed: None,
+        });
+        add_operator!(BitShift, [input_bool, input_bool], {
+            direction: ops::BitShiftDirection::Left
+        });
+        add_operator!(BitwiseAnd, [input_bool, input_bool]);
+        add_operator!(BitwiseNot, [input_bool]);
+        add_operator!(BitwiseOr, [input_bool, input_bool]);
+        add_operator!(BitwiseXor, [input_bool, input_bool]);
+
+        let window_size = graph_builder.add_constant(Tensor::from(4).view());
+        add_operator!(BlackmanWindow, [window_size], { periodic: true });
+
         add_operator!(Cast, [input_node], { to: ops::DataType::Float });
         add_operator!(Ceil, [input_node]);
+        add_operator!(Celu, [input_node], { alpha: 1.0 });
+
+        let crop_pad_shape = graph_builder.add_constant(Tensor::from([2, 4]).view());
+        add_operator!(CenterCropPad, [input_node, crop_pad_shape], {
+            axes: Some(vec![-2, -1]),
+        });
 
         let clip_min = graph_builder.add_constant(Tensor::from(1.).view());
         let clip_max = graph_builder.add_constant(Tensor::from(6.).view());
         add_operator!(Clip, [input_node, clip_min, clip_max]);
+
+        let compress_cond = graph_builder.add_constant(Tensor::from([1, 0, 1]).view());
+        add_operator!(Compress, [input_node, compress_cond], { axis: Some(-1) });
         add_operator!(Concat, [input_node, input_node], { axis: 0 });
 
         let shape = graph_builder.add_constant(Tensor::from([1, 5, 10]).view());
@@ -1195,10 +1755,24 @@ mod tests {
         });
 
         add_operator!(ConvTranspose, [input_node, kernel], {
+            dilations: vec![1, 1],
+            groups: 1,
             strides: vec![2, 2],
             padding: [0, 0, 0, 0].into(),
         });
         add_operator!(Cos, [input_node]);
+        add_operator!(DepthToSpace, [input_node], {
+            block_size: 1,
+            mode: ops::DepthToSpaceMode::DepthColumnRow,
+        });
+
+        let dft_signal_val = Tensor::<f32>::zeros(&[1, 8, 1]);
+        let dft_signal = graph_builder.add_constant(dft_signal_val.view());
+        add_operator!(DFT, [dft_signal], {
+            axis: 1,
+            inverse: false,
+            onesided: false,
+        });
         add_operator!(Div, [input_node, input_node]);
         add_operator!(Elu, [input_node], { alpha: 1.0 });
         add_operator!(Equal, [input_node, input_node]);
@@ -1228,15 +1802,35 @@ mod tests {
             transpose_b: false,
         });
         add_operator!(GlobalAveragePool, [input_node]);
+        add_operator!(GlobalLpPool, [input_node], { p: 2 });
+        add_operator!(GlobalMaxPool, [input_node]);
         add_operator!(Greater, [input_node, input_node]);
         add_operator!(GreaterOrEqual, [input_node, input_node]);
+        add_operator!(HammingWindow, [window_size], { periodic: true });
+        add_operator!(HannWindow, [window_size], { periodic: true });
         add_operator!(HardSigmoid, [input_node], {
             alpha: 0.2,
             beta: 0.5,
         });
         add_operator!(HardSwish, [input_node]);
-
-        // TODO - Add GRU operator
+        add_operator!(Hardmax, [input_node], { axis: -1, flatten: false });
+
+        add_rnn_operator(
+            &mut graph_builder,
+            "GRU",
+            OpType::GRU(ops::GRU {
+                direction: ops::Direction::Forward,
+                hidden_size,
+                linear_before_reset: true,
+                options: ops::RnnOptions::default(),
+            }),
+            [
+                Tensor::full(&[1, 3 * hidden_size, 3], 0.1),
+                Tensor::full(&[1, 3 * hidden_size, hidden_size], 0.1),
+                Tensor::full(&[1, 6 * hidden_size], 0.1),
+            ],
+            2,
+        );
 
         add_operator!(Identity, [input_node]);
 
@@ -1248,6 +1842,12 @@ mod tests {
             input_node, instance_norm_scale, instance_norm_bias
         ], { epsilon: Some(1e-5) });
 
+        add_operator!(IsInf, [input_node], {
+            detect_negative: true,
+            detect_positive: true,
+        });
+        add_operator!(IsNaN, [input_node]);
+
         let layer_norm_scale_val = Tensor::from([1.0]);
         let layer_norm_scale = graph_builder.add_constant(layer_norm_scale_val.view());
         let layer_norm_bias_val = Tensor::from([1.0]);
@@ -1260,23 +1860,88 @@ mod tests {
         add_operator!(Less, [input_node, input_node]);
         add_operator!(LessOrEqual, [input_node, input_node]);
         add_operator!(Log, [input_node]);
-        add_operator!(LogSoftmax, [input_node], { axis: 1 });
+        add_operator!(LogSoftmax, [input_node], { axis: 1, flatten: false });
+        add_operator!(LpPool, [input_node], {
+            kernel_size: [2, 2].into(),
+            strides: [1, 1].into(),
+            padding: [0, 0, 0, 0].into(),
+            dilations: [1, 1].into(),
+            ceil_mode: false,
+            p: 2,
+        });
 
-        // TODO - Add LSTM operator
+        add_rnn_operator(
+            &mut graph_builder,
+            "LSTM",
+            OpType::LSTM(ops::LSTM {
+                direction: ops::Direction::Bidirectional,
+                hidden_size,
+                input_forget: false,
+                options: ops::RnnOptions {
+                    activations: [
+                        ops::RnnActivation::Sigmoid,
+                        ops::RnnActivation::Tanh,
+                        ops::RnnActivation::Tanh,
+                    ]
+                    .into(),
+                    clip: Some(10.),
+                    batch_first: false,
+                },
+            }),
+            [
+                Tensor::full(&[2, 4 * hidden_size, 3], 0.1),
+                Tensor::full(&[2, 4 * hidden_size, hidden_size], 0.1),
+                Tensor::full(&[2, 8 * hidden_size], 0.1),
+            ],
+            3,
+        );
 
         add_operator!(MatMul, [input_2d, input_2d]);
         add_operator!(Max, [input_node, input_node]);
         add_operator!(MaxPool, [input_node], {
-            kernel_size: [2, 2],
-            strides: [2, 2],
+            kernel_size: [2, 2].into(),
+            strides: [2, 2].into(),
+            padding: [0, 0, 0, 0].into(),
+            dilations: [1, 1].into(),
+            ceil_mode: true,
+            return_indices: false,
+        });
+
+        let unpool_indices = graph_builder
+            .add_constant(Tensor::from_data(&[1, 1, 3, 3], (0..9).collect::<Vec<i32>>()).view());
+        add_operator!(MaxUnpool, [input_node, unpool_indices], {
+            kernel_size: [1, 1].into(),
+            strides: [1, 1].into(),
             padding: [0, 0, 0, 0].into(),
         });
         add_operator!(Mean, [input_node, input_node]);
+
+        let mel_num_bins = graph_builder.add_constant(Tensor::from(4).view());
+        let mel_dft_length = graph_builder.add_constant(Tensor::from(16).view());
+        let mel_sample_rate = graph_builder.add_constant(Tensor::from(8000).view());
+        let mel_lower_edge = graph_builder.add_constant(Tensor::from(0.).view());
+        let mel_upper_edge = graph_builder.add_constant(Tensor::from(4000.).view());
+        add_operator!(
+            MelWeightMatrix,
+            [
+                mel_num_bins,
+                mel_dft_length,
+                mel_sample_rate,
+                mel_lower_edge,
+                mel_upper_edge
+            ]
+        );
+
         add_operator!(Min, [input_node, input_node]);
+        add_operator!(Mish, [input_node]);
         add_operator!(Mod, [input_node, input_node], {
             fmod: false,
         });
         add_operator!(Mul, [input_node, input_node]);
+        add_operator!(Multinomial, [input_2d], {
+            sample_size: 2,
+            seed: None,
+        });
         add_operator!(Neg, [input_node]);
 
         let nms_n_boxes = 10;
@@ -1308,6 +1973,7 @@ mod tests {
         let pads = graph_builder.add_constant(Tensor::from([0, 0, 1, 1, 0, 0, 1, 1]).view());
         add_operator!(Pad, [input_node, pads]);
         add_operator!(Pow, [input_node, input_node]);
+        add_operator!(PRelu, [input_node, input_node]);
 
         add_operator!(RandomNormal, [], {
             shape: vec![50, 50],
@@ -1335,12 +2001,24 @@ mod tests {
         let range_start_node = graph_builder.add_value("range_start", None);
         let range_limit_node = graph_builder.add_value("range_limit", None);
         let range_delta_node = graph_builder.add_value("range_delta", None);
-        let range_out = add_operator!(
+        add_operator!(
             Range,
             [range_start_node, range_limit_node, range_delta_node]
         );
 
         add_operator!(Reciprocal, [input_node]);
+        add_operator!(ReduceL1, [input_node], {
+            axes: None,
+            keep_dims: false,
+        });
+        add_operator!(ReduceLogSum, [input_node], {
+            axes: None,
+            keep_dims: false,
+        });
+        add_operator!(ReduceLogSumExp, [input_node], {
+            axes: None,
+            keep_dims: false,
+        });
         add_operator!(ReduceMean, [input_node], {
             axes: None,
             keep_dims: false,
@@ -1382,9 +2060,68 @@ mod tests {
             coord_mode: CoordTransformMode::default()
         });
 
+        let reverse_seq_lens = graph_builder.add_constant(Tensor::from([1]).view());
+        add_operator!(ReverseSequence, [input_node, reverse_seq_lens], {
+            batch_axis: 1,
+            time_axis: 0,
+        });
+
+        add_operator!(RMSNormalization, [input_node, layer_norm_scale], {
+            axis: -1,
+            epsilon: Some(1e-5),
+        });
+
+        add_rnn_operator(
+            &mut graph_builder,
+            "RNN",
+            OpType::RNN(ops::RNN {
+                direction: ops::Direction::Forward,
+                hidden_size,
+                options: ops::RnnOptions {
+                    activations: [ops::RnnActivation::LeakyRelu { alpha: 0.01 }].into(),
+                    clip: None,
+                    batch_first: false,
+                },
+            }),
+            [
+                Tensor::full(&[1, hidden_size, 3], 0.1),
+                Tensor::full(&[1, hidden_size, hidden_size], 0.1),
+                Tensor::full(&[1, 2 * hidden_size], 0.1),
+            ],
+            2,
+        );
+
+        let roi_align_rois = graph_builder.add_constant(Tensor::from([[0., 0., 2., 2.]]).view());
+        let roi_align_batch_indices = graph_builder.add_constant(Tensor::from([0]).view());
+        add_operator!(RoiAlign, [input_node, roi_align_rois, roi_align_batch_indices], {
+            mode: RoiAlignMode::Avg,
+            coord_mode: RoiAlignCoordMode::HalfPixel,
+            output_height: 2,
+            output_width: 2,
+            sampling_ratio: None,
+            spatial_scale: 1.0
+        });
+
+        let rope_position_ids = graph_builder.add_constant(Tensor::from([[0, 1, 2]]).view());
+        let rope_cos = graph_builder.add_constant(Tensor::from([[1.], [0.5], [0.]]).view());
+        let rope_sin = graph_builder.add_constant(Tensor::from([[0.], [0.5], [1.]]).view());
+        add_operator!(RotaryEmbedding, [input_node, rope_position_ids, rope_cos, rope_sin], {
+            interleaved: false,
+            num_heads: None,
+            rotary_dim: Some(2),
+        });
+
         add_operator!(Round, [input_node]);
 
+        add_operator!(Selu, [input_node], {
+            alpha: 1.67326,
+            gamma: 1.0507,
+        });
         add_operator!(Shape, [input_node]);
+        add_operator!(Shrink, [input_node], {
+            bias: 0.,
+            lambd: 0.5,
+        });
         add_operator!(Sigmoid, [input_node]);
         add_operator!(Sign, [input_node]);
         add_operator!(Sin, [input_node]);
@@ -1405,24 +2142,39 @@ mod tests {
         add_operator!(Slice, [input_node, const_0, const_1, const_0]);
 
         add_operator!(Softplus, [input_node]);
-        add_operator!(Softmax, [input_node], { axis: 1 });
+        add_operator!(Softsign, [input_node]);
+        add_operator!(Softmax, [input_node], { axis: 1, flatten: true });
+        add_operator!(SpaceToDepth, [input_node], { block_size: 1 });
         add_operator!(Sqrt, [input_node]);
         add_operator!(Squeeze, [input_node]);
 
+        let stft_frame_step = graph_builder.add_constant(Tensor::from(2).view());
+        let stft_window_val = Tensor::from([1., 1., 1., 1.]);
+        let stft_window = graph_builder.add_constant(stft_window_val.view());
+        add_operator!(STFT, [dft_signal, stft_frame_step, stft_window], {
+            onesided: true,
+        });
+
         let split_splits = graph_builder.add_constant(Tensor::from([1, 2]).view());
         let split_out_1 = graph_builder.add_value("Split_out_1", None);
         let split_out_2 = graph_builder.add_value("Split_out_2", None);
         graph_builder.add_operator(
             "Split",
-            OpType::Split(ops::Split { axis: 1 }),
+            OpType::Split(ops::Split {
+                axis: 1,
+                num_outputs: None,
+            }),
             &[input_2d, split_splits].map(Some),
             &[split_out_1, split_out_2],
         );
+        graph_builder.add_output(split_out_1);
+        graph_builder.add_output(split_out_2);
 
         add_operator!(Sub, [input_node, input_node]);
         add_operator!(Sum, [input_node, input_node]);
         add_operator!(Tan, [input_node]);
         add_operator!(Tanh, [input_node]);
+        add_operator!(ThresholdedRelu, [input_node], { alpha: 1.0 });
 
         let tile_repeats = graph_builder.add_constant(Tensor::from([1, 2, 3, 4]).view());
         add_operator!(Tile, [input_node, tile_repeats]);
@@ -1440,18 +2192,47 @@ mod tests {
             &[input_2d, topk_k].map(Some),
             &[topk_out_values, topk_out_indices],
         );
+        graph_builder.add_output(topk_out_values);
+        graph_builder.add_output(topk_out_indices);
 
         add_operator!(Transpose, [input_node], { perm: None });
 
         add_operator!(Trilu, [input_node], { upper: true });
 
+        let unique_out_values = graph_builder.add_value("Unique_out_values", None);
+        let unique_out_indices = graph_builder.add_value("Unique_out_indices", None);
+        let unique_out_inverse = graph_builder.add_value("Unique_out_inverse_indices", None);
+        let unique_out_counts = graph_builder.add_value("Unique_out_counts", None);
+        graph_builder.add_operator(
+            "Unique",
+            OpType::Unique(ops::Unique {
+                axis: Some(0),
+                sorted: true,
+            }),
+            &[input_2d].map(Some),
+            &[
+                unique_out_values,
+                unique_out_indices,
+                unique_out_inverse,
+                unique_out_counts,
+            ],
+        );
+        for output in [
+            unique_out_values,
+            unique_out_indices,
+            unique_out_inverse,
+            unique_out_counts,
+        ] {
+            graph_builder.add_output(output);
+        }
+
         let unsqueeze_axes = graph_builder.add_constant(Tensor::from([0, 4]).view());
         add_operator!(Unsqueeze, [input_node, unsqueeze_axes]);
 
         let where_cond = graph_builder.add_value("where_cond", None);
         let where_x = graph_builder.add_value("where_x", None);
         let where_y = graph_builder.add_value("where_y", None);
-        let where_out = add_operator!(Where, [where_cond, where_x, where_y]);
+        add_operator!(Where, [where_cond, where_x, where_y]);
 
         add_operator!(Xor, [input_bool, input_bool]);
 
@@ -1473,6 +2254,7 @@ mod tests {
             if [
                 "Gemm_out",
                 "MatMul_out",
+                "Multinomial_out",
                 "Range_out",
                 "Split_out_1",
                 "Split_out_2",
@@ -1490,8 +2272,11 @@ mod tests {
             let result = model
                 .run(
                     vec![
-                        (input_node as usize, input.view().into()),
-                        (input_bool as usize, input_bool_data.view().into()),
+                        (model.find_node("input").unwrap(), input.view().into()),
+                        (
+                            model.find_node("input.bool").unwrap(),
+                            input_bool_data.view().into(),
+                        ),
                     ],
                     &[output_id],
                     None,
@@ -1504,10 +2289,15 @@ mod tests {
         let outputs = vec![
             "Gemm_out",
             "MatMul_out",
+            "Multinomial_out",
             "Split_out_1",
             "Split_out_2",
             "TopK_out_indices",
             "TopK_out_values",
+            "Unique_out_counts",
+            "Unique_out_indices",
+            "Unique_out_inverse_indices",
+            "Unique_out_values",
         ];
         let input = Tensor::from_data(&[3, 3], vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
 
@@ -1515,7 +2305,7 @@ mod tests {
             let output_id = model.find_node(output).unwrap();
             let result = model
                 .run(
-                    vec![(input_2d as usize, input.view().into())],
+                    vec![(model.find_node("input.2d").unwrap(), input.view().into())],
                     &[output_id],
                     None,
                 )
@@ -1523,6 +2313,13 @@ mod tests {
             assert_eq!(result.len(), 1);
         }
 
+        // Outputs of recurrent ops, which only have constant inputs.
+        for output in rnn_outputs {
+            let output_id = model.find_node(&output).unwrap();
+            let result = model.run(vec![], &[output_id], None).unwrap();
+            assert_eq!(result.len(), 1);
+        }
+
         // Range op
         let start = Tensor::from(0.);
         let limit = Tensor::from(5.);
@@ -1530,11 +2327,11 @@ mod tests {
         let result = model
             .run(
                 vec![
-                    (range_start_node as usize, start.into()),
-                    (range_limit_node as usize, limit.into()),
-                    (range_delta_node as usize, delta.into()),
+                    (model.find_node("range_start").unwrap(), start.into()),
+                    (model.find_node("range_limit").unwrap(), limit.into()),
+                    (model.find_node("range_delta").unwrap(), delta.into()),
                 ],
-                &[range_out as usize],
+                &[model.find_node("Range_out").unwrap()],
                 None,
             )
             .unwrap();
@@ -1547,11 +2344,11 @@ mod tests {
         let result = model
             .run(
                 vec![
-                    (where_cond as usize, cond.into()),
-                    (where_x as usize, x.into()),
-                    (where_y as usize, y.into()),
+                    (model.find_node("where_cond").unwrap(), cond.into()),
+                    (model.find_node("where_x").unwrap(), x.into()),
+                    (model.find_node("where_y").unwrap(), y.into()),
                 ],
-                &[where_out as usize],
+                &[model.find_node("Where_out").unwrap()],
                 None,
             )
             .unwrap();
@@ -1,4 +1,4 @@
-use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, Vector, WIPOffset};
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, UnionWIPOffset, Vector, WIPOffset};
 use rten_tensor::prelude::*;
 use rten_tensor::TensorView;
 
@@ -6,17 +6,24 @@ use crate::graph::Dimension;
 use crate::header::Header;
 use crate::number::LeBytes;
 use crate::ops::{
-    ArgMax, ArgMin, AveragePool, BatchNormalization, BoxOrder, Cast, Concat, ConstantOfShape, Conv,
-    ConvTranspose, CoordTransformMode, DataType, Einsum, Elu, Flatten, Gather, GatherElements,
-    GatherND, Gelu, Gemm, HardSigmoid, InstanceNormalization, LayerNormalization, LeakyRelu,
-    LogSoftmax, MaxPool, Mod, NearestMode, NonMaxSuppression, OneHot, Padding, ReduceMax,
-    ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, Reshape, Resize, ResizeMode,
-    Scalar, ScatterElements, ScatterReduction, Softmax, Split, TopK, Transpose, Trilu,
+    ArgMax, ArgMin, AveragePool, BatchNormalization, BitShift, BitShiftDirection, BlackmanWindow,
+    BoxOrder, Cast, Celu, CenterCropPad, Compress, Concat, ConstantOfShape, Conv, ConvTranspose,
+    CoordTransformMode, DataType, DepthToSpace, DepthToSpaceMode, Direction, Einsum, Elu, Flatten,
+    Gather, GatherElements, GatherND, Gelu, Gemm, GlobalLpPool, HammingWindow, HannWindow,
+    HardSigmoid, Hardmax, InstanceNormalization, IsInf, LayerNormalization, LeakyRelu, LogSoftmax,
+    LpPool, MaxPool, MaxUnpool, Mod, NearestMode, NonMaxSuppression, OneHot, Padding,
+    RMSNormalization, ReduceL1, ReduceLogSum, ReduceLogSumExp, ReduceMax, ReduceMean, ReduceMin,
+    ReduceProd, ReduceSum, ReduceSumSquare, Reshape, Resize, ResizeMode, ReverseSequence,
+    RnnActivation, RnnOptions, RoiAlign, RoiAlignCoordMode, RoiAlignMode, RotaryEmbedding, Scalar,
+    ScatterElements, ScatterReduction, Selu, Shrink, Softmax, SpaceToDepth, Split, ThresholdedRelu,
+    TopK, Transpose, Trilu, Unique, DFT, GRU, LSTM, RNN, STFT,
 };
 use crate::schema_generated as sg;
 
 #[cfg(feature = "random")]
-use crate::ops::{RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
+use crate::ops::{
+    Bernoulli, Multinomial, RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike,
+};
 
 /// Enum of all the built-in operators
 pub enum OpType {
@@ -30,14 +37,27 @@ pub enum OpType {
     Atan,
     AveragePool(AveragePool),
     BatchNormalization(BatchNormalization),
+    #[cfg(feature = "random")]
+    Bernoulli(Bernoulli),
+    BitShift(BitShift),
+    BitwiseAnd,
+    BitwiseNot,
+    BitwiseOr,
+    BitwiseXor,
+    BlackmanWindow(BlackmanWindow),
     Cast(Cast),
     Ceil,
+    Celu(Celu),
+    CenterCropPad(CenterCropPad),
     Clip,
+    Compress(Compress),
     Concat(Concat),
     ConstantOfShape(ConstantOfShape),
     Conv(Conv),
     ConvTranspose(ConvTranspose),
     Cos,
+    DepthToSpace(DepthToSpace),
+    DFT(DFT),
     Div,
     Einsum(Einsum),
     Elu(Elu),
@@ -53,25 +73,40 @@ pub enum OpType {
     Gelu(Gelu),
     Gemm(Gemm),
     GlobalAveragePool,
+    GlobalLpPool(GlobalLpPool),
+    GlobalMaxPool,
     Greater,
     GreaterOrEqual,
+    GRU(GRU),
+    HammingWindow(HammingWindow),
+    HannWindow(HannWindow),
     HardSigmoid(HardSigmoid),
     HardSwish,
+    Hardmax(Hardmax),
     Identity,
     InstanceNormalization(InstanceNormalization),
+    IsInf(IsInf),
+    IsNaN,
     LayerNormalization(LayerNormalization),
     LeakyRelu(LeakyRelu),
     Less,
     LessOrEqual,
     Log,
     LogSoftmax(LogSoftmax),
+    LpPool(LpPool),
+    LSTM(LSTM),
     MatMul,
     Max,
     MaxPool(MaxPool),
+    MaxUnpool(MaxUnpool),
     Mean,
+    MelWeightMatrix,
     Min,
+    Mish,
     Mod(Mod),
     Mul,
+    #[cfg(feature = "random")]
+    Multinomial(Multinomial),
     Neg,
     NonMaxSuppression(NonMaxSuppression),
     NonZero,
@@ -80,6 +115,7 @@ pub enum OpType {
     Or,
     Pad,
     Pow,
+    PRelu,
 
     #[cfg(feature = "random")]
     RandomNormal(RandomNormal),
@@ -92,6 +128,9 @@ pub enum OpType {
 
     Range,
     Reciprocal,
+    ReduceL1(ReduceL1),
+    ReduceLogSum(ReduceLogSum),
+    ReduceLogSumExp(ReduceLogSumExp),
     ReduceMax(ReduceMax),
     ReduceMean(ReduceMean),
     ReduceMin(ReduceMin),
@@ -101,9 +140,16 @@ pub enum OpType {
     Relu,
     Reshape(Reshape),
     Resize(Resize),
+    ReverseSequence(ReverseSequence),
+    RMSNormalization(RMSNormalization),
+    RNN(RNN),
+    RoiAlign(RoiAlign),
+    RotaryEmbedding(RotaryEmbedding),
     Round,
     ScatterElements(ScatterElements),
+    Selu(Selu),
     Shape,
+    Shrink(Shrink),
     Sigmoid,
     Sign,
     Sin,
@@ -111,17 +157,22 @@ pub enum OpType {
     Slice,
     Softmax(Softmax),
     Softplus,
+    Softsign,
+    SpaceToDepth(SpaceToDepth),
     Split(Split),
     Sqrt,
     Squeeze,
+    STFT(STFT),
     Sub,
     Sum,
     Tan,
     Tanh,
+    ThresholdedRelu(ThresholdedRelu),
     Tile,
     TopK(TopK),
     Transpose(Transpose),
     Trilu(Trilu),
+    Unique(Unique),
     Unsqueeze,
     Where,
     Xor,
@@ -207,6 +258,14 @@ fn pad_args_from_padding(padding: Padding) -> PadArgs {
     }
 }
 
+fn rnn_direction(direction: Direction) -> sg::RNNDirection {
+    match direction {
+        Direction::Forward => sg::RNNDirection::Forward,
+        Direction::Reverse => sg::RNNDirection::Reverse,
+        Direction::Bidirectional => sg::RNNDirection::Bidirectional,
+    }
+}
+
 /// Builder for serializing a graph or subgraph to FlatBuffers.
 pub struct GraphBuilder<'mb, 'a> {
     builder: &'mb mut FlatBufferBuilder<'a>,
@@ -369,12 +428,14 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 sg::ArgMaxAttrsArgs {
                     axis: args.axis as i32,
                     keep_dims: args.keep_dims,
+                    select_last_index: args.select_last_index,
                 }
             }),
             OpType::ArgMin(args) => op_with_attrs!(ArgMin, ArgMaxAttrs, {
                 sg::ArgMaxAttrsArgs {
                     axis: args.axis as i32,
                     keep_dims: args.keep_dims,
+                    select_last_index: args.select_last_index,
                 }
             }),
             OpType::Asin => op!(Asin),
@@ -382,14 +443,17 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             OpType::AveragePool(args) => op_with_attrs!(AveragePool, AveragePoolAttrs, {
                 let pad_args = pad_args_from_padding(args.padding);
                 let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let kernel_size = self.create_vec(Some(args.kernel_size.into()), |sz| sz as u32);
-                let strides = self.create_vec(Some(args.strides.into()), |s| s as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size.to_vec()), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides.to_vec()), |s| s as u32);
+                let dilations = self.create_vec(Some(args.dilations.to_vec()), |d| d as u32);
                 sg::AveragePoolAttrsArgs {
                     kernel_size,
                     auto_pad: pad_args.auto_pad,
                     pads,
                     strides,
                     count_include_pad: args.count_include_pad,
+                    ceil_mode: args.ceil_mode,
+                    dilations,
                 }
             }),
             OpType::BatchNormalization(args) => op_with_attrs!(
@@ -399,6 +463,38 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     epsilon: args.epsilon
                 }
             ),
+            #[cfg(feature = "random")]
+            OpType::Bernoulli(args) => op_with_attrs!(Bernoulli, BernoulliAttrs, {
+                let dtype = match args.dtype {
+                    DataType::Int32 => sg::DataType::Int32,
+                    DataType::Float => sg::DataType::Float,
+                };
+                sg::BernoulliAttrsArgs {
+                    dtype,
+                    seed: args.seed,
+                }
+            }),
+            OpType::BlackmanWindow(args) => op_with_attrs!(
+                BlackmanWindow,
+                WindowAttrs,
+                sg::WindowAttrsArgs {
+                    periodic: args.periodic
+                }
+            ),
+            OpType::BitShift(args) => op_with_attrs!(
+                BitShift,
+                BitShiftAttrs,
+                sg::BitShiftAttrsArgs {
+                    direction: match args.direction {
+                        BitShiftDirection::Left => sg::BitShiftDirection::Left,
+                        BitShiftDirection::Right => sg::BitShiftDirection::Right,
+                    },
+                }
+            ),
+            OpType::BitwiseAnd => op!(BitwiseAnd),
+            OpType::BitwiseNot => op!(BitwiseNot),
+            OpType::BitwiseOr => op!(BitwiseOr),
+            OpType::BitwiseXor => op!(BitwiseXor),
             OpType::Cast(args) => op_with_attrs!(
                 Cast,
                 CastAttrs,
@@ -410,7 +506,21 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 }
             ),
             OpType::Ceil => op!(Ceil),
+            OpType::Celu(args) => {
+                op_with_attrs!(Celu, EluAttrs, sg::EluAttrsArgs { alpha: args.alpha })
+            }
+            OpType::CenterCropPad(args) => op_with_attrs!(CenterCropPad, CenterCropPadAttrs, {
+                let axes = self.create_vec(args.axes, |axis| axis);
+                sg::CenterCropPadAttrsArgs { axes }
+            }),
             OpType::Clip => op!(Clip),
+            OpType::Compress(args) => op_with_attrs!(
+                Compress,
+                CompressAttrs,
+                sg::CompressAttrsArgs {
+                    axis: args.axis.map(|axis| axis as i32),
+                }
+            ),
             OpType::Concat(args) => op_with_attrs!(
                 Concat,
                 ConcatAttrs,
@@ -461,14 +571,36 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             OpType::ConvTranspose(args) => op_with_attrs!(ConvTranspose, ConvTransposeAttrs, {
                 let pad_args = pad_args_from_padding(args.padding);
                 let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
+                let dilations = self.create_vec(Some(args.dilations), |d| d as u32);
                 let strides = self.create_vec(Some(args.strides), |s| s as u32);
                 sg::ConvTransposeAttrsArgs {
                     strides,
                     auto_pad: pad_args.auto_pad,
                     pads,
+                    groups: args.groups as u32,
+                    dilations,
                 }
             }),
             OpType::Cos => op!(Cos),
+            OpType::DepthToSpace(args) => op_with_attrs!(DepthToSpace, DepthToSpaceAttrs, {
+                let mode = match args.mode {
+                    DepthToSpaceMode::DepthColumnRow => sg::DepthToSpaceMode::DCR,
+                    DepthToSpaceMode::ColumnRowDepth => sg::DepthToSpaceMode::CRD,
+                };
+                sg::DepthToSpaceAttrsArgs {
+                    mode,
+                    block_size: args.block_size as u32,
+                }
+            }),
+            OpType::DFT(args) => op_with_attrs!(
+                DFT,
+                DFTAttrs,
+                sg::DFTAttrsArgs {
+                    axis: args.axis as i32,
+                    inverse: args.inverse,
+                    onesided: args.onesided,
+                }
+            ),
             OpType::Div => op!(Div),
             OpType::Einsum(args) => {
                 let equation = self.builder.create_string(&args.equation);
@@ -528,8 +660,39 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 }
             ),
             OpType::GlobalAveragePool => op!(GlobalAveragePool),
+            OpType::GlobalLpPool(args) => op_with_attrs!(
+                GlobalLpPool,
+                GlobalLpPoolAttrs,
+                sg::GlobalLpPoolAttrsArgs { p: args.p as u32 }
+            ),
+            OpType::GlobalMaxPool => op!(GlobalMaxPool),
             OpType::Greater => op!(Greater),
             OpType::GreaterOrEqual => op!(GreaterOrEqual),
+            OpType::GRU(args) => op_with_attrs!(GRU, GRUAttrs, {
+                let activations = self.create_rnn_activations(&args.options);
+                sg::GRUAttrsArgs {
+                    direction: rnn_direction(args.direction),
+                    hidden_size: args.hidden_size as u32,
+                    linear_before_reset: args.linear_before_reset,
+                    activations,
+                    clip: args.options.clip,
+                    batch_first: args.options.batch_first,
+                }
+            }),
+            OpType::HammingWindow(args) => op_with_attrs!(
+                HammingWindow,
+                WindowAttrs,
+                sg::WindowAttrsArgs {
+                    periodic: args.periodic
+                }
+            ),
+            OpType::HannWindow(args) => op_with_attrs!(
+                HannWindow,
+                WindowAttrs,
+                sg::WindowAttrsArgs {
+                    periodic: args.periodic
+                }
+            ),
             OpType::HardSigmoid(args) => op_with_attrs!(
                 HardSigmoid,
                 HardSigmoidAttrs,
@@ -539,6 +702,14 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 }
             ),
             OpType::HardSwish => op!(HardSwish),
+            OpType::Hardmax(args) => op_with_attrs!(
+                Hardmax,
+                SoftmaxAttrs,
+                sg::SoftmaxAttrsArgs {
+                    axis: args.axis as i32,
+                    flatten: args.flatten,
+                }
+            ),
             OpType::Identity => op!(Identity),
             OpType::InstanceNormalization(args) => op_with_attrs!(
                 InstanceNormalization,
@@ -547,6 +718,15 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     epsilon: args.epsilon.unwrap_or(1e-5)
                 }
             ),
+            OpType::IsInf(args) => op_with_attrs!(
+                IsInf,
+                IsInfAttrs,
+                sg::IsInfAttrsArgs {
+                    detect_negative: args.detect_negative,
+                    detect_positive: args.detect_positive,
+                }
+            ),
+            OpType::IsNaN => op!(IsNaN),
             OpType::LayerNormalization(args) => op_with_attrs!(
                 LayerNormalization,
                 LayerNormalizationAttrs,
@@ -568,28 +748,82 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 SoftmaxAttrs,
                 sg::SoftmaxAttrsArgs {
                     axis: args.axis as i32,
+                    flatten: args.flatten,
                 }
             ),
+            OpType::LpPool(args) => op_with_attrs!(LpPool, LpPoolAttrs, {
+                let pad_args = pad_args_from_padding(args.padding);
+                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size.to_vec()), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides.to_vec()), |s| s as u32);
+                let dilations = self.create_vec(Some(args.dilations.to_vec()), |d| d as u32);
+                sg::LpPoolAttrsArgs {
+                    kernel_size,
+                    auto_pad: pad_args.auto_pad,
+                    pads,
+                    strides,
+                    dilations,
+                    ceil_mode: args.ceil_mode,
+                    p: args.p as u32,
+                }
+            }),
+            OpType::LSTM(args) => op_with_attrs!(LSTM, LSTMAttrs, {
+                let activations = self.create_rnn_activations(&args.options);
+                sg::LSTMAttrsArgs {
+                    direction: rnn_direction(args.direction),
+                    hidden_size: args.hidden_size as u32,
+                    activations,
+                    clip: args.options.clip,
+                    batch_first: args.options.batch_first,
+                    input_forget: args.input_forget,
+                }
+            }),
             OpType::MatMul => op!(MatMul),
             OpType::Max => op!(Max),
             OpType::MaxPool(args) => op_with_attrs!(MaxPool, MaxPoolAttrs, {
                 let pad_args = pad_args_from_padding(args.padding);
                 let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
-                let kernel_size = self.create_vec(Some(args.kernel_size.into()), |sz| sz as u32);
-                let strides = self.create_vec(Some(args.strides.into()), |s| s as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size.to_vec()), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides.to_vec()), |s| s as u32);
+                let dilations = self.create_vec(Some(args.dilations.to_vec()), |d| d as u32);
                 sg::MaxPoolAttrsArgs {
                     kernel_size,
                     auto_pad: pad_args.auto_pad,
                     pads,
                     strides,
+                    ceil_mode: args.ceil_mode,
+                    dilations,
+                    return_indices: args.return_indices,
+                }
+            }),
+            OpType::MaxUnpool(args) => op_with_attrs!(MaxUnpool, MaxUnpoolAttrs, {
+                let pad_args = pad_args_from_padding(args.padding);
+                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size.to_vec()), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides.to_vec()), |s| s as u32);
+                sg::MaxUnpoolAttrsArgs {
+                    kernel_size,
+                    pads,
+                    strides,
                 }
             }),
             OpType::Mean => op!(Mean),
+            OpType::MelWeightMatrix => op!(MelWeightMatrix),
             OpType::Min => op!(Min),
+            OpType::Mish => op!(Mish),
             OpType::Mod(args) => {
                 op_with_attrs!(Mod, ModAttrs, sg::ModAttrsArgs { fmod: args.fmod })
             }
             OpType::Mul => op!(Mul),
+            #[cfg(feature = "random")]
+            OpType::Multinomial(args) => op_with_attrs!(
+                Multinomial,
+                MultinomialAttrs,
+                sg::MultinomialAttrsArgs {
+                    sample_size: args.sample_size as u32,
+                    seed: args.seed,
+                }
+            ),
             OpType::Neg => op!(Neg),
             OpType::NonMaxSuppression(args) => {
                 op_with_attrs!(
@@ -617,6 +851,7 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             }
             OpType::Pad => op!(Pad),
             OpType::Pow => op!(Pow),
+            OpType::PRelu => op!(PRelu),
 
             #[cfg(feature = "random")]
             OpType::RandomNormal(args) => {
@@ -668,6 +903,15 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
 
             OpType::Range => op!(Range),
             OpType::Reciprocal => op!(Reciprocal),
+            OpType::ReduceL1(args) => {
+                op_with_attrs!(ReduceL1, ReduceMeanAttrs, reduce_attrs!(args))
+            }
+            OpType::ReduceLogSum(args) => {
+                op_with_attrs!(ReduceLogSum, ReduceMeanAttrs, reduce_attrs!(args))
+            }
+            OpType::ReduceLogSumExp(args) => {
+                op_with_attrs!(ReduceLogSumExp, ReduceMeanAttrs, reduce_attrs!(args))
+            }
             OpType::ReduceMax(args) => {
                 op_with_attrs!(ReduceMax, ReduceMeanAttrs, reduce_attrs!(args))
             }
@@ -714,6 +958,59 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     nearest_mode,
                 }
             }),
+            OpType::ReverseSequence(args) => op_with_attrs!(
+                ReverseSequence,
+                ReverseSequenceAttrs,
+                sg::ReverseSequenceAttrsArgs {
+                    batch_axis: args.batch_axis as u32,
+                    time_axis: args.time_axis as u32,
+                }
+            ),
+            OpType::RMSNormalization(args) => op_with_attrs!(
+                RMSNormalization,
+                LayerNormalizationAttrs,
+                sg::LayerNormalizationAttrsArgs {
+                    axis: args.axis as i32,
+                    epsilon: args.epsilon.unwrap_or(1e-5)
+                }
+            ),
+            OpType::RNN(args) => op_with_attrs!(RNN, RNNAttrs, {
+                let activations = self.create_rnn_activations(&args.options);
+                sg::RNNAttrsArgs {
+                    direction: rnn_direction(args.direction),
+                    hidden_size: args.hidden_size as u32,
+                    activations,
+                    clip: args.options.clip,
+                    batch_first: args.options.batch_first,
+                }
+            }),
+            OpType::RoiAlign(args) => op_with_attrs!(RoiAlign, RoiAlignAttrs, {
+                let mode = match args.mode {
+                    RoiAlignMode::Avg => sg::RoiAlignMode::Avg,
+                    RoiAlignMode::Max => sg::RoiAlignMode::Max,
+                };
+                let coord_mode = match args.coord_mode {
+                    RoiAlignCoordMode::HalfPixel => sg::RoiAlignCoordMode::HalfPixel,
+                    RoiAlignCoordMode::OutputHalfPixel => sg::RoiAlignCoordMode::OutputHalfPixel,
+                };
+                sg::RoiAlignAttrsArgs {
+                    mode,
+                    coord_mode,
+                    output_height: args.output_height as u32,
+                    output_width: args.output_width as u32,
+                    sampling_ratio: args.sampling_ratio.unwrap_or(0) as u32,
+                    spatial_scale: args.spatial_scale,
+                }
+            }),
+            OpType::RotaryEmbedding(args) => op_with_attrs!(
+                RotaryEmbedding,
+                RotaryEmbeddingAttrs,
+                sg::RotaryEmbeddingAttrsArgs {
+                    interleaved: args.interleaved,
+                    num_heads: args.num_heads.unwrap_or(0) as u32,
+                    rotary_embedding_dim: args.rotary_dim.unwrap_or(0) as u32,
+                }
+            ),
             OpType::Round => op!(Round),
             OpType::ScatterElements(args) => {
                 op_with_attrs!(ScatterElements, ScatterElementsAttrs, {
@@ -730,7 +1027,23 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     }
                 })
             }
+            OpType::Selu(args) => op_with_attrs!(
+                Selu,
+                SeluAttrs,
+                sg::SeluAttrsArgs {
+                    alpha: args.alpha,
+                    gamma: args.gamma,
+                }
+            ),
             OpType::Shape => op!(Shape),
+            OpType::Shrink(args) => op_with_attrs!(
+                Shrink,
+                ShrinkAttrs,
+                sg::ShrinkAttrsArgs {
+                    bias: args.bias,
+                    lambd: args.lambd,
+                }
+            ),
             OpType::Sigmoid => op!(Sigmoid),
             OpType::Slice => op!(Slice),
             OpType::Sin => op!(Sin),
@@ -741,20 +1054,42 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 SoftmaxAttrs,
                 sg::SoftmaxAttrsArgs {
                     axis: args.axis as i32,
+                    flatten: args.flatten,
                 }
             ),
             OpType::Softplus => op!(Softplus),
+            OpType::Softsign => op!(Softsign),
+            OpType::SpaceToDepth(args) => op_with_attrs!(
+                SpaceToDepth,
+                SpaceToDepthAttrs,
+                sg::SpaceToDepthAttrsArgs {
+                    block_size: args.block_size as u32,
+                }
+            ),
             OpType::Split(args) => op_with_attrs!(Split, SplitAttrs, {
                 sg::SplitAttrsArgs {
                     axis: args.axis as i32,
+                    num_outputs: args.num_outputs,
                 }
             }),
             OpType::Sqrt => op!(Sqrt),
             OpType::Squeeze => op!(Squeeze),
+            OpType::STFT(args) => op_with_attrs!(
+                STFT,
+                STFTAttrs,
+                sg::STFTAttrsArgs {
+                    onesided: args.onesided
+                }
+            ),
             OpType::Sub => op!(Sub),
             OpType::Sum => op!(Sum),
             OpType::Tan => op!(Tan),
             OpType::Tanh => op!(Tanh),
+            OpType::ThresholdedRelu(args) => op_with_attrs!(
+                ThresholdedRelu,
+                EluAttrs,
+                sg::EluAttrsArgs { alpha: args.alpha }
+            ),
             OpType::Tile => op!(Tile),
             OpType::TopK(args) => op_with_attrs!(TopK, TopKAttrs, {
                 sg::TopKAttrsArgs {
@@ -770,6 +1105,14 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             OpType::Trilu(args) => op_with_attrs!(Trilu, TriluAttrs, {
                 sg::TriluAttrsArgs { upper: args.upper }
             }),
+            OpType::Unique(args) => op_with_attrs!(
+                Unique,
+                UniqueAttrs,
+                sg::UniqueAttrsArgs {
+                    axis: args.axis.map(|axis| axis as i32),
+                    sorted: args.sorted,
+                }
+            ),
             OpType::Unsqueeze => op!(Unsqueeze),
             OpType::Where => op!(Where),
             OpType::Xor => op!(Xor),
@@ -821,6 +1164,50 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
         })
     }
 
+    /// Write the activation functions of an RNN operator.
+    ///
+    /// Returns `None` if the operator uses its default activations.
+    fn create_rnn_activations(
+        &mut self,
+        options: &RnnOptions,
+    ) -> Option<WIPOffset<Vector<'a, ForwardsUOffset<sg::RNNActivationFn<'a>>>>> {
+        if options.activations.is_empty() {
+            return None;
+        }
+        let activations: Vec<_> = options
+            .activations
+            .iter()
+            .map(|act| {
+                let (kind, alpha, beta) = match *act {
+                    RnnActivation::Affine { alpha, beta } => {
+                        (sg::RNNActivation::Affine, alpha, beta)
+                    }
+                    RnnActivation::Elu { alpha } => (sg::RNNActivation::Elu, alpha, 0.),
+                    RnnActivation::HardSigmoid { alpha, beta } => {
+                        (sg::RNNActivation::HardSigmoid, alpha, beta)
+                    }
+                    RnnActivation::LeakyRelu { alpha } => (sg::RNNActivation::LeakyRelu, alpha, 0.),
+                    RnnActivation::Relu => (sg::RNNActivation::Relu, 0., 0.),
+                    RnnActivation::ScaledTanh { alpha, beta } => {
+                        (sg::RNNActivation::ScaledTanh, alpha, beta)
+                    }
+                    RnnActivation::Sigmoid => (sg::RNNActivation::Sigmoid, 0., 0.),
+                    RnnActivation::Softplus => (sg::RNNActivation::Softplus, 0., 0.),
+                    RnnActivation::Softsign => (sg::RNNActivation::Softsign, 0., 0.),
+                    RnnActivation::Tanh => (sg::RNNActivation::Tanh, 0., 0.),
+                    RnnActivation::ThresholdedRelu { alpha } => {
+                        (sg::RNNActivation::ThresholdedRelu, alpha, 0.)
+                    }
+                };
+                sg::RNNActivationFn::create(
+                    self.builder,
+                    &sg::RNNActivationFnArgs { kind, alpha, beta },
+                )
+            })
+            .collect();
+        Some(self.builder.create_vector(&activations))
+    }
+
     /// Finish writing this graph to the FlatBuffers buffer.
     pub fn finish(self) -> WIPOffset<sg::Graph<'a>> {
         let inputs_vec = self.builder.create_vector(&self.input_ids[..]);
@@ -2,12 +2,13 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
-use smallvec::smallvec;
+use smallvec::{smallvec, SmallVec};
 
 use crate::ops;
 use crate::ops::{
-    BoxOrder, CoordTransformMode, DataType, Direction, NearestMode, Operator, Padding, ResizeMode,
-    Scalar, ScatterReduction,
+    BitShiftDirection, BoxOrder, CoordTransformMode, DataType, DepthToSpaceMode, Direction,
+    NearestMode, Operator, Padding, ResizeMode, RnnActivation, RnnOptions, RoiAlignCoordMode,
+    RoiAlignMode, Scalar, ScatterReduction,
 };
 use crate::schema_generated as sg;
 use crate::schema_generated::{AutoPad, OperatorNode, OperatorType};
@@ -84,15 +85,28 @@ impl OpRegistry {
         register_op!(Atan);
         register_op!(AveragePool);
         register_op!(BatchNormalization);
+        #[cfg(feature = "random")]
+        register_op!(Bernoulli);
+        register_op!(BlackmanWindow);
+        register_op!(BitShift);
+        register_op!(BitwiseAnd);
+        register_op!(BitwiseNot);
+        register_op!(BitwiseOr);
+        register_op!(BitwiseXor);
         register_op!(Cast);
         register_op!(Ceil);
+        register_op!(CenterCropPad);
+        register_op!(Celu);
         register_op!(Clip);
+        register_op!(Compress);
         register_op!(Concat);
         register_op!(Conv);
         register_op!(ConstantOfShape);
         register_op!(ConvTranspose);
         register_op!(Cos);
         register_op!(CumSum);
+        register_op!(DepthToSpace);
+        register_op!(DFT);
         register_op!(Div);
         register_op!(Einsum);
         register_op!(Elu);
@@ -108,27 +122,40 @@ impl OpRegistry {
         register_op!(Gelu);
         register_op!(Gemm);
         register_op!(GlobalAveragePool);
+        register_op!(GlobalLpPool);
+        register_op!(GlobalMaxPool);
         register_op!(Greater);
         register_op!(GreaterOrEqual);
         register_op!(GRU);
+        register_op!(HammingWindow);
+        register_op!(HannWindow);
         register_op!(HardSigmoid);
         register_op!(HardSwish);
+        register_op!(Hardmax);
         register_op!(Identity);
         register_op!(InstanceNormalization);
+        register_op!(IsInf);
+        register_op!(IsNaN);
         register_op!(LayerNormalization);
         register_op!(LeakyRelu);
         register_op!(Less);
         register_op!(LessOrEqual);
         register_op!(Log);
         register_op!(LogSoftmax);
+        register_op!(LpPool);
         register_op!(LSTM);
         register_op!(MatMul);
         register_op!(Max);
         register_op!(MaxPool);
+        register_op!(MaxUnpool);
         register_op!(Mean);
+        register_op!(MelWeightMatrix);
         register_op!(Min);
+        register_op!(Mish);
         register_op!(Mod);
         register_op!(Mul);
+        #[cfg(feature = "random")]
+        register_op!(Multinomial);
         register_op!(Neg);
         register_op!(NonMaxSuppression);
         register_op!(NonZero);
@@ -137,6 +164,7 @@ impl OpRegistry {
         register_op!(Or);
         register_op!(Pad);
         register_op!(Pow);
+        register_op!(PRelu);
 
         #[cfg(feature = "random")]
         register_op!(RandomNormal);
@@ -149,7 +177,10 @@ impl OpRegistry {
 
         register_op!(Range);
         register_op!(Reciprocal);
+        register_op!(ReduceL1);
         register_op!(ReduceL2);
+        register_op!(ReduceLogSum);
+        register_op!(ReduceLogSumExp);
         register_op!(ReduceMax);
         register_op!(ReduceMean);
         register_op!(ReduceMin);
@@ -158,11 +189,18 @@ impl OpRegistry {
         register_op!(ReduceSumSquare);
         register_op!(Relu);
         register_op!(Reshape);
+        register_op!(ReverseSequence);
         register_op!(Resize);
+        register_op!(RMSNormalization);
+        register_op!(RNN);
+        register_op!(RoiAlign);
+        register_op!(RotaryEmbedding);
         register_op!(Round);
         register_op!(ScatterElements);
         register_op!(ScatterND);
+        register_op!(Selu);
         register_op!(Shape);
+        register_op!(Shrink);
         register_op!(Sigmoid);
         register_op!(Sign);
         register_op!(Sin);
@@ -170,17 +208,22 @@ impl OpRegistry {
         register_op!(Slice);
         register_op!(Softmax);
         register_op!(Softplus);
+        register_op!(Softsign);
+        register_op!(SpaceToDepth);
         register_op!(Split);
         register_op!(Sqrt);
         register_op!(Squeeze);
+        register_op!(STFT);
         register_op!(Sub);
         register_op!(Sum);
         register_op!(Tan);
         register_op!(Tanh);
+        register_op!(ThresholdedRelu);
         register_op!(Tile);
         register_op!(TopK);
         register_op!(Transpose);
         register_op!(Trilu);
+        register_op!(Unique);
         register_op!(Unsqueeze);
         register_op!(Where);
         register_op!(Xor);
@@ -233,17 +276,72 @@ fn padding_from_attrs(auto_pad: AutoPad, pads: Option<flatbuffers::Vector<'_, u3
     }
 }
 
-/// Read the first N items from `iter` into an array.
+/// Read the kernel size, strides and dilations of a pooling operator.
 ///
-/// Panics if the iterator yields fewer than N items.
-fn array_from_iter<const N: usize, T: Default + Copy, I: Iterator<Item = T>>(
-    mut iter: I,
-) -> [T; N] {
-    let mut result = [T::default(); N];
-    for i in 0..N {
-        result[i] = iter.next().expect("incorrect array size");
-    }
-    result
+/// Strides and dilations default to 1 for each spatial axis.
+#[allow(clippy::type_complexity)]
+fn pool_sizes_from_attrs(
+    kernel_size: flatbuffers::Vector<'_, u32>,
+    strides: Option<flatbuffers::Vector<'_, u32>>,
+    dilations: Option<flatbuffers::Vector<'_, u32>>,
+) -> (
+    SmallVec<[usize; 2]>,
+    SmallVec<[usize; 2]>,
+    SmallVec<[usize; 2]>,
+) {
+    let kernel_size: SmallVec<_> = kernel_size.iter().map(|x| x as usize).collect();
+    let ones = || smallvec![1; kernel_size.len()];
+    let strides = strides
+        .map(|stride| stride.iter().map(|x| x as usize).collect())
+        .unwrap_or_else(ones);
+    let dilations = dilations
+        .map(|dilation| dilation.iter().map(|x| x as usize).collect())
+        .unwrap_or_else(ones);
+    (kernel_size, strides, dilations)
+}
+
+fn rnn_direction_from_attrs(direction: sg::RNNDirection) -> Direction {
+    match direction {
+        sg::RNNDirection::Forward => Direction::Forward,
+        sg::RNNDirection::Reverse => Direction::Reverse,
+        sg::RNNDirection::Bidirectional => Direction::Bidirectional,
+        _ => Direction::Forward,
+    }
+}
+
+/// Read the attributes which are shared by the GRU, LSTM and RNN operators.
+fn rnn_options_from_attrs(
+    activations: Option<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<sg::RNNActivationFn>>>,
+    clip: Option<f32>,
+    batch_first: bool,
+) -> Result<RnnOptions, ReadOpError> {
+    let activations = activations
+        .iter()
+        .flatten()
+        .map(|act| {
+            let (alpha, beta) = (act.alpha(), act.beta());
+            let act = match act.kind() {
+                sg::RNNActivation::Affine => RnnActivation::Affine { alpha, beta },
+                sg::RNNActivation::Elu => RnnActivation::Elu { alpha },
+                sg::RNNActivation::HardSigmoid => RnnActivation::HardSigmoid { alpha, beta },
+                sg::RNNActivation::LeakyRelu => RnnActivation::LeakyRelu { alpha },
+                sg::RNNActivation::Relu => RnnActivation::Relu,
+                sg::RNNActivation::ScaledTanh => RnnActivation::ScaledTanh { alpha, beta },
+                sg::RNNActivation::Sigmoid => RnnActivation::Sigmoid,
+                sg::RNNActivation::Softplus => RnnActivation::Softplus,
+                sg::RNNActivation::Softsign => RnnActivation::Softsign,
+                sg::RNNActivation::Tanh => RnnActivation::Tanh,
+                sg::RNNActivation::ThresholdedRelu => RnnActivation::ThresholdedRelu { alpha },
+                _ => return Err(ReadOpError::AttrError),
+            };
+            Ok(act)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(RnnOptions {
+        activations,
+        clip,
+        batch_first,
+    })
 }
 
 fn vec_from_attr(attr: Option<flatbuffers::Vector<u32>>, default: &[usize]) -> Vec<usize> {
@@ -331,6 +429,7 @@ macro_rules! impl_read_op {
                 let op = ops::$op {
                     axis: attrs.axis() as isize,
                     keep_dims: attrs.keep_dims(),
+                    select_last_index: attrs.select_last_index(),
                 };
                 Ok(op)
             }
@@ -383,18 +482,17 @@ impl_read_op!(
     AveragePool,
     attrs_as_average_pool_attrs,
     |attrs: sg::AveragePoolAttrs| {
-        let kernel_size = array_from_iter(attrs.kernel_size().iter().map(|x| x as usize));
+        let (kernel_size, strides, dilations) =
+            pool_sizes_from_attrs(attrs.kernel_size(), attrs.strides(), attrs.dilations());
         let padding = padding_from_attrs(attrs.auto_pad(), attrs.pads());
-        let strides = attrs
-            .strides()
-            .map(|stride| array_from_iter(stride.iter().map(|x| x as usize)))
-            .unwrap_or([1, 1]);
 
         Ok(ops::AveragePool {
             kernel_size,
             padding,
             count_include_pad: attrs.count_include_pad(),
             strides,
+            dilations,
+            ceil_mode: attrs.ceil_mode(),
         })
     }
 );
@@ -407,6 +505,49 @@ impl_read_op!(
         })
     }
 );
+#[cfg(feature = "random")]
+impl_read_op!(
+    Bernoulli,
+    attrs_as_bernoulli_attrs,
+    |attrs: sg::BernoulliAttrs| {
+        let dtype = match attrs.dtype() {
+            sg::DataType::Int32 => DataType::Int32,
+            sg::DataType::Float => DataType::Float,
+            _ => return Err(ReadOpError::AttrError),
+        };
+        Ok(ops::Bernoulli {
+            dtype,
+            seed: attrs.seed(),
+        })
+    }
+);
+impl_read_op!(
+    BlackmanWindow,
+    attrs_as_window_attrs,
+    |attrs: sg::WindowAttrs| {
+        Ok(ops::BlackmanWindow {
+            periodic: attrs.periodic(),
+        })
+    }
+);
+impl_read_op!(
+    BitShift,
+    attrs_as_bit_shift_attrs,
+    |attrs: sg::BitShiftAttrs| {
+        let direction = match attrs.direction() {
+            sg::BitShiftDirection::Left => BitShiftDirection::Left,
+            sg::BitShiftDirection::Right => BitShiftDirection::Right,
+            _ => {
+                return Err(ReadOpError::AttrError);
+            }
+        };
+        Ok(ops::BitShift { direction })
+    }
+);
+impl_read_op!(BitwiseAnd);
+impl_read_op!(BitwiseNot);
+impl_read_op!(BitwiseOr);
+impl_read_op!(BitwiseXor);
 impl_read_op!(Cast, attrs_as_cast_attrs, |attrs: sg::CastAttrs| {
     let to = match attrs.to() {
         sg::DataType::Int32 => DataType::Int32,
@@ -416,7 +557,30 @@ impl_read_op!(Cast, attrs_as_cast_attrs, |attrs: sg::CastAttrs| {
     Ok(ops::Cast { to })
 });
 impl_read_op!(Ceil);
+impl_read_op!(
+    CenterCropPad,
+    attrs_as_center_crop_pad_attrs,
+    |attrs: sg::CenterCropPadAttrs| {
+        Ok(ops::CenterCropPad {
+            axes: attrs.axes().map(|axes| axes.iter().collect()),
+        })
+    }
+);
+impl_read_op!(Celu, attrs_as_elu_attrs, |attrs: sg::EluAttrs| {
+    Ok(ops::Celu {
+        alpha: attrs.alpha(),
+    })
+});
 impl_read_op!(Clip);
+impl_read_op!(
+    Compress,
+    attrs_as_compress_attrs,
+    |attrs: sg::CompressAttrs| {
+        Ok(ops::Compress {
+            axis: attrs.axis().map(|axis| axis as isize),
+        })
+    }
+);
 impl_read_op!(Concat, attrs_as_concat_attrs, axis);
 impl_read_op!(Conv, attrs_as_conv_attrs, |attrs: sg::ConvAttrs| {
     let groups = attrs.groups() as usize;
@@ -448,13 +612,63 @@ impl_read_op!(
     ConvTranspose,
     attrs_as_conv_transpose_attrs,
     |attrs: sg::ConvTransposeAttrs| {
+        let groups = attrs.groups() as usize;
         let padding = padding_from_attrs(attrs.auto_pad(), attrs.pads());
         let strides = vec_from_attr(attrs.strides(), &[1, 1]);
-        Ok(ops::ConvTranspose { padding, strides })
+        // Models serialized before dilations were supported have no
+        // dilations, which are then 1 for each spatial axis.
+        let dilations = vec_from_attr(attrs.dilations(), &vec![1; strides.len()]);
+        Ok(ops::ConvTranspose {
+            groups,
+            padding,
+            strides,
+            dilations,
+        })
     }
 );
 impl_read_op!(Cos);
-impl_read_op!(CumSum);
+
+// CumSum is read manually because models serialized before `CumSumAttrs` was
+// added have no attributes for this operator.
+impl ReadOp for ops::CumSum {
+    fn op_type() -> OperatorType {
+        OperatorType::CumSum
+    }
+
+    fn read(op: &OperatorNode) -> Result<Self, ReadOpError> {
+        let op = match op.attrs_as_cum_sum_attrs() {
+            Some(attrs) => ops::CumSum {
+                exclusive: attrs.exclusive(),
+                reverse: attrs.reverse(),
+            },
+            None => ops::CumSum::default(),
+        };
+        Ok(op)
+    }
+}
+
+impl_read_op!(
+    DepthToSpace,
+    attrs_as_depth_to_space_attrs,
+    |attrs: sg::DepthToSpaceAttrs| {
+        let mode = match attrs.mode() {
+            sg::DepthToSpaceMode::DCR => DepthToSpaceMode::DepthColumnRow,
+            sg::DepthToSpaceMode::CRD => DepthToSpaceMode::ColumnRowDepth,
+            _ => DepthToSpaceMode::default(),
+        };
+        Ok(ops::DepthToSpace {
+            block_size: attrs.block_size() as usize,
+            mode,
+        })
+    }
+);
+impl_read_op!(DFT, attrs_as_dftattrs, |attrs: sg::DFTAttrs| {
+    Ok(ops::DFT {
+        axis: attrs.axis() as isize,
+        inverse: attrs.inverse(),
+        onesided: attrs.onesided(),
+    })
+});
 impl_read_op!(Div);
 impl_read_op!(Einsum, attrs_as_einsum_attrs, |attrs: sg::EinsumAttrs| {
     Ok(ops::Einsum {
@@ -495,23 +709,48 @@ impl_read_op!(Gemm, attrs_as_gemm_attrs, |attrs: sg::GemmAttrs| {
     })
 });
 impl_read_op!(GlobalAveragePool);
+impl_read_op!(
+    GlobalLpPool,
+    attrs_as_global_lp_pool_attrs,
+    |attrs: sg::GlobalLpPoolAttrs| {
+        Ok(ops::GlobalLpPool {
+            p: attrs.p() as usize,
+        })
+    }
+);
+impl_read_op!(GlobalMaxPool);
 impl_read_op!(Greater);
 impl_read_op!(GreaterOrEqual);
 impl_read_op!(GRU, attrs_as_gruattrs, |attrs: sg::GRUAttrs| {
     let hidden_size = attrs.hidden_size() as usize;
-    let direction = match attrs.direction() {
-        sg::RNNDirection::Forward => Direction::Forward,
-        sg::RNNDirection::Reverse => Direction::Reverse,
-        sg::RNNDirection::Bidirectional => Direction::Bidirectional,
-        _ => Direction::Forward,
-    };
+    let direction = rnn_direction_from_attrs(attrs.direction());
+    let options = rnn_options_from_attrs(attrs.activations(), attrs.clip(), attrs.batch_first())?;
 
     Ok(ops::GRU {
         direction,
         hidden_size,
         linear_before_reset: attrs.linear_before_reset(),
+        options,
     })
 });
+impl_read_op!(
+    HammingWindow,
+    attrs_as_window_attrs,
+    |attrs: sg::WindowAttrs| {
+        Ok(ops::HammingWindow {
+            periodic: attrs.periodic(),
+        })
+    }
+);
+impl_read_op!(
+    HannWindow,
+    attrs_as_window_attrs,
+    |attrs: sg::WindowAttrs| {
+        Ok(ops::HannWindow {
+            periodic: attrs.periodic(),
+        })
+    }
+);
 impl_read_op!(
     HardSigmoid,
     attrs_as_hard_sigmoid_attrs,
@@ -523,6 +762,16 @@ impl_read_op!(
     }
 );
 impl_read_op!(HardSwish);
+impl_read_op!(
+    Hardmax,
+    attrs_as_softmax_attrs,
+    |attrs: sg::SoftmaxAttrs| {
+        Ok(ops::Hardmax {
+            axis: attrs.axis() as isize,
+            flatten: attrs.flatten(),
+        })
+    }
+);
 impl_read_op!(Identity);
 impl_read_op!(
     InstanceNormalization,
@@ -533,6 +782,13 @@ impl_read_op!(
         })
     }
 );
+impl_read_op!(IsInf, attrs_as_is_inf_attrs, |attrs: sg::IsInfAttrs| {
+    Ok(ops::IsInf {
+        detect_negative: attrs.detect_negative(),
+        detect_positive: attrs.detect_positive(),
+    })
+});
+impl_read_op!(IsNaN);
 impl_read_op!(
     LayerNormalization,
     attrs_as_layer_normalization_attrs,
@@ -555,18 +811,39 @@ impl_read_op!(
 impl_read_op!(Less);
 impl_read_op!(LessOrEqual);
 impl_read_op!(Log);
-impl_read_op!(LogSoftmax, attrs_as_softmax_attrs, axis);
+impl_read_op!(
+    LogSoftmax,
+    attrs_as_softmax_attrs,
+    |attrs: sg::SoftmaxAttrs| {
+        Ok(ops::LogSoftmax {
+            axis: attrs.axis() as isize,
+            flatten: attrs.flatten(),
+        })
+    }
+);
+impl_read_op!(LpPool, attrs_as_lp_pool_attrs, |attrs: sg::LpPoolAttrs| {
+    let (kernel_size, strides, dilations) =
+        pool_sizes_from_attrs(attrs.kernel_size(), attrs.strides(), attrs.dilations());
+    let padding = padding_from_attrs(attrs.auto_pad(), attrs.pads());
+
+    Ok(ops::LpPool {
+        kernel_size,
+        padding,
+        strides,
+        dilations,
+        ceil_mode: attrs.ceil_mode(),
+        p: attrs.p() as usize,
+    })
+});
 impl_read_op!(LSTM, attrs_as_lstmattrs, |attrs: sg::LSTMAttrs| {
     let hidden_size = attrs.hidden_size() as usize;
-    let direction = match attrs.direction() {
-        sg::RNNDirection::Forward => Direction::Forward,
-        sg::RNNDirection::Reverse => Direction::Reverse,
-        sg::RNNDirection::Bidirectional => Direction::Bidirectional,
-        _ => Direction::Forward,
-    };
+    let direction = rnn_direction_from_attrs(attrs.direction());
+    let options = rnn_options_from_attrs(attrs.activations(), attrs.clip(), attrs.batch_first())?;
     Ok(ops::LSTM {
         direction,
         hidden_size,
+        input_forget: attrs.input_forget(),
+        options,
     })
 });
 impl_read_op!(MatMul);
@@ -575,26 +852,54 @@ impl_read_op!(
     MaxPool,
     attrs_as_max_pool_attrs,
     |attrs: sg::MaxPoolAttrs| {
-        let kernel_size = array_from_iter(attrs.kernel_size().iter().map(|x| x as usize));
+        let (kernel_size, strides, dilations) =
+            pool_sizes_from_attrs(attrs.kernel_size(), attrs.strides(), attrs.dilations());
         let padding = padding_from_attrs(attrs.auto_pad(), attrs.pads());
-        let strides = attrs
-            .strides()
-            .map(|stride| array_from_iter(stride.iter().map(|x| x as usize)))
-            .unwrap_or([1, 1]);
 
         Ok(ops::MaxPool {
             kernel_size,
             padding,
             strides,
+            dilations,
+            ceil_mode: attrs.ceil_mode(),
+            return_indices: attrs.return_indices(),
+        })
+    }
+);
+impl_read_op!(
+    MaxUnpool,
+    attrs_as_max_unpool_attrs,
+    |attrs: sg::MaxUnpoolAttrs| {
+        let (kernel_size, strides, _dilations) =
+            pool_sizes_from_attrs(attrs.kernel_size(), attrs.strides(), None);
+        let padding = padding_from_attrs(AutoPad::NotSet, attrs.pads());
+
+        Ok(ops::MaxUnpool {
+            kernel_size,
+            padding,
+            strides,
         })
     }
 );
 impl_read_op!(Mean);
+impl_read_op!(MelWeightMatrix);
 impl_read_op!(Min);
+impl_read_op!(Mish);
 impl_read_op!(Mod, attrs_as_mod_attrs, |attrs: sg::ModAttrs| {
     Ok(ops::Mod { fmod: attrs.fmod() })
 });
 impl_read_op!(Mul);
+#[cfg(feature = "random")]
+impl_read_op!(
+    Multinomial,
+    attrs_as_multinomial_attrs,
+    |attrs: sg::MultinomialAttrs| {
+        Ok(ops::Multinomial {
+            sample_size: attrs.sample_size() as usize,
+            seed: attrs.seed(),
+        })
+    }
+);
 impl_read_op!(Neg);
 impl_read_op!(
     NonMaxSuppression,
@@ -614,6 +919,7 @@ impl_read_op!(OneHot, attrs_as_one_hot_attrs, axis);
 impl_read_op!(Or);
 impl_read_op!(Pad);
 impl_read_op!(Pow);
+impl_read_op!(PRelu);
 
 #[cfg(feature = "random")]
 impl_read_op!(
@@ -678,7 +984,10 @@ impl_read_op!(
 
 impl_read_op!(Range);
 impl_read_op!(Reciprocal);
+impl_read_op!(ReduceL1, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceL2, attrs_as_reduce_mean_attrs, reduce_axes);
+impl_read_op!(ReduceLogSum, attrs_as_reduce_mean_attrs, reduce_axes);
+impl_read_op!(ReduceLogSumExp, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceMax, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceMean, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceMin, attrs_as_reduce_mean_attrs, reduce_axes);
@@ -695,6 +1004,16 @@ impl_read_op!(
         })
     }
 );
+impl_read_op!(
+    ReverseSequence,
+    attrs_as_reverse_sequence_attrs,
+    |attrs: sg::ReverseSequenceAttrs| {
+        Ok(ops::ReverseSequence {
+            batch_axis: attrs.batch_axis() as usize,
+            time_axis: attrs.time_axis() as usize,
+        })
+    }
+);
 impl_read_op!(Resize, attrs_as_resize_attrs, |attrs: sg::ResizeAttrs| {
     let mode = match attrs.mode() {
         sg::ResizeMode::Nearest => ResizeMode::Nearest,
@@ -722,6 +1041,67 @@ impl_read_op!(Resize, attrs_as_resize_attrs, |attrs: sg::ResizeAttrs| {
         nearest_mode,
     })
 });
+impl_read_op!(
+    RMSNormalization,
+    attrs_as_layer_normalization_attrs,
+    |attrs: sg::LayerNormalizationAttrs| {
+        Ok(ops::RMSNormalization {
+            axis: attrs.axis() as isize,
+            epsilon: Some(attrs.epsilon()),
+        })
+    }
+);
+impl_read_op!(RNN, attrs_as_rnnattrs, |attrs: sg::RNNAttrs| {
+    let hidden_size = attrs.hidden_size() as usize;
+    let direction = rnn_direction_from_attrs(attrs.direction());
+    let options = rnn_options_from_attrs(attrs.activations(), attrs.clip(), attrs.batch_first())?;
+    Ok(ops::RNN {
+        direction,
+        hidden_size,
+        options,
+    })
+});
+impl_read_op!(
+    RoiAlign,
+    attrs_as_roi_align_attrs,
+    |attrs: sg::RoiAlignAttrs| {
+        let mode = match attrs.mode() {
+            sg::RoiAlignMode::Avg => RoiAlignMode::Avg,
+            sg::RoiAlignMode::Max => RoiAlignMode::Max,
+            _ => RoiAlignMode::default(),
+        };
+        let coord_mode = match attrs.coord_mode() {
+            sg::RoiAlignCoordMode::HalfPixel => RoiAlignCoordMode::HalfPixel,
+            sg::RoiAlignCoordMode::OutputHalfPixel => RoiAlignCoordMode::OutputHalfPixel,
+            _ => RoiAlignCoordMode::default(),
+        };
+        let sampling_ratio = match attrs.sampling_ratio() {
+            0 => None,
+            n => Some(n as usize),
+        };
+
+        Ok(ops::RoiAlign {
+            mode,
+            coord_mode,
+            output_height: attrs.output_height() as usize,
+            output_width: attrs.output_width() as usize,
+            sampling_ratio,
+            spatial_scale: attrs.spatial_scale(),
+        })
+    }
+);
+impl_read_op!(
+    RotaryEmbedding,
+    attrs_as_rotary_embedding_attrs,
+    |attrs: sg::RotaryEmbeddingAttrs| {
+        let to_option = |n: u32| if n == 0 { None } else { Some(n as usize) };
+        Ok(ops::RotaryEmbedding {
+            interleaved: attrs.interleaved(),
+            num_heads: to_option(attrs.num_heads()),
+            rotary_dim: to_option(attrs.rotary_embedding_dim()),
+        })
+    }
+);
 impl_read_op!(Round);
 impl_read_op!(
     ScatterElements,
@@ -742,21 +1122,71 @@ impl_read_op!(
         })
     }
 );
+impl_read_op!(Selu, attrs_as_selu_attrs, |attrs: sg::SeluAttrs| {
+    Ok(ops::Selu {
+        alpha: attrs.alpha(),
+        gamma: attrs.gamma(),
+    })
+});
 impl_read_op!(Shape);
+impl_read_op!(Shrink, attrs_as_shrink_attrs, |attrs: sg::ShrinkAttrs| {
+    Ok(ops::Shrink {
+        bias: attrs.bias(),
+        lambd: attrs.lambd(),
+    })
+});
 impl_read_op!(Sigmoid);
 impl_read_op!(Sign);
 impl_read_op!(Sin);
 impl_read_op!(Size);
 impl_read_op!(Slice);
-impl_read_op!(Softmax, attrs_as_softmax_attrs, axis);
+impl_read_op!(
+    Softmax,
+    attrs_as_softmax_attrs,
+    |attrs: sg::SoftmaxAttrs| {
+        Ok(ops::Softmax {
+            axis: attrs.axis() as isize,
+            flatten: attrs.flatten(),
+        })
+    }
+);
 impl_read_op!(Softplus);
-impl_read_op!(Split, attrs_as_split_attrs, axis);
+impl_read_op!(Softsign);
+impl_read_op!(
+    SpaceToDepth,
+    attrs_as_space_to_depth_attrs,
+    |attrs: sg::SpaceToDepthAttrs| {
+        Ok(ops::SpaceToDepth {
+            block_size: attrs.block_size() as usize,
+        })
+    }
+);
+impl_read_op!(Split, attrs_as_split_attrs, |attrs: sg::SplitAttrs| {
+    Ok(ops::Split {
+        axis: attrs.axis() as isize,
+        num_outputs: attrs.num_outputs(),
+    })
+});
 impl_read_op!(Sqrt);
 impl_read_op!(Squeeze);
+impl_read_op!(STFT, attrs_as_stftattrs, |attrs: sg::STFTAttrs| {
+    Ok(ops::STFT {
+        onesided: attrs.onesided(),
+    })
+});
 impl_read_op!(Sub);
 impl_read_op!(Sum);
 impl_read_op!(Tan);
 impl_read_op!(Tanh);
+impl_read_op!(
+    ThresholdedRelu,
+    attrs_as_elu_attrs,
+    |attrs: sg::EluAttrs| {
+        Ok(ops::ThresholdedRelu {
+            alpha: attrs.alpha(),
+        })
+    }
+);
 impl_read_op!(Tile);
 impl_read_op!(TopK, attrs_as_top_kattrs, |attrs: sg::TopKAttrs| {
     let largest = attrs.largest();
@@ -783,6 +1213,12 @@ impl_read_op!(Trilu, attrs_as_trilu_attrs, |attrs: sg::TriluAttrs| {
         upper: attrs.upper(),
     })
 });
+impl_read_op!(Unique, attrs_as_unique_attrs, |attrs: sg::UniqueAttrs| {
+    Ok(ops::Unique {
+        axis: attrs.axis().map(|axis| axis as isize),
+        sorted: attrs.sorted(),
+    })
+});
 impl_read_op!(Unsqueeze);
 impl_read_op!(Where);
 impl_read_op!(Xor);
@@ -0,0 +1,686 @@
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
+
+use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
+use crate::ops::{
+    add, matmul, mul, softmax, InputList, IntoOpResult, OpError, Operator, OutputList,
+};
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Number of query rows that are processed together.
+const QUERY_BLOCK_SIZE: usize = 64;
+
+/// Number of keys that are processed in each step of the blockwise softmax.
+const KEY_BLOCK_SIZE: usize = 256;
+
+/// Compute attention outputs for a block of queries against all keys in one
+/// head.
+///
+/// The keys are processed in blocks, using an "online" softmax that keeps a
+/// running maximum and sum for each query. This avoids materializing the full
+/// `[seq_q, seq_k]` attention matrix.
+///
+/// `first_query` is the index of the first row of `query` in the full query
+/// sequence and is used for causal masking. `causal_offset` is
+/// `seq_k - seq_q`. `out` has shape `[query.rows(), value.cols()]`.
+fn attention_block(
+    gemm: &GemmExecutor,
+    query: NdTensorView<f32, 2>,
+    key_t: NdTensorView<f32, 2>,
+    value: NdTensorView<f32, 2>,
+    mask: Option<NdTensorView<f32, 2>>,
+    causal: Option<(usize, isize)>,
+    scale: f32,
+    out: &mut [f32],
+) {
+    let n_queries = query.size(0);
+    let v_dim = value.size(1);
+    let mut seq_k = key_t.size(1);
+
+    // With causal masking, no query in this block attends to keys after
+    // the last query's position.
+    if let Some((first_query, causal_offset)) = causal {
+        let last_query = (first_query + n_queries - 1) as isize;
+        seq_k = seq_k.min((last_query + causal_offset + 1).max(0) as usize);
+    }
+
+    let mut scores = vec![0.; n_queries * KEY_BLOCK_SIZE.min(seq_k)];
+    let mut row_max = vec![f32::NEG_INFINITY; n_queries];
+    let mut row_sum = vec![0.; n_queries];
+    out.fill(0.);
+
+    for k_start in (0..seq_k).step_by(KEY_BLOCK_SIZE) {
+        let k_end = (k_start + KEY_BLOCK_SIZE).min(seq_k);
+        let n_keys = k_end - k_start;
+        let scores = &mut scores[..n_queries * n_keys];
+
+        gemm.gemm(
+            scores,
+            n_keys,
+            GemmInputA::Unpacked(query),
+            GemmInputB::Unpacked(key_t.slice((.., k_start..k_end))),
+            scale,
+            0., // beta
+        );
+
+        for (row, row_scores) in scores.chunks_mut(n_keys).enumerate() {
+            if let Some(mask) = mask {
+                let mask_row = mask.slice::<1, _>((row, k_start..k_end));
+                for (s, m) in row_scores.iter_mut().zip(mask_row.iter()) {
+                    *s += m;
+                }
+            }
+
+            if let Some((first_query, causal_offset)) = causal {
+                let last_key = (first_query + row) as isize + causal_offset;
+                for (k, s) in row_scores.iter_mut().enumerate() {
+                    if (k_start + k) as isize > last_key {
+                        *s = f32::NEG_INFINITY;
+                    }
+                }
+            }
+
+            let block_max = row_scores.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            let new_max = row_max[row].max(block_max);
+            if new_max == f32::NEG_INFINITY {
+                // All keys so far are masked out for this query.
+                row_scores.fill(0.);
+                continue;
+            }
+
+            // Rescale the sums and outputs accumulated so far to be relative
+            // to the new maximum.
+            let correction = (row_max[row] - new_max).exp();
+            row_max[row] = new_max;
+
+            let mut block_sum = 0.;
+            for s in row_scores.iter_mut() {
+                *s = (*s - new_max).exp();
+                block_sum += *s;
+            }
+            row_sum[row] = row_sum[row] * correction + block_sum;
+
+            if correction != 1. {
+                for x in &mut out[row * v_dim..(row + 1) * v_dim] {
+                    *x *= correction;
+                }
+            }
+        }
+
+        let probs = NdTensorView::from_data([n_queries, n_keys], &scores[..]);
+        gemm.gemm(
+            out,
+            v_dim,
+            GemmInputA::Unpacked(probs),
+            GemmInputB::Unpacked(value.slice((k_start..k_end, ..))),
+            1., // alpha
+            1., // beta
+        );
+    }
+
+    // Normalize. Queries for which all keys are masked out get NaN outputs,
+    // as with an unfused `Softmax`.
+    for (out_row, sum) in out.chunks_mut(v_dim).zip(row_sum) {
+        for x in out_row {
+            *x /= sum;
+        }
+    }
+}
+
+/// Compute scaled dot-product attention: `Softmax(Q @ K^T * scale + mask) @ V`.
+///
+/// - `query` has shape `[batch, heads, seq_q, head_dim]`
+/// - `key` has shape `[batch, kv_heads, seq_k, head_dim]`
+/// - `value` has shape `[batch, kv_heads, seq_k, v_dim]`
+/// - `mask` is an optional additive mask which is broadcast to
+///   `[batch, heads, seq_q, seq_k]`.
+///
+/// The batch size of `key` and `value` can be 1, in which case they are
+/// broadcast. `heads` must be a multiple of `kv_heads`. If there are fewer
+/// key-value heads than query heads (grouped-query attention), each key-value
+/// head is shared by `heads / kv_heads` consecutive query heads.
+///
+/// If `causal` is true, each query at position `i` attends only to keys at
+/// positions `j <= i + seq_k - seq_q`. This is in addition to any `mask`.
+///
+/// `scale` defaults to `1 / sqrt(head_dim)`.
+///
+/// The output has shape `[batch, heads, seq_q, v_dim]`.
+pub fn scaled_dot_product_attention(
+    pool: &TensorPool,
+    query: NdTensorView<f32, 4>,
+    key: NdTensorView<f32, 4>,
+    value: NdTensorView<f32, 4>,
+    mask: Option<TensorView<f32>>,
+    causal: bool,
+    scale: Option<f32>,
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let [batch, n_heads, seq_q, head_dim] = query.shape();
+    let [k_batch, n_kv_heads, seq_k, k_head_dim] = key.shape();
+    let [v_batch, v_heads, v_seq, v_dim] = value.shape();
+
+    if k_head_dim != head_dim {
+        return Err(OpError::IncompatibleInputShapes(
+            "Query and key head sizes do not match",
+        ));
+    }
+    if [v_batch, v_heads, v_seq] != [k_batch, n_kv_heads, seq_k] {
+        return Err(OpError::IncompatibleInputShapes(
+            "Key and value shapes do not match",
+        ));
+    }
+    if k_batch != batch && k_batch != 1 {
+        return Err(OpError::IncompatibleInputShapes(
+            "Key batch size must be 1 or match query",
+        ));
+    }
+    if n_kv_heads == 0 || n_heads % n_kv_heads != 0 {
+        return Err(OpError::IncompatibleInputShapes(
+            "Query heads must be a multiple of key-value heads",
+        ));
+    }
+
+    let scores_shape = [batch, n_heads, seq_q, seq_k];
+    let mask = match mask {
+        Some(mask) if !mask.can_broadcast_to(&scores_shape) => {
+            return Err(OpError::IncompatibleInputShapes(
+                "Mask cannot be broadcast to attention scores",
+            ));
+        }
+        Some(mask) => Some(mask.broadcast(scores_shape)),
+        None => None,
+    };
+
+    let mut output = NdTensor::zeros_in(pool, [batch, n_heads, seq_q, v_dim]);
+    let head_len = seq_q * v_dim;
+    if head_len == 0 {
+        return Ok(output);
+    }
+
+    let scale = scale.unwrap_or(1. / (head_dim as f32).sqrt());
+    let causal_offset = seq_k as isize - seq_q as isize;
+    let group_size = n_heads / n_kv_heads;
+    let gemm = GemmExecutor::new();
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(head_len)
+        .enumerate()
+        .for_each(|(batch_head, out_head)| {
+            let (b, h) = (batch_head / n_heads, batch_head % n_heads);
+            let kv_b = if k_batch == 1 { 0 } else { b };
+            let kv_h = h / group_size;
+
+            let query = query.slice::<2, _>((b, h));
+            let key_t = key.slice::<2, _>((kv_b, kv_h)).transposed();
+            let value = value.slice::<2, _>((kv_b, kv_h));
+            let mask = mask.as_ref().map(|m| m.slice::<2, _>((b, h)));
+
+            out_head
+                .par_chunks_mut(QUERY_BLOCK_SIZE * v_dim)
+                .enumerate()
+                .for_each(|(q_block, out_block)| {
+                    let q_start = q_block * QUERY_BLOCK_SIZE;
+                    let q_end = q_start + out_block.len() / v_dim;
+                    attention_block(
+                        &gemm,
+                        query.slice((q_start..q_end, ..)),
+                        key_t,
+                        value,
+                        mask.map(|m| m.slice((q_start..q_end, ..))),
+                        causal.then_some((q_start, causal_offset)),
+                        scale,
+                        out_block,
+                    );
+                });
+        });
+
+    Ok(output)
+}
+
+/// Compute attention using separate MatMul, Softmax and Add operations.
+///
+/// This supports arbitrary broadcasting between inputs, but materializes the
+/// full attention matrix.
+fn unfused_attention(
+    pool: &TensorPool,
+    query: TensorView,
+    key_t: TensorView,
+    value: TensorView,
+    mask: Option<TensorView>,
+    scale: Option<f32>,
+) -> Result<Tensor, OpError> {
+    let head_dim = query.shape().last().copied().unwrap_or(1);
+    let scale = scale.unwrap_or(1. / (head_dim as f32).sqrt());
+
+    let scores = matmul(pool, query, key_t)?.auto_return(pool);
+    let scale = Tensor::from(scale);
+    let mut scores = mul(pool, scores.view(), scale.view())?.auto_return(pool);
+    if let Some(mask) = mask {
+        scores = add(pool, scores.view(), mask)?.auto_return(pool);
+    }
+    let probs = softmax(pool, scores.view(), -1)?.auto_return(pool);
+    matmul(pool, probs.view(), value)
+}
+
+/// Swap the last two dimensions of a view.
+fn swap_last_two<'a>(mut view: TensorView<'a>) -> TensorView<'a> {
+    let ndim = view.ndim();
+    if ndim >= 2 {
+        let mut perm: Vec<usize> = (0..ndim).collect();
+        perm.swap(ndim - 2, ndim - 1);
+        view.permute(&perm);
+    }
+    view
+}
+
+/// Return true if inputs to [`ScaledDotProductAttention`] have ranks and
+/// batch and head counts that [`scaled_dot_product_attention`] supports.
+///
+/// `key` has shape `[batch, kv_heads, seq_k, head_dim]`. Other mismatches in
+/// sizes are reported as errors by the fused implementation.
+fn fused_supported(
+    query: &TensorView,
+    key: &TensorView,
+    value: &TensorView,
+    mask: Option<&TensorView>,
+) -> bool {
+    let ndim = query.ndim();
+    if !(ndim == 3 || ndim == 4)
+        || key.ndim() != ndim
+        || value.ndim() != ndim
+        || mask.is_some_and(|m| m.ndim() > ndim)
+    {
+        return false;
+    }
+
+    // Batch and head dims, with a batch size of 1 for 3D inputs.
+    let lead_dims = |view: &TensorView| {
+        let shape = view.shape();
+        let batch = if ndim == 4 { shape[0] } else { 1 };
+        (batch, shape[ndim - 3])
+    };
+    let (batch, n_heads) = lead_dims(query);
+    let (k_batch, n_kv_heads) = lead_dims(key);
+
+    (k_batch == batch || k_batch == 1)
+        && n_kv_heads > 0
+        && n_heads % n_kv_heads == 0
+        && lead_dims(value) == (k_batch, n_kv_heads)
+}
+
+/// Convert a 3D or 4D view to a 4D view by inserting a leading 1 dim.
+fn to_4d<'a>(mut view: TensorView<'a>) -> NdTensorView<'a, f32, 4> {
+    if view.ndim() == 3 {
+        view.insert_axis(0);
+    }
+    view.nd_view()
+}
+
+/// Fused scaled dot-product attention.
+///
+/// Inputs are `(query, key, value, mask?)`. See
+/// [`scaled_dot_product_attention`] for details. Inputs may also have 3 dims,
+/// in which case the batch dimension is omitted.
+///
+/// This operator is created by graph optimization from subgraphs of the form
+/// `MatMul(Softmax(MatMul(Q, K^T) * scale + mask), V)`. If the mask is a
+/// constant causal mask, it is replaced by the `causal` option.
+#[derive(Debug)]
+pub struct ScaledDotProductAttention {
+    /// Scale applied to `Q @ K^T`. Defaults to `1 / sqrt(head_dim)`.
+    pub scale: Option<f32>,
+
+    /// Apply a causal mask, in addition to the optional additive mask input.
+    pub causal: bool,
+
+    /// If true, the key input is transposed, with shape
+    /// `[batch, kv_heads, head_dim, seq_k]`.
+    pub key_transposed: bool,
+}
+
+impl Operator for ScaledDotProductAttention {
+    fn name(&self) -> &str {
+        "ScaledDotProductAttention"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let query: TensorView = inputs.require_as(0)?;
+        let key: TensorView = inputs.require_as(1)?;
+        let value: TensorView = inputs.require_as(2)?;
+        let mask: Option<TensorView> = inputs.get_as(3)?;
+
+        let ndim = query.ndim();
+        if key.ndim() != ndim {
+            return Err(OpError::IncompatibleInputShapes(
+                "Query and key must have the same rank",
+            ));
+        }
+        let key = if self.key_transposed {
+            swap_last_two(key)
+        } else {
+            key
+        };
+
+        // Use the fused implementation if the inputs have a supported rank
+        // and layout. Otherwise fall back to the unfused computation, which
+        // supports the same broadcasting as `MatMul`. Errors from the fused
+        // implementation are returned, as the unfused computation would
+        // fail for the same inputs.
+        if fused_supported(&query, &key, &value, mask.as_ref()) {
+            let mut output = scaled_dot_product_attention(
+                pool,
+                to_4d(query),
+                to_4d(key),
+                to_4d(value),
+                mask,
+                self.causal,
+                self.scale,
+            )?
+            .into_dyn();
+            if ndim == 3 {
+                output.remove_axis(0);
+            }
+            return output.into_op_result();
+        }
+
+        if self.causal {
+            return Err(OpError::UnsupportedValue(
+                "Causal attention requires 3D or 4D inputs with compatible shapes",
+            ));
+        }
+        unfused_attention(pool, query, swap_last_two(key), value, mask, self.scale).into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::{NdTensor, Tensor};
+
+    use super::unfused_attention;
+    use crate::ops::tests::{expect_eq_1e4, new_pool};
+    use crate::ops::ScaledDotProductAttention;
+    use crate::ops::{scaled_dot_product_attention, InputList, OpError, Operator};
+
+    /// Compute attention using separate operations and with keys repeated to
+    /// match the number of query heads.
+    fn reference_attention(
+        query: &NdTensor<f32, 4>,
+        key: &NdTensor<f32, 4>,
+        value: &NdTensor<f32, 4>,
+        mask: Option<&Tensor>,
+        scale: Option<f32>,
+    ) -> Tensor {
+        let pool = new_pool();
+        let [_, n_heads, _, _] = query.shape();
+        let [batch, n_kv_heads, seq_k, head_dim] = key.shape();
+        let v_dim = value.size(3);
+        let group_size = n_heads / n_kv_heads;
+
+        let repeat_heads = |x: &NdTensor<f32, 4>, dim: usize| {
+            NdTensor::from_fn([batch, n_heads, seq_k, dim], |[b, h, s, d]| {
+                x[[b, h / group_size, s, d]]
+            })
+        };
+        let key = repeat_heads(key, head_dim);
+        let value = repeat_heads(value, v_dim);
+
+        unfused_attention(
+            &pool,
+            query.as_dyn(),
+            key.permuted([0, 1, 3, 2]).as_dyn(),
+            value.as_dyn(),
+            mask.map(|m| m.view()),
+            scale,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case {
+            n_heads: usize,
+            n_kv_heads: usize,
+            seq_q: usize,
+            seq_k: usize,
+            with_mask: bool,
+            scale: Option<f32>,
+        }
+
+        let cases = [
+            // Single query.
+            Case {
+                n_heads: 2,
+                n_kv_heads: 2,
+                seq_q: 1,
+                seq_k: 5,
+                with_mask: false,
+                scale: None,
+            },
+            // Sequences spanning multiple query and key blocks.
+            Case {
+                n_heads: 2,
+                n_kv_heads: 2,
+                seq_q: 70,
+                seq_k: 300,
+                with_mask: true,
+                scale: Some(0.5),
+            },
+            // Grouped-query attention.
+            Case {
+                n_heads: 4,
+                n_kv_heads: 2,
+                seq_q: 8,
+                seq_k: 8,
+                with_mask: true,
+                scale: None,
+            },
+            // Multi-query attention.
+            Case {
+                n_heads: 3,
+                n_kv_heads: 1,
+                seq_q: 4,
+                seq_k: 6,
+                with_mask: false,
+                scale: None,
+            },
+        ];
+
+        for Case {
+            n_heads,
+            n_kv_heads,
+            seq_q,
+            seq_k,
+            with_mask,
+            scale,
+        } in cases
+        {
+            let (batch, head_dim, v_dim) = (2, 8, 6);
+            let query = NdTensor::rand([batch, n_heads, seq_q, head_dim], &mut rng);
+            let key = NdTensor::rand([batch, n_kv_heads, seq_k, head_dim], &mut rng);
+            let value = NdTensor::rand([batch, n_kv_heads, seq_k, v_dim], &mut rng);
+
+            // Mask which is broadcast over heads.
+            let mask = with_mask.then(|| {
+                Tensor::from_fn(&[batch, 1, seq_q, seq_k], |idx| {
+                    if (idx[2] + idx[3]) % 3 == 0 {
+                        f32::NEG_INFINITY
+                    } else {
+                        idx[3] as f32 * 0.1
+                    }
+                })
+            });
+
+            let result = scaled_dot_product_attention(
+                &pool,
+                query.view(),
+                key.view(),
+                value.view(),
+                mask.as_ref().map(|m| m.view()),
+                false, /* causal */
+                scale,
+            )?;
+            let expected = reference_attention(&query, &key, &value, mask.as_ref(), scale);
+            expect_eq_1e4(&result.as_dyn(), &expected.view())?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_causal() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Cases with equal query and key lengths, and with fewer queries than
+        // keys (eg. when using a KV cache).
+        for (seq_q, seq_k) in [(5, 5), (3, 7), (100, 300)] {
+            let query = NdTensor::rand([1, 2, seq_q, 4], &mut rng);
+            let key = NdTensor::rand([1, 2, seq_k, 4], &mut rng);
+            let value = NdTensor::rand([1, 2, seq_k, 4], &mut rng);
+
+            let offset = seq_k - seq_q;
+            let causal_mask = Tensor::from_fn(&[seq_q, seq_k], |idx| {
+                if idx[1] > idx[0] + offset {
+                    f32::NEG_INFINITY
+                } else {
+                    0.
+                }
+            });
+
+            let result = scaled_dot_product_attention(
+                &pool,
+                query.view(),
+                key.view(),
+                value.view(),
+                None,
+                true, /* causal */
+                None,
+            )?;
+            let expected = reference_attention(&query, &key, &value, Some(&causal_mask), None);
+            expect_eq_1e4(&result.as_dyn(), &expected.view())?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_invalid() {
+        let pool = new_pool();
+        let query = NdTensor::zeros([1, 3, 4, 8]);
+        let key = NdTensor::zeros([1, 2, 4, 8]);
+        let value = NdTensor::zeros([1, 2, 4, 8]);
+
+        let result = scaled_dot_product_attention(
+            &pool,
+            query.view(),
+            key.view(),
+            value.view(),
+            None,
+            false,
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Query heads must be a multiple of key-value heads"
+            ))
+        );
+
+        let key = NdTensor::zeros([1, 3, 4, 4]);
+        let result = scaled_dot_product_attention(
+            &pool,
+            query.view(),
+            key.view(),
+            value.view(),
+            None,
+            false,
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Query and key head sizes do not match"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention_op() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // 3D inputs with a transposed key, as produced by fusion.
+        let query = Tensor::rand(&[2, 5, 4], &mut rng);
+        let key_t = Tensor::rand(&[2, 4, 6], &mut rng);
+        let value = Tensor::rand(&[2, 6, 3], &mut rng);
+        let op = ScaledDotProductAttention {
+            scale: Some(0.25),
+            causal: false,
+            key_transposed: true,
+        };
+        let inputs = InputList::from(&[
+            query.view().into(),
+            key_t.view().into(),
+            value.view().into(),
+        ]);
+        let result: Tensor = op.run(&pool, inputs)?.remove(0).try_into()?;
+        let expected = unfused_attention(
+            &pool,
+            query.view(),
+            key_t.view(),
+            value.view(),
+            None,
+            Some(0.25),
+        )?;
+        assert_eq!(result.shape(), [2, 5, 3]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // Inputs with broadcasting that the fused implementation does not
+        // support fall back to the unfused computation.
+        let query = Tensor::rand(&[1, 5, 4], &mut rng);
+        let inputs = InputList::from(&[
+            query.view().into(),
+            key_t.view().into(),
+            value.view().into(),
+        ]);
+        let result: Tensor = op.run(&pool, inputs)?.remove(0).try_into()?;
+        let expected = unfused_attention(
+            &pool,
+            query.view(),
+            key_t.view(),
+            value.view(),
+            None,
+            Some(0.25),
+        )?;
+        assert_eq!(result.shape(), [2, 5, 3]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // Shape errors in inputs that the fused implementation supports are
+        // returned rather than handled by the fallback.
+        let query = Tensor::rand(&[2, 5, 3], &mut rng);
+        let inputs = InputList::from(&[
+            query.view().into(),
+            key_t.view().into(),
+            value.view().into(),
+        ]);
+        let result = op.run(&pool, inputs);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Query and key head sizes do not match"
+            ))
+        );
+
+        Ok(())
+    }
+}
@@ -6,7 +6,10 @@ use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView, TensorViewMut};
 
 use crate::number::{AsBool, Identities, IsInt};
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output, OutputList};
+use crate::ops::{
+    infer_broadcast_shape, is_same_op, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    OutputList, ShapeInputs,
+};
 use crate::tensor_pool::TensorPool;
 
 /// Given the shapes of two inputs to a binary operation, return the shape
@@ -408,7 +411,7 @@ pub fn add_in_place<T: Copy + Debug + std::ops::Add<Output = T>>(
     binary_op_in_place(a, b, |x, y| x + y);
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Add {}
 
 impl Operator for Add {
@@ -416,10 +419,18 @@ impl Operator for Add {
         "Add"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         run_typed_op!(pool, inputs, add)
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -438,6 +449,157 @@ impl Operator for Add {
     }
 }
 
+/// Direction of a [BitShift] operation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BitShiftDirection {
+    Left,
+    Right,
+}
+
+fn bit_shift_elem(x: i32, shift: i32, direction: BitShiftDirection) -> i32 {
+    // Values are shifted as unsigned integers. Shifts by amounts greater than
+    // the bit width produce zero.
+    let (x, shift) = (x as u32, shift as u32);
+    let shifted = match direction {
+        BitShiftDirection::Left => x.checked_shl(shift),
+        BitShiftDirection::Right => x.checked_shr(shift),
+    };
+    shifted.unwrap_or(0) as i32
+}
+
+/// Shift the bits of elements in `a` by the amounts in the corresponding
+/// elements of `b`.
+pub fn bit_shift(
+    pool: &TensorPool,
+    a: TensorView<i32>,
+    b: TensorView<i32>,
+    direction: BitShiftDirection,
+) -> Result<Tensor<i32>, OpError> {
+    binary_op(pool, a, b, |x, y| bit_shift_elem(x, y, direction))
+}
+
+/// Perform an in-place bit shift of elements in `a` by amounts in `b`.
+pub fn bit_shift_in_place(a: TensorViewMut<i32>, b: TensorView<i32>, direction: BitShiftDirection) {
+    binary_op_in_place(a, b, |x, y| bit_shift_elem(x, y, direction));
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BitShift {
+    pub direction: BitShiftDirection,
+}
+
+impl Operator for BitShift {
+    fn name(&self) -> &str {
+        "BitShift"
+    }
+
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let a = inputs.require_as(0)?;
+        let b = inputs.require_as(1)?;
+        bit_shift(pool, a, b, self.direction).into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        pool: &TensorPool,
+        input: Output,
+        other: InputList,
+    ) -> Result<Output, OpError> {
+        let mut a = input.into_int().ok_or(OpError::IncorrectInputType)?;
+        let b = other.require_as(0)?;
+
+        if can_run_binary_op_in_place(&a, &b) {
+            bit_shift_in_place(a.view_mut(), b, self.direction);
+            Ok(a.into())
+        } else {
+            bit_shift(pool, a.view(), b, self.direction).map(|t| t.into())
+        }
+    }
+}
+
+/// Define a bitwise operator.
+///
+/// These accept two i32 tensors and produce an i32 result.
+macro_rules! bitwise_op {
+    ($op:ident, $op_fn:ident, $in_place_op_fn:ident, $expr:expr) => {
+        pub fn $op_fn(
+            pool: &TensorPool,
+            a: TensorView<i32>,
+            b: TensorView<i32>,
+        ) -> Result<Tensor<i32>, OpError> {
+            binary_commutative_op(pool, a, b, $expr)
+        }
+
+        pub fn $in_place_op_fn(a: TensorViewMut<i32>, b: TensorView<i32>) {
+            binary_op_in_place(a, b, $expr);
+        }
+
+        #[derive(Debug, PartialEq)]
+        pub struct $op {}
+
+        impl Operator for $op {
+            fn name(&self) -> &str {
+                stringify!($op)
+            }
+
+            fn is_equivalent(&self, other: &dyn Operator) -> bool {
+                is_same_op(self, other)
+            }
+
+            fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+                let a = inputs.require_as(0)?;
+                let b = inputs.require_as(1)?;
+                $op_fn(pool, a, b).into_op_result()
+            }
+
+            fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+                infer_broadcast_shape(inputs)
+            }
+
+            fn can_run_in_place(&self) -> bool {
+                true
+            }
+
+            fn is_commutative(&self) -> bool {
+                true
+            }
+
+            fn run_in_place(
+                &self,
+                pool: &TensorPool,
+                input: Output,
+                other: InputList,
+            ) -> Result<Output, OpError> {
+                let mut a = input.into_int().ok_or(OpError::IncorrectInputType)?;
+                let b = other.require_as(0)?;
+
+                if can_run_binary_op_in_place(&a, &b) {
+                    $in_place_op_fn(a.view_mut(), b);
+                    Ok(a.into())
+                } else {
+                    $op_fn(pool, a.view(), b).map(|t| t.into())
+                }
+            }
+        }
+    };
+}
+
+bitwise_op!(BitwiseAnd, bitwise_and, bitwise_and_in_place, |x, y| x & y);
+bitwise_op!(BitwiseOr, bitwise_or, bitwise_or_in_place, |x, y| x | y);
+bitwise_op!(BitwiseXor, bitwise_xor, bitwise_xor_in_place, |x, y| x ^ y);
+
 /// Define a logical boolean operator.
 ///
 /// These accept two i32 tensors and produce an i32 result.
@@ -452,7 +614,7 @@ macro_rules! logical_boolean_op {
             binary_op(pool, a, b, |x, y| $expr(x.as_bool(), y.as_bool()).into())
         }
 
-        #[derive(Debug)]
+        #[derive(Debug, PartialEq)]
         pub struct $op {}
 
         impl Operator for $op {
@@ -460,6 +622,10 @@ macro_rules! logical_boolean_op {
                 stringify!($op)
             }
 
+            fn is_equivalent(&self, other: &dyn Operator) -> bool {
+                is_same_op(self, other)
+            }
+
             fn is_commutative(&self) -> bool {
                 // These ops are marked as commutative because that is
                 // technically true, but this will have no effect until
@@ -472,6 +638,10 @@ macro_rules! logical_boolean_op {
                 let b: TensorView<i32> = inputs.require_as(1)?;
                 $op_fn(pool, a, b).into_op_result()
             }
+
+            fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+                infer_broadcast_shape(inputs)
+            }
         }
     };
 }
@@ -516,7 +686,7 @@ pub fn div_in_place<
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Div {}
 
 impl Operator for Div {
@@ -524,10 +694,18 @@ impl Operator for Div {
         "Div"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         run_typed_op!(pool, inputs, div)
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -579,7 +757,7 @@ macro_rules! boolean_cmp_op {
             boolean_op(pool, a, b, BooleanOp::$name)
         }
 
-        #[derive(Debug)]
+        #[derive(Debug, PartialEq)]
         pub struct $name {}
 
         impl Operator for $name {
@@ -587,6 +765,10 @@ macro_rules! boolean_cmp_op {
                 stringify!($name)
             }
 
+            fn is_equivalent(&self, other: &dyn Operator) -> bool {
+                is_same_op(self, other)
+            }
+
             fn is_commutative(&self) -> bool {
                 // `Equal` is marked as commutative, but this will have no
                 // effect until an in-place version of the operator is
@@ -597,6 +779,10 @@ macro_rules! boolean_cmp_op {
             fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
                 run_typed_op!(pool, inputs, $func)
             }
+
+            fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+                infer_broadcast_shape(inputs)
+            }
         }
     };
 }
@@ -659,7 +845,7 @@ pub fn mod_op<
     )
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Mod {
     /// If true, use truncated division (see [DivMode::TruncDiv], otherwise
     /// use flooring division (see [DivMode::FloorDiv]).
@@ -671,6 +857,10 @@ impl Operator for Mod {
         "Mod"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let a = inputs.require(0)?;
         let mode = if self.fmod {
@@ -690,6 +880,10 @@ impl Operator for Mod {
             }
         }
     }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
 }
 
 /// Multiply two tensors elementwise.
@@ -709,7 +903,7 @@ pub fn mul_in_place<T: Copy + Debug + std::ops::Mul<Output = T>>(
     binary_op_in_place(a, b, |a_elt, b_elt| a_elt * b_elt);
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Mul {}
 
 impl Operator for Mul {
@@ -717,10 +911,18 @@ impl Operator for Mul {
         "Mul"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         run_typed_op!(pool, inputs, mul)
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -740,7 +942,7 @@ impl Operator for Mul {
 }
 
 /// Like [f32::powf] but with fast paths for common values.
-fn powf(x: f32, y: f32) -> f32 {
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
     if y == 2. {
         x * x
     } else if y == 3. {
@@ -768,7 +970,7 @@ pub fn pow_in_place(mut a: TensorViewMut, b: TensorView) {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Pow {}
 
 impl Operator for Pow {
@@ -776,12 +978,20 @@ impl Operator for Pow {
         "Pow"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let a = inputs.require_as(0)?;
         let b = inputs.require_as(1)?;
         pow(pool, a, b).into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -804,6 +1014,78 @@ impl Operator for Pow {
     }
 }
 
+fn prelu_elem(x: f32, slope: f32) -> f32 {
+    if x < 0. {
+        slope * x
+    } else {
+        x
+    }
+}
+
+/// Parametric ReLU. This computes `slope * x` for negative elements of `x`
+/// and `x` otherwise, where `slope` is broadcast to the shape of `x`.
+pub fn prelu(pool: &TensorPool, x: TensorView, slope: TensorView) -> Result<Tensor, OpError> {
+    if let Some(&slope) = slope.item() {
+        Ok(x.map_in(pool, |x| prelu_elem(*x, slope)))
+    } else {
+        binary_op(pool, x, slope, prelu_elem)
+    }
+}
+
+/// Perform in-place Parametric ReLU. `slope` must be broadcastable to the
+/// shape of `x`.
+pub fn prelu_in_place(mut x: TensorViewMut, slope: TensorView) {
+    if let Some(&slope) = slope.item() {
+        x.apply(|x| prelu_elem(*x, slope))
+    } else {
+        binary_op_in_place(x, slope, prelu_elem);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PRelu {}
+
+impl Operator for PRelu {
+    fn name(&self) -> &str {
+        "PRelu"
+    }
+
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let x = inputs.require_as(0)?;
+        let slope = inputs.require_as(1)?;
+        prelu(pool, x, slope).into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        pool: &TensorPool,
+        input: Output,
+        other: InputList,
+    ) -> Result<Output, OpError> {
+        let mut x = input.into_float().ok_or(OpError::IncorrectInputType)?;
+        let slope = other.require_as(0)?;
+
+        if can_run_binary_op_in_place(&x, &slope) {
+            prelu_in_place(x.view_mut(), slope);
+            Ok(x.into())
+        } else {
+            prelu(pool, x.view(), slope).map(|t| t.into())
+        }
+    }
+}
+
 /// Perform elementwise subtraction of two tensors.
 pub fn sub<T: Copy + Debug + Default + std::ops::Sub<Output = T>>(
     pool: &TensorPool,
@@ -821,7 +1103,7 @@ pub fn sub_in_place<T: Copy + Debug + std::ops::Sub<Output = T>>(
     binary_op_in_place(a, b, |x, y| x - y);
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Sub {}
 
 impl Operator for Sub {
@@ -829,10 +1111,18 @@ impl Operator for Sub {
         "Sub"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         run_typed_op!(pool, inputs, sub)
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -928,7 +1218,7 @@ pub fn where_op<T: Copy>(
     Ok(Tensor::from_data(&result_shape, out_data))
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Where {}
 
 impl Operator for Where {
@@ -936,6 +1226,10 @@ impl Operator for Where {
         "Where"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let condition = inputs.require_as::<i32>(0)?;
         let x = inputs.require(1)?;
@@ -951,6 +1245,10 @@ impl Operator for Where {
             }
         }
     }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_broadcast_shape(inputs)
+    }
 }
 
 #[cfg(test)]
@@ -964,9 +1262,10 @@ mod tests {
     use super::{fast_broadcast_cycles, fast_broadcast_cycles_repeats};
     use crate::ops::tests::new_pool;
     use crate::ops::{
-        add, add_in_place, and, div, div_in_place, equal, greater, greater_or_equal, less,
-        less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub, sub_in_place,
-        where_op, xor, Add, DivMode, OpError, Operator, Output,
+        add, add_in_place, and, bit_shift, bit_shift_in_place, bitwise_and, bitwise_or,
+        bitwise_xor, div, div_in_place, equal, greater, greater_or_equal, less, less_or_equal,
+        mod_op, mul, mul_in_place, or, pow, pow_in_place, prelu, prelu_in_place, sub, sub_in_place,
+        where_op, xor, Add, BitShiftDirection, BitwiseAnd, DivMode, OpError, Operator, Output,
     };
 
     #[test]
@@ -1206,6 +1505,61 @@ mod tests {
         assert_eq!(&result, &expected);
     }
 
+    #[test]
+    fn test_bit_shift() {
+        let pool = new_pool();
+
+        let a = Tensor::from([1, 4, 10, -1]);
+        let b = Tensor::from([1, 2, 3, 31]);
+        let result = bit_shift(&pool, a.view(), b.view(), BitShiftDirection::Left).unwrap();
+        assert_eq!(result, Tensor::from([2, 16, 80, i32::MIN]));
+
+        // Right shifts are logical rather than arithmetic.
+        let result = bit_shift(&pool, a.view(), b.view(), BitShiftDirection::Right).unwrap();
+        assert_eq!(result, Tensor::from([0, 1, 1, 1]));
+
+        // Shift amounts greater than the bit width produce zero.
+        let b = Tensor::from(32);
+        let result = bit_shift(&pool, a.view(), b.view(), BitShiftDirection::Left).unwrap();
+        assert_eq!(result, Tensor::from([0, 0, 0, 0]));
+
+        // In-place
+        let mut a = Tensor::from([1, 2, 3]);
+        let b = Tensor::from(2);
+        bit_shift_in_place(a.view_mut(), b.view(), BitShiftDirection::Left);
+        assert_eq!(a, Tensor::from([4, 8, 12]));
+    }
+
+    #[test]
+    fn test_bitwise_ops() {
+        let pool = new_pool();
+        let a = Tensor::from_data(&[2, 2], vec![0b1100, 0b1010, 0, -1]);
+        let b = Tensor::from([0b1010, 0b0110]);
+
+        let result = bitwise_and(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(
+            result,
+            Tensor::from_data(&[2, 2], vec![0b1000, 0b0010, 0, 0b0110])
+        );
+
+        let result = bitwise_or(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(
+            result,
+            Tensor::from_data(&[2, 2], vec![0b1110, 0b1110, 0b1010, -1])
+        );
+
+        let result = bitwise_xor(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(
+            result,
+            Tensor::from_data(&[2, 2], vec![0b0110, 0b1100, 0b1010, !0b0110])
+        );
+
+        // Float inputs are not supported.
+        let x = Tensor::from([1.0f32]);
+        let result = BitwiseAnd {}.run(&pool, (&x, &x).into());
+        assert_eq!(result.err(), Some(OpError::IncorrectInputType));
+    }
+
     #[test]
     fn test_div() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1499,6 +1853,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_prelu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        // Scalar slope
+        let x = Tensor::from([-2., -1., 0., 1., 2.]);
+        let slope = Tensor::from(0.5);
+        let expected = Tensor::from([-1., -0.5, 0., 1., 2.]);
+        let result = prelu(&pool, x.view(), slope.view())?;
+        expect_equal(&result, &expected)?;
+
+        // Per-channel slope, broadcast over spatial dims of an NCHW input.
+        let x = Tensor::from_data(&[1, 2, 1, 2], vec![-1., 2., -3., -4.]);
+        let slope = Tensor::from([[[0.1]], [[0.2]]]);
+        let expected = Tensor::from_data(&[1, 2, 1, 2], vec![-0.1, 2., -0.6, -0.8]);
+        let result = prelu(&pool, x.view(), slope.view())?;
+        expect_equal(&result, &expected)?;
+
+        let mut x = x.clone();
+        prelu_in_place(x.view_mut(), slope.view());
+        expect_equal(&x, &expected)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_sub() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
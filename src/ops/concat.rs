@@ -6,21 +6,21 @@ use rten_tensor::{NdTensorView, Tensor, TensorView};
 use smallvec::SmallVec;
 
 use crate::ops::{
-    resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output, OutputList,
+    is_same_op, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    OutputList, ShapeInputs,
 };
 use crate::static_dims;
 use crate::tensor_pool::{AutoReturn, TensorPool};
 
 /// Return the shape formed by concatenating all tensors along a given axis.
-fn concatenated_shape<T: Copy>(
+fn concatenated_shape<'a>(
     first_shape: &[usize],
-    inputs: &[TensorView<T>],
+    other_shapes: impl Iterator<Item = &'a [usize]>,
     axis: usize,
 ) -> Result<SmallVec<[usize; 4]>, OpError> {
     let mut out_shape = SmallVec::from_slice(first_shape);
 
-    for other in inputs {
-        let other_shape = other.shape();
+    for other_shape in other_shapes {
         if other_shape.len() != first_shape.len() {
             return Err(OpError::IncompatibleInputShapes(
                 "Tensors must have the same number of dimensions",
@@ -72,7 +72,11 @@ pub fn concat<T: Copy>(
     axis: isize,
 ) -> Result<Tensor<T>, OpError> {
     let axis = resolve_axis(inputs[0].ndim(), axis)?;
-    let out_shape = concatenated_shape(inputs[0].shape(), &inputs[1..], axis)?;
+    let out_shape = concatenated_shape(
+        inputs[0].shape(),
+        inputs[1..].iter().map(|input| input.shape()),
+        axis,
+    )?;
     concat_impl(pool, &out_shape, axis, &inputs[0], &inputs[1..])
 }
 
@@ -83,7 +87,11 @@ pub fn concat_in_place<T: Copy>(
     axis: isize,
 ) -> Result<Tensor<T>, OpError> {
     let axis = resolve_axis(output.ndim(), axis)?;
-    let out_shape = concatenated_shape(output.shape(), inputs, axis)?;
+    let out_shape = concatenated_shape(
+        output.shape(),
+        inputs.iter().map(|input| input.shape()),
+        axis,
+    )?;
     if !output.has_capacity(axis, out_shape[axis]) {
         return concat_impl(pool, &out_shape, axis, &output.view(), inputs);
     }
@@ -95,7 +103,7 @@ pub fn concat_in_place<T: Copy>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Concat {
     pub axis: isize,
 }
@@ -105,6 +113,10 @@ impl Operator for Concat {
         "Concat"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let first = inputs.require(0)?;
         match first {
@@ -119,6 +131,14 @@ impl Operator for Concat {
         }
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let mut shapes = inputs.shapes();
+        let first = shapes.next()?;
+        let axis = resolve_axis(first.len(), self.axis).ok()?;
+        let out_shape = concatenated_shape(first, shapes, axis).ok()?;
+        Some(vec![out_shape.to_vec()])
+    }
+
     fn can_run_in_place(&self) -> bool {
         // This operator can run in place in several cases:
         //
@@ -250,7 +270,7 @@ pub fn tile<T: Copy>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Tile {}
 
 impl Operator for Tile {
@@ -258,6 +278,10 @@ impl Operator for Tile {
         "Tile"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let repeats = inputs.require_as::<i32>(1)?;
@@ -7,17 +7,23 @@ use rayon::prelude::*;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
 
-use crate::check_dims;
-use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
-use crate::ops::pooling::calc_output_size_and_padding;
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, OutputList, Padding};
+use crate::gemm::{Activation, BiasVector, GemmExecutor, GemmInputA, GemmInputB, PackedAMatrix};
+use crate::ops::pooling::{calc_output_size_and_padding, calc_output_size_and_padding_3d};
+use crate::ops::{
+    Input, InputList, IntoOpResult, OpError, Operator, OutputList, Padding, PrepackedInput,
+};
 use crate::tensor_pool::{AutoReturn, TensorPool};
+use crate::{check_dims, static_dims};
 
 mod depthwise;
 mod im2col;
+mod nhwc;
+mod winograd;
 
 use depthwise::conv_2d_depthwise;
-use im2col::VirtualIm2Col;
+use im2col::{im2col_3d, VirtualIm2Col};
+use nhwc::{conv_2d_nhwc, prepack_nhwc_kernel};
+use winograd::{conv_2d_winograd, prepack_winograd_kernel, use_winograd};
 
 /// Specialization of conv_2d for pointwise convolutions over one image. This
 /// can be reduced to tensor reshaping and matrix multiplication.
@@ -26,6 +32,8 @@ fn conv_2d_pointwise(
     input: &NdTensorView<f32, 4>,
     kernel: &NdTensorView<f32, 4>,
     bias: Option<NdTensorView<f32, 1>>,
+    activation: Option<Activation>,
+    packed_kernel: Option<&PackedAMatrix>,
 ) -> Tensor {
     let [batch, _, in_h, in_w]: [usize; 4] = input.shape();
     let [out_c, in_c, _, _]: [usize; 4] = kernel.shape();
@@ -49,13 +57,16 @@ fn conv_2d_pointwise(
 
         let in_mat = input.slice::<3, _>([n]).reshaped([in_c, in_h * in_w]);
 
-        gemm.gemm_uninit_bias(
+        gemm.gemm_uninit_bias_activation(
             out_item.data_mut().unwrap(),
             out_row_stride,
-            GemmInputA::Unpacked(kernel_mat),
+            packed_kernel
+                .map(GemmInputA::Packed)
+                .unwrap_or(GemmInputA::Unpacked(kernel_mat)),
             GemmInputB::Unpacked(in_mat),
             1., // alpha
-            bias.as_ref().map(|b| b.data().unwrap()),
+            bias.as_ref().map(|b| BiasVector::Column(b.data().unwrap())),
+            activation,
         );
         n_init += out_item.len();
     }
@@ -71,7 +82,8 @@ fn conv_2d_pointwise(
 ///
 /// For a 2D convolution `input` has dimensions NCHW while `kernel` has OGHW
 /// where `G` is `C / groups`. 1D convolutions are similar except the "H"
-/// dimension is omitted.
+/// dimension is omitted, and 3D convolutions have an additional "D" dimension
+/// before "H".
 ///
 /// - `padding` specifies the amount of horizontal and vertical padding respectively
 ///   that is added to each side.
@@ -93,6 +105,33 @@ pub fn conv(
     strides: &[usize],
     dilations: &[usize],
 ) -> Result<Tensor, OpError> {
+    conv_impl(
+        pool, input, kernel, bias, padding, groups, strides, dilations, None, None,
+    )
+}
+
+/// Perform a convolution of `input` with `kernel`, followed by an optional
+/// activation function. See [`conv`].
+///
+/// `packed_kernel` is an optional copy of the kernel prepacked by
+/// [`prepack_conv_kernel`].
+fn conv_impl(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel: TensorView,
+    bias: Option<TensorView>,
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+    activation: Option<Activation>,
+    packed_kernel: Option<&[PackedAMatrix]>,
+) -> Result<Tensor, OpError> {
+    // Kernels prepacked for the Winograd path have one matrix per element of
+    // the transformed tiles, rather than one per group.
+    let winograd_kernel = packed_kernel;
+    let packed_kernel = packed_kernel.filter(|pk| pk.len() == groups);
+
     // Handle 1D convolution by expanding to 2D and then removing the extra
     // dimension from the result.
     if let &[n, c, w] = input.shape() {
@@ -120,7 +159,7 @@ pub fn conv(
             }
         };
 
-        let result_2d = conv(
+        let result_2d = conv_impl(
             pool,
             input_2d,
             kernel_2d,
@@ -129,6 +168,8 @@ pub fn conv(
             groups,
             &strides_2d,
             &dilations_2d,
+            activation,
+            packed_kernel,
         );
 
         return result_2d.map(|mut t| {
@@ -138,7 +179,25 @@ pub fn conv(
         });
     }
 
-    let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
+    if input.ndim() == 5 {
+        let input = static_dims!(input, 5, "NCDHW")?;
+        let kernel = static_dims!(kernel, 5, "OCDHW")?;
+        let bias = bias.map(|b| static_dims!(b, 1)).transpose()?;
+        return conv_3d(
+            pool,
+            input,
+            kernel,
+            bias,
+            padding,
+            groups,
+            strides,
+            dilations,
+            activation,
+            packed_kernel,
+        );
+    }
+
+    let [_batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
     let [out_c, k_in_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
     check_dims!(bias?, 1);
 
@@ -178,10 +237,11 @@ pub fn conv(
             &input.nd_view(),
             &kernel.nd_view(),
             bias.as_ref().map(|b| b.nd_view()),
+            activation,
+            packed_kernel.map(|pk| &pk[0]),
         ));
     }
 
-    let out_channels_per_group = out_c / groups;
     let in_channels_per_group = in_c / groups;
 
     if in_channels_per_group != k_in_c {
@@ -197,7 +257,7 @@ pub fn conv(
     }
 
     if in_c == out_c && groups == in_c {
-        return Ok(conv_2d_depthwise(
+        let mut output = conv_2d_depthwise(
             pool,
             &input.nd_view(),
             &kernel.nd_view(),
@@ -206,9 +266,74 @@ pub fn conv(
             [stride_y, stride_x],
             [dilation_y, dilation_x],
             [out_h, out_w],
-        ));
+        );
+        if let Some(activation) = activation {
+            activation.apply(output.data_mut().unwrap());
+        }
+        return Ok(output);
+    }
+
+    if use_winograd(
+        [k_h, k_w],
+        k_in_c,
+        out_c,
+        groups,
+        &[stride_y, stride_x],
+        &[dilation_y, dilation_x],
+    ) {
+        return Ok(conv_2d_winograd(
+            pool,
+            input.nd_view(),
+            kernel.nd_view(),
+            bias.map(|b| b.nd_view()),
+            fixed_padding,
+            [out_h, out_w],
+            activation,
+            winograd_kernel,
+        )
+        .into_dyn());
     }
 
+    Ok(conv_2d_im2col(
+        pool,
+        input,
+        kernel,
+        bias,
+        fixed_padding,
+        groups,
+        [stride_y, stride_x],
+        [dilation_y, dilation_x],
+        [out_h, out_w],
+        activation,
+        packed_kernel,
+    ))
+}
+
+/// Compute a 2D convolution by unrolling patches of each input group into a
+/// matrix using [`VirtualIm2Col`], which is then multiplied with the kernel.
+///
+/// This is the general path for convolutions that are not handled by a more
+/// specialized implementation. The padding and output size are as computed
+/// by [`calc_output_size_and_padding`]. `packed_kernel` is an optional copy
+/// of the per-group kernel matrices prepacked by [`prepack_conv_kernel`].
+fn conv_2d_im2col(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel: TensorView,
+    bias: Option<TensorView>,
+    fixed_padding: [usize; 4],
+    groups: usize,
+    [stride_y, stride_x]: [usize; 2],
+    [dilation_y, dilation_x]: [usize; 2],
+    [out_h, out_w]: [usize; 2],
+    activation: Option<Activation>,
+    packed_kernel: Option<&[PackedAMatrix]>,
+) -> Tensor {
+    let [batch, in_c, _in_h, _in_w]: [usize; 4] = input.shape().try_into().unwrap();
+    let [out_c, _k_in_c, k_h, k_w]: [usize; 4] = kernel.shape().try_into().unwrap();
+    let out_channels_per_group = out_c / groups;
+    let in_channels_per_group = in_c / groups;
+
     let n_patches = out_h * out_w;
     let mut output = NdTensor::uninit_in(pool, [batch, out_c, n_patches]);
     let gemm = GemmExecutor::new();
@@ -233,12 +358,14 @@ pub fn conv(
             .reshaped([out_channels_per_group, in_channels_per_group * k_h * k_w]);
 
         // Prepack kernel if we'll be able to reuse packed weights.
-        let prepacked_kernel = if in_group.size(0) > 1 {
+        let prepacked_kernel = if packed_kernel.is_none() && in_group.size(0) > 1 {
             Some(gemm.prepack_a_in(pool, kernel_mat).auto_return(pool))
         } else {
             None
         };
-        let prepacked_kernel = prepacked_kernel.as_deref();
+        let prepacked_kernel = packed_kernel
+            .map(|pk| &pk[group])
+            .or(prepacked_kernel.as_deref());
 
         zip(out_group.axis_iter_mut(0), in_group.axis_iter(0))
             .par_bridge()
@@ -256,7 +383,7 @@ pub fn conv(
                     gemm.b_panel_width(),
                 );
 
-                gemm.gemm_uninit_bias(
+                gemm.gemm_uninit_bias_activation(
                     out_mat.data_mut().unwrap(),
                     out_row_stride,
                     prepacked_kernel
@@ -264,7 +391,9 @@ pub fn conv(
                         .unwrap_or(GemmInputA::Unpacked(kernel_mat)),
                     GemmInputB::Virtual(&im2col),
                     1., // alpha
-                    bias.as_ref().map(|b| &b.data().unwrap()[out_chans.clone()]),
+                    bias.as_ref()
+                        .map(|b| BiasVector::Column(&b.data().unwrap()[out_chans.clone()])),
+                    activation,
                 );
                 n_init.fetch_add(out_mat.len(), Ordering::SeqCst);
             });
@@ -276,10 +405,135 @@ pub fn conv(
     assert!(n_init.load(Ordering::SeqCst) == output.len());
     let output = unsafe { output.assume_init() };
 
+    output.into()
+}
+
+/// Perform a 3D convolution of `input` with `kernel`.
+///
+/// `input` has dimensions NCDHW and `kernel` has OGDHW where `G` is
+/// `C / groups`. Patches of each input group are unrolled into a matrix
+/// using [`im2col_3d`] which is then multiplied with the kernel.
+fn conv_3d(
+    pool: &TensorPool,
+    input: NdTensorView<f32, 5>,
+    kernel: NdTensorView<f32, 5>,
+    bias: Option<NdTensorView<f32, 1>>,
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+    activation: Option<Activation>,
+    packed_kernel: Option<&[PackedAMatrix]>,
+) -> Result<Tensor, OpError> {
+    let [batch, in_c, in_d, in_h, in_w] = input.shape();
+    let [out_c, k_in_c, k_d, k_h, k_w] = kernel.shape();
+
+    let strides: [usize; 3] = strides
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 stride values"))?;
+    let dilations: [usize; 3] = dilations
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 dilation values"))?;
+
+    if groups == 0 || in_c % groups != 0 || out_c % groups != 0 {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels and output channels must be divisible by group count",
+        ));
+    }
+
+    let out_channels_per_group = out_c / groups;
+    let in_channels_per_group = in_c / groups;
+
+    if in_channels_per_group != k_in_c {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels (per group) does not match kernel input channels",
+        ));
+    }
+
+    let (out_size, fixed_padding) = calc_output_size_and_padding_3d(
+        [in_d, in_h, in_w],
+        [k_d, k_h, k_w],
+        strides,
+        padding,
+        Some(dilations),
+    )?;
+    let [pad_front, pad_top, pad_left, _pad_back, _pad_bottom, _pad_right] = fixed_padding;
+
+    let n_patches = out_size.iter().product();
+    let mut output = NdTensor::uninit_in(pool, [batch, out_c, n_patches]);
+
+    // Kernel must be contiguous to reshape it into a matrix, and bias must be
+    // contiguous for use with `gemm_bias`.
+    let kernel = kernel.to_contiguous_in(pool).auto_return(pool);
+    let bias = bias.map(|b| b.to_contiguous());
+
+    let mut columns =
+        NdTensor::uninit_in(pool, [k_in_c * k_d * k_h * k_w, n_patches]).auto_return(pool);
+    let gemm = GemmExecutor::new();
+    let mut n_init = 0;
+
+    for group in 0..groups {
+        let in_chans = group * in_channels_per_group..(group + 1) * in_channels_per_group;
+        let out_chans = group * out_channels_per_group..(group + 1) * out_channels_per_group;
+
+        let kernel_mat = kernel
+            .slice::<5, _>([out_chans.clone()])
+            .reshaped([out_channels_per_group, k_in_c * k_d * k_h * k_w]);
+
+        // Prepack kernel if we'll be able to reuse packed weights.
+        let prepacked_kernel = if packed_kernel.is_none() && batch > 1 {
+            Some(gemm.prepack_a_in(pool, kernel_mat).auto_return(pool))
+        } else {
+            None
+        };
+        let prepacked_kernel = packed_kernel
+            .map(|pk| &pk[group])
+            .or(prepacked_kernel.as_deref());
+
+        for n in 0..batch {
+            let in_group = input.slice::<4, _>((n, in_chans.clone()));
+            im2col_3d(
+                &mut columns.view_mut(),
+                in_group,
+                [k_d, k_h, k_w],
+                out_size,
+                [pad_front, pad_top, pad_left],
+                strides,
+                dilations,
+            );
+
+            // Safety: `im2col_3d` initialized all elements of `columns`.
+            let columns = unsafe { columns.view().assume_init() };
+
+            let mut out_mat = output.slice_mut::<2, _>((n, out_chans.clone()));
+            let out_row_stride = out_mat.stride(0);
+            gemm.gemm_uninit_bias_activation(
+                out_mat.data_mut().unwrap(),
+                out_row_stride,
+                prepacked_kernel
+                    .map(GemmInputA::Packed)
+                    .unwrap_or(GemmInputA::Unpacked(kernel_mat)),
+                GemmInputB::Unpacked(columns),
+                1., // alpha
+                bias.as_ref()
+                    .map(|b| BiasVector::Column(&b.data().unwrap()[out_chans.clone()])),
+                activation,
+            );
+            n_init += out_mat.len();
+        }
+    }
+
+    let [out_d, out_h, out_w] = out_size;
+    let output = output.into_shape([batch, out_c, out_d, out_h, out_w]);
+
+    // Safety: We used `gemm_uninit_bias` to initialize all elements.
+    assert!(n_init == output.len());
+    let output = unsafe { output.assume_init() };
+
     Ok(output.into())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Conv {
     pub groups: usize,
     pub dilations: Vec<usize>,
@@ -296,7 +550,7 @@ impl Operator for Conv {
         let input = inputs.require_as(0)?;
         let weight = inputs.require_as(1)?;
         let bias = inputs.get_as(2)?;
-        conv(
+        conv_impl(
             pool,
             input,
             weight,
@@ -305,9 +559,198 @@ impl Operator for Conv {
             self.groups,
             &self.strides,
             &self.dilations,
+            None,
+            get_packed_kernel(&inputs),
+        )
+        .into_op_result()
+    }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        match index {
+            1 => prepack_conv_kernel(input, self),
+            _ => None,
+        }
+    }
+}
+
+/// Pack the kernel of a convolution with `OC...` layout for use as the "A"
+/// input of the GEMMs that compute each group's output.
+///
+/// For convolutions computed using the Winograd algorithm, this instead
+/// returns the transformed kernel, with one matrix per element of the
+/// transformed tiles. Returns `None` for depthwise convolutions, which are
+/// not computed using GEMMs.
+fn prepack_conv_kernel(input: Input, conv: &Conv) -> Option<PrepackedInput> {
+    let Input::FloatTensor(kernel) = input else {
+        return None;
+    };
+    let &[out_c, k_in_c, ..] = kernel.shape() else {
+        return None;
+    };
+    let groups = conv.groups;
+
+    if let &[_, _, k_h, k_w] = kernel.shape() {
+        if use_winograd(
+            [k_h, k_w],
+            k_in_c,
+            out_c,
+            groups,
+            &conv.strides,
+            &conv.dilations,
+        ) {
+            let packed = prepack_winograd_kernel(kernel.nd_view());
+            return Some(PrepackedInput::from_packed_a(packed));
+        }
+    }
+
+    let is_depthwise = k_in_c == 1 && out_c == groups;
+    if out_c == 0 || groups == 0 || out_c % groups != 0 || is_depthwise {
+        return None;
+    }
+
+    let kernel = kernel.to_contiguous();
+    let kernel_mat = kernel.reshaped([out_c, kernel.len() / out_c]);
+    let out_channels_per_group = out_c / groups;
+    let gemm = GemmExecutor::new();
+    let packed = (0..groups)
+        .map(|group| {
+            let out_chans = group * out_channels_per_group..(group + 1) * out_channels_per_group;
+            gemm.prepack_a(kernel_mat.slice::<2, _>(out_chans))
+        })
+        .collect();
+    Some(PrepackedInput::from_packed_a(packed))
+}
+
+/// Get the per-group kernel matrices prepacked by [`prepack_conv_kernel`].
+fn get_packed_kernel<'a>(inputs: &InputList<'a>) -> Option<&'a [PackedAMatrix]> {
+    inputs.get_prepacked(1).and_then(|p| p.packed_a())
+}
+
+/// Convolution followed by an activation function.
+///
+/// This is created by the graph optimizer to fuse activations into the
+/// convolution, so that they are applied as each output tile is computed,
+/// rather than in a separate pass over the output.
+#[derive(Debug)]
+pub struct FusedConv {
+    pub conv: Conv,
+    pub activation: Activation,
+}
+
+impl Operator for FusedConv {
+    fn name(&self) -> &str {
+        "FusedConv"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let weight = inputs.require_as(1)?;
+        let bias = inputs.get_as(2)?;
+        conv_impl(
+            pool,
+            input,
+            weight,
+            bias,
+            self.conv.padding.clone(),
+            self.conv.groups,
+            &self.conv.strides,
+            &self.conv.dilations,
+            Some(self.activation),
+            get_packed_kernel(&inputs),
         )
         .into_op_result()
     }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        match index {
+            1 => prepack_conv_kernel(input, &self.conv),
+            _ => None,
+        }
+    }
+}
+
+/// 2D convolution of an input in channels-last (NHWC) layout, followed by an
+/// optional activation function.
+///
+/// The weights have HWIO layout, ie. `[Kh, Kw, C / groups, M]`, and the output
+/// has dimensions `[N, Ho, Wo, M]`. This is created by the graph optimizer
+/// when running convolution-heavy subgraphs in channels-last layout, with the
+/// weights of the original `Conv` operator reordered ahead of time.
+#[derive(Debug)]
+pub struct ConvNhwc {
+    pub conv: Conv,
+    pub activation: Option<Activation>,
+}
+
+impl Operator for ConvNhwc {
+    fn name(&self) -> &str {
+        "ConvNhwc"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input: TensorView = inputs.require_as(0)?;
+        let input = static_dims!(input, 4, "NHWC")?;
+        let weight: TensorView = inputs.require_as(1)?;
+        let weight = static_dims!(weight, 4, "HWIO")?;
+        let bias: Option<TensorView> = inputs.get_as(2)?;
+        let bias = bias.map(|b| static_dims!(b, 1)).transpose()?;
+
+        let strides: [usize; 2] = self
+            .conv
+            .strides
+            .as_slice()
+            .try_into()
+            .map_err(|_| OpError::InvalidValue("expected 2 stride values"))?;
+        let dilations: [usize; 2] = self
+            .conv
+            .dilations
+            .as_slice()
+            .try_into()
+            .map_err(|_| OpError::InvalidValue("expected 2 dilation values"))?;
+
+        conv_2d_nhwc(
+            pool,
+            input,
+            weight,
+            bias,
+            self.conv.padding.clone(),
+            self.conv.groups,
+            strides,
+            dilations,
+            self.activation,
+            inputs.get_prepacked(1).and_then(|p| p.packed_b()),
+        )
+        .map(|output| output.into_dyn())
+        .into_op_result()
+    }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        let Input::FloatTensor(weight) = input else {
+            return None;
+        };
+        if index != 1 || weight.ndim() != 4 {
+            return None;
+        }
+        prepack_nhwc_kernel(
+            weight.nd_view(),
+            self.conv.groups,
+            &self.conv.strides,
+            &self.conv.dilations,
+        )
+        .map(PrepackedInput::from_packed_b)
+    }
 }
 
 /// Unpack columns of a matrix into an image. This is the inverse of the
@@ -331,9 +774,11 @@ fn col2im(
     columns: &NdTensorView<f32, 5>,
     padding: [usize; 4],
     strides: [usize; 2],
+    dilations: [usize; 2],
     bias: Option<NdTensorView<f32, 1>>,
 ) {
     let [stride_h, stride_w] = strides;
+    let [dilation_h, dilation_w] = dilations;
     let [pad_top, pad_left, _pad_bottom, _pad_right] = padding;
     let [col_chans, kernel_h, kernel_w, _img_h, _img_w] = columns.shape();
     let [out_chans, out_h, out_w] = output.shape();
@@ -353,13 +798,13 @@ fn col2im(
                 let [img_h, img_w] = in_img.shape();
 
                 for y in 0..img_h {
-                    let out_y = y * stride_h + k_y;
+                    let out_y = y * stride_h + k_y * dilation_h;
                     if out_y < pad_top || out_y >= out_h + pad_top {
                         continue;
                     }
 
                     for x in 0..img_w {
-                        let out_x = x * stride_w + k_x;
+                        let out_x = x * stride_w + k_x * dilation_w;
                         if out_x < pad_left || out_x >= out_w + pad_left {
                             continue;
                         }
@@ -421,7 +866,7 @@ fn conv_transpose_output_size_and_padding(
             let pad_left = pad_w / 2;
             let pad_right = pad_w.div_ceil(2);
 
-            Ok(([out_h, out_w], [pad_top, pad_bottom, pad_left, pad_right]))
+            Ok(([out_h, out_w], [pad_top, pad_left, pad_bottom, pad_right]))
         }
         Padding::Fixed(pads) => match pads.as_slice() {
             &[pad_top, pad_left, pad_bottom, pad_right] => {
@@ -439,17 +884,262 @@ fn conv_transpose_output_size_and_padding(
     }
 }
 
-/// Perform a transposed 2D convolution of a tensor by a kernel.
+/// 3D variant of [`conv_transpose_output_size_and_padding`].
+///
+/// Fixed padding is specified as `[front, top, left, back, bottom, right]`
+/// and the returned padding uses the same order.
+fn conv_transpose_output_size_and_padding_3d(
+    input_shape: [usize; 3],
+    kernel_shape: [usize; 3],
+    padding: Padding,
+    strides: [usize; 3],
+) -> Result<([usize; 3], [usize; 6]), OpError> {
+    let [in_d, in_h, in_w] = input_shape;
+    let [k_d, k_h, k_w] = kernel_shape;
+    let [stride_d, stride_h, stride_w] = strides;
+
+    // Each axis is independent, so compute the depth and height axes
+    // together, and then the width axis paired with a unit-sized dummy axis.
+    let (dh_padding, w_padding) = match padding {
+        Padding::Same => (Padding::Same, Padding::Same),
+        Padding::Fixed(pads) => match pads.as_slice() {
+            &[pad_front, pad_top, pad_left, pad_back, pad_bottom, pad_right] => (
+                Padding::Fixed([pad_front, pad_top, pad_back, pad_bottom].into()),
+                Padding::Fixed([0, pad_left, 0, pad_right].into()),
+            ),
+            _ => return Err(OpError::InvalidValue("Wrong number of pad values")),
+        },
+    };
+
+    let ([out_d, out_h], [pad_front, pad_top, pad_back, pad_bottom]) =
+        conv_transpose_output_size_and_padding(
+            [in_d, in_h],
+            [k_d, k_h],
+            dh_padding,
+            [stride_d, stride_h],
+        )?;
+    let ([_, out_w], [_, pad_left, _, pad_right]) =
+        conv_transpose_output_size_and_padding([1, in_w], [1, k_w], w_padding, [1, stride_w])?;
+
+    Ok((
+        [out_d, out_h, out_w],
+        [
+            pad_front, pad_top, pad_left, pad_back, pad_bottom, pad_right,
+        ],
+    ))
+}
+
+/// Unpack columns of a matrix into a volume. This is the 3D equivalent of
+/// [`col2im`].
+///
+/// `output` has shape [O,D,H,W] and `columns` is a view of a matrix
+/// (O x Kd x Kh x Kw, Di * Hi * Wi) reshaped to [O,Kd,Kh,Kw,Di,Hi,Wi].
+///
+/// `padding` specifies the padding at the start of each spatial axis.
+fn col2im_3d(
+    output: &mut NdTensorViewMut<MaybeUninit<f32>, 4>,
+    columns: &NdTensorView<f32, 7>,
+    padding: [usize; 3],
+    strides: [usize; 3],
+    dilations: [usize; 3],
+    bias: Option<NdTensorView<f32, 1>>,
+) {
+    let [stride_d, stride_h, stride_w] = strides;
+    let [dilation_d, dilation_h, dilation_w] = dilations;
+    let [pad_front, pad_top, pad_left] = padding;
+    let [col_chans, kernel_d, kernel_h, kernel_w, img_d, img_h, img_w] = columns.shape();
+    let [out_chans, out_d, out_h, out_w] = output.shape();
+    assert!(col_chans == out_chans);
+
+    output
+        .axis_iter_mut(0)
+        .enumerate()
+        .par_bridge()
+        .for_each(|(out_c, mut out_vol)| {
+            // Initialize each output channel just before we accumulate into it.
+            out_vol.fill(MaybeUninit::new(bias.map(|b| b[[out_c]]).unwrap_or(0.)));
+
+            // Safety: We just initialized all elements of `out_vol`.
+            let mut out_vol = unsafe { out_vol.assume_init() };
+
+            for k_z in 0..kernel_d {
+                for k_y in 0..kernel_h {
+                    for k_x in 0..kernel_w {
+                        let in_vol = columns.slice::<3, _>([out_c, k_z, k_y, k_x]);
+
+                        for z in 0..img_d {
+                            let out_z = z * stride_d + k_z * dilation_d;
+                            if out_z < pad_front || out_z >= out_d + pad_front {
+                                continue;
+                            }
+
+                            for y in 0..img_h {
+                                let out_y = y * stride_h + k_y * dilation_h;
+                                if out_y < pad_top || out_y >= out_h + pad_top {
+                                    continue;
+                                }
+
+                                for x in 0..img_w {
+                                    let out_x = x * stride_w + k_x * dilation_w;
+                                    if out_x < pad_left || out_x >= out_w + pad_left {
+                                        continue;
+                                    }
+                                    unsafe {
+                                        *out_vol.get_unchecked_mut([
+                                            out_z - pad_front,
+                                            out_y - pad_top,
+                                            out_x - pad_left,
+                                        ]) += in_vol.get_unchecked([z, y, x]);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+}
+
+/// Check the channel counts and group count of a transposed convolution,
+/// and return the number of output channels.
+///
+/// `k_out_c` is the number of output channels per group in the kernel.
+fn conv_transpose_channels(
+    in_c: usize,
+    k_in_c: usize,
+    k_out_c: usize,
+    groups: usize,
+) -> Result<usize, OpError> {
+    if in_c != k_in_c {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels does not match kernel input channels",
+        ));
+    }
+    if groups == 0 || !in_c.is_multiple_of(groups) {
+        return Err(OpError::InvalidValue(
+            "Input channels must be divisible by group count",
+        ));
+    }
+    Ok(k_out_c * groups)
+}
+
+/// Perform a transposed 3D convolution of a tensor by a kernel.
+///
+/// `input` has dimensions NCDHW and `kernel` has dimensions CODHW where `O`
+/// is the number of output channels per group.
+fn conv_transpose_3d(
+    pool: &TensorPool,
+    input: NdTensorView<f32, 5>,
+    kernel: NdTensorView<f32, 5>,
+    bias: Option<NdTensorView<f32, 1>>,
+    padding: Padding,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+) -> Result<Tensor, OpError> {
+    let [batch, in_c, in_d, in_h, in_w] = input.shape();
+    let [k_in_c, k_out_c, k_d, k_h, k_w] = kernel.shape();
+    let out_c = conv_transpose_channels(in_c, k_in_c, k_out_c, groups)?;
+
+    let strides: [usize; 3] = strides
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 stride values"))?;
+    let dilations: [usize; 3] = dilations
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 3 dilation values"))?;
+
+    let (out_shape, fixed_padding) = conv_transpose_output_size_and_padding_3d(
+        [in_d, in_h, in_w],
+        dilated_kernel_size([k_d, k_h, k_w], dilations),
+        padding,
+        strides,
+    )?;
+    let [out_d, out_h, out_w] = out_shape;
+    let [pad_front, pad_top, pad_left, _pad_back, _pad_bottom, _pad_right] = fixed_padding;
+
+    let mut output = NdTensor::uninit_in(pool, [batch, out_c, out_d, out_h, out_w]);
+
+    // Ensure input and kernel are contiguous to support reshaping.
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let kernel = kernel.to_contiguous_in(pool).auto_return(pool);
+
+    let n_pixels = in_d * in_h * in_w;
+    let in_group_c = in_c / groups;
+    let mut col2im_mat =
+        NdTensor::uninit_in(pool, [k_out_c * k_d * k_h * k_w, n_pixels]).auto_return(pool);
+    let gemm = GemmExecutor::new();
+
+    // The implementation here is the inverse of the im2col-based convolution.
+    let mut n_init = 0;
+    for n in 0..batch {
+        for g in 0..groups {
+            let in_chans = g * in_group_c..(g + 1) * in_group_c;
+            let out_chans = g * k_out_c..(g + 1) * k_out_c;
+
+            let kernel_mat = kernel
+                .slice::<5, _>(in_chans.clone())
+                .reshaped([in_group_c, k_out_c * k_d * k_h * k_w])
+                .transposed();
+            let input_mat = input
+                .slice::<4, _>((n, in_chans))
+                .reshaped([in_group_c, n_pixels]);
+
+            let col2im_row_stride = col2im_mat.stride(0);
+            gemm.gemm_uninit(
+                col2im_mat.data_mut().unwrap(),
+                col2im_row_stride,
+                GemmInputA::Unpacked(kernel_mat),
+                GemmInputB::Unpacked(input_mat),
+                1., /* alpha */
+            );
+
+            // Safety: `gemm_uninit` initialized col2im_mat.
+            let col2im_mat = unsafe { col2im_mat.view().assume_init() };
+            let mut out_vol = output.slice_mut((n, out_chans.clone()));
+
+            col2im_3d(
+                &mut out_vol,
+                &col2im_mat.reshaped([k_out_c, k_d, k_h, k_w, in_d, in_h, in_w]),
+                [pad_front, pad_top, pad_left],
+                strides,
+                dilations,
+                bias.map(|b| b.slice(out_chans)),
+            );
+            n_init += out_vol.len();
+        }
+    }
+
+    assert!(n_init == output.len());
+    let output = unsafe { output.assume_init() };
+    Ok(output.into())
+}
+
+/// Return the size of the region of the input covered by a kernel of size
+/// `kernel_size` with dilations `dilations`.
+fn dilated_kernel_size<const N: usize>(
+    kernel_size: [usize; N],
+    dilations: [usize; N],
+) -> [usize; N] {
+    std::array::from_fn(|i| match kernel_size[i] {
+        0 => 0,
+        size => (size - 1) * dilations[i] + 1,
+    })
+}
+
+/// Perform a transposed 1D, 2D or 3D convolution of a tensor by a kernel.
 ///
-/// `input` has dimensions NCHW and `kernel` has dimensions COHW where `O` is
-/// the number of output channels.
+/// For the 2D case `input` has dimensions NCHW and `kernel` has dimensions
+/// COHW where `O` is the number of output channels per group. The 1D and 3D
+/// cases are similar but with one fewer or one more spatial dimension.
 pub fn conv_transpose(
     pool: &TensorPool,
     input: TensorView,
     kernel: TensorView,
     bias: Option<TensorView>,
     padding: Padding,
+    groups: usize,
     strides: &[usize],
+    dilations: &[usize],
 ) -> Result<Tensor, OpError> {
     // Handle 1D transposed convolution by expanding to 2D and then removing
     // the extra dimension from the result.
@@ -470,8 +1160,23 @@ pub fn conv_transpose(
                 return Err(OpError::InvalidValue("expected 1 stride value"));
             }
         };
+        let dilations_2d = match dilations {
+            &[dilation] => [1, dilation],
+            _ => {
+                return Err(OpError::InvalidValue("expected 1 dilation value"));
+            }
+        };
 
-        let result_2d = conv_transpose(pool, input_2d, kernel_2d, bias, padding_2d, &strides_2d);
+        let result_2d = conv_transpose(
+            pool,
+            input_2d,
+            kernel_2d,
+            bias,
+            padding_2d,
+            groups,
+            &strides_2d,
+            &dilations_2d,
+        );
 
         return result_2d.map(|mut t| {
             let [n, c, _h, w]: [usize; 4] = t.shape().try_into().expect("expected 4D output");
@@ -480,79 +1185,100 @@ pub fn conv_transpose(
         });
     }
 
+    if input.ndim() == 5 {
+        let input = static_dims!(input, 5, "NCDHW")?;
+        let kernel = static_dims!(kernel, 5, "CODHW")?;
+        let bias = bias.map(|b| static_dims!(b, 1)).transpose()?;
+        return conv_transpose_3d(
+            pool, input, kernel, bias, padding, groups, strides, dilations,
+        );
+    }
+
     let [batch, in_c, in_h, in_w] = check_dims!(input, 4, "NCHW");
-    let [k_in_c, out_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
+    let [k_in_c, k_out_c, k_h, k_w] = check_dims!(kernel, 4, "OCHW");
     check_dims!(bias?, 1);
 
-    let bias = bias.map(|b| b.nd_view());
-
-    if in_c != k_in_c {
-        return Err(OpError::IncompatibleInputShapes(
-            "Input channels does not match kernel input channels",
-        ));
-    }
+    let bias = bias.map(|b| b.nd_view::<1>());
+    let out_c = conv_transpose_channels(in_c, k_in_c, k_out_c, groups)?;
 
     let &[stride_h, stride_w] = strides else {
         return Err(OpError::InvalidValue("expected 2 stride values"));
     };
+    let dilations: [usize; 2] = dilations
+        .try_into()
+        .map_err(|_| OpError::InvalidValue("expected 2 dilation values"))?;
 
     let (out_shape, fixed_padding) = conv_transpose_output_size_and_padding(
         [in_h, in_w],
-        [k_h, k_w],
+        dilated_kernel_size([k_h, k_w], dilations),
         padding,
         [stride_h, stride_w],
     )?;
     let [out_h, out_w] = out_shape;
     let [pad_top, pad_left, pad_bottom, pad_right] = fixed_padding;
 
-    let mut output = Tensor::uninit_in(pool, [batch, out_c, out_h, out_w].as_slice());
+    let mut output = NdTensor::uninit_in(pool, [batch, out_c, out_h, out_w]);
 
     // Ensure input and kernel are contiguous to support reshaping.
     let input = input.to_contiguous_in(pool).auto_return(pool);
     let kernel = kernel.to_contiguous_in(pool).auto_return(pool);
 
+    let in_group_c = in_c / groups;
     let mut col2im_mat =
-        NdTensor::uninit_in(pool, [out_c * k_h * k_w, in_h * in_w]).auto_return(pool);
-    let kernel_mat = kernel.reshaped([k_in_c, out_c * k_h * k_w]).transposed();
+        NdTensor::uninit_in(pool, [k_out_c * k_h * k_w, in_h * in_w]).auto_return(pool);
     let gemm = GemmExecutor::new();
 
     // The implementation here is the inverse of the im2col-based convolution.
     let mut n_init = 0;
     for n in 0..batch {
-        let input_mat = input.slice::<3, _>([n]).reshaped([in_c, in_h * in_w]);
-
-        let col2im_row_stride = col2im_mat.stride(0);
-        gemm.gemm_uninit(
-            col2im_mat.data_mut().unwrap(),
-            col2im_row_stride,
-            GemmInputA::Unpacked(kernel_mat),
-            GemmInputB::Unpacked(input_mat),
-            1., /* alpha */
-        );
-
-        // Safety: `gemm_uninit` initialized col2im_mat.
-        let col2im_mat = unsafe { col2im_mat.view().assume_init() };
-        let mut out_img = output.slice_mut(n);
+        for g in 0..groups {
+            let in_chans = g * in_group_c..(g + 1) * in_group_c;
+            let out_chans = g * k_out_c..(g + 1) * k_out_c;
+
+            let kernel_mat = kernel
+                .slice::<4, _>(in_chans.clone())
+                .reshaped([in_group_c, k_out_c * k_h * k_w])
+                .transposed();
+            let input_mat = input
+                .slice::<3, _>((n, in_chans))
+                .reshaped([in_group_c, in_h * in_w]);
+
+            let col2im_row_stride = col2im_mat.stride(0);
+            gemm.gemm_uninit(
+                col2im_mat.data_mut().unwrap(),
+                col2im_row_stride,
+                GemmInputA::Unpacked(kernel_mat),
+                GemmInputB::Unpacked(input_mat),
+                1., /* alpha */
+            );
 
-        col2im(
-            &mut out_img,
-            &col2im_mat.reshaped([out_c, k_h, k_w, in_h, in_w]),
-            [pad_top, pad_left, pad_right, pad_bottom],
-            [stride_h, stride_w],
-            bias,
-        );
-        n_init += out_img.len();
+            // Safety: `gemm_uninit` initialized col2im_mat.
+            let col2im_mat = unsafe { col2im_mat.view().assume_init() };
+            let mut out_img = output.slice_mut((n, out_chans.clone()));
+
+            col2im(
+                &mut out_img,
+                &col2im_mat.reshaped([k_out_c, k_h, k_w, in_h, in_w]),
+                [pad_top, pad_left, pad_right, pad_bottom],
+                [stride_h, stride_w],
+                dilations,
+                bias.map(|b| b.slice(out_chans)),
+            );
+            n_init += out_img.len();
+        }
     }
 
     assert!(n_init == output.len());
     let output = unsafe { output.assume_init() };
-    Ok(output)
+    Ok(output.into_dyn())
 }
 
 #[derive(Debug)]
 pub struct ConvTranspose {
+    pub groups: usize,
     pub padding: Padding,
     pub strides: Vec<usize>,
+    pub dilations: Vec<usize>,
 }
 
 impl Operator for ConvTranspose {
@@ -570,7 +1296,9 @@ impl Operator for ConvTranspose {
             weight,
             bias,
             self.padding.clone(),
+            self.groups,
             &self.strides,
+            &self.dilations,
         )
         .into_op_result()
     }
@@ -583,15 +1311,19 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::{expect_equal, ExpectEqualError};
-    use rten_tensor::{Tensor, TensorView};
+    use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
 
+    use crate::gemm::Activation;
     use crate::ops::pooling::calc_output_size_and_padding;
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::tests::new_pool;
-    use crate::ops::{conv, conv_transpose, Conv, OpError, Operator, Padding};
+    use crate::ops::{conv, conv_transpose, Conv, InputList, OpError, Operator, Padding};
     use crate::tensor_pool::AutoReturn;
 
-    use super::conv_transpose_output_size_and_padding;
+    use super::{
+        conv_2d_im2col, conv_impl, conv_transpose_output_size_and_padding, prepack_winograd_kernel,
+        use_winograd, ConvNhwc, FusedConv,
+    };
 
     /// Un-optimized reference implementation of convolution.
     ///
@@ -1152,6 +1884,753 @@ mod tests {
         assert_eq!(result.shape(), &[n, out_c, in_w]);
     }
 
+    /// Un-optimized reference implementation of 3D convolution.
+    fn reference_conv_3d(
+        input: NdTensorView<f32, 5>,
+        kernel: NdTensorView<f32, 5>,
+        bias: Option<NdTensorView<f32, 1>>,
+        pads: [usize; 6],
+        groups: usize,
+        strides: [usize; 3],
+        dilations: [usize; 3],
+    ) -> NdTensor<f32, 5> {
+        let [batch, _in_c, in_d, in_h, in_w] = input.shape();
+        let [out_c, k_in_c, k_d, k_h, k_w] = kernel.shape();
+        let [pad_front, pad_top, pad_left, pad_back, pad_bottom, pad_right] = pads;
+        let out_size = |in_size, pad, k, stride, dilation| {
+            (in_size + pad - dilation * (k - 1) - 1) / stride + 1
+        };
+        let out_d = out_size(in_d, pad_front + pad_back, k_d, strides[0], dilations[0]);
+        let out_h = out_size(in_h, pad_top + pad_bottom, k_h, strides[1], dilations[1]);
+        let out_w = out_size(in_w, pad_left + pad_right, k_w, strides[2], dilations[2]);
+        let out_chans_per_group = out_c / groups;
+
+        NdTensor::from_fn([batch, out_c, out_d, out_h, out_w], |[n, oc, z, y, x]| {
+            let group = oc / out_chans_per_group;
+            let mut accum = bias.map(|b| b[[oc]]).unwrap_or(0.);
+            for kc in 0..k_in_c {
+                let ic = group * k_in_c + kc;
+                for kz in 0..k_d {
+                    for ky in 0..k_h {
+                        for kx in 0..k_w {
+                            let in_z =
+                                (z * strides[0] + kz * dilations[0]) as isize - pad_front as isize;
+                            let in_y =
+                                (y * strides[1] + ky * dilations[1]) as isize - pad_top as isize;
+                            let in_x =
+                                (x * strides[2] + kx * dilations[2]) as isize - pad_left as isize;
+                            if (0..in_d as isize).contains(&in_z)
+                                && (0..in_h as isize).contains(&in_y)
+                                && (0..in_w as isize).contains(&in_x)
+                            {
+                                accum += input
+                                    [[n, ic, in_z as usize, in_y as usize, in_x as usize]]
+                                    * kernel[[oc, kc, kz, ky, kx]];
+                            }
+                        }
+                    }
+                }
+            }
+            accum
+        })
+    }
+
+    #[test]
+    fn test_conv_3d() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+
+        struct Case {
+            in_chans: usize,
+            out_chans: usize,
+            kernel_size: [usize; 3],
+            pads: [usize; 6],
+            groups: usize,
+            strides: [usize; 3],
+            dilations: [usize; 3],
+        }
+
+        let default = Case {
+            in_chans: 3,
+            out_chans: 4,
+            kernel_size: [3, 3, 3],
+            pads: [0; 6],
+            groups: 1,
+            strides: [1, 1, 1],
+            dilations: [1, 1, 1],
+        };
+
+        let cases = [
+            Case { ..default },
+            Case {
+                pads: [1, 1, 1, 1, 1, 1],
+                ..default
+            },
+            Case {
+                pads: [0, 1, 2, 2, 1, 0],
+                kernel_size: [2, 3, 1],
+                ..default
+            },
+            Case {
+                strides: [2, 1, 2],
+                ..default
+            },
+            Case {
+                dilations: [2, 1, 2],
+                ..default
+            },
+            Case {
+                in_chans: 4,
+                groups: 2,
+                ..default
+            },
+            Case {
+                in_chans: 4,
+                out_chans: 4,
+                groups: 4,
+                pads: [1, 1, 1, 1, 1, 1],
+                ..default
+            },
+        ];
+
+        for Case {
+            in_chans,
+            out_chans,
+            kernel_size,
+            pads,
+            groups,
+            strides,
+            dilations,
+        } in cases
+        {
+            let [k_d, k_h, k_w] = kernel_size;
+            let input = NdTensor::rand([2, in_chans, 5, 6, 7], &mut rng);
+            let kernel = NdTensor::rand([out_chans, in_chans / groups, k_d, k_h, k_w], &mut rng);
+            let bias = NdTensor::rand([out_chans], &mut rng);
+
+            let result = conv(
+                &pool,
+                input.as_dyn(),
+                kernel.as_dyn(),
+                Some(bias.as_dyn()),
+                pads.into(),
+                groups,
+                &strides,
+                &dilations,
+            )?;
+            let expected = reference_conv_3d(
+                input.view(),
+                kernel.view(),
+                Some(bias.view()),
+                pads,
+                groups,
+                strides,
+                dilations,
+            );
+            expect_eq_1e4(&result.view(), &expected.as_dyn())?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fused_conv() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+
+        struct Case {
+            input_shape: Vec<usize>,
+            kernel_shape: Vec<usize>,
+            groups: usize,
+            padding: Padding,
+        }
+
+        let cases = [
+            // Pointwise
+            Case {
+                input_shape: vec![2, 4, 5, 5],
+                kernel_shape: vec![6, 4, 1, 1],
+                groups: 1,
+                padding: [0, 0, 0, 0].into(),
+            },
+            // Depthwise
+            Case {
+                input_shape: vec![1, 4, 5, 5],
+                kernel_shape: vec![4, 1, 3, 3],
+                groups: 4,
+                padding: [1, 1, 1, 1].into(),
+            },
+            // General 2D
+            Case {
+                input_shape: vec![1, 3, 9, 9],
+                kernel_shape: vec![5, 3, 3, 3],
+                groups: 1,
+                padding: [1, 1, 1, 1].into(),
+            },
+            // 1D
+            Case {
+                input_shape: vec![1, 3, 9],
+                kernel_shape: vec![5, 3, 3],
+                groups: 1,
+                padding: [1, 1].into(),
+            },
+            // 3D
+            Case {
+                input_shape: vec![1, 2, 4, 4, 4],
+                kernel_shape: vec![3, 2, 2, 2, 2],
+                groups: 1,
+                padding: [0; 6].into(),
+            },
+        ];
+
+        let activations = [
+            Activation::Relu,
+            Activation::LeakyRelu { alpha: 0.1 },
+            Activation::Clip { min: 0., max: 0.5 },
+            Activation::Sigmoid,
+        ];
+
+        for Case {
+            input_shape,
+            kernel_shape,
+            groups,
+            padding,
+        } in cases
+        {
+            // Shift values so that activations have a range of inputs.
+            let mut input = Tensor::rand(&input_shape, &mut rng);
+            input.apply(|x| x - 0.5);
+            let mut kernel = Tensor::rand(&kernel_shape, &mut rng);
+            kernel.apply(|x| x - 0.5);
+            let bias = Tensor::rand(&[kernel_shape[0]], &mut rng);
+
+            let spatial_dims = input_shape.len() - 2;
+            let conv_op = Conv {
+                groups,
+                dilations: vec![1; spatial_dims],
+                padding,
+                strides: vec![1; spatial_dims],
+            };
+
+            for activation in activations {
+                let mut expected: Tensor = conv_op
+                    .run(
+                        &pool,
+                        InputList::from(&[
+                            input.view().into(),
+                            kernel.view().into(),
+                            bias.view().into(),
+                        ]),
+                    )?
+                    .remove(0)
+                    .try_into()?;
+                activation.apply(expected.data_mut().unwrap());
+
+                let fused_op = FusedConv {
+                    conv: conv_op.clone(),
+                    activation,
+                };
+                let result: Tensor = fused_op
+                    .run(
+                        &pool,
+                        InputList::from(&[
+                            input.view().into(),
+                            kernel.view().into(),
+                            bias.view().into(),
+                        ]),
+                    )?
+                    .remove(0)
+                    .try_into()?;
+
+                expect_eq_1e4(&result, &expected)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conv_nhwc() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+
+        struct Case {
+            // Input shape in NCHW layout.
+            input_shape: [usize; 4],
+            // Kernel shape in OIHW layout.
+            kernel_shape: [usize; 4],
+            groups: usize,
+            padding: Padding,
+            strides: [usize; 2],
+            dilations: [usize; 2],
+        }
+
+        let cases = [
+            // Pointwise
+            Case {
+                input_shape: [2, 6, 4, 5],
+                kernel_shape: [8, 6, 1, 1],
+                groups: 1,
+                padding: [0, 0, 0, 0].into(),
+                strides: [1, 1],
+                dilations: [1, 1],
+            },
+            // Pointwise with strides
+            Case {
+                input_shape: [1, 6, 5, 5],
+                kernel_shape: [4, 6, 1, 1],
+                groups: 1,
+                padding: [0, 0, 0, 0].into(),
+                strides: [2, 2],
+                dilations: [1, 1],
+            },
+            // Depthwise
+            Case {
+                input_shape: [1, 8, 7, 6],
+                kernel_shape: [8, 1, 3, 3],
+                groups: 8,
+                padding: [1, 1, 1, 1].into(),
+                strides: [1, 1],
+                dilations: [1, 1],
+            },
+            // Depthwise with uneven padding, strides and dilations
+            Case {
+                input_shape: [2, 5, 9, 9],
+                kernel_shape: [5, 1, 3, 3],
+                groups: 5,
+                padding: [2, 1, 0, 2].into(),
+                strides: [2, 1],
+                dilations: [2, 2],
+            },
+            // Depthwise with a channel multiplier
+            Case {
+                input_shape: [1, 3, 5, 5],
+                kernel_shape: [6, 1, 3, 3],
+                groups: 3,
+                padding: [1, 1, 1, 1].into(),
+                strides: [1, 1],
+                dilations: [1, 1],
+            },
+            // General 2D
+            Case {
+                input_shape: [1, 3, 9, 9],
+                kernel_shape: [5, 3, 3, 3],
+                groups: 1,
+                padding: [1, 1, 1, 1].into(),
+                strides: [1, 1],
+                dilations: [1, 1],
+            },
+            // Grouped, with "same" padding
+            Case {
+                input_shape: [1, 4, 6, 6],
+                kernel_shape: [6, 2, 3, 3],
+                groups: 2,
+                padding: Padding::Same,
+                strides: [2, 2],
+                dilations: [1, 1],
+            },
+            // Input large enough to be processed in multiple blocks
+            Case {
+                input_shape: [1, 16, 64, 64],
+                kernel_shape: [8, 16, 3, 3],
+                groups: 1,
+                padding: [1, 1, 1, 1].into(),
+                strides: [1, 1],
+                dilations: [1, 1],
+            },
+        ];
+
+        for Case {
+            input_shape,
+            kernel_shape,
+            groups,
+            padding,
+            strides,
+            dilations,
+        } in cases
+        {
+            let mut input = Tensor::rand(&input_shape, &mut rng);
+            input.apply(|x| x - 0.5);
+            let kernel = Tensor::rand(&kernel_shape, &mut rng);
+            let bias = Tensor::rand(&[kernel_shape[0]], &mut rng);
+
+            let conv_op = Conv {
+                groups,
+                dilations: dilations.into(),
+                padding,
+                strides: strides.into(),
+            };
+            let input_nhwc = input.permuted([0, 2, 3, 1].as_slice()).to_tensor();
+            let kernel_hwio = kernel.permuted([2, 3, 1, 0].as_slice()).to_tensor();
+
+            for activation in [None, Some(Activation::Relu)] {
+                let mut expected: Tensor = conv_op
+                    .run(
+                        &pool,
+                        InputList::from(&[
+                            input.view().into(),
+                            kernel.view().into(),
+                            bias.view().into(),
+                        ]),
+                    )?
+                    .remove(0)
+                    .try_into()?;
+                if let Some(activation) = activation {
+                    activation.apply(expected.data_mut().unwrap());
+                }
+                let expected = expected.permuted([0, 2, 3, 1].as_slice()).to_tensor();
+
+                let nhwc_op = ConvNhwc {
+                    conv: conv_op.clone(),
+                    activation,
+                };
+                let result: Tensor = nhwc_op
+                    .run(
+                        &pool,
+                        InputList::from(&[
+                            input_nhwc.view().into(),
+                            kernel_hwio.view().into(),
+                            bias.view().into(),
+                        ]),
+                    )?
+                    .remove(0)
+                    .try_into()?;
+
+                expect_eq_1e4(&result, &expected)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compare convolutions computed using the Winograd algorithm, in NCHW and
+    /// NHWC layouts, against the im2col + GEMM path.
+    #[test]
+    fn test_conv_winograd() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+
+        struct Case {
+            // Input shape in NCHW layout.
+            input_shape: [usize; 4],
+            out_c: usize,
+            padding: Padding,
+        }
+
+        let cases = [
+            // Output size a multiple of the tile size
+            Case {
+                input_shape: [1, 16, 8, 8],
+                out_c: 16,
+                padding: [1, 1, 1, 1].into(),
+            },
+            // Odd output size, with multiple batch items
+            Case {
+                input_shape: [2, 16, 7, 9],
+                out_c: 24,
+                padding: [1, 1, 1, 1].into(),
+            },
+            // No padding
+            Case {
+                input_shape: [1, 20, 9, 6],
+                out_c: 16,
+                padding: [0, 0, 0, 0].into(),
+            },
+            // Uneven padding
+            Case {
+                input_shape: [1, 16, 5, 7],
+                out_c: 16,
+                padding: [2, 0, 1, 1].into(),
+            },
+            // "Same" padding
+            Case {
+                input_shape: [1, 16, 6, 5],
+                out_c: 17,
+                padding: Padding::Same,
+            },
+            // Output smaller than a tile
+            Case {
+                input_shape: [1, 16, 3, 3],
+                out_c: 16,
+                padding: [0, 0, 0, 0].into(),
+            },
+            // Input large enough to be processed in multiple blocks
+            Case {
+                input_shape: [1, 32, 64, 64],
+                out_c: 32,
+                padding: [1, 1, 1, 1].into(),
+            },
+        ];
+
+        for Case {
+            input_shape,
+            out_c,
+            padding,
+        } in cases
+        {
+            let [_, in_c, in_h, in_w] = input_shape;
+            assert!(use_winograd([3, 3], in_c, out_c, 1, &[1, 1], &[1, 1]));
+
+            let mut input = Tensor::rand(&input_shape, &mut rng);
+            input.apply(|x| x - 0.5);
+            let mut kernel = Tensor::rand(&[out_c, in_c, 3, 3], &mut rng);
+            kernel.apply(|x| x - 0.5);
+            let bias = Tensor::rand(&[out_c], &mut rng);
+
+            let (out_h, out_w, fixed_padding) =
+                calc_output_size_and_padding((in_h, in_w), (3, 3), (1, 1), padding.clone(), None)?;
+            let conv_op = Conv {
+                groups: 1,
+                dilations: vec![1, 1],
+                padding,
+                strides: vec![1, 1],
+            };
+            let packed_kernel = prepack_winograd_kernel(kernel.nd_view());
+
+            let input_nhwc = input.permuted([0, 2, 3, 1].as_slice()).to_tensor();
+            let kernel_hwio = kernel.permuted([2, 3, 1, 0].as_slice()).to_tensor();
+
+            for activation in [None, Some(Activation::Relu)] {
+                let expected = conv_2d_im2col(
+                    &pool,
+                    input.view(),
+                    kernel.view(),
+                    Some(bias.view()),
+                    fixed_padding,
+                    1, // groups
+                    [1, 1],
+                    [1, 1],
+                    [out_h, out_w],
+                    activation,
+                    None,
+                );
+
+                for packed_kernel in [None, Some(packed_kernel.as_slice())] {
+                    let result = conv_impl(
+                        &pool,
+                        input.view(),
+                        kernel.view(),
+                        Some(bias.view()),
+                        conv_op.padding.clone(),
+                        conv_op.groups,
+                        &conv_op.strides,
+                        &conv_op.dilations,
+                        activation,
+                        packed_kernel,
+                    )?;
+                    expect_eq_1e4(&result, &expected)?;
+                }
+
+                let expected = expected.permuted([0, 2, 3, 1].as_slice()).to_tensor();
+                let nhwc_op = ConvNhwc {
+                    conv: conv_op.clone(),
+                    activation,
+                };
+                let prepacked = [None, nhwc_op.prepack(1, kernel_hwio.view().into())];
+                assert!(prepacked[1].is_some());
+
+                for prepacked in [&[][..], &prepacked[..]] {
+                    let result: Tensor = nhwc_op
+                        .run(
+                            &pool,
+                            InputList::from(&[
+                                input_nhwc.view().into(),
+                                kernel_hwio.view().into(),
+                                bias.view().into(),
+                            ])
+                            .with_prepacked(prepacked),
+                        )?
+                        .remove(0)
+                        .try_into()?;
+                    expect_eq_1e4(&result, &expected)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reference implementation of transposed 3D convolution, which scatters
+    /// each input element multiplied by the kernel into an unpadded output,
+    /// and then crops it.
+    fn reference_conv_transpose_3d(
+        input: NdTensorView<f32, 5>,
+        kernel: NdTensorView<f32, 5>,
+        bias: NdTensorView<f32, 1>,
+        pads: [usize; 6],
+        groups: usize,
+        strides: [usize; 3],
+        dilations: [usize; 3],
+    ) -> NdTensor<f32, 5> {
+        let [batch, in_c, in_d, in_h, in_w] = input.shape();
+        let [_, k_out_c, k_d, k_h, k_w] = kernel.shape();
+        let in_size = [in_d, in_h, in_w];
+        let kernel_size = [k_d, k_h, k_w];
+        let out_c = k_out_c * groups;
+        let in_group_c = in_c / groups;
+
+        let full_size: [usize; 3] = std::array::from_fn(|i| {
+            (in_size[i] - 1) * strides[i] + (kernel_size[i] - 1) * dilations[i] + 1
+        });
+        let mut full =
+            NdTensor::<f32, 5>::zeros([batch, out_c, full_size[0], full_size[1], full_size[2]]);
+        for [n, ic, z, y, x] in input.indices() {
+            let g = ic / in_group_c;
+            for [oc, kz, ky, kx] in kernel.slice::<4, _>([ic]).indices() {
+                full[[
+                    n,
+                    g * k_out_c + oc,
+                    z * strides[0] + kz * dilations[0],
+                    y * strides[1] + ky * dilations[1],
+                    x * strides[2] + kx * dilations[2],
+                ]] += input[[n, ic, z, y, x]] * kernel[[ic, oc, kz, ky, kx]];
+            }
+        }
+        let [pad_front, pad_top, pad_left, pad_back, pad_bottom, pad_right] = pads;
+        NdTensor::from_fn(
+            [
+                batch,
+                out_c,
+                full_size[0] - pad_front - pad_back,
+                full_size[1] - pad_top - pad_bottom,
+                full_size[2] - pad_left - pad_right,
+            ],
+            |[n, c, z, y, x]| full[[n, c, z + pad_front, y + pad_top, x + pad_left]] + bias[[c]],
+        )
+    }
+
+    #[test]
+    fn test_conv_transpose_3d() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+
+        struct Case {
+            groups: usize,
+            dilations: [usize; 3],
+        }
+
+        let cases = [
+            Case {
+                groups: 1,
+                dilations: [1, 1, 1],
+            },
+            Case {
+                groups: 2,
+                dilations: [1, 1, 1],
+            },
+            Case {
+                groups: 1,
+                dilations: [2, 1, 3],
+            },
+            Case {
+                groups: 2,
+                dilations: [2, 2, 1],
+            },
+        ];
+
+        let [batch, in_c, k_out_c] = [2, 4, 3];
+        let in_size = [3, 4, 5];
+        let kernel_size = [2, 3, 2];
+        let strides = [2, 1, 2];
+        let pads = [1, 0, 1, 0, 1, 1];
+
+        let [in_d, in_h, in_w] = in_size;
+        let [k_d, k_h, k_w] = kernel_size;
+
+        for Case { groups, dilations } in cases {
+            let input = NdTensor::rand([batch, in_c, in_d, in_h, in_w], &mut rng);
+            let kernel = NdTensor::rand([in_c, k_out_c, k_d, k_h, k_w], &mut rng);
+            let bias = NdTensor::rand([k_out_c * groups], &mut rng);
+
+            let expected = reference_conv_transpose_3d(
+                input.view(),
+                kernel.view(),
+                bias.view(),
+                pads,
+                groups,
+                strides,
+                dilations,
+            );
+
+            let result = conv_transpose(
+                &pool,
+                input.as_dyn(),
+                kernel.as_dyn(),
+                Some(bias.as_dyn()),
+                pads.into(),
+                groups,
+                &strides,
+                &dilations,
+            )?;
+            expect_eq_1e4(&result.view(), &expected.as_dyn())?;
+
+            // The 2D implementation should match the 3D implementation with a
+            // depth of 1.
+            let input_2d = input.slice::<4, _>((.., .., 0));
+            let kernel_2d = kernel.slice::<4, _>((.., .., 0));
+            let pads_2d = [pads[1], pads[2], pads[4], pads[5]];
+            let result_2d = conv_transpose(
+                &pool,
+                input_2d.as_dyn(),
+                kernel_2d.as_dyn(),
+                Some(bias.as_dyn()),
+                pads_2d.into(),
+                groups,
+                &strides[1..],
+                &dilations[1..],
+            )?;
+            let expected_2d = reference_conv_transpose_3d(
+                input.slice((.., .., ..1)),
+                kernel.slice((.., .., ..1)),
+                bias.view(),
+                [0, pads[1], pads[2], 0, pads[4], pads[5]],
+                groups,
+                strides,
+                dilations,
+            );
+            expect_eq_1e4(
+                &result_2d.view(),
+                &expected_2d.slice::<4, _>((.., .., 0)).as_dyn(),
+            )?;
+        }
+
+        // "Same" padding. The output shape should be `input_size * stride`
+        // for each spatial axis.
+        let input = NdTensor::rand([batch, in_c, in_d, in_h, in_w], &mut rng);
+        let kernel = NdTensor::rand([in_c, k_out_c, k_d, k_h, k_w], &mut rng);
+        let result = conv_transpose(
+            &pool,
+            input.as_dyn(),
+            kernel.as_dyn(),
+            None,
+            Padding::Same,
+            1, // groups
+            &strides,
+            &[1, 1, 1],
+        )?;
+        assert_eq!(result.shape(), &[batch, k_out_c, in_d * 2, in_h, in_w * 2]);
+
+        // Group count that does not divide the input channels.
+        let result = conv_transpose(
+            &pool,
+            input.as_dyn(),
+            kernel.as_dyn(),
+            None,
+            pads.into(),
+            3, // groups
+            &strides,
+            &[1, 1, 1],
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Input channels must be divisible by group count"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_conv_transpose() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1173,7 +2652,9 @@ mod tests {
             kernel.view(),
             None,
             Padding::zero::<2>(),
+            1, // groups
             &[2, 2],
+            &[1, 1],
         )
         .unwrap();
         expect_equal(&result, &expected)?;
@@ -1189,7 +2670,9 @@ mod tests {
             kernel.view(),
             Some(bias.view()),
             Padding::zero::<2>(),
+            1, // groups
             &[2, 2],
+            &[1, 1],
         )
         .unwrap();
         expect_equal(&result, &expected_with_bias)?;
@@ -1215,7 +2698,9 @@ mod tests {
             kernel.view(),
             None,
             Padding::Fixed([1, 1, 1, 1].into()),
+            1, // groups
             &strides,
+            &[1, 1],
         )
         .unwrap();
         expect_equal(&result, &expected)?;
@@ -1228,7 +2713,9 @@ mod tests {
             kernel.view(),
             None,
             Padding::Same,
+            1, // groups
             &strides,
+            &[1, 1],
         )
         .unwrap();
         assert_eq!(
@@ -1259,7 +2746,9 @@ mod tests {
             kernel.view(),
             None,
             Padding::zero::<1>(),
+            1, // groups
             &[2],
+            &[1],
         )
         .unwrap();
         expect_equal(&result, &expected)?;
@@ -1272,7 +2761,9 @@ mod tests {
             kernel.view(),
             Some(bias.view()),
             Padding::zero::<1>(),
+            1, // groups
             &[2],
+            &[1],
         )
         .unwrap();
         expect_equal(&result, &expected_with_bias)?;
@@ -1338,6 +2829,16 @@ mod tests {
                 strides: [1, 1],
                 expected: Ok(([5, 5], [1, 1, 1, 1])),
             },
+            // Same padding with an odd amount of total padding. The extra
+            // unit goes at the end of the axis, and the pads are returned in
+            // the same `[top, left, bottom, right]` order as fixed padding.
+            Case {
+                input_shape: [5, 5],
+                kernel_shape: [4, 3],
+                padding: Padding::Same,
+                strides: [1, 1],
+                expected: Ok(([5, 5], [1, 1, 2, 1])),
+            },
             // Same padding. Case where output size is smaller than
             // `input_shape * stride` even with no padding.
             Case {
@@ -1468,6 +2969,7 @@ mod tests {
                 &columns.view(),
                 [0, 0, 0, 0], // Padding
                 [stride_y, stride_x],
+                [1, 1], // Dilations
                 None,
             );
         });
@@ -1479,6 +2981,7 @@ mod tests {
                 &columns.view(),
                 [1, 1, 1, 1], // Padding
                 [stride_y, stride_x],
+                [1, 1], // Dilations
                 None,
             );
         });
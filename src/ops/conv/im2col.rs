@@ -1,13 +1,14 @@
 use std::mem::MaybeUninit;
 use std::ops::Range;
 
+use rayon::prelude::*;
 use rten_simd::{vec_count, SimdFloat, SimdInt, SimdMask};
 
 #[cfg(feature = "avx512")]
 use rten_simd::isa_detection::is_avx512_supported;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensorView, Storage};
+use rten_tensor::{NdTensorView, NdTensorViewMut, Storage};
 
 use crate::gemm::{KernelType, VirtualMatrix};
 use crate::ops::pooling::calc_output_size_and_padding;
@@ -365,3 +366,77 @@ unsafe impl<'a> VirtualMatrix for VirtualIm2Col<'a> {
         }
     }
 }
+
+/// Unroll patches of a volume into columns of a matrix.
+///
+/// The input volume has shape [C,D,H,W] and `out` has shape
+/// [C * Kd * Kh * Kw, Od * Oh * Ow], where Kd/Kh/Kw are the kernel sizes and
+/// Od/Oh/Ow are the number of patches along each spatial axis. Elements of
+/// patches which fall into the padding region are set to zero. All elements
+/// of `out` are initialized.
+///
+/// `padding` specifies the padding at the start of each spatial axis.
+///
+/// Unlike [`VirtualIm2Col`], this materializes the whole matrix, which is
+/// then used as an ordinary GEMM input.
+pub fn im2col_3d(
+    out: &mut NdTensorViewMut<MaybeUninit<f32>, 2>,
+    input: NdTensorView<f32, 4>,
+    kernel_size: [usize; 3],
+    out_size: [usize; 3],
+    padding: [usize; 3],
+    strides: [usize; 3],
+    dilations: [usize; 3],
+) {
+    let [in_c, in_d, in_h, in_w] = input.shape();
+    let [k_d, k_h, k_w] = kernel_size;
+    let [out_d, out_h, out_w] = out_size;
+    let [pad_front, pad_top, pad_left] = padding;
+    let [stride_d, stride_h, stride_w] = strides;
+    let [dilation_d, dilation_h, dilation_w] = dilations;
+
+    let n_patches = out_d * out_h * out_w;
+    assert_eq!(out.shape(), [in_c * k_d * k_h * k_w, n_patches]);
+
+    if !out.is_empty() {
+        out.data_mut()
+            .expect("output should be contiguous")
+            .par_chunks_mut(n_patches)
+            .enumerate()
+            .for_each(|(row, out_row)| {
+                let chan = row / (k_d * k_h * k_w);
+                let k_z = (row / (k_h * k_w)) % k_d;
+                let k_y = (row / k_w) % k_h;
+                let k_x = row % k_w;
+                let in_chan = input.slice::<3, _>([chan]);
+
+                let mut out_iter = out_row.iter_mut();
+                for z in 0..out_d {
+                    let in_z = (z * stride_d + k_z * dilation_d) as isize - pad_front as isize;
+                    for y in 0..out_h {
+                        let in_y = (y * stride_h + k_y * dilation_h) as isize - pad_top as isize;
+                        for x in 0..out_w {
+                            let in_x =
+                                (x * stride_w + k_x * dilation_w) as isize - pad_left as isize;
+                            let in_bounds = (0..in_d as isize).contains(&in_z)
+                                && (0..in_h as isize).contains(&in_y)
+                                && (0..in_w as isize).contains(&in_x);
+                            let val = if in_bounds {
+                                // Safety: We checked the coordinates are valid.
+                                unsafe {
+                                    *in_chan.get_unchecked([
+                                        in_z as usize,
+                                        in_y as usize,
+                                        in_x as usize,
+                                    ])
+                                }
+                            } else {
+                                0.
+                            };
+                            out_iter.next().unwrap().write(val);
+                        }
+                    }
+                }
+            });
+    }
+}
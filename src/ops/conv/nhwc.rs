@@ -0,0 +1,413 @@
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView};
+
+use crate::gemm::{Activation, BiasVector, GemmExecutor, GemmInputA, GemmInputB, PackedBMatrix};
+use crate::ops::pooling::calc_output_size_and_padding;
+use crate::ops::{OpError, Padding};
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+use super::winograd::{conv_2d_nhwc_winograd, prepack_winograd_kernel_nhwc, use_winograd};
+
+/// Maximum number of elements in the buffer of image patches that is
+/// multiplied with the kernel in one step of [`conv_2d_nhwc`].
+const MAX_PATCH_BUFFER_LEN: usize = 256 * 1024;
+
+/// Perform a 2D convolution of an input in channels-last (NHWC) layout.
+///
+/// `kernel` has HWIO layout, ie. dimensions `[Kh, Kw, C / groups, M]` where
+/// `M` is the number of output channels. The output has dimensions
+/// `[N, Ho, Wo, M]`. The other parameters have the same meaning as for
+/// [`conv`](crate::ops::conv).
+///
+/// Since the channels of each input pixel are contiguous, depthwise
+/// convolutions are computed directly with the inner loop vectorized over
+/// channels. 3x3 convolutions which satisfy [`use_winograd`] are computed
+/// using the Winograd algorithm. Other convolutions are computed by
+/// multiplying a matrix of image patches with the kernel. For 1x1
+/// convolutions with unit strides and no padding, the input is used as this
+/// matrix without copying.
+///
+/// `packed_kernel` is an optional copy of the kernel prepacked by
+/// [`prepack_nhwc_kernel`].
+pub fn conv_2d_nhwc(
+    pool: &TensorPool,
+    input: NdTensorView<f32, 4>,
+    kernel: NdTensorView<f32, 4>,
+    bias: Option<NdTensorView<f32, 1>>,
+    padding: Padding,
+    groups: usize,
+    strides: [usize; 2],
+    dilations: [usize; 2],
+    activation: Option<Activation>,
+    packed_kernel: Option<&[PackedBMatrix]>,
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let [batch, in_h, in_w, in_c] = input.shape();
+    let [k_h, k_w, k_in_c, out_c] = kernel.shape();
+
+    if groups == 0 || in_c % groups != 0 || out_c % groups != 0 {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels and output channels must be divisible by group count",
+        ));
+    }
+    if in_c / groups != k_in_c {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input channels (per group) does not match kernel input channels",
+        ));
+    }
+    if bias.as_ref().is_some_and(|b| b.size(0) != out_c) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Bias length does not match output channels",
+        ));
+    }
+
+    let [stride_y, stride_x] = strides;
+    let [dilation_y, dilation_x] = dilations;
+    let (out_h, out_w, fixed_padding) = calc_output_size_and_padding(
+        (in_h, in_w),
+        (k_h, k_w),
+        (stride_y, stride_x),
+        padding,
+        Some((dilation_y, dilation_x)),
+    )?;
+
+    let mut output = NdTensor::uninit_in(pool, [batch, out_h, out_w, out_c]);
+    if output.is_empty() {
+        // Safety: Empty output is already initialized.
+        return Ok(unsafe { output.assume_init() });
+    }
+
+    // Use of input pixels below assumes a contiguous input.
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let kernel = kernel.to_contiguous_in(pool).auto_return(pool);
+    let bias = bias.map(|b| b.to_contiguous());
+    let bias = bias.as_ref().map(|b| b.data().unwrap());
+
+    let geometry = ConvGeometry {
+        in_hw: [in_h, in_w],
+        out_hw: [out_h, out_w],
+        kernel_hw: [k_h, k_w],
+        padding: fixed_padding,
+        strides,
+        dilations,
+    };
+
+    if groups == in_c && groups == out_c {
+        conv_2d_nhwc_depthwise(
+            output.data_mut().unwrap(),
+            input.data().unwrap(),
+            kernel.data().unwrap(),
+            bias,
+            in_c,
+            &geometry,
+            activation,
+        );
+    } else if use_winograd([k_h, k_w], k_in_c, out_c, groups, &strides, &dilations) {
+        conv_2d_nhwc_winograd(
+            pool,
+            output.data_mut().unwrap(),
+            input.view(),
+            kernel.view(),
+            bias,
+            fixed_padding,
+            [out_h, out_w],
+            activation,
+            packed_kernel,
+        );
+    } else {
+        conv_2d_nhwc_gemm(
+            pool,
+            output.data_mut().unwrap(),
+            input.view(),
+            kernel.view(),
+            bias,
+            groups,
+            &geometry,
+            activation,
+            packed_kernel.filter(|pk| pk.len() == groups),
+        );
+    }
+
+    // Safety: The depthwise, Winograd and GEMM paths initialize all output
+    // elements.
+    Ok(unsafe { output.assume_init() })
+}
+
+/// Pack an HWIO convolution kernel for use as the "B" input of the GEMMs that
+/// compute each group's output.
+///
+/// For convolutions computed using the Winograd algorithm, this instead
+/// returns the transformed kernel. Returns `None` for depthwise convolutions,
+/// which are not computed using GEMMs.
+pub fn prepack_nhwc_kernel(
+    kernel: NdTensorView<f32, 4>,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+) -> Option<Vec<PackedBMatrix>> {
+    let [k_h, k_w, k_in_c, out_c] = kernel.shape();
+    if use_winograd([k_h, k_w], k_in_c, out_c, groups, strides, dilations) {
+        return Some(prepack_winograd_kernel_nhwc(kernel));
+    }
+    let is_depthwise = k_in_c == 1 && out_c == groups;
+    if groups == 0 || out_c % groups != 0 || is_depthwise {
+        return None;
+    }
+
+    let kernel = kernel.to_contiguous();
+    let kernel_mat = kernel.reshaped([k_h * k_w * k_in_c, out_c]);
+    let out_chans_per_group = out_c / groups;
+    let gemm = GemmExecutor::new();
+    let packed = (0..groups)
+        .map(|group| {
+            let chans = group * out_chans_per_group..(group + 1) * out_chans_per_group;
+            gemm.prepack_b(kernel_mat.slice::<2, _>((.., chans)))
+        })
+        .collect();
+    Some(packed)
+}
+
+/// Spatial sizes, padding, strides and dilations of a 2D convolution.
+struct ConvGeometry {
+    in_hw: [usize; 2],
+    out_hw: [usize; 2],
+    kernel_hw: [usize; 2],
+    padding: [usize; 4],
+    strides: [usize; 2],
+    dilations: [usize; 2],
+}
+
+impl ConvGeometry {
+    /// Return the input coordinates that kernel element `k_yx` is applied to
+    /// when computing output pixel `out_yx`, or `None` if the kernel element
+    /// is over the padding.
+    fn input_coords(&self, out_yx: [usize; 2], k_yx: [usize; 2]) -> Option<[usize; 2]> {
+        let [pad_top, pad_left, _pad_bottom, _pad_right] = self.padding;
+        let pads = [pad_top, pad_left];
+        let mut coords = [0; 2];
+        for i in 0..2 {
+            let padded_coord = out_yx[i] * self.strides[i] + k_yx[i] * self.dilations[i];
+            if padded_coord < pads[i] || padded_coord >= self.in_hw[i] + pads[i] {
+                return None;
+            }
+            coords[i] = padded_coord - pads[i];
+        }
+        Some(coords)
+    }
+}
+
+/// Compute a depthwise convolution of an NHWC input.
+///
+/// `kernel` has dimensions `[Kh, Kw, 1, C]`, so the weights for each kernel
+/// position are contiguous, like the channels of each input pixel.
+fn conv_2d_nhwc_depthwise(
+    output: &mut [MaybeUninit<f32>],
+    input: &[f32],
+    kernel: &[f32],
+    bias: Option<&[f32]>,
+    chans: usize,
+    geometry: &ConvGeometry,
+    activation: Option<Activation>,
+) {
+    let [in_h, in_w] = geometry.in_hw;
+    let [out_h, out_w] = geometry.out_hw;
+    let [k_h, k_w] = geometry.kernel_hw;
+
+    let n_init = AtomicUsize::new(0);
+    output
+        .par_chunks_mut(out_w * chans)
+        .enumerate()
+        .for_each(|(row, out_row)| {
+            let (n, out_y) = (row / out_h, row % out_h);
+
+            for (out_x, out_pixel) in out_row.chunks_mut(chans).enumerate() {
+                match bias {
+                    Some(bias) => {
+                        for (out, &b) in out_pixel.iter_mut().zip(bias) {
+                            out.write(b);
+                        }
+                    }
+                    None => out_pixel.fill(MaybeUninit::new(0.)),
+                }
+
+                // Safety: We just initialized all elements of `out_pixel`.
+                let out_pixel: &mut [f32] = unsafe { std::mem::transmute(out_pixel) };
+
+                for k_y in 0..k_h {
+                    for k_x in 0..k_w {
+                        let Some([in_y, in_x]) = geometry.input_coords([out_y, out_x], [k_y, k_x])
+                        else {
+                            continue;
+                        };
+                        let in_offset = ((n * in_h + in_y) * in_w + in_x) * chans;
+                        let in_pixel = &input[in_offset..][..chans];
+                        let weights = &kernel[(k_y * k_w + k_x) * chans..][..chans];
+                        for ((out, &x), &w) in out_pixel.iter_mut().zip(in_pixel).zip(weights) {
+                            *out += x * w;
+                        }
+                    }
+                }
+            }
+
+            // Safety: All elements of the row were initialized above.
+            let out_row: &mut [f32] = unsafe { std::mem::transmute(out_row) };
+            if let Some(activation) = activation {
+                activation.apply(out_row);
+            }
+            n_init.fetch_add(out_row.len(), Ordering::SeqCst);
+        });
+    assert!(n_init.load(Ordering::SeqCst) == output.len());
+}
+
+/// Compute a convolution of an NHWC input by multiplying a matrix of image
+/// patches with the kernel for each group.
+///
+/// The output is computed in blocks of rows, so that the patch matrix for
+/// each block stays small.
+fn conv_2d_nhwc_gemm(
+    pool: &TensorPool,
+    output: &mut [MaybeUninit<f32>],
+    input: NdTensorView<f32, 4>,
+    kernel: NdTensorView<f32, 4>,
+    bias: Option<&[f32]>,
+    groups: usize,
+    geometry: &ConvGeometry,
+    activation: Option<Activation>,
+    packed_kernel: Option<&[PackedBMatrix]>,
+) {
+    let [batch, in_h, in_w, in_c] = input.shape();
+    let [k_h, k_w, k_in_c, out_c] = kernel.shape();
+    let [out_h, out_w] = geometry.out_hw;
+    let out_chans_per_group = out_c / groups;
+    let gemm = GemmExecutor::new();
+
+    let kernel_mat = kernel.reshaped([k_h * k_w * k_in_c, out_c]);
+    let group_kernel = |group: usize| {
+        let chans = group * out_chans_per_group..(group + 1) * out_chans_per_group;
+        kernel_mat.slice::<2, _>((.., chans))
+    };
+    let group_bias = |group: usize| {
+        bias.map(|b| {
+            BiasVector::Row(&b[group * out_chans_per_group..(group + 1) * out_chans_per_group])
+        })
+    };
+
+    // For pointwise convolutions with unit strides and no padding, the input
+    // is already a `[N * H * W, C]` matrix of patches.
+    let is_pointwise = k_h == 1
+        && k_w == 1
+        && groups == 1
+        && geometry.strides == [1, 1]
+        && geometry.padding == [0, 0, 0, 0];
+    if is_pointwise {
+        let in_mat = input.reshaped([batch * in_h * in_w, in_c]);
+        gemm.gemm_uninit_bias_activation(
+            output,
+            out_c,
+            GemmInputA::Unpacked(in_mat),
+            packed_kernel
+                .map(|pk| GemmInputB::Packed(&pk[0]))
+                .unwrap_or(GemmInputB::Unpacked(group_kernel(0))),
+            1., // alpha
+            group_bias(0),
+            activation,
+        );
+        return;
+    }
+
+    let patch_len = k_h * k_w * k_in_c;
+    let rows_per_block = (MAX_PATCH_BUFFER_LEN / (out_w * patch_len).max(1)).max(1);
+    let n_blocks = (batch * out_h).div_ceil(rows_per_block);
+
+    // Prepack kernel if we'll be able to reuse packed weights.
+    let packed_kernels: Vec<_> = (0..groups)
+        .map(|group| {
+            (packed_kernel.is_none() && n_blocks > 1).then(|| {
+                gemm.prepack_b_in(pool, group_kernel(group))
+                    .auto_return(pool)
+            })
+        })
+        .collect();
+    let packed_kernels: Vec<_> = packed_kernels
+        .iter()
+        .enumerate()
+        .map(|(group, pk)| packed_kernel.map(|pk| &pk[group]).or(pk.as_deref()))
+        .collect();
+
+    let input = input.data().unwrap();
+    let n_init = AtomicUsize::new(0);
+
+    output
+        .par_chunks_mut(rows_per_block * out_w * out_c)
+        .enumerate()
+        .for_each(|(block, out_block)| {
+            let n_pixels = out_block.len() / out_c;
+            let first_pixel = block * rows_per_block * out_w;
+            let mut patches = vec![0.; n_pixels * patch_len];
+            let mut group_out_buf = if groups > 1 {
+                vec![MaybeUninit::uninit(); n_pixels * out_chans_per_group]
+            } else {
+                Vec::new()
+            };
+
+            for (group, packed_kernel) in packed_kernels.iter().enumerate() {
+                let in_chans = group * k_in_c..(group + 1) * k_in_c;
+
+                for (pixel, patch) in patches.chunks_mut(patch_len).enumerate() {
+                    let pixel = first_pixel + pixel;
+                    let (n, out_y, out_x) = (
+                        pixel / (out_h * out_w),
+                        (pixel / out_w) % out_h,
+                        pixel % out_w,
+                    );
+
+                    for (k_yx, patch_chans) in patch.chunks_mut(k_in_c).enumerate() {
+                        let k_yx = [k_yx / k_w, k_yx % k_w];
+                        if let Some([in_y, in_x]) = geometry.input_coords([out_y, out_x], k_yx) {
+                            let in_offset = ((n * in_h + in_y) * in_w + in_x) * in_c;
+                            patch_chans.copy_from_slice(&input[in_offset..][in_chans.clone()]);
+                        } else {
+                            patch_chans.fill(0.);
+                        }
+                    }
+                }
+
+                // The GEMM output must be contiguous, so when there are
+                // multiple groups, the output channels for each group are
+                // computed separately and then copied into the output.
+                let group_out = if groups == 1 {
+                    &mut *out_block
+                } else {
+                    &mut group_out_buf[..]
+                };
+
+                let patch_mat = NdTensorView::from_data([n_pixels, patch_len], patches.as_slice());
+                gemm.gemm_uninit_bias_activation(
+                    group_out,
+                    out_chans_per_group,
+                    GemmInputA::Unpacked(patch_mat),
+                    packed_kernel
+                        .map(GemmInputB::Packed)
+                        .unwrap_or(GemmInputB::Unpacked(group_kernel(group))),
+                    1., // alpha
+                    group_bias(group),
+                    activation,
+                );
+
+                if groups > 1 {
+                    for (out_pixel, group_pixel) in out_block
+                        .chunks_mut(out_c)
+                        .zip(group_out_buf.chunks(out_chans_per_group))
+                    {
+                        out_pixel[group * out_chans_per_group..][..out_chans_per_group]
+                            .copy_from_slice(group_pixel);
+                    }
+                }
+            }
+            n_init.fetch_add(out_block.len(), Ordering::SeqCst);
+        });
+    assert!(n_init.load(Ordering::SeqCst) == output.len());
+}
@@ -0,0 +1,548 @@
+use std::array;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{Alloc, GlobalAlloc, NdTensor, NdTensorView};
+
+use crate::gemm::{Activation, GemmExecutor, GemmInputA, GemmInputB, PackedAMatrix, PackedBMatrix};
+use crate::iter_util::range_chunks;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Number of elements in each transformed tile of the F(2x2, 3x3) algorithm.
+///
+/// Each 4x4 tile of the input produces a 2x2 tile of the output.
+pub const TILE_ELEMS: usize = 16;
+
+/// Minimum number of input and output channels for which the Winograd
+/// algorithm is used. With fewer channels the cost of transforming the input
+/// and output outweighs the reduction in multiplications.
+const MIN_CHANNELS: usize = 16;
+
+/// Maximum number of elements in each of the buffers of transformed input
+/// tiles and products used in one step of the Winograd algorithm.
+const MAX_TILE_BUFFER_LEN: usize = 256 * 1024;
+
+/// Return true if a 2D convolution should be computed using the Winograd
+/// algorithm, rather than im2col + GEMM.
+///
+/// `in_c` is the number of input channels per group. The choice only depends
+/// on the kernel and convolution attributes, not on the input size, so that
+/// it is already known when the kernel is prepacked.
+pub fn use_winograd(
+    kernel_hw: [usize; 2],
+    in_c: usize,
+    out_c: usize,
+    groups: usize,
+    strides: &[usize],
+    dilations: &[usize],
+) -> bool {
+    kernel_hw == [3, 3]
+        && groups == 1
+        && strides == [1, 1]
+        && dilations == [1, 1]
+        && in_c >= MIN_CHANNELS
+        && out_c >= MIN_CHANNELS
+}
+
+/// Multiply the 4 rows `d` of input tiles by `B^T`, writing the result to
+/// `t`.
+///
+/// Each row is a vector of lanes that are transformed independently, such as
+/// the tiles in a row of tiles, or the channels of a tile. Multiplying the
+/// transposed result by `B^T` again gives `B^T d B`.
+fn transform_input_rows(d: [&[f32]; 4], t: [&mut [f32]; 4]) {
+    let [d0, d1, d2, d3] = d;
+    let [t0, t1, t2, t3] = t;
+    let n = t0.len();
+    assert!([d0, d1, d2, d3].iter().all(|d| d.len() == n));
+    assert!(t1.len() == n && t2.len() == n && t3.len() == n);
+
+    for i in 0..n {
+        t0[i] = d0[i] - d2[i];
+        t1[i] = d1[i] + d2[i];
+        t2[i] = d2[i] - d1[i];
+        t3[i] = d1[i] - d3[i];
+    }
+}
+
+/// Compute `G g G^T` for a 3x3 kernel `g`.
+fn transform_kernel_tile(g: [[f32; 3]; 3]) -> [f32; TILE_ELEMS] {
+    let t: [[f32; 3]; 4] = [
+        g[0],
+        array::from_fn(|x| 0.5 * (g[0][x] + g[1][x] + g[2][x])),
+        array::from_fn(|x| 0.5 * (g[0][x] - g[1][x] + g[2][x])),
+        g[2],
+    ];
+    let mut u = [0.; TILE_ELEMS];
+    for (y, row) in t.iter().enumerate() {
+        u[y * 4] = row[0];
+        u[y * 4 + 1] = 0.5 * (row[0] + row[1] + row[2]);
+        u[y * 4 + 2] = 0.5 * (row[0] - row[1] + row[2]);
+        u[y * 4 + 3] = row[2];
+    }
+    u
+}
+
+/// Multiply the 4 rows `m` of tiles of elementwise products by `A^T`, writing
+/// the result to `s`.
+///
+/// This is the counterpart of [`transform_input_rows`] for the output
+/// transform `A^T m A`.
+fn transform_output_rows(m: [&[f32]; 4], s: [&mut [f32]; 2]) {
+    let [m0, m1, m2, m3] = m;
+    let [s0, s1] = s;
+    let n = s0.len();
+    assert!([m0, m1, m2, m3].iter().all(|m| m.len() == n));
+    assert!(s1.len() == n);
+
+    for i in 0..n {
+        s0[i] = m0[i] + m1[i] + m2[i];
+        s1[i] = m1[i] - m2[i] - m3[i];
+    }
+}
+
+/// Split `buf` into `N` consecutive chunks of `chunk_len` elements.
+fn split_chunks_mut<T, const N: usize>(buf: &mut [T], chunk_len: usize) -> [&mut [T]; N] {
+    let mut chunks = buf.chunks_exact_mut(chunk_len);
+    array::from_fn(|_| chunks.next().unwrap())
+}
+
+/// Transform a `[M, C, 3, 3]` kernel into a `[16, M, C]` tensor, where each
+/// `[M, C]` matrix holds one element of the transformed kernel tiles.
+fn transform_kernel<A: Alloc>(alloc: A, kernel: NdTensorView<f32, 4>) -> NdTensor<f32, 3> {
+    let [out_c, in_c, _, _] = kernel.shape();
+    let mut transformed = NdTensor::zeros_in(alloc, [TILE_ELEMS, out_c, in_c]);
+    for k in 0..out_c {
+        for c in 0..in_c {
+            let g = array::from_fn(|y| array::from_fn(|x| kernel[[k, c, y, x]]));
+            for (i, u) in transform_kernel_tile(g).into_iter().enumerate() {
+                transformed[[i, k, c]] = u;
+            }
+        }
+    }
+    transformed
+}
+
+/// Transform and pack a `[M, C, 3, 3]` kernel for use as the "A" inputs of the
+/// GEMMs in [`conv_2d_winograd`].
+pub fn prepack_winograd_kernel(kernel: NdTensorView<f32, 4>) -> Vec<PackedAMatrix> {
+    let transformed = transform_kernel(GlobalAlloc::new(), kernel);
+    let gemm = GemmExecutor::new();
+    (0..TILE_ELEMS)
+        .map(|i| gemm.prepack_a(transformed.slice::<2, _>([i])))
+        .collect()
+}
+
+/// Transform and pack an HWIO kernel for use as the "B" inputs of the GEMMs
+/// in [`conv_2d_nhwc_winograd`].
+pub fn prepack_winograd_kernel_nhwc(kernel: NdTensorView<f32, 4>) -> Vec<PackedBMatrix> {
+    let transformed = transform_kernel(GlobalAlloc::new(), kernel.permuted([3, 2, 0, 1]));
+    let gemm = GemmExecutor::new();
+    (0..TILE_ELEMS)
+        .map(|i| gemm.prepack_b(transformed.slice::<2, _>([i]).transposed()))
+        .collect()
+}
+
+/// Return the input coordinates of position `yx` in the 4x4 tile whose
+/// top-left corner is at `tile_yx` in the padded input, or `None` if the
+/// position is over the padding.
+fn tile_input_coords(
+    tile_yx: [usize; 2],
+    yx: [usize; 2],
+    in_hw: [usize; 2],
+    pad_top_left: [usize; 2],
+) -> Option<[usize; 2]> {
+    let in_y = (tile_yx[0] + yx[0])
+        .checked_sub(pad_top_left[0])
+        .filter(|&y| y < in_hw[0])?;
+    let in_x = (tile_yx[1] + yx[1])
+        .checked_sub(pad_top_left[1])
+        .filter(|&x| x < in_hw[1])?;
+    Some([in_y, in_x])
+}
+
+/// Compute a 2D convolution of an NCHW input with a 3x3 kernel, using the
+/// Winograd F(2x2, 3x3) algorithm.
+///
+/// The padded input is split into overlapping 4x4 tiles, each of which
+/// produces a 2x2 tile of the output. After transforming the input tiles and
+/// kernel, the convolution becomes an elementwise product of tiles summed
+/// over input channels. This is computed as one `[M, C] x [C, tiles]` GEMM
+/// per tile element, and the products are then transformed into the output.
+/// This uses 16 multiplications per 2x2 output tile and input channel, rather
+/// than the 36 needed by im2col + GEMM.
+///
+/// The convolution must have unit strides and dilations and one group (see
+/// [`use_winograd`]). `padding` and `out_hw` are the fixed padding and
+/// output size. `packed_kernel` is an optional copy of the kernel transformed
+/// and packed by [`prepack_winograd_kernel`]. Otherwise the kernel is
+/// transformed on each call.
+pub fn conv_2d_winograd(
+    pool: &TensorPool,
+    input: NdTensorView<f32, 4>,
+    kernel: NdTensorView<f32, 4>,
+    bias: Option<NdTensorView<f32, 1>>,
+    padding: [usize; 4],
+    out_hw: [usize; 2],
+    activation: Option<Activation>,
+    packed_kernel: Option<&[PackedAMatrix]>,
+) -> NdTensor<f32, 4> {
+    let [batch, in_c, in_h, in_w] = input.shape();
+    let [out_c, _, _, _] = kernel.shape();
+    let [out_h, out_w] = out_hw;
+    let [pad_top, pad_left, _pad_bottom, _pad_right] = padding;
+
+    let mut output = NdTensor::uninit_in(pool, [batch, out_c, out_h, out_w]);
+    if output.is_empty() {
+        // Safety: Empty output is already initialized.
+        return unsafe { output.assume_init() };
+    }
+
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let bias = bias.map(|b| b.to_contiguous());
+    let bias = bias.as_ref().map(|b| b.data().unwrap());
+
+    let gemm = GemmExecutor::new();
+    let packed_kernel = packed_kernel.filter(|pk| pk.len() == TILE_ELEMS);
+    let owned_kernel: Vec<_> = if packed_kernel.is_none() {
+        let transformed = transform_kernel(pool, kernel).auto_return(pool);
+        (0..TILE_ELEMS)
+            .map(|i| {
+                gemm.prepack_a_in(pool, transformed.slice::<2, _>([i]))
+                    .auto_return(pool)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let kernel_mats: Vec<&PackedAMatrix> = match packed_kernel {
+        Some(pk) => pk.iter().collect(),
+        None => owned_kernel.iter().map(|pk| &**pk).collect(),
+    };
+
+    let tiles_h = out_h.div_ceil(2);
+    let tiles_w = out_w.div_ceil(2);
+    let tile_rows_per_block =
+        (MAX_TILE_BUFFER_LEN / (TILE_ELEMS * in_c.max(out_c) * tiles_w)).max(1);
+
+    // Width of the padded input rows used by each row of tiles.
+    let padded_w = tiles_w * 2 + 2;
+
+    let n_init = AtomicUsize::new(0);
+
+    for n in 0..batch {
+        let in_item = input.slice::<3, _>([n]);
+        let in_item = in_item.data().unwrap();
+        let mut out_item = output.slice_mut::<3, _>([n]);
+        let out_item = out_item.data_mut().unwrap();
+
+        for tile_rows in range_chunks(0..tiles_h, tile_rows_per_block) {
+            let n_tiles = tile_rows.len() * tiles_w;
+
+            // Transform input tiles into a `[C, 16, tiles]` tensor. For each
+            // row of tiles, `B^T d` is computed for whole rows of the padded
+            // input, which is then multiplied by `B` for each tile.
+            let mut transformed_input = NdTensor::uninit_in(pool, [in_c, TILE_ELEMS, n_tiles]);
+            transformed_input
+                .data_mut()
+                .unwrap()
+                .par_chunks_mut(TILE_ELEMS * n_tiles)
+                .zip(in_item.par_chunks(in_h * in_w))
+                .for_each(|(out_chan, in_chan)| {
+                    let mut rows = vec![0.; 4 * padded_w];
+                    let mut t = vec![0.; 4 * padded_w];
+
+                    for (row_tile, ty) in tile_rows.clone().enumerate() {
+                        for (y, row) in rows.chunks_mut(padded_w).enumerate() {
+                            row.fill(0.);
+                            let in_y = (ty * 2 + y).checked_sub(pad_top).filter(|&y| y < in_h);
+                            if let Some(in_y) = in_y {
+                                row[pad_left..][..in_w]
+                                    .copy_from_slice(&in_chan[in_y * in_w..][..in_w]);
+                            }
+                        }
+                        let mut d = rows.chunks_exact(padded_w);
+                        transform_input_rows(
+                            array::from_fn(|_| d.next().unwrap()),
+                            split_chunks_mut(&mut t, padded_w),
+                        );
+
+                        let mut out_rows = out_chan
+                            .chunks_mut(n_tiles)
+                            .map(|row| &mut row[row_tile * tiles_w..][..tiles_w]);
+                        for t_row in t.chunks(padded_w) {
+                            let [v0, v1, v2, v3] = array::from_fn(|_| out_rows.next().unwrap());
+                            for (tx, t) in t_row.windows(4).step_by(2).enumerate() {
+                                v0[tx].write(t[0] - t[2]);
+                                v1[tx].write(t[1] + t[2]);
+                                v2[tx].write(t[2] - t[1]);
+                                v3[tx].write(t[1] - t[3]);
+                            }
+                        }
+                    }
+                });
+
+            // Safety: All elements were initialized above.
+            let transformed_input = unsafe { transformed_input.assume_init() }.auto_return(pool);
+            let transformed_input = transformed_input.view();
+
+            // Multiply the transformed kernel and input for each tile
+            // element, producing a `[16, M, tiles]` tensor.
+            let mut products = NdTensor::uninit_in(pool, [TILE_ELEMS, out_c, n_tiles]);
+            products
+                .data_mut()
+                .unwrap()
+                .par_chunks_mut(out_c * n_tiles)
+                .zip(kernel_mats.par_iter())
+                .enumerate()
+                .for_each(|(i, (prod, kernel_mat))| {
+                    gemm.gemm_uninit(
+                        prod,
+                        n_tiles,
+                        GemmInputA::Packed(kernel_mat),
+                        GemmInputB::Unpacked(transformed_input.slice::<2, _>((.., i))),
+                        1., // alpha
+                    );
+                });
+
+            // Safety: `gemm_uninit` initialized all elements.
+            let products = unsafe { products.assume_init() }.auto_return(pool);
+            let products = products.data().unwrap();
+
+            // Transform the products into output tiles. For each row of
+            // tiles, `A^T m` is computed for all tiles in the row, which is
+            // then multiplied by `A` to produce two rows of output.
+            let out_rows = tile_rows.start * 2..(tile_rows.end * 2).min(out_h);
+            out_item
+                .par_chunks_mut(out_h * out_w)
+                .enumerate()
+                .for_each(|(k, out_chan)| {
+                    let out_block = &mut out_chan[out_rows.start * out_w..out_rows.end * out_w];
+                    let chan_bias = bias.map(|b| b[k]).unwrap_or(0.);
+                    let mut s = vec![0.; 8 * tiles_w];
+
+                    for (row_tile, out_row_pair) in out_block.chunks_mut(2 * out_w).enumerate() {
+                        let m: [&[f32]; TILE_ELEMS] = array::from_fn(|i| {
+                            &products[(i * out_c + k) * n_tiles + row_tile * tiles_w..][..tiles_w]
+                        });
+                        let [s0, s1]: [&mut [f32]; 2] = split_chunks_mut(&mut s, 4 * tiles_w);
+                        let mut s0 = s0.chunks_exact_mut(tiles_w);
+                        let mut s1 = s1.chunks_exact_mut(tiles_w);
+                        for x in 0..4 {
+                            transform_output_rows(
+                                [m[x], m[4 + x], m[8 + x], m[12 + x]],
+                                [s0.next().unwrap(), s1.next().unwrap()],
+                            );
+                        }
+
+                        for (s, out_row) in
+                            s.chunks(4 * tiles_w).zip(out_row_pair.chunks_mut(out_w))
+                        {
+                            let [s0, s1, s2, s3] = array::from_fn(|x| &s[x * tiles_w..][..tiles_w]);
+                            for (tx, out) in out_row.chunks_mut(2).enumerate() {
+                                out[0].write(s0[tx] + s1[tx] + s2[tx] + chan_bias);
+                                if let Some(out) = out.get_mut(1) {
+                                    out.write(s1[tx] - s2[tx] - s3[tx] + chan_bias);
+                                }
+                            }
+                        }
+                    }
+
+                    // Safety: The tiles cover all elements of the block.
+                    let out_block: &mut [f32] = unsafe { std::mem::transmute(out_block) };
+                    if let Some(activation) = activation {
+                        activation.apply(out_block);
+                    }
+                    n_init.fetch_add(out_block.len(), Ordering::SeqCst);
+                });
+        }
+    }
+
+    assert!(n_init.load(Ordering::SeqCst) == output.len());
+
+    // Safety: All output blocks were initialized above.
+    unsafe { output.assume_init() }
+}
+
+/// Variant of [`conv_2d_winograd`] for inputs in channels-last (NHWC) layout.
+///
+/// `input` must be contiguous and `kernel` has HWIO layout. The result is
+/// written to `output`, which has `[N, Ho, Wo, M]` layout. Blocks of output
+/// rows are computed in parallel, with the transformed input tiles for each
+/// block multiplied with the transformed kernel as `[tiles, C] x [C, M]`
+/// GEMMs. `packed_kernel` is an optional copy of the kernel transformed and
+/// packed by [`prepack_winograd_kernel_nhwc`].
+pub fn conv_2d_nhwc_winograd(
+    pool: &TensorPool,
+    output: &mut [MaybeUninit<f32>],
+    input: NdTensorView<f32, 4>,
+    kernel: NdTensorView<f32, 4>,
+    bias: Option<&[f32]>,
+    padding: [usize; 4],
+    out_hw: [usize; 2],
+    activation: Option<Activation>,
+    packed_kernel: Option<&[PackedBMatrix]>,
+) {
+    let [_batch, in_h, in_w, in_c] = input.shape();
+    let [_, _, _, out_c] = kernel.shape();
+    let [out_h, out_w] = out_hw;
+    let [pad_top, pad_left, _pad_bottom, _pad_right] = padding;
+
+    let gemm = GemmExecutor::new();
+    let packed_kernel = packed_kernel.filter(|pk| pk.len() == TILE_ELEMS);
+    let owned_kernel: Vec<_> = if packed_kernel.is_none() {
+        let transformed = transform_kernel(pool, kernel.permuted([3, 2, 0, 1])).auto_return(pool);
+        (0..TILE_ELEMS)
+            .map(|i| {
+                gemm.prepack_b_in(pool, transformed.slice::<2, _>([i]).transposed())
+                    .auto_return(pool)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let kernel_mats: Vec<&PackedBMatrix> = match packed_kernel {
+        Some(pk) => pk.iter().collect(),
+        None => owned_kernel.iter().map(|pk| &**pk).collect(),
+    };
+
+    let tiles_w = out_w.div_ceil(2);
+    let tile_rows_per_block =
+        (MAX_TILE_BUFFER_LEN / (TILE_ELEMS * in_c.max(out_c) * tiles_w)).max(1);
+
+    let input = input.data().unwrap();
+    let zeros = vec![0.; in_c.max(out_c)];
+    let bias = bias.unwrap_or(&zeros[..out_c]);
+    let n_init = AtomicUsize::new(0);
+
+    output
+        .par_chunks_mut(out_h * out_w * out_c)
+        .zip(input.par_chunks(in_h * in_w * in_c))
+        .for_each(|(out_image, in_image)| {
+            out_image
+                .par_chunks_mut(tile_rows_per_block * 2 * out_w * out_c)
+                .enumerate()
+                .for_each(|(block, out_block)| {
+                    let first_tile_row = block * tile_rows_per_block;
+                    let n_tiles = (out_block.len() / (out_w * out_c)).div_ceil(2) * tiles_w;
+                    let tile_coords =
+                        |tile: usize| [first_tile_row + tile / tiles_w, tile % tiles_w];
+
+                    // Transform input tiles into a `[16, tiles, C]` buffer,
+                    // with the channels of each tile transformed together.
+                    let mut transformed_input = vec![0.; TILE_ELEMS * n_tiles * in_c];
+                    let mut t = vec![0.; TILE_ELEMS * in_c];
+                    for tile in 0..n_tiles {
+                        let [ty, tx] = tile_coords(tile);
+                        let d: [[&[f32]; 4]; 4] = array::from_fn(|y| {
+                            array::from_fn(|x| {
+                                match tile_input_coords(
+                                    [ty * 2, tx * 2],
+                                    [y, x],
+                                    [in_h, in_w],
+                                    [pad_top, pad_left],
+                                ) {
+                                    Some([in_y, in_x]) => {
+                                        &in_image[(in_y * in_w + in_x) * in_c..][..in_c]
+                                    }
+                                    None => &zeros[..in_c],
+                                }
+                            })
+                        });
+
+                        // `t[y][x]` is row `y` of `B^T d`, for column `x`.
+                        let t_rows: [&mut [f32]; TILE_ELEMS] = split_chunks_mut(&mut t, in_c);
+                        let mut t_rows = t_rows.map(Some);
+                        for x in 0..4 {
+                            transform_input_rows(
+                                [d[0][x], d[1][x], d[2][x], d[3][x]],
+                                array::from_fn(|y| t_rows[y * 4 + x].take().unwrap()),
+                            );
+                        }
+
+                        // Compute `(B^T d) B` by transforming the columns.
+                        let mut out_rows = transformed_input
+                            .chunks_exact_mut(n_tiles * in_c)
+                            .map(|v| &mut v[tile * in_c..][..in_c]);
+                        for t_row in t.chunks(4 * in_c) {
+                            transform_input_rows(
+                                array::from_fn(|x| &t_row[x * in_c..][..in_c]),
+                                array::from_fn(|_| out_rows.next().unwrap()),
+                            );
+                        }
+                    }
+
+                    // Multiply the transformed input and kernel for each tile
+                    // element, producing a `[16, tiles, M]` buffer.
+                    let mut products = vec![MaybeUninit::uninit(); TILE_ELEMS * n_tiles * out_c];
+                    for ((prod, in_mat), kernel_mat) in products
+                        .chunks_mut(n_tiles * out_c)
+                        .zip(transformed_input.chunks(n_tiles * in_c))
+                        .zip(&kernel_mats)
+                    {
+                        gemm.gemm_uninit(
+                            prod,
+                            out_c,
+                            GemmInputA::Unpacked(NdTensorView::from_data([n_tiles, in_c], in_mat)),
+                            GemmInputB::Packed(kernel_mat),
+                            1., // alpha
+                        );
+                    }
+
+                    // Safety: `gemm_uninit` initialized all elements.
+                    let products: &[f32] = unsafe { std::mem::transmute(products.as_slice()) };
+
+                    // Transform the products into output tiles, with the
+                    // channels of each tile transformed together.
+                    let mut s = vec![0.; 8 * out_c];
+                    let mut y = vec![0.; 2 * out_c];
+                    for tile in 0..n_tiles {
+                        let [ty, tx] = tile_coords(tile);
+                        let m: [&[f32]; TILE_ELEMS] =
+                            array::from_fn(|i| &products[(i * n_tiles + tile) * out_c..][..out_c]);
+
+                        // `s[dy][x]` is row `dy` of `A^T m`, for column `x`.
+                        let s_rows: [&mut [f32]; 8] = split_chunks_mut(&mut s, out_c);
+                        let mut s_rows = s_rows.map(Some);
+                        for x in 0..4 {
+                            transform_output_rows(
+                                [m[x], m[4 + x], m[8 + x], m[12 + x]],
+                                array::from_fn(|dy| s_rows[dy * 4 + x].take().unwrap()),
+                            );
+                        }
+
+                        for (dy, s_row) in s.chunks(4 * out_c).enumerate() {
+                            let out_y = ty * 2 + dy;
+                            if out_y >= out_h {
+                                continue;
+                            }
+                            transform_output_rows(
+                                array::from_fn(|x| &s_row[x * out_c..][..out_c]),
+                                split_chunks_mut(&mut y, out_c),
+                            );
+                            for (dx, y) in y.chunks(out_c).enumerate() {
+                                let out_x = tx * 2 + dx;
+                                if out_x >= out_w {
+                                    continue;
+                                }
+                                let out_y = out_y - first_tile_row * 2;
+                                let out_pixel = &mut out_block[(out_y * out_w + out_x) * out_c..];
+                                for ((out, y), b) in out_pixel.iter_mut().zip(y).zip(bias) {
+                                    out.write(y + b);
+                                }
+                            }
+                        }
+                    }
+
+                    // Safety: The tiles cover all elements of the block.
+                    let out_block: &mut [f32] = unsafe { std::mem::transmute(out_block) };
+                    if let Some(activation) = activation {
+                        activation.apply(out_block);
+                    }
+                    n_init.fetch_add(out_block.len(), Ordering::SeqCst);
+                });
+        });
+    assert!(n_init.load(Ordering::SeqCst) == output.len());
+}
@@ -1,6 +1,9 @@
 use rten_tensor::prelude::*;
 
-use crate::ops::{DataType, Input, InputList, IntoOpResult, OpError, Operator, Output, OutputList};
+use crate::ops::{
+    infer_unary_shape, is_same_op, DataType, Input, InputList, IntoOpResult, OpError, Operator,
+    Output, OutputList, ShapeInputs,
+};
 use crate::tensor_pool::TensorPool;
 
 fn cast(pool: &TensorPool, input: Input, dtype: DataType) -> Output {
@@ -16,7 +19,7 @@ fn cast(pool: &TensorPool, input: Input, dtype: DataType) -> Output {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Cast {
     pub to: DataType,
 }
@@ -26,11 +29,19 @@ impl Operator for Cast {
         "Cast"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         cast(pool, input, self.to).into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
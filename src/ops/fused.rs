@@ -1,9 +1,22 @@
+use std::mem::MaybeUninit;
 use std::sync::Arc;
 
+use rayon::prelude::*;
 use rten_tensor::prelude::*;
+use rten_tensor::{Tensor, TensorView};
+use rten_vecmath::{
+    vec_erf_in_place, vec_exp_in_place, vec_gelu_in_place, vec_mish_in_place, vec_sigmoid_in_place,
+    vec_silu_in_place, vec_tanh_in_place,
+};
+use smallvec::SmallVec;
 
-use crate::ops::{Input, InputList, OpError, Operator, OutputList};
-use crate::tensor_pool::TensorPool;
+use crate::downcast::DowncastDyn;
+use crate::ops::binary_elementwise::{broadcast_shapes, powf};
+use crate::ops::{
+    Abs, Add, Clip, Div, Erf, Exp, Gelu, Input, InputList, IntoOpResult, Mish, Mul, Neg, OpError,
+    Operator, Output, OutputList, Pow, Sigmoid, Silu, Sub, Tanh,
+};
+use crate::tensor_pool::{AutoReturn, TensorPool};
 
 /// Specifies a permutation to an operator input.
 #[derive(Clone, Debug, PartialEq)]
@@ -77,17 +90,482 @@ impl Operator for FusedTranspose {
     }
 }
 
+/// Input to a step of a [`FusedElementwise`] operator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElementwiseArg {
+    /// The input of the fused operator with a given index.
+    Input(usize),
+
+    /// The output of an earlier step.
+    Step(usize),
+}
+
+/// An operation in the chain evaluated by a [`FusedElementwise`] operator.
+#[derive(Clone, Debug)]
+pub struct ElementwiseStep {
+    /// The elementwise operator which this step evaluates.
+    pub op: Arc<dyn Operator + Send + Sync>,
+
+    /// Inputs for the operator, in the same order as the operator's inputs.
+    pub args: Vec<Option<ElementwiseArg>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BinaryKernel {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+/// Float implementation of a step in a [`FusedElementwise`] operator.
+#[derive(Clone, Copy, Debug)]
+enum ElementwiseKernel {
+    Abs,
+    Neg,
+    /// Clamp values using the scalar min and max inputs.
+    Clip,
+    /// SIMD-vectorized function which updates values in place.
+    Vectorized(fn(&mut [f32])),
+    /// Function applied to each element using
+    /// [`UnaryFloatOp::map_element`](crate::ops::UnaryFloatOp::map_element).
+    Map,
+    Binary(BinaryKernel),
+}
+
+impl ElementwiseKernel {
+    fn for_operator(op: &dyn Operator) -> Option<ElementwiseKernel> {
+        let binary = |kernel| Some(ElementwiseKernel::Binary(kernel));
+        let vectorized = |kernel| Some(ElementwiseKernel::Vectorized(kernel));
+
+        if op.downcast_ref::<Add>().is_some() {
+            binary(BinaryKernel::Add)
+        } else if op.downcast_ref::<Sub>().is_some() {
+            binary(BinaryKernel::Sub)
+        } else if op.downcast_ref::<Mul>().is_some() {
+            binary(BinaryKernel::Mul)
+        } else if op.downcast_ref::<Div>().is_some() {
+            binary(BinaryKernel::Div)
+        } else if op.downcast_ref::<Pow>().is_some() {
+            binary(BinaryKernel::Pow)
+        } else if op.downcast_ref::<Abs>().is_some() {
+            Some(ElementwiseKernel::Abs)
+        } else if op.downcast_ref::<Neg>().is_some() {
+            Some(ElementwiseKernel::Neg)
+        } else if op.downcast_ref::<Clip>().is_some() {
+            Some(ElementwiseKernel::Clip)
+        } else if op.downcast_ref::<Erf>().is_some() {
+            vectorized(vec_erf_in_place)
+        } else if op.downcast_ref::<Exp>().is_some() {
+            vectorized(vec_exp_in_place)
+        } else if op.downcast_ref::<Gelu>().is_some() {
+            vectorized(vec_gelu_in_place)
+        } else if op.downcast_ref::<Mish>().is_some() {
+            vectorized(vec_mish_in_place)
+        } else if op.downcast_ref::<Sigmoid>().is_some() {
+            vectorized(vec_sigmoid_in_place)
+        } else if op.downcast_ref::<Silu>().is_some() {
+            vectorized(vec_silu_in_place)
+        } else if op.downcast_ref::<Tanh>().is_some() {
+            vectorized(vec_tanh_in_place)
+        } else if op.as_unary_float_op().is_some() {
+            Some(ElementwiseKernel::Map)
+        } else {
+            None
+        }
+    }
+
+    /// Return true if `args` are valid inputs for this kernel.
+    fn valid_args(&self, args: &[Option<ElementwiseArg>]) -> bool {
+        match self {
+            ElementwiseKernel::Binary(_) => matches!(args, [Some(_), Some(_)]),
+            ElementwiseKernel::Clip => matches!(args.len(), 1..=3) && args[0].is_some(),
+            _ => matches!(args, [Some(_)]),
+        }
+    }
+
+    /// Return the number of leading args which are broadcast against each
+    /// other. The remaining args are scalar parameters.
+    fn data_args(&self) -> usize {
+        match self {
+            ElementwiseKernel::Binary(_) => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// Number of output elements that are evaluated in a single pass through all
+/// the steps of a [`FusedElementwise`] operator. This is chosen so that the
+/// intermediate values stay in the L1 cache.
+const BLOCK_SIZE: usize = 1024;
+
+/// Minimum number of blocks that are evaluated on a single thread.
+const MIN_BLOCKS_PER_THREAD: usize = 32;
+
+/// Source of values for an input to a [`FusedElementwise`] operator.
+enum InputSource<'a> {
+    /// Contiguous input with the same shape as the output.
+    Full(&'a [f32]),
+
+    /// Input with a single element which is broadcast to the output shape.
+    Scalar(f32),
+
+    /// Contiguous input which is broadcast to the output shape using the
+    /// given strides.
+    Broadcast {
+        data: &'a [f32],
+        strides: SmallVec<[usize; 4]>,
+    },
+}
+
+/// Return strides which broadcast a contiguous tensor of shape `shape` to
+/// `out_shape`.
+fn broadcast_strides(shape: &[usize], out_shape: &[usize]) -> SmallVec<[usize; 4]> {
+    let pad = out_shape.len() - shape.len();
+    let mut strides = SmallVec::from_elem(0, out_shape.len());
+    let mut stride = 1;
+    for (dim, &size) in shape.iter().enumerate().rev() {
+        if size != 1 {
+            strides[pad + dim] = stride;
+        }
+        stride *= size;
+    }
+    strides
+}
+
+/// Copy the elements of a broadcast input which correspond to output elements
+/// `start..start + dst.len()` into `dst`.
+fn gather_broadcast(
+    data: &[f32],
+    strides: &[usize],
+    out_shape: &[usize],
+    start: usize,
+    dst: &mut [f32],
+) {
+    let ndim = out_shape.len();
+    let mut index: SmallVec<[usize; 4]> = SmallVec::from_elem(0, ndim);
+    let mut remainder = start;
+    for dim in (0..ndim).rev() {
+        index[dim] = remainder % out_shape[dim];
+        remainder /= out_shape[dim];
+    }
+
+    let inner_stride = strides[ndim - 1];
+    let mut written = 0;
+    while written < dst.len() {
+        let offset: usize = index.iter().zip(strides).map(|(i, s)| i * s).sum();
+        let len = (out_shape[ndim - 1] - index[ndim - 1]).min(dst.len() - written);
+        for (i, x) in dst[written..written + len].iter_mut().enumerate() {
+            *x = data[offset + i * inner_stride];
+        }
+        written += len;
+
+        // Advance to the start of the next row.
+        index[ndim - 1] += len;
+        let mut dim = ndim - 1;
+        while dim > 0 && index[dim] == out_shape[dim] {
+            index[dim] = 0;
+            dim -= 1;
+            index[dim] += 1;
+        }
+    }
+}
+
+fn binary_loop<F: Fn(f32, f32) -> f32>(dst: &mut [f32], a: &[f32], b: &[f32], op: F) {
+    for ((x, a), b) in dst.iter_mut().zip(a).zip(b) {
+        *x = op(*a, *b);
+    }
+}
+
+/// Buffers for the inputs and step outputs of a block.
+struct BlockScratch {
+    inputs: Vec<Vec<f32>>,
+    steps: Vec<Vec<f32>>,
+}
+
+/// Operator which evaluates a chain of elementwise operations in a single
+/// pass over the output.
+///
+/// The output is evaluated in blocks that are small enough for intermediate
+/// values to stay in cache, instead of materializing the full output of each
+/// operation. Inputs are broadcast to the output shape as in the individual
+/// operators. If any input is not a float tensor, the steps are evaluated
+/// separately using the original operators.
+#[derive(Clone, Debug)]
+pub struct FusedElementwise {
+    steps: Vec<ElementwiseStep>,
+    kernels: Vec<ElementwiseKernel>,
+}
+
+impl FusedElementwise {
+    /// Create an operator which evaluates `steps` in order. The output of the
+    /// last step is the output of the operator.
+    ///
+    /// Returns `None` if any of the steps uses an operator that is not
+    /// supported, or has invalid arguments. Scalar parameters, such as the
+    /// bounds of `Clip`, must be inputs of the fused operator rather than the
+    /// outputs of other steps.
+    pub fn new(steps: Vec<ElementwiseStep>) -> Option<FusedElementwise> {
+        if steps.is_empty() {
+            return None;
+        }
+
+        let mut kernels = Vec::with_capacity(steps.len());
+        for (i, step) in steps.iter().enumerate() {
+            let kernel = ElementwiseKernel::for_operator(step.op.as_ref())?;
+            let args_valid = kernel.valid_args(&step.args)
+                && step
+                    .args
+                    .iter()
+                    .enumerate()
+                    .all(|(arg_idx, arg)| match arg {
+                        Some(ElementwiseArg::Step(j)) => *j < i && arg_idx < kernel.data_args(),
+                        _ => true,
+                    });
+            if !args_valid {
+                return None;
+            }
+            kernels.push(kernel);
+        }
+
+        Some(FusedElementwise { steps, kernels })
+    }
+
+    /// Return true if `op` can be evaluated as a step of a fused operator.
+    pub fn supports_operator(op: &dyn Operator) -> bool {
+        ElementwiseKernel::for_operator(op).is_some()
+    }
+
+    /// Return the number of leading inputs of `op` which are broadcast
+    /// against each other to determine the output shape. The remaining inputs
+    /// are scalar parameters.
+    ///
+    /// Returns `None` if `op` is not supported.
+    pub fn data_inputs(op: &dyn Operator) -> Option<usize> {
+        ElementwiseKernel::for_operator(op).map(|kernel| kernel.data_args())
+    }
+
+    fn run_float(&self, pool: &TensorPool, inputs: &[TensorView]) -> Result<Tensor, OpError> {
+        let input = |arg: Option<ElementwiseArg>| match arg {
+            Some(ElementwiseArg::Input(idx)) => inputs.get(idx).ok_or(OpError::MissingInputs),
+            _ => Err(OpError::MissingInputs),
+        };
+
+        let mut out_shape = SmallVec::<[usize; 4]>::new();
+        let mut clip_bounds = Vec::with_capacity(self.steps.len());
+        for (step, kernel) in self.steps.iter().zip(&self.kernels) {
+            for arg in &step.args[..kernel.data_args()] {
+                if let Some(ElementwiseArg::Input(_)) = arg {
+                    out_shape = broadcast_shapes(&out_shape, input(*arg)?.shape())
+                        .ok_or(OpError::IncompatibleInputShapes("Cannot broadcast inputs"))?;
+                }
+            }
+
+            let mut bounds = (f32::MIN, f32::MAX);
+            if let ElementwiseKernel::Clip = kernel {
+                let scalar = |idx| -> Result<Option<f32>, OpError> {
+                    let Some(arg) = step.args.get(idx).copied().flatten() else {
+                        return Ok(None);
+                    };
+                    input(Some(arg))?
+                        .item()
+                        .copied()
+                        .map(Some)
+                        .ok_or(OpError::InvalidValue("Expected scalar value"))
+                };
+                if let Some(min) = scalar(1)? {
+                    bounds.0 = min;
+                }
+                if let Some(max) = scalar(2)? {
+                    bounds.1 = max;
+                }
+            }
+            clip_bounds.push(bounds);
+        }
+
+        let inputs: Vec<_> = inputs
+            .iter()
+            .map(|x| x.to_contiguous_in(pool).auto_return(pool))
+            .collect();
+        let sources: Vec<_> = inputs
+            .iter()
+            .map(|x| {
+                let data = x.data().unwrap();
+                if x.shape() == out_shape.as_slice() {
+                    InputSource::Full(data)
+                } else if data.len() == 1 {
+                    InputSource::Scalar(data[0])
+                } else {
+                    InputSource::Broadcast {
+                        data,
+                        strides: broadcast_strides(x.shape(), &out_shape),
+                    }
+                }
+            })
+            .collect();
+
+        let mut output = Tensor::uninit_in(pool, &out_shape);
+        output
+            .data_mut()
+            .unwrap()
+            .par_chunks_mut(BLOCK_SIZE)
+            .with_min_len(MIN_BLOCKS_PER_THREAD)
+            .enumerate()
+            .for_each_init(
+                || BlockScratch {
+                    inputs: vec![vec![0.; BLOCK_SIZE]; sources.len()],
+                    steps: vec![vec![0.; BLOCK_SIZE]; self.steps.len()],
+                },
+                |scratch, (block_idx, out_block)| {
+                    self.eval_block(
+                        &sources,
+                        &out_shape,
+                        &clip_bounds,
+                        block_idx * BLOCK_SIZE,
+                        out_block,
+                        scratch,
+                    )
+                },
+            );
+
+        // Safety: `eval_block` initialized all elements of each block.
+        Ok(unsafe { output.assume_init() })
+    }
+
+    /// Evaluate output elements `start..start + out_block.len()`.
+    fn eval_block(
+        &self,
+        sources: &[InputSource],
+        out_shape: &[usize],
+        clip_bounds: &[(f32, f32)],
+        start: usize,
+        out_block: &mut [MaybeUninit<f32>],
+        scratch: &mut BlockScratch,
+    ) {
+        let len = out_block.len();
+
+        for (source, buf) in sources.iter().zip(scratch.inputs.iter_mut()) {
+            match source {
+                InputSource::Full(_) => {}
+                InputSource::Scalar(x) => buf[..len].fill(*x),
+                InputSource::Broadcast { data, strides } => {
+                    gather_broadcast(data, strides, out_shape, start, &mut buf[..len])
+                }
+            }
+        }
+        let input_vals: SmallVec<[&[f32]; 4]> = sources
+            .iter()
+            .zip(&scratch.inputs)
+            .map(|(source, buf)| match source {
+                InputSource::Full(data) => &data[start..start + len],
+                _ => &buf[..len],
+            })
+            .collect();
+
+        for (i, (step, kernel)) in self.steps.iter().zip(&self.kernels).enumerate() {
+            let (prev_steps, next_steps) = scratch.steps.split_at_mut(i);
+            let dst = &mut next_steps[0][..len];
+            let arg = |idx: usize| match step.args[idx] {
+                Some(ElementwiseArg::Input(input_idx)) => input_vals[input_idx],
+                Some(ElementwiseArg::Step(step_idx)) => &prev_steps[step_idx][..len],
+                None => unreachable!("missing arg"),
+            };
+
+            if let ElementwiseKernel::Binary(op) = kernel {
+                let (a, b) = (arg(0), arg(1));
+                match op {
+                    BinaryKernel::Add => binary_loop(dst, a, b, |x, y| x + y),
+                    BinaryKernel::Sub => binary_loop(dst, a, b, |x, y| x - y),
+                    BinaryKernel::Mul => binary_loop(dst, a, b, |x, y| x * y),
+                    BinaryKernel::Div => binary_loop(dst, a, b, |x, y| x / y),
+                    BinaryKernel::Pow => binary_loop(dst, a, b, powf),
+                }
+                continue;
+            }
+
+            dst.copy_from_slice(arg(0));
+            match kernel {
+                ElementwiseKernel::Abs => dst.iter_mut().for_each(|x| *x = x.abs()),
+                ElementwiseKernel::Neg => dst.iter_mut().for_each(|x| *x = -*x),
+                ElementwiseKernel::Clip => {
+                    let (min, max) = clip_bounds[i];
+                    dst.iter_mut().for_each(|x| *x = x.clamp(min, max));
+                }
+                ElementwiseKernel::Vectorized(op) => op(dst),
+                ElementwiseKernel::Map => {
+                    let op = step.op.as_unary_float_op().unwrap();
+                    dst.iter_mut().for_each(|x| *x = op.map_element(*x));
+                }
+                ElementwiseKernel::Binary(_) => unreachable!(),
+            }
+        }
+
+        let result = &scratch.steps[self.steps.len() - 1][..len];
+        for (out, x) in out_block.iter_mut().zip(result) {
+            out.write(*x);
+        }
+    }
+
+    /// Evaluate each step separately using the original operators.
+    fn run_unfused(&self, pool: &TensorPool, inputs: &InputList) -> Result<Output, OpError> {
+        let mut results: Vec<Output> = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            let step_inputs: Vec<Option<Input>> = step
+                .args
+                .iter()
+                .map(|arg| match arg {
+                    Some(ElementwiseArg::Input(idx)) => inputs.get(*idx),
+                    Some(ElementwiseArg::Step(idx)) => Some(results[*idx].as_input()),
+                    None => None,
+                })
+                .collect();
+            let mut outputs = step.op.run(pool, InputList::from_optional(&step_inputs))?;
+            results.push(outputs.remove(0));
+        }
+        Ok(results.pop().unwrap())
+    }
+}
+
+impl Operator for FusedElementwise {
+    fn name(&self) -> &str {
+        "FusedElementwise"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let float_inputs: Option<Vec<TensorView>> = inputs
+            .iter()
+            .map(|input| match input {
+                Input::FloatTensor(t) => Some(t),
+                Input::IntTensor(_) => None,
+            })
+            .collect();
+
+        match float_inputs {
+            Some(float_inputs) if float_inputs.len() == inputs.len() => {
+                self.run_float(pool, &float_inputs).into_op_result()
+            }
+            _ => self.run_unfused(pool, &inputs).into_op_result(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
     use std::sync::Arc;
 
     use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal_with_tolerance;
     use rten_tensor::Tensor;
 
-    use super::FusedTranspose;
+    use super::{ElementwiseArg, ElementwiseStep, FusedElementwise, FusedTranspose};
     use crate::ops::tests::new_pool;
-    use crate::ops::{InputList, Operator, Sub};
+    use crate::ops::{
+        add, clip, mul, sigmoid, Add, Clip, Div, InputList, Mul, Neg, Operator, Relu, Sigmoid, Sub,
+        Transpose,
+    };
 
     #[test]
     fn test_fused_transpose() -> Result<(), Box<dyn Error>> {
@@ -143,4 +621,153 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_fused_elementwise() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Steps for `Clip(Sigmoid(x + y) * z, min, max)`.
+        let steps = vec![
+            ElementwiseStep {
+                op: Arc::new(Add {}),
+                args: vec![
+                    Some(ElementwiseArg::Input(0)),
+                    Some(ElementwiseArg::Input(1)),
+                ],
+            },
+            ElementwiseStep {
+                op: Arc::new(Sigmoid {}),
+                args: vec![Some(ElementwiseArg::Step(0))],
+            },
+            ElementwiseStep {
+                op: Arc::new(Mul {}),
+                args: vec![
+                    Some(ElementwiseArg::Step(1)),
+                    Some(ElementwiseArg::Input(2)),
+                ],
+            },
+            ElementwiseStep {
+                op: Arc::new(Clip {}),
+                args: vec![
+                    Some(ElementwiseArg::Step(2)),
+                    Some(ElementwiseArg::Input(3)),
+                    Some(ElementwiseArg::Input(4)),
+                ],
+            },
+        ];
+        let fused_op = FusedElementwise::new(steps).unwrap();
+
+        // Cases include outputs with multiple blocks, and inputs which are
+        // broadcast along different axes.
+        let cases: [(&[usize], &[usize], &[usize]); 4] = [
+            (&[2, 3], &[2, 3], &[]),
+            (&[2, 3], &[3], &[2, 1]),
+            (&[5, 700], &[5, 1], &[700]),
+            (&[64, 64, 16], &[1, 64, 1], &[64, 64, 16]),
+        ];
+
+        for (x_shape, y_shape, z_shape) in cases {
+            let mut x = Tensor::<f32>::rand(x_shape, &mut rng);
+            x.apply(|x| x - 0.5);
+            let y = Tensor::<f32>::rand(y_shape, &mut rng);
+            let mut z = Tensor::<f32>::rand(z_shape, &mut rng);
+            z.apply(|z| z * 2.);
+            let min = Tensor::from(0.2);
+            let max = Tensor::from(0.8);
+
+            let xy = add(&pool, x.view(), y.view())?;
+            let xyz = mul(&pool, sigmoid(&pool, xy.view()).view(), z.view())?;
+            let expected = clip(&pool, xyz.view(), Some(0.2), Some(0.8));
+
+            let inputs = InputList::from(&[
+                x.view().into(),
+                y.view().into(),
+                z.view().into(),
+                min.view().into(),
+                max.view().into(),
+            ]);
+            let mut outputs = fused_op.run(&pool, inputs)?;
+            let output: Tensor = outputs.remove(0).try_into().unwrap();
+
+            expect_equal_with_tolerance(&output, &expected, 1e-6, 0.)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fused_elementwise_int() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        // Steps for `(x + y) / z`. Integer inputs are evaluated using the
+        // original operators.
+        let steps = vec![
+            ElementwiseStep {
+                op: Arc::new(Add {}),
+                args: vec![
+                    Some(ElementwiseArg::Input(0)),
+                    Some(ElementwiseArg::Input(1)),
+                ],
+            },
+            ElementwiseStep {
+                op: Arc::new(Div {}),
+                args: vec![
+                    Some(ElementwiseArg::Step(0)),
+                    Some(ElementwiseArg::Input(2)),
+                ],
+            },
+        ];
+        let fused_op = FusedElementwise::new(steps).unwrap();
+
+        let x = Tensor::from([1, 2, 3]);
+        let y = Tensor::from(4);
+        let z = Tensor::from(2);
+        let inputs = InputList::from(&[x.view().into(), y.view().into(), z.view().into()]);
+        let mut outputs = fused_op.run(&pool, inputs)?;
+        let output: Tensor<i32> = outputs.remove(0).try_into().unwrap();
+
+        assert_eq!(output, Tensor::from([2, 3, 3]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fused_elementwise_invalid_steps() {
+        // Unsupported operator.
+        let steps = vec![ElementwiseStep {
+            op: Arc::new(Transpose { perm: None }),
+            args: vec![Some(ElementwiseArg::Input(0))],
+        }];
+        assert!(FusedElementwise::new(steps).is_none());
+
+        // Step which uses the output of a later step.
+        let steps = vec![
+            ElementwiseStep {
+                op: Arc::new(Relu {}),
+                args: vec![Some(ElementwiseArg::Step(1))],
+            },
+            ElementwiseStep {
+                op: Arc::new(Relu {}),
+                args: vec![Some(ElementwiseArg::Input(0))],
+            },
+        ];
+        assert!(FusedElementwise::new(steps).is_none());
+
+        // Clip bound which is the output of an earlier step.
+        let steps = vec![
+            ElementwiseStep {
+                op: Arc::new(Neg {}),
+                args: vec![Some(ElementwiseArg::Input(1))],
+            },
+            ElementwiseStep {
+                op: Arc::new(Clip {}),
+                args: vec![
+                    Some(ElementwiseArg::Input(0)),
+                    Some(ElementwiseArg::Step(0)),
+                ],
+            },
+        ];
+        assert!(FusedElementwise::new(steps).is_none());
+    }
 }
@@ -1,12 +1,14 @@
+use std::cmp::Ordering;
 use std::iter::zip;
 
 use rten_tensor::prelude::*;
 use rten_tensor::{to_slice_items, NdTensorView, SliceItem, Tensor, TensorView, TensorViewMut};
 use smallvec::SmallVec;
 
-use crate::ops::reduce::{cmp_nan_greater, cmp_nan_less};
+use crate::ops::reduce::{cmp_nan_greater, cmp_nan_less, is_nan};
 use crate::ops::{
-    resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator, OutputList,
+    is_same_op, resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator,
+    OutputList, ShapeInputs,
 };
 use crate::tensor_pool::{AutoReturn, TensorPool};
 
@@ -75,7 +77,7 @@ pub fn gather<T: Copy + Default>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Gather {
     pub axis: isize,
 }
@@ -85,6 +87,10 @@ impl Operator for Gather {
         "Gather"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let indices = inputs.require_as::<i32>(1)?;
@@ -93,6 +99,12 @@ impl Operator for Gather {
             Input::FloatTensor(input) => gather(pool, input, self.axis, indices).into_op_result(),
         }
     }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let (shape, indices) = (inputs.shape(0)?, inputs.shape(1)?);
+        let axis = resolve_axis(shape.len(), self.axis).ok()?;
+        Some(vec![[&shape[..axis], indices, &shape[axis + 1..]].concat()])
+    }
 }
 
 /// Optimized implementation of `gather_elements` for tensor with static rank.
@@ -339,6 +351,75 @@ impl Operator for GatherND {
     }
 }
 
+/// Select slices of `input` along `axis` where the corresponding entry in
+/// `condition` is non-zero.
+///
+/// If `axis` is `None`, the input is flattened before selecting elements.
+/// `condition` may be shorter than the selected dimension, in which case the
+/// remaining slices are discarded.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Compress.html>.
+pub fn compress<T: Copy + Default>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    condition: TensorView<i32>,
+    axis: Option<isize>,
+) -> Result<Tensor<T>, OpError> {
+    if condition.ndim() != 1 {
+        return Err(OpError::InvalidValue("`condition` must be a vector"));
+    }
+
+    let flat_input;
+    let (input, axis) = match axis {
+        Some(axis) => {
+            let axis = resolve_axis(input.ndim(), axis)?;
+            (input, axis)
+        }
+        None => {
+            flat_input = input.to_contiguous_in(pool).auto_return(pool);
+            (flat_input.reshaped([flat_input.len()].as_slice()), 0)
+        }
+    };
+
+    if condition.len() > input.size(axis) {
+        return Err(OpError::InvalidValue(
+            "`condition` length exceeds size of selected dimension",
+        ));
+    }
+
+    let indices: Vec<i32> = condition
+        .iter()
+        .enumerate()
+        .filter(|(_, cond)| **cond != 0)
+        .map(|(i, _)| i as i32)
+        .collect();
+    let indices = Tensor::from_vec(indices);
+
+    gather(pool, input, axis as isize, indices.view())
+}
+
+#[derive(Debug)]
+pub struct Compress {
+    pub axis: Option<isize>,
+}
+
+impl Operator for Compress {
+    fn name(&self) -> &str {
+        "Compress"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+        let condition = inputs.require_as::<i32>(1)?;
+        match input {
+            Input::IntTensor(input) => compress(pool, input, condition, self.axis).into_op_result(),
+            Input::FloatTensor(input) => {
+                compress(pool, input, condition, self.axis).into_op_result()
+            }
+        }
+    }
+}
+
 // Specifies how to combine an existing element value with an update in a
 // scatter operation.
 #[derive(Copy, Clone, Debug)]
@@ -550,6 +631,165 @@ impl Operator for ScatterND {
     }
 }
 
+/// Compare `a` and `b` using a total order in which NaN values are equal to
+/// each other and greater than all other values.
+fn cmp_nans_last<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
+/// Outputs of a [unique] operation.
+#[derive(Debug, PartialEq)]
+pub struct UniqueOutput<T> {
+    /// Unique elements or slices of the input.
+    pub values: Tensor<T>,
+
+    /// Index of the first occurrence of each entry in `values` in the input.
+    pub indices: Tensor<i32>,
+
+    /// Index in `values` of each element or slice of the input.
+    pub inverse_indices: Tensor<i32>,
+
+    /// Number of occurrences of each entry in `values` in the input.
+    pub counts: Tensor<i32>,
+}
+
+/// Find the unique elements of `input`, or unique slices along `axis`.
+///
+/// If `axis` is `None`, the input is flattened and the unique elements are
+/// returned as a vector. If `sorted` is true the unique values are returned
+/// in ascending order, otherwise they are returned in order of first
+/// occurrence. NaN values are considered equal to each other.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Unique.html>.
+pub fn unique<T: Copy + Default + PartialOrd>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    axis: Option<isize>,
+    sorted: bool,
+) -> Result<UniqueOutput<T>, OpError> {
+    let flat_input;
+    let (input, axis) = match axis {
+        Some(axis) => {
+            let axis = resolve_axis(input.ndim(), axis)?;
+            (input, axis)
+        }
+        None => {
+            flat_input = input.to_contiguous_in(pool).auto_return(pool);
+            (flat_input.reshaped([flat_input.len()].as_slice()), 0)
+        }
+    };
+
+    // Move the selected axis to the front so that each slice is a contiguous
+    // chunk of elements.
+    let mut slices = input.view();
+    slices.move_axis(axis, 0);
+    let slices = slices.to_contiguous_in(pool).auto_return(pool);
+    let slices = slices.data().unwrap();
+
+    let n_slices = input.size(axis);
+    let slice_len = slices.len().checked_div(n_slices).unwrap_or(0);
+    let slice = |i: usize| &slices[i * slice_len..(i + 1) * slice_len];
+    let cmp_slices = |a: usize, b: usize| {
+        zip(slice(a), slice(b))
+            .map(|(x, y)| cmp_nans_last(x, y))
+            .find(|ord| *ord != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    };
+
+    // Sort slice indices by value. The sort is stable, so the first entry in
+    // each group of equal slices is its first occurrence.
+    let mut order: Vec<usize> = (0..n_slices).collect();
+    order.sort_by(|&a, &b| cmp_slices(a, b));
+
+    // Group equal slices, recording the first occurrence and count.
+    let mut groups: Vec<(usize, Vec<usize>)> = Vec::new();
+    for idx in order {
+        match groups.last_mut() {
+            Some((first, members)) if cmp_slices(*first, idx) == Ordering::Equal => {
+                members.push(idx);
+            }
+            _ => groups.push((idx, vec![idx])),
+        }
+    }
+
+    if !sorted {
+        groups.sort_by_key(|(first, _)| *first);
+    }
+
+    let mut inverse_indices = vec![0; n_slices];
+    for (group_idx, (_, members)) in groups.iter().enumerate() {
+        for &member in members {
+            inverse_indices[member] = group_idx as i32;
+        }
+    }
+
+    let indices: Vec<i32> = groups.iter().map(|(first, _)| *first as i32).collect();
+    let counts: Vec<i32> = groups
+        .iter()
+        .map(|(_, members)| members.len() as i32)
+        .collect();
+    let indices = Tensor::from_vec(indices);
+    let values = gather(pool, input, axis as isize, indices.view())?;
+
+    Ok(UniqueOutput {
+        values,
+        indices,
+        inverse_indices: Tensor::from_vec(inverse_indices),
+        counts: Tensor::from_vec(counts),
+    })
+}
+
+#[derive(Debug)]
+pub struct Unique {
+    pub axis: Option<isize>,
+    pub sorted: bool,
+}
+
+impl Operator for Unique {
+    fn name(&self) -> &str {
+        "Unique"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+        match input {
+            Input::IntTensor(input) => {
+                let UniqueOutput {
+                    values,
+                    indices,
+                    inverse_indices,
+                    counts,
+                } = unique(pool, input, self.axis, self.sorted)?;
+                Ok([
+                    values.into(),
+                    indices.into(),
+                    inverse_indices.into(),
+                    counts.into(),
+                ]
+                .into_iter()
+                .collect())
+            }
+            Input::FloatTensor(input) => {
+                let UniqueOutput {
+                    values,
+                    indices,
+                    inverse_indices,
+                    counts,
+                } = unique(pool, input, self.axis, self.sorted)?;
+                Ok([
+                    values.into(),
+                    indices.into(),
+                    inverse_indices.into(),
+                    counts.into(),
+                ]
+                .into_iter()
+                .collect())
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -561,9 +801,38 @@ mod tests {
 
     use crate::ops::tests::new_pool;
     use crate::ops::{
-        gather, gather_elements, gather_nd, scatter_elements, scatter_nd, OpError, ScatterReduction,
+        compress, gather, gather_elements, gather_nd, scatter_elements, scatter_nd, unique,
+        OpError, ScatterReduction,
     };
 
+    #[test]
+    fn test_compress() {
+        let pool = new_pool();
+        let input = Tensor::from([[1, 2], [3, 4], [5, 6]]);
+
+        // Examples from the ONNX spec.
+        let cond = Tensor::from([0, 1, 1]);
+        let result = compress(&pool, input.view(), cond.view(), Some(0)).unwrap();
+        assert_eq!(result, Tensor::from([[3, 4], [5, 6]]));
+
+        let cond = Tensor::from([0, 1]);
+        let result = compress(&pool, input.view(), cond.view(), Some(1)).unwrap();
+        assert_eq!(result, Tensor::from([[2], [4], [6]]));
+
+        let cond = Tensor::from([0, 1, 0, 0, 1]);
+        let result = compress(&pool, input.view(), cond.view(), None).unwrap();
+        assert_eq!(result, Tensor::from([2, 5]));
+
+        let cond = Tensor::from([0, 1, 0, 0, 1, 0, 1]);
+        let result = compress(&pool, input.view(), cond.view(), None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "`condition` length exceeds size of selected dimension"
+            ))
+        );
+    }
+
     #[test]
     fn test_gather_scalar_index() {
         let pool = new_pool();
@@ -1059,4 +1328,51 @@ mod tests {
             assert_eq!(result, Err(expected));
         }
     }
+
+    #[test]
+    fn test_unique() {
+        let pool = new_pool();
+
+        // Examples from the ONNX spec.
+        let input = Tensor::from([2, 1, 1, 3, 4, 3]);
+        let result = unique(&pool, input.view(), None, false).unwrap();
+        assert_eq!(result.values, Tensor::from([2, 1, 3, 4]));
+        assert_eq!(result.indices, Tensor::from([0, 1, 3, 4]));
+        assert_eq!(result.inverse_indices, Tensor::from([0, 1, 1, 2, 3, 2]));
+        assert_eq!(result.counts, Tensor::from([1, 2, 2, 1]));
+
+        let result = unique(&pool, input.view(), None, true).unwrap();
+        assert_eq!(result.values, Tensor::from([1, 2, 3, 4]));
+        assert_eq!(result.indices, Tensor::from([1, 0, 3, 4]));
+        assert_eq!(result.inverse_indices, Tensor::from([1, 0, 0, 2, 3, 2]));
+        assert_eq!(result.counts, Tensor::from([2, 1, 2, 1]));
+
+        let input = Tensor::from([[1, 0, 0], [1, 0, 0], [2, 3, 4]]);
+        let result = unique(&pool, input.view(), Some(0), true).unwrap();
+        assert_eq!(result.values, Tensor::from([[1, 0, 0], [2, 3, 4]]));
+        assert_eq!(result.indices, Tensor::from([0, 2]));
+        assert_eq!(result.inverse_indices, Tensor::from([0, 0, 1]));
+        assert_eq!(result.counts, Tensor::from([2, 1]));
+
+        let input = Tensor::from([
+            [[1, 1], [0, 1], [2, 1], [0, 1]],
+            [[1, 1], [0, 1], [2, 1], [0, 1]],
+        ]);
+        let result = unique(&pool, input.view(), Some(1), true).unwrap();
+        assert_eq!(
+            result.values,
+            Tensor::from([[[0, 1], [1, 1], [2, 1]], [[0, 1], [1, 1], [2, 1]]])
+        );
+        assert_eq!(result.indices, Tensor::from([1, 0, 2]));
+        assert_eq!(result.inverse_indices, Tensor::from([1, 0, 2, 0]));
+        assert_eq!(result.counts, Tensor::from([2, 1, 1]));
+
+        // NaN values are treated as equal.
+        let input = Tensor::from([f32::NAN, 1., f32::NAN]);
+        let result = unique(&pool, input.view(), None, true).unwrap();
+        assert_eq!(result.values.shape(), &[2]);
+        assert_eq!(result.values[[0]], 1.);
+        assert!(result.values[[1]].is_nan());
+        assert_eq!(result.counts, Tensor::from([1, 2]));
+    }
 }
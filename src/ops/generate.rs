@@ -5,8 +5,8 @@ use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, Tensor, TensorView};
 
 use crate::ops::{
-    resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator, OutputList,
-    Scalar,
+    is_same_op, resolve_axis, resolve_index, Input, InputList, IntoOpResult, OpError, Operator,
+    OutputList, Scalar,
 };
 use crate::static_dims;
 use crate::tensor_pool::TensorPool;
@@ -20,7 +20,7 @@ pub fn constant_of_shape<T: Copy>(
     Tensor::full_in(pool, &shape, value)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct ConstantOfShape {
     pub value: Scalar,
 }
@@ -30,6 +30,10 @@ impl Operator for ConstantOfShape {
         "ConstantOfShape"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let shape = inputs.require_as::<i32>(0)?;
         let shape = static_dims!(shape, 1)?;
@@ -78,7 +82,7 @@ fn extract_on_off_values<T: Copy>(values: NdTensorView<T, 1>) -> Result<(T, T),
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct OneHot {
     pub axis: isize,
 }
@@ -88,6 +92,10 @@ impl Operator for OneHot {
         "OneHot"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let indices = inputs.require_as::<i32>(0)?;
         let depth = inputs.require_as::<i32>(1)?;
@@ -133,7 +141,7 @@ pub fn range<T: Copy + Default + ops::Add<Output = T> + PartialOrd>(
     Ok(output.into())
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Range {}
 
 impl Operator for Range {
@@ -141,6 +149,10 @@ impl Operator for Range {
         "Range"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, _pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let start = inputs.require(0)?;
         let limit = inputs.require(1)?;
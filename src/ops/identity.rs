@@ -1,7 +1,10 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output, OutputList};
+use crate::ops::{
+    infer_unary_shape, Input, InputList, IntoOpResult, OpError, Operator, Output, OutputList,
+    ShapeInputs,
+};
 use crate::tensor_pool::TensorPool;
 
 fn identity<T: Copy>(pool: &TensorPool, src: TensorView<T>) -> Tensor<T> {
@@ -25,6 +28,10 @@ impl Operator for Identity {
         result.into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -3,16 +3,120 @@
 use std::iter::zip;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{is_valid_permutation, NdTensorView, Tensor, TensorView};
+use rten_tensor::{is_valid_permutation, NdTensor, NdTensorView, SliceItem, Tensor, TensorView};
 use smallvec::SmallVec;
 
 use crate::ops::binary_elementwise::{broadcast_shapes, fast_broadcast_cycles_repeats};
 use crate::ops::{
-    resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output,
-    OutputList,
+    is_same_op, resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator,
+    Output, OutputList, ShapeInputs,
 };
 use crate::static_dims;
-use crate::tensor_pool::TensorPool;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Reshape a contiguous copy of `input` to `split_shape`, permute the result
+/// by `perm` and copy it into a new tensor with shape `out_shape`.
+///
+/// This is the common implementation of the operators which rearrange blocks
+/// of elements between the channel and spatial dimensions. The copy is done
+/// one 4D block at a time so that the strided copy used by `Transpose` can
+/// be used for the inner dimensions.
+fn reshape_permute_copy<T: Copy>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    split_shape: [usize; 6],
+    perm: [usize; 6],
+    out_shape: [usize; 4],
+) -> Tensor<T> {
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let permuted = input.reshaped(split_shape).permuted(perm);
+
+    let mut output = NdTensor::uninit_in(pool, permuted.shape());
+    for (out_block, in_block) in output.inner_iter_mut::<4>().zip(permuted.inner_iter::<4>()) {
+        out_block.init_from(&in_block);
+    }
+
+    // Safety: The loop above initialized all output elements.
+    let output = unsafe { output.assume_init() };
+    output.into_shape(out_shape).into_dyn()
+}
+
+/// Order in which the channel dimension is divided into blocks by
+/// [DepthToSpace].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum DepthToSpaceMode {
+    /// Depth-column-row order ("DCR" in ONNX). Input channel
+    /// `(y * block_size + x) * out_channels + c` is moved to offset `(y, x)`
+    /// within each block of output channel `c`.
+    #[default]
+    DepthColumnRow,
+
+    /// Column-row-depth order ("CRD" in ONNX). Input channel
+    /// `(c * block_size + y) * block_size + x` is moved to offset `(y, x)`
+    /// within each block of output channel `c`.
+    ColumnRowDepth,
+}
+
+/// Rearrange data from the channel dimension of an NCHW tensor into blocks
+/// in the spatial dimensions.
+///
+/// The output has shape `[N, C / block_size^2, H * block_size, W * block_size]`.
+pub fn depth_to_space<T: Copy>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    block_size: usize,
+    mode: DepthToSpaceMode,
+) -> Result<Tensor<T>, OpError> {
+    let [n, c, h, w] = static_dims!(input, 4, "NCHW")?.shape();
+    if block_size == 0 {
+        return Err(OpError::InvalidValue("block_size must be > 0"));
+    }
+    let block_area = block_size * block_size;
+    if c % block_area != 0 {
+        return Err(OpError::InvalidValue(
+            "Channel count must be a multiple of block_size^2",
+        ));
+    }
+    let out_c = c / block_area;
+    let bs = block_size;
+
+    let (split_shape, perm) = match mode {
+        DepthToSpaceMode::DepthColumnRow => ([n, bs, bs, out_c, h, w], [0, 3, 4, 1, 5, 2]),
+        DepthToSpaceMode::ColumnRowDepth => ([n, out_c, bs, bs, h, w], [0, 1, 4, 2, 5, 3]),
+    };
+    Ok(reshape_permute_copy(
+        pool,
+        input,
+        split_shape,
+        perm,
+        [n, out_c, h * bs, w * bs],
+    ))
+}
+
+#[derive(Debug)]
+pub struct DepthToSpace {
+    pub block_size: usize,
+    pub mode: DepthToSpaceMode,
+}
+
+impl Operator for DepthToSpace {
+    fn name(&self) -> &str {
+        "DepthToSpace"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+
+        match input {
+            Input::FloatTensor(input) => {
+                depth_to_space(pool, input, self.block_size, self.mode).into_op_result()
+            }
+            Input::IntTensor(input) => {
+                depth_to_space(pool, input, self.block_size, self.mode).into_op_result()
+            }
+        }
+    }
+}
 
 /// Return the tensor shape resulting from broadcasting `input_shape` with `shape`.
 fn expand_output_shape(
@@ -81,7 +185,7 @@ pub fn expand<T: Copy>(
     Ok(expand_to(pool, input, &out_shape))
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Expand {}
 
 impl Operator for Expand {
@@ -89,6 +193,10 @@ impl Operator for Expand {
         "Expand"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let shape = inputs.require_as(1)?;
@@ -100,6 +208,12 @@ impl Operator for Expand {
         }
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let shape = inputs.constant_vec_as(1)?;
+        let out_shape = expand_output_shape(inputs.shape(0)?, &shape).ok()?;
+        Some(vec![out_shape.to_vec()])
+    }
+
     fn can_run_in_place(&self) -> bool {
         // Expand can run in place if it is a noop, ie. if the broadcasted
         // shape is the same as the input shape.
@@ -156,7 +270,7 @@ pub fn flatten_in_place<T: Copy>(
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Flatten {
     pub axis: isize,
 }
@@ -166,6 +280,10 @@ impl Operator for Flatten {
         "Flatten"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
 
@@ -175,6 +293,11 @@ impl Operator for Flatten {
         }
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let out_shape = flattened_shape(inputs.shape(0)?, self.axis).ok()?;
+        Some(vec![out_shape.to_vec()])
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -294,7 +417,7 @@ pub fn reshape_in_place<T: Copy>(
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Reshape {
     pub allow_zero: bool,
 }
@@ -304,6 +427,10 @@ impl Operator for Reshape {
         "Reshape"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let shape = inputs.require_as(1)?;
@@ -315,6 +442,12 @@ impl Operator for Reshape {
         }
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let shape = inputs.constant_vec_as(1)?;
+        let out_shape = resolve_shape(inputs.shape(0)?, &shape, self.allow_zero).ok()?;
+        Some(vec![out_shape.to_vec()])
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -341,7 +474,88 @@ impl Operator for Reshape {
     }
 }
 
+/// Reverse the first `sequence_lens[b]` entries along `time_axis` for each
+/// batch item `b` along `batch_axis`.
+///
+/// Entries beyond the sequence length of each batch item are copied
+/// unchanged. See <https://onnx.ai/onnx/operators/onnx__ReverseSequence.html>.
+pub fn reverse_sequence<T: Copy>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    sequence_lens: NdTensorView<i32, 1>,
+    batch_axis: usize,
+    time_axis: usize,
+) -> Result<Tensor<T>, OpError> {
+    if input.ndim() < 2 {
+        return Err(OpError::InvalidValue("Input must have at least 2 dims"));
+    }
+    if batch_axis > 1 || time_axis > 1 || batch_axis == time_axis {
+        return Err(OpError::InvalidValue(
+            "`batch_axis` and `time_axis` must be 0 and 1 or 1 and 0",
+        ));
+    }
+    if sequence_lens.size(0) != input.size(batch_axis) {
+        return Err(OpError::InvalidValue(
+            "`sequence_lens` length does not match batch size",
+        ));
+    }
+
+    let max_len = input.size(time_axis);
+    let mut output = input.to_tensor_in(pool);
+    let mut in_range: SmallVec<[SliceItem; 4]> =
+        (0..input.ndim()).map(|_| SliceItem::full_range()).collect();
+    let mut out_range = in_range.clone();
+
+    for (batch, &seq_len) in sequence_lens.iter().enumerate() {
+        if seq_len < 0 || seq_len as usize > max_len {
+            return Err(OpError::InvalidValue("Sequence length is out of range"));
+        }
+        let seq_len = seq_len as usize;
+
+        in_range[batch_axis] = SliceItem::Index(batch as isize);
+        out_range[batch_axis] = SliceItem::Index(batch as isize);
+        for t in 0..seq_len {
+            in_range[time_axis] = SliceItem::Index((seq_len - 1 - t) as isize);
+            out_range[time_axis] = SliceItem::Index(t as isize);
+            output
+                .slice_mut_dyn(out_range.as_slice())
+                .copy_from(&input.slice_dyn(in_range.as_slice()));
+        }
+    }
+
+    Ok(output)
+}
+
 #[derive(Debug)]
+pub struct ReverseSequence {
+    pub batch_axis: usize,
+    pub time_axis: usize,
+}
+
+impl Operator for ReverseSequence {
+    fn name(&self) -> &str {
+        "ReverseSequence"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+        let sequence_lens = inputs.require_as::<i32>(1)?;
+        let sequence_lens = static_dims!(sequence_lens, 1)?;
+
+        match input {
+            Input::FloatTensor(input) => {
+                reverse_sequence(pool, input, sequence_lens, self.batch_axis, self.time_axis)
+                    .into_op_result()
+            }
+            Input::IntTensor(input) => {
+                reverse_sequence(pool, input, sequence_lens, self.batch_axis, self.time_axis)
+                    .into_op_result()
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub struct Shape {}
 
 impl Operator for Shape {
@@ -349,6 +563,10 @@ impl Operator for Shape {
         "Shape"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
 
@@ -360,9 +578,13 @@ impl Operator for Shape {
         let shape = Tensor::from_data(&[input.ndim()], data);
         shape.into_op_result()
     }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        Some(vec![vec![inputs.shape(0)?.len()]])
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Size {}
 
 impl Operator for Size {
@@ -370,6 +592,10 @@ impl Operator for Size {
         "Size"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let len = input.len() as i32;
@@ -381,21 +607,83 @@ impl Operator for Size {
 
         output.into_op_result()
     }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        inputs.shape(0)?;
+        Some(vec![Vec::new()])
+    }
 }
 
-pub fn squeeze_in_place<T: Clone>(
-    input: &mut Tensor<T>,
+/// Rearrange blocks of the spatial dimensions of an NCHW tensor into the
+/// channel dimension. This is the inverse of [depth_to_space] with
+/// [DepthToSpaceMode::DepthColumnRow].
+///
+/// The output has shape `[N, C * block_size^2, H / block_size, W / block_size]`.
+pub fn space_to_depth<T: Copy>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    block_size: usize,
+) -> Result<Tensor<T>, OpError> {
+    let [n, c, h, w] = static_dims!(input, 4, "NCHW")?.shape();
+    if block_size == 0 {
+        return Err(OpError::InvalidValue("block_size must be > 0"));
+    }
+    if h % block_size != 0 || w % block_size != 0 {
+        return Err(OpError::InvalidValue(
+            "Spatial dims must be a multiple of block_size",
+        ));
+    }
+    let bs = block_size;
+    let (out_h, out_w) = (h / bs, w / bs);
+
+    Ok(reshape_permute_copy(
+        pool,
+        input,
+        [n, c, out_h, bs, out_w, bs],
+        [0, 3, 5, 1, 2, 4],
+        [n, c * bs * bs, out_h, out_w],
+    ))
+}
+
+#[derive(Debug)]
+pub struct SpaceToDepth {
+    pub block_size: usize,
+}
+
+impl Operator for SpaceToDepth {
+    fn name(&self) -> &str {
+        "SpaceToDepth"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+
+        match input {
+            Input::FloatTensor(input) => {
+                space_to_depth(pool, input, self.block_size).into_op_result()
+            }
+            Input::IntTensor(input) => {
+                space_to_depth(pool, input, self.block_size).into_op_result()
+            }
+        }
+    }
+}
+
+/// Return the shape resulting from removing the dimensions in `axes` from
+/// `shape`, or all dimensions of size 1 if `axes` is `None`.
+fn squeezed_shape(
+    shape: &[usize],
     axes: Option<NdTensorView<i32, 1>>,
-) -> Result<(), OpError> {
+) -> Result<SmallVec<[usize; 5]>, OpError> {
     let axes = axes
-        .map(|axes| resolve_axes(input.ndim(), axes.iter()))
+        .map(|axes| resolve_axes(shape.len(), axes.iter()))
         .transpose()?;
     if let Some(ref axes) = axes {
         for &axis in axes.iter() {
-            if axis >= input.ndim() {
+            if axis >= shape.len() {
                 return Err(OpError::InvalidValue("Axis is invalid"));
             }
-            if input.size(axis) != 1 {
+            if shape[axis] != 1 {
                 return Err(OpError::InvalidValue(
                     "Can only remove dimensions of size 1",
                 ));
@@ -403,8 +691,7 @@ pub fn squeeze_in_place<T: Clone>(
         }
     }
 
-    let new_shape: SmallVec<[usize; 5]> = input
-        .shape()
+    Ok(shape
         .iter()
         .enumerate()
         .filter(|(dim, &size)| {
@@ -415,7 +702,14 @@ pub fn squeeze_in_place<T: Clone>(
             }
         })
         .map(|(_, &size)| size)
-        .collect();
+        .collect())
+}
+
+pub fn squeeze_in_place<T: Clone>(
+    input: &mut Tensor<T>,
+    axes: Option<NdTensorView<i32, 1>>,
+) -> Result<(), OpError> {
+    let new_shape = squeezed_shape(input.shape(), axes)?;
     input.reshape(&new_shape);
     Ok(())
 }
@@ -430,7 +724,7 @@ pub fn squeeze<T: Copy>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Squeeze {}
 
 impl Operator for Squeeze {
@@ -438,6 +732,10 @@ impl Operator for Squeeze {
         "Squeeze"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let axes = inputs.get_as(1)?;
@@ -449,6 +747,15 @@ impl Operator for Squeeze {
         }
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let axes = match inputs.shape(1) {
+            Some(_) => Some(inputs.constant_vec_as(1)?),
+            None => None,
+        };
+        let out_shape = squeezed_shape(inputs.shape(0)?, axes).ok()?;
+        Some(vec![out_shape.to_vec()])
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -497,7 +804,7 @@ pub fn transpose<T: Copy>(
     Ok(output.init_from(&transposed))
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Transpose {
     /// The order of the transposed dimensions. If ommitted, the dimensions
     /// are reversed.
@@ -509,6 +816,10 @@ impl Operator for Transpose {
         "Transpose"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let perm_slice = self.perm.as_deref();
@@ -517,17 +828,31 @@ impl Operator for Transpose {
             Input::IntTensor(input) => transpose(pool, input, perm_slice).into_op_result(),
         }
     }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let shape = inputs.shape(0)?;
+        let out_shape = match self.perm.as_deref() {
+            Some(perm) if is_valid_permutation(shape.len(), perm) => {
+                perm.iter().map(|&dim| shape[dim]).collect()
+            }
+            Some(_) => return None,
+            None => shape.iter().rev().copied().collect(),
+        };
+        Some(vec![out_shape])
+    }
 }
 
-pub fn unsqueeze_in_place<T: Clone>(
-    mut input: Tensor<T>,
+/// Resolve the axes for an unsqueeze operation on a tensor with `ndim`
+/// dimensions and return them in ascending order.
+fn unsqueeze_axes(
+    ndim: usize,
     axes: &NdTensorView<i32, 1>,
-) -> Result<Tensor<T>, OpError> {
-    let sorted_axes = if axes.len() == 1 {
-        let axis = resolve_axis(input.ndim() + 1, axes[[0]] as isize)?;
-        SmallVec::from_slice(&[axis])
+) -> Result<SmallVec<[usize; 4]>, OpError> {
+    if axes.len() == 1 {
+        let axis = resolve_axis(ndim + 1, axes[[0]] as isize)?;
+        Ok(SmallVec::from_slice(&[axis]))
     } else {
-        let mut sorted_axes = resolve_axes(input.ndim() + axes.len(), axes.iter())?;
+        let mut sorted_axes = resolve_axes(ndim + axes.len(), axes.iter())?;
         sorted_axes.sort_unstable();
 
         let axes_unique = zip(sorted_axes.iter().skip(1), sorted_axes.iter())
@@ -535,8 +860,15 @@ pub fn unsqueeze_in_place<T: Clone>(
         if !axes_unique {
             return Err(OpError::InvalidValue("Axes must be unique"));
         }
-        sorted_axes
-    };
+        Ok(sorted_axes)
+    }
+}
+
+pub fn unsqueeze_in_place<T: Clone>(
+    mut input: Tensor<T>,
+    axes: &NdTensorView<i32, 1>,
+) -> Result<Tensor<T>, OpError> {
+    let sorted_axes = unsqueeze_axes(input.ndim(), axes)?;
 
     input.make_contiguous();
     for axis in sorted_axes {
@@ -554,7 +886,7 @@ pub fn unsqueeze<T: Copy>(
     unsqueeze_in_place(input.to_tensor_in(pool), axes)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Unsqueeze {}
 
 impl Operator for Unsqueeze {
@@ -562,6 +894,10 @@ impl Operator for Unsqueeze {
         "Unsqueeze"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         let axes = inputs.require_as(1)?;
@@ -573,6 +909,15 @@ impl Operator for Unsqueeze {
         }
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let mut out_shape = inputs.shape(0)?.to_vec();
+        let axes = inputs.constant_vec_as(1)?;
+        for axis in unsqueeze_axes(out_shape.len(), &axes).ok()? {
+            out_shape.insert(axis, 1);
+        }
+        Some(vec![out_shape])
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -604,11 +949,153 @@ mod tests {
     use rten_tensor::{NdTensor, Tensor};
 
     use crate::ops::layout::{
-        expand, flatten, reshape, reshape_in_place, squeeze, squeeze_in_place, transpose,
-        unsqueeze, Reshape, Shape, Size,
+        depth_to_space, expand, flatten, reshape, reshape_in_place, reverse_sequence,
+        space_to_depth, squeeze, squeeze_in_place, transpose, unsqueeze, DepthToSpaceMode, Expand,
+        Flatten, Reshape, Shape, Size, Squeeze, Transpose, Unsqueeze,
     };
     use crate::ops::tests::new_pool;
-    use crate::ops::{OpError, Operator};
+    use crate::ops::{InputInfo, OpError, Operator, ShapeInputs};
+
+    #[test]
+    fn test_depth_to_space() {
+        let pool = new_pool();
+
+        // Examples from the ONNX spec.
+        let input = Tensor::arange(0, 48, None).into_shape([1, 8, 2, 3].as_slice());
+
+        let result = depth_to_space(
+            &pool,
+            input.view(),
+            2, /* block_size */
+            DepthToSpaceMode::DepthColumnRow,
+        )
+        .unwrap();
+        let expected = Tensor::from([
+            [
+                [0, 12, 1, 13, 2, 14],
+                [24, 36, 25, 37, 26, 38],
+                [3, 15, 4, 16, 5, 17],
+                [27, 39, 28, 40, 29, 41],
+            ],
+            [
+                [6, 18, 7, 19, 8, 20],
+                [30, 42, 31, 43, 32, 44],
+                [9, 21, 10, 22, 11, 23],
+                [33, 45, 34, 46, 35, 47],
+            ],
+        ])
+        .into_shape([1, 2, 4, 6].as_slice());
+        assert_eq!(result, expected);
+
+        let result = depth_to_space(
+            &pool,
+            input.view(),
+            2, /* block_size */
+            DepthToSpaceMode::ColumnRowDepth,
+        )
+        .unwrap();
+        let expected = Tensor::from([
+            [
+                [0, 6, 1, 7, 2, 8],
+                [12, 18, 13, 19, 14, 20],
+                [3, 9, 4, 10, 5, 11],
+                [15, 21, 16, 22, 17, 23],
+            ],
+            [
+                [24, 30, 25, 31, 26, 32],
+                [36, 42, 37, 43, 38, 44],
+                [27, 33, 28, 34, 29, 35],
+                [39, 45, 40, 46, 41, 47],
+            ],
+        ])
+        .into_shape([1, 2, 4, 6].as_slice());
+        assert_eq!(result, expected);
+
+        // Non-contiguous input
+        let input = Tensor::arange(0, 48, None).into_shape([1, 8, 3, 2].as_slice());
+        let transposed = input.permuted(&[0, 1, 3, 2]);
+        let result = depth_to_space(
+            &pool,
+            transposed.view(),
+            2,
+            DepthToSpaceMode::DepthColumnRow,
+        )
+        .unwrap();
+        let expected = depth_to_space(
+            &pool,
+            transposed.to_tensor().view(),
+            2,
+            DepthToSpaceMode::DepthColumnRow,
+        )
+        .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_depth_to_space_invalid() {
+        let pool = new_pool();
+        let input = Tensor::<f32>::zeros(&[1, 6, 2, 2]);
+
+        let result = depth_to_space(&pool, input.view(), 2, DepthToSpaceMode::DepthColumnRow);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Channel count must be a multiple of block_size^2"
+            ))
+        );
+
+        let result = depth_to_space(&pool, input.view(), 0, DepthToSpaceMode::DepthColumnRow);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("block_size must be > 0"))
+        );
+
+        let input = Tensor::<f32>::zeros(&[6, 2, 2]);
+        let result = depth_to_space(&pool, input.view(), 2, DepthToSpaceMode::DepthColumnRow);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("input must have 4 dims (NCHW)"))
+        );
+    }
+
+    #[test]
+    fn test_space_to_depth() {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Example from the ONNX spec.
+        let input = Tensor::arange(0, 48, None).into_shape([1, 2, 4, 6].as_slice());
+        let result = space_to_depth(&pool, input.view(), 2).unwrap();
+        let expected = Tensor::from([
+            [[0, 2, 4], [12, 14, 16]],
+            [[24, 26, 28], [36, 38, 40]],
+            [[1, 3, 5], [13, 15, 17]],
+            [[25, 27, 29], [37, 39, 41]],
+            [[6, 8, 10], [18, 20, 22]],
+            [[30, 32, 34], [42, 44, 46]],
+            [[7, 9, 11], [19, 21, 23]],
+            [[31, 33, 35], [43, 45, 47]],
+        ])
+        .into_shape([1, 8, 2, 3].as_slice());
+        assert_eq!(result, expected);
+
+        // SpaceToDepth should be the inverse of DepthToSpace in DCR mode.
+        let input = Tensor::<f32>::rand(&[2, 3, 6, 9], &mut rng);
+        let result = space_to_depth(&pool, input.view(), 3).unwrap();
+        assert_eq!(result.shape(), &[2, 27, 2, 3]);
+        let roundtrip =
+            depth_to_space(&pool, result.view(), 3, DepthToSpaceMode::DepthColumnRow).unwrap();
+        assert_eq!(roundtrip, input);
+
+        // Invalid spatial size
+        let result = space_to_depth(&pool, input.view(), 4);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Spatial dims must be a multiple of block_size"
+            ))
+        );
+    }
 
     #[test]
     fn test_expand() {
@@ -688,6 +1175,73 @@ mod tests {
         assert_eq!(result.shape(), &[1, 24]);
     }
 
+    #[test]
+    fn test_infer_shapes() {
+        let shape_input = |shape: &'static [usize]| Some(InputInfo::Shape(shape));
+        let axes = Tensor::from([0, 2]);
+        let target_shape = Tensor::from([0, -1]);
+        let expand_shape = Tensor::from([5, 1, 1]);
+
+        let infer = |op: &dyn Operator, inputs: &[Option<InputInfo>]| {
+            op.infer_shapes(&ShapeInputs::new(inputs))
+        };
+        let data: &'static [usize] = &[2, 3, 4];
+
+        assert_eq!(infer(&Shape {}, &[shape_input(data)]), Some(vec![vec![3]]));
+        assert_eq!(infer(&Size {}, &[shape_input(data)]), Some(vec![vec![]]));
+        assert_eq!(
+            infer(&Flatten { axis: 1 }, &[shape_input(data)]),
+            Some(vec![vec![2, 12]])
+        );
+        assert_eq!(
+            infer(&Transpose { perm: None }, &[shape_input(data)]),
+            Some(vec![vec![4, 3, 2]])
+        );
+        assert_eq!(
+            infer(
+                &Unsqueeze {},
+                &[
+                    shape_input(data),
+                    Some(InputInfo::Constant(axes.view().into()))
+                ]
+            ),
+            Some(vec![vec![1, 2, 1, 3, 4]])
+        );
+        assert_eq!(
+            infer(
+                &Reshape { allow_zero: false },
+                &[
+                    shape_input(data),
+                    Some(InputInfo::Constant(target_shape.view().into()))
+                ]
+            ),
+            Some(vec![vec![2, 12]])
+        );
+        assert_eq!(
+            infer(
+                &Expand {},
+                &[
+                    shape_input(&[3, 1]),
+                    Some(InputInfo::Constant(expand_shape.view().into()))
+                ]
+            ),
+            Some(vec![vec![5, 3, 1]])
+        );
+        assert_eq!(
+            infer(&Squeeze {}, &[shape_input(&[1, 3, 1])]),
+            Some(vec![vec![3]])
+        );
+
+        // Shapes can't be inferred if a required input is not a constant.
+        assert_eq!(
+            infer(
+                &Reshape { allow_zero: false },
+                &[shape_input(data), shape_input(data)]
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_reshape_with_unspecified_dim() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -868,6 +1422,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reverse_sequence() {
+        let pool = new_pool();
+
+        // Examples from the ONNX spec.
+        let input = Tensor::from([[0, 4, 8, 12], [1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15]]);
+        let seq_lens = NdTensor::from([4, 3, 2, 1]);
+        let result = reverse_sequence(&pool, input.view(), seq_lens.view(), 1, 0).unwrap();
+        assert_eq!(
+            result,
+            Tensor::from([[3, 6, 9, 12], [2, 5, 8, 13], [1, 4, 10, 14], [0, 7, 11, 15]])
+        );
+
+        let input = Tensor::from([[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11], [12, 13, 14, 15]]);
+        let seq_lens = NdTensor::from([1, 2, 3, 4]);
+        let result = reverse_sequence(&pool, input.view(), seq_lens.view(), 0, 1).unwrap();
+        assert_eq!(
+            result,
+            Tensor::from([[0, 1, 2, 3], [5, 4, 6, 7], [10, 9, 8, 11], [15, 14, 13, 12]])
+        );
+
+        // Invalid sequence length
+        let seq_lens = NdTensor::from([1, 2, 3, 5]);
+        let result = reverse_sequence(&pool, input.view(), seq_lens.view(), 0, 1);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Sequence length is out of range"))
+        );
+
+        // Invalid axes
+        let seq_lens = NdTensor::from([1, 2, 3, 4]);
+        let result = reverse_sequence(&pool, input.view(), seq_lens.view(), 0, 0);
+        assert!(matches!(result, Err(OpError::InvalidValue(_))));
+    }
+
     #[test]
     fn test_shape() {
         let pool = new_pool();
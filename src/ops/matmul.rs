@@ -4,13 +4,16 @@ use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView};
 
 use crate::check_dims;
-use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
+use crate::gemm::{Activation, BiasVector, GemmExecutor, GemmInputA, GemmInputB, PackedBMatrix};
 use crate::ops::binary_elementwise::broadcast_shapes;
 use crate::ops::layout::expand_to;
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::ops::{
+    is_same_op, Input, InputList, IntoOpResult, OpError, Operator, OutputList, PrepackedInput,
+    ShapeInputs,
+};
 use crate::tensor_pool::{AutoReturn, TensorPool};
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Gemm {
     pub alpha: f32,
     pub beta: f32,
@@ -33,6 +36,34 @@ pub fn gemm_op(
     beta: f32,
     transpose_a: bool,
     transpose_b: bool,
+) -> Result<Tensor, OpError> {
+    gemm_op_impl(
+        pool,
+        a,
+        b,
+        None,
+        c,
+        alpha,
+        beta,
+        transpose_a,
+        transpose_b,
+        None,
+    )
+}
+
+/// Variant of [`gemm_op`] which can use a prepacked copy of `b`, with any
+/// transposition already applied, and apply an activation to the output.
+fn gemm_op_impl(
+    pool: &TensorPool,
+    a: TensorView,
+    b: TensorView,
+    packed_b: Option<&PackedBMatrix>,
+    c: Option<TensorView>,
+    alpha: f32,
+    beta: f32,
+    transpose_a: bool,
+    transpose_b: bool,
+    activation: Option<Activation>,
 ) -> Result<Tensor, OpError> {
     check_dims!(a, 2);
     check_dims!(b, 2);
@@ -43,6 +74,13 @@ pub fn gemm_op(
     let out_shape = &[a.size(0), b.size(1)][..];
     let gemm = GemmExecutor::new();
 
+    // Vector-matrix products use a special case that doesn't benefit from
+    // packing.
+    let b_input = match packed_b {
+        Some(packed) if a.size(0) > 1 => GemmInputB::Packed(packed),
+        _ => GemmInputB::Unpacked(b.nd_view()),
+    };
+
     let output = match c {
         Some(c) if beta != 0. => {
             if !c.can_broadcast_to(out_shape) {
@@ -52,25 +90,29 @@ pub fn gemm_op(
             }
             let mut output = expand_to(pool, c, out_shape);
             let out_row_stride = output.stride(0);
-            gemm.gemm(
+            gemm.gemm_bias(
                 output.data_mut().unwrap(),
                 out_row_stride,
                 GemmInputA::Unpacked(a.nd_view()),
-                GemmInputB::Unpacked(b.nd_view()),
+                b_input,
                 alpha,
                 beta,
+                None, /* bias */
+                activation,
             );
             output
         }
         _ => {
             let mut output = Tensor::uninit_in(pool, out_shape);
             let out_row_stride = output.stride(0);
-            gemm.gemm_uninit(
+            gemm.gemm_uninit_bias_activation(
                 output.data_mut().unwrap(),
                 out_row_stride,
                 GemmInputA::Unpacked(a.nd_view()),
-                GemmInputB::Unpacked(b.nd_view()),
+                b_input,
                 alpha,
+                None, /* bias */
+                activation,
             );
             // Safety: `gemm_uninit` initialized all elements
             unsafe { output.assume_init() }
@@ -85,22 +127,108 @@ impl Operator for Gemm {
         "Gemm"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let a = inputs.require_as(0)?;
         let b = inputs.require_as(1)?;
         let c = inputs.get_as(2)?;
-        gemm_op(
+        gemm_op_impl(
             pool,
             a,
             b,
+            get_packed_b(&inputs, 1),
             c,
             self.alpha,
             self.beta,
             self.transpose_a,
             self.transpose_b,
+            None, /* activation */
         )
         .into_op_result()
     }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        match index {
+            1 => prepack_b(input, self.transpose_b),
+            _ => None,
+        }
+    }
+}
+
+/// Gemm followed by an activation function.
+///
+/// This is created by the graph optimizer to fuse activations into the
+/// matrix multiplication, so that they are applied as each output tile is
+/// computed, rather than in a separate pass over the output.
+#[derive(Debug)]
+pub struct FusedGemm {
+    pub gemm: Gemm,
+    pub activation: Activation,
+}
+
+impl Operator for FusedGemm {
+    fn name(&self) -> &str {
+        "FusedGemm"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let a = inputs.require_as(0)?;
+        let b = inputs.require_as(1)?;
+        let c = inputs.get_as(2)?;
+        gemm_op_impl(
+            pool,
+            a,
+            b,
+            get_packed_b(&inputs, 1),
+            c,
+            self.gemm.alpha,
+            self.gemm.beta,
+            self.gemm.transpose_a,
+            self.gemm.transpose_b,
+            Some(self.activation),
+        )
+        .into_op_result()
+    }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        match index {
+            1 => prepack_b(input, self.gemm.transpose_b),
+            _ => None,
+        }
+    }
+}
+
+/// Pack a constant 2D matrix, or its transpose, for use as the "B" input of
+/// matrix multiplications.
+fn prepack_b(input: Input, transpose: bool) -> Option<PrepackedInput> {
+    let Input::FloatTensor(b) = input else {
+        return None;
+    };
+    if b.ndim() != 2 {
+        return None;
+    }
+    let b = if transpose { b.transposed() } else { b };
+    let packed = GemmExecutor::new().prepack_b(b.nd_view());
+    Some(PrepackedInput::from_packed_b(vec![packed]))
+}
+
+/// Get the packed matrix for an input prepacked by [`prepack_b`].
+fn get_packed_b<'a>(inputs: &InputList<'a>, index: usize) -> Option<&'a PackedBMatrix> {
+    inputs
+        .get_prepacked(index)
+        .and_then(|p| p.packed_b())
+        .and_then(|p| p.first())
 }
 
 /// Hints for how a batched MatMul should be performed. This exists to enable
@@ -117,13 +245,76 @@ enum MatmulStrategy {
 }
 
 pub fn matmul(pool: &TensorPool, a: TensorView, b: TensorView) -> Result<Tensor, OpError> {
-    matmul_impl(pool, a, b, MatmulStrategy::Auto)
+    matmul_impl(pool, a, b, None, None, 1., None, MatmulStrategy::Auto)
+}
+
+/// Compute `activation(alpha * matmul(a, b) + bias)`, where `bias` is a vector
+/// that is broadcast against the columns of the output.
+///
+/// The bias length must match the columns of `b` or be 1. `packed_b` is an
+/// optional prepacked copy of `b`, which must be a matrix.
+pub fn fused_matmul(
+    pool: &TensorPool,
+    a: TensorView,
+    b: TensorView,
+    bias: Option<TensorView>,
+    alpha: f32,
+    activation: Option<Activation>,
+    packed_b: Option<&PackedBMatrix>,
+) -> Result<Tensor, OpError> {
+    let Some(bias) = bias else {
+        return matmul_impl(
+            pool,
+            a,
+            b,
+            packed_b,
+            None,
+            alpha,
+            activation,
+            MatmulStrategy::Auto,
+        );
+    };
+    check_dims!(bias, 1);
+
+    if b.ndim() < 2 {
+        return Err(OpError::InvalidValue("Inputs must have >= 2 dimensions"));
+    }
+    let b_cols = b.size(b.ndim() - 1);
+    let bias = if bias.size(0) == 1 {
+        bias.broadcast([b_cols].as_slice()).to_contiguous_in(pool)
+    } else if bias.size(0) == b_cols {
+        bias.to_contiguous_in(pool)
+    } else {
+        return Err(OpError::IncompatibleInputShapes(
+            "Bias length does not match columns of second matrix",
+        ));
+    }
+    .auto_return(pool);
+
+    matmul_impl(
+        pool,
+        a,
+        b,
+        packed_b,
+        Some(bias.data().unwrap()),
+        alpha,
+        activation,
+        MatmulStrategy::Auto,
+    )
 }
 
+/// Compute `activation(alpha * matmul(a, b) + bias)`, where `bias` is a row
+/// vector whose length matches the columns of `b`.
+///
+/// `packed_b` is an optional prepacked copy of `b`, which must be a matrix.
 fn matmul_impl(
     pool: &TensorPool,
     a: TensorView,
     b: TensorView,
+    packed_b: Option<&PackedBMatrix>,
+    bias: Option<&[f32]>,
+    alpha: f32,
+    activation: Option<Activation>,
     strategy: MatmulStrategy,
 ) -> Result<Tensor, OpError> {
     if a.ndim() < 2 || b.ndim() < 2 {
@@ -163,7 +354,16 @@ fn matmul_impl(
         // nb. We assume `a` is likely already contiguous, so this will be cheap.
         let a_contig = a.to_contiguous_in(pool).auto_return(pool);
         let a_matrix = a_contig.reshaped([num_a_matrices * a_rows, a_cols].as_slice());
-        let mut output = matmul(pool, a_matrix, b.clone())?;
+        let mut output = matmul_impl(
+            pool,
+            a_matrix,
+            b.clone(),
+            packed_b,
+            bias,
+            alpha,
+            activation,
+            strategy,
+        )?;
         output.reshape(out_shape);
         return Ok(output);
     }
@@ -199,11 +399,15 @@ fn matmul_impl(
     });
     let prepacked_a = prepacked_a.as_deref();
 
-    let prepacked_b = (num_a_matrices > 1 && num_b_matrices == 1 && a_rows > 1).then(|| {
-        let b_matrix = b.inner_iter::<2>().next().unwrap();
-        gemm.prepack_b_in(pool, b_matrix).auto_return(pool)
-    });
-    let prepacked_b = prepacked_b.as_deref();
+    let packed_b = packed_b.filter(|_| a_rows > 1);
+    let prepacked_b =
+        (packed_b.is_none() && num_a_matrices > 1 && num_b_matrices == 1 && a_rows > 1).then(
+            || {
+                let b_matrix = b.inner_iter::<2>().next().unwrap();
+                gemm.prepack_b_in(pool, b_matrix).auto_return(pool)
+            },
+        );
+    let prepacked_b = packed_b.or(prepacked_b.as_deref());
 
     a_broadcast
         .inner_iter::<2>()
@@ -223,12 +427,14 @@ fn matmul_impl(
                 GemmInputB::Unpacked(b_mat)
             };
 
-            gemm.gemm_uninit(
+            gemm.gemm_uninit_bias_activation(
                 out_mat,
                 out_row_stride,
                 a_input,
                 b_input,
-                1., // alpha
+                alpha,
+                bias.map(BiasVector::Row),
+                activation,
             );
         });
 
@@ -238,7 +444,7 @@ fn matmul_impl(
     Ok(output)
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct MatMul {}
 
 impl Operator for MatMul {
@@ -246,10 +452,84 @@ impl Operator for MatMul {
         "MatMul"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let a = inputs.require_as(0)?;
         let b = inputs.require_as(1)?;
-        matmul(pool, a, b).into_op_result()
+        let packed_b = get_packed_b(&inputs, 1);
+        matmul_impl(pool, a, b, packed_b, None, 1., None, MatmulStrategy::Auto).into_op_result()
+    }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        match index {
+            1 => prepack_b(input, false),
+            _ => None,
+        }
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let [a_prefix @ .., a_rows, a_cols] = inputs.shape(0)? else {
+            return None;
+        };
+        let [b_prefix @ .., b_rows, b_cols] = inputs.shape(1)? else {
+            return None;
+        };
+        if a_cols != b_rows {
+            return None;
+        }
+        let mut out_shape = broadcast_shapes(a_prefix, b_prefix)?.to_vec();
+        out_shape.extend([*a_rows, *b_cols]);
+        Some(vec![out_shape])
+    }
+}
+
+/// MatMul with scaling, bias addition and activation fused into the matrix
+/// multiplication.
+///
+/// This computes `activation(alpha * MatMul(A, B) + bias)`, where the optional
+/// third input is a bias vector which is added to each row of the output. This
+/// is created by the graph optimizer from MatMul operations which are followed
+/// by multiplication with a scalar, addition of a bias vector and/or an
+/// activation function.
+#[derive(Clone, Debug)]
+pub struct FusedMatMul {
+    pub alpha: f32,
+    pub activation: Option<Activation>,
+}
+
+impl Operator for FusedMatMul {
+    fn name(&self) -> &str {
+        "FusedMatMul"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let a = inputs.require_as(0)?;
+        let b = inputs.require_as(1)?;
+        let bias = inputs.get_as(2)?;
+        let packed_b = get_packed_b(&inputs, 1);
+        fused_matmul(pool, a, b, bias, self.alpha, self.activation, packed_b).into_op_result()
+    }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        match index {
+            1 => prepack_b(input, false),
+            _ => None,
+        }
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        MatMul {}.infer_shapes(inputs)
     }
 }
 
@@ -263,11 +543,14 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::{Tensor, TensorView, TensorViewMut};
 
-    use crate::gemm::gemm;
+    use crate::gemm::{gemm, Activation};
     use crate::ops::tests::new_pool;
+    use crate::ops::{InputList, Operator};
     use crate::tensor_pool::AutoReturn;
 
-    use super::{gemm_op, matmul, matmul_impl, MatmulStrategy, OpError};
+    use super::{
+        fused_matmul, gemm_op, matmul, matmul_impl, FusedGemm, Gemm, MatmulStrategy, OpError,
+    };
 
     fn gemm_tensors(c: &mut Tensor, a: &Tensor, b: &Tensor, alpha: f32, beta: f32) {
         c.make_contiguous();
@@ -379,6 +662,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fused_gemm() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Shift values so that activations have a range of inputs.
+        let mut a = Tensor::rand(&[3, 10], &mut rng);
+        a.apply(|x| x - 0.5);
+        let b = Tensor::rand(&[8, 10], &mut rng);
+        let c = Tensor::rand(&[8], &mut rng);
+
+        let activations = [
+            Activation::Relu,
+            Activation::LeakyRelu { alpha: 0.1 },
+            Activation::Clip { min: 0., max: 0.5 },
+            Activation::Sigmoid,
+        ];
+
+        // Cases with and without the `C` input, which use different GEMM
+        // methods.
+        for c in [Some(&c), None] {
+            let mut inputs: Vec<_> = vec![a.view().into(), b.view().into()];
+            if let Some(c) = c {
+                inputs.push(c.view().into());
+            }
+            let gemm_op = Gemm {
+                alpha: 0.5,
+                beta: 2.,
+                transpose_a: false,
+                transpose_b: true,
+            };
+
+            for activation in activations {
+                let mut expected: Tensor = gemm_op
+                    .run(&pool, InputList::from(inputs.as_slice()))?
+                    .remove(0)
+                    .try_into()?;
+                activation.apply(expected.data_mut().unwrap());
+
+                let fused_op = FusedGemm {
+                    gemm: gemm_op.clone(),
+                    activation,
+                };
+                let result: Tensor = fused_op
+                    .run(&pool, InputList::from(inputs.as_slice()))?
+                    .remove(0)
+                    .try_into()?;
+
+                expect_equal(&result, &expected)?;
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_gemm_op_invalid_inputs() {
         let pool = new_pool();
@@ -532,6 +870,115 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fused_matmul() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        struct Case<'a> {
+            a_shape: &'a [usize],
+            b_shape: &'a [usize],
+            bias_len: Option<usize>,
+        }
+
+        let cases = [
+            // Unbatched, no bias
+            Case {
+                a_shape: &[5, 10],
+                b_shape: &[10, 3],
+                bias_len: None,
+            },
+            // Batched "A" with a single "B" matrix
+            Case {
+                a_shape: &[2, 5, 10],
+                b_shape: &[10, 3],
+                bias_len: Some(3),
+            },
+            // Batched "A" and "B"
+            Case {
+                a_shape: &[2, 5, 10],
+                b_shape: &[2, 10, 3],
+                bias_len: Some(3),
+            },
+            // Vector-matrix product
+            Case {
+                a_shape: &[1, 10],
+                b_shape: &[10, 3],
+                bias_len: Some(3),
+            },
+            // Bias that is broadcast
+            Case {
+                a_shape: &[5, 10],
+                b_shape: &[10, 3],
+                bias_len: Some(1),
+            },
+            // Zero depth
+            Case {
+                a_shape: &[5, 0],
+                b_shape: &[0, 3],
+                bias_len: Some(3),
+            },
+        ];
+
+        for Case {
+            a_shape,
+            b_shape,
+            bias_len,
+        } in cases
+        {
+            let a = Tensor::<f32>::rand(a_shape, &mut rng);
+            let b = Tensor::<f32>::rand(b_shape, &mut rng);
+            let bias = bias_len.map(|len| Tensor::<f32>::rand(&[len], &mut rng));
+            let alpha = 0.5;
+
+            let mut expected = matmul(&pool, a.view(), b.view()).unwrap();
+            expected.apply(|x| x * alpha);
+            if let Some(bias) = &bias {
+                let bias_len = bias.len();
+                for (i, x) in expected.iter_mut().enumerate() {
+                    *x += bias[[i % b.size(b.ndim() - 1) % bias_len]];
+                }
+            }
+
+            for activation in [
+                None,
+                Some(Activation::Clip { min: 0.5, max: 1. }),
+                Some(Activation::Sigmoid),
+            ] {
+                let mut expected = expected.clone();
+                if let Some(activation) = activation {
+                    activation.apply(expected.data_mut().unwrap());
+                }
+
+                let result = fused_matmul(
+                    &pool,
+                    a.view(),
+                    b.view(),
+                    bias.as_ref().map(|b| b.view()),
+                    alpha,
+                    activation,
+                    None,
+                )
+                .unwrap();
+                expect_equal(&result, &expected)?;
+            }
+        }
+
+        // Bias length that doesn't match the columns of B.
+        let a = Tensor::<f32>::rand(&[5, 10], &mut rng);
+        let b = Tensor::<f32>::rand(&[10, 3], &mut rng);
+        let bias = Tensor::<f32>::rand(&[4], &mut rng);
+        let result = fused_matmul(&pool, a.view(), b.view(), Some(bias.view()), 1., None, None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Bias length does not match columns of second matrix"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_matmul_zero_sized_dim() {
         struct Case {
@@ -603,7 +1050,7 @@ mod tests {
                 );
                 let pool = new_pool();
                 run_bench(trials, Some(&desc), || {
-                    matmul_impl(&pool, a.view(), b.view(), strategy)
+                    matmul_impl(&pool, a.view(), b.view(), None, None, 1., None, strategy)
                         .unwrap()
                         .auto_return(&pool);
                 });
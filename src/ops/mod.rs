@@ -25,9 +25,11 @@ use rten_tensor::{
     DynLayout, MutLayout, NdTensor, NdTensorView, Storage, Tensor, TensorBase, TensorView, ViewData,
 };
 
-use crate::downcast::impl_downcastdyn;
+use crate::downcast::{impl_downcastdyn, DowncastDyn};
+use crate::gemm::{PackedAMatrix, PackedBMatrix};
 use crate::tensor_pool::{ExtractBuffer, TensorPool};
 
+mod attention;
 mod binary_elementwise;
 mod concat;
 mod conv;
@@ -49,6 +51,9 @@ mod random;
 mod reduce;
 mod resize;
 mod rnn;
+mod roi_align;
+mod rotary_embedding;
+mod signal;
 mod slice;
 mod split;
 mod trilu;
@@ -58,63 +63,87 @@ mod variadic_elementwise;
 // Fused operators.
 pub(crate) mod fused;
 
+pub use attention::{scaled_dot_product_attention, ScaledDotProductAttention};
 pub use binary_elementwise::{
-    add, add_in_place, and, div, div_in_place, equal, greater, greater_or_equal, less,
-    less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub, sub_in_place, where_op,
-    xor, Add, And, Div, DivMode, Equal, Greater, GreaterOrEqual, Less, LessOrEqual, Mod, Mul, Or,
-    Pow, Sub, Where, Xor,
+    add, add_in_place, and, bit_shift, bit_shift_in_place, bitwise_and, bitwise_and_in_place,
+    bitwise_or, bitwise_or_in_place, bitwise_xor, bitwise_xor_in_place, div, div_in_place, equal,
+    greater, greater_or_equal, less, less_or_equal, mod_op, mul, mul_in_place, or, pow,
+    pow_in_place, prelu, prelu_in_place, sub, sub_in_place, where_op, xor, Add, And, BitShift,
+    BitShiftDirection, BitwiseAnd, BitwiseOr, BitwiseXor, Div, DivMode, Equal, Greater,
+    GreaterOrEqual, Less, LessOrEqual, Mod, Mul, Or, PRelu, Pow, Sub, Where, Xor,
 };
 pub use concat::{concat, tile, Concat, Tile};
 pub use conv::{conv, conv_transpose, Conv, ConvTranspose};
+pub(crate) use conv::{ConvNhwc, FusedConv};
 pub use convert::Cast;
 pub use einsum::{einsum, Einsum};
 pub use gather::{
-    gather, gather_elements, gather_nd, scatter_elements, scatter_nd, Gather, GatherElements,
-    GatherND, ScatterElements, ScatterND, ScatterReduction,
+    compress, gather, gather_elements, gather_nd, scatter_elements, scatter_nd, unique, Compress,
+    Gather, GatherElements, GatherND, ScatterElements, ScatterND, ScatterReduction, Unique,
+    UniqueOutput,
 };
 pub use generate::{constant_of_shape, onehot, range, ConstantOfShape, OneHot, Range};
 pub use identity::Identity;
 pub use layout::{
-    expand, flatten, reshape, squeeze, squeeze_in_place, Expand, Flatten, Reshape, Shape, Size,
-    Squeeze, Transpose, Unsqueeze,
+    depth_to_space, expand, flatten, reshape, reverse_sequence, space_to_depth, squeeze,
+    squeeze_in_place, DepthToSpace, DepthToSpaceMode, Expand, Flatten, Reshape, ReverseSequence,
+    Shape, Size, SpaceToDepth, Squeeze, Transpose, Unsqueeze,
 };
 pub use matmul::{gemm_op, matmul, Gemm, MatMul};
+pub(crate) use matmul::{FusedGemm, FusedMatMul};
 pub use non_max_suppression::{non_max_suppression, BoxOrder, NonMaxSuppression};
 pub use norm::{
-    batch_norm, batch_norm_in_place, instance_normalization, layer_normalization, log_softmax,
-    softmax, BatchNormalization, InstanceNormalization, LayerNormalization, LogSoftmax, Softmax,
+    batch_norm, batch_norm_in_place, hardmax, hardmax_in_place, instance_normalization,
+    layer_normalization, log_softmax, rms_normalization, softmax, BatchNormalization, Hardmax,
+    InstanceNormalization, LayerNormalization, LogSoftmax, RMSNormalization, Softmax,
 };
-pub use pad::{pad, Pad};
+pub use pad::{center_crop_pad, pad, CenterCropPad, Pad};
 pub use pooling::{
-    average_pool, global_average_pool, max_pool, AveragePool, GlobalAveragePool, MaxPool,
+    average_pool, global_average_pool, global_lp_pool, global_max_pool, lp_pool, max_pool,
+    max_pool_with_indices, max_unpool, AveragePool, GlobalAveragePool, GlobalLpPool, GlobalMaxPool,
+    LpPool, MaxPool, MaxUnpool,
 };
+pub(crate) use pooling::{AveragePoolNhwc, MaxPoolNhwc};
 
 #[cfg(feature = "random")]
-pub use random::{RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
+pub use random::{
+    Bernoulli, Multinomial, RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike,
+};
 
 pub use reduce::{
-    arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_max, reduce_mean, reduce_min,
-    reduce_prod, reduce_sum, reduce_sum_square, topk, ArgMax, ArgMin, CumSum, NonZero, ReduceL2,
-    ReduceMax, ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, TopK,
+    arg_max, arg_min, cum_sum, nonzero, reduce_l1, reduce_l2, reduce_log_sum, reduce_log_sum_exp,
+    reduce_max, reduce_mean, reduce_min, reduce_prod, reduce_sum, reduce_sum_square, topk, ArgMax,
+    ArgMin, CumSum, NonZero, ReduceL1, ReduceL2, ReduceLogSum, ReduceLogSumExp, ReduceMax,
+    ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, TopK,
 };
+pub(crate) use resize::ResizeNhwc;
 pub use resize::{
     resize, resize_image, CoordTransformMode, NearestMode, Resize, ResizeMode, ResizeTarget,
 };
-pub use rnn::{gru, lstm, Direction, GRU, LSTM};
+pub use rnn::{gru, lstm, rnn, Direction, RnnActivation, RnnOptions, GRU, LSTM, RNN};
+pub use roi_align::{roi_align, RoiAlign, RoiAlignCoordMode, RoiAlignMode};
+pub use rotary_embedding::{rotary_embedding, RotaryEmbedding};
+pub use signal::{
+    blackman_window, dft, hamming_window, hann_window, mel_weight_matrix, stft, BlackmanWindow,
+    HammingWindow, HannWindow, MelWeightMatrix, DFT, STFT,
+};
 pub use slice::{slice, slice_in_place, Slice};
 pub use split::{split, Split};
 pub use trilu::{trilu, Trilu};
 pub use unary_elementwise::{
-    abs, abs_in_place, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, ceil,
-    ceil_in_place, clip, clip_in_place, cos, cos_in_place, elu, elu_in_place, erf, erf_in_place,
-    exp, exp_in_place, floor, floor_in_place, gelu, gelu_in_place, hard_sigmoid,
-    hard_sigmoid_in_place, hard_swish, hard_swish_in_place, leaky_relu, leaky_relu_in_place, log,
-    log_in_place, neg, neg_in_place, not, not_in_place, reciprocal, reciprocal_in_place, relu,
-    relu_in_place, round, round_in_place, sigmoid, sigmoid_in_place, sign, sign_in_place, silu,
-    silu_in_place, sin, sin_in_place, softplus, softplus_in_place, sqrt, sqrt_in_place, tan,
-    tan_in_place, tanh, tanh_in_place, Abs, Acos, Asin, Atan, Ceil, Clip, Cos, Elu, Erf, Exp,
-    Floor, Gelu, HardSigmoid, HardSwish, LeakyRelu, Log, Neg, Not, Reciprocal, Relu, Round,
-    Sigmoid, Sign, Silu, Sin, Softplus, Sqrt, Tan, Tanh,
+    abs, abs_in_place, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, bitwise_not,
+    bitwise_not_in_place, ceil, ceil_in_place, celu, celu_in_place, clip, clip_in_place, cos,
+    cos_in_place, elu, elu_in_place, erf, erf_in_place, exp, exp_in_place, floor, floor_in_place,
+    gelu, gelu_in_place, hard_sigmoid, hard_sigmoid_in_place, hard_swish, hard_swish_in_place,
+    is_inf, is_nan, leaky_relu, leaky_relu_in_place, log, log_in_place, mish, mish_in_place, neg,
+    neg_in_place, not, not_in_place, reciprocal, reciprocal_in_place, relu, relu_in_place, round,
+    round_in_place, selu, selu_in_place, shrink, shrink_in_place, sigmoid, sigmoid_in_place, sign,
+    sign_in_place, silu, silu_in_place, sin, sin_in_place, softplus, softplus_in_place, softsign,
+    softsign_in_place, sqrt, sqrt_in_place, tan, tan_in_place, tanh, tanh_in_place,
+    thresholded_relu, thresholded_relu_in_place, Abs, Acos, Asin, Atan, BitwiseNot, Ceil, Celu,
+    Clip, Cos, Elu, Erf, Exp, Floor, Gelu, HardSigmoid, HardSwish, IsInf, IsNaN, LeakyRelu, Log,
+    Mish, Neg, Not, Reciprocal, Relu, Round, Selu, Shrink, Sigmoid, Sign, Silu, Sin, Softplus,
+    Softsign, Sqrt, Tan, Tanh, ThresholdedRelu, UnaryFloatOp,
 };
 pub use variadic_elementwise::{max, mean, min, sum, Max, Mean, Min, Sum};
 
@@ -162,7 +191,7 @@ impl<S: AsRef<[usize]>> From<S> for Padding {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum DataType {
     Int32,
     Float,
@@ -857,6 +886,35 @@ pub trait Operator: Any + Debug {
         true
     }
 
+    /// Return true if `other` is an operator of the same type with the same
+    /// attributes, so that it produces the same outputs given the same inputs.
+    ///
+    /// This is used by the graph optimizer to merge duplicate operators. The
+    /// default implementation returns false, so operators are only merged if
+    /// they implement this, eg. by downcasting `other` and comparing
+    /// attributes.
+    fn is_equivalent(&self, _other: &dyn Operator) -> bool {
+        false
+    }
+
+    /// Execute a non-deterministic operator using a seed for its random
+    /// number generator.
+    ///
+    /// This is called instead of [`run`](Operator::run) for operators which
+    /// are not [deterministic](Operator::is_deterministic) when a seed is
+    /// specified via [`RunOptions::seed`](crate::RunOptions::seed). The seed
+    /// takes precedence over any seed specified by the operator's attributes.
+    ///
+    /// The default implementation ignores the seed.
+    fn run_with_seed(
+        &self,
+        pool: &TensorPool,
+        input: InputList,
+        _seed: u64,
+    ) -> Result<OutputList, OpError> {
+        self.run(pool, input)
+    }
+
     /// Execute this operator in-place on an existing tensor.
     ///
     /// This may only be called if `can_run_in_place` returns true.
@@ -877,10 +935,112 @@ pub trait Operator: Any + Debug {
     ) -> Result<Output, OpError> {
         unimplemented!("in-place execution not supported")
     }
+
+    /// Infer the shapes of this operator's outputs from information about
+    /// its inputs that is available before the graph is run.
+    ///
+    /// This is used to fold shape computations into constants when a model is
+    /// specialized for particular input shapes. Implementations should return
+    /// `None` if the output shapes cannot be determined, eg. because they
+    /// depend on the values of non-constant inputs, or if the inputs are
+    /// invalid.
+    ///
+    /// The default implementation returns `None`.
+    fn infer_shapes(&self, _inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        None
+    }
+
+    /// Return this operator as a [`UnaryFloatOp`], if it applies a function
+    /// independently to each element of a single float input.
+    ///
+    /// This allows the graph optimizer to fuse the operator with adjacent
+    /// elementwise operations.
+    fn as_unary_float_op(&self) -> Option<&dyn UnaryFloatOp> {
+        None
+    }
+
+    /// Return the indices of inputs which this operator can use in a
+    /// prepacked form. See [`prepack`](Operator::prepack).
+    ///
+    /// The default implementation returns an empty list.
+    fn prepack_inputs(&self) -> &[usize] {
+        &[]
+    }
+
+    /// Prepare a constant input for use in all subsequent runs of this
+    /// operator, eg. by packing a weight matrix into the layout used by the
+    /// matrix multiplication kernel.
+    ///
+    /// `index` is one of the indices returned by
+    /// [`prepack_inputs`](Operator::prepack_inputs). This is called when a
+    /// model is loaded, if enabled via
+    /// [`ModelOptions::prepack_weights`](crate::ModelOptions::prepack_weights).
+    /// The result is passed to [`run`](Operator::run) alongside the original
+    /// input and can be retrieved using [`InputList::get_prepacked`].
+    /// Returns `None` if the input cannot be prepacked, eg. because it has an
+    /// unsupported shape.
+    ///
+    /// The default implementation returns `None`.
+    fn prepack(&self, _index: usize, _input: Input) -> Option<PrepackedInput> {
+        None
+    }
 }
 
 impl_downcastdyn!(Operator);
 
+/// Return true if `other` has the same type as `op` and equal attributes.
+///
+/// This implements [`Operator::is_equivalent`] for operators whose attributes
+/// fully determine their behavior.
+pub(crate) fn is_same_op<Op: Operator + PartialEq>(op: &Op, other: &dyn Operator) -> bool {
+    other.downcast_ref::<Op>() == Some(op)
+}
+
+/// Operator input which has been prepared ahead of time by
+/// [`Operator::prepack`].
+pub struct PrepackedInput {
+    matrices: PackedMatrices,
+}
+
+/// Sequence of matrices packed for use as one of the inputs to a GEMM.
+///
+/// Weights such as those of a grouped convolution or a bidirectional RNN
+/// consist of multiple matrices, which are packed separately.
+enum PackedMatrices {
+    A(Vec<PackedAMatrix>),
+    B(Vec<PackedBMatrix>),
+}
+
+impl PrepackedInput {
+    pub(crate) fn from_packed_a(matrices: Vec<PackedAMatrix>) -> PrepackedInput {
+        PrepackedInput {
+            matrices: PackedMatrices::A(matrices),
+        }
+    }
+
+    pub(crate) fn from_packed_b(matrices: Vec<PackedBMatrix>) -> PrepackedInput {
+        PrepackedInput {
+            matrices: PackedMatrices::B(matrices),
+        }
+    }
+
+    /// Return the matrices if they were packed as "A" GEMM inputs.
+    pub(crate) fn packed_a(&self) -> Option<&[PackedAMatrix]> {
+        match &self.matrices {
+            PackedMatrices::A(matrices) => Some(matrices),
+            PackedMatrices::B(_) => None,
+        }
+    }
+
+    /// Return the matrices if they were packed as "B" GEMM inputs.
+    pub(crate) fn packed_b(&self) -> Option<&[PackedBMatrix]> {
+        match &self.matrices {
+            PackedMatrices::A(_) => None,
+            PackedMatrices::B(matrices) => Some(matrices),
+        }
+    }
+}
+
 /// List of inputs for an operator evaluation.
 ///
 /// Conceptually this is a `Cow<[Option<Input>]>` with methods to conveniently
@@ -891,6 +1051,9 @@ impl_downcastdyn!(Operator);
 /// references using `into`.
 pub struct InputList<'a> {
     inputs: Cow<'a, [Option<Input<'a>>]>,
+
+    /// Prepacked versions of constant inputs, indexed by input position.
+    prepacked: &'a [Option<PrepackedInput>],
 }
 
 impl<'a> InputList<'a> {
@@ -898,6 +1061,7 @@ impl<'a> InputList<'a> {
     pub fn new() -> InputList<'a> {
         InputList {
             inputs: Cow::Owned(vec![]),
+            prepacked: &[],
         }
     }
 
@@ -923,6 +1087,7 @@ impl<'a> InputList<'a> {
     pub fn from(inputs: &[Input<'a>]) -> InputList<'a> {
         InputList {
             inputs: inputs.iter().cloned().map(Some).collect(),
+            prepacked: &[],
         }
     }
 
@@ -932,14 +1097,27 @@ impl<'a> InputList<'a> {
     pub fn from_optional(inputs: &'a [Option<Input<'a>>]) -> InputList<'a> {
         InputList {
             inputs: Cow::Borrowed(inputs),
+            prepacked: &[],
         }
     }
 
+    /// Attach prepacked versions of inputs to this list. See
+    /// [`Operator::prepack`].
+    pub fn with_prepacked(mut self, prepacked: &'a [Option<PrepackedInput>]) -> InputList<'a> {
+        self.prepacked = prepacked;
+        self
+    }
+
     /// Get an optional input.
     pub fn get(&self, index: usize) -> Option<Input<'a>> {
         self.inputs.get(index).cloned().flatten()
     }
 
+    /// Get the prepacked version of an input, if available.
+    pub fn get_prepacked(&self, index: usize) -> Option<&'a PrepackedInput> {
+        self.prepacked.get(index).and_then(|p| p.as_ref())
+    }
+
     /// Get a mutable reference to an input.
     ///
     /// This will convert the list into an owned list of inputs first.
@@ -1026,7 +1204,96 @@ impl<'a, I1: Into<Input<'a>>, I2: Into<Input<'a>>, I3: Into<Input<'a>>> From<(I1
     }
 }
 
-#[derive(Debug)]
+/// Information about an operator input that is available before the graph
+/// is run.
+#[derive(Clone)]
+pub enum InputInfo<'a> {
+    /// An input whose value is known, such as a model weight.
+    Constant(Input<'a>),
+
+    /// An input whose shape is known, but whose value is computed at runtime.
+    Shape(&'a [usize]),
+}
+
+impl<'a> InputInfo<'a> {
+    /// Return the shape of the input.
+    pub fn shape(&self) -> &[usize] {
+        match self {
+            InputInfo::Constant(input) => input.shape(),
+            InputInfo::Shape(shape) => shape,
+        }
+    }
+}
+
+/// List of inputs for [`Operator::infer_shapes`].
+///
+/// Each entry is `None` if the corresponding optional input was omitted.
+pub struct ShapeInputs<'a> {
+    inputs: &'a [Option<InputInfo<'a>>],
+}
+
+impl<'a> ShapeInputs<'a> {
+    pub fn new(inputs: &'a [Option<InputInfo<'a>>]) -> ShapeInputs<'a> {
+        ShapeInputs { inputs }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// Get the shape of an optional input.
+    pub fn shape(&self, index: usize) -> Option<&'a [usize]> {
+        let info: &'a InputInfo<'a> = self.inputs.get(index)?.as_ref()?;
+        Some(info.shape())
+    }
+
+    /// Iterate over the shapes of the inputs that are present.
+    pub fn shapes(&self) -> impl Iterator<Item = &'a [usize]> {
+        self.inputs.iter().flatten().map(|info| info.shape())
+    }
+
+    /// Get the value of an optional input if it is a constant.
+    pub fn constant_as<T>(&self, index: usize) -> Option<TensorView<'a, T>>
+    where
+        TensorView<'a, T>: TryFrom<Input<'a>, Error = OpError>,
+    {
+        match self.inputs.get(index)? {
+            Some(InputInfo::Constant(input)) => input.clone().try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value of an optional input if it is a constant vector.
+    pub fn constant_vec_as<T>(&self, index: usize) -> Option<NdTensorView<'a, T, 1>>
+    where
+        TensorView<'a, T>: TryFrom<Input<'a>, Error = OpError>,
+    {
+        self.constant_as(index)?.try_into().ok()
+    }
+}
+
+/// Shape inference for operators whose output has the same shape as the
+/// first input.
+pub(crate) fn infer_unary_shape(inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+    Some(vec![inputs.shape(0)?.to_vec()])
+}
+
+/// Shape inference for operators which broadcast their inputs against each
+/// other.
+pub(crate) fn infer_broadcast_shape(inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+    let mut shapes = inputs.shapes();
+    let first = shapes.next()?.to_vec();
+    let shape = shapes.try_fold(first, |shape, next| {
+        binary_elementwise::broadcast_shapes(&shape, next).map(|s| s.to_vec())
+    })?;
+    Some(vec![shape])
+}
+
+#[derive(Debug, PartialEq)]
 pub enum Scalar {
     Int(i32),
     Float(f32),
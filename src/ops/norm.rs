@@ -2,12 +2,15 @@ use rayon::prelude::*;
 
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, Tensor, TensorView};
-use rten_vecmath::vec_softmax_in_place;
+use rten_vecmath::{vec_rms_norm_in_place, vec_softmax_in_place};
 use smallvec::SmallVec;
 
 use crate::ops::reduce::reduce_inverse_rms;
 use crate::ops::{add_in_place, mul_in_place, reduce_mean, sub};
-use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, Output, OutputList};
+use crate::ops::{
+    infer_unary_shape, resolve_axis, InputList, IntoOpResult, OpError, Operator, Output,
+    OutputList, ShapeInputs,
+};
 use crate::slice_reductions::{slice_max, slice_sum};
 use crate::static_dims;
 use crate::tensor_pool::{AutoReturn, TensorPool};
@@ -98,6 +101,10 @@ impl Operator for BatchNormalization {
         batch_norm(pool, input, &scale, &bias, &mean, &var, self.epsilon).into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -218,6 +225,10 @@ impl Operator for InstanceNormalization {
         instance_normalization(pool, input, scale, bias, self.epsilon).into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -317,6 +328,86 @@ impl Operator for LayerNormalization {
         layer_normalization(pool, input.view(), scale, bias, self.axis, self.epsilon)
             .into_op_result()
     }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+}
+
+/// Grain size for parallelizing RMS normalization.
+const RMS_NORM_GRAIN_SIZE: usize = 4096;
+
+/// Apply RMS normalization to the input.
+///
+/// Elements are divided by the root-mean-square over the dimensions from
+/// `axis` onwards and then multiplied by `scale`, which must be broadcastable
+/// to the shape of those dimensions.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__RMSNormalization.html>.
+pub fn rms_normalization(
+    pool: &TensorPool,
+    input: TensorView,
+    scale: TensorView,
+    axis: isize,
+    epsilon: Option<f32>,
+) -> Result<Tensor, OpError> {
+    let epsilon = epsilon.unwrap_or(1e-5);
+    let resolved_axis = resolve_axis(input.ndim(), axis)?;
+    let normalized_shape = &input.shape()[resolved_axis..];
+    if !scale.can_broadcast_to(normalized_shape) {
+        return Err(OpError::IncompatibleInputShapes(
+            "`scale` cannot be broadcast to normalized shape",
+        ));
+    }
+
+    let scale = scale
+        .broadcast(normalized_shape)
+        .to_contiguous_in(pool)
+        .auto_return(pool);
+    let scale = scale.data().unwrap();
+
+    let mut output = input.to_tensor_in(pool);
+    let lane_size = scale.len();
+    if lane_size == 0 {
+        return Ok(output);
+    }
+
+    let n_lanes_per_grain = RMS_NORM_GRAIN_SIZE.div_ceil(lane_size);
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(lane_size)
+        .with_min_len(n_lanes_per_grain)
+        .for_each(|lane| vec_rms_norm_in_place(lane, scale, epsilon));
+
+    Ok(output)
+}
+
+/// RMS normalization, as used by Llama and related models.
+///
+/// This also implements ONNX Runtime's `SimplifiedLayerNormalization`
+/// operator, which has the same semantics.
+#[derive(Debug)]
+pub struct RMSNormalization {
+    pub axis: isize,
+    pub epsilon: Option<f32>,
+}
+
+impl Operator for RMSNormalization {
+    fn name(&self) -> &str {
+        "RMSNormalization"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let scale = inputs.require_as(1)?;
+
+        rms_normalization(pool, input, scale, self.axis, self.epsilon).into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
 }
 
 pub fn log_softmax(pool: &TensorPool, input: TensorView, axis: isize) -> Result<Tensor, OpError> {
@@ -378,6 +469,98 @@ fn softmax_lanes<F: Fn(&mut [f32]) + Send + Sync>(
     Ok(())
 }
 
+/// Apply a softmax-like operation in-place, optionally using the semantics of
+/// ONNX opset < 13.
+///
+/// In older opsets, the input is coerced to a matrix of shape
+/// `[prod(shape[..axis]), prod(shape[axis..])]` and the operation is applied
+/// along the second dimension.
+fn apply_softmax_op(
+    output: &mut Tensor,
+    axis: isize,
+    flatten: bool,
+    op: fn(&mut Tensor, isize) -> Result<(), OpError>,
+) -> Result<(), OpError> {
+    if !flatten {
+        return op(output, axis);
+    }
+
+    let axis = resolve_axis(output.ndim(), axis)?;
+    let shape = output.shape().to_vec();
+    let rows = shape[..axis].iter().product();
+    let cols = shape[axis..].iter().product();
+
+    output.reshape(&[rows, cols]);
+    op(output, 1)?;
+    output.reshape(&shape);
+
+    Ok(())
+}
+
+pub fn hardmax(pool: &TensorPool, input: TensorView, axis: isize) -> Result<Tensor, OpError> {
+    let mut output = input.to_tensor_in(pool);
+    hardmax_in_place(&mut output, axis)?;
+    Ok(output)
+}
+
+/// Replace each lane along `axis` with a one-hot vector which is 1 at the
+/// position of the first maximum value in the lane.
+pub fn hardmax_in_place(output: &mut Tensor, axis: isize) -> Result<(), OpError> {
+    softmax_lanes(output, axis, |lane| {
+        let mut max_idx = 0;
+        for (i, &x) in lane.iter().enumerate() {
+            if x > lane[max_idx] {
+                max_idx = i;
+            }
+        }
+        lane.fill(0.);
+        if let Some(max) = lane.get_mut(max_idx) {
+            *max = 1.;
+        }
+    })
+}
+
+#[derive(Debug)]
+pub struct Hardmax {
+    pub axis: isize,
+
+    /// Coerce the input to 2D before applying the operation, as in ONNX
+    /// opset < 13.
+    pub flatten: bool,
+}
+
+impl Operator for Hardmax {
+    fn name(&self) -> &str {
+        "Hardmax"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input: TensorView = inputs.require_as(0)?;
+        let mut output = input.to_tensor_in(pool);
+        apply_softmax_op(&mut output, self.axis, self.flatten, hardmax_in_place)?;
+        output.into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        _pool: &TensorPool,
+        input: Output,
+        _other: InputList,
+    ) -> Result<Output, OpError> {
+        let mut output = input.into_float().ok_or(OpError::IncorrectInputType)?;
+        apply_softmax_op(&mut output, self.axis, self.flatten, hardmax_in_place)?;
+        Ok(output.into())
+    }
+}
+
 pub fn log_softmax_in_place(output: &mut Tensor, axis: isize) -> Result<(), OpError> {
     softmax_lanes(output, axis, |lane| {
         // This operator computes:
@@ -408,6 +591,10 @@ pub fn log_softmax_in_place(output: &mut Tensor, axis: isize) -> Result<(), OpEr
 #[derive(Debug)]
 pub struct LogSoftmax {
     pub axis: isize,
+
+    /// Coerce the input to 2D before applying the operation, as in ONNX
+    /// opset < 13.
+    pub flatten: bool,
 }
 
 impl Operator for LogSoftmax {
@@ -416,8 +603,14 @@ impl Operator for LogSoftmax {
     }
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
-        let input = inputs.require_as(0)?;
-        log_softmax(pool, input.view(), self.axis).into_op_result()
+        let input: TensorView = inputs.require_as(0)?;
+        let mut output = input.to_tensor_in(pool);
+        apply_softmax_op(&mut output, self.axis, self.flatten, log_softmax_in_place)?;
+        output.into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -431,7 +624,7 @@ impl Operator for LogSoftmax {
         _other: InputList,
     ) -> Result<Output, OpError> {
         let mut output = input.into_float().ok_or(OpError::IncorrectInputType)?;
-        log_softmax_in_place(&mut output, self.axis)?;
+        apply_softmax_op(&mut output, self.axis, self.flatten, log_softmax_in_place)?;
         Ok(output.into())
     }
 }
@@ -450,6 +643,10 @@ pub fn softmax_in_place(output: &mut Tensor, axis: isize) -> Result<(), OpError>
 #[derive(Debug)]
 pub struct Softmax {
     pub axis: isize,
+
+    /// Coerce the input to 2D before applying the operation, as in ONNX
+    /// opset < 13.
+    pub flatten: bool,
 }
 
 impl Operator for Softmax {
@@ -458,8 +655,14 @@ impl Operator for Softmax {
     }
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
-        let input = inputs.require_as(0)?;
-        softmax(pool, input.view(), self.axis).into_op_result()
+        let input: TensorView = inputs.require_as(0)?;
+        let mut output = input.to_tensor_in(pool);
+        apply_softmax_op(&mut output, self.axis, self.flatten, softmax_in_place)?;
+        output.into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -473,7 +676,7 @@ impl Operator for Softmax {
         _other: InputList,
     ) -> Result<Output, OpError> {
         let mut output = input.into_float().ok_or(OpError::IncorrectInputType)?;
-        softmax_in_place(&mut output, self.axis)?;
+        apply_softmax_op(&mut output, self.axis, self.flatten, softmax_in_place)?;
         Ok(output.into())
     }
 }
@@ -485,15 +688,16 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::Tensor;
+    use rten_tensor::{Tensor, TensorView};
 
     use super::SOFTMAX_GRAIN_SIZE;
     use crate::ops::tests::{expect_eq_1e4, new_pool};
     use crate::ops::OpError;
     use crate::ops::{
-        batch_norm, batch_norm_in_place, instance_normalization, layer_normalization, log_softmax,
-        softmax,
+        batch_norm, batch_norm_in_place, hardmax, instance_normalization, layer_normalization,
+        log_softmax, rms_normalization, softmax, Hardmax, InputList, LogSoftmax, Operator, Softmax,
     };
+    use crate::tensor_pool::TensorPool;
 
     #[test]
     fn test_batch_norm() -> Result<(), Box<dyn Error>> {
@@ -668,6 +872,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_hardmax() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from([[1., 3., 2.], [4., 4., 0.]]);
+
+        let result = hardmax(&pool, input.view(), -1)?;
+        let expected = Tensor::from([[0., 1., 0.], [1., 0., 0.]]);
+        expect_equal(&result, &expected)?;
+
+        let result = hardmax(&pool, input.view(), 0)?;
+        let expected = Tensor::from([[0., 0., 1.], [1., 1., 0.]]);
+        expect_equal(&result, &expected)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_log_softmax() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -704,6 +924,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_rms_normalization() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Reference implementation using separate reduction and elementwise
+        // ops, as in the decomposed form exported by PyTorch.
+        let reference_rms_norm = |input: &Tensor, scale: &Tensor, epsilon: f32| {
+            let lane_size = scale.len();
+            let mut output = input.clone();
+            for lane in output.data_mut().unwrap().chunks_mut(lane_size) {
+                let mean_square = lane.iter().map(|x| x * x).sum::<f32>() / lane_size as f32;
+                let inv_rms = 1. / (mean_square + epsilon).sqrt();
+                for (x, s) in lane.iter_mut().zip(scale.iter()) {
+                    *x *= inv_rms * s;
+                }
+            }
+            output
+        };
+
+        for (shape, axis) in [([2, 3, 8], -1), ([4, 5, 33], 2), ([2, 300, 20], 1)] {
+            let input = Tensor::rand(&shape, &mut rng);
+            let resolved_axis = if axis < 0 { 3 + axis } else { axis } as usize;
+            let scale = Tensor::rand(&shape[resolved_axis..], &mut rng);
+            let result = rms_normalization(
+                &pool,
+                input.view(),
+                scale.view(),
+                axis,
+                Some(1e-6), /* epsilon */
+            )?;
+            let expected = reference_rms_norm(&input, &scale, 1e-6);
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        // Scale which is broadcast to the normalized shape.
+        let input = Tensor::rand(&[2, 3, 4], &mut rng);
+        let scale = Tensor::from([2.]);
+        let result = rms_normalization(&pool, input.view(), scale.view(), -1, None)?;
+        let expected = reference_rms_norm(&input, &Tensor::full(&[4], 2.), 1e-5);
+        expect_eq_1e4(&result, &expected)?;
+
+        // Invalid scale shape.
+        let scale = Tensor::from([1., 2., 3.]);
+        let result = rms_normalization(&pool, input.view(), scale.view(), -1, None);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "`scale` cannot be broadcast to normalized shape"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_softmax() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -768,6 +1043,62 @@ mod tests {
         Ok(())
     }
 
+    // Test the opset < 13 semantics of softmax-like operators, where the input
+    // is coerced to 2D.
+    #[test]
+    fn test_softmax_ops_flatten() -> Result<(), Box<dyn Error>> {
+        type RefOp = fn(&TensorPool, TensorView, isize) -> Result<Tensor, OpError>;
+
+        let pool = new_pool();
+        let input = Tensor::from([[[1., 2.], [3., 4.]], [[4., 3.], [2., 0.]]]);
+        let ops: [(Box<dyn Operator>, RefOp); 3] = [
+            (
+                Box::new(Softmax {
+                    axis: 1,
+                    flatten: true,
+                }),
+                softmax,
+            ),
+            (
+                Box::new(LogSoftmax {
+                    axis: 1,
+                    flatten: true,
+                }),
+                log_softmax,
+            ),
+            (
+                Box::new(Hardmax {
+                    axis: 1,
+                    flatten: true,
+                }),
+                hardmax,
+            ),
+        ];
+
+        for (op, reference) in ops {
+            let result = op
+                .run(&pool, InputList::from(&[input.view().into()]))?
+                .remove(0)
+                .into_float()
+                .unwrap();
+
+            // The result should match applying the operation to the input
+            // reshaped to `[2, 4]`.
+            let mut expected = reference(&pool, input.reshaped([2, 4]).as_dyn(), 1)?;
+            expected.reshape(input.shape());
+            expect_eq_1e4(&result, &expected)?;
+
+            // In-place execution should produce the same result.
+            let result = op
+                .run_in_place(&pool, input.clone().into(), InputList::new())?
+                .into_float()
+                .unwrap();
+            expect_eq_1e4(&result, &expected)?;
+        }
+
+        Ok(())
+    }
+
     // Test softmax with some additional input sizes and axis dimensions.
     // These tests don't check the individual output values in detail, but they
     // do check the shape and that each lane sums to 1.
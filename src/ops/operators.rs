@@ -115,7 +115,15 @@ impl<T: Send, S: Storage<Elem = T>, L: MutLayout> Operators for TensorBase<S, L>
         T: Copy + PartialOrd,
     {
         let view = self.as_dyn();
-        use_thread_pool(|| arg_max(&TensorPool::new(), view, axis, keep_dims))
+        use_thread_pool(|| {
+            arg_max(
+                &TensorPool::new(),
+                view,
+                axis,
+                keep_dims,
+                false, /* select_last_index */
+            )
+        })
     }
 
     fn div(&self, other: TensorView<Self::Elem>) -> Result<Tensor<Self::Elem>, OpError>
@@ -1,7 +1,7 @@
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, SliceItem, Tensor, TensorView};
 
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::ops::{resolve_axes, Input, InputList, IntoOpResult, OpError, Operator, OutputList};
 use crate::static_dims;
 use crate::tensor_pool::TensorPool;
 
@@ -82,6 +82,86 @@ impl Operator for Pad {
     }
 }
 
+/// Center crop or pad `input` so that the dimensions listed in `axes` have the
+/// sizes given by `shape`.
+///
+/// If `axes` is `None`, `shape` specifies the sizes of all dimensions. When
+/// the difference between the input and output size of a dimension is odd,
+/// the extra element is cropped or padded at the end. Padded elements are
+/// set to zero.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__CenterCropPad.html>.
+pub fn center_crop_pad<T: Copy + Default>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    shape: &NdTensorView<i32, 1>,
+    axes: Option<&[i32]>,
+) -> Result<Tensor<T>, OpError> {
+    let axes: Vec<usize> = match axes {
+        Some(axes) => resolve_axes(input.ndim(), axes.iter())?
+            .into_iter()
+            .collect(),
+        None => (0..input.ndim()).collect(),
+    };
+    if shape.size(0) != axes.len() {
+        return Err(OpError::InvalidValue(
+            "`shape` length does not match number of axes",
+        ));
+    }
+
+    let mut out_shape = input.shape().to_vec();
+    let mut in_region: Vec<SliceItem> =
+        input.shape().iter().map(|&size| (0..size).into()).collect();
+    let mut out_region = in_region.clone();
+
+    for (&axis, &size) in axes.iter().zip(shape.iter()) {
+        if size < 0 {
+            return Err(OpError::InvalidValue("`shape` entries must be >= 0"));
+        }
+        let (in_size, out_size) = (input.size(axis), size as usize);
+        out_shape[axis] = out_size;
+
+        if out_size < in_size {
+            let start = (in_size - out_size) / 2;
+            in_region[axis] = (start..start + out_size).into();
+            out_region[axis] = (0..out_size).into();
+        } else {
+            let start = (out_size - in_size) / 2;
+            out_region[axis] = (start..start + in_size).into();
+        }
+    }
+
+    let mut output = Tensor::full_in(pool, &out_shape, T::default());
+    output
+        .slice_mut_dyn(out_region.as_slice())
+        .copy_from(&input.slice_dyn(in_region.as_slice()));
+
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct CenterCropPad {
+    pub axes: Option<Vec<i32>>,
+}
+
+impl Operator for CenterCropPad {
+    fn name(&self) -> &str {
+        "CenterCropPad"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+        let shape = inputs.require_as::<i32>(1)?;
+        let shape = static_dims!(shape, 1)?;
+        let axes = self.axes.as_deref();
+
+        match input {
+            Input::IntTensor(t) => center_crop_pad(pool, t, &shape, axes).into_op_result(),
+            Input::FloatTensor(t) => center_crop_pad(pool, t, &shape, axes).into_op_result(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -91,12 +171,46 @@ mod tests {
     use rten_tensor::Tensor;
 
     use crate::ops::tests::new_pool;
-    use crate::ops::{pad, OpError, Operator, Pad};
+    use crate::ops::{center_crop_pad, pad, OpError, Operator, Pad};
 
     fn from_slice<T: Clone>(data: &[T]) -> Tensor<T> {
         Tensor::from_data(&[data.len()], data.to_vec())
     }
 
+    #[test]
+    fn test_center_crop_pad() {
+        let pool = new_pool();
+        let input = Tensor::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+
+        // Crop first dim and pad second dim.
+        let shape = Tensor::from([2, 6]);
+        let result = center_crop_pad(&pool, input.view(), &shape.nd_view(), None).unwrap();
+        assert_eq!(
+            result,
+            Tensor::from([[0, 1, 2, 3, 4, 0], [0, 5, 6, 7, 8, 0]])
+        );
+
+        // Crop only selected axes.
+        let shape = Tensor::from([2]);
+        let result = center_crop_pad(&pool, input.view(), &shape.nd_view(), Some(&[-1])).unwrap();
+        assert_eq!(result, Tensor::from([[2, 3], [6, 7], [10, 11]]));
+
+        // Odd size differences crop or pad more at the end.
+        let shape = Tensor::from([5, 1]);
+        let result = center_crop_pad(&pool, input.view(), &shape.nd_view(), None).unwrap();
+        assert_eq!(result, Tensor::from([[0], [2], [6], [10], [0]]));
+
+        // Mismatched shape and axes
+        let shape = Tensor::from([2, 2]);
+        let result = center_crop_pad(&pool, input.view(), &shape.nd_view(), Some(&[0]));
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "`shape` length does not match number of axes"
+            ))
+        );
+    }
+
     #[test]
     fn test_pad() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -4,10 +4,11 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 
 use rayon::prelude::*;
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView, TensorViewMut};
+use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
+use smallvec::{smallvec, SmallVec};
 
 use crate::ops::{InputList, IntoOpResult, OpError, Operator, OutputList, Padding};
-use crate::tensor_pool::TensorPool;
+use crate::tensor_pool::{AutoReturn, TensorPool};
 use crate::{check_dims, static_dims};
 
 /// Calculate the output size and padding for a convolution or pooling operation.
@@ -88,177 +89,524 @@ pub fn calc_output_size_and_padding(
     Ok((out_h, out_w, padding))
 }
 
+/// Calculate the output size and padding for a 3D convolution or pooling
+/// operation.
+///
+/// This is the same as [`calc_output_size_and_padding`] but for inputs with
+/// three spatial dimensions. Fixed padding is specified as `[front, top, left,
+/// back, bottom, right]`.
+///
+/// Returns an `([out_d, out_h, out_w], padding)` tuple, where `padding` uses
+/// the same order as fixed input padding.
+pub fn calc_output_size_and_padding_3d(
+    in_size: [usize; 3],
+    kernel_size: [usize; 3],
+    strides: [usize; 3],
+    padding: Padding,
+    dilations: Option<[usize; 3]>,
+) -> Result<([usize; 3], [usize; 6]), OpError> {
+    let [in_d, in_h, in_w] = in_size;
+    let [k_d, k_h, k_w] = kernel_size;
+    let [stride_d, stride_h, stride_w] = strides;
+    let [dilation_d, dilation_h, dilation_w] = dilations.unwrap_or([1, 1, 1]);
+
+    // Output size and padding are computed independently for each axis, so
+    // handle the depth and height axes together, and then the width axis
+    // paired with a unit-sized dummy axis.
+    let (dh_padding, w_padding) = match padding {
+        Padding::Same => (Padding::Same, Padding::Same),
+        Padding::Fixed(pads) => {
+            let [pad_front, pad_top, pad_left, pad_back, pad_bottom, pad_right]: [usize; 6] = pads
+                .as_slice()
+                .try_into()
+                .map_err(|_| OpError::InvalidValue("Expected 6 padding values"))?;
+            (
+                Padding::Fixed([pad_front, pad_top, pad_back, pad_bottom].into()),
+                Padding::Fixed([0, pad_left, 0, pad_right].into()),
+            )
+        }
+    };
+
+    let (out_d, out_h, [pad_front, pad_top, pad_back, pad_bottom]) = calc_output_size_and_padding(
+        (in_d, in_h),
+        (k_d, k_h),
+        (stride_d, stride_h),
+        dh_padding,
+        Some((dilation_d, dilation_h)),
+    )?;
+    let (_, out_w, [_, pad_left, _, pad_right]) = calc_output_size_and_padding(
+        (1, in_w),
+        (1, k_w),
+        (1, stride_w),
+        w_padding,
+        Some((1, dilation_w)),
+    )?;
+
+    Ok((
+        [out_d, out_h, out_w],
+        [
+            pad_front, pad_top, pad_left, pad_back, pad_bottom, pad_right,
+        ],
+    ))
+}
+
 /// Number of channels processed together by the pooling kernel.
 const CHAN_GROUP_SIZE: usize = 4;
 
+/// Sizes of the input, output and pooling window along one spatial axis.
+#[derive(Copy, Clone, Debug)]
+struct PoolAxis {
+    in_size: usize,
+    out_size: usize,
+    kernel_size: usize,
+    stride: usize,
+    dilation: usize,
+    pad_start: usize,
+    pad_end: usize,
+}
+
+impl PoolAxis {
+    /// Axis used in place of missing leading axes for inputs with fewer than
+    /// 3 spatial dimensions.
+    const UNIT: PoolAxis = PoolAxis {
+        in_size: 1,
+        out_size: 1,
+        kernel_size: 1,
+        stride: 1,
+        dilation: 1,
+        pad_start: 0,
+        pad_end: 0,
+    };
+
+    /// Return the input coordinate for an output coordinate and kernel
+    /// offset, relative to the start of the padded input.
+    fn padded_coord(&self, out_coord: usize, k: usize) -> usize {
+        out_coord * self.stride + k * self.dilation
+    }
+
+    /// Return true if a coordinate in the padded input is inside the input.
+    fn in_input(&self, coord: usize) -> bool {
+        coord >= self.pad_start && coord < self.in_size + self.pad_start
+    }
+
+    /// Return true if a coordinate in the padded input is inside the padded
+    /// input. This can be false when using `ceil_mode`.
+    fn in_padded_input(&self, coord: usize) -> bool {
+        coord < self.in_size + self.pad_start + self.pad_end
+    }
+}
+
+/// Compute sizes of the input, output and pooling window for each spatial
+/// axis of an input with spatial shape `in_shape`.
+///
+/// `in_shape` may have 1 to 3 dimensions. The result always has 3 axes, with
+/// unit axes inserted at the start for inputs with fewer spatial dimensions.
+///
+/// If `ceil_mode` is true, output sizes are rounded up rather than down, so
+/// the last window along an axis may extend past the end of the padded input.
+fn pool_axes(
+    in_shape: &[usize],
+    kernel_size: &[usize],
+    strides: &[usize],
+    dilations: &[usize],
+    padding: Padding,
+    ceil_mode: bool,
+) -> Result<[PoolAxis; 3], OpError> {
+    let ndim = in_shape.len();
+    if !(1..=3).contains(&ndim) {
+        return Err(OpError::UnsupportedValue(
+            "Pooling supports inputs with 1 to 3 spatial dims",
+        ));
+    }
+    if kernel_size.len() != ndim {
+        return Err(OpError::InvalidValue(
+            "Kernel size does not match input spatial dims",
+        ));
+    }
+    if strides.len() != ndim {
+        return Err(OpError::InvalidValue(
+            "Strides do not match input spatial dims",
+        ));
+    }
+    if dilations.len() != ndim {
+        return Err(OpError::InvalidValue(
+            "Dilations do not match input spatial dims",
+        ));
+    }
+    let pads = match &padding {
+        Padding::Same => None,
+        Padding::Fixed(pads) if pads.len() == ndim * 2 => Some(pads),
+        Padding::Fixed(_) => {
+            return Err(OpError::InvalidValue(
+                "Padding does not match input spatial dims",
+            ));
+        }
+    };
+
+    let mut axes = [PoolAxis::UNIT; 3];
+    for i in 0..ndim {
+        let axis_padding = match pads {
+            Some(pads) => Padding::Fixed([0, pads[i], 0, pads[ndim + i]].into()),
+            None => Padding::Same,
+        };
+        let (_, mut out_size, [_, pad_start, _, pad_end]) = calc_output_size_and_padding(
+            (1, in_shape[i]),
+            (1, kernel_size[i]),
+            (1, strides[i]),
+            axis_padding,
+            Some((1, dilations[i])),
+        )?;
+
+        if ceil_mode && out_size > 0 {
+            let padded_size = in_shape[i] + pad_start + pad_end;
+            let dilated_kernel = dilations[i] * (kernel_size[i] - 1) + 1;
+            out_size = (padded_size - dilated_kernel).div_ceil(strides[i]) + 1;
+
+            // Each window must start inside the input or the start padding.
+            if (out_size - 1) * strides[i] >= in_shape[i] + pad_start {
+                out_size -= 1;
+            }
+        }
+
+        axes[3 - ndim + i] = PoolAxis {
+            in_size: in_shape[i],
+            out_size,
+            kernel_size: kernel_size[i],
+            stride: strides[i],
+            dilation: dilations[i],
+            pad_start,
+            pad_end,
+        };
+    }
+
+    Ok(axes)
+}
+
+/// Number of elements in a pooling window.
+#[derive(Copy, Clone, Debug)]
+struct WindowCount {
+    /// Number of elements inside the input.
+    non_pad: usize,
+
+    /// Number of elements inside the padded input. This is the kernel size,
+    /// unless the window extends past the end of the padded input due to
+    /// `ceil_mode`.
+    padded: usize,
+}
+
 /// Generic pooling implementation.
 ///
 /// The value of each output point is computed by:
 ///
-/// - Collecting values from `input`, with a window size and stride determined
-///   by `kernel_size` and `strides` respectively, except for values that are part
-///   of the padding region.
-/// - Folding the values using `fold`, starting with `fold_init`
-/// - Computing an average of the accumulated value using `average(accum,
-///   non_padding_count)`
-fn pool_impl<T: Copy + Send, F: Fn(T, T) -> T + Sync, A: Fn(T, usize) -> T + Sync>(
+/// - Collecting values from `input`, with a window size, stride and dilation
+///   determined by `kernel_size`, `strides` and `dilations` respectively,
+///   except for values that are part of the padding region.
+/// - Folding the values using `fold(accum, value, offset)`, starting with
+///   `fold_init`. `offset` is the offset of the value within its channel,
+///   in row-major order.
+/// - Computing the output from the accumulated value using
+///   `reduce(accum, window_count)`.
+///
+/// `input` has dimensions NC followed by 1 to 3 spatial dimensions, and
+/// `kernel_size`, `strides` and `dilations` must have one entry per spatial
+/// dimension.
+fn pool_impl<
+    T: Copy + Send + Sync,
+    Acc: Copy + Send + Sync,
+    Out: Clone + Send,
+    F: Fn(Acc, T, usize) -> Acc + Sync,
+    R: Fn(Acc, WindowCount) -> Out + Sync,
+>(
     pool: &TensorPool,
     input: TensorView<T>,
-    kernel_size: [usize; 2],
-    strides: [usize; 2],
+    kernel_size: &[usize],
+    strides: &[usize],
+    dilations: &[usize],
     padding: Padding,
-    fold_init: T,
+    ceil_mode: bool,
+    fold_init: Acc,
     fold: &F,
-    average: &A,
-) -> Result<Tensor<T>, OpError>
-where
-    for<'a> TensorViewMut<'a, T>: Send,
-    for<'a> TensorView<'a, T>: Send,
-    for<'a> &'a T: Sync,
-{
-    let input = static_dims!(input, 4, "NCHW")?;
-    let [batch, in_c, in_h, in_w] = input.shape();
-    let (out_h, out_w, fixed_padding) = calc_output_size_and_padding(
-        (in_h, in_w),
-        (kernel_size[0], kernel_size[1]),
-        (strides[0], strides[1]),
+    reduce: &R,
+) -> Result<Tensor<Out>, OpError> {
+    if input.ndim() < 3 {
+        return Err(OpError::InvalidValue(
+            "Input must have at least 3 dims (NC + spatial dims)",
+        ));
+    }
+    let spatial_ndim = input.ndim() - 2;
+    let axes = pool_axes(
+        &input.shape()[2..],
+        kernel_size,
+        strides,
+        dilations,
         padding,
-        None, /* dilations */
+        ceil_mode,
     )?;
-    let [pad_top, pad_left, _pad_bottom, _pad_right] = fixed_padding;
-    let mut output = NdTensor::uninit_in(pool, [batch, in_c, out_h, out_w]);
+
+    // Treat all inputs as having 3 spatial dims.
+    let mut input = input.clone();
+    while input.ndim() < 5 {
+        input.insert_axis(2);
+    }
+    let input = input.nd_view::<5>();
+    let [batch, in_c, ..] = input.shape();
+    let [out_d, out_h, out_w] = axes.map(|axis| axis.out_size);
+
+    let mut output = NdTensor::uninit_in(pool, [batch, in_c, out_d, out_h, out_w]);
 
     // Apply pooling to the channel indexes specified by `chans`.
     // Assuming `N` is chosen appropriately the inner loop should get unrolled /
     // autovectorized.
-    fn pool_chans<T: Copy, F: Fn(T, T) -> T, A: Fn(T, usize) -> T, const N: usize>(
-        mut out: NdTensorViewMut<MaybeUninit<T>, 3>,
-        in_view: NdTensorView<T, 3>,
+    fn pool_chans<T: Copy, Acc: Copy, Out, F, R, const N: usize>(
+        out: &mut NdTensorViewMut<MaybeUninit<Out>, 4>,
+        in_view: NdTensorView<T, 4>,
         chans: [usize; N],
-        [kernel_h, kernel_w]: [usize; 2],
-        [stride_h, stride_w]: [usize; 2],
-        [pad_top, pad_left]: [usize; 2],
-        fold_init: T,
-        fold: F,
-        average: A,
-    ) {
-        let [out_chans, out_h, out_w] = out.shape();
-        let [in_chans, in_h, in_w] = in_view.shape();
+        [axis_d, axis_h, axis_w]: &[PoolAxis; 3],
+        fold_init: Acc,
+        fold: &F,
+        reduce: &R,
+    ) where
+        F: Fn(Acc, T, usize) -> Acc,
+        R: Fn(Acc, WindowCount) -> Out,
+    {
+        let [out_chans, out_d, out_h, out_w] = out.shape();
+        let [in_chans, _in_d, in_h, in_w] = in_view.shape();
         assert!(chans.into_iter().all(|c| c < out_chans && c < in_chans));
 
-        for out_y in 0..out_h {
-            for out_x in 0..out_w {
-                let mut accumulator = [fold_init; N];
-                let mut non_pad_elements = 0;
-
-                for k_y in 0..kernel_h {
-                    for k_x in 0..kernel_w {
-                        let in_y = out_y * stride_h + k_y;
-                        let in_x = out_x * stride_w + k_x;
-                        if in_y >= pad_top
-                            && in_y < in_h + pad_top
-                            && in_x >= pad_left
-                            && in_x < in_w + pad_left
-                        {
-                            for (i, chan) in chans.into_iter().enumerate() {
-                                // Safety:
-                                //  - We checked all `chans` are in-bounds
-                                //  - `in_y` and `in_x` are >= pad_top and pad_left
-                                let val = unsafe {
-                                    *in_view.get_unchecked([chan, in_y - pad_top, in_x - pad_left])
-                                };
-                                accumulator[i] = fold(accumulator[i], val);
+        for out_z in 0..out_d {
+            for out_y in 0..out_h {
+                for out_x in 0..out_w {
+                    let mut accumulator = [fold_init; N];
+                    let mut count = WindowCount {
+                        non_pad: 0,
+                        padded: 0,
+                    };
+
+                    for k_z in 0..axis_d.kernel_size {
+                        let in_z = axis_d.padded_coord(out_z, k_z);
+                        if !axis_d.in_padded_input(in_z) {
+                            continue;
+                        }
+                        for k_y in 0..axis_h.kernel_size {
+                            let in_y = axis_h.padded_coord(out_y, k_y);
+                            if !axis_h.in_padded_input(in_y) {
+                                continue;
+                            }
+                            for k_x in 0..axis_w.kernel_size {
+                                let in_x = axis_w.padded_coord(out_x, k_x);
+                                if !axis_w.in_padded_input(in_x) {
+                                    continue;
+                                }
+                                count.padded += 1;
+
+                                if !axis_d.in_input(in_z)
+                                    || !axis_h.in_input(in_y)
+                                    || !axis_w.in_input(in_x)
+                                {
+                                    continue;
+                                }
+
+                                let [z, y, x] = [
+                                    in_z - axis_d.pad_start,
+                                    in_y - axis_h.pad_start,
+                                    in_x - axis_w.pad_start,
+                                ];
+                                let offset = (z * in_h + y) * in_w + x;
+                                for (i, chan) in chans.into_iter().enumerate() {
+                                    // Safety:
+                                    //  - We checked all `chans` are in-bounds
+                                    //  - We checked that `z`, `y` and `x` are
+                                    //    inside the input.
+                                    let val = unsafe { *in_view.get_unchecked([chan, z, y, x]) };
+                                    accumulator[i] = fold(accumulator[i], val, offset);
+                                }
+                                count.non_pad += 1;
                             }
-                            non_pad_elements += 1;
                         }
                     }
-                }
-                for (i, chan) in chans.into_iter().enumerate() {
-                    // Safety:
-                    //  - We checked all `chans` are in-bounds
-                    //  - `out_y` and `out_x` are in 0..out_h, 0..out_w
-                    unsafe {
-                        out.get_unchecked_mut([chan, out_y, out_x])
-                            .write(average(accumulator[i], non_pad_elements));
+
+                    for (i, chan) in chans.into_iter().enumerate() {
+                        // Safety:
+                        //  - We checked all `chans` are in-bounds
+                        //  - `out_z`, `out_y` and `out_x` are in-bounds
+                        unsafe {
+                            out.get_unchecked_mut([chan, out_z, out_y, out_x])
+                                .write(reduce(accumulator[i], count));
+                        }
                     }
                 }
             }
         }
     }
 
-    // Work around error if using `fold_init` directly in closure.
-    let accum_init_val = || fold_init;
-
     let n_init = AtomicUsize::new(0);
     zip(output.axis_iter_mut(0), input.axis_iter(0))
         .par_bridge()
         .for_each(|(mut out_item, in_item)| {
-            let [_, out_h, out_w] = out_item.shape();
-
-            // Loop over channel groups.
             const N: usize = CHAN_GROUP_SIZE;
-            for chan in (0..in_c).step_by(N) {
-                if in_c - chan < N {
-                    break;
-                }
-                pool_chans(
-                    out_item.view_mut(),
-                    in_item,
-                    [chan, chan + 1, chan + 2, chan + 3],
-                    kernel_size,
-                    strides,
-                    [pad_top, pad_left],
-                    accum_init_val(),
-                    fold,
-                    average,
-                );
-                n_init.fetch_add(N * out_h * out_w, Ordering::SeqCst);
-            }
 
-            // Loop over remaining channels.
-            for chan in (in_c - in_c % N)..in_c {
-                pool_chans(
-                    out_item.view_mut(),
-                    in_item,
-                    [chan],
-                    kernel_size,
-                    strides,
-                    [pad_top, pad_left],
-                    accum_init_val(),
-                    fold,
-                    average,
-                );
-                n_init.fetch_add(out_h * out_w, Ordering::SeqCst);
+            zip(out_item.axis_chunks_mut(0, N), in_item.axis_chunks(0, N))
+                .par_bridge()
+                .for_each(|(mut out_group, in_group)| {
+                    let n_chans = in_group.size(0);
+                    if n_chans == N {
+                        pool_chans(
+                            &mut out_group,
+                            in_group,
+                            [0, 1, 2, 3],
+                            &axes,
+                            fold_init,
+                            fold,
+                            reduce,
+                        );
+                    } else {
+                        // Handle remaining channels one at a time.
+                        for chan in 0..n_chans {
+                            pool_chans(
+                                &mut out_group,
+                                in_group,
+                                [chan],
+                                &axes,
+                                fold_init,
+                                fold,
+                                reduce,
+                            );
+                        }
+                    }
+                    n_init.fetch_add(out_group.len(), Ordering::SeqCst);
+                });
+        });
+
+    assert!(n_init.load(Ordering::SeqCst) == output.len());
+    let output = unsafe { output.assume_init() };
+
+    let mut out_shape: SmallVec<[usize; 5]> = smallvec![batch, in_c];
+    out_shape.extend(axes[3 - spatial_ndim..].iter().map(|axis| axis.out_size));
+    Ok(output.into_shape(out_shape.as_slice()))
+}
+
+/// Variant of [`pool_impl`] for 2D pooling of an input in channels-last
+/// (NHWC) layout.
+///
+/// The output for each pixel is computed by initializing an accumulator for
+/// each channel to `fold_init`, updating the accumulators with each input
+/// pixel in the window using `fold(accum, pixel)`, and then computing the
+/// output using `reduce(accum, window_count)`. Since the channels of each pixel
+/// are contiguous, these functions operate on slices of all channels.
+fn pool_nhwc_impl<F: Fn(&mut [f32], &[f32]) + Sync, R: Fn(&mut [f32], WindowCount) + Sync>(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    dilations: &[usize],
+    padding: Padding,
+    ceil_mode: bool,
+    fold_init: f32,
+    fold: &F,
+    reduce: &R,
+) -> Result<Tensor, OpError> {
+    let input = static_dims!(input, 4, "NHWC")?;
+    let [batch, in_h, in_w, chans] = input.shape();
+    let [_, axis_h, axis_w] = pool_axes(
+        &[in_h, in_w],
+        kernel_size,
+        strides,
+        dilations,
+        padding,
+        ceil_mode,
+    )?;
+    let [out_h, out_w] = [axis_h.out_size, axis_w.out_size];
+
+    let mut output = NdTensor::uninit_in(pool, [batch, out_h, out_w, chans]);
+    if output.is_empty() {
+        // Safety: Empty output is already initialized.
+        return Ok(unsafe { output.assume_init() }.into_dyn());
+    }
+
+    // Use of input pixels below assumes a contiguous input.
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let in_data = input.data().unwrap();
+
+    let n_init = AtomicUsize::new(0);
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(out_w * chans)
+        .enumerate()
+        .for_each(|(row, out_row)| {
+            let (n, out_y) = (row / out_h, row % out_h);
+
+            for (out_x, out_pixel) in out_row.chunks_mut(chans).enumerate() {
+                out_pixel.fill(MaybeUninit::new(fold_init));
+
+                // Safety: We just initialized all elements of `out_pixel`.
+                let out_pixel: &mut [f32] = unsafe { std::mem::transmute(out_pixel) };
+
+                let mut count = WindowCount {
+                    non_pad: 0,
+                    padded: 0,
+                };
+                for k_y in 0..axis_h.kernel_size {
+                    let in_y = axis_h.padded_coord(out_y, k_y);
+                    if !axis_h.in_padded_input(in_y) {
+                        continue;
+                    }
+                    for k_x in 0..axis_w.kernel_size {
+                        let in_x = axis_w.padded_coord(out_x, k_x);
+                        if !axis_w.in_padded_input(in_x) {
+                            continue;
+                        }
+                        count.padded += 1;
+
+                        if !axis_h.in_input(in_y) || !axis_w.in_input(in_x) {
+                            continue;
+                        }
+                        let [y, x] = [in_y - axis_h.pad_start, in_x - axis_w.pad_start];
+                        let in_offset = ((n * in_h + y) * in_w + x) * chans;
+                        fold(out_pixel, &in_data[in_offset..][..chans]);
+                        count.non_pad += 1;
+                    }
+                }
+                reduce(out_pixel, count);
             }
+            n_init.fetch_add(out_row.len(), Ordering::SeqCst);
         });
 
     assert!(n_init.load(Ordering::SeqCst) == output.len());
     let output = unsafe { output.assume_init() };
-    Ok(output.into())
+
+    Ok(output.into_dyn())
 }
 
+/// Apply average pooling to an input with 1 to 3 spatial dimensions.
+///
+/// `input` has dimensions NC followed by the spatial dimensions, eg. NCHW for
+/// a 2D input.
 pub fn average_pool(
     pool: &TensorPool,
     input: TensorView,
-    kernel_size: [usize; 2],
-    strides: [usize; 2],
+    kernel_size: &[usize],
+    strides: &[usize],
     padding: Padding,
+    dilations: &[usize],
+    ceil_mode: bool,
     count_include_pad: bool,
 ) -> Result<Tensor, OpError> {
-    let kernel_len = kernel_size[0] * kernel_size[1];
     pool_impl(
         pool,
         input,
         kernel_size,
         strides,
+        dilations,
         padding,
+        ceil_mode,
         0.,
-        &|acc, x| acc + x,
-        &|acc, non_pad_elements| {
+        &|acc, x, _offset| acc + x,
+        &|acc, count| {
             if count_include_pad {
-                acc / (kernel_len as f32)
+                acc / (count.padded as f32)
             } else {
-                acc / (non_pad_elements as f32)
+                acc / (count.non_pad as f32)
             }
         },
     )
@@ -266,10 +614,12 @@ pub fn average_pool(
 
 #[derive(Debug)]
 pub struct AveragePool {
-    pub kernel_size: [usize; 2],
+    pub kernel_size: SmallVec<[usize; 2]>,
     pub padding: Padding,
     pub count_include_pad: bool,
-    pub strides: [usize; 2],
+    pub strides: SmallVec<[usize; 2]>,
+    pub dilations: SmallVec<[usize; 2]>,
+    pub ceil_mode: bool,
 }
 
 impl Operator for AveragePool {
@@ -282,9 +632,88 @@ impl Operator for AveragePool {
         average_pool(
             pool,
             input,
-            self.kernel_size,
-            self.strides,
+            &self.kernel_size,
+            &self.strides,
             self.padding.clone(),
+            &self.dilations,
+            self.ceil_mode,
+            self.count_include_pad,
+        )
+        .into_op_result()
+    }
+}
+
+/// Apply 2D average pooling to an input in channels-last (NHWC) layout.
+///
+/// The parameters have the same meaning as for [`average_pool`].
+pub fn average_pool_nhwc(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: &[usize],
+    ceil_mode: bool,
+    count_include_pad: bool,
+) -> Result<Tensor, OpError> {
+    pool_nhwc_impl(
+        pool,
+        input,
+        kernel_size,
+        strides,
+        dilations,
+        padding,
+        ceil_mode,
+        0.,
+        &|acc, xs| {
+            for (acc, x) in zip(acc, xs) {
+                *acc += x;
+            }
+        },
+        &|acc, count| {
+            let count = if count_include_pad {
+                count.padded
+            } else {
+                count.non_pad
+            };
+            let scale = 1. / count as f32;
+            for acc in acc {
+                *acc *= scale;
+            }
+        },
+    )
+}
+
+/// Variant of [`AveragePool`] for 2D pooling of an input in channels-last
+/// (NHWC) layout.
+///
+/// This is created by the graph optimizer when running convolution-heavy
+/// subgraphs in channels-last layout.
+#[derive(Debug)]
+pub struct AveragePoolNhwc {
+    pub kernel_size: SmallVec<[usize; 2]>,
+    pub padding: Padding,
+    pub count_include_pad: bool,
+    pub strides: SmallVec<[usize; 2]>,
+    pub dilations: SmallVec<[usize; 2]>,
+    pub ceil_mode: bool,
+}
+
+impl Operator for AveragePoolNhwc {
+    fn name(&self) -> &str {
+        "AveragePoolNhwc"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        average_pool_nhwc(
+            pool,
+            input,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            &self.dilations,
+            self.ceil_mode,
             self.count_include_pad,
         )
         .into_op_result()
@@ -355,30 +784,91 @@ impl Operator for GlobalAveragePool {
     }
 }
 
+/// Apply max pooling to an input with 1 to 3 spatial dimensions.
+///
+/// `input` has dimensions NC followed by the spatial dimensions, eg. NCHW for
+/// a 2D input.
 pub fn max_pool(
     pool: &TensorPool,
     input: TensorView,
-    kernel_size: [usize; 2],
-    strides: [usize; 2],
+    kernel_size: &[usize],
+    strides: &[usize],
     padding: Padding,
+    dilations: &[usize],
+    ceil_mode: bool,
 ) -> Result<Tensor, OpError> {
     pool_impl(
         pool,
         input,
         kernel_size,
         strides,
+        dilations,
         padding,
+        ceil_mode,
         f32::NEG_INFINITY,
-        &|acc, x| acc.max(x),
-        &|x, _non_pad_count| x,
+        &|acc, x, _offset| acc.max(x),
+        &|x, _count| x,
     )
 }
 
+/// Variant of [`max_pool`] which also returns the indices of the maximum
+/// values.
+///
+/// Indices are offsets into the flattened input, including the batch and
+/// channel dimensions, as in the ONNX `MaxPool` operator.
+pub fn max_pool_with_indices(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: &[usize],
+    ceil_mode: bool,
+) -> Result<(Tensor, Tensor<i32>), OpError> {
+    let in_chan_len: usize = input.shape().iter().skip(2).product();
+    let output = pool_impl(
+        pool,
+        input,
+        kernel_size,
+        strides,
+        dilations,
+        padding,
+        ceil_mode,
+        (f32::NEG_INFINITY, 0),
+        &|(max, max_offset), x, offset| {
+            if x > max {
+                (x, offset)
+            } else {
+                (max, max_offset)
+            }
+        },
+        &|acc, _count| acc,
+    )?;
+
+    let values = output.map_in(pool, |(max, _)| *max);
+
+    let out_chan_len: usize = output.shape().iter().skip(2).product();
+    let mut indices = pool.alloc(output.len());
+    indices.extend(output.iter().enumerate().map(|(i, (_, offset))| {
+        let chan = i / out_chan_len.max(1);
+        (chan * in_chan_len + offset) as i32
+    }));
+    let indices = Tensor::from_data(output.shape(), indices);
+
+    Ok((values, indices))
+}
+
 #[derive(Debug)]
 pub struct MaxPool {
-    pub kernel_size: [usize; 2],
+    pub kernel_size: SmallVec<[usize; 2]>,
     pub padding: Padding,
-    pub strides: [usize; 2],
+    pub strides: SmallVec<[usize; 2]>,
+    pub dilations: SmallVec<[usize; 2]>,
+    pub ceil_mode: bool,
+
+    /// Whether to compute the indices of the maximum values as a second
+    /// output.
+    pub return_indices: bool,
 }
 
 impl Operator for MaxPool {
@@ -388,12 +878,349 @@ impl Operator for MaxPool {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require_as(0)?;
+
+        if self.return_indices {
+            let (values, indices) = max_pool_with_indices(
+                pool,
+                input,
+                &self.kernel_size,
+                &self.strides,
+                self.padding.clone(),
+                &self.dilations,
+                self.ceil_mode,
+            )?;
+            return Ok([values.into(), indices.into()].into_iter().collect());
+        }
+
         max_pool(
             pool,
             input,
-            self.kernel_size,
-            self.strides,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            &self.dilations,
+            self.ceil_mode,
+        )
+        .into_op_result()
+    }
+}
+
+/// Apply 2D max pooling to an input in channels-last (NHWC) layout.
+///
+/// The parameters have the same meaning as for [`max_pool`].
+pub fn max_pool_nhwc(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: &[usize],
+    ceil_mode: bool,
+) -> Result<Tensor, OpError> {
+    pool_nhwc_impl(
+        pool,
+        input,
+        kernel_size,
+        strides,
+        dilations,
+        padding,
+        ceil_mode,
+        f32::NEG_INFINITY,
+        &|acc, xs| {
+            for (acc, x) in zip(acc, xs) {
+                *acc = acc.max(*x);
+            }
+        },
+        &|_acc, _count| {},
+    )
+}
+
+/// Variant of [`MaxPool`] for 2D pooling of an input in channels-last (NHWC)
+/// layout.
+///
+/// This is created by the graph optimizer when running convolution-heavy
+/// subgraphs in channels-last layout. Unlike `MaxPool`, this does not support
+/// returning the indices of the maximum values.
+#[derive(Debug)]
+pub struct MaxPoolNhwc {
+    pub kernel_size: SmallVec<[usize; 2]>,
+    pub padding: Padding,
+    pub strides: SmallVec<[usize; 2]>,
+    pub dilations: SmallVec<[usize; 2]>,
+    pub ceil_mode: bool,
+}
+
+impl Operator for MaxPoolNhwc {
+    fn name(&self) -> &str {
+        "MaxPoolNhwc"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        max_pool_nhwc(
+            pool,
+            input,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            &self.dilations,
+            self.ceil_mode,
+        )
+        .into_op_result()
+    }
+}
+
+/// Apply max pooling over all spatial dimensions of an input.
+pub fn global_max_pool(pool: &TensorPool, input: TensorView) -> Result<Tensor, OpError> {
+    if input.ndim() < 3 {
+        return Err(OpError::InvalidValue(
+            "Input must have at least 3 dims (NC + spatial dims)",
+        ));
+    }
+    let kernel_size = &input.shape()[2..];
+    let ones: SmallVec<[usize; 3]> = smallvec![1; kernel_size.len()];
+    max_pool(
+        pool,
+        input.view(),
+        kernel_size,
+        &ones,
+        Padding::Fixed(smallvec![0; kernel_size.len() * 2]),
+        &ones,
+        false, /* ceil_mode */
+    )
+}
+
+#[derive(Debug)]
+pub struct GlobalMaxPool {}
+
+impl Operator for GlobalMaxPool {
+    fn name(&self) -> &str {
+        "GlobalMaxPool"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        global_max_pool(pool, input).into_op_result()
+    }
+}
+
+/// Apply Lp pooling to an input with 1 to 3 spatial dimensions.
+///
+/// Each output is the Lp norm of the elements in the corresponding window,
+/// ie. `sum(abs(x)^p)^(1/p)`.
+pub fn lp_pool(
+    pool: &TensorPool,
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    dilations: &[usize],
+    ceil_mode: bool,
+    p: usize,
+) -> Result<Tensor, OpError> {
+    if p == 0 {
+        return Err(OpError::InvalidValue("p must be > 0"));
+    }
+    let inv_p = 1. / p as f32;
+    pool_impl(
+        pool,
+        input,
+        kernel_size,
+        strides,
+        dilations,
+        padding,
+        ceil_mode,
+        0.,
+        &|acc, x, _offset| acc + x.abs().powi(p as i32),
+        &|acc, _count| acc.powf(inv_p),
+    )
+}
+
+#[derive(Debug)]
+pub struct LpPool {
+    pub kernel_size: SmallVec<[usize; 2]>,
+    pub padding: Padding,
+    pub strides: SmallVec<[usize; 2]>,
+    pub dilations: SmallVec<[usize; 2]>,
+    pub ceil_mode: bool,
+    pub p: usize,
+}
+
+impl Operator for LpPool {
+    fn name(&self) -> &str {
+        "LpPool"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        lp_pool(
+            pool,
+            input,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            &self.dilations,
+            self.ceil_mode,
+            self.p,
+        )
+        .into_op_result()
+    }
+}
+
+/// Apply Lp pooling over all spatial dimensions of an input.
+pub fn global_lp_pool(pool: &TensorPool, input: TensorView, p: usize) -> Result<Tensor, OpError> {
+    if input.ndim() < 3 {
+        return Err(OpError::InvalidValue(
+            "Input must have at least 3 dims (NC + spatial dims)",
+        ));
+    }
+    let kernel_size = &input.shape()[2..];
+    let ones: SmallVec<[usize; 3]> = smallvec![1; kernel_size.len()];
+    lp_pool(
+        pool,
+        input.view(),
+        kernel_size,
+        &ones,
+        Padding::Fixed(smallvec![0; kernel_size.len() * 2]),
+        &ones,
+        false, /* ceil_mode */
+        p,
+    )
+}
+
+#[derive(Debug)]
+pub struct GlobalLpPool {
+    pub p: usize,
+}
+
+impl Operator for GlobalLpPool {
+    fn name(&self) -> &str {
+        "GlobalLpPool"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        global_lp_pool(pool, input, self.p).into_op_result()
+    }
+}
+
+/// Compute a partial inverse of [`max_pool_with_indices`].
+///
+/// Each value in `input` is written to the position in the output given by
+/// the corresponding entry in `indices`, and all other output values are
+/// zero. `indices` are offsets into the flattened output.
+///
+/// The output shape is `output_shape` if specified, or otherwise the
+/// smallest shape that could have produced `input` when max pooled with the
+/// given kernel size, strides and padding.
+pub fn max_unpool(
+    pool: &TensorPool,
+    input: TensorView,
+    indices: TensorView<i32>,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    output_shape: Option<&[usize]>,
+) -> Result<Tensor, OpError> {
+    if input.shape() != indices.shape() {
+        return Err(OpError::IncompatibleInputShapes(
+            "Input and indices must have the same shape",
+        ));
+    }
+    if input.ndim() < 3 {
+        return Err(OpError::InvalidValue(
+            "Input must have at least 3 dims (NC + spatial dims)",
+        ));
+    }
+
+    let spatial_ndim = input.ndim() - 2;
+    if kernel_size.len() != spatial_ndim || strides.len() != spatial_ndim {
+        return Err(OpError::InvalidValue(
+            "Kernel size and strides must match input spatial dims",
+        ));
+    }
+    let pads = match padding {
+        Padding::Same => {
+            return Err(OpError::UnsupportedValue(
+                "MaxUnpool does not support \"same\" padding",
+            ));
+        }
+        Padding::Fixed(pads) if pads.len() == spatial_ndim * 2 => pads,
+        Padding::Fixed(_) => {
+            return Err(OpError::InvalidValue(
+                "Padding does not match input spatial dims",
+            ));
+        }
+    };
+
+    let out_shape: SmallVec<[usize; 5]> = if let Some(output_shape) = output_shape {
+        if output_shape.len() != input.ndim() || output_shape[..2] != input.shape()[..2] {
+            return Err(OpError::InvalidValue(
+                "Output shape must match input batch and channel dims",
+            ));
+        }
+        output_shape.into()
+    } else {
+        let mut shape: SmallVec<[usize; 5]> = input.shape()[..2].into();
+        for i in 0..spatial_ndim {
+            let size = ((input.size(2 + i).max(1) - 1) * strides[i] + kernel_size[i])
+                .checked_sub(pads[i] + pads[spatial_ndim + i])
+                .ok_or(OpError::InvalidValue("Padding is too large"))?;
+            shape.push(size);
+        }
+        shape
+    };
+
+    let mut output = Tensor::zeros_in(pool, &out_shape);
+    let out_data = output.data_mut().unwrap();
+    for (&x, &index) in zip(input.iter(), indices.iter()) {
+        let out_el = usize::try_from(index)
+            .ok()
+            .and_then(|index| out_data.get_mut(index))
+            .ok_or(OpError::InvalidValue("Index is out of range"))?;
+        *out_el = x;
+    }
+
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct MaxUnpool {
+    pub kernel_size: SmallVec<[usize; 2]>,
+    pub padding: Padding,
+    pub strides: SmallVec<[usize; 2]>,
+}
+
+impl Operator for MaxUnpool {
+    fn name(&self) -> &str {
+        "MaxUnpool"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let indices = inputs.require_as(1)?;
+        let output_shape = inputs.get_as::<i32>(2)?;
+        let output_shape: Option<SmallVec<[usize; 5]>> = output_shape
+            .map(|shape| {
+                let shape = static_dims!(shape, 1)?;
+                shape
+                    .iter()
+                    .map(|&size| {
+                        usize::try_from(size)
+                            .map_err(|_| OpError::InvalidValue("Output shape must be >= 0"))
+                    })
+                    .collect()
+            })
+            .transpose()?;
+
+        max_unpool(
+            pool,
+            input,
+            indices,
+            &self.kernel_size,
+            &self.strides,
             self.padding.clone(),
+            output_shape.as_deref(),
         )
         .into_op_result()
     }
@@ -404,13 +1231,17 @@ mod tests {
     use std::error::Error;
 
     use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::Tensor;
 
-    use super::calc_output_size_and_padding;
+    use super::{average_pool_nhwc, calc_output_size_and_padding, max_pool_nhwc};
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::tests::new_pool;
-    use crate::ops::{average_pool, global_average_pool, max_pool, OpError, Padding};
+    use crate::ops::{
+        average_pool, global_average_pool, global_lp_pool, global_max_pool, lp_pool, max_pool,
+        max_pool_with_indices, max_unpool, OpError, Padding,
+    };
 
     #[test]
     fn test_average_pool() -> Result<(), Box<dyn Error>> {
@@ -482,9 +1313,11 @@ mod tests {
             let result = average_pool(
                 &pool,
                 input.view(),
-                case.kernel_size,
-                case.strides,
+                &case.kernel_size,
+                &case.strides,
                 [0, 0, 0, 0].into(),
+                &[1, 1],
+                false,
                 false, /* count_include_pad */
             )
             .unwrap();
@@ -524,9 +1357,11 @@ mod tests {
         let result = average_pool(
             &pool,
             input.as_dyn(),
-            [2, 2],
-            [2, 2], /* stride */
+            &[2, 2],
+            &[2, 2], /* stride */
             [1, 1, 1, 1].into(),
+            &[1, 1],
+            false,
             false, /* count_include_pad */
         )
         .unwrap();
@@ -543,9 +1378,11 @@ mod tests {
         let result = average_pool(
             &pool,
             input.as_dyn(),
-            [2, 2],
-            [2, 2], /* stride */
+            &[2, 2],
+            &[2, 2], /* stride */
             [1, 1, 1, 1].into(),
+            &[1, 1],
+            false,
             true, /* count_include_pad */
         )
         .unwrap();
@@ -554,6 +1391,103 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_pool_3d() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(
+            &[1, 1, 2, 2, 2],
+            (1..=8).map(|x| x as f32).collect::<Vec<_>>(),
+        );
+
+        // Kernel covering the whole volume.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2, 2],
+            &[1, 1, 1],
+            Padding::zero::<3>(),
+            &[1, 1, 1],
+            false,
+        )?;
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 1, 1, 1], vec![8.]))?;
+
+        let result = average_pool(
+            &pool,
+            input.view(),
+            &[2, 2, 2],
+            &[1, 1, 1],
+            Padding::zero::<3>(),
+            &[1, 1, 1],
+            false,
+            false, /* count_include_pad */
+        )?;
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 1, 1, 1], vec![4.5]))?;
+
+        // Kernel and strides which vary per axis.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 1, 2],
+            &[1, 1, 2],
+            Padding::zero::<3>(),
+            &[1, 1, 1],
+            false,
+        )?;
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 1, 2, 1], vec![6., 8.]))?;
+
+        // Padding only at the front of the depth axis, with and without
+        // counting padding elements.
+        let padding: Padding = [1, 0, 0, 0, 0, 0].into();
+        let result = average_pool(
+            &pool,
+            input.view(),
+            &[2, 2, 2],
+            &[1, 1, 1],
+            padding.clone(),
+            &[1, 1, 1],
+            false,
+            false, /* count_include_pad */
+        )?;
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 2, 1, 1], vec![2.5, 4.5]),
+        )?;
+
+        let result = average_pool(
+            &pool,
+            input.view(),
+            &[2, 2, 2],
+            &[1, 1, 1],
+            padding,
+            &[1, 1, 1],
+            false,
+            true, /* count_include_pad */
+        )?;
+        expect_equal(
+            &result,
+            &Tensor::from_data(&[1, 1, 2, 1, 1], vec![1.25, 4.5]),
+        )?;
+
+        // Mismatch between kernel size and input rank.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[1, 1],
+            Padding::zero::<3>(),
+            &[1, 1],
+            false,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Kernel size does not match input spatial dims"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_global_average_pool() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -633,9 +1567,11 @@ mod tests {
             let result = max_pool(
                 &pool,
                 input.view(),
-                case.kernel_size,
-                case.strides,
+                &case.kernel_size,
+                &case.strides,
                 [0, 0, 0, 0].into(),
+                &[1, 1],
+                false,
             )
             .unwrap();
             expect_equal(&result, &case.expected)?;
@@ -644,24 +1580,345 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_pool_ceil_mode_and_dilations() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[1, 1, 5, 5], (0..25).map(|x| x as f32).collect::<Vec<_>>());
+        let zero_pad = Padding::zero::<2>();
+
+        // With `ceil_mode`, the last window along each axis is partial.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            zero_pad.clone(),
+            &[1, 1],
+            true, /* ceil_mode */
+        )?;
+        let expected = Tensor::from([[6., 8., 9.], [16., 18., 19.], [21., 23., 24.]]);
+        expect_equal(&result, &expected.into_shape([1, 1, 3, 3].as_slice()))?;
+
+        // With `count_include_pad`, elements past the end of the padded input
+        // are not included in the average.
+        let result = average_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            zero_pad.clone(),
+            &[1, 1],
+            true, /* ceil_mode */
+            true, /* count_include_pad */
+        )?;
+        assert_eq!(result.shape(), &[1, 1, 3, 3]);
+        assert_eq!(result[[0, 0, 0, 2]], 6.5);
+        assert_eq!(result[[0, 0, 2, 2]], 24.);
+
+        // Dilated kernel.
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[1, 1],
+            zero_pad,
+            &[2, 2],
+            false, /* ceil_mode */
+        )?;
+        let expected = Tensor::from([[12., 13., 14.], [17., 18., 19.], [22., 23., 24.]]);
+        expect_equal(&result, &expected.into_shape([1, 1, 3, 3].as_slice()))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_nhwc() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+
+        struct Case {
+            kernel_size: [usize; 2],
+            strides: [usize; 2],
+            padding: Padding,
+            dilations: [usize; 2],
+            ceil_mode: bool,
+        }
+
+        let cases = [
+            Case {
+                kernel_size: [2, 2],
+                strides: [2, 2],
+                padding: Padding::zero::<2>(),
+                dilations: [1, 1],
+                ceil_mode: false,
+            },
+            Case {
+                kernel_size: [3, 3],
+                strides: [2, 1],
+                padding: [1, 0, 1, 2].into(),
+                dilations: [1, 1],
+                ceil_mode: false,
+            },
+            Case {
+                kernel_size: [2, 3],
+                strides: [2, 2],
+                padding: Padding::zero::<2>(),
+                dilations: [2, 1],
+                ceil_mode: true,
+            },
+            Case {
+                kernel_size: [3, 3],
+                strides: [2, 2],
+                padding: Padding::Same,
+                dilations: [1, 1],
+                ceil_mode: false,
+            },
+        ];
+
+        let input = Tensor::rand(&[2, 5, 7, 8], &mut rng);
+        let input_nhwc = input.permuted([0, 2, 3, 1].as_slice()).to_tensor();
+
+        for Case {
+            kernel_size,
+            strides,
+            padding,
+            dilations,
+            ceil_mode,
+        } in cases
+        {
+            let expected = max_pool(
+                &pool,
+                input.view(),
+                &kernel_size,
+                &strides,
+                padding.clone(),
+                &dilations,
+                ceil_mode,
+            )?;
+            let result = max_pool_nhwc(
+                &pool,
+                input_nhwc.view(),
+                &kernel_size,
+                &strides,
+                padding.clone(),
+                &dilations,
+                ceil_mode,
+            )?;
+            expect_equal(
+                &result,
+                &expected.permuted([0, 2, 3, 1].as_slice()).to_tensor(),
+            )?;
+
+            for count_include_pad in [false, true] {
+                let expected = average_pool(
+                    &pool,
+                    input.view(),
+                    &kernel_size,
+                    &strides,
+                    padding.clone(),
+                    &dilations,
+                    ceil_mode,
+                    count_include_pad,
+                )?;
+                let result = average_pool_nhwc(
+                    &pool,
+                    input_nhwc.view(),
+                    &kernel_size,
+                    &strides,
+                    padding.clone(),
+                    &dilations,
+                    ceil_mode,
+                    count_include_pad,
+                )?;
+                expect_eq_1e4(
+                    &result,
+                    &expected.permuted([0, 2, 3, 1].as_slice()).to_tensor(),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_pool_with_indices() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[1, 2, 2, 2], vec![1., 5., 3., 2., 0., -1., 7., 4.]);
+
+        let (values, indices) = max_pool_with_indices(
+            &pool,
+            input.view(),
+            &[2, 1],
+            &[1, 1],
+            Padding::zero::<2>(),
+            &[1, 1],
+            false, /* ceil_mode */
+        )?;
+        expect_equal(
+            &values,
+            &Tensor::from_data(&[1, 2, 1, 2], vec![3., 5., 7., 4.]),
+        )?;
+
+        // Indices are offsets into the flattened input, including the
+        // channel dimension.
+        assert_eq!(indices.shape(), &[1, 2, 1, 2]);
+        assert_eq!(indices.to_vec(), &[2, 1, 6, 7]);
+
+        // Unpooling should scatter the values back to their source positions.
+        let unpooled = max_unpool(
+            &pool,
+            values.view(),
+            indices.view(),
+            &[2, 1],
+            &[1, 1],
+            Padding::zero::<2>(),
+            None, /* output_shape */
+        )?;
+        expect_equal(
+            &unpooled,
+            &Tensor::from_data(&[1, 2, 2, 2], vec![0., 5., 3., 0., 0., 0., 7., 4.]),
+        )?;
+
+        // Unpooling with an explicit output shape.
+        let unpooled = max_unpool(
+            &pool,
+            values.view(),
+            indices.view(),
+            &[2, 1],
+            &[1, 1],
+            Padding::zero::<2>(),
+            Some(&[1, 2, 2, 3]),
+        )?;
+        assert_eq!(unpooled.shape(), &[1, 2, 2, 3]);
+
+        // Out of range indices.
+        let unpooled = max_unpool(
+            &pool,
+            values.view(),
+            Tensor::from_data(&[1, 2, 1, 2], vec![0, 1, 2, 8]).view(),
+            &[2, 1],
+            &[1, 1],
+            Padding::zero::<2>(),
+            None, /* output_shape */
+        );
+        assert_eq!(
+            unpooled.err(),
+            Some(OpError::InvalidValue("Index is out of range"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_max_pool() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from_data(&[1, 2, 2, 2], vec![1., 5., 3., 2., 0., -1., 7., 4.]);
+        let result = global_max_pool(&pool, input.view())?;
+        expect_equal(&result, &Tensor::from_data(&[1, 2, 1, 1], vec![5., 7.]))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_lp_pool() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        // 1D input with p=1.
+        let input = Tensor::from_data(&[1, 1, 4], vec![1., -2., 3., -4.]);
+        let result = lp_pool(
+            &pool,
+            input.view(),
+            &[2],
+            &[2],
+            Padding::zero::<1>(),
+            &[1],
+            false, /* ceil_mode */
+            1,     /* p */
+        )?;
+        expect_equal(&result, &Tensor::from_data(&[1, 1, 2], vec![3., 7.]))?;
+
+        // 2D input with p=2.
+        let input = Tensor::from_data(&[1, 1, 2, 2], vec![3., 0., 0., -4.]);
+        let result = lp_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[1, 1],
+            Padding::zero::<2>(),
+            &[1, 1],
+            false, /* ceil_mode */
+            2,     /* p */
+        )?;
+        expect_eq_1e4(&result, &Tensor::from_data(&[1, 1, 1, 1], vec![5.]))?;
+
+        let result = global_lp_pool(&pool, input.view(), 2)?;
+        expect_eq_1e4(&result, &Tensor::from_data(&[1, 1, 1, 1], vec![5.]))?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_max_pool_padding() {
         let pool = new_pool();
         let input = Tensor::zeros(&[1, 1, 9, 9]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], [0, 0, 0, 0].into()).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            [0, 0, 0, 0].into(),
+            &[1, 1],
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 4, 4]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], [1, 1, 1, 1].into()).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            [1, 1, 1, 1].into(),
+            &[1, 1],
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 5, 5]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], [2, 2, 2, 2].into()).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            [2, 2, 2, 2].into(),
+            &[1, 1],
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 6, 6]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [2, 2], Padding::Same).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            Padding::Same,
+            &[1, 1],
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 5, 5]);
 
-        let result = max_pool(&pool, input.view(), [2, 2], [3, 3], Padding::Same).unwrap();
+        let result = max_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[3, 3],
+            Padding::Same,
+            &[1, 1],
+            false,
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[1, 1, 3, 3]);
     }
 
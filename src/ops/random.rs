@@ -1,11 +1,56 @@
 use fastrand::Rng;
 use fastrand_contrib::RngExt;
 use rten_tensor::prelude::*;
-use rten_tensor::Tensor;
+use rten_tensor::{NdTensor, Tensor, TensorView};
 
-use crate::ops::{InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::ops::{DataType, InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::static_dims;
 use crate::tensor_pool::TensorPool;
 
+/// Create the random number generator for an operator.
+///
+/// `run_seed` is the seed passed to [Operator::run_with_seed], which takes
+/// precedence over `op_seed`, the operator's `seed` attribute. If neither is
+/// set, the generator is randomly seeded.
+fn new_rng(op_seed: Option<f32>, run_seed: Option<u64>) -> Rng {
+    match (run_seed, op_seed) {
+        (Some(seed), _) => Rng::with_seed(seed),
+        (None, Some(seed)) => Rng::with_seed(seed.to_bits() as u64),
+        (None, None) => Rng::new(),
+    }
+}
+
+/// Implement [Operator] for a random operator.
+///
+/// The operator must have a `seed: Option<f32>` field and a
+/// `run_with_rng(&self, pool, inputs, rng)` method.
+macro_rules! impl_random_operator {
+    ($op:ident) => {
+        impl Operator for $op {
+            fn name(&self) -> &str {
+                stringify!($op)
+            }
+
+            fn is_deterministic(&self) -> bool {
+                false
+            }
+
+            fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+                self.run_with_rng(pool, inputs, new_rng(self.seed, None))
+            }
+
+            fn run_with_seed(
+                &self,
+                pool: &TensorPool,
+                inputs: InputList,
+                seed: u64,
+            ) -> Result<OutputList, OpError> {
+                self.run_with_rng(pool, inputs, new_rng(self.seed, Some(seed)))
+            }
+        }
+    };
+}
+
 #[derive(Debug)]
 pub struct RandomUniform {
     pub low: f32,
@@ -19,28 +64,24 @@ pub struct RandomUniform {
     pub seed: Option<f32>,
 }
 
-impl Operator for RandomUniform {
-    fn name(&self) -> &str {
-        "RandomUniform"
+impl RandomUniform {
+    fn run_with_rng(
+        &self,
+        pool: &TensorPool,
+        _inputs: InputList,
+        rng: Rng,
+    ) -> Result<OutputList, OpError> {
+        random_uniform(pool, &self.shape, self.low, self.high, rng).into_op_result()
     }
+}
 
-    fn is_deterministic(&self) -> bool {
-        false
-    }
-
-    fn run(&self, pool: &TensorPool, _inputs: InputList) -> Result<OutputList, OpError> {
-        let scale_value = |val: f32| self.low + val * (self.high - self.low);
-        let shape = self.shape.as_slice();
-
-        let mut rng = if let Some(seed) = self.seed {
-            Rng::with_seed(seed.to_bits() as u64)
-        } else {
-            Rng::new()
-        };
-        Tensor::from_simple_fn_in(pool, shape, || scale_value(rng.f32())).into_op_result()
-    }
+fn random_uniform(pool: &TensorPool, shape: &[usize], low: f32, high: f32, mut rng: Rng) -> Tensor {
+    let scale_value = |val: f32| low + val * (high - low);
+    Tensor::from_simple_fn_in(pool, shape, || scale_value(rng.f32()))
 }
 
+impl_random_operator!(RandomUniform);
+
 #[derive(Debug)]
 pub struct RandomUniformLike {
     pub low: f32,
@@ -50,27 +91,20 @@ pub struct RandomUniformLike {
     pub seed: Option<f32>,
 }
 
-impl Operator for RandomUniformLike {
-    fn name(&self) -> &str {
-        "RandomUniformLike"
-    }
-
-    fn is_deterministic(&self) -> bool {
-        false
-    }
-
-    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+impl RandomUniformLike {
+    fn run_with_rng(
+        &self,
+        pool: &TensorPool,
+        inputs: InputList,
+        rng: Rng,
+    ) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
-        let op = RandomUniform {
-            low: self.low,
-            high: self.high,
-            seed: self.seed,
-            shape: input.shape().to_vec(),
-        };
-        op.run(pool, InputList::new())
+        random_uniform(pool, input.shape(), self.low, self.high, rng).into_op_result()
     }
 }
 
+impl_random_operator!(RandomUniformLike);
+
 #[derive(Debug)]
 pub struct RandomNormal {
     pub mean: f32,
@@ -84,59 +118,172 @@ pub struct RandomNormal {
     pub seed: Option<f32>,
 }
 
-impl Operator for RandomNormal {
-    fn name(&self) -> &str {
-        "RandomNormal"
+impl RandomNormal {
+    fn run_with_rng(
+        &self,
+        pool: &TensorPool,
+        _inputs: InputList,
+        rng: Rng,
+    ) -> Result<OutputList, OpError> {
+        random_normal(pool, &self.shape, self.mean, self.scale, rng).into_op_result()
     }
+}
 
-    fn is_deterministic(&self) -> bool {
-        false
+fn random_normal(
+    pool: &TensorPool,
+    shape: &[usize],
+    mean: f32,
+    scale: f32,
+    mut rng: Rng,
+) -> Tensor {
+    Tensor::from_simple_fn_in(pool, shape, || rng.f32_normal(mean, scale))
+}
+
+impl_random_operator!(RandomNormal);
+
+#[derive(Debug)]
+pub struct RandomNormalLike {
+    pub mean: f32,
+    pub scale: f32,
+
+    /// Random seed. See [RandomUniform::seed].
+    pub seed: Option<f32>,
+}
+
+impl RandomNormalLike {
+    fn run_with_rng(
+        &self,
+        pool: &TensorPool,
+        inputs: InputList,
+        rng: Rng,
+    ) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+        random_normal(pool, input.shape(), self.mean, self.scale, rng).into_op_result()
     }
+}
 
-    fn run(&self, pool: &TensorPool, _inputs: InputList) -> Result<OutputList, OpError> {
-        let shape = self.shape.as_slice();
+impl_random_operator!(RandomNormalLike);
+
+/// Draw samples from the categorical distributions specified by each row of
+/// `input`.
+///
+/// `input` has shape `[batch, class]` and contains unnormalized
+/// log-probabilities for each class. The output has shape
+/// `[batch, sample_size]` and contains the sampled class indices.
+fn multinomial(
+    pool: &TensorPool,
+    input: TensorView,
+    sample_size: usize,
+    mut rng: Rng,
+) -> Result<NdTensor<i32, 2>, OpError> {
+    let input = static_dims!(input, 2, "batch, class")?;
+    let [batch, n_classes] = input.shape();
+    if n_classes == 0 {
+        return Err(OpError::InvalidValue("Input must have at least one class"));
+    }
 
-        let mut rng = if let Some(seed) = self.seed {
-            Rng::with_seed(seed.to_bits() as u64)
-        } else {
-            Rng::new()
-        };
+    let mut output = NdTensor::zeros_in(pool, [batch, sample_size]);
+    let mut cum_probs = Vec::with_capacity(n_classes);
+
+    for (logits, mut samples) in input.inner_iter::<1>().zip(output.inner_iter_mut::<1>()) {
+        // Compute the cumulative sum of unnormalized probabilities. The max
+        // value is subtracted from the logits for numerical stability, as in
+        // softmax.
+        let max_logit = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        cum_probs.clear();
+        let mut sum = 0.;
+        for logit in logits.iter() {
+            sum += (logit - max_logit).exp();
+            cum_probs.push(sum);
+        }
 
-        Tensor::from_simple_fn_in(pool, shape, || rng.f32_normal(self.mean, self.scale))
-            .into_op_result()
+        for sample in samples.iter_mut() {
+            let threshold = rng.f32() * sum;
+            let class = cum_probs
+                .partition_point(|&cum_prob| cum_prob <= threshold)
+                .min(n_classes - 1);
+            *sample = class as i32;
+        }
     }
+
+    Ok(output)
 }
 
+/// Sample class indices from a categorical distribution.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Multinomial.html>.
 #[derive(Debug)]
-pub struct RandomNormalLike {
-    pub mean: f32,
-    pub scale: f32,
+pub struct Multinomial {
+    /// Number of samples to draw for each batch item.
+    pub sample_size: usize,
 
     /// Random seed. See [RandomUniform::seed].
     pub seed: Option<f32>,
 }
 
-impl Operator for RandomNormalLike {
-    fn name(&self) -> &str {
-        "RandomNormalLike"
+impl Multinomial {
+    fn run_with_rng(
+        &self,
+        pool: &TensorPool,
+        inputs: InputList,
+        rng: Rng,
+    ) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        multinomial(pool, input, self.sample_size, rng)
+            .map(|output| output.into_dyn())
+            .into_op_result()
     }
+}
 
-    fn is_deterministic(&self) -> bool {
-        false
+impl_random_operator!(Multinomial);
+
+/// Sample values from Bernoulli distributions with probabilities given by
+/// `input`.
+///
+/// Each output value is `one` with probability equal to the corresponding
+/// input value, or `zero` otherwise.
+fn bernoulli<T: Copy + Default>(
+    pool: &TensorPool,
+    input: TensorView,
+    [zero, one]: [T; 2],
+    mut rng: Rng,
+) -> Tensor<T> {
+    let mut output = Tensor::zeros_in(pool, input.shape());
+    for (out, &prob) in output.iter_mut().zip(input.iter()) {
+        *out = if rng.f32() < prob { one } else { zero };
     }
+    output
+}
 
-    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
-        let input = inputs.require(0)?;
-        let op = RandomNormal {
-            mean: self.mean,
-            scale: self.scale,
-            seed: self.seed,
-            shape: input.shape().to_vec(),
-        };
-        op.run(pool, InputList::new())
+/// Sample values from Bernoulli distributions.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Bernoulli.html>.
+#[derive(Debug)]
+pub struct Bernoulli {
+    /// Data type of the output.
+    pub dtype: DataType,
+
+    /// Random seed. See [RandomUniform::seed].
+    pub seed: Option<f32>,
+}
+
+impl Bernoulli {
+    fn run_with_rng(
+        &self,
+        pool: &TensorPool,
+        inputs: InputList,
+        rng: Rng,
+    ) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        match self.dtype {
+            DataType::Float => bernoulli(pool, input, [0., 1.], rng).into_op_result(),
+            DataType::Int32 => bernoulli(pool, input, [0, 1], rng).into_op_result(),
+        }
     }
 }
 
+impl_random_operator!(Bernoulli);
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -146,9 +293,11 @@ mod tests {
 
     use crate::ops::operators::{FloatOperators, Operators};
     use crate::ops::tests::{new_pool, run_op};
-    use crate::ops::{InputList, Operator};
+    use crate::ops::{DataType, InputList, OpError, Operator};
 
-    use super::{RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
+    use super::{
+        Bernoulli, Multinomial, RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike,
+    };
 
     #[test]
     fn test_random_uniform() {
@@ -361,4 +510,135 @@ mod tests {
         let output: Tensor<f32> = run_op(&op, input.view()).unwrap();
         assert_eq!(output.shape(), &[5, 5]);
     }
+
+    #[test]
+    fn test_multinomial() {
+        let pool = new_pool();
+        let n_samples = 1000;
+        let probs = [0.1, 0.2, 0.7, 0.];
+
+        // Input is unnormalized log-probabilities.
+        let logits = Tensor::from([probs.map(|p: f32| p.ln() + 5.), [0., 0., 0., 0.]]);
+
+        // Use a fixed seed so the frequency checks below are deterministic.
+        let op = Multinomial {
+            sample_size: n_samples,
+            seed: Some(0.5),
+        };
+        let output = op.run(&pool, logits.view().into()).unwrap().remove(0);
+        let output: Tensor<i32> = output.try_into().unwrap();
+        assert_eq!(output.shape(), &[2, n_samples]);
+
+        // Check the frequency of each class is approximately proportional to
+        // its probability.
+        let mut counts = [0; 4];
+        for class in output.slice::<1, _>(0).iter() {
+            counts[*class as usize] += 1;
+        }
+        for (count, prob) in counts.iter().zip(probs) {
+            let freq = *count as f32 / n_samples as f32;
+            assert!((freq - prob).abs() < 0.05, "freq {freq} prob {prob}");
+        }
+        assert_eq!(counts[3], 0);
+
+        // All classes in the second row have equal probability.
+        assert!(output
+            .slice::<1, _>(1)
+            .iter()
+            .all(|class| (0..4).contains(class)));
+    }
+
+    #[test]
+    fn test_multinomial_invalid() {
+        let op = Multinomial {
+            sample_size: 1,
+            seed: None,
+        };
+        let result: Result<Tensor<i32>, _> = run_op(&op, Tensor::<f32>::zeros(&[5]).view());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "input must have 2 dims (batch, class)"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_bernoulli() {
+        let pool = new_pool();
+        let input = Tensor::from([0., 1., 0.5]).broadcast([1000, 3]).to_tensor();
+
+        // Use a fixed seed so the mean check below is deterministic.
+        let op = Bernoulli {
+            dtype: DataType::Float,
+            seed: Some(0.5),
+        };
+        let output = op.run(&pool, input.view().into()).unwrap().remove(0);
+        let output: Tensor<f32> = output.try_into().unwrap();
+        assert_eq!(output.shape(), input.shape());
+
+        let zeros = output.slice::<1, _>((.., 0));
+        assert!(zeros.iter().all(|x| *x == 0.));
+        let ones = output.slice::<1, _>((.., 1));
+        assert!(ones.iter().all(|x| *x == 1.));
+        let halves = output.slice::<1, _>((.., 2));
+        let mean = halves.iter().sum::<f32>() / halves.len() as f32;
+        assert!((mean - 0.5).abs() < 0.05, "mean {mean}");
+
+        let op = Bernoulli {
+            dtype: DataType::Int32,
+            seed: Some(0.5),
+        };
+        let output: Tensor<i32> = run_op(&op, input.view()).unwrap();
+        assert!(output.iter().all(|x| *x == 0 || *x == 1));
+    }
+
+    #[test]
+    fn test_run_with_seed() {
+        let pool = new_pool();
+        // Input used as logits for Multinomial and probabilities for
+        // Bernoulli.
+        let input = Tensor::full(&[1, 32], 0.5);
+
+        let ops: [Box<dyn Operator>; 4] = [
+            Box::new(RandomUniform {
+                low: 0.,
+                high: 1.,
+                shape: vec![10],
+                seed: Some(0.5),
+            }),
+            Box::new(RandomNormalLike {
+                mean: 0.,
+                scale: 1.,
+                seed: Some(0.5),
+            }),
+            Box::new(Multinomial {
+                sample_size: 20,
+                seed: Some(0.5),
+            }),
+            Box::new(Bernoulli {
+                dtype: DataType::Float,
+                seed: Some(0.5),
+            }),
+        ];
+
+        for op in ops {
+            let run = |seed: Option<u64>| {
+                let inputs = input.view().into();
+                match seed {
+                    Some(seed) => op.run_with_seed(&pool, inputs, seed),
+                    None => op.run(&pool, inputs),
+                }
+                .unwrap()
+                .remove(0)
+            };
+
+            // The same run seed should produce the same output.
+            assert_eq!(run(Some(1)), run(Some(1)));
+
+            // The run seed should take precedence over the op's seed.
+            assert_ne!(run(Some(1)), run(None));
+            assert_ne!(run(Some(1)), run(Some(2)));
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::iter::zip;
+use std::mem::MaybeUninit;
 
 use rten_tensor;
 use rten_tensor::prelude::*;
@@ -8,6 +9,7 @@ use rten_tensor::{DynIndices, NdTensor, NdTensorView, SliceItem, Tensor, TensorV
 
 use crate::number::Identities;
 use crate::ops::layout::squeeze_in_place;
+use crate::ops::unary_elementwise::AbsValue;
 use crate::ops::{
     resolve_axes, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, OutputList,
 };
@@ -16,11 +18,15 @@ use crate::tensor_pool::TensorPool;
 
 /// Compute the indices of the max elements along an axis, according to a
 /// comparison function `compare`.
+///
+/// If there are multiple max elements in a lane, this returns the index of
+/// the first one, or the last if `select_last_index` is true.
 fn select_max_index<T, Cmp: Fn(&T, &T) -> std::cmp::Ordering>(
     pool: &TensorPool,
     input: TensorView<T>,
     axis: isize,
     keep_dims: bool,
+    select_last_index: bool,
     compare: Cmp,
 ) -> Result<Tensor<i32>, OpError> {
     let resolved_axis = resolve_axis(input.ndim(), axis)?;
@@ -40,8 +46,18 @@ fn select_max_index<T, Cmp: Fn(&T, &T) -> std::cmp::Ordering>(
 
     if !input.is_empty() {
         for slice in input.lanes(resolved_axis) {
-            let (index, _) = slice.enumerate().max_by(|a, b| compare(a.1, b.1)).unwrap(); // Ok because we checked tensor is not empty.
-            reduced_data.push(index as i32);
+            let mut items = slice.enumerate();
+            let (mut max_index, mut max_val) = items.next().unwrap(); // Ok because we checked tensor is not empty.
+            for (index, val) in items {
+                match compare(val, max_val) {
+                    Ordering::Greater => {}
+                    Ordering::Equal if select_last_index => {}
+                    _ => continue,
+                }
+                max_index = index;
+                max_val = val;
+            }
+            reduced_data.push(max_index as i32);
         }
     }
 
@@ -59,19 +75,25 @@ fn select_max_index<T, Cmp: Fn(&T, &T) -> std::cmp::Ordering>(
 /// Return the index of the maximum value along a given axis.
 ///
 /// NaN values are propagated by treating NaNs as greater than other values.
+/// If the maximum occurs multiple times, the index of the first occurrence is
+/// returned, unless `select_last_index` is true.
 pub fn arg_max<T: Copy + PartialOrd>(
     pool: &TensorPool,
     input: TensorView<T>,
     axis: isize,
     keep_dims: bool,
+    select_last_index: bool,
 ) -> Result<Tensor<i32>, OpError> {
-    select_max_index(pool, input, axis, keep_dims, |a, b| cmp_nan_greater(*a, *b))
+    select_max_index(pool, input, axis, keep_dims, select_last_index, |a, b| {
+        cmp_nan_greater(*a, *b)
+    })
 }
 
 #[derive(Debug)]
 pub struct ArgMax {
     pub axis: isize,
     pub keep_dims: bool,
+    pub select_last_index: bool,
 }
 
 impl Operator for ArgMax {
@@ -81,31 +103,47 @@ impl Operator for ArgMax {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require_as::<f32>(0)?;
-        arg_max(pool, input, self.axis, self.keep_dims).into_op_result()
+        arg_max(
+            pool,
+            input,
+            self.axis,
+            self.keep_dims,
+            self.select_last_index,
+        )
+        .into_op_result()
     }
 }
 
 /// Return the index of the minimum value along a given axis.
 ///
 /// NaN values are propagated by treating NaNs as smaller than other values.
+/// If the minimum occurs multiple times, the index of the first occurrence is
+/// returned, unless `select_last_index` is true.
 pub fn arg_min<T: Copy + PartialOrd>(
     pool: &TensorPool,
     input: TensorView<T>,
     axis: isize,
     keep_dims: bool,
+    select_last_index: bool,
 ) -> Result<Tensor<i32>, OpError> {
-    select_max_index(pool, input, axis, keep_dims, |a, b| {
-        match a.partial_cmp(b) {
+    select_max_index(
+        pool,
+        input,
+        axis,
+        keep_dims,
+        select_last_index,
+        |a, b| match a.partial_cmp(b) {
             Some(ordering) => ordering.reverse(),
             None => cmp_nan_greater(a, b),
-        }
-    })
+        },
+    )
 }
 
 #[derive(Debug)]
 pub struct ArgMin {
     pub axis: isize,
     pub keep_dims: bool,
+    pub select_last_index: bool,
 }
 
 impl Operator for ArgMin {
@@ -115,28 +153,56 @@ impl Operator for ArgMin {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require_as::<f32>(0)?;
-        arg_min(pool, input, self.axis, self.keep_dims).into_op_result()
+        arg_min(
+            pool,
+            input,
+            self.axis,
+            self.keep_dims,
+            self.select_last_index,
+        )
+        .into_op_result()
     }
 }
 
+/// Compute the cumulative sum of elements along an axis.
+///
+/// If `exclusive` is true, each output excludes the corresponding input
+/// element. If `reverse` is true, sums are accumulated from the end of the
+/// axis towards the start.
 pub fn cum_sum<T: Copy + Default + Identities + std::ops::AddAssign>(
     pool: &TensorPool,
     input: TensorView<T>,
     axis: isize,
+    exclusive: bool,
+    reverse: bool,
 ) -> Result<Tensor<T>, OpError> {
     let resolved_axis = resolve_axis(input.ndim(), axis)?;
     let mut output = Tensor::uninit_in(pool, input.shape());
 
     let mut n_init = 0;
+    let mut cum_sum_lane =
+        |xs: &mut dyn Iterator<Item = &T>, ys: &mut dyn Iterator<Item = &mut MaybeUninit<T>>| {
+            let mut cum_sum = T::zero();
+            for (x, y) in zip(xs, ys) {
+                if exclusive {
+                    y.write(cum_sum);
+                    cum_sum += *x;
+                } else {
+                    cum_sum += *x;
+                    y.write(cum_sum);
+                }
+                n_init += 1;
+            }
+        };
+
     if !input.is_empty() {
-        for (in_slice, out_slice) in
+        for (mut in_slice, mut out_slice) in
             zip(input.lanes(resolved_axis), output.lanes_mut(resolved_axis))
         {
-            let mut cum_sum = T::zero();
-            for (x, y) in zip(in_slice, out_slice) {
-                cum_sum += *x;
-                y.write(cum_sum);
-                n_init += 1;
+            if reverse {
+                cum_sum_lane(&mut in_slice.rev(), &mut out_slice.rev());
+            } else {
+                cum_sum_lane(&mut in_slice, &mut out_slice);
             }
         }
     }
@@ -147,8 +213,11 @@ pub fn cum_sum<T: Copy + Default + Identities + std::ops::AddAssign>(
     Ok(output)
 }
 
-#[derive(Debug)]
-pub struct CumSum {}
+#[derive(Debug, Default)]
+pub struct CumSum {
+    pub exclusive: bool,
+    pub reverse: bool,
+}
 
 impl Operator for CumSum {
     fn name(&self) -> &str {
@@ -159,8 +228,12 @@ impl Operator for CumSum {
         let input = inputs.require(0)?;
         let axis: i32 = inputs.require_as_scalar(1)?;
         match input {
-            Input::IntTensor(input) => cum_sum(pool, input, axis as isize).into_op_result(),
-            Input::FloatTensor(input) => cum_sum(pool, input, axis as isize).into_op_result(),
+            Input::IntTensor(input) => {
+                cum_sum(pool, input, axis as isize, self.exclusive, self.reverse).into_op_result()
+            }
+            Input::FloatTensor(input) => {
+                cum_sum(pool, input, axis as isize, self.exclusive, self.reverse).into_op_result()
+            }
         }
     }
 }
@@ -451,6 +524,133 @@ impl Operator for ReduceL2 {
     }
 }
 
+pub fn reduce_log_sum(
+    pool: &TensorPool,
+    input: TensorView,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+) -> Result<Tensor, OpError> {
+    struct LogSumReducer {}
+    impl Reducer<f32> for LogSumReducer {
+        fn reduce<I: ExactSizeIterator<Item = f32>>(&self, iter: I) -> f32 {
+            iter_sum(iter).ln()
+        }
+
+        fn reduce_slice(&self, slice: &[f32]) -> f32 {
+            slice_sum(slice).ln()
+        }
+    }
+
+    reduce(pool, input, axes, keep_dims, LogSumReducer {})
+}
+
+#[derive(Debug)]
+pub struct ReduceLogSum {
+    pub axes: Option<Vec<i32>>,
+    pub keep_dims: bool,
+}
+
+impl Operator for ReduceLogSum {
+    fn name(&self) -> &str {
+        "ReduceLogSum"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let axes = get_axes(&inputs, &self.axes)?;
+        reduce_log_sum(
+            pool,
+            input,
+            axes.as_ref().map(|axis| &axis[..]),
+            self.keep_dims,
+        )
+        .into_op_result()
+    }
+}
+
+/// Reduces axes of a tensor by computing `log(sum(exp(x)))`.
+///
+/// This is computed as `max(x) + log(sum(exp(x - max(x))))` to avoid overflow
+/// for large inputs.
+pub fn reduce_log_sum_exp(
+    pool: &TensorPool,
+    input: TensorView,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+) -> Result<Tensor, OpError> {
+    struct LogSumExpReducer {}
+    impl Reducer<f32> for LogSumExpReducer {
+        fn reduce<I: ExactSizeIterator<Item = f32>>(&self, iter: I) -> f32 {
+            // Single-pass version of `reduce_slice`, which rescales the sum
+            // whenever the max increases.
+            let mut max = f32::NEG_INFINITY;
+            let mut sum_exp = 0.;
+            for x in iter {
+                if x.is_nan() {
+                    return x;
+                }
+                if x > max {
+                    sum_exp = sum_exp * (max - x).exp() + 1.;
+                    max = x;
+                } else {
+                    sum_exp += (x - max).exp();
+                }
+            }
+
+            if !max.is_finite() {
+                return max;
+            }
+            max + sum_exp.ln()
+        }
+
+        fn reduce_slice(&self, slice: &[f32]) -> f32 {
+            // Find the max value, propagating NaNs.
+            let max = slice.iter().fold(f32::NEG_INFINITY, |max, &x| {
+                if x.is_nan() || x > max {
+                    x
+                } else {
+                    max
+                }
+            });
+
+            // If the max is infinite or NaN, then so is the result. Handling
+            // this separately avoids computing `inf - inf`.
+            if !max.is_finite() {
+                return max;
+            }
+
+            let sum_exp = iter_sum(slice.iter().map(|x| (x - max).exp()));
+            max + sum_exp.ln()
+        }
+    }
+
+    reduce(pool, input, axes, keep_dims, LogSumExpReducer {})
+}
+
+#[derive(Debug)]
+pub struct ReduceLogSumExp {
+    pub axes: Option<Vec<i32>>,
+    pub keep_dims: bool,
+}
+
+impl Operator for ReduceLogSumExp {
+    fn name(&self) -> &str {
+        "ReduceLogSumExp"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let axes = get_axes(&inputs, &self.axes)?;
+        reduce_log_sum_exp(
+            pool,
+            input,
+            axes.as_ref().map(|axis| &axis[..]),
+            self.keep_dims,
+        )
+        .into_op_result()
+    }
+}
+
 macro_rules! dispatch_reduce_op {
     ($pool:expr, $input:expr, $reduce_op:ident, $axes:expr, $keep_dims:expr) => {
         match $input {
@@ -472,7 +672,41 @@ macro_rules! dispatch_reduce_op {
     };
 }
 
-fn is_nan<T: PartialOrd>(a: &T) -> bool {
+pub fn reduce_l1<T: Copy + Default + AbsValue + std::ops::Add<T, Output = T>>(
+    pool: &TensorPool,
+    input: TensorView<T>,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+) -> Result<Tensor<T>, OpError> {
+    struct L1Reducer {}
+    impl<T: Copy + Default + AbsValue + std::ops::Add<T, Output = T>> Reducer<T> for L1Reducer {
+        fn reduce<I: ExactSizeIterator<Item = T>>(&self, iter: I) -> T {
+            iter_sum(iter.map(|x| x.abs()))
+        }
+    }
+
+    reduce(pool, input, axes, keep_dims, L1Reducer {})
+}
+
+#[derive(Debug)]
+pub struct ReduceL1 {
+    pub axes: Option<Vec<i32>>,
+    pub keep_dims: bool,
+}
+
+impl Operator for ReduceL1 {
+    fn name(&self) -> &str {
+        "ReduceL1"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require(0)?;
+        let axes = get_axes(&inputs, &self.axes)?;
+        dispatch_reduce_op!(pool, input, reduce_l1, axes, self.keep_dims)
+    }
+}
+
+pub(crate) fn is_nan<T: PartialOrd>(a: &T) -> bool {
     a.partial_cmp(a).is_none()
 }
 
@@ -812,11 +1046,12 @@ mod tests {
     use rten_tensor::test_util::{eq_with_nans, expect_equal};
     use rten_tensor::{NdTensor, Tensor};
 
-    use crate::ops::tests::{new_pool, run_op};
+    use crate::ops::tests::{expect_eq_1e4, new_pool, run_op};
     use crate::ops::{
-        arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_max, reduce_mean, reduce_min,
-        reduce_prod, reduce_sum, reduce_sum_square, topk, OpError, Operator, ReduceL2, ReduceMax,
-        ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare,
+        arg_max, arg_min, cum_sum, nonzero, reduce_l1, reduce_l2, reduce_log_sum,
+        reduce_log_sum_exp, reduce_max, reduce_mean, reduce_min, reduce_prod, reduce_sum,
+        reduce_sum_square, topk, OpError, Operator, ReduceL2, ReduceMax, ReduceMean, ReduceMin,
+        ReduceProd, ReduceSum, ReduceSumSquare,
     };
 
     #[test]
@@ -825,11 +1060,25 @@ mod tests {
 
         // Reduce a simple vector.
         let probs = Tensor::from([0.1, 0.5, 0.2, 0.9, 0.01, 0.6]);
-        let class = arg_max(&pool, probs.view(), 0, false /* keep_dims */).unwrap();
+        let class = arg_max(
+            &pool,
+            probs.view(),
+            0,
+            false, /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
         assert_eq!(class.item(), Some(&3));
 
         // Same, but keep dims
-        let class = arg_max(&pool, probs.view(), 0, true /* keep_dims */).unwrap();
+        let class = arg_max(
+            &pool,
+            probs.view(),
+            0,
+            true,  /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
         assert_eq!(class.shape(), &[1]);
         assert_eq!(class.to_vec(), &[3]);
 
@@ -845,24 +1094,51 @@ mod tests {
                 0.1, 0.01, 0.2, // Fourth item
             ],
         );
-        let seq_classes = arg_max(&pool, seq_probs.view(), 2, false /* keep_dims */).unwrap();
+        let seq_classes = arg_max(
+            &pool,
+            seq_probs.view(),
+            2,
+            false, /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
         assert_eq!(seq_classes.shape(), &[1, 4]);
         assert_eq!(seq_classes.to_vec(), &[2, 0, 1, 2]);
 
         // Same, but keep dims
-        let seq_classes = arg_max(&pool, seq_probs.view(), 2, true /* keep_dims */).unwrap();
+        let seq_classes = arg_max(
+            &pool,
+            seq_probs.view(),
+            2,
+            true,  /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
         assert_eq!(seq_classes.shape(), &[1, 4, 1]);
         assert_eq!(seq_classes.to_vec(), &[2, 0, 1, 2]);
 
         // Empty tensor, axis is a non-zero-sized dim
         let empty = Tensor::<i32>::from_data(&[10, 0, 5], vec![]);
-        let result = arg_max(&pool, empty.view(), 0, false /* keep_dims */).unwrap();
+        let result = arg_max(
+            &pool,
+            empty.view(),
+            0,
+            false, /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
         assert_eq!(result.shape(), &[0, 5]);
         assert_eq!(result.to_vec(), &[] as &[i32]);
 
         // Empty tensor, axis is a zero-sized dim
         let empty = Tensor::<i32>::from_data(&[10, 0, 5], vec![]);
-        let result = arg_max(&pool, empty.view(), 1, false /* keep_dims */);
+        let result = arg_max(
+            &pool,
+            empty.view(),
+            1,
+            false, /* keep_dims */
+            false, /* select_last_index */
+        );
         assert_eq!(
             result.err(),
             Some(OpError::InvalidValue(
@@ -877,7 +1153,14 @@ mod tests {
     fn test_arg_min() {
         let pool = new_pool();
         let probs = Tensor::from([0.1, 0.5, 0.2, 0.9, 0.01, 0.6]);
-        let class = arg_min(&pool, probs.view(), 0, false /* keep_dims */).unwrap();
+        let class = arg_min(
+            &pool,
+            probs.view(),
+            0,
+            false, /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
         assert_eq!(class.item(), Some(&4));
     }
 
@@ -889,29 +1172,104 @@ mod tests {
     fn test_arg_min_max_nan() {
         let pool = new_pool();
         let probs = Tensor::from([0.1, 0.5, f32::NAN, 0.9, 0.01, 0.6]);
-        let min_idx = arg_min(&pool, probs.view(), 0, false /* keep_dims */).unwrap();
-        let max_idx = arg_max(&pool, probs.view(), 0, false /* keep_dims */).unwrap();
+        let min_idx = arg_min(
+            &pool,
+            probs.view(),
+            0,
+            false, /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
+        let max_idx = arg_max(
+            &pool,
+            probs.view(),
+            0,
+            false, /* keep_dims */
+            false, /* select_last_index */
+        )
+        .unwrap();
         assert_eq!(min_idx.item(), Some(&2));
         assert_eq!(max_idx.item(), Some(&2));
     }
 
+    #[test]
+    fn test_arg_min_max_select_last_index() {
+        let pool = new_pool();
+        let values = Tensor::from([1., 3., 0., 3., 0.]);
+
+        let max_idx = arg_max(
+            &pool,
+            values.view(),
+            0,
+            false,
+            false, /* select_last_index */
+        );
+        assert_eq!(max_idx.unwrap().item(), Some(&1));
+        let max_idx = arg_max(
+            &pool,
+            values.view(),
+            0,
+            false,
+            true, /* select_last_index */
+        );
+        assert_eq!(max_idx.unwrap().item(), Some(&3));
+
+        let min_idx = arg_min(
+            &pool,
+            values.view(),
+            0,
+            false,
+            false, /* select_last_index */
+        );
+        assert_eq!(min_idx.unwrap().item(), Some(&2));
+        let min_idx = arg_min(
+            &pool,
+            values.view(),
+            0,
+            false,
+            true, /* select_last_index */
+        );
+        assert_eq!(min_idx.unwrap().item(), Some(&4));
+    }
+
     #[test]
     fn test_cum_sum() {
         let pool = new_pool();
         let elements = Tensor::from_vec((0..=5).collect());
-        let sums = cum_sum(&pool, elements.view(), 0).unwrap();
+        let sums = cum_sum(
+            &pool,
+            elements.view(),
+            0,
+            false, /* exclusive */
+            false, /* reverse */
+        )
+        .unwrap();
         assert_eq!(sums.shape(), &[6]);
         assert_eq!(sums.to_vec(), &[0, 1, 3, 6, 10, 15]);
 
         let elements = Tensor::from_data(&[1, 4, 4], vec![1; 16]);
-        let sums = cum_sum(&pool, elements.view(), 1).unwrap();
+        let sums = cum_sum(
+            &pool,
+            elements.view(),
+            1,
+            false, /* exclusive */
+            false, /* reverse */
+        )
+        .unwrap();
         assert_eq!(sums.shape(), &[1, 4, 4]);
         assert_eq!(
             sums.to_vec(),
             &[1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4]
         );
 
-        let sums = cum_sum(&pool, elements.view(), -1).unwrap();
+        let sums = cum_sum(
+            &pool,
+            elements.view(),
+            -1,
+            false, /* exclusive */
+            false, /* reverse */
+        )
+        .unwrap();
         assert_eq!(sums.shape(), &[1, 4, 4]);
         assert_eq!(
             sums.to_vec(),
@@ -919,11 +1277,38 @@ mod tests {
         );
 
         let elements: Tensor<f32> = Tensor::from([0.; 0]);
-        let sums = cum_sum(&pool, elements.view(), 0).unwrap();
+        let sums = cum_sum(
+            &pool,
+            elements.view(),
+            0,
+            false, /* exclusive */
+            false, /* reverse */
+        )
+        .unwrap();
         assert_eq!(sums.shape(), &[0]);
         assert_eq!(sums.to_vec(), &[] as &[f32]);
     }
 
+    #[test]
+    fn test_cum_sum_exclusive_reverse() {
+        let pool = new_pool();
+        let elements = Tensor::from([1, 2, 3, 4]);
+
+        let sums = cum_sum(&pool, elements.view(), 0, true /* exclusive */, false).unwrap();
+        assert_eq!(sums.to_vec(), &[0, 1, 3, 6]);
+
+        let sums = cum_sum(&pool, elements.view(), 0, false, true /* reverse */).unwrap();
+        assert_eq!(sums.to_vec(), &[10, 9, 7, 4]);
+
+        let sums = cum_sum(&pool, elements.view(), 0, true, true).unwrap();
+        assert_eq!(sums.to_vec(), &[9, 7, 4, 0]);
+
+        // Reverse along a non-contiguous axis.
+        let elements = Tensor::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let sums = cum_sum(&pool, elements.view(), 0, false, true /* reverse */).unwrap();
+        assert_eq!(sums.to_vec(), &[9., 12., 8., 10., 5., 6.]);
+    }
+
     #[test]
     fn test_nonzero() {
         let pool = new_pool();
@@ -1000,6 +1385,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reduce_l1() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from([[1., -2.], [-3., 4.]]);
+
+        let result = reduce_l1(&pool, input.view(), Some(&[1]), false /* keep_dims */)?;
+        expect_equal(&result, &Tensor::from([3., 7.]))?;
+
+        let input = Tensor::from([[1, -2], [-3, 4]]);
+        let result = reduce_l1(&pool, input.view(), None, false /* keep_dims */)?;
+        assert_eq!(result.item(), Some(&10));
+
+        Ok(())
+    }
+
     #[test]
     fn test_reduce_l2() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1026,6 +1426,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reduce_log_sum() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from([[1., 2.], [3., 4.]]);
+        let result = reduce_log_sum(&pool, input.view(), Some(&[1]), true /* keep_dims */)?;
+        expect_eq_1e4(&result, &Tensor::from([[3f32.ln()], [7f32.ln()]]))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduce_log_sum_exp() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+
+        let input = Tensor::from([[0., 1., 2.], [-1., 0., 1.]]);
+        let expected = Tensor::from([2.407606, 1.407606]);
+        let result = reduce_log_sum_exp(&pool, input.view(), Some(&[1]), false)?;
+        expect_eq_1e4(&result, &expected)?;
+
+        // Reduce along a non-innermost axis.
+        let expected = Tensor::from([0.313262, 1.313262, 2.313262]);
+        let result = reduce_log_sum_exp(&pool, input.view(), Some(&[0]), false)?;
+        expect_eq_1e4(&result, &expected)?;
+
+        // Large values which would overflow if computed naively.
+        let input = Tensor::from([1000., 1000.]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false)?;
+        expect_eq_1e4(&result, &Tensor::from_scalar(1000. + 2f32.ln()))?;
+
+        // Non-finite values.
+        let input = Tensor::from([f32::NEG_INFINITY, f32::NEG_INFINITY]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false)?;
+        assert_eq!(result.item(), Some(&f32::NEG_INFINITY));
+
+        let input = Tensor::from([1., f32::INFINITY]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false)?;
+        assert_eq!(result.item(), Some(&f32::INFINITY));
+
+        let input = Tensor::from([1., f32::NAN, 2.]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false)?;
+        assert!(result.item().unwrap().is_nan());
+
+        // Non-finite values along a non-innermost axis.
+        let input = Tensor::from([
+            [1000., f32::NEG_INFINITY, 1., 1.],
+            [1000., f32::NEG_INFINITY, f32::INFINITY, f32::NAN],
+        ]);
+        let result = reduce_log_sum_exp(&pool, input.view(), Some(&[0]), false)?;
+        assert!((result[[0]] - (1000. + 2f32.ln())).abs() < 1e-4);
+        assert_eq!(result[[1]], f32::NEG_INFINITY);
+        assert_eq!(result[[2]], f32::INFINITY);
+        assert!(result[[3]].is_nan());
+
+        Ok(())
+    }
+
     #[test]
     fn test_reduce_mean() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -8,7 +8,7 @@ use rten_tensor::{NdTensor, NdTensorView, NdTensorViewMut, Tensor, TensorView};
 
 use crate::iter_util::range_chunks;
 use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, OutputList};
-use crate::tensor_pool::TensorPool;
+use crate::tensor_pool::{AutoReturn, TensorPool};
 use crate::{check_dims, static_dims};
 
 /// Specifies an output size for a resize operation.
@@ -82,23 +82,10 @@ fn lerp(a: f32, b: f32, weight: f32) -> f32 {
     (1. - weight) * a + weight * b
 }
 
-/// Resize a group of channels in a CHW tensor using nearest neighbor resizing.
-///
-/// This initializes all elements of `output`.
-fn nearest_resize(
-    input: NdTensorView<f32, 3>,
-    mut output: NdTensorViewMut<MaybeUninit<f32>, 3>,
-    mode: NearestMode,
-    coord_mode: CoordTransformMode,
-) {
-    let [chans, rows, cols] = output.shape();
-    let [_, in_rows, in_cols] = input.shape();
-
-    // Scale factors to map output coords to input coords.
-    let inv_scale_y = in_rows as f32 / rows as f32;
-    let inv_scale_x = in_cols as f32 / cols as f32;
-
-    let round_coord = |coord: f32| match mode {
+/// Map a fractional input coordinate to an image coordinate for nearest
+/// neighbor resizing.
+fn round_coord(coord: f32, mode: NearestMode) -> usize {
+    match mode {
         NearestMode::Ceil => coord.ceil() as usize,
         NearestMode::Floor => coord as usize,
 
@@ -118,7 +105,26 @@ fn nearest_resize(
                 coord.round() as usize
             }
         }
-    };
+    }
+}
+
+/// Resize a group of channels in a CHW tensor using nearest neighbor resizing.
+///
+/// This initializes all elements of `output`.
+fn nearest_resize(
+    input: NdTensorView<f32, 3>,
+    mut output: NdTensorViewMut<MaybeUninit<f32>, 3>,
+    mode: NearestMode,
+    coord_mode: CoordTransformMode,
+) {
+    let [chans, rows, cols] = output.shape();
+    let [_, in_rows, in_cols] = input.shape();
+
+    // Scale factors to map output coords to input coords.
+    let inv_scale_y = in_rows as f32 / rows as f32;
+    let inv_scale_x = in_cols as f32 / cols as f32;
+
+    let round_coord = |coord: f32| round_coord(coord, mode);
 
     let mut n_init = 0;
     for y in 0..rows {
@@ -240,22 +246,16 @@ pub fn resize_image(input: TensorView, size: [usize; 2]) -> Result<Tensor, OpErr
     )
 }
 
-pub fn resize(
-    pool: &TensorPool,
-    input: TensorView,
-    target: ResizeTarget,
-    mode: ResizeMode,
-    coord_mode: CoordTransformMode,
-    nearest_mode: NearestMode,
-) -> Result<Tensor, OpError> {
+/// Compute the output sizes for a resize of an input with shape `in_shape`.
+fn target_sizes(in_shape: &[usize], target: ResizeTarget) -> Result<NdTensor<i32, 1>, OpError> {
     let sizes: NdTensor<i32, 1> = match target {
-        ResizeTarget::Scales(scales) => zip(input.shape().iter(), scales.iter())
+        ResizeTarget::Scales(scales) => zip(in_shape.iter(), scales.iter())
             .map(|(&in_size, scale)| ((in_size as f32) * scale).floor() as i32)
             .collect(),
         ResizeTarget::Sizes(sizes) => sizes.to_tensor(),
     };
 
-    if sizes.len() != input.ndim() {
+    if sizes.len() != in_shape.len() {
         return Err(OpError::IncompatibleInputShapes(
             "scales/sizes length should equal input rank",
         ));
@@ -264,6 +264,19 @@ pub fn resize(
         return Err(OpError::InvalidValue("scales/sizes must be positive"));
     }
 
+    Ok(sizes)
+}
+
+pub fn resize(
+    pool: &TensorPool,
+    input: TensorView,
+    target: ResizeTarget,
+    mode: ResizeMode,
+    coord_mode: CoordTransformMode,
+    nearest_mode: NearestMode,
+) -> Result<Tensor, OpError> {
+    let sizes = target_sizes(input.shape(), target)?;
+
     // Fall back to a simple copy if this is a no-op resize.
     if input
         .shape()
@@ -323,6 +336,111 @@ pub fn resize(
     Ok(output)
 }
 
+/// Resize an image in channels-last (NHWC) layout.
+///
+/// `target` specifies scales or sizes for the dimensions of the input in NCHW
+/// order, as for the inputs of the `Resize` operator. Only the height and
+/// width can be resized.
+pub fn resize_nhwc(
+    pool: &TensorPool,
+    input: TensorView,
+    target: ResizeTarget,
+    mode: ResizeMode,
+    coord_mode: CoordTransformMode,
+    nearest_mode: NearestMode,
+) -> Result<Tensor, OpError> {
+    let [batch, in_h, in_w, chans] = check_dims!(input, 4, "NHWC");
+    let sizes = target_sizes(&[batch, chans, in_h, in_w], target)?;
+    let [out_batch, out_chans, out_h, out_w] = [0, 1, 2, 3].map(|i| sizes[[i]] as usize);
+    if out_batch != batch || out_chans != chans {
+        return Err(OpError::UnsupportedValue(
+            "only height and width dimensions can be resized",
+        ));
+    }
+
+    // Fall back to a simple copy if this is a no-op resize.
+    if [out_h, out_w] == [in_h, in_w] {
+        return Ok(input.to_tensor_in(pool));
+    }
+
+    let mut output = NdTensor::uninit_in(pool, [batch, out_h, out_w, chans]);
+    if output.is_empty() {
+        // Safety: Empty output is already initialized.
+        return Ok(unsafe { output.assume_init() }.into_dyn());
+    }
+
+    // Use of input pixels below assumes a contiguous input.
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let in_data = input.data().unwrap();
+    let in_pixel = |n: usize, y: usize, x: usize| {
+        let offset = ((n * in_h + y) * in_w + x) * chans;
+        &in_data[offset..][..chans]
+    };
+
+    // Scale factors to map output coords to input coords.
+    let inv_scale_y = in_h as f32 / out_h as f32;
+    let inv_scale_x = in_w as f32 / out_w as f32;
+    let coord_y =
+        |y| input_coord(y, inv_scale_y, coord_mode, in_h, out_h).clamp(0., in_h as f32 - 1.);
+    let coord_x =
+        |x| input_coord(x, inv_scale_x, coord_mode, in_w, out_w).clamp(0., in_w as f32 - 1.);
+
+    let n_init = AtomicUsize::new(0);
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(out_w * chans)
+        .enumerate()
+        .for_each(|(row, out_row)| {
+            let (n, y) = (row / out_h, row % out_h);
+
+            match mode {
+                ResizeMode::Nearest => {
+                    let in_y = round_coord(coord_y(y), nearest_mode);
+                    for (x, out_pixel) in out_row.chunks_mut(chans).enumerate() {
+                        let in_x = round_coord(coord_x(x), nearest_mode);
+                        for (out, &val) in zip(out_pixel, in_pixel(n, in_y, in_x)) {
+                            out.write(val);
+                        }
+                    }
+                }
+                ResizeMode::Linear => {
+                    let in_y = coord_y(y);
+                    let in_y1 = in_y as usize;
+                    let in_y2 = (in_y1 + 1).min(in_h - 1);
+                    let weight_y = in_y - (in_y1 as f32);
+
+                    for (x, out_pixel) in out_row.chunks_mut(chans).enumerate() {
+                        let in_x = coord_x(x);
+                        let in_x1 = in_x as usize;
+                        let in_x2 = (in_x1 + 1).min(in_w - 1);
+                        let weight_x = in_x - (in_x1 as f32);
+
+                        let in_tl = in_pixel(n, in_y1, in_x1);
+                        let in_tr = in_pixel(n, in_y1, in_x2);
+                        let in_bl = in_pixel(n, in_y2, in_x1);
+                        let in_br = in_pixel(n, in_y2, in_x2);
+
+                        for (c, out) in out_pixel.iter_mut().enumerate() {
+                            // Interpolate in X direction
+                            let out_top = lerp(in_tl[c], in_tr[c], weight_x);
+                            let out_bottom = lerp(in_bl[c], in_br[c], weight_x);
+
+                            // Interpolate in Y direction
+                            out.write(lerp(out_top, out_bottom, weight_y));
+                        }
+                    }
+                }
+            }
+            n_init.fetch_add(out_row.len(), Ordering::SeqCst);
+        });
+
+    assert!(n_init.load(Ordering::SeqCst) == output.len());
+    let output = unsafe { output.assume_init() };
+
+    Ok(output.into_dyn())
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub enum ResizeMode {
     #[default]
@@ -363,6 +481,23 @@ where
     Ok(tensor)
 }
 
+/// Get the output scales or sizes from the inputs of a `Resize` operator.
+fn get_resize_target<'a>(inputs: &InputList<'a>) -> Result<ResizeTarget<'a>, OpError> {
+    // The `roi` input is only used if the `coordinate_transformation_mode`
+    // ONNX attr is `tf_crop_and_resize`, which is not currently supported.
+    let _roi = get_optional_input::<f32>(inputs, 1)?;
+
+    let scales = get_optional_input(inputs, 2)?
+        .map(|scales| static_dims!(scales, 1))
+        .transpose()?
+        .map(ResizeTarget::Scales);
+    let sizes = get_optional_input(inputs, 3)?
+        .map(|sizes| static_dims!(sizes, 1))
+        .transpose()?
+        .map(ResizeTarget::Sizes);
+    scales.or(sizes).ok_or(OpError::MissingInputs)
+}
+
 impl Operator for Resize {
     fn name(&self) -> &str {
         "Resize"
@@ -370,22 +505,42 @@ impl Operator for Resize {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require_as(0)?;
+        let target = get_resize_target(&inputs)?;
+
+        resize(
+            pool,
+            input,
+            target,
+            self.mode,
+            self.coord_mode,
+            self.nearest_mode,
+        )
+        .into_op_result()
+    }
+}
 
-        // The `roi` input is only used if the `coordinate_transformation_mode`
-        // ONNX attr is `tf_crop_and_resize`, which is not currently supported.
-        let _roi = get_optional_input::<f32>(&inputs, 1)?;
+/// Variant of [`Resize`] for an input in channels-last (NHWC) layout.
+///
+/// The scales or sizes inputs are specified in NCHW order, as for `Resize`.
+/// This is created by the graph optimizer when running convolution-heavy
+/// subgraphs in channels-last layout.
+#[derive(Debug)]
+pub struct ResizeNhwc {
+    pub mode: ResizeMode,
+    pub coord_mode: CoordTransformMode,
+    pub nearest_mode: NearestMode,
+}
 
-        let scales = get_optional_input(&inputs, 2)?
-            .map(|scales| static_dims!(scales, 1))
-            .transpose()?
-            .map(ResizeTarget::Scales);
-        let sizes = get_optional_input(&inputs, 3)?
-            .map(|sizes| static_dims!(sizes, 1))
-            .transpose()?
-            .map(ResizeTarget::Sizes);
-        let target = scales.or(sizes).ok_or(OpError::MissingInputs)?;
+impl Operator for ResizeNhwc {
+    fn name(&self) -> &str {
+        "ResizeNhwc"
+    }
 
-        resize(
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let target = get_resize_target(&inputs)?;
+
+        resize_nhwc(
             pool,
             input,
             target,
@@ -402,9 +557,11 @@ mod tests {
     use std::error::Error;
 
     use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
     use rten_tensor::{NdTensor, NdTensorView, Tensor};
 
+    use super::resize_nhwc;
     use crate::ops::tests::expect_eq_1e4;
     use crate::ops::tests::new_pool;
     use crate::ops::{
@@ -694,6 +851,72 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resize_nhwc() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+
+        let input = Tensor::rand(&[2, 5, 6, 7], &mut rng);
+        let input_nhwc = input.permuted([0, 2, 3, 1].as_slice()).to_tensor();
+
+        let targets = [
+            vec![2, 5, 12, 14],
+            vec![2, 5, 4, 3],
+            vec![2, 5, 9, 7],
+            vec![2, 5, 6, 7],
+        ];
+        let modes = [
+            (ResizeMode::Nearest, CoordTransformMode::Asymmetric),
+            (ResizeMode::Nearest, CoordTransformMode::HalfPixel),
+            (ResizeMode::Linear, CoordTransformMode::HalfPixel),
+            (ResizeMode::Linear, CoordTransformMode::AlignCorners),
+        ];
+
+        for sizes in targets {
+            for (mode, coord_mode) in modes {
+                let expected = resize(
+                    &pool,
+                    input.view(),
+                    ResizeTarget::Sizes(sizes.as_slice().into()),
+                    mode,
+                    coord_mode,
+                    NearestMode::RoundPreferFloor,
+                )?;
+                let result = resize_nhwc(
+                    &pool,
+                    input_nhwc.view(),
+                    ResizeTarget::Sizes(sizes.as_slice().into()),
+                    mode,
+                    coord_mode,
+                    NearestMode::RoundPreferFloor,
+                )?;
+                expect_eq_1e4(
+                    &result,
+                    &expected.permuted([0, 2, 3, 1].as_slice()).to_tensor(),
+                )?;
+            }
+        }
+
+        // Only the height and width can be resized. Sizes are specified in
+        // NCHW order.
+        let result = resize_nhwc(
+            &pool,
+            input_nhwc.view(),
+            ResizeTarget::Sizes([2, 6, 6, 7].as_slice().into()),
+            ResizeMode::Nearest,
+            CoordTransformMode::HalfPixel,
+            NearestMode::RoundPreferFloor,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::UnsupportedValue(
+                "only height and width dimensions can be resized"
+            ))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_resize_scales_sizes() {
         enum CaseOutput {
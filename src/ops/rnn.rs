@@ -2,15 +2,18 @@ use std::iter::{zip, Rev};
 use std::ops::Range;
 
 use rten_tensor::prelude::*;
-use rten_tensor::{Tensor, TensorView};
+use rten_tensor::{NdTensorView, NdTensorViewMut, Tensor, TensorView, TensorViewMut};
+use smallvec::SmallVec;
 
-use crate::check_dims;
-use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB};
+use crate::gemm::{GemmExecutor, GemmInputA, GemmInputB, PackedBMatrix};
 use crate::ops::{
-    add_in_place, mul_in_place, sigmoid, tanh, InputList, IntoOpResult, OpError, Operator,
-    OutputList,
+    add_in_place, elu_in_place, hard_sigmoid_in_place, leaky_relu_in_place, mul, mul_in_place,
+    relu_in_place, sigmoid_in_place, softplus_in_place, softsign_in_place, tanh_in_place,
+    thresholded_relu_in_place, Input, InputList, IntoOpResult, OpError, Operator, OutputList,
+    PrepackedInput,
 };
 use crate::tensor_pool::{AutoReturn, TensorPool};
+use crate::{check_dims, static_dims};
 
 /// Direction that an RNN operator will traverse the input sequence in.
 #[derive(Copy, Clone, Debug)]
@@ -30,6 +33,219 @@ impl Direction {
     }
 }
 
+/// Activation function applied to the gates of an RNN operator.
+///
+/// See the `activations` attribute of the ONNX
+/// [LSTM](https://onnx.ai/onnx/operators/onnx__LSTM.html) operator.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RnnActivation {
+    /// Computes `alpha * x + beta`.
+    Affine {
+        alpha: f32,
+        beta: f32,
+    },
+    Elu {
+        alpha: f32,
+    },
+    HardSigmoid {
+        alpha: f32,
+        beta: f32,
+    },
+    LeakyRelu {
+        alpha: f32,
+    },
+    Relu,
+    /// Computes `alpha * tanh(beta * x)`.
+    ScaledTanh {
+        alpha: f32,
+        beta: f32,
+    },
+    Sigmoid,
+    Softplus,
+    Softsign,
+    Tanh,
+    ThresholdedRelu {
+        alpha: f32,
+    },
+}
+
+impl RnnActivation {
+    /// Apply the activation function to `input` in place.
+    fn apply(self, mut input: TensorViewMut) {
+        match self {
+            Self::Affine { alpha, beta } => input.apply(|x| alpha * x + beta),
+            Self::Elu { alpha } => elu_in_place(input, alpha),
+            Self::HardSigmoid { alpha, beta } => hard_sigmoid_in_place(input, alpha, beta),
+            Self::LeakyRelu { alpha } => leaky_relu_in_place(input, alpha),
+            Self::Relu => relu_in_place(input),
+            Self::ScaledTanh { alpha, beta } => input.apply(|x| alpha * (beta * x).tanh()),
+            Self::Sigmoid => sigmoid_in_place(input),
+            Self::Softplus => softplus_in_place(input),
+            Self::Softsign => softsign_in_place(input),
+            Self::Tanh => tanh_in_place(input),
+            Self::ThresholdedRelu { alpha } => thresholded_relu_in_place(input, alpha),
+        }
+    }
+}
+
+/// Clip `input` to `[-clip, clip]` and then apply `act` in place.
+fn activate_in_place(mut input: TensorViewMut, act: RnnActivation, clip: Option<f32>) {
+    if let Some(clip) = clip {
+        input.apply(|x| x.clamp(-clip, clip));
+    }
+    act.apply(input);
+}
+
+/// Return a copy of `input` with `clip` and `act` applied.
+///
+/// Activations are applied to a copy because they are slow with
+/// non-contiguous tensors, and gate slices will be non-contiguous if the batch
+/// size is > 1. See https://github.com/robertknight/rten/issues/192.
+fn activate(pool: &TensorPool, input: TensorView, act: RnnActivation, clip: Option<f32>) -> Tensor {
+    let mut output = input.to_tensor_in(pool);
+    activate_in_place(output.view_mut(), act, clip);
+    output
+}
+
+/// Attributes that are shared by the RNN operators ([GRU], [LSTM] and [RNN]).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RnnOptions {
+    /// Activation functions, in the order specified by the operator (eg.
+    /// `f`, `g` and `h` for LSTM).
+    ///
+    /// For bidirectional operators this may contain functions for both
+    /// directions, forwards first, or for one direction in which case they
+    /// are used for both. If empty, the operator's default activations are
+    /// used.
+    pub activations: Vec<RnnActivation>,
+
+    /// Threshold for clipping the inputs of gate activations to
+    /// `[-clip, clip]`.
+    pub clip: Option<f32>,
+
+    /// Use a batch-first layout for inputs and outputs.
+    ///
+    /// This corresponds to `layout=1` in ONNX. The input has shape
+    /// `[batch, seq, input]`, the hidden sequence output has shape
+    /// `[batch, seq, directions, hidden]` and the hidden and cell states have
+    /// shape `[batch, directions, hidden]`.
+    pub batch_first: bool,
+}
+
+impl RnnOptions {
+    /// Return the activation functions to use for direction `dir`.
+    fn activations_for_dir<const N: usize>(
+        &self,
+        dir: usize,
+        num_directions: usize,
+        defaults: [RnnActivation; N],
+    ) -> Result<[RnnActivation; N], OpError> {
+        let acts = match self.activations.len() {
+            0 => return Ok(defaults),
+            len if len == N => &self.activations[..],
+            len if len == N * num_directions => &self.activations[dir * N..(dir + 1) * N],
+            _ => {
+                return Err(OpError::InvalidValue(
+                    "Number of activations does not match operator",
+                ))
+            }
+        };
+        Ok(std::array::from_fn(|i| acts[i]))
+    }
+}
+
+/// Swap the first two dimensions of a tensor to convert between the
+/// batch-first and sequence-first (or direction-first) layouts.
+fn swap_batch_dim(mut x: TensorView, batch_first: bool) -> TensorView {
+    if batch_first {
+        x.move_axis(0, 1);
+    }
+    x
+}
+
+/// Convert the outputs of an RNN operator, which are computed in
+/// sequence-first layout, to batch-first layout if required.
+///
+/// The first output is the hidden sequence with shape
+/// `[seq, directions, batch, hidden]`. The remaining outputs are states with
+/// shape `[directions, batch, hidden]`.
+fn outputs_for_layout(pool: &TensorPool, outputs: Vec<Tensor>, batch_first: bool) -> Vec<Tensor> {
+    if !batch_first {
+        return outputs;
+    }
+    outputs
+        .into_iter()
+        .enumerate()
+        .map(|(i, output)| {
+            let mut view = output.view();
+            if i == 0 {
+                view.move_axis(2, 0);
+            } else {
+                view.move_axis(0, 1);
+            }
+            view.to_tensor_in(pool)
+        })
+        .collect()
+}
+
+/// Validate the `sequence_lens` input of an RNN operator and convert it to
+/// a list of sequence lengths for each batch item.
+fn sequence_lengths(
+    sequence_lens: Option<NdTensorView<i32, 1>>,
+    batch: usize,
+    seq_len: usize,
+) -> Result<Option<Vec<usize>>, OpError> {
+    let Some(sequence_lens) = sequence_lens else {
+        return Ok(None);
+    };
+    if sequence_lens.size(0) != batch {
+        return Err(OpError::InvalidValue(
+            "`sequence_lens` length must match batch size",
+        ));
+    }
+    sequence_lens
+        .iter()
+        .map(|&len| {
+            if len < 0 || len as usize > seq_len {
+                Err(OpError::InvalidValue("Sequence length is out of range"))
+            } else {
+                Ok(len as usize)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+/// Return the batch items whose sequences end before step `seq`.
+///
+/// The states of these items are left unchanged by the step, and their
+/// outputs are zero.
+fn inactive_batch_items(seq_lens: Option<&[usize]>, seq: usize) -> SmallVec<[usize; 4]> {
+    seq_lens
+        .map(|lens| {
+            lens.iter()
+                .enumerate()
+                .filter(|(_, len)| seq >= **len)
+                .map(|(batch, _)| batch)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Restore the states of batch items which are inactive in the current step
+/// to their values before the step.
+fn restore_inactive_states(
+    inactive: &[usize],
+    mut state: NdTensorViewMut<f32, 2>,
+    prev_state: NdTensorView<f32, 2>,
+) {
+    for &batch in inactive {
+        state
+            .slice_mut::<1, _>([batch])
+            .copy_from(&prev_state.slice::<1, _>([batch]));
+    }
+}
+
 /// Forward or backward iterator over values in a range.
 enum Sequence {
     Forward(Range<usize>),
@@ -91,6 +307,53 @@ fn zip4<T1, T2, T3, T4>(
 /// TODO: This value was chosen because it seemed reasonable. It needs tuning.
 const PREPACK_MIN_SEQ_LEN: usize = 5;
 
+/// Weights of an RNN operator which were prepacked by
+/// [`prepack_rnn_weights`].
+#[derive(Clone, Copy, Default)]
+struct PackedRnnWeights<'a> {
+    weights: Option<&'a [PackedBMatrix]>,
+    recurrent_weights: Option<&'a [PackedBMatrix]>,
+}
+
+impl<'a> PackedRnnWeights<'a> {
+    /// Get the prepacked weights from the inputs of an RNN operator.
+    fn from_inputs(inputs: &InputList<'a>) -> PackedRnnWeights<'a> {
+        PackedRnnWeights {
+            weights: inputs.get_prepacked(1).and_then(|p| p.packed_b()),
+            recurrent_weights: inputs.get_prepacked(2).and_then(|p| p.packed_b()),
+        }
+    }
+
+    /// Return the packed, transposed input weights for a direction.
+    fn input(&self, dir: usize) -> Option<&'a PackedBMatrix> {
+        self.weights.and_then(|w| w.get(dir))
+    }
+
+    /// Return the packed, transposed recurrent weights for a direction.
+    fn recurrent(&self, dir: usize) -> Option<&'a PackedBMatrix> {
+        self.recurrent_weights.and_then(|w| w.get(dir))
+    }
+}
+
+/// Pack the input or recurrent weights of an RNN operator, which have shape
+/// `[directions, gates * hidden_size, size]`, for use as the "B" input of
+/// GEMMs. The weights for each direction are transposed and packed
+/// separately.
+fn prepack_rnn_weights(index: usize, input: Input) -> Option<PrepackedInput> {
+    let Input::FloatTensor(weights) = input else {
+        return None;
+    };
+    if !matches!(index, 1 | 2) || weights.ndim() != 3 {
+        return None;
+    }
+    let gemm = GemmExecutor::new();
+    let packed = weights
+        .axis_iter(0)
+        .map(|dir_weights| gemm.prepack_b(dir_weights.nd_view::<2>().transposed()))
+        .collect();
+    Some(PrepackedInput::from_packed_b(packed))
+}
+
 /// Gated Recurrent Unit operator.
 #[derive(Debug)]
 pub struct GRU {
@@ -100,6 +363,8 @@ pub struct GRU {
     /// When computing the output of the hidden gate, apply the linear
     /// transformation before multiplying by the output of the reset gate.
     pub linear_before_reset: bool,
+
+    pub options: RnnOptions,
 }
 
 /// Compute the output for a single GRU layer.
@@ -118,7 +383,13 @@ pub struct GRU {
 /// concatenation of input biases for the update, reset and hidden gates
 /// followed by hidden biases for the same gates.
 ///
+/// `sequence_lens` has shape `[batch]` and specifies the length of each
+/// sequence in the batch. Outputs beyond the end of a sequence are zero.
+///
 /// `initial_hidden` has shape `[directions, batch, hidden_size]`.
+///
+/// The expected shapes of the input and outputs are changed if
+/// [RnnOptions::batch_first] is set.
 pub fn gru(
     pool: &TensorPool,
     direction: Direction,
@@ -126,28 +397,52 @@ pub fn gru(
     weights: TensorView,
     recurrent_weights: TensorView,
     bias: Option<TensorView>,
+    sequence_lens: Option<NdTensorView<i32, 1>>,
     initial_hidden: Option<TensorView>,
     linear_before_reset: bool,
+    options: &RnnOptions,
 ) -> Result<Vec<Tensor>, OpError> {
-    // PyTorch and cuDNN only support the `linear_before_reset=true` case, as
-    // it enables better efficiency. The `linear_before_reset=false` case
-    // matches the paper that introduced the GRU operator.
-    //
-    // See note in https://pytorch.org/docs/stable/generated/torch.nn.GRU.html.
-    if !linear_before_reset {
-        // PyTorch and cuDNN
-        return Err(OpError::UnsupportedValue(
-            "`linear_before_reset=0` is not supported",
-        ));
-    }
+    gru_impl(
+        pool,
+        direction,
+        input,
+        weights,
+        recurrent_weights,
+        bias,
+        sequence_lens,
+        initial_hidden,
+        linear_before_reset,
+        options,
+        PackedRnnWeights::default(),
+    )
+}
+
+/// Variant of [`gru`] which can use prepacked weights.
+fn gru_impl(
+    pool: &TensorPool,
+    direction: Direction,
+    input: TensorView,
+    weights: TensorView,
+    recurrent_weights: TensorView,
+    bias: Option<TensorView>,
+    sequence_lens: Option<NdTensorView<i32, 1>>,
+    initial_hidden: Option<TensorView>,
+    linear_before_reset: bool,
+    options: &RnnOptions,
+    packed_weights: PackedRnnWeights,
+) -> Result<Vec<Tensor>, OpError> {
+    check_dims!(input, 3, "seq, batch, input");
+    check_dims!(initial_hidden?, 3);
+    let input = swap_batch_dim(input, options.batch_first);
+    let initial_hidden = initial_hidden.map(|h| swap_batch_dim(h, options.batch_first));
 
     let [seq_len, batch, _input_size] = check_dims!(input, 3, "seq, batch, input");
     let [_directions, hidden_x3, _input_size] = check_dims!(weights, 3, "dir, hidden x 3, input");
     check_dims!(recurrent_weights, 3);
-    check_dims!(initial_hidden?, 3);
 
     let num_directions = direction.num_directions();
     let hidden_size = hidden_x3 / 3;
+    let seq_lens = sequence_lengths(sequence_lens, batch, seq_len)?;
 
     let mut hidden = initial_hidden
         .map(|t| t.to_tensor_in(pool))
@@ -167,25 +462,41 @@ pub fn gru(
     let mut hidden_scratch =
         Tensor::zeros_in(pool, &[batch, n_gates * hidden_size]).auto_return(pool);
 
+    // Scratch space for output of `(reset_gate * hidden_state) @ hidden_weights`
+    // matmul, used when `linear_before_reset` is false.
+    let mut reset_hidden_scratch = (!linear_before_reset)
+        .then(|| Tensor::zeros_in(pool, &[batch, hidden_size]).auto_return(pool));
+
     let gemm = GemmExecutor::new();
     for dir in 0..num_directions {
+        let [f, g] = options.activations_for_dir(
+            dir,
+            num_directions,
+            [RnnActivation::Sigmoid, RnnActivation::Tanh],
+        )?;
+
         let prepack = seq_len >= PREPACK_MIN_SEQ_LEN;
 
         let input_weights = weights.slice::<2, _>(dir).transposed();
-        let packed_input_weights =
-            prepack.then(|| gemm.prepack_b_in(pool, input_weights).auto_return(pool));
-        let input_weights = packed_input_weights
-            .as_ref()
-            .map(|packed| GemmInputB::Packed(packed))
+        let packed_input_weights = (prepack && packed_weights.input(dir).is_none())
+            .then(|| gemm.prepack_b_in(pool, input_weights).auto_return(pool));
+        let input_weights = packed_weights
+            .input(dir)
+            .or(packed_input_weights.as_deref())
+            .map(GemmInputB::Packed)
             .unwrap_or(GemmInputB::Unpacked(input_weights));
 
         let hidden_weights = recurrent_weights.slice::<2, _>(dir).transposed();
-        let packed_hidden_weights =
-            prepack.then(|| gemm.prepack_b_in(pool, hidden_weights).auto_return(pool));
-        let hidden_weights = packed_hidden_weights
-            .as_ref()
-            .map(|packed| GemmInputB::Packed(packed))
+        let packed_hidden_weights = (prepack && packed_weights.recurrent(dir).is_none())
+            .then(|| gemm.prepack_b_in(pool, hidden_weights).auto_return(pool));
+        let hidden_weights = packed_weights
+            .recurrent(dir)
+            .or(packed_hidden_weights.as_deref())
+            .map(GemmInputB::Packed)
             .unwrap_or(GemmInputB::Unpacked(hidden_weights));
+        let hidden_gate_weights = recurrent_weights
+            .slice::<2, _>((dir, gate_range(HIDDEN_GATE)))
+            .transposed();
 
         let input_bias = bias
             .as_ref()
@@ -196,6 +507,15 @@ pub fn gru(
 
         for seq in sequence_for_dir(direction, dir, seq_len) {
             let in_item = input.slice::<2, _>([seq]);
+
+            let inactive = inactive_batch_items(seq_lens.as_deref(), seq);
+            let prev_hidden = (!inactive.is_empty()).then(|| {
+                hidden
+                    .slice::<2, _>([dir])
+                    .to_tensor_in(pool)
+                    .auto_return(pool)
+            });
+
             let hidden_item = hidden.slice::<2, _>([dir]);
 
             // From the ONNX spec, the intermediate values are computed as:
@@ -204,9 +524,9 @@ pub fn gru(
             //   rt = f(Xt*(Wr^T) + Ht-1*(Rr^T) + Wbr + Rbr)
             //
             //   If `linear_before_reset` is true:
-            //     ht = tanh(dot(input, hidden_w) + rt * (dot(hidden, rec_hidden_w) + rec_hidden_bias) + hidden_bias)
+            //     ht = g(dot(input, hidden_w) + rt * (dot(hidden, rec_hidden_w) + rec_hidden_bias) + hidden_bias)
             //   Else:
-            //     ht = tanh(dot(input, hidden_w) + dot((rt * hidden), rec_hidden_w) + rec_hidden_bias + hidden_bias)
+            //     ht = g(dot(input, hidden_w) + dot((rt * hidden), rec_hidden_w) + rec_hidden_bias + hidden_bias)
             //
             //   Ht = (1 - zt) (.) ht + zt (.) (Ht-1)
             //
@@ -216,12 +536,14 @@ pub fn gru(
             //  - `Xt`, `Ht` are the input and hidden states at time `t`
             //  - `W{z,r,h}` and `R{z,r,h}` are the input and recurrent weights
             //  - `Wb{z,r,h}` and `Rb{z,r,h}` are the input and recurrent biases
-            //  - `f` and `g` are activations. f=sigmoid, g=tanh
+            //  - `f` and `g` are activations. Defaults are f=sigmoid, g=tanh
             //
-            // In the `linear_before_reset=true` case, which is all we currently
-            // support, the matrix multiplications for all gates can be
-            // combined into two: one for `input @ input_weights`, one for
-            // `hidden @ hidden_weights`.
+            // In the `linear_before_reset=true` case, which PyTorch and cuDNN
+            // use, the matrix multiplications for all gates can be combined
+            // into two: one for `input @ input_weights`, one for
+            // `hidden @ hidden_weights`. In the `linear_before_reset=false`
+            // case, which matches the paper that introduced the GRU, an extra
+            // matrix multiplication is needed for the hidden gate.
 
             // Compute `input @ weights + bias` for all gates.
             let gates_row_stride = gates.stride(gates.ndim() - 2);
@@ -265,28 +587,45 @@ pub fn gru(
                 hidden_scratch_reset_update_gates.as_dyn(),
             );
 
-            // Copy gates before applying activation because `sigmoid_in_place`
-            // and `tanh_in_place` are slow with non-contiguous tensors, and
-            // `update_reset_gates` will be non-contiguous if the batch size is
-            // > 1. See https://github.com/robertknight/rten/issues/192.
-            //
             // Note `gate_range` can be still used because the update and reset
             // gates are in the same positions in the `update_reset_gates` slice
             // as `gates`.
-            let update_reset_gates = sigmoid(pool, update_reset_gates.as_dyn()).auto_return(pool);
+            let update_reset_gates =
+                activate(pool, update_reset_gates.as_dyn(), f, options.clip).auto_return(pool);
             let update_gate = update_reset_gates.slice::<2, _>((.., gate_range(UPDATE_GATE)));
             let reset_gate = update_reset_gates.slice::<2, _>((.., gate_range(RESET_GATE)));
 
             // Combine inputs for hidden gate and apply activation.
             let mut hidden_gate_recurrent =
                 hidden_scratch.slice_mut::<2, _>((.., gate_range(HIDDEN_GATE)));
-            mul_in_place(hidden_gate_recurrent.as_dyn_mut(), reset_gate.as_dyn());
+            if let Some(reset_hidden_scratch) = reset_hidden_scratch.as_mut() {
+                // Compute `(rt * Ht-1) @ rec_hidden_w + rec_hidden_bias`.
+                let reset_hidden =
+                    mul(pool, reset_gate.as_dyn(), hidden_item.as_dyn())?.auto_return(pool);
+                let row_stride = reset_hidden_scratch.stride(0);
+                gemm.gemm(
+                    reset_hidden_scratch.data_mut().unwrap(),
+                    row_stride,
+                    GemmInputA::Unpacked(reset_hidden.nd_view()),
+                    GemmInputB::Unpacked(hidden_gate_weights),
+                    1., /* alpha */
+                    0., /* beta */
+                );
+                if let Some(hidden_bias) = hidden_bias {
+                    add_in_place(
+                        reset_hidden_scratch.view_mut(),
+                        hidden_bias.slice::<1, _>(gate_range(HIDDEN_GATE)).as_dyn(),
+                    );
+                }
+                hidden_gate_recurrent.copy_from(&reset_hidden_scratch.nd_view::<2>());
+            } else {
+                mul_in_place(hidden_gate_recurrent.as_dyn_mut(), reset_gate.as_dyn());
+            }
 
             let mut hidden_gate = gates.slice_mut::<2, _>((.., gate_range(HIDDEN_GATE)));
             add_in_place(hidden_gate.as_dyn_mut(), hidden_gate_recurrent.as_dyn());
-
-            // See note above about `sigmoid_in_place`.
-            let hidden_gate = tanh(pool, hidden_gate.as_dyn()).auto_return(pool);
+            let hidden_gate =
+                activate(pool, hidden_gate.as_dyn(), g, options.clip).auto_return(pool);
 
             // Compute next hidden state
             let mut hidden_item = hidden.slice_mut::<2, _>([dir]);
@@ -299,13 +638,23 @@ pub fn gru(
                 *hidden = (1. - update) * hidden_gate + update * (*hidden);
             }
 
-            hidden_seq
-                .slice_mut::<2, _>([seq, dir])
-                .copy_from(&hidden_item);
+            if let Some(prev_hidden) = prev_hidden.as_ref() {
+                restore_inactive_states(&inactive, hidden_item, prev_hidden.nd_view());
+            }
+
+            let mut seq_output = hidden_seq.slice_mut::<2, _>([seq, dir]);
+            seq_output.copy_from(&hidden.slice::<2, _>([dir]));
+            for &batch in &inactive {
+                seq_output.slice_mut::<1, _>([batch]).fill(0.);
+            }
         }
     }
 
-    Ok([hidden_seq, hidden].into())
+    Ok(outputs_for_layout(
+        pool,
+        [hidden_seq, hidden].into(),
+        options.batch_first,
+    ))
 }
 
 impl Operator for GRU {
@@ -318,21 +667,35 @@ impl Operator for GRU {
         let weights = inputs.require_as(1)?;
         let recurrent_weights = inputs.require_as(2)?;
         let bias = inputs.get_as(3)?;
-        let _seq_len = inputs.get_as::<i32>(4)?;
+        let sequence_lens = inputs.get_as::<i32>(4)?;
+        let sequence_lens = sequence_lens
+            .map(|sequence_lens| static_dims!(sequence_lens, 1))
+            .transpose()?;
         let initial_hidden = inputs.get_as(5)?;
 
-        gru(
+        gru_impl(
             pool,
             self.direction,
             input,
             weights,
             recurrent_weights,
             bias,
+            sequence_lens,
             initial_hidden,
             self.linear_before_reset,
+            &self.options,
+            PackedRnnWeights::from_inputs(&inputs),
         )
         .into_op_result()
     }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1, 2]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        prepack_rnn_weights(index, input)
+    }
 }
 
 /// Long Short-Term Memory operator.
@@ -340,6 +703,12 @@ impl Operator for GRU {
 pub struct LSTM {
     pub direction: Direction,
     pub hidden_size: usize,
+
+    /// Couple the input and forget gates, so that the forget gate is computed
+    /// as `1 - input_gate`.
+    pub input_forget: bool,
+
+    pub options: RnnOptions,
 }
 
 /// Compute the output for a single LSTM layer.
@@ -358,8 +727,18 @@ pub struct LSTM {
 /// a concatenation of input biases for the input, output, forget and cell gates
 /// followed by hidden biases for the same gates.
 ///
+/// `sequence_lens` has shape `[batch]` and specifies the length of each
+/// sequence in the batch. Outputs beyond the end of a sequence are zero.
+///
 /// `initial_hidden` has shape `[directions, batch, hidden_size]`.
 /// `initial_cell` has shape `[directions, batch, hidden_size]`.
+///
+/// `peephole` has shape `[directions, 3 * hidden_size]`. The last dimension
+/// is a concatenation of peephole weights for the input, output and forget
+/// gates.
+///
+/// The expected shapes of the input and outputs are changed if
+/// [RnnOptions::batch_first] is set.
 pub fn lstm(
     pool: &TensorPool,
     direction: Direction,
@@ -367,9 +746,53 @@ pub fn lstm(
     weights: TensorView,
     recurrent_weights: TensorView,
     bias: Option<TensorView>,
+    sequence_lens: Option<NdTensorView<i32, 1>>,
     initial_hidden: Option<TensorView>,
     initial_cell: Option<TensorView>,
+    peephole: Option<TensorView>,
+    input_forget: bool,
+    options: &RnnOptions,
 ) -> Result<Vec<Tensor>, OpError> {
+    lstm_impl(
+        pool,
+        direction,
+        input,
+        weights,
+        recurrent_weights,
+        bias,
+        sequence_lens,
+        initial_hidden,
+        initial_cell,
+        peephole,
+        input_forget,
+        options,
+        PackedRnnWeights::default(),
+    )
+}
+
+/// Variant of [`lstm`] which can use prepacked weights.
+fn lstm_impl(
+    pool: &TensorPool,
+    direction: Direction,
+    input: TensorView,
+    weights: TensorView,
+    recurrent_weights: TensorView,
+    bias: Option<TensorView>,
+    sequence_lens: Option<NdTensorView<i32, 1>>,
+    initial_hidden: Option<TensorView>,
+    initial_cell: Option<TensorView>,
+    peephole: Option<TensorView>,
+    input_forget: bool,
+    options: &RnnOptions,
+    packed_weights: PackedRnnWeights,
+) -> Result<Vec<Tensor>, OpError> {
+    check_dims!(input, 3, "seq, batch, input");
+    check_dims!(initial_hidden?, 3);
+    check_dims!(initial_cell?, 3);
+    let input = swap_batch_dim(input, options.batch_first);
+    let initial_hidden = initial_hidden.map(|h| swap_batch_dim(h, options.batch_first));
+    let initial_cell = initial_cell.map(|c| swap_batch_dim(c, options.batch_first));
+
     // TODO - Add validation of the sizes of individual dimensions in the inputs.
     let [seq_len, batch, _input_size] = check_dims!(input, 3, "seq, batch, input");
     let [_directions, hidden_x4, _input_size] = check_dims!(weights, 3, "dir, hidden x 4, input");
@@ -389,8 +812,15 @@ pub fn lstm(
             return Err(OpError::InvalidValue("bias dim 1 must be 8 * hidden_size"));
         }
     }
-    check_dims!(initial_hidden?, 3);
-    check_dims!(initial_cell?, 3);
+    if let Some(peephole) = peephole.as_ref() {
+        check_dims!(peephole, 2);
+        if peephole.size(1) != 3 * hidden_size {
+            return Err(OpError::InvalidValue(
+                "peephole dim 1 must be 3 * hidden_size",
+            ));
+        }
+    }
+    let seq_lens = sequence_lengths(sequence_lens, batch, seq_len)?;
 
     // Contiguous input and bias needed to allow reshaping below.
     let input = input.to_contiguous_in(pool).auto_return(pool);
@@ -420,22 +850,34 @@ pub fn lstm(
     let gate_range = |gate| (gate * hidden_size)..((gate + 1) * hidden_size);
 
     for dir in 0..num_directions {
+        let [f, g, h] = options.activations_for_dir(
+            dir,
+            num_directions,
+            [
+                RnnActivation::Sigmoid,
+                RnnActivation::Tanh,
+                RnnActivation::Tanh,
+            ],
+        )?;
+
         let prepack = seq_len >= PREPACK_MIN_SEQ_LEN;
 
         let input_weights = weights.slice::<2, _>(dir).transposed();
-        let packed_input_weights =
-            prepack.then(|| gemm.prepack_b_in(pool, input_weights).auto_return(pool));
-        let input_weights = packed_input_weights
-            .as_ref()
-            .map(|packed| GemmInputB::Packed(packed))
+        let packed_input_weights = (prepack && packed_weights.input(dir).is_none())
+            .then(|| gemm.prepack_b_in(pool, input_weights).auto_return(pool));
+        let input_weights = packed_weights
+            .input(dir)
+            .or(packed_input_weights.as_deref())
+            .map(GemmInputB::Packed)
             .unwrap_or(GemmInputB::Unpacked(input_weights));
 
         let hidden_weights = recurrent_weights.slice::<2, _>(dir).transposed();
-        let packed_hidden_weights =
-            prepack.then(|| gemm.prepack_b_in(pool, hidden_weights).auto_return(pool));
-        let hidden_weights = packed_hidden_weights
-            .as_ref()
-            .map(|packed| GemmInputB::Packed(packed))
+        let packed_hidden_weights = (prepack && packed_weights.recurrent(dir).is_none())
+            .then(|| gemm.prepack_b_in(pool, hidden_weights).auto_return(pool));
+        let hidden_weights = packed_weights
+            .recurrent(dir)
+            .or(packed_hidden_weights.as_deref())
+            .map(GemmInputB::Packed)
             .unwrap_or(GemmInputB::Unpacked(hidden_weights));
 
         let input_bias = bias
@@ -445,6 +887,12 @@ pub fn lstm(
             .as_ref()
             .map(|b| b.slice::<1, _>((dir, (n_gates * hidden_size)..)));
 
+        // Peephole weights for the input, output and forget gates.
+        let peephole = peephole.as_ref().map(|p| {
+            [0, 1, 2]
+                .map(|gate| p.slice::<1, _>((dir, gate * hidden_size..(gate + 1) * hidden_size)))
+        });
+
         for seq in sequence_for_dir(direction, dir, seq_len) {
             // From the ONNX spec, the intermediate values are computed as:
             //
@@ -461,11 +909,26 @@ pub fn lstm(
             //  - `Xt`, `Ht` and `Ct` are the input, hidden state and cell state at time `t`
             //  - `W{i,o,f,c}` and `R{i,o,f,c}` are the input and recurrent gate weights
             //  - `Wb{i,o,f,c}` and `Rb{i,o,f,c}` are the input and recurrent gate biases
-            //  - `P{i,o,f,c}` are peephole weights. These are not currently
-            //    supported.
-            //  - `f`, `g` and `h` are activations. `f`=sigmoid, `g` and `h`
-            //    are tanh.
+            //  - `P{i,o,f}` are peephole weights
+            //  - `f`, `g` and `h` are activations. The defaults are
+            //    `f`=sigmoid, `g` and `h` = tanh.
+            //
+            // If `input_forget` is set, `ft` is replaced with `1 - it`.
             let in_item = input.slice::<2, _>([seq]);
+
+            let inactive = inactive_batch_items(seq_lens.as_deref(), seq);
+            let prev_state = (!inactive.is_empty()).then(|| {
+                (
+                    hidden
+                        .slice::<2, _>([dir])
+                        .to_tensor_in(pool)
+                        .auto_return(pool),
+                    cell.slice::<2, _>([dir])
+                        .to_tensor_in(pool)
+                        .auto_return(pool),
+                )
+            });
+
             let hidden_item = hidden.slice::<2, _>([dir]);
 
             // Update input, output, forget and cell gates.
@@ -494,21 +957,31 @@ pub fn lstm(
                 add_in_place(gates.view_mut(), hidden_bias.as_dyn());
             }
 
-            // Copy gates to work around `tanh_in_place` and `sigmoid_in_place`
-            // being slow for non-contiguous inputs. See notes in GRU op.
+            // Add peephole connections from the previous cell state to the
+            // input and forget gates.
+            if let Some([peep_input, _, peep_forget]) = peephole.as_ref() {
+                let cell_item = cell.slice::<2, _>([dir]);
+                for (gate, peep) in [(INPUT_GATE, peep_input), (FORGET_GATE, peep_forget)] {
+                    let mut gate = gates.slice_mut::<2, _>((.., gate_range(gate)));
+                    add_peephole(gate.view_mut(), peep.view(), cell_item);
+                }
+            }
+
+            // Apply activations to input, output and forget gates together,
+            // since they use the same activation function.
             let iof_gates = gates.slice::<2, _>((
                 ..,
                 gate_range(INPUT_GATE).start..gate_range(FORGET_GATE).end,
             ));
-            let iof_gates = sigmoid(pool, iof_gates.as_dyn()).auto_return(pool);
+            let iof_gates = activate(pool, iof_gates.as_dyn(), f, options.clip).auto_return(pool);
             let input_gate = iof_gates.slice::<2, _>((.., gate_range(INPUT_GATE)));
             let out_gate = iof_gates.slice::<2, _>((.., gate_range(OUTPUT_GATE)));
             let forget_gate = iof_gates.slice::<2, _>((.., gate_range(FORGET_GATE)));
 
             let cell_gate = gates.slice::<2, _>((.., gate_range(CELL_GATE)));
-            let cell_gate = tanh(pool, cell_gate.as_dyn()).auto_return(pool);
+            let cell_gate = activate(pool, cell_gate.as_dyn(), g, options.clip).auto_return(pool);
 
-            // Update cell and hidden state
+            // Update cell state
             let mut cell_item = cell.slice_mut::<2, _>([dir]);
 
             for (cell, forget_gate, input_gate, cell_gate) in zip4(
@@ -517,23 +990,78 @@ pub fn lstm(
                 input_gate.iter(),
                 cell_gate.iter(),
             ) {
+                let forget_gate = if input_forget {
+                    1. - input_gate
+                } else {
+                    *forget_gate
+                };
                 *cell = forget_gate * *cell + input_gate * cell_gate;
             }
 
+            // If there is a peephole connection from the new cell state to the
+            // output gate, the output gate has to be re-computed.
+            let peephole_out_gate = peephole.as_ref().map(|[_, peep_output, _]| {
+                let mut out_gate = gates
+                    .slice::<2, _>((.., gate_range(OUTPUT_GATE)))
+                    .to_tensor_in(pool);
+                add_peephole(out_gate.view_mut(), peep_output.view(), cell_item.view());
+                activate_in_place(out_gate.as_dyn_mut(), f, options.clip);
+                out_gate.auto_return(pool)
+            });
+            let out_gate = peephole_out_gate
+                .as_ref()
+                .map(|gate| gate.view())
+                .unwrap_or(out_gate);
+
+            // Update hidden state
+            let cell_output = activate(pool, cell_item.as_dyn(), h, None).auto_return(pool);
             let mut hidden_item = hidden.slice_mut::<2, _>([dir]);
             for (hidden, out_gate, cell) in
-                zip3(hidden_item.iter_mut(), out_gate.iter(), cell_item.iter())
+                zip3(hidden_item.iter_mut(), out_gate.iter(), cell_output.iter())
             {
-                *hidden = out_gate * cell.tanh()
+                *hidden = out_gate * cell
+            }
+
+            if let Some((prev_hidden, prev_cell)) = prev_state.as_ref() {
+                restore_inactive_states(&inactive, hidden_item, prev_hidden.nd_view());
+                restore_inactive_states(
+                    &inactive,
+                    cell.slice_mut::<2, _>([dir]),
+                    prev_cell.nd_view(),
+                );
             }
 
-            hidden_seq
-                .slice_mut::<2, _>([seq, dir])
-                .copy_from(&hidden_item);
+            let mut seq_output = hidden_seq.slice_mut::<2, _>([seq, dir]);
+            seq_output.copy_from(&hidden.slice::<2, _>([dir]));
+            for &batch in &inactive {
+                seq_output.slice_mut::<1, _>([batch]).fill(0.);
+            }
         }
     }
 
-    Ok([hidden_seq, hidden, cell].into())
+    Ok(outputs_for_layout(
+        pool,
+        [hidden_seq, hidden, cell].into(),
+        options.batch_first,
+    ))
+}
+
+/// Add `peephole (.) cell` to `gate`.
+///
+/// `gate` and `cell` have shape `[batch, hidden_size]` and `peephole` has
+/// shape `[hidden_size]`.
+fn add_peephole(
+    mut gate: NdTensorViewMut<f32, 2>,
+    peephole: NdTensorView<f32, 1>,
+    cell: NdTensorView<f32, 2>,
+) {
+    for (mut gate_row, cell_row) in zip(gate.inner_iter_mut::<1>(), cell.inner_iter::<1>()) {
+        for (gate, (peephole, cell)) in
+            zip(gate_row.iter_mut(), zip(peephole.iter(), cell_row.iter()))
+        {
+            *gate += peephole * cell;
+        }
+    }
 }
 
 impl Operator for LSTM {
@@ -546,22 +1074,266 @@ impl Operator for LSTM {
         let weights = inputs.require_as(1)?;
         let recurrent_weights = inputs.require_as(2)?;
         let bias = inputs.get_as(3)?;
-        let _seq_len = inputs.get_as::<i32>(4)?;
+        let sequence_lens = inputs.get_as::<i32>(4)?;
+        let sequence_lens = sequence_lens
+            .map(|sequence_lens| static_dims!(sequence_lens, 1))
+            .transpose()?;
         let initial_hidden = inputs.get_as(5)?;
         let initial_cell = inputs.get_as(6)?;
+        let peephole = inputs.get_as(7)?;
 
-        lstm(
+        lstm_impl(
             pool,
             self.direction,
             input,
             weights,
             recurrent_weights,
             bias,
+            sequence_lens,
             initial_hidden,
             initial_cell,
+            peephole,
+            self.input_forget,
+            &self.options,
+            PackedRnnWeights::from_inputs(&inputs),
         )
         .into_op_result()
     }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1, 2]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        prepack_rnn_weights(index, input)
+    }
+}
+
+/// Recurrent Neural Network operator.
+///
+/// This is the basic recurrent layer with no gates.
+#[derive(Debug)]
+pub struct RNN {
+    pub direction: Direction,
+    pub hidden_size: usize,
+    pub options: RnnOptions,
+}
+
+/// Compute the output for a single RNN layer.
+///
+/// `input` has shape [sequence_length, batch, input_size].
+///
+/// `weights` has shape `[directions, hidden_size, input_size]`.
+///
+/// `recurrent_weights` has shape `[directions, hidden_size, hidden_size]`.
+///
+/// `bias` has shape `[directions, 2 * hidden_size]`. The last dimension is a
+/// concatenation of the input and hidden biases.
+///
+/// `sequence_lens` has shape `[batch]` and specifies the length of each
+/// sequence in the batch. Outputs beyond the end of a sequence are zero.
+///
+/// `initial_hidden` has shape `[directions, batch, hidden_size]`.
+///
+/// The expected shapes of the input and outputs are changed if
+/// [RnnOptions::batch_first] is set.
+pub fn rnn(
+    pool: &TensorPool,
+    direction: Direction,
+    input: TensorView,
+    weights: TensorView,
+    recurrent_weights: TensorView,
+    bias: Option<TensorView>,
+    sequence_lens: Option<NdTensorView<i32, 1>>,
+    initial_hidden: Option<TensorView>,
+    options: &RnnOptions,
+) -> Result<Vec<Tensor>, OpError> {
+    rnn_impl(
+        pool,
+        direction,
+        input,
+        weights,
+        recurrent_weights,
+        bias,
+        sequence_lens,
+        initial_hidden,
+        options,
+        PackedRnnWeights::default(),
+    )
+}
+
+/// Variant of [`rnn`] which can use prepacked weights.
+fn rnn_impl(
+    pool: &TensorPool,
+    direction: Direction,
+    input: TensorView,
+    weights: TensorView,
+    recurrent_weights: TensorView,
+    bias: Option<TensorView>,
+    sequence_lens: Option<NdTensorView<i32, 1>>,
+    initial_hidden: Option<TensorView>,
+    options: &RnnOptions,
+    packed_weights: PackedRnnWeights,
+) -> Result<Vec<Tensor>, OpError> {
+    check_dims!(input, 3, "seq, batch, input");
+    check_dims!(initial_hidden?, 3);
+    let input = swap_batch_dim(input, options.batch_first);
+    let initial_hidden = initial_hidden.map(|h| swap_batch_dim(h, options.batch_first));
+
+    let [seq_len, batch, _input_size] = check_dims!(input, 3, "seq, batch, input");
+    let [_directions, hidden_size, _input_size] = check_dims!(weights, 3, "dir, hidden, input");
+    check_dims!(recurrent_weights, 3);
+    if let Some(bias) = bias.as_ref() {
+        check_dims!(bias, 2);
+        if bias.size(1) != 2 * hidden_size {
+            return Err(OpError::InvalidValue("bias dim 1 must be 2 * hidden_size"));
+        }
+    }
+
+    let num_directions = direction.num_directions();
+    let seq_lens = sequence_lengths(sequence_lens, batch, seq_len)?;
+
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+
+    let mut gate = Tensor::zeros_in(pool, &[batch, hidden_size]).auto_return(pool);
+    let mut hidden = initial_hidden
+        .map(|t| t.to_tensor_in(pool))
+        .unwrap_or_else(|| Tensor::zeros_in(pool, &[num_directions, batch, hidden_size]));
+    let mut hidden_seq = Tensor::zeros_in(pool, &[seq_len, num_directions, batch, hidden_size]);
+
+    let gemm = GemmExecutor::new();
+
+    for dir in 0..num_directions {
+        let [f] = options.activations_for_dir(dir, num_directions, [RnnActivation::Tanh])?;
+
+        let prepack = seq_len >= PREPACK_MIN_SEQ_LEN;
+
+        let input_weights = weights.slice::<2, _>(dir).transposed();
+        let packed_input_weights = (prepack && packed_weights.input(dir).is_none())
+            .then(|| gemm.prepack_b_in(pool, input_weights).auto_return(pool));
+        let input_weights = packed_weights
+            .input(dir)
+            .or(packed_input_weights.as_deref())
+            .map(GemmInputB::Packed)
+            .unwrap_or(GemmInputB::Unpacked(input_weights));
+
+        let hidden_weights = recurrent_weights.slice::<2, _>(dir).transposed();
+        let packed_hidden_weights = (prepack && packed_weights.recurrent(dir).is_none())
+            .then(|| gemm.prepack_b_in(pool, hidden_weights).auto_return(pool));
+        let hidden_weights = packed_weights
+            .recurrent(dir)
+            .or(packed_hidden_weights.as_deref())
+            .map(GemmInputB::Packed)
+            .unwrap_or(GemmInputB::Unpacked(hidden_weights));
+
+        let input_bias = bias.as_ref().map(|b| b.slice::<1, _>((dir, ..hidden_size)));
+        let hidden_bias = bias.as_ref().map(|b| b.slice::<1, _>((dir, hidden_size..)));
+
+        for seq in sequence_for_dir(direction, dir, seq_len) {
+            // From the ONNX spec, the hidden state is computed as:
+            //
+            //   Ht = f(Xt*(Wi^T) + Ht-1*(Ri^T) + Wbi + Rbi)
+            //
+            // Where `f` is the activation, tanh by default.
+            let in_item = input.slice::<2, _>([seq]);
+
+            let inactive = inactive_batch_items(seq_lens.as_deref(), seq);
+            let prev_hidden = (!inactive.is_empty()).then(|| {
+                hidden
+                    .slice::<2, _>([dir])
+                    .to_tensor_in(pool)
+                    .auto_return(pool)
+            });
+
+            let hidden_item = hidden.slice::<2, _>([dir]);
+
+            let gate_row_stride = gate.stride(0);
+            gemm.gemm(
+                gate.data_mut().expect("expected contiguous input"),
+                gate_row_stride,
+                GemmInputA::Unpacked(in_item),
+                input_weights,
+                1., /* alpha */
+                0., /* beta */
+            );
+            gemm.gemm(
+                gate.data_mut().expect("expected contiguous input"),
+                gate_row_stride,
+                GemmInputA::Unpacked(hidden_item),
+                hidden_weights,
+                1., /* alpha */
+                1., /* beta */
+            );
+            for bias in [input_bias, hidden_bias].into_iter().flatten() {
+                add_in_place(gate.view_mut(), bias.as_dyn());
+            }
+            activate_in_place(gate.view_mut(), f, options.clip);
+
+            hidden
+                .slice_mut::<2, _>([dir])
+                .copy_from(&gate.nd_view::<2>());
+
+            if let Some(prev_hidden) = prev_hidden.as_ref() {
+                restore_inactive_states(
+                    &inactive,
+                    hidden.slice_mut::<2, _>([dir]),
+                    prev_hidden.nd_view(),
+                );
+            }
+
+            let mut seq_output = hidden_seq.slice_mut::<2, _>([seq, dir]);
+            seq_output.copy_from(&hidden.slice::<2, _>([dir]));
+            for &batch in &inactive {
+                seq_output.slice_mut::<1, _>([batch]).fill(0.);
+            }
+        }
+    }
+
+    Ok(outputs_for_layout(
+        pool,
+        [hidden_seq, hidden].into(),
+        options.batch_first,
+    ))
+}
+
+impl Operator for RNN {
+    fn name(&self) -> &str {
+        "RNN"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let weights = inputs.require_as(1)?;
+        let recurrent_weights = inputs.require_as(2)?;
+        let bias = inputs.get_as(3)?;
+        let sequence_lens = inputs.get_as::<i32>(4)?;
+        let sequence_lens = sequence_lens
+            .map(|sequence_lens| static_dims!(sequence_lens, 1))
+            .transpose()?;
+        let initial_hidden = inputs.get_as(5)?;
+
+        rnn_impl(
+            pool,
+            self.direction,
+            input,
+            weights,
+            recurrent_weights,
+            bias,
+            sequence_lens,
+            initial_hidden,
+            &self.options,
+            PackedRnnWeights::from_inputs(&inputs),
+        )
+        .into_op_result()
+    }
+
+    fn prepack_inputs(&self) -> &[usize] {
+        &[1, 2]
+    }
+
+    fn prepack(&self, index: usize, input: Input) -> Option<PrepackedInput> {
+        prepack_rnn_weights(index, input)
+    }
 }
 
 #[cfg(test)]
@@ -573,11 +1345,14 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::expect_equal;
-    use rten_tensor::Tensor;
+    use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
     use serde_json::Value;
 
+    use super::activate_in_place;
     use crate::ops::tests::new_pool;
-    use crate::ops::{concat, gru, lstm, split, Direction};
+    use crate::ops::{
+        concat, gru, lstm, rnn, split, Direction, OpError, RnnActivation, RnnOptions,
+    };
 
     /// Read a float tensor from a JSON value.
     ///
@@ -613,10 +1388,113 @@ mod tests {
         serde_json::from_reader(reader).unwrap()
     }
 
-    #[derive(Clone, Copy, PartialEq)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
     enum Op {
         Gru,
         Lstm,
+        Rnn,
+    }
+
+    impl Op {
+        fn num_gates(self) -> usize {
+            match self {
+                Op::Gru => 3,
+                Op::Lstm => 4,
+                Op::Rnn => 1,
+            }
+        }
+    }
+
+    /// Inputs for an RNN operator, with random weights.
+    struct OpInputs {
+        /// Input as [seq, batch, feature]
+        input: Tensor,
+
+        /// Input-hidden weights as [direction, num_gates * hidden, feature]
+        weights: Tensor,
+
+        /// Hidden-hidden weights as [direction, num_gates * hidden, hidden]
+        recurrent_weights: Tensor,
+
+        /// Bias as [direction, 2 * num_gates * hidden]
+        bias: Tensor,
+    }
+
+    impl OpInputs {
+        fn random(
+            rng: &mut XorShiftRng,
+            op: Op,
+            dir: Direction,
+            [seq_len, batch, features]: [usize; 3],
+            hidden_size: usize,
+        ) -> OpInputs {
+            let num_gates = op.num_gates();
+            let num_directions = dir.num_directions();
+            OpInputs {
+                input: Tensor::rand(&[seq_len, batch, features], rng).map(|x| x - 0.5),
+                weights: Tensor::rand(&[num_directions, num_gates * hidden_size, features], rng)
+                    .map(|x| x - 0.5),
+                recurrent_weights: Tensor::rand(
+                    &[num_directions, num_gates * hidden_size, hidden_size],
+                    rng,
+                )
+                .map(|x| x - 0.5),
+                bias: Tensor::rand(&[num_directions, 2 * num_gates * hidden_size], rng)
+                    .map(|x| x - 0.5),
+            }
+        }
+
+        /// Run `op` using the default GRU / LSTM specific attributes.
+        fn run(
+            &self,
+            op: Op,
+            dir: Direction,
+            input: TensorView,
+            sequence_lens: Option<NdTensorView<i32, 1>>,
+            options: &RnnOptions,
+        ) -> Vec<Tensor> {
+            let pool = new_pool();
+            match op {
+                Op::Gru => gru(
+                    &pool,
+                    dir,
+                    input,
+                    self.weights.view(),
+                    self.recurrent_weights.view(),
+                    Some(self.bias.view()),
+                    sequence_lens,
+                    None, /* initial_hidden */
+                    true, /* linear_before_reset */
+                    options,
+                ),
+                Op::Lstm => lstm(
+                    &pool,
+                    dir,
+                    input,
+                    self.weights.view(),
+                    self.recurrent_weights.view(),
+                    Some(self.bias.view()),
+                    sequence_lens,
+                    None,  /* initial_hidden */
+                    None,  /* initial_cell */
+                    None,  /* peephole */
+                    false, /* input_forget */
+                    options,
+                ),
+                Op::Rnn => rnn(
+                    &pool,
+                    dir,
+                    input,
+                    self.weights.view(),
+                    self.recurrent_weights.view(),
+                    Some(self.bias.view()),
+                    sequence_lens,
+                    None, /* initial_hidden */
+                    options,
+                ),
+            }
+            .unwrap()
+        }
     }
 
     // Basic test that runs bidirectional RNN operators with random inputs and
@@ -664,14 +1542,23 @@ mod tests {
                 with_hidden_init: false,
                 with_initial_cell: false,
             },
+            Case {
+                op: Op::Rnn,
+                with_bias: true,
+                with_hidden_init: true,
+                with_initial_cell: false,
+            },
+            Case {
+                op: Op::Rnn,
+                with_bias: false,
+                with_hidden_init: false,
+                with_initial_cell: false,
+            },
         ];
 
         let pool = new_pool();
         for case in cases {
-            let num_gates = match case.op {
-                Op::Gru => 3,
-                Op::Lstm => 4,
-            };
+            let num_gates = case.op.num_gates();
 
             let input = Tensor::rand(&[seq_len, batch, features], &mut rng).map(|x| x - 0.5);
             let weights = Tensor::rand(
@@ -700,8 +1587,12 @@ mod tests {
                     weights.view(),
                     recurrent_weights.view(),
                     case.with_bias.then_some(bias.view()),
+                    None, /* sequence_lens */
                     case.with_hidden_init.then_some(initial_hidden.view()),
                     case.with_initial_cell.then_some(initial_cell.view()),
+                    None,  /* peephole */
+                    false, /* input_forget */
+                    &RnnOptions::default(),
                 )
                 .expect("lstm op failed"),
                 Op::Gru => gru(
@@ -711,17 +1602,31 @@ mod tests {
                     weights.view(),
                     recurrent_weights.view(),
                     case.with_bias.then_some(bias.view()),
+                    None, /* sequence_lens */
                     case.with_hidden_init.then_some(initial_hidden.view()),
                     true, /* linear_before_reset */
+                    &RnnOptions::default(),
                 )
                 .expect("gru op failed"),
+                Op::Rnn => rnn(
+                    &pool,
+                    dir,
+                    input.view(),
+                    weights.view(),
+                    recurrent_weights.view(),
+                    case.with_bias.then_some(bias.view()),
+                    None, /* sequence_lens */
+                    case.with_hidden_init.then_some(initial_hidden.view()),
+                    &RnnOptions::default(),
+                )
+                .expect("rnn op failed"),
             };
 
             // Check that outputs have the right shapes.
             assert_eq!(
                 result.len(),
                 match case.op {
-                    Op::Gru => 2,
+                    Op::Gru | Op::Rnn => 2,
                     Op::Lstm => 3,
                 }
             );
@@ -826,6 +1731,14 @@ mod tests {
         ///
         /// Only applicable for LSTM operator.
         initial_cell: Option<Tensor>,
+
+        /// Peephole weights as [direction, 3 * hidden].
+        ///
+        /// Only applicable for LSTM operator.
+        peephole: Option<Tensor>,
+
+        /// Length of each sequence in the batch.
+        sequence_lens: Option<NdTensor<i32, 1>>,
     }
 
     /// Read inputs for a PyTorch reference test for RNN ops from a JSON value.
@@ -836,18 +1749,30 @@ mod tests {
         let is_bidirectional = params.get("weight_ih_l0_reverse").is_some();
 
         let mut input = read_tensor(&case["input"]).expect("failed to read input");
-        input.insert_axis(1); // Add batch dim
+        let is_batched = input.ndim() == 3;
+        if !is_batched {
+            input.insert_axis(1); // Add batch dim
+        }
 
         let mut expected = read_tensor(&case["output"]).expect("failed to read output");
 
-        // Reshape from [seq, dir * hidden_size] to [seq, dir, hidden_size]
-        if is_bidirectional {
+        if is_batched {
+            // Reshape from [seq, batch, dir * hidden_size] to
+            // [seq, dir, batch, hidden_size].
             let es = expected.shape();
-            expected.reshape(&[es[0], 2, es[1] / 2]);
+            let num_dirs = if is_bidirectional { 2 } else { 1 };
+            expected.reshape(&[es[0], es[1], num_dirs, es[2] / num_dirs]);
+            expected.permute(&[0, 2, 1, 3]);
         } else {
-            expected.insert_axis(1);
+            // Reshape from [seq, dir * hidden_size] to [seq, dir, hidden_size]
+            if is_bidirectional {
+                let es = expected.shape();
+                expected.reshape(&[es[0], 2, es[1] / 2]);
+            } else {
+                expected.insert_axis(1);
+            }
+            expected.insert_axis(2); // Add batch dim
         }
-        expected.insert_axis(2); // Add batch dim
 
         let read_param = |name| match op {
             Op::Lstm => reorder_ifco_to_iofc(
@@ -858,6 +1783,7 @@ mod tests {
                 &read_tensor(&params[name]).expect("failed to read weight"),
                 0,
             ),
+            Op::Rnn => read_tensor(&params[name]).expect("failed to read weight"),
         };
 
         let mut weights = read_param("weight_ih_l0");
@@ -908,6 +1834,20 @@ mod tests {
             init
         });
 
+        let peephole = case.get("peephole").map(|param| {
+            let mut peephole = read_tensor(param).expect("failed to read peephole weights");
+            peephole.insert_axis(0); // Add directions dim
+            peephole
+        });
+
+        let sequence_lens = case.get("sequence_lens").map(|lens| {
+            lens.as_array()
+                .expect("sequence_lens should be an array")
+                .iter()
+                .map(|len| len.as_i64().expect("sequence length should be an int") as i32)
+                .collect()
+        });
+
         RNNRefTest {
             input,
             weights,
@@ -916,6 +1856,8 @@ mod tests {
             expected,
             initial_hidden,
             initial_cell,
+            peephole,
+            sequence_lens,
         }
     }
 
@@ -926,43 +1868,79 @@ mod tests {
         struct Case {
             name: &'static str,
             dir: Direction,
+            options: RnnOptions,
+
+            /// Only applicable for GRU operator.
+            linear_before_reset: bool,
         }
 
-        let cases = &[
-            Case {
-                name: "lstm_forwards",
-                dir: Direction::Forward,
-            },
-            Case {
-                name: "lstm_initial",
-                dir: Direction::Forward,
-            },
+        impl Case {
+            fn new(name: &'static str, dir: Direction) -> Case {
+                Case {
+                    name,
+                    dir,
+                    options: RnnOptions::default(),
+                    linear_before_reset: true,
+                }
+            }
+        }
+
+        let cases = [
+            Case::new("lstm_forwards", Direction::Forward),
+            Case::new("lstm_initial", Direction::Forward),
+            Case::new("lstm_bidirectional", Direction::Bidirectional),
+            Case::new("gru_forwards", Direction::Forward),
+            Case::new("gru_initial", Direction::Forward),
+            Case::new("gru_bidirectional", Direction::Bidirectional),
+            Case::new("rnn_forwards", Direction::Forward),
+            Case::new("rnn_initial", Direction::Forward),
             Case {
-                name: "lstm_bidirectional",
-                dir: Direction::Bidirectional,
+                options: RnnOptions {
+                    activations: vec![RnnActivation::Relu],
+                    ..Default::default()
+                },
+                ..Case::new("rnn_relu_bidirectional", Direction::Bidirectional)
             },
+            Case::new("lstm_peephole", Direction::Forward),
             Case {
-                name: "gru_forwards",
-                dir: Direction::Forward,
+                options: RnnOptions {
+                    clip: Some(0.25),
+                    ..Default::default()
+                },
+                ..Case::new("lstm_clip", Direction::Forward)
             },
             Case {
-                name: "gru_initial",
-                dir: Direction::Forward,
+                options: RnnOptions {
+                    activations: vec![
+                        RnnActivation::HardSigmoid {
+                            alpha: 0.2,
+                            beta: 0.5,
+                        },
+                        RnnActivation::Softsign,
+                        RnnActivation::Elu { alpha: 1.0 },
+                    ],
+                    ..Default::default()
+                },
+                ..Case::new("lstm_activations", Direction::Forward)
             },
             Case {
-                name: "gru_bidirectional",
-                dir: Direction::Bidirectional,
+                linear_before_reset: false,
+                ..Case::new("gru_linear_before_reset_0", Direction::Forward)
             },
+            Case::new("lstm_sequence_lens", Direction::Bidirectional),
         ];
 
         let pool = new_pool();
         for case in cases {
             let op = if case.name.starts_with("lstm") {
                 Op::Lstm
-            } else {
+            } else if case.name.starts_with("gru") {
                 Op::Gru
+            } else {
+                Op::Rnn
             };
             let data = read_pytorch_ref_test(op, &dict[case.name]);
+            let sequence_lens = data.sequence_lens.as_ref().map(|lens| lens.view());
             let result = match op {
                 Op::Lstm => lstm(
                     &pool,
@@ -971,8 +1949,12 @@ mod tests {
                     data.weights.view(),
                     data.hidden_weights.view(),
                     data.bias.as_ref().map(|b| b.view()),
+                    sequence_lens,
                     data.initial_hidden.as_ref().map(|ih| ih.view()),
                     data.initial_cell.as_ref().map(|ic| ic.view()),
+                    data.peephole.as_ref().map(|p| p.view()),
+                    false, /* input_forget */
+                    &case.options,
                 )
                 .expect("LSTM op failed"),
                 Op::Gru => gru(
@@ -982,14 +1964,445 @@ mod tests {
                     data.weights.view(),
                     data.hidden_weights.view(),
                     data.bias.as_ref().map(|b| b.view()),
+                    sequence_lens,
                     data.initial_hidden.as_ref().map(|ih| ih.view()),
-                    true, /* linear_before_reset */
+                    case.linear_before_reset,
+                    &case.options,
                 )
                 .expect("GRU op failed"),
+                Op::Rnn => rnn(
+                    &pool,
+                    case.dir,
+                    data.input.view(),
+                    data.weights.view(),
+                    data.hidden_weights.view(),
+                    data.bias.as_ref().map(|b| b.view()),
+                    sequence_lens,
+                    data.initial_hidden.as_ref().map(|ih| ih.view()),
+                    &case.options,
+                )
+                .expect("RNN op failed"),
             };
             let output = &result[0];
 
-            expect_equal(output, &data.expected)?;
+            expect_equal(output, &data.expected)
+                .map_err(|err| format!("case {} failed: {}", case.name, err))?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rnn_ops_sequence_lens() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let seq_len = 4;
+        let hidden_size = 3;
+        let features = 2;
+        let seq_lens = [4, 2, 1];
+        let batch = seq_lens.len();
+
+        for op in [Op::Gru, Op::Lstm, Op::Rnn] {
+            for dir in [
+                Direction::Forward,
+                Direction::Reverse,
+                Direction::Bidirectional,
+            ] {
+                let inputs =
+                    OpInputs::random(&mut rng, op, dir, [seq_len, batch, features], hidden_size);
+                let seq_lens_tensor = NdTensor::from(seq_lens);
+                let result = inputs.run(
+                    op,
+                    dir,
+                    inputs.input.view(),
+                    Some(seq_lens_tensor.view()),
+                    &RnnOptions::default(),
+                );
+
+                // Each batch item should produce the same outputs as if it
+                // were run on its own with the padding removed.
+                for (b, &len) in seq_lens.iter().enumerate() {
+                    let len = len as usize;
+                    let item_input = inputs.input.slice::<3, _>((..len, b..b + 1));
+                    let expected =
+                        inputs.run(op, dir, item_input.as_dyn(), None, &RnnOptions::default());
+
+                    let hidden_seq = result[0].slice::<4, _>((..len, .., b..b + 1));
+                    expect_equal(&hidden_seq.as_dyn().to_tensor(), &expected[0])?;
+
+                    let padding = result[0].slice::<3, _>((len.., .., b));
+                    assert!(padding.iter().all(|x| *x == 0.));
+
+                    for (state, expected_state) in result[1..].iter().zip(&expected[1..]) {
+                        let state = state.slice::<3, _>((.., b..b + 1));
+                        expect_equal(&state.as_dyn().to_tensor(), expected_state)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rnn_ops_batch_first() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let dir = Direction::Bidirectional;
+
+        for op in [Op::Gru, Op::Lstm, Op::Rnn] {
+            let inputs = OpInputs::random(&mut rng, op, dir, [5, 2, 3], 4);
+            let expected = inputs.run(op, dir, inputs.input.view(), None, &RnnOptions::default());
+
+            let mut input = inputs.input.view();
+            input.permute(&[1, 0, 2]);
+            let result = inputs.run(
+                op,
+                dir,
+                input,
+                None,
+                &RnnOptions {
+                    batch_first: true,
+                    ..Default::default()
+                },
+            );
+
+            // Hidden sequence: [batch, seq, dir, hidden]
+            let mut hidden_seq = result[0].view();
+            hidden_seq.permute(&[1, 2, 0, 3]);
+            expect_equal(&hidden_seq.as_dyn().to_tensor(), &expected[0])?;
+
+            // Last states: [batch, dir, hidden]
+            for (state, expected_state) in result[1..].iter().zip(&expected[1..]) {
+                let mut state = state.view();
+                state.permute(&[1, 0, 2]);
+                expect_equal(&state.as_dyn().to_tensor(), expected_state)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rnn_ops_invalid_activations() {
+        let mut rng = XorShiftRng::new(1234);
+        let pool = new_pool();
+        let inputs = OpInputs::random(&mut rng, Op::Rnn, Direction::Forward, [2, 1, 3], 2);
+        let result = rnn(
+            &pool,
+            Direction::Forward,
+            inputs.input.view(),
+            inputs.weights.view(),
+            inputs.recurrent_weights.view(),
+            None,
+            None,
+            None,
+            &RnnOptions {
+                activations: [RnnActivation::Tanh, RnnActivation::Tanh].into(),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Number of activations does not match operator"
+            ))
+        );
+    }
+
+    /// Apply an activation function to a single value.
+    fn activate_scalar(act: RnnActivation, x: f32, clip: Option<f32>) -> f32 {
+        let mut x = Tensor::from(x);
+        activate_in_place(x.view_mut(), act, clip);
+        x.to_vec()[0]
+    }
+
+    /// Attributes and extra inputs for [reference_rnn].
+    #[derive(Default)]
+    struct ReferenceOptions {
+        options: RnnOptions,
+        linear_before_reset: bool,
+        input_forget: bool,
+        peephole: Option<Tensor>,
+    }
+
+    /// Simple reference implementation of the RNN operators, for a single
+    /// forwards direction.
+    ///
+    /// Returns the hidden sequence as `[seq, batch, hidden]`.
+    fn reference_rnn(op: Op, inputs: &OpInputs, opts: &ReferenceOptions) -> NdTensor<f32, 3> {
+        let [seq_len, batch, _] = inputs.input.shape().try_into().unwrap();
+        let num_gates = op.num_gates();
+        let hidden_size = inputs.recurrent_weights.size(2);
+        let clip = opts.options.clip;
+
+        let weights = inputs.weights.slice::<2, _>(0);
+        let recurrent_weights = inputs.recurrent_weights.slice::<2, _>(0);
+        let bias = inputs.bias.slice::<1, _>(0);
+
+        // Compute `W_gate @ x + Wb_gate`.
+        let input_part = |gate: usize, x: &[f32]| -> Vec<f32> {
+            (0..hidden_size)
+                .map(|i| {
+                    let row = gate * hidden_size + i;
+                    let dot: f32 = (0..x.len()).map(|k| weights[[row, k]] * x[k]).sum();
+                    dot + bias[[row]]
+                })
+                .collect()
+        };
+
+        // Compute `R_gate @ h + Rb_gate`.
+        let hidden_part = |gate: usize, h: &[f32]| -> Vec<f32> {
+            (0..hidden_size)
+                .map(|i| {
+                    let row = gate * hidden_size + i;
+                    let dot: f32 = (0..h.len())
+                        .map(|k| recurrent_weights[[row, k]] * h[k])
+                        .sum();
+                    dot + bias[[num_gates * hidden_size + row]]
+                })
+                .collect()
+        };
+
+        let peephole = |gate: usize, i: usize| {
+            opts.peephole
+                .as_ref()
+                .map(|p| p[[0, gate * hidden_size + i]])
+                .unwrap_or(0.)
+        };
+
+        let acts = &opts.options.activations;
+        let act = |n: usize, default: RnnActivation| acts.get(n).copied().unwrap_or(default);
+
+        let mut output = NdTensor::zeros([seq_len, batch, hidden_size]);
+        for b in 0..batch {
+            let mut h = vec![0.; hidden_size];
+            let mut c = vec![0.; hidden_size];
+
+            for t in 0..seq_len {
+                let x = inputs.input.slice::<1, _>([t, b]).to_vec();
+
+                match op {
+                    Op::Rnn => {
+                        let f = act(0, RnnActivation::Tanh);
+                        let (xp, hp) = (input_part(0, &x), hidden_part(0, &h));
+                        for i in 0..hidden_size {
+                            h[i] = activate_scalar(f, xp[i] + hp[i], clip);
+                        }
+                    }
+                    Op::Gru => {
+                        let f = act(0, RnnActivation::Sigmoid);
+                        let g = act(1, RnnActivation::Tanh);
+                        let (xz, hz) = (input_part(0, &x), hidden_part(0, &h));
+                        let (xr, hr) = (input_part(1, &x), hidden_part(1, &h));
+                        let z: Vec<f32> = (0..hidden_size)
+                            .map(|i| activate_scalar(f, xz[i] + hz[i], clip))
+                            .collect();
+                        let r: Vec<f32> = (0..hidden_size)
+                            .map(|i| activate_scalar(f, xr[i] + hr[i], clip))
+                            .collect();
+                        let xh = input_part(2, &x);
+                        let hh: Vec<f32> = if opts.linear_before_reset {
+                            let hh = hidden_part(2, &h);
+                            (0..hidden_size).map(|i| xh[i] + r[i] * hh[i]).collect()
+                        } else {
+                            let rh: Vec<f32> = (0..hidden_size).map(|i| r[i] * h[i]).collect();
+                            let hh = hidden_part(2, &rh);
+                            (0..hidden_size).map(|i| xh[i] + hh[i]).collect()
+                        };
+                        for i in 0..hidden_size {
+                            let hh = activate_scalar(g, hh[i], clip);
+                            h[i] = (1. - z[i]) * hh + z[i] * h[i];
+                        }
+                    }
+                    Op::Lstm => {
+                        let f = act(0, RnnActivation::Sigmoid);
+                        let g = act(1, RnnActivation::Tanh);
+                        let h_act = act(2, RnnActivation::Tanh);
+                        let gates: Vec<Vec<f32>> = (0..4)
+                            .map(|gate| {
+                                let (xp, hp) = (input_part(gate, &x), hidden_part(gate, &h));
+                                (0..hidden_size).map(|i| xp[i] + hp[i]).collect()
+                            })
+                            .collect();
+                        let [in_gate, out_gate, forget_gate, cell_gate] = [0, 1, 2, 3];
+                        for i in 0..hidden_size {
+                            let ig =
+                                activate_scalar(f, gates[in_gate][i] + peephole(0, i) * c[i], clip);
+                            let fg = if opts.input_forget {
+                                1. - ig
+                            } else {
+                                activate_scalar(
+                                    f,
+                                    gates[forget_gate][i] + peephole(2, i) * c[i],
+                                    clip,
+                                )
+                            };
+                            let cg = activate_scalar(g, gates[cell_gate][i], clip);
+                            c[i] = fg * c[i] + ig * cg;
+                            let og = activate_scalar(
+                                f,
+                                gates[out_gate][i] + peephole(1, i) * c[i],
+                                clip,
+                            );
+                            h[i] = og * activate_scalar(h_act, c[i], None);
+                        }
+                    }
+                }
+
+                output
+                    .slice_mut::<1, _>([t, b])
+                    .copy_from(&h.as_slice().into());
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn test_rnn_ops_attributes() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+        let hidden_size = 3;
+        let shape = [4, 2, 3];
+
+        struct Case {
+            op: Op,
+            opts: ReferenceOptions,
+        }
+
+        let cases = [
+            Case {
+                op: Op::Rnn,
+                opts: ReferenceOptions::default(),
+            },
+            Case {
+                op: Op::Rnn,
+                opts: ReferenceOptions {
+                    options: RnnOptions {
+                        activations: [RnnActivation::LeakyRelu { alpha: 0.1 }].into(),
+                        clip: Some(0.5),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            },
+            Case {
+                op: Op::Gru,
+                opts: ReferenceOptions {
+                    linear_before_reset: true,
+                    ..Default::default()
+                },
+            },
+            Case {
+                op: Op::Gru,
+                opts: ReferenceOptions {
+                    linear_before_reset: false,
+                    ..Default::default()
+                },
+            },
+            Case {
+                op: Op::Gru,
+                opts: ReferenceOptions {
+                    options: RnnOptions {
+                        activations: [
+                            RnnActivation::HardSigmoid {
+                                alpha: 0.2,
+                                beta: 0.5,
+                            },
+                            RnnActivation::ScaledTanh {
+                                alpha: 0.5,
+                                beta: 2.0,
+                            },
+                        ]
+                        .into(),
+                        clip: Some(0.3),
+                        ..Default::default()
+                    },
+                    linear_before_reset: false,
+                    ..Default::default()
+                },
+            },
+            Case {
+                op: Op::Lstm,
+                opts: ReferenceOptions::default(),
+            },
+            Case {
+                op: Op::Lstm,
+                opts: ReferenceOptions {
+                    peephole: Some(Tensor::rand(&[1, 3 * hidden_size], &mut rng)),
+                    ..Default::default()
+                },
+            },
+            Case {
+                op: Op::Lstm,
+                opts: ReferenceOptions {
+                    options: RnnOptions {
+                        activations: [
+                            RnnActivation::Sigmoid,
+                            RnnActivation::Elu { alpha: 1.0 },
+                            RnnActivation::Affine {
+                                alpha: 0.5,
+                                beta: 0.1,
+                            },
+                        ]
+                        .into(),
+                        clip: Some(1.0),
+                        ..Default::default()
+                    },
+                    input_forget: true,
+                    peephole: Some(Tensor::rand(&[1, 3 * hidden_size], &mut rng)),
+                    ..Default::default()
+                },
+            },
+        ];
+
+        let pool = new_pool();
+        for Case { op, opts } in cases {
+            let dir = Direction::Forward;
+            let inputs = OpInputs::random(&mut rng, op, dir, shape, hidden_size);
+            let expected = reference_rnn(op, &inputs, &opts);
+
+            let bias = Some(inputs.bias.view());
+            let result = match op {
+                Op::Gru => gru(
+                    &pool,
+                    dir,
+                    inputs.input.view(),
+                    inputs.weights.view(),
+                    inputs.recurrent_weights.view(),
+                    bias,
+                    None,
+                    None,
+                    opts.linear_before_reset,
+                    &opts.options,
+                ),
+                Op::Lstm => lstm(
+                    &pool,
+                    dir,
+                    inputs.input.view(),
+                    inputs.weights.view(),
+                    inputs.recurrent_weights.view(),
+                    bias,
+                    None,
+                    None,
+                    None,
+                    opts.peephole.as_ref().map(|p| p.view()),
+                    opts.input_forget,
+                    &opts.options,
+                ),
+                Op::Rnn => rnn(
+                    &pool,
+                    dir,
+                    inputs.input.view(),
+                    inputs.weights.view(),
+                    inputs.recurrent_weights.view(),
+                    bias,
+                    None,
+                    None,
+                    &opts.options,
+                ),
+            }
+            .unwrap();
+
+            let hidden_seq = result[0].slice::<3, _>((.., 0));
+            expect_equal(&hidden_seq.to_tensor(), &expected)?;
         }
 
         Ok(())
@@ -0,0 +1,513 @@
+use std::mem::MaybeUninit;
+
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView};
+
+use crate::ops::{InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::static_dims;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Method used to combine the samples in each output bin of [RoiAlign].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum RoiAlignMode {
+    /// Output the average of the sampled values.
+    #[default]
+    Avg,
+
+    /// Output the maximum of the sampled values.
+    Max,
+}
+
+/// Specifies how ROI coordinates are mapped to input coordinates in
+/// [RoiAlign].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum RoiAlignCoordMode {
+    /// Shift ROI coordinates by -0.5 so that pixel centers are at integer
+    /// coordinates. This is the "aligned" mode in torchvision's `roi_align`.
+    #[default]
+    HalfPixel,
+
+    /// Do not shift ROI coordinates, and treat ROIs smaller than one pixel as
+    /// being one pixel in size. This is the legacy behavior from ONNX opset 10.
+    OutputHalfPixel,
+}
+
+/// A point sampled from an input image using bilinear interpolation.
+#[derive(Clone, Copy, Default)]
+struct BilinearSample {
+    /// Offsets of the four neighboring pixels within an image channel.
+    offsets: [usize; 4],
+
+    /// Interpolation weights for each pixel in `offsets`.
+    weights: [f32; 4],
+}
+
+impl BilinearSample {
+    /// Compute the neighboring pixels and weights for sampling an image of
+    /// size `[height, width]` at `(y, x)`.
+    ///
+    /// Points which are more than one pixel outside the image have zero
+    /// weights.
+    fn new(height: usize, width: usize, mut y: f32, mut x: f32) -> BilinearSample {
+        if height == 0
+            || width == 0
+            || y < -1.0
+            || y > height as f32
+            || x < -1.0
+            || x > width as f32
+        {
+            return BilinearSample::default();
+        }
+
+        y = y.max(0.);
+        x = x.max(0.);
+
+        let mut y_low = y as usize;
+        let y_high = if y_low >= height - 1 {
+            y_low = height - 1;
+            y = y_low as f32;
+            y_low
+        } else {
+            y_low + 1
+        };
+
+        let mut x_low = x as usize;
+        let x_high = if x_low >= width - 1 {
+            x_low = width - 1;
+            x = x_low as f32;
+            x_low
+        } else {
+            x_low + 1
+        };
+
+        let ly = y - y_low as f32;
+        let lx = x - x_low as f32;
+        let hy = 1. - ly;
+        let hx = 1. - lx;
+
+        BilinearSample {
+            offsets: [
+                y_low * width + x_low,
+                y_low * width + x_high,
+                y_high * width + x_low,
+                y_high * width + x_high,
+            ],
+            weights: [hy * hx, hy * lx, ly * hx, ly * lx],
+        }
+    }
+}
+
+/// Extract and resize regions of interest from a batch of NCHW images.
+///
+/// `rois` is a `[num_rois, 4]` tensor of `[x1, y1, x2, y2]` box coordinates,
+/// which are multiplied by `spatial_scale` to get coordinates in `input`.
+/// `batch_indices` specifies the image in the batch that each ROI refers to.
+///
+/// The output has shape `[num_rois, C, output_size[0], output_size[1]]`. Each
+/// output element is computed by dividing the ROI into a grid of bins and
+/// combining `sampling_ratio x sampling_ratio` bilinearly interpolated samples
+/// from each bin. If `sampling_ratio` is `None`, the number of samples is
+/// chosen adaptively based on the size of the ROI.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__RoiAlign.html>.
+pub fn roi_align(
+    pool: &TensorPool,
+    input: NdTensorView<f32, 4>,
+    rois: NdTensorView<f32, 2>,
+    batch_indices: NdTensorView<i32, 1>,
+    mode: RoiAlignMode,
+    coord_mode: RoiAlignCoordMode,
+    output_size: [usize; 2],
+    sampling_ratio: Option<usize>,
+    spatial_scale: f32,
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let [batch, chans, in_h, in_w] = input.shape();
+    let [n_rois, n_coords] = rois.shape();
+
+    if n_coords != 4 {
+        return Err(OpError::InvalidValue(
+            "`rois` last dimension should have size 4",
+        ));
+    }
+    if batch_indices.size(0) != n_rois {
+        return Err(OpError::IncompatibleInputShapes(
+            "`rois` and `batch_indices` have different lengths",
+        ));
+    }
+    if batch_indices
+        .iter()
+        .any(|&idx| idx < 0 || idx as usize >= batch)
+    {
+        return Err(OpError::InvalidValue("Batch index is out of range"));
+    }
+
+    let [out_h, out_w] = output_size;
+    let mut output = NdTensor::uninit_in(pool, [n_rois, chans, out_h, out_w]);
+    let roi_len = chans * out_h * out_w;
+    if roi_len == 0 {
+        // Safety: Output is empty.
+        return Ok(unsafe { output.assume_init() });
+    }
+
+    let input = input.to_contiguous_in(pool).auto_return(pool);
+    let in_data = input.data().unwrap();
+    let chan_len = in_h * in_w;
+
+    let offset = match coord_mode {
+        RoiAlignCoordMode::HalfPixel => 0.5,
+        RoiAlignCoordMode::OutputHalfPixel => 0.,
+    };
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(roi_len)
+        .enumerate()
+        .for_each(|(roi_idx, out_roi)| {
+            let [x1, y1, x2, y2] = rois.slice::<1, _>(roi_idx).to_array();
+            let start_x = x1 * spatial_scale - offset;
+            let start_y = y1 * spatial_scale - offset;
+            let mut roi_w = x2 * spatial_scale - offset - start_x;
+            let mut roi_h = y2 * spatial_scale - offset - start_y;
+            if coord_mode == RoiAlignCoordMode::OutputHalfPixel {
+                roi_w = roi_w.max(1.);
+                roi_h = roi_h.max(1.);
+            }
+
+            let bin_h = roi_h / out_h as f32;
+            let bin_w = roi_w / out_w as f32;
+            let grid_h = sampling_ratio.unwrap_or(bin_h.ceil().max(0.) as usize);
+            let grid_w = sampling_ratio.unwrap_or(bin_w.ceil().max(0.) as usize);
+            let n_samples = grid_h * grid_w;
+
+            // Compute the sample positions for each output bin. These are the
+            // same for every channel.
+            let mut samples = Vec::with_capacity(out_h * out_w * n_samples);
+            for bin_y in 0..out_h {
+                for bin_x in 0..out_w {
+                    for sample_y in 0..grid_h {
+                        let y = start_y
+                            + bin_y as f32 * bin_h
+                            + (sample_y as f32 + 0.5) * bin_h / grid_h as f32;
+                        for sample_x in 0..grid_w {
+                            let x = start_x
+                                + bin_x as f32 * bin_w
+                                + (sample_x as f32 + 0.5) * bin_w / grid_w as f32;
+                            samples.push(BilinearSample::new(in_h, in_w, y, x));
+                        }
+                    }
+                }
+            }
+
+            let image = batch_indices[[roi_idx]] as usize;
+            for (c, out_chan) in out_roi.chunks_mut(out_h * out_w).enumerate() {
+                if n_samples == 0 || chan_len == 0 {
+                    out_chan.fill(MaybeUninit::new(0.));
+                    continue;
+                }
+
+                let in_offset = (image * chans + c) * chan_len;
+                let in_chan = &in_data[in_offset..in_offset + chan_len];
+
+                let bin_samples = samples.chunks(n_samples);
+                for (out_el, bin_samples) in out_chan.iter_mut().zip(bin_samples) {
+                    let weighted_vals = bin_samples.iter().map(|s| {
+                        let [o0, o1, o2, o3] = s.offsets;
+                        let [w0, w1, w2, w3] = s.weights;
+                        [
+                            w0 * in_chan[o0],
+                            w1 * in_chan[o1],
+                            w2 * in_chan[o2],
+                            w3 * in_chan[o3],
+                        ]
+                    });
+                    let val = match mode {
+                        RoiAlignMode::Avg => {
+                            let sum: f32 = weighted_vals.map(|v| v.iter().sum::<f32>()).sum();
+                            sum / n_samples as f32
+                        }
+                        RoiAlignMode::Max => weighted_vals
+                            .map(|v| v.into_iter().fold(f32::NEG_INFINITY, f32::max))
+                            .reduce(f32::max)
+                            .unwrap_or(0.),
+                    };
+                    out_el.write(val);
+                }
+            }
+        });
+
+    // Safety: We initialized all output elements.
+    Ok(unsafe { output.assume_init() })
+}
+
+#[derive(Debug)]
+pub struct RoiAlign {
+    pub mode: RoiAlignMode,
+    pub coord_mode: RoiAlignCoordMode,
+    pub output_height: usize,
+    pub output_width: usize,
+
+    /// Number of samples to take along each axis of an output bin. If `None`,
+    /// this is determined adaptively from the size of the ROI.
+    pub sampling_ratio: Option<usize>,
+
+    pub spatial_scale: f32,
+}
+
+impl Operator for RoiAlign {
+    fn name(&self) -> &str {
+        "RoiAlign"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let input = static_dims!(input, 4, "NCHW")?;
+
+        let rois = inputs.require_as(1)?;
+        let rois = static_dims!(rois, 2, "N4")?;
+
+        let batch_indices = inputs.require_as(2)?;
+        let batch_indices = static_dims!(batch_indices, 1)?;
+
+        let output = roi_align(
+            pool,
+            input,
+            rois,
+            batch_indices,
+            self.mode,
+            self.coord_mode,
+            [self.output_height, self.output_width],
+            self.sampling_ratio,
+            self.spatial_scale,
+        )?;
+
+        output.into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal;
+    use rten_tensor::{NdTensor, Tensor};
+
+    use crate::ops::tests::new_pool;
+    use crate::ops::{
+        average_pool, max_pool, roi_align, OpError, Padding, RoiAlignCoordMode, RoiAlignMode,
+    };
+
+    #[test]
+    fn test_roi_align_linear_input() {
+        let pool = new_pool();
+
+        // Input where each value is equal to its X coordinate. Bilinear
+        // interpolation is exact for this input, so each output is the mean
+        // X coordinate of the samples in the bin.
+        let input = NdTensor::from_fn([1, 1, 6, 6], |[_, _, _, x]| x as f32);
+        let rois = NdTensor::from([[0., 0., 4., 4.]]);
+        let batch_indices = NdTensor::from([0]);
+
+        struct Case {
+            coord_mode: RoiAlignCoordMode,
+            expected: [f32; 2],
+        }
+
+        let cases = [
+            Case {
+                coord_mode: RoiAlignCoordMode::HalfPixel,
+                expected: [0.5, 2.5],
+            },
+            Case {
+                coord_mode: RoiAlignCoordMode::OutputHalfPixel,
+                expected: [1., 3.],
+            },
+        ];
+
+        for Case {
+            coord_mode,
+            expected,
+        } in cases
+        {
+            let result = roi_align(
+                &pool,
+                input.view(),
+                rois.view(),
+                batch_indices.view(),
+                RoiAlignMode::Avg,
+                coord_mode,
+                [2, 2],
+                Some(2),
+                1.,
+            )
+            .unwrap();
+            assert_eq!(result.shape(), [1, 1, 2, 2]);
+            assert_eq!(result.slice::<1, _>((0, 0, 0)).to_vec(), expected);
+            assert_eq!(result.slice::<1, _>((0, 0, 1)).to_vec(), expected);
+        }
+    }
+
+    #[test]
+    fn test_roi_align_matches_pooling() -> Result<(), Box<dyn std::error::Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // When the ROI covers the whole image and samples fall exactly on
+        // pixel centers, RoiAlign is equivalent to average or max pooling.
+        let input = NdTensor::rand([2, 3, 4, 4], &mut rng);
+        let rois = NdTensor::from([[0., 0., 4., 4.], [0., 0., 8., 8.]]);
+        let batch_indices = NdTensor::from([1, 0]);
+        let spatial_scale = [1., 0.5];
+
+        for (mode, expected) in [
+            (
+                RoiAlignMode::Avg,
+                average_pool(
+                    &pool,
+                    input.as_dyn(),
+                    &[2, 2],
+                    &[2, 2],
+                    Padding::zero::<2>(),
+                    &[1, 1],
+                    false,
+                    false,
+                )?,
+            ),
+            (
+                RoiAlignMode::Max,
+                max_pool(
+                    &pool,
+                    input.as_dyn(),
+                    &[2, 2],
+                    &[2, 2],
+                    Padding::zero::<2>(),
+                    &[1, 1],
+                    false,
+                )?,
+            ),
+        ] {
+            for roi in 0..rois.size(0) {
+                let result = roi_align(
+                    &pool,
+                    input.view(),
+                    rois.slice((roi..roi + 1, ..)),
+                    batch_indices.slice(roi..roi + 1),
+                    mode,
+                    RoiAlignCoordMode::HalfPixel,
+                    [2, 2],
+                    Some(2),
+                    spatial_scale[roi],
+                )?;
+                let image = batch_indices[[roi]] as usize;
+                let expected: Tensor = expected.slice_dyn(image..image + 1).to_tensor();
+                expect_equal(&result.as_dyn(), &expected.view())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_roi_align_adaptive_sampling() {
+        let pool = new_pool();
+
+        // With a constant input, every sample inside the image has the same
+        // value, regardless of how many samples are taken.
+        let input = NdTensor::full([1, 2, 10, 10], 3.);
+        let rois = NdTensor::from([[1., 1., 9., 7.], [2.5, 0., 3.5, 1.]]);
+        let batch_indices = NdTensor::from([0, 0]);
+
+        let result = roi_align(
+            &pool,
+            input.view(),
+            rois.view(),
+            batch_indices.view(),
+            RoiAlignMode::Avg,
+            RoiAlignCoordMode::HalfPixel,
+            [3, 2],
+            None,
+            1.,
+        )
+        .unwrap();
+
+        assert_eq!(result.shape(), [2, 2, 3, 2]);
+        assert!(result.iter().all(|&x| (x - 3.).abs() < 1e-5));
+
+        // ROIs with a non-positive size take no samples and produce zeros.
+        let rois = NdTensor::from([[5., 5., 2., 2.]]);
+        let result = roi_align(
+            &pool,
+            input.view(),
+            rois.view(),
+            NdTensor::from([0]).view(),
+            RoiAlignMode::Avg,
+            RoiAlignCoordMode::HalfPixel,
+            [3, 2],
+            None,
+            1.,
+        )
+        .unwrap();
+        assert!(result.iter().all(|&x| x == 0.));
+    }
+
+    #[test]
+    fn test_roi_align_invalid() {
+        let pool = new_pool();
+        let input = NdTensor::zeros([1, 1, 4, 4]);
+
+        let rois = NdTensor::from([[0., 0., 4., 4.]]);
+        let result = roi_align(
+            &pool,
+            input.view(),
+            rois.view(),
+            NdTensor::from([1]).view(),
+            RoiAlignMode::Avg,
+            RoiAlignCoordMode::HalfPixel,
+            [2, 2],
+            None,
+            1.,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Batch index is out of range"))
+        );
+
+        let result = roi_align(
+            &pool,
+            input.view(),
+            rois.view(),
+            NdTensor::from([0, 0]).view(),
+            RoiAlignMode::Avg,
+            RoiAlignCoordMode::HalfPixel,
+            [2, 2],
+            None,
+            1.,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "`rois` and `batch_indices` have different lengths"
+            ))
+        );
+
+        let rois = NdTensor::from([[0., 0., 4.]]);
+        let result = roi_align(
+            &pool,
+            input.view(),
+            rois.view(),
+            NdTensor::from([0]).view(),
+            RoiAlignMode::Avg,
+            RoiAlignCoordMode::HalfPixel,
+            [2, 2],
+            None,
+            1.,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "`rois` last dimension should have size 4"
+            ))
+        );
+    }
+}
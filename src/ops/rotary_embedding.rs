@@ -0,0 +1,465 @@
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
+
+use crate::ops::{InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+/// Minimum number of rows of the input to process in each parallel task.
+const MIN_ROWS_PER_TASK: usize = 64;
+
+/// Apply rotary embeddings to the first `2 * half` elements of a row.
+///
+/// `cos` and `sin` have either `half` elements, which are shared between both
+/// elements of each rotated pair, or `2 * half` elements, with separate values
+/// for each element.
+fn rotate_row(row: &mut [f32], cos: &[f32], sin: &[f32], half: usize, interleaved: bool) {
+    let full = cos.len() == 2 * half && half > 0;
+    let coeffs = |i: usize| {
+        if !full {
+            (cos[i], cos[i], sin[i], sin[i])
+        } else if interleaved {
+            (cos[2 * i], cos[2 * i + 1], sin[2 * i], sin[2 * i + 1])
+        } else {
+            (cos[i], cos[i + half], sin[i], sin[i + half])
+        }
+    };
+
+    for i in 0..half {
+        let (i0, i1) = if interleaved {
+            (2 * i, 2 * i + 1)
+        } else {
+            (i, i + half)
+        };
+        let (cos_0, cos_1, sin_0, sin_1) = coeffs(i);
+        let (x0, x1) = (row[i0], row[i1]);
+        row[i0] = x0 * cos_0 - x1 * sin_0;
+        row[i1] = x1 * cos_1 + x0 * sin_1;
+    }
+}
+
+/// Look up the rows of a `[max_position, dim]` cos or sin cache for each
+/// position in a `[batch, seq]` input.
+///
+/// `position_ids` either has shape `[batch, seq]`, or contains a single
+/// value which is the position of the first item in each sequence.
+fn gather_cache(
+    pool: &TensorPool,
+    cache: TensorView,
+    position_ids: TensorView<i32>,
+    batch: usize,
+    seq: usize,
+) -> Result<NdTensor<f32, 3>, OpError> {
+    let cache: NdTensorView<f32, 2> = cache.try_into().map_err(|_| {
+        OpError::InvalidValue("Cos and sin caches must have 2 dims if position IDs are given")
+    })?;
+    let [max_position, dim] = cache.shape();
+
+    let positions: NdTensor<i32, 2> = if position_ids.ndim() == 1 && position_ids.len() == 1 {
+        let offset = position_ids.iter().next().copied().unwrap();
+        NdTensor::from_fn([batch, seq], |[_, s]| offset + s as i32)
+    } else if position_ids.can_broadcast_to(&[batch, seq]) {
+        position_ids.broadcast([batch, seq]).to_tensor()
+    } else {
+        return Err(OpError::IncompatibleInputShapes(
+            "Position IDs must have shape [batch, seq] or [1]",
+        ));
+    };
+
+    let mut rows = pool.alloc(batch * seq * dim);
+    for &pos in positions.iter() {
+        if pos < 0 || pos as usize >= max_position {
+            pool.add(rows);
+            return Err(OpError::InvalidValue("Position ID is out of range"));
+        }
+        rows.extend(cache.slice::<1, _>(pos as usize).iter().copied());
+    }
+    Ok(NdTensor::from_data([batch, seq, dim], rows))
+}
+
+/// Apply rotary position embeddings (RoPE) to an input.
+///
+/// `input` has shape `[batch, heads, seq, head_size]` or
+/// `[batch, seq, hidden]`. In the latter case, `hidden` is split into
+/// `num_heads` heads. If `num_heads` is not given, the head size is inferred
+/// from `cos`.
+///
+/// The first `rotary_dim` elements of each head are rotated and the rest are
+/// passed through unchanged. `rotary_dim` defaults to the head size. If
+/// `interleaved` is true, rotated pairs are adjacent elements, otherwise the
+/// pairs are `(x[i], x[i + rotary_dim / 2])`.
+///
+/// `cos` and `sin` specify the rotation for each position, and have a last
+/// dimension of either `rotary_dim / 2` or `rotary_dim`. In the first case the
+/// same value is used for both elements of a pair. The leading dimensions are
+/// either:
+///
+/// - `[max_position]`, if `position_ids` is given. Rows are looked up using
+///   `position_ids`, which either has shape `[batch, seq]` or contains a single
+///   offset that is added to the index of each item in the sequence.
+/// - `[batch, seq]`, if `position_ids` is not given.
+/// - Any shape that can be broadcast to `[batch, heads, seq]` for 4D inputs,
+///   if `position_ids` is not given.
+pub fn rotary_embedding(
+    pool: &TensorPool,
+    input: TensorView,
+    position_ids: Option<TensorView<i32>>,
+    cos: TensorView,
+    sin: TensorView,
+    interleaved: bool,
+    num_heads: Option<usize>,
+    rotary_dim: Option<usize>,
+) -> Result<Tensor, OpError> {
+    if cos.shape() != sin.shape() {
+        return Err(OpError::IncompatibleInputShapes(
+            "Cos and sin inputs must have the same shape",
+        ));
+    }
+    let Some(&cache_dim) = cos.shape().last() else {
+        return Err(OpError::InvalidValue(
+            "Cos and sin inputs must have at least 1 dim",
+        ));
+    };
+
+    // Get the batch, head and sequence sizes. `heads_first` indicates whether
+    // the layout is `[batch, heads, seq, head_size]` or
+    // `[batch, seq, heads, head_size]`.
+    let (batch, n_heads, seq, head_size, heads_first) = match *input.shape() {
+        [batch, n_heads, seq, head_size] => (batch, n_heads, seq, head_size, true),
+        [batch, seq, hidden] => {
+            let head_size = match num_heads {
+                Some(0) => {
+                    return Err(OpError::InvalidValue("`num_heads` must be positive"));
+                }
+                Some(n_heads) if hidden % n_heads != 0 => {
+                    return Err(OpError::InvalidValue(
+                        "Hidden size must be a multiple of `num_heads`",
+                    ));
+                }
+                Some(n_heads) => hidden / n_heads,
+                None => rotary_dim.unwrap_or(cache_dim * 2),
+            };
+            if head_size == 0 || hidden % head_size != 0 {
+                return Err(OpError::InvalidValue(
+                    "Hidden size must be a multiple of head size",
+                ));
+            }
+            (batch, hidden / head_size, seq, head_size, false)
+        }
+        _ => {
+            return Err(OpError::InvalidValue("Input must have 3 or 4 dims"));
+        }
+    };
+
+    let rotary_dim = rotary_dim.unwrap_or(head_size);
+    if rotary_dim % 2 == 1 || rotary_dim > head_size {
+        return Err(OpError::InvalidValue(
+            "Rotary dim must be even and not exceed head size",
+        ));
+    }
+    if cache_dim != rotary_dim / 2 && cache_dim != rotary_dim {
+        return Err(OpError::IncompatibleInputShapes(
+            "Cos and sin size must be half or equal to rotary dim",
+        ));
+    }
+
+    // Get cos and sin values as contiguous `[batch?, heads?, seq, cache_dim]`
+    // tensors.
+    let (mut cos, mut sin) = if let Some(position_ids) = position_ids {
+        let cos = gather_cache(pool, cos, position_ids.view(), batch, seq)?.into_dyn();
+        let sin = gather_cache(pool, sin, position_ids, batch, seq)?.into_dyn();
+        (cos, sin)
+    } else {
+        (cos.to_tensor_in(pool), sin.to_tensor_in(pool))
+    };
+    if cos.ndim() == 3 || !heads_first {
+        // Insert the head dimension for `[batch, seq, cache_dim]` values.
+        cos.insert_axis(cos.ndim().saturating_sub(2));
+        sin.insert_axis(sin.ndim().saturating_sub(2));
+    }
+    let cos = cos.auto_return(pool);
+    let sin = sin.auto_return(pool);
+
+    let cache_shape = [batch, n_heads, seq, cache_dim];
+    if !cos.can_broadcast_to(&cache_shape) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Cos and sin cannot be broadcast to input shape",
+        ));
+    }
+    let cos: NdTensorView<f32, 4> = cos.broadcast(cache_shape);
+    let sin: NdTensorView<f32, 4> = sin.broadcast(cache_shape);
+
+    let mut output = input.to_tensor_in(pool);
+    if output.is_empty() {
+        return Ok(output);
+    }
+
+    let half = rotary_dim / 2;
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(head_size)
+        .with_min_len(MIN_ROWS_PER_TASK)
+        .enumerate()
+        .for_each(|(row_idx, row)| {
+            let (b, h, s) = if heads_first {
+                let s = row_idx % seq;
+                let h = (row_idx / seq) % n_heads;
+                (row_idx / (seq * n_heads), h, s)
+            } else {
+                let h = row_idx % n_heads;
+                let s = (row_idx / n_heads) % seq;
+                (row_idx / (seq * n_heads), h, s)
+            };
+            let cos_row = cos.slice::<1, _>([b, h, s]);
+            let sin_row = sin.slice::<1, _>([b, h, s]);
+            rotate_row(
+                row,
+                cos_row.data().unwrap(),
+                sin_row.data().unwrap(),
+                half,
+                interleaved,
+            );
+        });
+
+    Ok(output)
+}
+
+/// Apply rotary position embeddings.
+///
+/// Inputs are `(input, position_ids?, cos, sin)`. This follows the input
+/// order of ONNX Runtime's `com.microsoft.RotaryEmbedding` operator. See
+/// [`rotary_embedding`] for details.
+#[derive(Debug)]
+pub struct RotaryEmbedding {
+    pub interleaved: bool,
+    pub num_heads: Option<usize>,
+    pub rotary_dim: Option<usize>,
+}
+
+impl Operator for RotaryEmbedding {
+    fn name(&self) -> &str {
+        "RotaryEmbedding"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let position_ids = inputs.get_as(1)?;
+        let cos = inputs.require_as(2)?;
+        let sin = inputs.require_as(3)?;
+
+        rotary_embedding(
+            pool,
+            input,
+            position_ids,
+            cos,
+            sin,
+            self.interleaved,
+            self.num_heads,
+            self.rotary_dim,
+        )
+        .into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::{NdTensor, Tensor};
+
+    use crate::ops::tests::{expect_eq_1e4, new_pool};
+    use crate::ops::{rotary_embedding, OpError};
+
+    /// Compute `[max_position, dim / 2]` cos and sin caches.
+    fn rope_caches(max_position: usize, dim: usize) -> (Tensor, Tensor) {
+        let freq = |i: usize| 1. / 10000f32.powf(2. * i as f32 / dim as f32);
+        let cos = Tensor::from_fn(&[max_position, dim / 2], |idx| {
+            (idx[0] as f32 * freq(idx[1])).cos()
+        });
+        let sin = Tensor::from_fn(&[max_position, dim / 2], |idx| {
+            (idx[0] as f32 * freq(idx[1])).sin()
+        });
+        (cos, sin)
+    }
+
+    /// Reference implementation of the decomposed
+    /// `x * cos + rotate_half(x) * sin` computation used in PyTorch models,
+    /// with `[batch, heads, seq, head_size]` inputs and positions `0..seq`.
+    fn reference_rope(x: &NdTensor<f32, 4>, cos: &Tensor, sin: &Tensor) -> NdTensor<f32, 4> {
+        let [_, _, _, head_size] = x.shape();
+        let half = head_size / 2;
+        NdTensor::from_fn(x.shape(), |[b, h, s, d]| {
+            let (c, sn) = (cos[[s, d % half]], sin[[s, d % half]]);
+            let rotated = if d < half {
+                -x[[b, h, s, d + half]]
+            } else {
+                x[[b, h, s, d - half]]
+            };
+            x[[b, h, s, d]] * c + rotated * sn
+        })
+    }
+
+    #[test]
+    fn test_rotary_embedding() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        let [batch, n_heads, seq, head_size] = [2, 3, 5, 8];
+        let x = NdTensor::rand([batch, n_heads, seq, head_size], &mut rng);
+        let (cos, sin) = rope_caches(16, head_size);
+        let expected = reference_rope(&x, &cos, &sin);
+
+        // 4D input with position IDs.
+        let position_ids = Tensor::from_fn(&[batch, seq], |idx| idx[1] as i32);
+        let result = rotary_embedding(
+            &pool,
+            x.as_dyn(),
+            Some(position_ids.view()),
+            cos.view(),
+            sin.view(),
+            false, /* interleaved */
+            None,  /* num_heads */
+            None,  /* rotary_dim */
+        )?;
+        expect_eq_1e4(&result, &expected.as_dyn().to_tensor())?;
+
+        // 4D input with a position offset.
+        let offset = Tensor::from([0i32]);
+        let result = rotary_embedding(
+            &pool,
+            x.as_dyn(),
+            Some(offset.view()),
+            cos.view(),
+            sin.view(),
+            false,
+            None,
+            None,
+        )?;
+        expect_eq_1e4(&result, &expected.as_dyn().to_tensor())?;
+
+        // 3D `[batch, seq, hidden]` input.
+        let x_3d = x.permuted([0, 2, 1, 3]).to_tensor();
+        let x_3d = x_3d.reshaped([batch, seq, n_heads * head_size]);
+        let expected_3d = expected.permuted([0, 2, 1, 3]).to_tensor();
+        let expected_3d = expected_3d.reshaped([batch, seq, n_heads * head_size]);
+        let result = rotary_embedding(
+            &pool,
+            x_3d.as_dyn(),
+            Some(position_ids.view()),
+            cos.view(),
+            sin.view(),
+            false,
+            Some(n_heads),
+            None,
+        )?;
+        expect_eq_1e4(&result, &expected_3d.as_dyn().to_tensor())?;
+
+        // Full-size `[seq, head_size]` cos and sin values, broadcast over batch
+        // and heads, as used by fusion of decomposed rotary embeddings.
+        let full_cos = Tensor::from_fn(&[seq, head_size], |idx| cos[[idx[0], idx[1] % 4]]);
+        let full_sin = Tensor::from_fn(&[seq, head_size], |idx| sin[[idx[0], idx[1] % 4]]);
+        let result = rotary_embedding(
+            &pool,
+            x.as_dyn(),
+            None,
+            full_cos.view(),
+            full_sin.view(),
+            false,
+            None,
+            None,
+        )?;
+        expect_eq_1e4(&result, &expected.as_dyn().to_tensor())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotary_embedding_interleaved_partial() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        let [batch, seq, head_size, rotary_dim] = [1, 4, 6, 4];
+        let x = Tensor::rand(&[batch, seq, head_size], &mut rng);
+        let (cos, sin) = rope_caches(8, rotary_dim);
+
+        // Use `[batch, seq, rotary_dim / 2]` values without position IDs.
+        let cos_rows = cos
+            .slice::<2, _>(0..seq)
+            .to_tensor()
+            .into_shape([1, seq, 2]);
+        let sin_rows = sin
+            .slice::<2, _>(0..seq)
+            .to_tensor()
+            .into_shape([1, seq, 2]);
+
+        let result = rotary_embedding(
+            &pool,
+            x.view(),
+            None,
+            cos_rows.as_dyn(),
+            sin_rows.as_dyn(),
+            true,    /* interleaved */
+            Some(1), /* num_heads */
+            Some(rotary_dim),
+        )?;
+
+        let expected = Tensor::from_fn(&[batch, seq, head_size], |idx| {
+            let [b, s, d] = [idx[0], idx[1], idx[2]];
+            if d >= rotary_dim {
+                return x[[b, s, d]];
+            }
+            let (c, sn) = (cos[[s, d / 2]], sin[[s, d / 2]]);
+            if d % 2 == 0 {
+                x[[b, s, d]] * c - x[[b, s, d + 1]] * sn
+            } else {
+                x[[b, s, d]] * c + x[[b, s, d - 1]] * sn
+            }
+        });
+        expect_eq_1e4(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotary_embedding_invalid() {
+        let pool = new_pool();
+        let x = Tensor::zeros(&[1, 2, 3, 4]);
+        let (cos, sin) = rope_caches(4, 4);
+
+        let position_ids = Tensor::from([[0, 1, 5]]);
+        let result = rotary_embedding(
+            &pool,
+            x.view(),
+            Some(position_ids.view()),
+            cos.view(),
+            sin.view(),
+            false,
+            None,
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Position ID is out of range"))
+        );
+
+        let result = rotary_embedding(
+            &pool,
+            x.view(),
+            None,
+            cos.view(),
+            sin.view(),
+            false,
+            None,
+            Some(3),
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Rotary dim must be even and not exceed head size"
+            ))
+        );
+    }
+}
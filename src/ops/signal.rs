@@ -0,0 +1,829 @@
+use std::f64::consts::PI;
+
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
+
+use crate::ops::{resolve_axis, InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::static_dims;
+use crate::tensor_pool::{AutoReturn, TensorPool};
+
+mod fft;
+
+use fft::{Complex, FftPlan, RealFftPlan};
+
+/// Scratch buffers used when transforming a signal with [SignalTransform].
+#[derive(Default)]
+struct TransformScratch {
+    real: Vec<f32>,
+    complex: Vec<Complex>,
+    fft: Vec<Complex>,
+}
+
+/// Computes the DFT of signals of a fixed length.
+struct SignalTransform {
+    len: usize,
+    inverse: bool,
+    plan: TransformPlan,
+}
+
+enum TransformPlan {
+    /// Plan for forward transforms of real signals.
+    Real(RealFftPlan),
+    Complex(FftPlan),
+}
+
+impl SignalTransform {
+    fn new(len: usize, real_input: bool, inverse: bool) -> SignalTransform {
+        let plan = if real_input && !inverse {
+            TransformPlan::Real(RealFftPlan::new(len))
+        } else {
+            TransformPlan::Complex(FftPlan::new(len))
+        };
+        SignalTransform { len, inverse, plan }
+    }
+
+    /// Transform a signal and write the first `output.len() / 2` bins to
+    /// `output` as interleaved `(real, imag)` pairs.
+    ///
+    /// `signal` yields `(real, imag)` samples. It is truncated or zero-padded
+    /// to the transform length.
+    fn run(
+        &self,
+        signal: impl Iterator<Item = (f32, f32)>,
+        output: &mut [f32],
+        scratch: &mut TransformScratch,
+    ) {
+        let len = self.len;
+        let out_bins = output.chunks_exact_mut(2);
+
+        match &self.plan {
+            TransformPlan::Real(plan) => {
+                scratch.real.clear();
+                scratch.real.extend(signal.map(|(re, _im)| re).take(len));
+                scratch.real.resize(len, 0.);
+
+                scratch
+                    .complex
+                    .resize(plan.output_len(), Complex::default());
+                plan.forward(&scratch.real, &mut scratch.complex, &mut scratch.fft);
+
+                let half = &scratch.complex;
+                for (k, out) in out_bins.enumerate() {
+                    let x = half.get(k).copied().unwrap_or_else(|| half[len - k].conj());
+                    out[0] = x.re;
+                    out[1] = x.im;
+                }
+            }
+            TransformPlan::Complex(plan) => {
+                scratch.complex.clear();
+                scratch
+                    .complex
+                    .extend(signal.map(|(re, im)| Complex::new(re, im)).take(len));
+                scratch.complex.resize(len, Complex::default());
+
+                if self.inverse {
+                    plan.inverse(&mut scratch.complex, &mut scratch.fft);
+                } else {
+                    plan.forward(&mut scratch.complex, &mut scratch.fft);
+                }
+
+                let scale = if self.inverse { 1. / len as f32 } else { 1. };
+                for (out, x) in out_bins.zip(&scratch.complex) {
+                    out[0] = x.re * scale;
+                    out[1] = x.im * scale;
+                }
+            }
+        }
+    }
+}
+
+/// Return an iterator over `(real, imag)` samples in a signal with
+/// `n_components` (1 or 2) values per sample.
+fn signal_samples(signal: &[f32], n_components: usize) -> impl Iterator<Item = (f32, f32)> + '_ {
+    signal
+        .chunks_exact(n_components)
+        .map(|s| (s[0], s.get(1).copied().unwrap_or(0.)))
+}
+
+/// Return the number of components per sample (1 for real signals, 2 for
+/// complex signals) given the size of the last dimension of a signal tensor.
+fn signal_components(size: usize) -> Result<usize, OpError> {
+    match size {
+        1 | 2 => Ok(size),
+        _ => Err(OpError::InvalidValue(
+            "Last dimension of signal must have size 1 or 2",
+        )),
+    }
+}
+
+/// Compute the discrete Fourier transform of a real or complex signal.
+///
+/// The last dimension of `input` has size 1 for real signals or 2 for complex
+/// signals. The transform is computed along `axis`, which must not be the last
+/// dimension. The signal is zero-padded or truncated to `dft_length` samples
+/// if specified.
+///
+/// The output is always complex. If `onesided` is true, only the first
+/// `dft_length / 2 + 1` bins are returned. The remaining bins of the
+/// transform of a real signal can be obtained via conjugate symmetry.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__DFT.html>.
+pub fn dft(
+    pool: &TensorPool,
+    input: TensorView,
+    dft_length: Option<usize>,
+    axis: isize,
+    inverse: bool,
+    onesided: bool,
+) -> Result<Tensor, OpError> {
+    let ndim = input.ndim();
+    if ndim < 2 {
+        return Err(OpError::InvalidValue("Input must have at least 2 dims"));
+    }
+    let n_components = signal_components(input.size(ndim - 1))?;
+    let axis = resolve_axis(ndim, axis)?;
+    if axis == ndim - 1 {
+        return Err(OpError::InvalidValue(
+            "Axis must not be the last dimension of the input",
+        ));
+    }
+    if onesided && (inverse || n_components != 1) {
+        return Err(OpError::UnsupportedValue(
+            "Onesided output is only supported for forward transforms of real signals",
+        ));
+    }
+
+    let signal_len = input.size(axis);
+    let n = dft_length.unwrap_or(signal_len);
+    if n == 0 {
+        return Err(OpError::InvalidValue("DFT length must be > 0"));
+    }
+    let n_bins = if onesided { n / 2 + 1 } else { n };
+
+    // Move the signal axis next to the last dimension, so that each signal is
+    // a contiguous lane of the input.
+    let mut perm: Vec<usize> = (0..ndim).filter(|&d| d != axis && d != ndim - 1).collect();
+    perm.push(axis);
+    perm.push(ndim - 1);
+
+    let lanes = input
+        .permuted(&perm)
+        .to_contiguous_in(pool)
+        .auto_return(pool);
+    let lane_len = signal_len * n_components;
+    let in_data = lanes.data().unwrap();
+
+    let mut out_shape: Vec<usize> = perm[..ndim - 2].iter().map(|&d| input.size(d)).collect();
+    out_shape.push(n_bins);
+    out_shape.push(2);
+    let mut output = Tensor::zeros_in(pool, &out_shape).auto_return(pool);
+
+    let transform = SignalTransform::new(n, n_components == 1, inverse);
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(n_bins * 2)
+        .enumerate()
+        .for_each_init(TransformScratch::default, |scratch, (lane, out_lane)| {
+            let signal = &in_data[lane * lane_len..(lane + 1) * lane_len];
+            transform.run(signal_samples(signal, n_components), out_lane, scratch);
+        });
+
+    // Move the signal axis back to its original position.
+    let mut inverse_perm = vec![0; ndim];
+    for (i, &d) in perm.iter().enumerate() {
+        inverse_perm[d] = i;
+    }
+    let output = output.permuted(inverse_perm.as_slice()).to_tensor_in(pool);
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct DFT {
+    /// Axis along which to compute the transform. This is overridden by the
+    /// optional `axis` input, if present.
+    pub axis: isize,
+    pub inverse: bool,
+    pub onesided: bool,
+}
+
+impl Operator for DFT {
+    fn name(&self) -> &str {
+        "DFT"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        let dft_length = inputs
+            .get_as_scalar::<i32>(1)?
+            .map(|len| {
+                if len > 0 {
+                    Ok(len as usize)
+                } else {
+                    Err(OpError::InvalidValue("DFT length must be > 0"))
+                }
+            })
+            .transpose()?;
+        let axis = inputs
+            .get_as_scalar::<i32>(2)?
+            .map(|axis| axis as isize)
+            .unwrap_or(self.axis);
+
+        dft(pool, input, dft_length, axis, self.inverse, self.onesided).into_op_result()
+    }
+}
+
+/// Compute the short-time Fourier transform of a batch of signals.
+///
+/// `signal` has shape `[batch, signal_length, 1]` for real signals or
+/// `[batch, signal_length, 2]` for complex signals. Frames of `frame_length`
+/// samples are taken every `frame_step` samples, multiplied by `window` and
+/// then transformed.
+///
+/// The output has shape `[batch, frames, bins, 2]`, where `bins` is
+/// `frame_length / 2 + 1` if `onesided` is true, or `frame_length` otherwise.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__STFT.html>.
+pub fn stft(
+    pool: &TensorPool,
+    signal: NdTensorView<f32, 3>,
+    frame_step: usize,
+    window: Option<NdTensorView<f32, 1>>,
+    frame_length: Option<usize>,
+    onesided: bool,
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let [batch, signal_len, n_components] = signal.shape();
+    let n_components = signal_components(n_components)?;
+
+    let frame_length =
+        frame_length
+            .or(window.as_ref().map(|w| w.size(0)))
+            .ok_or(OpError::InvalidValue(
+                "Either `window` or `frame_length` must be specified",
+            ))?;
+    if window.as_ref().is_some_and(|w| w.size(0) != frame_length) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Window length does not match frame length",
+        ));
+    }
+    if frame_step == 0 {
+        return Err(OpError::InvalidValue("Frame step must be > 0"));
+    }
+    if frame_length == 0 || frame_length > signal_len {
+        return Err(OpError::InvalidValue(
+            "Frame length must be > 0 and <= signal length",
+        ));
+    }
+    if onesided && n_components != 1 {
+        return Err(OpError::UnsupportedValue(
+            "Onesided output is only supported for real signals",
+        ));
+    }
+
+    let n_frames = (signal_len - frame_length) / frame_step + 1;
+    let n_bins = if onesided {
+        frame_length / 2 + 1
+    } else {
+        frame_length
+    };
+
+    let signal = signal.to_contiguous_in(pool).auto_return(pool);
+    let in_data = signal.data().unwrap();
+    let window = window.map(|w| w.to_vec());
+
+    let mut output = NdTensor::zeros_in(pool, [batch, n_frames, n_bins, 2]);
+    let transform = SignalTransform::new(frame_length, n_components == 1, false /* inverse */);
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(n_bins * 2)
+        .enumerate()
+        .for_each_init(TransformScratch::default, |scratch, (i, out_frame)| {
+            let (batch_idx, frame_idx) = (i / n_frames, i % n_frames);
+            let start = (batch_idx * signal_len + frame_idx * frame_step) * n_components;
+            let frame = &in_data[start..start + frame_length * n_components];
+            let samples = signal_samples(frame, n_components);
+
+            if let Some(window) = &window {
+                let windowed = samples.zip(window).map(|((re, im), &w)| (re * w, im * w));
+                transform.run(windowed, out_frame, scratch);
+            } else {
+                transform.run(samples, out_frame, scratch);
+            }
+        });
+
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct STFT {
+    pub onesided: bool,
+}
+
+impl Operator for STFT {
+    fn name(&self) -> &str {
+        "STFT"
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let signal = inputs.require_as(0)?;
+        let signal = static_dims!(signal, 3, "NLC")?;
+
+        let frame_step: i32 = inputs.require_as_scalar(1)?;
+        let frame_step = usize::try_from(frame_step)
+            .map_err(|_| OpError::InvalidValue("Frame step must be > 0"))?;
+
+        let window = inputs.get_as(2)?;
+        let window = window.map(|w| static_dims!(w, 1)).transpose()?;
+
+        let frame_length = inputs
+            .get_as_scalar::<i32>(3)?
+            .map(|len| {
+                usize::try_from(len).map_err(|_| {
+                    OpError::InvalidValue("Frame length must be > 0 and <= signal length")
+                })
+            })
+            .transpose()?;
+
+        let output = stft(
+            pool,
+            signal,
+            frame_step,
+            window,
+            frame_length,
+            self.onesided,
+        )?;
+        output.into_op_result()
+    }
+}
+
+/// Generate a window of the form `sum_k (-1)^k a_k cos(2πkn / N)`, where `a`
+/// are the `coeffs`.
+///
+/// `N` is `size` for periodic windows or `size - 1` for symmetric windows.
+fn cosine_sum_window(size: usize, periodic: bool, coeffs: &[f64]) -> NdTensor<f32, 1> {
+    let denom = if periodic {
+        size
+    } else {
+        size.saturating_sub(1)
+    }
+    .max(1) as f64;
+    NdTensor::from_fn([size], |[n]| {
+        let phase = 2. * PI * n as f64 / denom;
+        coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, a)| {
+                let sign = if k % 2 == 0 { 1. } else { -1. };
+                sign * a * (k as f64 * phase).cos()
+            })
+            .sum::<f64>() as f32
+    })
+}
+
+/// Generate a Hann window.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__HannWindow.html>.
+pub fn hann_window(size: usize, periodic: bool) -> NdTensor<f32, 1> {
+    cosine_sum_window(size, periodic, &[0.5, 0.5])
+}
+
+/// Generate a Hamming window.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__HammingWindow.html>.
+pub fn hamming_window(size: usize, periodic: bool) -> NdTensor<f32, 1> {
+    cosine_sum_window(size, periodic, &[25. / 46., 21. / 46.])
+}
+
+/// Generate a Blackman window.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__BlackmanWindow.html>.
+pub fn blackman_window(size: usize, periodic: bool) -> NdTensor<f32, 1> {
+    cosine_sum_window(size, periodic, &[0.42, 0.5, 0.08])
+}
+
+/// Read the window size input for a window operator.
+fn window_size(inputs: &InputList) -> Result<usize, OpError> {
+    let size: i32 = inputs.require_as_scalar(0)?;
+    usize::try_from(size).map_err(|_| OpError::InvalidValue("Window size must be >= 0"))
+}
+
+macro_rules! impl_window_op {
+    ($op_name:ident, $window_fn:ident) => {
+        #[derive(Debug)]
+        pub struct $op_name {
+            pub periodic: bool,
+        }
+
+        impl Operator for $op_name {
+            fn name(&self) -> &str {
+                stringify!($op_name)
+            }
+
+            fn run(&self, _pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+                let size = window_size(&inputs)?;
+                $window_fn(size, self.periodic).into_op_result()
+            }
+        }
+    };
+}
+
+impl_window_op!(BlackmanWindow, blackman_window);
+impl_window_op!(HammingWindow, hamming_window);
+impl_window_op!(HannWindow, hann_window);
+
+fn hz_to_mel(hz: f64) -> f64 {
+    2595. * (1. + hz / 700.).log10()
+}
+
+fn mel_to_hz(mel: f64) -> f64 {
+    700. * (10f64.powf(mel / 2595.) - 1.)
+}
+
+/// Generate a matrix which maps a linear magnitude spectrogram with
+/// `dft_length / 2 + 1` bins to `num_mel_bins` bins on the mel scale.
+///
+/// The output has shape `[dft_length / 2 + 1, num_mel_bins]`. Each column is a
+/// triangular filter whose edges are evenly spaced on the mel scale between
+/// `lower_edge_hertz` and `upper_edge_hertz`.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__MelWeightMatrix.html>.
+pub fn mel_weight_matrix(
+    num_mel_bins: usize,
+    dft_length: usize,
+    sample_rate: usize,
+    lower_edge_hertz: f32,
+    upper_edge_hertz: f32,
+) -> Result<NdTensor<f32, 2>, OpError> {
+    if sample_rate == 0 {
+        return Err(OpError::InvalidValue("Sample rate must be > 0"));
+    }
+    if lower_edge_hertz < 0. || lower_edge_hertz > upper_edge_hertz {
+        return Err(OpError::InvalidValue(
+            "Frequency range must satisfy 0 <= lower_edge_hertz <= upper_edge_hertz",
+        ));
+    }
+
+    let num_spectrogram_bins = dft_length / 2 + 1;
+
+    // Map evenly spaced points on the mel scale to spectrogram bins. Points
+    // `i`, `i + 1` and `i + 2` are the left edge, center and right edge of the
+    // filter for mel bin `i`. The step matches the ONNX reference, which
+    // divides the range into `num_mel_bins + 2` steps, so the last point is
+    // one step below `upper_edge_hertz`.
+    let low_mel = hz_to_mel(lower_edge_hertz as f64);
+    let high_mel = hz_to_mel(upper_edge_hertz as f64);
+    let mel_step = (high_mel - low_mel) / (num_mel_bins + 2) as f64;
+    let edges: Vec<usize> = (0..num_mel_bins + 2)
+        .map(|i| {
+            let hz = mel_to_hz(low_mel + i as f64 * mel_step);
+            (((dft_length + 1) as f64 * hz) / sample_rate as f64).floor() as usize
+        })
+        .collect();
+
+    let mut output = NdTensor::zeros([num_spectrogram_bins, num_mel_bins]);
+    for (i, edges) in edges.windows(3).enumerate() {
+        let [left, center, right] = [edges[0], edges[1], edges[2]];
+
+        // Bins outside the spectrogram are ignored. This happens if
+        // `upper_edge_hertz` exceeds the Nyquist frequency.
+        let mut set_weight = |bin: usize, weight: f32| {
+            if let Some(el) = output.get_mut([bin, i]) {
+                *el = weight;
+            }
+        };
+
+        if center == left {
+            set_weight(center, 1.);
+        } else {
+            for bin in left..=center {
+                set_weight(bin, (bin - left) as f32 / (center - left) as f32);
+            }
+        }
+        for bin in center..right {
+            set_weight(bin, (right - bin) as f32 / (right - center) as f32);
+        }
+    }
+
+    Ok(output)
+}
+
+#[derive(Debug)]
+pub struct MelWeightMatrix {}
+
+impl Operator for MelWeightMatrix {
+    fn name(&self) -> &str {
+        "MelWeightMatrix"
+    }
+
+    fn run(&self, _pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let read_size = |index: usize| -> Result<usize, OpError> {
+            let size: i32 = inputs.require_as_scalar(index)?;
+            usize::try_from(size).map_err(|_| OpError::InvalidValue("Size must be >= 0"))
+        };
+        let num_mel_bins = read_size(0)?;
+        let dft_length = read_size(1)?;
+        let sample_rate = read_size(2)?;
+        let lower_edge_hertz = inputs.require_as_scalar(3)?;
+        let upper_edge_hertz = inputs.require_as_scalar(4)?;
+
+        let matrix = mel_weight_matrix(
+            num_mel_bins,
+            dft_length,
+            sample_rate,
+            lower_edge_hertz,
+            upper_edge_hertz,
+        )?;
+        matrix.into_op_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal;
+    use rten_tensor::{NdTensor, Tensor};
+
+    use crate::ops::tests::{expect_eq_1e4, new_pool};
+    use crate::ops::{
+        blackman_window, dft, hamming_window, hann_window, mel_weight_matrix, stft, OpError,
+    };
+
+    /// Compute the DFT of a complex signal using the O(n²) definition.
+    fn reference_dft(signal: &[(f32, f32)], inverse: bool) -> Vec<(f32, f32)> {
+        let n = signal.len();
+        let sign = if inverse { 1. } else { -1. };
+        let scale = if inverse { 1. / n as f32 } else { 1. };
+        (0..n)
+            .map(|k| {
+                let (mut re, mut im) = (0., 0.);
+                for (j, &(x_re, x_im)) in signal.iter().enumerate() {
+                    let angle = sign * 2. * PI * ((j * k) % n) as f32 / n as f32;
+                    let (sin, cos) = angle.sin_cos();
+                    re += x_re * cos - x_im * sin;
+                    im += x_re * sin + x_im * cos;
+                }
+                (re * scale, im * scale)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dft() -> Result<(), Box<dyn std::error::Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Real input of shape [batch, signal, 1], transformed along the
+        // signal axis.
+        let input = Tensor::rand(&[2, 10, 1], &mut rng);
+        let result = dft(&pool, input.view(), None, 1, false, false)?;
+        assert_eq!(result.shape(), [2, 10, 2]);
+
+        let mut expected = Tensor::zeros(&[2, 10, 2]);
+        for b in 0..2 {
+            let signal: Vec<_> = input
+                .slice::<1, _>((b, .., 0))
+                .iter()
+                .map(|&x| (x, 0.))
+                .collect();
+            for (k, (re, im)) in reference_dft(&signal, false).into_iter().enumerate() {
+                expected[[b, k, 0]] = re;
+                expected[[b, k, 1]] = im;
+            }
+        }
+        expect_eq_1e4(&result, &expected)?;
+
+        // Onesided output returns the first `n / 2 + 1` bins.
+        let result = dft(&pool, input.view(), None, 1, false, true)?;
+        assert_eq!(result.shape(), [2, 6, 2]);
+        expect_eq_1e4(&result, &expected.slice_dyn((.., ..6, ..)).to_tensor())?;
+
+        // Padding and truncation with `dft_length`.
+        for dft_length in [7, 16] {
+            let result = dft(&pool, input.view(), Some(dft_length), 1, false, false)?;
+            assert_eq!(result.shape(), [2, dft_length, 2]);
+
+            let mut signal: Vec<_> = input
+                .slice::<1, _>((0, .., 0))
+                .iter()
+                .map(|&x| (x, 0.))
+                .collect();
+            signal.resize(dft_length, (0., 0.));
+            for (k, (re, im)) in reference_dft(&signal[..dft_length], false)
+                .into_iter()
+                .enumerate()
+            {
+                assert!((result[[0, k, 0]] - re).abs() < 1e-4);
+                assert!((result[[0, k, 1]] - im).abs() < 1e-4);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dft_complex_axis() -> Result<(), Box<dyn std::error::Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        // Complex input of shape [3, 5, 2], transformed along axis 0, then
+        // inverted to recover the input.
+        let input = Tensor::rand(&[3, 5, 2], &mut rng);
+        let result = dft(&pool, input.view(), None, 0, false, false)?;
+        assert_eq!(result.shape(), [3, 5, 2]);
+
+        for col in 0..5 {
+            let signal: Vec<_> = (0..3)
+                .map(|i| (input[[i, col, 0]], input[[i, col, 1]]))
+                .collect();
+            for (k, (re, im)) in reference_dft(&signal, false).into_iter().enumerate() {
+                assert!((result[[k, col, 0]] - re).abs() < 1e-4);
+                assert!((result[[k, col, 1]] - im).abs() < 1e-4);
+            }
+        }
+
+        let inverted = dft(&pool, result.view(), None, 0, true, false)?;
+        expect_eq_1e4(&inverted, &input)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dft_invalid() {
+        let pool = new_pool();
+        let input = Tensor::<f32>::zeros(&[2, 4, 2]);
+
+        let result = dft(&pool, input.view(), None, 2, false, false);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Axis must not be the last dimension of the input"
+            ))
+        );
+
+        let result = dft(&pool, input.view(), None, 1, false, true);
+        assert_eq!(
+            result.err(),
+            Some(OpError::UnsupportedValue(
+                "Onesided output is only supported for forward transforms of real signals"
+            ))
+        );
+
+        let input = Tensor::<f32>::zeros(&[2, 4, 3]);
+        let result = dft(&pool, input.view(), None, 1, false, false);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Last dimension of signal must have size 1 or 2"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_stft() -> Result<(), Box<dyn std::error::Error>> {
+        let pool = new_pool();
+        let mut rng = XorShiftRng::new(1234);
+
+        let signal = NdTensor::rand([2, 20, 1], &mut rng);
+        let window = hann_window(8, true);
+        let frame_step = 5;
+
+        let result = stft(
+            &pool,
+            signal.view(),
+            frame_step,
+            Some(window.view()),
+            None,
+            true,
+        )?;
+        assert_eq!(result.shape(), [2, 3, 5, 2]);
+
+        for b in 0..2 {
+            for frame in 0..3 {
+                let frame_signal: Vec<_> = (0..8)
+                    .map(|i| (signal[[b, frame * frame_step + i, 0]] * window[[i]], 0.))
+                    .collect();
+                let expected = reference_dft(&frame_signal, false);
+                for (k, (re, im)) in expected.into_iter().take(5).enumerate() {
+                    assert!((result[[b, frame, k, 0]] - re).abs() < 1e-4);
+                    assert!((result[[b, frame, k, 1]] - im).abs() < 1e-4);
+                }
+            }
+        }
+
+        // Without a window, each frame is equivalent to a DFT of the frame.
+        let result = stft(&pool, signal.view(), frame_step, None, Some(8), false)?;
+        assert_eq!(result.shape(), [2, 3, 8, 2]);
+        let frame = signal.slice::<3, _>((.., 5..13, ..));
+        let expected = dft(&pool, frame.as_dyn(), None, 1, false, false)?;
+        expect_eq_1e4(&result.slice_dyn((.., 1, .., ..)).to_tensor(), &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stft_invalid() {
+        let pool = new_pool();
+        let signal = NdTensor::<f32, 3>::zeros([1, 16, 1]);
+        let window = hann_window(4, true);
+
+        let result = stft(&pool, signal.view(), 2, None, None, true);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Either `window` or `frame_length` must be specified"
+            ))
+        );
+
+        let result = stft(&pool, signal.view(), 2, Some(window.view()), Some(8), true);
+        assert_eq!(
+            result.err(),
+            Some(OpError::IncompatibleInputShapes(
+                "Window length does not match frame length"
+            ))
+        );
+
+        let result = stft(&pool, signal.view(), 0, Some(window.view()), None, true);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Frame step must be > 0"))
+        );
+    }
+
+    #[test]
+    fn test_windows() -> Result<(), Box<dyn std::error::Error>> {
+        let hann = hann_window(5, true);
+        expect_equal(
+            &hann,
+            &NdTensor::from([0., 0.3454915, 0.9045085, 0.9045085, 0.3454915]),
+        )?;
+        let hann = hann_window(5, false);
+        expect_equal(&hann, &NdTensor::from([0., 0.5, 1., 0.5, 0.]))?;
+
+        let hamming = hamming_window(4, true);
+        expect_equal(
+            &hamming,
+            &NdTensor::from([0.08695652, 0.54347826, 1., 0.54347826]),
+        )?;
+
+        let blackman = blackman_window(5, false);
+        expect_equal(&blackman, &NdTensor::from([0., 0.34, 1., 0.34, 0.]))?;
+
+        assert_eq!(hann_window(0, true).shape(), [0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mel_weight_matrix() -> Result<(), Box<dyn std::error::Error>> {
+        // Expected values computed using the ONNX reference implementation
+        // (`onnx.reference.ops.op_mel_weight_matrix`).
+        let result = mel_weight_matrix(4, 16, 8000, 0., 4000.)?;
+        let expected = NdTensor::from([
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+            [0., 0., 0., 0.5],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        expect_equal(&result, &expected)?;
+
+        let result = mel_weight_matrix(4, 32, 8000, 100., 3500.)?;
+        let expected = NdTensor::from([
+            [0., 0., 0., 0.],
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0.5, 0.5, 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0.6666667, 0.3333333],
+            [0., 0., 0.3333333, 0.6666667],
+            [0., 0., 0., 1.],
+            [0., 0., 0., 0.6666667],
+            [0., 0., 0., 0.3333333],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+            [0., 0., 0., 0.],
+        ]);
+        expect_equal(&result, &expected)?;
+
+        let result = mel_weight_matrix(8, 16, 0, 0., 4096.);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Sample rate must be > 0"))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,383 @@
+//! Fast Fourier Transforms used by the DFT and STFT operators.
+
+use std::f64::consts::PI;
+use std::ops::{Add, Mul, Sub};
+
+/// Complex number with single-precision components.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Complex {
+    pub re: f32,
+    pub im: f32,
+}
+
+impl Complex {
+    pub fn new(re: f32, im: f32) -> Complex {
+        Complex { re, im }
+    }
+
+    /// Return `exp(i * theta)`.
+    fn from_angle(theta: f64) -> Complex {
+        Complex::new(theta.cos() as f32, theta.sin() as f32)
+    }
+
+    pub fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn scale(self, factor: f32) -> Complex {
+        Complex::new(self.re * factor, self.im * factor)
+    }
+
+    /// Multiply by `-i`.
+    fn mul_neg_i(self) -> Complex {
+        Complex::new(self.im, -self.re)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+enum Algorithm {
+    /// Iterative radix-2 Cooley-Tukey FFT for power-of-two lengths.
+    ///
+    /// `twiddles[k]` is `exp(-2πik / len)` for `k` in `[0, len / 2)`.
+    Radix2 { twiddles: Vec<Complex> },
+
+    /// Bluestein's algorithm, which re-expresses a DFT of arbitrary length
+    /// as a convolution. The convolution is evaluated using power-of-two
+    /// FFTs of length `inner.len()`.
+    ///
+    /// `chirp[k]` is `exp(-πik² / len)` and `kernel` is the FFT of the
+    /// conjugated chirp, wrapped around to length `inner.len()`.
+    Bluestein {
+        inner: Box<FftPlan>,
+        chirp: Vec<Complex>,
+        kernel: Vec<Complex>,
+    },
+}
+
+/// Pre-computed data for evaluating DFTs of complex signals of a given length.
+pub struct FftPlan {
+    len: usize,
+    algorithm: Algorithm,
+}
+
+impl FftPlan {
+    pub fn new(len: usize) -> FftPlan {
+        let algorithm = if len <= 1 || len.is_power_of_two() {
+            Algorithm::Radix2 {
+                twiddles: (0..len / 2)
+                    .map(|k| Complex::from_angle(-2. * PI * k as f64 / len as f64))
+                    .collect(),
+            }
+        } else {
+            let inner = FftPlan::new((2 * len - 1).next_power_of_two());
+            let chirp: Vec<Complex> = (0..len)
+                .map(|k| {
+                    // Reduce k² modulo 2 * len to avoid losing precision in
+                    // the angle for large `k`.
+                    let k_sqr = (k as u64 * k as u64) % (2 * len as u64);
+                    Complex::from_angle(-PI * k_sqr as f64 / len as f64)
+                })
+                .collect();
+
+            let mut kernel = vec![Complex::default(); inner.len()];
+            kernel[0] = chirp[0].conj();
+            for k in 1..len {
+                kernel[k] = chirp[k].conj();
+                kernel[inner.len() - k] = chirp[k].conj();
+            }
+            inner.forward(&mut kernel, &mut Vec::new());
+
+            Algorithm::Bluestein {
+                inner: Box::new(inner),
+                chirp,
+                kernel,
+            }
+        };
+        FftPlan { len, algorithm }
+    }
+
+    /// Return the signal length this plan was created for.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Replace `data` with its DFT.
+    ///
+    /// `scratch` is a buffer which is resized as needed and can be re-used
+    /// across calls.
+    pub fn forward(&self, data: &mut [Complex], scratch: &mut Vec<Complex>) {
+        assert_eq!(data.len(), self.len);
+
+        match &self.algorithm {
+            Algorithm::Radix2 { twiddles } => radix2_fft(data, twiddles),
+            Algorithm::Bluestein {
+                inner,
+                chirp,
+                kernel,
+            } => {
+                let inner_len = inner.len();
+                scratch.clear();
+                scratch.extend(data.iter().zip(chirp).map(|(&x, &w)| x * w));
+                scratch.resize(inner_len, Complex::default());
+
+                // Convolve with the kernel by multiplying in the frequency
+                // domain. The inner plan is radix-2 so needs no scratch space.
+                inner.forward(scratch, &mut Vec::new());
+                for (x, &k) in scratch.iter_mut().zip(kernel) {
+                    *x = *x * k;
+                }
+                inner.inverse(scratch, &mut Vec::new());
+
+                let norm = 1. / inner_len as f32;
+                for ((out, &x), &w) in data.iter_mut().zip(scratch.iter()).zip(chirp) {
+                    *out = (x * w).scale(norm);
+                }
+            }
+        }
+    }
+
+    /// Replace `data` with its inverse DFT.
+    ///
+    /// The result is not normalized, so it is scaled by `len` compared to the
+    /// input of [`forward`](FftPlan::forward).
+    pub fn inverse(&self, data: &mut [Complex], scratch: &mut Vec<Complex>) {
+        for x in data.iter_mut() {
+            *x = x.conj();
+        }
+        self.forward(data, scratch);
+        for x in data.iter_mut() {
+            *x = x.conj();
+        }
+    }
+}
+
+fn radix2_fft(data: &mut [Complex], twiddles: &[Complex]) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if j > i {
+            data.swap(i, j);
+        }
+    }
+
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+        let twiddle_step = n / size;
+        for block in data.chunks_exact_mut(size) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (j, (a, b)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                let t = *b * twiddles[j * twiddle_step];
+                *b = *a - t;
+                *a = *a + t;
+            }
+        }
+        size *= 2;
+    }
+}
+
+/// Pre-computed data for evaluating DFTs of real signals of a given length.
+///
+/// The DFT of a real signal is conjugate-symmetric, so only the first
+/// `len / 2 + 1` bins are computed. For even lengths, the signal is packed
+/// into a complex signal of half the length, which is transformed and then
+/// unpacked. This does roughly half the work of a complex FFT.
+pub struct RealFftPlan {
+    len: usize,
+    inner: FftPlan,
+
+    /// `exp(-2πik / len)` for `k` in `[0, len / 2)`. Empty if `len` is odd.
+    twiddles: Vec<Complex>,
+}
+
+impl RealFftPlan {
+    pub fn new(len: usize) -> RealFftPlan {
+        if len.is_multiple_of(2) {
+            let half = len / 2;
+            RealFftPlan {
+                len,
+                inner: FftPlan::new(half),
+                twiddles: (0..half)
+                    .map(|k| Complex::from_angle(-2. * PI * k as f64 / len as f64))
+                    .collect(),
+            }
+        } else {
+            RealFftPlan {
+                len,
+                inner: FftPlan::new(len),
+                twiddles: Vec::new(),
+            }
+        }
+    }
+
+    /// Return the number of bins computed by [`forward`](RealFftPlan::forward).
+    pub fn output_len(&self) -> usize {
+        self.len / 2 + 1
+    }
+
+    /// Compute the first `len / 2 + 1` bins of the DFT of `input`.
+    ///
+    /// The remaining bins can be obtained from `X[len - k] = conj(X[k])`.
+    pub fn forward(&self, input: &[f32], output: &mut [Complex], scratch: &mut Vec<Complex>) {
+        assert_eq!(input.len(), self.len);
+        assert_eq!(output.len(), self.output_len());
+
+        if self.len % 2 == 1 {
+            let mut buf: Vec<Complex> = input.iter().map(|&x| Complex::new(x, 0.)).collect();
+            self.inner.forward(&mut buf, scratch);
+            output.copy_from_slice(&buf[..output.len()]);
+            return;
+        }
+
+        let half = self.len / 2;
+        if half == 0 {
+            output[0] = Complex::default();
+            return;
+        }
+
+        // Pack even samples into the real part and odd samples into the
+        // imaginary part, then transform.
+        for (out, pair) in output.iter_mut().zip(input.chunks_exact(2)) {
+            *out = Complex::new(pair[0], pair[1]);
+        }
+        self.inner.forward(&mut output[..half], scratch);
+
+        // Separate the transforms of the even and odd samples (`even` and
+        // `odd`) and combine them to get the transform of the full signal.
+        //
+        // Bins `k` and `half - k` are computed together since each depends on
+        // the packed values at both positions.
+        let z0 = output[0];
+        output[0] = Complex::new(z0.re + z0.im, 0.);
+        output[half] = Complex::new(z0.re - z0.im, 0.);
+
+        for k in 1..=half / 2 {
+            let zk = output[k];
+            let zr = output[half - k];
+
+            let even = (zk + zr.conj()).scale(0.5);
+            let odd = (zk - zr.conj()).mul_neg_i().scale(0.5);
+            let even_r = (zr + zk.conj()).scale(0.5);
+            let odd_r = (zr - zk.conj()).mul_neg_i().scale(0.5);
+
+            output[k] = even + self.twiddles[k] * odd;
+            output[half - k] = even_r + self.twiddles[half - k] * odd_r;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use rten_tensor::rng::XorShiftRng;
+
+    use super::{Complex, FftPlan, RealFftPlan};
+
+    /// Compute the DFT using the O(n²) definition.
+    fn reference_dft(input: &[Complex]) -> Vec<Complex> {
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                let (mut re, mut im) = (0f64, 0f64);
+                for (j, x) in input.iter().enumerate() {
+                    let angle = -2. * PI * ((j * k) % n) as f64 / n as f64;
+                    let (sin, cos) = angle.sin_cos();
+                    re += x.re as f64 * cos - x.im as f64 * sin;
+                    im += x.re as f64 * sin + x.im as f64 * cos;
+                }
+                Complex::new(re as f32, im as f32)
+            })
+            .collect()
+    }
+
+    fn assert_close(actual: &[Complex], expected: &[Complex]) {
+        assert_eq!(actual.len(), expected.len());
+        let tolerance = 1e-4 * (expected.len().max(1) as f32);
+        for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+            assert!(
+                (a.re - e.re).abs() < tolerance && (a.im - e.im).abs() < tolerance,
+                "mismatch at {}: {:?} vs {:?}",
+                i,
+                a,
+                e
+            );
+        }
+    }
+
+    fn random_signal(rng: &mut XorShiftRng, len: usize) -> Vec<Complex> {
+        (0..len)
+            .map(|_| Complex::new(rng.next_f32() - 0.5, rng.next_f32() - 0.5))
+            .collect()
+    }
+
+    #[test]
+    fn test_fft() {
+        let mut rng = XorShiftRng::new(1234);
+        let mut scratch = Vec::new();
+
+        for len in [0, 1, 2, 3, 5, 8, 12, 17, 64, 100, 400] {
+            let input = random_signal(&mut rng, len);
+            let expected = reference_dft(&input);
+
+            let plan = FftPlan::new(len);
+            let mut output = input.clone();
+            plan.forward(&mut output, &mut scratch);
+            assert_close(&output, &expected);
+
+            // Inverse transform should recover the input, scaled by `len`.
+            plan.inverse(&mut output, &mut scratch);
+            let scale = 1. / len.max(1) as f32;
+            let output: Vec<_> = output.iter().map(|x| x.scale(scale)).collect();
+            assert_close(&output, &input);
+        }
+    }
+
+    #[test]
+    fn test_real_fft() {
+        let mut rng = XorShiftRng::new(1234);
+        let mut scratch = Vec::new();
+
+        for len in [1, 2, 3, 4, 6, 9, 16, 30, 400, 511] {
+            let input: Vec<f32> = (0..len).map(|_| rng.next_f32() - 0.5).collect();
+            let complex_input: Vec<_> = input.iter().map(|&x| Complex::new(x, 0.)).collect();
+            let expected = reference_dft(&complex_input);
+
+            let plan = RealFftPlan::new(len);
+            let mut output = vec![Complex::default(); plan.output_len()];
+            plan.forward(&input, &mut output, &mut scratch);
+            assert_close(&output, &expected[..plan.output_len()]);
+        }
+    }
+}
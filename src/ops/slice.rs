@@ -6,7 +6,8 @@ use rten_tensor::{NdTensorView, SliceItem, SliceRange, Tensor, TensorView};
 use smallvec::SmallVec;
 
 use crate::ops::{
-    resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output, OutputList,
+    is_same_op, resolve_axis, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    OutputList, ShapeInputs,
 };
 use crate::static_dims;
 use crate::tensor_pool::TensorPool;
@@ -80,7 +81,7 @@ pub fn slice_in_place<T: Copy>(
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Slice {}
 
 impl Operator for Slice {
@@ -88,6 +89,10 @@ impl Operator for Slice {
         "Slice"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
 
@@ -118,6 +123,25 @@ impl Operator for Slice {
         result.into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        let shape = inputs.shape(0)?;
+        let starts = inputs.constant_vec_as(1)?;
+        let ends = inputs.constant_vec_as(2)?;
+        let axes = match inputs.shape(3) {
+            Some(_) => Some(inputs.constant_vec_as(3)?),
+            None => None,
+        };
+        let steps = match inputs.shape(4) {
+            Some(_) => Some(inputs.constant_vec_as(4)?),
+            None => None,
+        };
+        let ranges = slice_ranges(shape, &starts, &ends, axes.as_ref(), steps.as_ref()).ok()?;
+        let out_shape = zip(ranges, shape)
+            .map(|(range, &dim_size)| range.steps(dim_size))
+            .collect();
+        Some(vec![out_shape])
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -47,9 +47,29 @@ pub fn split<T: Copy>(
     Ok(outputs)
 }
 
+/// Compute the sizes of `num_outputs` chunks which split a dimension of size
+/// `dim_size`.
+///
+/// Each chunk has size `ceil(dim_size / num_outputs)`, except for the last
+/// non-empty chunk, which may be smaller.
+fn even_split_sizes(dim_size: usize, num_outputs: usize) -> Vec<i32> {
+    let chunk_size = dim_size.div_ceil(num_outputs);
+    (0..num_outputs)
+        .map(|i| {
+            let start = (i * chunk_size).min(dim_size);
+            let end = (start + chunk_size).min(dim_size);
+            (end - start) as i32
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct Split {
     pub axis: isize,
+
+    /// Number of outputs to split the input into if the `split` input is
+    /// not provided.
+    pub num_outputs: Option<u32>,
 }
 
 impl Operator for Split {
@@ -59,11 +79,24 @@ impl Operator for Split {
 
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require_as::<f32>(0)?;
-        let splits = inputs.require_as::<i32>(1)?;
-        let splits = static_dims!(splits, 1)?;
-
-        split(pool, input, self.axis, &splits)
-            .map(|tensors| tensors.into_iter().map(|t| t.into()).collect())
+        let splits = inputs.get_as::<i32>(1)?;
+
+        let outputs = match (splits, self.num_outputs) {
+            (Some(splits), _) => {
+                let splits = static_dims!(splits, 1)?;
+                split(pool, input, self.axis, &splits)
+            }
+            (None, Some(0)) => Err(OpError::InvalidValue("num_outputs must be > 0")),
+            (None, Some(num_outputs)) => {
+                let axis = resolve_axis(input.ndim(), self.axis)?;
+                let splits = even_split_sizes(input.size(axis), num_outputs as usize);
+                let splits = NdTensorView::from_data([splits.len()], splits.as_slice());
+                split(pool, input, self.axis, &splits)
+            }
+            (None, None) => Err(OpError::MissingInputs),
+        };
+
+        outputs.map(|tensors| tensors.into_iter().map(|t| t.into()).collect())
     }
 }
 
@@ -73,7 +106,7 @@ mod tests {
     use rten_tensor::Tensor;
 
     use crate::ops::tests::new_pool;
-    use crate::ops::{split, OpError};
+    use crate::ops::{split, InputList, OpError, Operator, Split};
 
     #[test]
     fn test_split() {
@@ -98,6 +131,61 @@ mod tests {
         assert_eq!(results[1].data().unwrap(), &[1., 3., 5., 7., 9.]);
     }
 
+    #[test]
+    fn test_split_num_outputs() {
+        let pool = new_pool();
+        let input = Tensor::from([0., 1., 2., 3., 4.]);
+
+        struct Case {
+            num_outputs: Option<u32>,
+            expected: Result<Vec<Vec<f32>>, OpError>,
+        }
+
+        let cases = [
+            Case {
+                num_outputs: Some(1),
+                expected: Ok(vec![vec![0., 1., 2., 3., 4.]]),
+            },
+            // Last chunk is smaller if the input is not evenly divisible.
+            Case {
+                num_outputs: Some(2),
+                expected: Ok(vec![vec![0., 1., 2.], vec![3., 4.]]),
+            },
+            Case {
+                num_outputs: Some(5),
+                expected: Ok(vec![vec![0.], vec![1.], vec![2.], vec![3.], vec![4.]]),
+            },
+            Case {
+                num_outputs: Some(0),
+                expected: Err(OpError::InvalidValue("num_outputs must be > 0")),
+            },
+            Case {
+                num_outputs: None,
+                expected: Err(OpError::MissingInputs),
+            },
+        ];
+
+        for Case {
+            num_outputs,
+            expected,
+        } in cases
+        {
+            let op = Split {
+                axis: 0,
+                num_outputs,
+            };
+            let result = op
+                .run(&pool, InputList::from(&[input.view().into()]))
+                .map(|outputs| {
+                    outputs
+                        .into_iter()
+                        .map(|output| output.into_float().unwrap().to_vec())
+                        .collect::<Vec<_>>()
+                });
+            assert_eq!(result, expected);
+        }
+    }
+
     #[test]
     fn test_split_invalid_inputs() {
         let pool = new_pool();
@@ -7,14 +7,19 @@ use std::mem::MaybeUninit;
 use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView, TensorViewMut};
 use rten_vecmath::{
-    erf as erf_scalar, exp as exp_scalar, gelu as gelu_scalar, sigmoid as sigmoid_scalar,
-    silu as silu_scalar, tanh as tanh_scalar, vec_erf, vec_erf_in_place, vec_exp, vec_exp_in_place,
-    vec_gelu, vec_gelu_in_place, vec_sigmoid, vec_sigmoid_in_place, vec_silu, vec_silu_in_place,
-    vec_tanh, vec_tanh_in_place,
+    celu as celu_scalar, erf as erf_scalar, exp as exp_scalar, gelu as gelu_scalar,
+    mish as mish_scalar, selu as selu_scalar, sigmoid as sigmoid_scalar, silu as silu_scalar,
+    tanh as tanh_scalar, vec_celu, vec_celu_in_place, vec_erf, vec_erf_in_place, vec_exp,
+    vec_exp_in_place, vec_gelu, vec_gelu_in_place, vec_mish, vec_mish_in_place, vec_selu,
+    vec_selu_in_place, vec_sigmoid, vec_sigmoid_in_place, vec_silu, vec_silu_in_place, vec_tanh,
+    vec_tanh_in_place,
 };
 
 use crate::number::AsBool;
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, Output, OutputList};
+use crate::ops::{
+    infer_unary_shape, is_same_op, Input, InputList, IntoOpResult, OpError, Operator, Output,
+    OutputList, ShapeInputs,
+};
 use crate::tensor_pool::{AutoReturn, TensorPool};
 
 /// Trait for operators which take a single float tensor and apply a function
@@ -36,7 +41,7 @@ pub trait UnaryFloatOp {
     }
 }
 
-impl<Op: Any + Debug + UnaryFloatOp> Operator for Op {
+impl<Op: Any + Debug + PartialEq + UnaryFloatOp> Operator for Op {
     fn name(&self) -> &str {
         self.name()
     }
@@ -46,6 +51,10 @@ impl<Op: Any + Debug + UnaryFloatOp> Operator for Op {
         self.map(pool, input).into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -60,6 +69,14 @@ impl<Op: Any + Debug + UnaryFloatOp> Operator for Op {
         self.apply(output.view_mut());
         Ok(output.into())
     }
+
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
+    fn as_unary_float_op(&self) -> Option<&dyn UnaryFloatOp> {
+        Some(self)
+    }
 }
 
 /// Define a unary operator, with no arguments, which supports all numeric
@@ -69,7 +86,7 @@ impl<Op: Any + Debug + UnaryFloatOp> Operator for Op {
 /// operator to 1) an immutable view and 2) a mutable tensor.
 macro_rules! unary_numeric_op {
     ($name:ident, $view_impl:ident, $mut_impl:ident) => {
-        #[derive(Debug)]
+        #[derive(Debug, PartialEq)]
         pub struct $name {}
 
         impl Operator for $name {
@@ -77,6 +94,10 @@ macro_rules! unary_numeric_op {
                 stringify!($name)
             }
 
+            fn is_equivalent(&self, other: &dyn Operator) -> bool {
+                is_same_op(self, other)
+            }
+
             fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
                 let input = inputs.require(0)?;
                 match input {
@@ -85,6 +106,10 @@ macro_rules! unary_numeric_op {
                 }
             }
 
+            fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+                infer_unary_shape(inputs)
+            }
+
             fn can_run_in_place(&self) -> bool {
                 true
             }
@@ -131,7 +156,7 @@ macro_rules! unary_float_op {
     ($name:ident, $func_name:ident, $in_place_func_name:ident, $expr:expr) => {
         unary_float_funcs!($name, $func_name, $in_place_func_name);
 
-        #[derive(Debug)]
+        #[derive(Debug, PartialEq)]
         pub struct $name {}
 
         impl UnaryFloatOp for $name {
@@ -190,7 +215,7 @@ fn par_unary_op_in_place<T: Copy + Send, VF: Fn(&mut [T]) + Send + Sync, SF: Fn(
 /// and multithreading.
 macro_rules! parallel_unary_float_op {
     ($op_name:ident, $func_name:ident, $in_place_func_name:ident, $impl_func_name:ident, $impl_in_place_func_name:ident, $impl_scalar_name:ident) => {
-        #[derive(Debug)]
+        #[derive(Debug, PartialEq)]
         pub struct $op_name {}
 
         impl Operator for $op_name {
@@ -198,6 +223,10 @@ macro_rules! parallel_unary_float_op {
                 stringify!($op_name)
             }
 
+            fn is_equivalent(&self, other: &dyn Operator) -> bool {
+                is_same_op(self, other)
+            }
+
             fn can_run_in_place(&self) -> bool {
                 true
             }
@@ -206,6 +235,10 @@ macro_rules! parallel_unary_float_op {
                 $func_name(pool, inputs.require_as(0)?).into_op_result()
             }
 
+            fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+                infer_unary_shape(inputs)
+            }
+
             fn run_in_place(
                 &self,
                 _pool: &TensorPool,
@@ -331,7 +364,7 @@ pub fn clip_in_place<T: Copy + Clamp>(input: &mut Tensor<T>, min: Option<T>, max
 // TODO - Move `Clip` operator into another module since it is no longer a
 // unary op (it used to take `min` and `max` as attributes).
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Clip {}
 
 impl Operator for Clip {
@@ -339,6 +372,10 @@ impl Operator for Clip {
         "Clip"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require(0)?;
         match input {
@@ -355,6 +392,10 @@ impl Operator for Clip {
         }
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -382,9 +423,93 @@ impl Operator for Clip {
     }
 }
 
+/// Continuously Differentiable Exponential Linear Unit.
+///
+/// See <https://arxiv.org/abs/1704.07483>.
+#[derive(Debug, PartialEq)]
+pub struct Celu {
+    pub alpha: f32,
+}
+
+impl UnaryFloatOp for Celu {
+    fn name(&self) -> &str {
+        "Celu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        celu_scalar(val, self.alpha)
+    }
+
+    fn map(&self, pool: &TensorPool, input: TensorView) -> Tensor {
+        par_unary_op(pool, input, |xs, out| vec_celu(xs, out, self.alpha))
+    }
+
+    fn apply(&self, input: TensorViewMut) {
+        par_unary_op_in_place(
+            input,
+            |xs| vec_celu_in_place(xs, self.alpha),
+            |x| self.map_element(x),
+        )
+    }
+}
+
+pub fn celu(pool: &TensorPool, input: TensorView, alpha: f32) -> Tensor {
+    Celu { alpha }.map(pool, input)
+}
+
+pub fn celu_in_place(input: TensorViewMut, alpha: f32) {
+    Celu { alpha }.apply(input)
+}
+
+/// Invert the bits of each element in `input`.
+pub fn bitwise_not(pool: &TensorPool, input: TensorView<i32>) -> Tensor<i32> {
+    input.map_in(pool, |x| !x)
+}
+
+pub fn bitwise_not_in_place(mut input: TensorViewMut<i32>) {
+    input.apply(|x| !x);
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BitwiseNot {}
+
+impl Operator for BitwiseNot {
+    fn name(&self) -> &str {
+        "BitwiseNot"
+    }
+
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as::<i32>(0)?;
+        bitwise_not(pool, input).into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(
+        &self,
+        _pool: &TensorPool,
+        input: Output,
+        _: InputList,
+    ) -> Result<Output, OpError> {
+        let mut output = input.into_int().ok_or(OpError::IncorrectInputType)?;
+        bitwise_not_in_place(output.view_mut());
+        Ok(output.into())
+    }
+}
+
 unary_float_op!(Cos, cos, cos_in_place, |val: f32| val.cos());
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Elu {
     pub alpha: f32,
 }
@@ -446,7 +571,7 @@ parallel_unary_float_op!(
     gelu_scalar
 );
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct HardSigmoid {
     pub alpha: f32,
     pub beta: f32,
@@ -470,7 +595,7 @@ pub fn hard_sigmoid_in_place(input: TensorViewMut, alpha: f32, beta: f32) {
     HardSigmoid { alpha, beta }.apply(input)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct HardSwish {}
 
 impl UnaryFloatOp for HardSwish {
@@ -487,6 +612,75 @@ impl UnaryFloatOp for HardSwish {
 
 unary_float_funcs!(HardSwish, hard_swish, hard_swish_in_place);
 
+/// Return a boolean tensor indicating which elements of `input` are infinite.
+///
+/// `detect_negative` and `detect_positive` control whether negative and
+/// positive infinity respectively are detected.
+pub fn is_inf(
+    pool: &TensorPool,
+    input: TensorView,
+    detect_negative: bool,
+    detect_positive: bool,
+) -> Tensor<i32> {
+    input.map_in(pool, |x| {
+        let is_inf = (detect_negative && *x == f32::NEG_INFINITY)
+            || (detect_positive && *x == f32::INFINITY);
+        i32::from(is_inf)
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct IsInf {
+    pub detect_negative: bool,
+    pub detect_positive: bool,
+}
+
+impl Operator for IsInf {
+    fn name(&self) -> &str {
+        "IsInf"
+    }
+
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        is_inf(pool, input, self.detect_negative, self.detect_positive).into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+}
+
+/// Return a boolean tensor indicating which elements of `input` are NaN.
+pub fn is_nan(pool: &TensorPool, input: TensorView) -> Tensor<i32> {
+    input.map_in(pool, |x| i32::from(x.is_nan()))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct IsNaN {}
+
+impl Operator for IsNaN {
+    fn name(&self) -> &str {
+        "IsNaN"
+    }
+
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
+    fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+        let input = inputs.require_as(0)?;
+        is_nan(pool, input).into_op_result()
+    }
+
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+}
+
 pub fn leaky_relu(pool: &TensorPool, input: TensorView, alpha: f32) -> Tensor {
     LeakyRelu { alpha }.map(pool, input)
 }
@@ -495,7 +689,7 @@ pub fn leaky_relu_in_place(input: TensorViewMut, alpha: f32) {
     LeakyRelu { alpha }.apply(input)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct LeakyRelu {
     pub alpha: f32,
 }
@@ -516,6 +710,16 @@ impl UnaryFloatOp for LeakyRelu {
 
 unary_float_op!(Log, log, log_in_place, |val: f32| val.ln());
 
+// Mish function (https://arxiv.org/abs/1908.08681).
+parallel_unary_float_op!(
+    Mish,
+    mish,
+    mish_in_place,
+    vec_mish,
+    vec_mish_in_place,
+    mish_scalar
+);
+
 pub fn neg<T: Copy + std::ops::Neg<Output = T>>(
     pool: &TensorPool,
     input: TensorView<T>,
@@ -537,7 +741,7 @@ pub fn not_in_place(mut input: TensorViewMut<i32>) {
     input.apply(|x| i32::from(!x.as_bool()));
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Not {}
 
 impl Operator for Not {
@@ -545,11 +749,19 @@ impl Operator for Not {
         "Not"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let input = inputs.require_as::<i32>(0)?;
         not(pool, input).into_op_result()
     }
 
+    fn infer_shapes(&self, inputs: &ShapeInputs) -> Option<Vec<Vec<usize>>> {
+        infer_unary_shape(inputs)
+    }
+
     fn can_run_in_place(&self) -> bool {
         true
     }
@@ -573,7 +785,7 @@ unary_float_op!(Relu, relu, relu_in_place, |val: f32| val.max(0.));
 /// Round float values to the nearest integer. Values with a fractional part
 /// of 0.5 are rounded to the nearest even number, like `round` in Python and
 /// unlike `f32::round` in Rust.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Round {}
 impl UnaryFloatOp for Round {
     fn name(&self) -> &str {
@@ -594,6 +806,81 @@ pub fn round_in_place(x: TensorViewMut) {
     Round {}.apply(x)
 }
 
+/// Scaled Exponential Linear Unit.
+///
+/// See <https://arxiv.org/abs/1706.02515>.
+#[derive(Debug, PartialEq)]
+pub struct Selu {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+
+impl UnaryFloatOp for Selu {
+    fn name(&self) -> &str {
+        "Selu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        selu_scalar(val, self.alpha, self.gamma)
+    }
+
+    fn map(&self, pool: &TensorPool, input: TensorView) -> Tensor {
+        par_unary_op(pool, input, |xs, out| {
+            vec_selu(xs, out, self.alpha, self.gamma)
+        })
+    }
+
+    fn apply(&self, input: TensorViewMut) {
+        par_unary_op_in_place(
+            input,
+            |xs| vec_selu_in_place(xs, self.alpha, self.gamma),
+            |x| self.map_element(x),
+        )
+    }
+}
+
+pub fn selu(pool: &TensorPool, input: TensorView, alpha: f32, gamma: f32) -> Tensor {
+    Selu { alpha, gamma }.map(pool, input)
+}
+
+pub fn selu_in_place(input: TensorViewMut, alpha: f32, gamma: f32) {
+    Selu { alpha, gamma }.apply(input)
+}
+
+/// Shrink function.
+///
+/// This computes `x + bias` if `x < -lambd`, `x - bias` if `x > lambd` and
+/// zero otherwise.
+#[derive(Debug, PartialEq)]
+pub struct Shrink {
+    pub bias: f32,
+    pub lambd: f32,
+}
+
+impl UnaryFloatOp for Shrink {
+    fn name(&self) -> &str {
+        "Shrink"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        if val < -self.lambd {
+            val + self.bias
+        } else if val > self.lambd {
+            val - self.bias
+        } else {
+            0.
+        }
+    }
+}
+
+pub fn shrink(pool: &TensorPool, input: TensorView, bias: f32, lambd: f32) -> Tensor {
+    Shrink { bias, lambd }.map(pool, input)
+}
+
+pub fn shrink_in_place(input: TensorViewMut, bias: f32, lambd: f32) {
+    Shrink { bias, lambd }.apply(input)
+}
+
 parallel_unary_float_op!(
     Sigmoid,
     sigmoid,
@@ -654,6 +941,9 @@ unary_float_op!(Sqrt, sqrt, sqrt_in_place, |val: f32| val.sqrt());
 unary_float_op!(Softplus, softplus, softplus_in_place, |val: f32| {
     val.exp().ln_1p()
 });
+unary_float_op!(Softsign, softsign, softsign_in_place, |val: f32| {
+    val / (1. + val.abs())
+});
 unary_float_op!(Tan, tan, tan_in_place, |val: f32| val.tan());
 parallel_unary_float_op!(
     Tanh,
@@ -664,6 +954,33 @@ parallel_unary_float_op!(
     tanh_scalar
 );
 
+#[derive(Debug, PartialEq)]
+pub struct ThresholdedRelu {
+    pub alpha: f32,
+}
+
+impl UnaryFloatOp for ThresholdedRelu {
+    fn name(&self) -> &str {
+        "ThresholdedRelu"
+    }
+
+    fn map_element(&self, val: f32) -> f32 {
+        if val > self.alpha {
+            val
+        } else {
+            0.
+        }
+    }
+}
+
+pub fn thresholded_relu(pool: &TensorPool, input: TensorView, alpha: f32) -> Tensor {
+    ThresholdedRelu { alpha }.map(pool, input)
+}
+
+pub fn thresholded_relu_in_place(input: TensorViewMut, alpha: f32) {
+    ThresholdedRelu { alpha }.apply(input)
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -675,13 +992,15 @@ mod tests {
 
     use crate::ops::tests::new_pool;
     use crate::ops::{
-        abs, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, ceil, clip,
-        clip_in_place, cos, cos_in_place, elu, elu_in_place, erf, erf_in_place, exp, exp_in_place,
-        floor, gelu, gelu_in_place, hard_sigmoid, hard_swish, leaky_relu, leaky_relu_in_place, log,
-        log_in_place, neg, neg_in_place, not, not_in_place, reciprocal, relu, relu_in_place, round,
-        round_in_place, sigmoid, sigmoid_in_place, sign, sign_in_place, silu, silu_in_place, sin,
-        sin_in_place, softplus, softplus_in_place, sqrt, sqrt_in_place, tan, tan_in_place, tanh,
-        tanh_in_place,
+        abs, acos, acos_in_place, asin, asin_in_place, atan, atan_in_place, bitwise_not,
+        bitwise_not_in_place, ceil, celu, celu_in_place, clip, clip_in_place, cos, cos_in_place,
+        elu, elu_in_place, erf, erf_in_place, exp, exp_in_place, floor, gelu, gelu_in_place,
+        hard_sigmoid, hard_swish, is_inf, is_nan, leaky_relu, leaky_relu_in_place, log,
+        log_in_place, mish, mish_in_place, neg, neg_in_place, not, not_in_place, reciprocal, relu,
+        relu_in_place, round, round_in_place, selu, selu_in_place, shrink, sigmoid,
+        sigmoid_in_place, sign, sign_in_place, silu, silu_in_place, sin, sin_in_place, softplus,
+        softplus_in_place, softsign, softsign_in_place, sqrt, sqrt_in_place, tan, tan_in_place,
+        tanh, tanh_in_place, thresholded_relu,
     };
 
     /// Define a test for a simple unary operator which applies the function
@@ -834,6 +1153,36 @@ mod tests {
     // TODO: Eliminate the duplication for tests that apply the operator
     // in-place vs returning a new tensor.
 
+    #[test]
+    fn test_bitwise_not() {
+        let pool = new_pool();
+        let input = Tensor::from([0, 1, -1, 0b1010]);
+        let expected = Tensor::from([-1, -2, 0, !0b1010]);
+        let result = bitwise_not(&pool, input.view());
+        assert_eq!(result, expected);
+
+        let mut input = input.clone();
+        bitwise_not_in_place(input.view_mut());
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_celu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        for alpha in [0.5, 1.0, 2.0] {
+            let input = Tensor::from([-5., -2., -1., -0.5, 0., 0.5, 1., 2., 5.]);
+            let expected = input.map(|&x: &f32| x.max(0.) + (alpha * (x / alpha).exp_m1()).min(0.));
+
+            let actual = celu(&pool, input.view(), alpha);
+            expect_equal_with_tolerance(&actual, &expected, 1e-6, 0.)?;
+
+            let mut input = input.clone();
+            celu_in_place(input.view_mut(), alpha);
+            expect_equal_with_tolerance(&input, &expected, 1e-6, 0.)?;
+        }
+        Ok(())
+    }
+
     test_unary_op!(test_cos, cos, cos_in_place, |x: &f32| x.cos());
 
     #[test]
@@ -995,6 +1344,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_is_inf() {
+        let pool = new_pool();
+        let input = Tensor::from([f32::NEG_INFINITY, -1., 0., f32::NAN, 1., f32::INFINITY]);
+
+        let result = is_inf(&pool, input.view(), true, true);
+        assert_eq!(result, Tensor::from([1, 0, 0, 0, 0, 1]));
+
+        let result = is_inf(&pool, input.view(), true, false);
+        assert_eq!(result, Tensor::from([1, 0, 0, 0, 0, 0]));
+
+        let result = is_inf(&pool, input.view(), false, true);
+        assert_eq!(result, Tensor::from([0, 0, 0, 0, 0, 1]));
+    }
+
+    #[test]
+    fn test_is_nan() {
+        let pool = new_pool();
+        let input = Tensor::from([f32::NEG_INFINITY, -1., f32::NAN, 1., -f32::NAN]);
+        let result = is_nan(&pool, input.view());
+        assert_eq!(result, Tensor::from([0, 0, 1, 0, 1]));
+    }
+
     #[test]
     fn test_leaky_relu() -> Result<(), Box<dyn Error>> {
         let pool = new_pool();
@@ -1150,6 +1522,50 @@ mod tests {
 
     test_unary_op!(test_silu, silu, silu_in_place, |x: &f32| x
         * reference_sigmoid(*x));
+    fn reference_mish(x: f32) -> f32 {
+        x * x.exp().ln_1p().tanh()
+    }
+
+    test_unary_op!(test_mish, mish, mish_in_place, |x| reference_mish(*x));
+
+    #[test]
+    fn test_selu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let (alpha, gamma) = (1.67326, 1.0507);
+        let input = Tensor::from([-5., -2., -1., -0.5, 0., 0.5, 1., 2., 5.]);
+        let expected = input.map(|&x: &f32| {
+            if x > 0. {
+                gamma * x
+            } else {
+                gamma * alpha * x.exp_m1()
+            }
+        });
+
+        let actual = selu(&pool, input.view(), alpha, gamma);
+        expect_equal_with_tolerance(&actual, &expected, 1e-6, 0.)?;
+
+        let mut input = input.clone();
+        selu_in_place(input.view_mut(), alpha, gamma);
+        expect_equal_with_tolerance(&input, &expected, 1e-6, 0.)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shrink() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from([-2., -0.5, 0., 0.5, 2.]);
+        let expected = Tensor::from([-1., 0., 0., 0., 1.]);
+        let result = shrink(
+            &pool,
+            input.view(),
+            1.0, /* bias */
+            0.5, /* lambd */
+        );
+        expect_equal(&result, &expected)?;
+        Ok(())
+    }
+
     test_unary_op!(test_sign, sign, sign_in_place, |x: &f32| x.signum());
     test_unary_op!(test_sin, sin, sin_in_place, |x: &f32| x.sin());
     test_unary_op!(test_softplus, softplus, softplus_in_place, |x: &f32| {
@@ -1175,6 +1591,18 @@ mod tests {
         Ok(())
     }
 
+    test_unary_op!(test_softsign, softsign, softsign_in_place, |x: &f32| x
+        / (1. + x.abs()));
     test_unary_op!(test_tan, tan, tan_in_place, |x: &f32| x.tan());
     test_unary_op!(test_tanh, tanh, tanh_in_place, |x: &f32| x.tanh());
+
+    #[test]
+    fn test_thresholded_relu() -> Result<(), Box<dyn Error>> {
+        let pool = new_pool();
+        let input = Tensor::from([-1., 0.5, 1., 1.5, 3.]);
+        let expected = Tensor::from([0., 0., 0., 1.5, 3.]);
+        let result = thresholded_relu(&pool, input.view(), 1.0 /* alpha */);
+        expect_equal(&result, &expected)?;
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use rten_tensor::{Tensor, TensorView};
 
 use crate::ops::binary_elementwise::binary_op;
 use crate::ops::reduce::{cmp_nan_greater, cmp_nan_less};
-use crate::ops::{Input, InputList, IntoOpResult, OpError, Operator, OutputList};
+use crate::ops::{is_same_op, Input, InputList, IntoOpResult, OpError, Operator, OutputList};
 use crate::tensor_pool::{AutoReturn, TensorPool};
 
 /// Apply an elementwise reduction to a sequence of tensors.
@@ -68,7 +68,7 @@ macro_rules! run_typed_op {
     }};
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Max {}
 
 impl Operator for Max {
@@ -76,6 +76,10 @@ impl Operator for Max {
         "Max"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         run_typed_op!(pool, inputs, max)
     }
@@ -87,7 +91,7 @@ pub fn mean(pool: &TensorPool, inputs: &[TensorView]) -> Result<Tensor, OpError>
     Ok(result)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Mean {}
 
 impl Operator for Mean {
@@ -95,6 +99,10 @@ impl Operator for Mean {
         "Mean"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         let inputs: Vec<TensorView<f32>> = typed_views(&inputs)?;
         mean(pool, &inputs).into_op_result()
@@ -111,7 +119,7 @@ pub fn min<T: Copy + PartialOrd>(
     })
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Min {}
 
 impl Operator for Min {
@@ -119,6 +127,10 @@ impl Operator for Min {
         "Min"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         run_typed_op!(pool, inputs, min)
     }
@@ -131,7 +143,7 @@ pub fn sum<T: Copy + std::ops::Add<Output = T>>(
     reduce_elementwise(pool, inputs, |a, b| a + b)
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Sum {}
 
 impl Operator for Sum {
@@ -139,6 +151,10 @@ impl Operator for Sum {
         "Sum"
     }
 
+    fn is_equivalent(&self, other: &dyn Operator) -> bool {
+        is_same_op(self, other)
+    }
+
     fn run(&self, pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
         run_typed_op!(pool, inputs, sum)
     }
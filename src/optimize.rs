@@ -1,20 +1,50 @@
+//! Graph optimizations which are applied when a model is loaded.
+//!
+//! When a model is loaded, a standard set of optimization passes is applied to
+//! its graph, such as constant propagation and fusion of common subgraphs
+//! into more efficient operators. Additional passes can be added by
+//! implementing [`OptimizePass`] and registering them using
+//! [`ModelOptions::add_optimization_pass`](crate::ModelOptions::add_optimization_pass).
+//!
+//! Passes modify the graph via a [`GraphMutator`], which provides read-only
+//! access to nodes and operations to update the graph. Subgraphs can be found
+//! using the symbolic patterns in [`pattern_matcher`] and replaced using a
+//! [`Fusion`]. Simple fusions which replace a subgraph matching a pattern
+//! with a single operator can be defined using [`PatternFusion`].
+//!
+//! Individual passes can be disabled by name using
+//! [`ModelOptions::disable_optimization_pass`](crate::ModelOptions::disable_optimization_pass),
+//! and the rewrites that passes apply are recorded in a log. See
+//! [`Model::optimization_log`](crate::Model::optimization_log).
+
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 
-use rten_tensor::Tensor;
-use rustc_hash::FxHashMap;
+use rten_tensor::prelude::*;
+use rten_tensor::{Tensor, TensorView};
+use rustc_hash::{FxHashMap, FxHashSet};
+use smallvec::SmallVec;
 
 use crate::downcast::DowncastDyn;
-use crate::graph::{
-    Constant, ConstantNode, Graph, Node, NodeId, OperatorNode, RunError, TypedConstant,
+use crate::gemm::Activation;
+use crate::graph::{Constant, Dimension, Graph, Node, NodeId, RunError, TypedConstant};
+use crate::ops::fused::{ElementwiseArg, ElementwiseStep, FusedElementwise, FusedTranspose};
+use crate::ops::{
+    Add, AveragePool, AveragePoolNhwc, BatchNormalization, Clip, Concat, Conv, ConvNhwc, FusedConv,
+    FusedGemm, FusedMatMul, Gelu, Gemm, Identity, Input, InputInfo, LayerNormalization, LeakyRelu,
+    MatMul, MaxPool, MaxPoolNhwc, Mul, Operator, RMSNormalization, ReduceMean, Relu, Reshape,
+    Resize, ResizeNhwc, RotaryEmbedding, ScaledDotProductAttention, Shape, ShapeInputs, Sigmoid,
+    Silu, Size, Softmax, Transpose,
 };
-use crate::ops::fused::FusedTranspose;
-use crate::ops::{Gelu, LayerNormalization, Operator, ReduceMean, Silu, Transpose};
 use crate::Output;
 
-mod pattern_matcher;
+pub mod pattern_matcher;
 
-use pattern_matcher::{binary_op, const_symbol, symbol, unary_op, unary_op_key};
+pub use crate::graph::OperatorNode;
+use pattern_matcher::{
+    binary_op, const_symbol, operator, symbol, unary_op, unary_op_key, Match, Pattern,
+};
 
 /// Errors that occur while applying graph optimizations.
 #[derive(Debug, PartialEq)]
@@ -22,26 +52,60 @@ pub enum OptimizeError {
     /// An error occurred while evaluating parts of the graph (eg. as part
     /// of constant propagation).
     RunError(RunError),
+    /// A pass was disabled which does not match the name of any built-in or
+    /// custom pass.
+    UnknownPass(String),
 }
 
 impl Display for OptimizeError {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             Self::RunError(err) => write!(f, "partial evaluation failed: {}", err),
+            Self::UnknownPass(name) => write!(f, "unknown optimization pass \"{}\"", name),
         }
     }
 }
 
 impl Error for OptimizeError {}
 
-/// Holds a [`Graph`] and associated data structures while it is being mutated
-/// by an optimizer, and provides operations to update the graph.
-struct GraphMutator {
+/// Record of a rewrite applied to a graph by an optimization pass.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rewrite {
+    /// Name of the pass which applied the rewrite.
+    pub pass: String,
+
+    /// Names of the operators which were replaced.
+    pub replaced_ops: Vec<String>,
+
+    /// Type of the operator which replaced them (eg. `"FusedMatMul"`), or
+    /// `None` if their outputs were replaced by existing values or constants.
+    pub fused_op: Option<String>,
+}
+
+impl Display for Rewrite {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.pass, self.replaced_ops.join(", "))?;
+        if let Some(fused_op) = &self.fused_op {
+            write!(f, " -> {}", fused_op)?;
+        }
+        Ok(())
+    }
+}
+
+/// Holds a graph and associated data structures while it is being mutated
+/// by an optimizer, and provides operations to inspect and update the graph.
+pub struct GraphMutator {
     /// Map of (value_node_id, operator_node_ids) for each value node that
     /// is an input to one or more operators.
     edges: FxHashMap<NodeId, Vec<NodeId>>,
     graph: Graph,
     output_ids: Vec<usize>,
+
+    /// Name of the pass being applied, used when recording rewrites.
+    current_pass: String,
+
+    /// Log of rewrites applied to the graph.
+    rewrites: Vec<Rewrite>,
 }
 
 impl GraphMutator {
@@ -67,28 +131,48 @@ impl GraphMutator {
             output_ids: graph.output_ids().to_vec(),
             edges,
             graph,
+            current_pass: String::new(),
+            rewrites: Vec::new(),
         }
     }
 
     /// Add a new constant value to the graph.
-    fn add_constant<T>(&mut self, name: Option<&str>, value: Tensor<T>) -> NodeId
+    pub fn add_constant<T>(&mut self, name: Option<&str>, value: Tensor<T>) -> NodeId
     where
-        Constant: From<ConstantNode<T>>,
+        Tensor<T>: Into<Output>,
     {
-        self.graph.add_constant(name, value)
+        match value.into() {
+            Output::FloatTensor(value) => self.graph.add_constant(name, value),
+            Output::IntTensor(value) => self.graph.add_constant(name, value),
+        }
     }
 
     /// Add a new operator to the graph with a single output node.
     ///
     /// Returns the ID of the output node.
-    fn add_operator(
+    pub fn add_operator(
         &mut self,
         name: Option<&str>,
         op: Box<dyn Operator + Send + Sync>,
         inputs: &[Option<NodeId>],
+        output_name: Option<&str>,
+    ) -> NodeId {
+        self.add_shared_operator(name, Arc::from(op), inputs, output_name)
+    }
+
+    /// Variant of [`add_operator`](Self::add_operator) which takes a shared
+    /// reference to an operator.
+    fn add_shared_operator(
+        &mut self,
+        name: Option<&str>,
+        op: Arc<dyn Operator + Send + Sync>,
+        inputs: &[Option<NodeId>],
+        output_name: Option<&str>,
     ) -> NodeId {
-        let op_output_id = self.graph.add_value(None, None);
-        let op_id = self.graph.add_op(name, op, inputs, &[Some(op_output_id)]);
+        let op_output_id = self.graph.add_value(output_name, None);
+        let op_id = self
+            .graph
+            .add_shared_op(name, op, inputs, &[Some(op_output_id)]);
 
         for input_id in inputs.iter().filter_map(|id| *id) {
             if let Some(op_ids) = self.edges.get_mut(&input_id) {
@@ -105,18 +189,65 @@ impl GraphMutator {
     ///
     /// Note there is no mutable variant of this method. All graph updates must
     /// be done via methods of this struct.
-    fn graph(&self) -> &Graph {
+    pub(crate) fn graph(&self) -> &Graph {
         &self.graph
     }
 
-    /// Update the output IDs of the graph and return it.
-    fn finalize_graph(mut self) -> Graph {
+    /// Return the operator node with a given ID.
+    pub fn get_operator(&self, node_id: NodeId) -> Option<&OperatorNode> {
+        match self.graph.get_node(node_id) {
+            Some(Node::Operator(op_node)) => Some(op_node),
+            _ => None,
+        }
+    }
+
+    /// Return the operator which produces a value, along with its node ID.
+    pub fn get_source_operator(&self, value_id: NodeId) -> Option<(NodeId, &OperatorNode)> {
+        self.graph.get_source_node(value_id)
+    }
+
+    /// Return the value of a constant node.
+    pub fn get_constant(&self, node_id: NodeId) -> Option<Input<'_>> {
+        match self.graph.get_node(node_id) {
+            Some(Node::Constant(constant)) => Some(constant.as_input()),
+            _ => None,
+        }
+    }
+
+    /// Return the name of a node, if it has one.
+    pub fn node_name(&self, node_id: NodeId) -> Option<&str> {
+        self.graph.get_node(node_id).and_then(|node| node.name())
+    }
+
+    /// Update the output IDs of the graph and return it, along with the log
+    /// of rewrites applied.
+    fn finalize_graph(mut self) -> (Graph, Vec<Rewrite>) {
         self.graph.set_output_ids(&self.output_ids);
-        self.graph
+        (self.graph, self.rewrites)
+    }
+
+    /// Set the name of the pass used when recording rewrites.
+    fn set_current_pass(&mut self, name: &str) {
+        self.current_pass = name.to_string();
+    }
+
+    /// Add an entry to the rewrite log for the current pass.
+    fn record_rewrite(&mut self, replaced_op_ids: &[NodeId], fused_op: Option<&str>) {
+        if replaced_op_ids.is_empty() {
+            return;
+        }
+        self.rewrites.push(Rewrite {
+            pass: self.current_pass.clone(),
+            replaced_ops: replaced_op_ids
+                .iter()
+                .map(|&id| self.graph.node_name(id))
+                .collect(),
+            fused_op: fused_op.map(|op| op.to_string()),
+        });
     }
 
     /// Iterate over operator nodes and their IDs.
-    fn iter_operators(&self) -> impl Iterator<Item = (NodeId, &OperatorNode)> {
+    pub fn iter_operators(&self) -> impl Iterator<Item = (NodeId, &OperatorNode)> {
         self.graph.iter().filter_map(|(node_id, node)| match node {
             Node::Operator(op) => Some((node_id, op)),
             _ => None,
@@ -125,7 +256,7 @@ impl GraphMutator {
 
     /// Iterate over each operator node in the graph and potentially apply a
     /// fusion which combines this node and adjacent nodes.
-    fn apply_fusion<F: Fn(&Self, NodeId, &OperatorNode) -> Option<Fusion>>(
+    pub fn apply_fusion<F: Fn(&Self, NodeId, &OperatorNode) -> Option<Fusion>>(
         &mut self,
         create_fusion: F,
     ) {
@@ -142,15 +273,39 @@ impl GraphMutator {
         }
     }
 
-    fn output_ids(&self) -> &[NodeId] {
+    /// Return the IDs of the graph's output values.
+    pub fn output_ids(&self) -> &[NodeId] {
         &self.output_ids
     }
 
+    /// Return true if a value node is used as an operator input or graph
+    /// output.
+    pub fn is_used(&self, value_node_id: NodeId) -> bool {
+        self.edges.contains_key(&value_node_id) || self.output_ids.contains(&value_node_id)
+    }
+
+    /// Remove nodes which are not needed to compute the graph's outputs.
+    ///
+    /// This changes the IDs of the remaining nodes.
+    fn remove_unused_nodes(&mut self) {
+        let mut graph = std::mem::take(&mut self.graph);
+        graph.set_output_ids(&self.output_ids);
+        graph.remove_unused_nodes();
+
+        let current_pass = std::mem::take(&mut self.current_pass);
+        let rewrites = std::mem::take(&mut self.rewrites);
+        *self = GraphMutator {
+            current_pass,
+            rewrites,
+            ..GraphMutator::from_graph(graph)
+        };
+    }
+
     /// Return the operator node in `graph` that has an incoming edge from a
     /// value node.
     ///
     /// Returns `None` if there are zero or many such operators.
-    fn find_operator_with_input(&self, value_node_id: NodeId) -> Option<&OperatorNode> {
+    pub fn find_operator_with_input(&self, value_node_id: NodeId) -> Option<&OperatorNode> {
         let targets = self.edges.get(&value_node_id).map(|v| v.as_slice())?;
         let target = match targets {
             &[op_id] => Some(op_id),
@@ -164,7 +319,17 @@ impl GraphMutator {
 
     /// Replace `old_value_id` with `new_value_id` in operator inputs and graph
     /// outputs.
-    fn replace_value(&mut self, old_value_id: NodeId, new_value_id: NodeId) {
+    ///
+    /// If `old_value_id` is the output of an operator, this is recorded in
+    /// the rewrite log.
+    pub fn replace_value(&mut self, old_value_id: NodeId, new_value_id: NodeId) {
+        if let Some((op_id, _)) = self.graph.get_source_node(old_value_id) {
+            self.record_rewrite(&[op_id], None);
+        }
+        self.replace_value_impl(old_value_id, new_value_id);
+    }
+
+    fn replace_value_impl(&mut self, old_value_id: NodeId, new_value_id: NodeId) {
         // Replace `old_value_id` in graph outputs.
         for output_id in self.output_ids.iter_mut().filter(|id| **id == old_value_id) {
             *output_id = new_value_id;
@@ -191,7 +356,7 @@ impl GraphMutator {
 }
 
 /// Defines a fused operator which replaces a subgraph.
-struct Fusion {
+pub struct Fusion {
     name: Option<String>,
     fused_op: Box<dyn Operator + Send + Sync>,
     input_ids: Vec<Option<NodeId>>,
@@ -203,25 +368,35 @@ impl Fusion {
     ///
     /// `old_output_id` specifies the output ID of the subgraph that this fusion
     /// replaces.
-    fn from_op<Op: Operator + Send + Sync>(
+    pub fn new(
         name: Option<&str>,
-        op: Op,
+        fused_op: Box<dyn Operator + Send + Sync>,
         input_ids: Vec<Option<NodeId>>,
         old_output_id: NodeId,
     ) -> Fusion {
         Fusion {
             name: name.map(|s| s.to_string()),
-            fused_op: Box::new(op),
+            fused_op,
             input_ids,
             old_output_id,
         }
     }
 
+    /// Variant of [`new`](Self::new) which takes an unboxed operator.
+    pub fn from_op<Op: Operator + Send + Sync>(
+        name: Option<&str>,
+        op: Op,
+        input_ids: Vec<Option<NodeId>>,
+        old_output_id: NodeId,
+    ) -> Fusion {
+        Self::new(name, Box::new(op), input_ids, old_output_id)
+    }
+
     /// Apply the fusion to the graph.
     ///
     /// This adds the fused operator to the graph and replaces references to
     /// the original output nodes with the fused operator's outputs.
-    fn apply(self, graph: &mut GraphMutator) {
+    pub fn apply(self, graph: &mut GraphMutator) {
         let Fusion {
             name,
             fused_op,
@@ -229,8 +404,138 @@ impl Fusion {
             old_output_id,
         } = self;
 
-        let fused_op_output_id = graph.add_operator(name.as_deref(), fused_op, &input_ids);
-        graph.replace_value(old_output_id, fused_op_output_id);
+        let replaced_op_ids = subgraph_operators(graph.graph(), old_output_id, &input_ids);
+        graph.record_rewrite(&replaced_op_ids, Some(fused_op.name()));
+
+        // The fused output takes the name of the value it replaces, so that
+        // graph outputs can still be found by name.
+        let output_name = graph
+            .graph()
+            .get_node(old_output_id)
+            .and_then(|n| n.name())
+            .map(|name| name.to_string());
+        let fused_op_output_id = graph.add_operator(
+            name.as_deref(),
+            fused_op,
+            &input_ids,
+            output_name.as_deref(),
+        );
+        graph.replace_value_impl(old_output_id, fused_op_output_id);
+    }
+}
+
+/// Return the IDs of operators in the subgraph which computes `output_id`
+/// from `input_ids`, in the order they are evaluated.
+fn subgraph_operators(
+    graph: &Graph,
+    output_id: NodeId,
+    input_ids: &[Option<NodeId>],
+) -> Vec<NodeId> {
+    let mut op_ids = Vec::new();
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![output_id];
+    while let Some(value_id) = stack.pop() {
+        if input_ids.contains(&Some(value_id)) {
+            continue;
+        }
+        let Some((op_id, op_node)) = graph.get_source_node(value_id) else {
+            continue;
+        };
+        if visited.insert(op_id) {
+            op_ids.push(op_id);
+            stack.extend(op_node.input_ids().iter().flatten());
+        }
+    }
+    op_ids.reverse();
+    op_ids
+}
+
+/// An optimization pass which transforms a graph.
+///
+/// Custom passes are added when loading a model using
+/// [`ModelOptions::add_optimization_pass`](crate::ModelOptions::add_optimization_pass).
+pub trait OptimizePass: Send + Sync {
+    /// Return a name for this pass, used for debugging.
+    fn name(&self) -> &str;
+
+    /// Apply this pass to a graph.
+    ///
+    /// Passes must preserve the graph's inputs and the values of its outputs.
+    fn run(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError>;
+}
+
+/// Function which creates the fused operator for a [`PatternFusion`].
+type CreateFusedOp =
+    dyn Fn(&Match, &GraphMutator) -> Option<Box<dyn Operator + Send + Sync>> + Send + Sync;
+
+/// An optimization pass which replaces subgraphs that match a [`Pattern`]
+/// with a single operator.
+///
+/// ```
+/// use rten::ops::Silu;
+/// use rten::optimize::pattern_matcher::{symbol, unary_op};
+/// use rten::optimize::PatternFusion;
+///
+/// let x = symbol("x");
+/// let silu = PatternFusion::new(
+///     "silu",
+///     x.clone() * unary_op("Sigmoid", x),
+///     &["x"],
+///     |_match, _graph| Some(Box::new(Silu {})),
+/// );
+/// ```
+pub struct PatternFusion {
+    name: String,
+    pattern: Pattern,
+    inputs: Vec<&'static str>,
+    create_op: Box<CreateFusedOp>,
+}
+
+impl PatternFusion {
+    /// Create a pass which replaces subgraphs matching `pattern`.
+    ///
+    /// `inputs` lists the symbols in `pattern` which are resolved to get the
+    /// inputs for the fused operator. `create_op` is called for each match to
+    /// create the fused operator. It can return `None` to skip the match, for
+    /// example if a constant in the subgraph has an unsupported value.
+    ///
+    /// The fused operator takes the name of the operator at the root of the
+    /// matched subgraph.
+    pub fn new<F>(name: &str, pattern: Pattern, inputs: &[&'static str], create_op: F) -> Self
+    where
+        F: Fn(&Match, &GraphMutator) -> Option<Box<dyn Operator + Send + Sync>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        PatternFusion {
+            name: name.to_string(),
+            pattern,
+            inputs: inputs.to_vec(),
+            create_op: Box::new(create_op),
+        }
+    }
+}
+
+impl OptimizePass for PatternFusion {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let pat_match = self.pattern.test(op_node_id, graph)?;
+            let input_ids = self
+                .inputs
+                .iter()
+                .map(|name| pat_match.resolved_symbol(name).map(Some))
+                .collect::<Option<Vec<_>>>()?;
+            let op_output = op_node.output_id()?;
+            let fused_op = (self.create_op)(&pat_match, graph)?;
+
+            Some(Fusion::new(op_node.name(), fused_op, input_ids, op_output))
+        });
+        Ok(())
     }
 }
 
@@ -266,453 +571,4013 @@ impl OperatorMatch for OperatorNode {
     }
 }
 
-/// Applies optimizations to a [`Graph`] to enable faster inference.
-pub struct GraphOptimizer {}
-
-impl GraphOptimizer {
-    /// Create a new optimizer with the default set of optimizations enabled.
-    pub fn new() -> Self {
-        GraphOptimizer {}
+/// Return the value of a float constant node.
+fn float_const(graph: &Graph, node_id: NodeId) -> Option<TensorView<'_, f32>> {
+    match graph.get_node(node_id) {
+        Some(Node::Constant(Constant::Float(val))) => Some(val.view()),
+        _ => None,
     }
+}
 
-    /// Apply optimizations to a graph.
-    ///
-    /// The input and output nodes specified by `input_ids` and `output_ids`
-    /// will be preserved, but their IDs may change. Other nodes in the graph
-    /// may be modified, removed or replaced by optimization.
-    ///
-    /// This method returns the new graph along with the node IDs in the new
-    /// graph that correspond to `input_ids` and `output_ids`.
-    pub fn optimize(&self, graph: Graph) -> Result<Graph, OptimizeError> {
-        let mut graph_mut = GraphMutator::from_graph(graph);
+/// Return the value of a float constant which is a scalar or single-item
+/// vector.
+///
+/// Single-item tensors with more than one dim are rejected as they can
+/// increase the rank of the other operand when broadcast.
+fn scalar_float_const(graph: &Graph, node_id: NodeId) -> Option<f32> {
+    float_const(graph, node_id)
+        .filter(|val| val.ndim() <= 1)
+        .and_then(|val| val.item().copied())
+}
 
-        self.propagate_constants(&mut graph_mut)?;
+/// Return true if a value is used by exactly one operator and is not a graph
+/// output, so the operator which produces it can be fused into its consumer.
+fn has_single_use(graph: &GraphMutator, value_id: NodeId) -> bool {
+    graph.find_operator_with_input(value_id).is_some() && !graph.output_ids().contains(&value_id)
+}
 
-        self.fuse_transpose(&mut graph_mut)?;
-        self.fuse_silu(&mut graph_mut)?;
-        self.fuse_gelu(&mut graph_mut)?;
-        self.fuse_layer_norm(&mut graph_mut)?;
+/// Return the shape of a value, if known.
+///
+/// `inferred_shapes` are fixed shapes from [`infer_value_shapes`]. If a value
+/// is not present there, the shape recorded in the graph is used.
+fn value_shape(
+    graph: &Graph,
+    inferred_shapes: &FxHashMap<NodeId, Vec<usize>>,
+    value_id: NodeId,
+) -> Option<Vec<Dimension>> {
+    if let Some(shape) = inferred_shapes.get(&value_id) {
+        return Some(shape.iter().map(|&size| Dimension::Fixed(size)).collect());
+    }
+    graph.get_node(value_id).and_then(|node| node.shape())
+}
 
-        Ok(graph_mut.finalize_graph())
+/// Return true if `mask` is an additive attention mask with shape
+/// `[1.., seq_q, seq_k]` which hides exactly the keys that causal attention
+/// hides, ie. keys at positions `j > i + seq_k - seq_q` for query `i`.
+///
+/// Hidden positions must be `-inf` or close to the minimum float value, so
+/// that they contribute nothing after softmax. Masks where some query
+/// attends to no keys, or which have a size-1 sequence dim and so can be
+/// broadcast to a different sequence length, are rejected.
+fn is_causal_mask(mask: TensorView<f32>) -> bool {
+    let ndim = mask.ndim();
+    if ndim < 2 || mask.shape()[..ndim - 2].iter().any(|&size| size != 1) {
+        return false;
     }
+    let [seq_q, seq_k] = [mask.size(ndim - 2), mask.size(ndim - 1)];
+    if seq_q < 2 || seq_k < seq_q {
+        return false;
+    }
+    let offset = seq_k - seq_q;
+    let mask = mask.reshaped([seq_q, seq_k]);
+    (0..seq_q).all(|i| {
+        (0..seq_k).all(|j| {
+            let x = mask[[i, j]];
+            if j > i + offset {
+                x <= f32::MIN / 2.
+            } else {
+                x == 0.
+            }
+        })
+    })
+}
 
-    /// Apply constant propagation to replace parts of the graph which depend
-    /// only on constant values with a pre-computed constant.
-    fn propagate_constants(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
-        // Do a partial run with no inputs. This evaluates all nodes that
-        // transitively depend only on constants.
-        let leaves = graph
-            .graph()
-            .partial_run(vec![], graph.output_ids(), None)
-            .map_err(OptimizeError::RunError)?;
+/// Return true if a value of shape `from` can be broadcast to `to` without
+/// changing `to`, ie. each dim of `from` is 1 or equal to the corresponding
+/// dim of `to`.
+fn broadcasts_to(from: &[Dimension], to: &[Dimension]) -> bool {
+    from.len() <= to.len()
+        && from
+            .iter()
+            .rev()
+            .zip(to.iter().rev())
+            .all(|(from_dim, to_dim)| *from_dim == Dimension::Fixed(1) || from_dim == to_dim)
+}
 
-        // Take the resulting (value_node_id, value) list, create new constant
-        // nodes in the graph with the value and replace references to
-        // `value_node_id` in operator inputs and model outputs with the new
-        // constant.
-        for (value_node_id, output) in leaves {
-            let const_name = graph
-                .graph()
-                .get_node(value_node_id)
-                .and_then(|n| n.name())
-                .map(|name| name.to_string());
-            let const_id = match output {
-                Output::FloatTensor(tensor) => graph.add_constant(const_name.as_deref(), tensor),
-                Output::IntTensor(tensor) => graph.add_constant(const_name.as_deref(), tensor),
-            };
-            graph.replace_value(value_node_id, const_id);
+/// Return true if reshaping a tensor of shape `shape` to `target` leaves the
+/// shape unchanged.
+///
+/// `target` and `allow_zero` have the same meaning as the inputs and
+/// attributes of the `Reshape` operator.
+fn reshape_is_noop(shape: &[Dimension], target: &[i32], allow_zero: bool) -> bool {
+    if shape.len() != target.len() {
+        return false;
+    }
+
+    let mut has_inferred_dim = false;
+    for (dim, &size) in shape.iter().zip(target) {
+        match (size, dim) {
+            // Dim is copied from the input.
+            (0, _) if !allow_zero => {}
+            // Dim is inferred from the remaining dims. If these all match, the
+            // inferred size will equal the input size.
+            (-1, _) if !has_inferred_dim => has_inferred_dim = true,
+            (size, Dimension::Fixed(dim_size)) if size > 0 && *dim_size == size as usize => {}
+            _ => return false,
         }
+    }
 
-        Ok(())
+    // The inferred size is ambiguous if other dims are zero.
+    !has_inferred_dim || !shape.contains(&Dimension::Fixed(0))
+}
+
+/// Return true if `perm` is a valid permutation of `0..perm.len()`.
+fn is_permutation(perm: &[usize]) -> bool {
+    let mut seen = vec![false; perm.len()];
+    perm.iter()
+        .all(|&p| p < perm.len() && !std::mem::replace(&mut seen[p], true))
+}
+
+/// Return the permutation equivalent to transposing with `first` and then
+/// `second`, where `None` reverses the order of dims.
+///
+/// Returns `None` if the result can't be determined because both
+/// permutations are `None`, or the permutations are invalid.
+fn compose_permutations(first: Option<&[usize]>, second: Option<&[usize]>) -> Option<Vec<usize>> {
+    let ndim = first.or(second)?.len();
+    let reversed: Vec<usize> = (0..ndim).rev().collect();
+    let first = first.unwrap_or(&reversed);
+    let second = second.unwrap_or(&reversed);
+    if first.len() != second.len() || !is_permutation(first) || !is_permutation(second) {
+        return None;
     }
+    Some(second.iter().map(|&p| first[p]).collect())
+}
 
-    /// Fuse `Op(Transpose(X), Y, ...) -> Z` into `FusedTranspose<Op>(X, Y, ...) -> Z`.
-    ///
-    /// This avoids materializing the transposed input for operators which can
-    /// efficiently handle the input being non-contiguous.
-    fn fuse_transpose(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
-        graph.apply_fusion(|edges, _op_node_id, op_node| {
-            let (transpose_op, [transpose_input], [transpose_output]) =
-                op_node.match_type::<Transpose, 1, 1>()?;
+/// Key used to identify constants with equal values.
+#[derive(PartialEq, Eq, Hash)]
+struct ConstantKey {
+    is_float: bool,
+    shape: Vec<usize>,
+    bits: Vec<u32>,
+}
 
-            let transpose_target = edges.find_operator_with_input(transpose_output)?;
+impl ConstantKey {
+    /// Maximum number of elements in constants that are compared by value.
+    const MAX_LEN: usize = 16;
 
-            // Filter against a set of operators which are known to efficiently
-            // handle transposed inputs.
-            if !["MatMul"].contains(&transpose_target.operator().name()) {
-                return None;
+    fn new(constant: &Constant) -> Option<ConstantKey> {
+        let (is_float, shape, bits) = match constant {
+            Constant::Float(val) => {
+                let val = val.view();
+                let bits = val.iter().map(|x| x.to_bits()).collect();
+                (true, val.shape().to_vec(), bits)
             }
+            Constant::Int(val) => {
+                let val = val.view();
+                let bits = val.iter().map(|x| *x as u32).collect();
+                (false, val.shape().to_vec(), bits)
+            }
+        };
+        (shape.iter().product::<usize>() <= Self::MAX_LEN).then_some(ConstantKey {
+            is_float,
+            shape,
+            bits,
+        })
+    }
+}
 
-            // Only single-output operators are currently supported.
-            let target_output = match transpose_target.output_ids() {
-                [Some(output)] => Some(*output),
-                _ => None,
-            }?;
+/// Return true if an operator can be evaluated as a step of a
+/// [`FusedElementwise`] operator.
+fn is_fusable_elementwise(op_node: &OperatorNode) -> bool {
+    op_node.output_ids().len() == 1 && FusedElementwise::supports_operator(op_node.operator())
+}
 
-            // Replace transpose output with transpose input in the fused
-            // operator's inputs.
-            let fused_input_idx = transpose_target
-                .input_ids()
-                .iter()
-                .position(|&input_id| input_id == Some(transpose_output))
-                .expect("fused input missing");
-            let mut fused_input = transpose_target.input_ids().to_vec();
-            fused_input[fused_input_idx] = Some(transpose_input);
+/// Return the number of leading inputs of a fusable elementwise operator
+/// which can be computed by earlier steps of a [`FusedElementwise`] operator.
+///
+/// The remaining inputs are scalar parameters, such as the bounds of `Clip`,
+/// which must be passed to the fused operator as inputs.
+fn elementwise_data_inputs(op_node: &OperatorNode) -> usize {
+    FusedElementwise::data_inputs(op_node.operator()).unwrap_or(0)
+}
 
-            let fused_op = FusedTranspose::wrap(
-                transpose_target.clone_operator(),
-                fused_input_idx,
-                transpose_op.perm.as_deref(),
-            );
+/// Steps and inputs of a [`FusedElementwise`] operator that is being built
+/// from a subgraph.
+#[derive(Default)]
+struct ElementwiseChain {
+    steps: Vec<ElementwiseStep>,
+    input_ids: Vec<NodeId>,
+}
 
-            Some(Fusion::from_op(
-                transpose_target.name(),
-                fused_op,
-                fused_input,
-                target_output,
-            ))
+impl ElementwiseChain {
+    /// Add `op_node` to the chain, preceded by the fusable operators which
+    /// produce its inputs, and return the index of its step.
+    fn add_operator(&mut self, graph: &GraphMutator, op_node: &OperatorNode) -> usize {
+        let mut args = Vec::with_capacity(op_node.input_ids().len());
+        let data_inputs = elementwise_data_inputs(op_node);
+        for (i, input_id) in op_node.input_ids().iter().enumerate() {
+            let Some(input_id) = *input_id else {
+                args.push(None);
+                continue;
+            };
+            let source = graph.graph().get_source_node(input_id).filter(|(_, src)| {
+                i < data_inputs && is_fusable_elementwise(src) && has_single_use(graph, input_id)
+            });
+            let arg = if let Some((_, source_node)) = source {
+                ElementwiseArg::Step(self.add_operator(graph, source_node))
+            } else {
+                ElementwiseArg::Input(self.add_input(input_id))
+            };
+            args.push(Some(arg));
+        }
+
+        self.steps.push(ElementwiseStep {
+            op: op_node.clone_operator(),
+            args,
         });
+        self.steps.len() - 1
+    }
 
-        Ok(())
+    /// Add a value to the inputs of the chain, if not already present, and
+    /// return its index.
+    fn add_input(&mut self, value_id: NodeId) -> usize {
+        if let Some(idx) = self.input_ids.iter().position(|id| *id == value_id) {
+            idx
+        } else {
+            self.input_ids.push(value_id);
+            self.input_ids.len() - 1
+        }
     }
+}
 
-    /// Fuse `x * Sigmoid(x)` into `Silu(x)`.
-    fn fuse_silu(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
-        let x = symbol("x");
-        let silu_pattern = x.clone() * unary_op("Sigmoid", x.clone());
+/// Return the per-channel values of a constant that is broadcast against a
+/// tensor with `ndim` dims and `channels` channels along axis 1.
+///
+/// Returns `None` if the constant is not a scalar or vector of per-channel
+/// values, or would increase the rank of the other operand when broadcast.
+fn per_channel_values(value: TensorView<f32>, ndim: usize, channels: usize) -> Option<Vec<f32>> {
+    if ndim < 2 || value.ndim() > ndim {
+        return None;
+    }
+    let pad = ndim - value.ndim();
+    let per_channel = value.shape().iter().enumerate().all(|(i, &size)| {
+        if i + pad == 1 {
+            size == 1 || size == channels
+        } else {
+            size == 1
+        }
+    });
+    if !per_channel {
+        return None;
+    }
+    if value.len() == 1 {
+        value.item().map(|&x| vec![x; channels])
+    } else {
+        Some(value.iter().copied().collect())
+    }
+}
 
-        graph.apply_fusion(|graph, op_node_id, op_node| {
-            let silu_match = silu_pattern.test(op_node_id, graph.graph())?;
-            let silu_input = silu_match.resolved_symbol("x").expect("missing symbol");
-            let op_output = op_node.output_id()?;
+/// Per-channel affine transform `y = x * scale + shift`, where channels are
+/// along axis 1 of `x`.
+struct ChannelAffine {
+    scale: Vec<f32>,
+    shift: Vec<f32>,
+}
 
-            Some(Fusion::from_op(
-                op_node.name(),
-                Silu {},
-                vec![Some(silu_input)],
-                op_output,
-            ))
-        });
+impl ChannelAffine {
+    /// Get the per-channel transform applied to `input_id` by an operator.
+    ///
+    /// This supports `BatchNormalization` in inference mode and `Mul` or
+    /// `Add` with a per-channel constant. `ndim` is the rank of `input_id` and
+    /// `channels` the size of its channel axis.
+    fn from_operator(
+        graph: &Graph,
+        op_node: &OperatorNode,
+        input_id: NodeId,
+        ndim: usize,
+        channels: usize,
+    ) -> Option<ChannelAffine> {
+        let channel_const = |node_id| {
+            let value = float_const(graph, node_id)?;
+            (value.shape() == [channels]).then(|| value.to_vec())
+        };
+        // Get the other operand of a commutative binary operator.
+        let other_operand = |[a, b]: [NodeId; 2]| match (a == input_id, b == input_id) {
+            (true, false) => Some(b),
+            (false, true) => Some(a),
+            _ => None,
+        };
 
-        Ok(())
+        if let Some((bn_op, [x, scale, bias, mean, var], _)) =
+            op_node.match_type::<BatchNormalization, 5, 1>()
+        {
+            if x != input_id {
+                return None;
+            }
+            let (scale, bias, mean, var) = (
+                channel_const(scale)?,
+                channel_const(bias)?,
+                channel_const(mean)?,
+                channel_const(var)?,
+            );
+            let scale: Vec<f32> = scale
+                .iter()
+                .zip(&var)
+                .map(|(scale, var)| scale / (var + bn_op.epsilon).sqrt())
+                .collect();
+            let shift = bias
+                .iter()
+                .zip(&mean)
+                .zip(&scale)
+                .map(|((bias, mean), scale)| bias - mean * scale)
+                .collect();
+            Some(ChannelAffine { scale, shift })
+        } else if let Some((_, inputs, _)) = op_node.match_type::<Mul, 2, 1>() {
+            let value = float_const(graph, other_operand(inputs)?)?;
+            Some(ChannelAffine {
+                scale: per_channel_values(value, ndim, channels)?,
+                shift: vec![0.; channels],
+            })
+        } else if let Some((_, inputs, _)) = op_node.match_type::<Add, 2, 1>() {
+            let value = float_const(graph, other_operand(inputs)?)?;
+            Some(ChannelAffine {
+                scale: vec![1.; channels],
+                shift: per_channel_values(value, ndim, channels)?,
+            })
+        } else {
+            None
+        }
     }
+}
 
-    /// Fuse `0.5 * X * (1 + Erf(X / Sqrt(2)))` into `Gelu(X)`.
-    fn fuse_gelu(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
-        // The expression for GELU is usually written as `x * 0.5 * (...)`
-        // instead of `x * (...) * 0.5`. Ideally our graph pattern matcher
-        // would be smart enough to let us write one pattern and have it match
-        // either structure. However it isn't. The pattern used matches PyTorch's
-        // `nn.GELU`.
-        let x = symbol("x");
-        let gelu_pattern = x.clone() * (unary_op("Erf", x.clone() / (2.0f32).sqrt()) + 1.0) * 0.5;
+/// Replacement for a `Conv` or `Gemm` operator with new weight and bias
+/// constants, created by folding a subsequent per-channel affine transform
+/// into the operator.
+struct WeightFold {
+    name: Option<String>,
+    op: Box<dyn Operator + Send + Sync>,
+    input_id: NodeId,
+    weight: Tensor,
+    bias: Tensor,
+    old_output_id: NodeId,
+}
+
+impl WeightFold {
+    fn apply(self, graph: &mut GraphMutator) {
+        let weight_id = graph.add_constant(None, self.weight);
+        let bias_id = graph.add_constant(None, self.bias);
+        Fusion {
+            name: self.name,
+            fused_op: self.op,
+            input_ids: vec![Some(self.input_id), Some(weight_id), Some(bias_id)],
+            old_output_id: self.old_output_id,
+        }
+        .apply(graph);
+    }
+}
+
+/// Multiply each slice of `weight` along `axis` by the corresponding value
+/// in `scale`.
+fn scale_weight(weight: TensorView<f32>, axis: usize, scale: &[f32]) -> Tensor {
+    let mut weight = weight.to_tensor();
+    for (mut slice, &scale) in weight.axis_iter_mut(axis).zip(scale) {
+        slice.apply(|x| x * scale);
+    }
+    weight
+}
+
+/// Permutation which converts a tensor from NCHW to NHWC layout.
+const NCHW_TO_NHWC: [usize; 4] = [0, 2, 3, 1];
+
+/// Permutation which converts a tensor from NHWC to NCHW layout.
+const NHWC_TO_NCHW: [usize; 4] = [0, 3, 1, 2];
+
+/// Input for an operator created by an [`NhwcRewrite`].
+enum NhwcInput {
+    /// An existing value.
+    Value(NodeId),
+
+    /// An existing value in NCHW layout, which is transposed to NHWC layout.
+    FromNchw(NodeId),
+
+    /// A new constant.
+    Constant(Tensor),
+}
+
+/// Replacement for an operator with NCHW inputs and output by a variant that
+/// runs in NHWC layout, followed by a transpose of its output back to NCHW
+/// layout.
+struct NhwcRewrite {
+    op_id: NodeId,
+    name: Option<String>,
+    op: Arc<dyn Operator + Send + Sync>,
+    inputs: Vec<Option<NhwcInput>>,
+    old_output_id: NodeId,
+}
+
+impl NhwcRewrite {
+    /// Create a rewrite which runs a 2D `Conv` or `FusedConv` operator with
+    /// constant weights in NHWC layout.
+    fn for_conv(graph: &GraphMutator, op_id: NodeId, op_node: &OperatorNode) -> Option<Self> {
+        let op = op_node.operator();
+        let (conv, activation) = if let Some(conv) = op.downcast_ref::<Conv>() {
+            (conv.clone(), None)
+        } else {
+            let fused_conv = op.downcast_ref::<FusedConv>()?;
+            (fused_conv.conv.clone(), Some(fused_conv.activation))
+        };
+        if conv.strides.len() != 2 || conv.dilations.len() != 2 {
+            return None;
+        }
+
+        let (input_id, weight_id, bias_id) = match op_node.input_ids() {
+            [Some(input), Some(weight)] => (*input, *weight, None),
+            [Some(input), Some(weight), bias] => (*input, *weight, *bias),
+            _ => return None,
+        };
+        let weight = float_const(graph.graph(), weight_id).filter(|w| w.ndim() == 4)?;
+
+        // Reorder weights from OIHW to HWIO layout.
+        let weight = weight.permuted([2, 3, 1, 0].as_slice()).to_tensor();
+
+        Some(NhwcRewrite {
+            op_id,
+            name: op_node.name().map(|name| name.to_string()),
+            op: Arc::new(ConvNhwc { conv, activation }),
+            inputs: vec![
+                Some(NhwcInput::FromNchw(input_id)),
+                Some(NhwcInput::Constant(weight)),
+                bias_id.map(NhwcInput::Value),
+            ],
+            old_output_id: op_node.output_id()?,
+        })
+    }
+
+    /// Create a rewrite which moves transposes from NHWC to NCHW layout from
+    /// the inputs of an operator to its output, by running the operator in
+    /// NHWC layout.
+    ///
+    /// This supports elementwise operators, `Concat`, 2D `MaxPool` and
+    /// `AveragePool`, and `Resize`. Constant inputs of elementwise operators
+    /// are transposed ahead of time.
+    fn for_transposed_inputs(
+        graph: &GraphMutator,
+        op_id: NodeId,
+        op_node: &OperatorNode,
+    ) -> Option<Self> {
+        // Return the input of a transpose from NHWC to NCHW layout which
+        // produces `value_id`, if `value_id` is used only by `op_node`.
+        let nhwc_source = |value_id: NodeId| {
+            let (_, source_node) = graph.graph().get_source_node(value_id)?;
+            let (transpose, [input_id], _) = source_node.match_type::<Transpose, 1, 1>()?;
+            (transpose.perm.as_deref() == Some(NHWC_TO_NCHW.as_slice())
+                && has_single_use(graph, value_id))
+            .then_some(input_id)
+        };
+
+        let op = op_node.operator();
+        let mut inputs: Vec<Option<NhwcInput>> = op_node
+            .input_ids()
+            .iter()
+            .map(|id| id.map(NhwcInput::Value))
+            .collect();
+
+        let nhwc_op: Arc<dyn Operator + Send + Sync> =
+            if let Some(n_data_inputs) = FusedElementwise::data_inputs(op) {
+                // Inputs after the data inputs are scalar parameters, which
+                // don't depend on the layout.
+                let mut has_nhwc_input = false;
+                for input in inputs.iter_mut().take(n_data_inputs) {
+                    let Some(NhwcInput::Value(value_id)) = input else {
+                        return None;
+                    };
+                    if let Some(nhwc_id) = nhwc_source(*value_id) {
+                        *value_id = nhwc_id;
+                        has_nhwc_input = true;
+                        continue;
+                    }
+                    let value = float_const(graph.graph(), *value_id)?;
+                    if value.shape().iter().any(|&size| size != 1) {
+                        *input = Some(NhwcInput::Constant(nchw_constant_to_nhwc(value)?));
+                    }
+                }
+                if !has_nhwc_input {
+                    return None;
+                }
+                op_node.clone_operator()
+            } else if let Some(concat) = op.downcast_ref::<Concat>() {
+                for input in inputs.iter_mut() {
+                    let Some(NhwcInput::Value(value_id)) = input else {
+                        return None;
+                    };
+                    *value_id = nhwc_source(*value_id)?;
+                }
+                let axis = if concat.axis < 0 {
+                    concat.axis + 4
+                } else {
+                    concat.axis
+                };
+                let axis = NCHW_TO_NHWC.iter().position(|&dim| dim as isize == axis)?;
+                Arc::new(Concat {
+                    axis: axis as isize,
+                })
+            } else {
+                let Some(Some(NhwcInput::Value(value_id))) = inputs.first_mut() else {
+                    return None;
+                };
+                *value_id = nhwc_source(*value_id)?;
+
+                if let Some(max_pool) = op.downcast_ref::<MaxPool>() {
+                    if max_pool.return_indices || max_pool.kernel_size.len() != 2 {
+                        return None;
+                    }
+                    Arc::new(MaxPoolNhwc {
+                        kernel_size: max_pool.kernel_size.clone(),
+                        padding: max_pool.padding.clone(),
+                        strides: max_pool.strides.clone(),
+                        dilations: max_pool.dilations.clone(),
+                        ceil_mode: max_pool.ceil_mode,
+                    })
+                } else if let Some(avg_pool) = op.downcast_ref::<AveragePool>() {
+                    if avg_pool.kernel_size.len() != 2 {
+                        return None;
+                    }
+                    Arc::new(AveragePoolNhwc {
+                        kernel_size: avg_pool.kernel_size.clone(),
+                        padding: avg_pool.padding.clone(),
+                        count_include_pad: avg_pool.count_include_pad,
+                        strides: avg_pool.strides.clone(),
+                        dilations: avg_pool.dilations.clone(),
+                        ceil_mode: avg_pool.ceil_mode,
+                    })
+                } else if let Some(resize) = op.downcast_ref::<Resize>() {
+                    Arc::new(ResizeNhwc {
+                        mode: resize.mode,
+                        coord_mode: resize.coord_mode,
+                        nearest_mode: resize.nearest_mode,
+                    })
+                } else {
+                    return None;
+                }
+            };
+
+        Some(NhwcRewrite {
+            op_id,
+            name: op_node.name().map(|name| name.to_string()),
+            op: nhwc_op,
+            inputs,
+            old_output_id: op_node.output_id()?,
+        })
+    }
+
+    /// Apply the rewrite and return the ID of the new NCHW output.
+    fn apply(self, graph: &mut GraphMutator) -> NodeId {
+        let mut input_ids = Vec::with_capacity(self.inputs.len());
+        for input in self.inputs {
+            input_ids.push(input.map(|input| match input {
+                NhwcInput::Value(value_id) => value_id,
+                NhwcInput::FromNchw(value_id) => graph.add_operator(
+                    None,
+                    Box::new(Transpose {
+                        perm: Some(NCHW_TO_NHWC.to_vec()),
+                    }),
+                    &[Some(value_id)],
+                    None,
+                ),
+                NhwcInput::Constant(value) => graph.add_constant(None, value),
+            }));
+        }
+        graph.record_rewrite(&[self.op_id], Some(self.op.name()));
+
+        // The transposed output takes the name of the value it replaces, so
+        // that graph outputs can still be found by name.
+        let output_name = graph
+            .graph()
+            .get_node(self.old_output_id)
+            .and_then(|n| n.name())
+            .map(|name| name.to_string());
+        let nhwc_output_id =
+            graph.add_shared_operator(self.name.as_deref(), self.op, &input_ids, None);
+        let nchw_output_id = graph.add_operator(
+            None,
+            Box::new(Transpose {
+                perm: Some(NHWC_TO_NCHW.to_vec()),
+            }),
+            &[Some(nhwc_output_id)],
+            output_name.as_deref(),
+        );
+        graph.replace_value_impl(self.old_output_id, nchw_output_id);
+        nchw_output_id
+    }
+}
+
+/// Convert a constant which is broadcast against NCHW values to the
+/// equivalent constant for NHWC values.
+fn nchw_constant_to_nhwc(value: TensorView<f32>) -> Option<Tensor> {
+    if value.ndim() > 4 {
+        return None;
+    }
+    let mut value = value.clone();
+    while value.ndim() < 4 {
+        value.insert_axis(0);
+    }
+    Some(value.permuted(NCHW_TO_NHWC.as_slice()).to_tensor())
+}
+
+/// Return the number of leading inputs of an operator which can be moved from
+/// NCHW to NHWC layout by [`NhwcRewrite::for_transposed_inputs`], or `None`
+/// if the operator can't run in NHWC layout.
+fn nhwc_layout_inputs(op_node: &OperatorNode) -> Option<usize> {
+    let op = op_node.operator();
+    if let Some(n_data_inputs) = FusedElementwise::data_inputs(op) {
+        Some(n_data_inputs)
+    } else if op.downcast_ref::<Concat>().is_some() {
+        Some(op_node.input_ids().len())
+    } else if let Some(max_pool) = op.downcast_ref::<MaxPool>() {
+        (!max_pool.return_indices && max_pool.kernel_size.len() == 2).then_some(1)
+    } else if let Some(avg_pool) = op.downcast_ref::<AveragePool>() {
+        (avg_pool.kernel_size.len() == 2).then_some(1)
+    } else if op.downcast_ref::<Resize>().is_some() {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// Return the IDs of the convolutions in `conv_ids` which compute `value_id`,
+/// either directly or via operators that can run in NHWC layout.
+///
+/// If these convolutions and the one which uses `value_id` are run in NHWC
+/// layout, the transposes between them cancel out.
+fn nhwc_source_convs(
+    graph: &GraphMutator,
+    value_id: NodeId,
+    conv_ids: &FxHashSet<NodeId>,
+) -> Vec<NodeId> {
+    let mut convs = Vec::new();
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![value_id];
+    while let Some(value_id) = stack.pop() {
+        let Some((op_id, op_node)) = graph.get_source_operator(value_id) else {
+            continue;
+        };
+        if !visited.insert(op_id) {
+            continue;
+        }
+        if conv_ids.contains(&op_id) {
+            convs.push(op_id);
+            continue;
+        }
+        let Some(n_inputs) = nhwc_layout_inputs(op_node) else {
+            continue;
+        };
+
+        // Transposes are only moved past an operator if its inputs are not
+        // used elsewhere.
+        stack.extend(
+            op_node
+                .input_ids()
+                .iter()
+                .take(n_inputs)
+                .flatten()
+                .filter(|&&id| has_single_use(graph, id)),
+        );
+    }
+    convs
+}
+
+/// Return the activation applied by an operator, if it is one that can be
+/// fused into a GEMM epilogue.
+fn fusable_activation(graph: &Graph, op_node: &OperatorNode) -> Option<Activation> {
+    let op = op_node.operator();
+    if op.downcast_ref::<Relu>().is_some() {
+        Some(Activation::Relu)
+    } else if let Some(leaky_relu) = op.downcast_ref::<LeakyRelu>() {
+        Some(Activation::LeakyRelu {
+            alpha: leaky_relu.alpha,
+        })
+    } else if op.downcast_ref::<Sigmoid>().is_some() {
+        Some(Activation::Sigmoid)
+    } else if op.downcast_ref::<Clip>().is_some() {
+        let scalar_input = |index: usize, default: f32| match op_node.input_ids().get(index) {
+            None | Some(None) => Some(default),
+            Some(Some(id)) => match graph.get_node(*id) {
+                Some(Node::Constant(Constant::Float(val))) => val.view().item().copied(),
+                _ => None,
+            },
+        };
+        let min = scalar_input(1, f32::MIN)?;
+        let max = scalar_input(2, f32::MAX)?;
+        (min <= max).then_some(Activation::Clip { min, max })
+    } else {
+        None
+    }
+}
+
+/// Test if a node is a `ReduceMean` operator that reduces over its last axis.
+fn reduce_mean_over_last_axis(graph: &Graph, node_id: NodeId) -> bool {
+    match graph.get_node(node_id) {
+        Some(Node::Operator(op_node)) => {
+            let Some(mean_op) = op_node.operator().downcast_ref::<ReduceMean>() else {
+                return false;
+            };
+
+            // The last axis can be specified with either a positive or
+            // negative value. We only support the negative case as that
+            // is easier to handle and used in popular models.
+            if mean_op.axes.as_deref() == Some(&[-1]) {
+                true
+            } else if let Some(axes_input) = op_node.input_ids().get(1).copied().flatten() {
+                match graph.get_node(axes_input) {
+                    Some(Node::Constant(val)) => val.as_vector() == Some(&[-1]),
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Infer the shapes of as many values in `graph` as possible without running
+/// it.
+///
+/// The shapes of graph inputs are taken from their value nodes, if all
+/// dimensions are fixed. Other shapes are determined using
+/// [`Operator::infer_shapes`]. Returns a map of value node ID to shape.
+fn infer_value_shapes(graph: &Graph) -> FxHashMap<NodeId, Vec<usize>> {
+    let mut shapes: FxHashMap<NodeId, Vec<usize>> = FxHashMap::default();
+    for &input_id in graph.input_ids() {
+        let Some(dims) = graph.get_node(input_id).and_then(|node| node.shape()) else {
+            continue;
+        };
+        let fixed_shape: Option<Vec<usize>> = dims
+            .iter()
+            .map(|dim| match dim {
+                Dimension::Fixed(size) => Some(*size),
+                Dimension::Symbolic(_) => None,
+            })
+            .collect();
+        if let Some(shape) = fixed_shape {
+            shapes.insert(input_id, shape);
+        }
+    }
+
+    // Operators are not guaranteed to be in topological order, so repeat
+    // until no new shapes are found.
+    loop {
+        let mut new_shapes = Vec::new();
+
+        for (_, node) in graph.iter() {
+            let Node::Operator(op_node) = node else {
+                continue;
+            };
+            let output_ids = op_node.output_ids();
+            if output_ids
+                .iter()
+                .flatten()
+                .all(|id| shapes.contains_key(id))
+            {
+                continue;
+            }
+
+            let input_info: Option<SmallVec<[Option<InputInfo>; 4]>> = op_node
+                .input_ids()
+                .iter()
+                .map(|id| match id {
+                    None => Some(None),
+                    Some(id) => match graph.get_node(*id) {
+                        Some(Node::Constant(constant)) => {
+                            Some(Some(InputInfo::Constant(constant.as_input())))
+                        }
+                        _ => shapes
+                            .get(id)
+                            .map(|shape| Some(InputInfo::Shape(shape.as_slice()))),
+                    },
+                })
+                .collect();
+            let Some(input_info) = input_info else {
+                continue;
+            };
+
+            let Some(output_shapes) = op_node
+                .operator()
+                .infer_shapes(&ShapeInputs::new(&input_info))
+            else {
+                continue;
+            };
+            if output_shapes.len() != output_ids.len() {
+                continue;
+            }
+            for (output_id, shape) in output_ids.iter().zip(output_shapes) {
+                if let Some(output_id) = output_id {
+                    new_shapes.push((*output_id, shape));
+                }
+            }
+        }
+
+        if new_shapes.is_empty() {
+            break;
+        }
+        shapes.extend(new_shapes);
+    }
+
+    shapes
+}
+
+/// Names of the built-in optimization passes, in the order they are run.
+///
+/// `fold_shapes` only runs when specializing a graph. `eliminate_dead_nodes`
+/// runs several times, to clean up after other passes.
+const BUILTIN_PASSES: [&str; 20] = [
+    "propagate_constants",
+    "fold_shapes",
+    "eliminate_noop_ops",
+    "cancel_transposes",
+    "eliminate_common_subexpressions",
+    "eliminate_dead_nodes",
+    "fuse_attention",
+    "fuse_matmul_scale",
+    "fuse_matmul_bias",
+    "fuse_matmul_activation",
+    "fuse_transpose",
+    "fuse_silu",
+    "fuse_gelu",
+    "fuse_layer_norm",
+    "fuse_rms_norm",
+    "fuse_rotary_embedding",
+    "fold_conv_affine",
+    "fuse_conv_activation",
+    "convert_to_nhwc",
+    "fuse_elementwise",
+];
+
+/// Signature of built-in optimization passes.
+type PassFn = fn(&GraphOptimizer, &mut GraphMutator) -> Result<(), OptimizeError>;
+
+/// Applies optimizations to a graph to enable faster inference.
+pub struct GraphOptimizer {
+    /// Custom passes added via [`add_pass`](Self::add_pass).
+    custom_passes: Vec<Arc<dyn OptimizePass>>,
+
+    /// Names of passes disabled via [`disable_pass`](Self::disable_pass).
+    disabled_passes: Vec<String>,
+}
+
+impl GraphOptimizer {
+    /// Create a new optimizer with the default set of optimizations enabled.
+    pub(crate) fn new() -> Self {
+        GraphOptimizer {
+            custom_passes: Vec::new(),
+            disabled_passes: Vec::new(),
+        }
+    }
+
+    /// Return the names of the built-in optimization passes, in the order
+    /// they are run.
+    pub fn builtin_passes() -> &'static [&'static str] {
+        &BUILTIN_PASSES
+    }
+
+    /// Disable a built-in or custom pass with a given name.
+    ///
+    /// Optimizing a graph will fail with [`OptimizeError::UnknownPass`] if
+    /// `name` does not match any pass.
+    pub(crate) fn disable_pass(&mut self, name: &str) -> &mut Self {
+        self.disabled_passes.push(name.to_string());
+        self
+    }
+
+    /// Add a custom optimization pass.
+    ///
+    /// Custom passes run in the order they are added, after the passes which
+    /// simplify the graph (constant propagation, dead node elimination etc.)
+    /// and before the built-in fusions. This allows custom fusions to match
+    /// subgraphs that the built-in fusions would otherwise replace.
+    pub(crate) fn add_pass(&mut self, pass: Arc<dyn OptimizePass>) -> &mut Self {
+        self.custom_passes.push(pass);
+        self
+    }
+
+    /// Apply optimizations to a graph.
+    ///
+    /// The input and output nodes specified by `input_ids` and `output_ids`
+    /// will be preserved, but their IDs may change. Other nodes in the graph
+    /// may be modified, removed or replaced by optimization.
+    ///
+    /// This method returns the new graph along with the node IDs in the new
+    /// graph that correspond to `input_ids` and `output_ids`.
+    #[cfg(test)]
+    pub(crate) fn optimize(&self, graph: Graph) -> Result<Graph, OptimizeError> {
+        self.optimize_with_log(graph, false).map(|(graph, _)| graph)
+    }
+
+    /// Apply optimizations to a graph which has been specialized for
+    /// particular input shapes.
+    ///
+    /// This is like [`optimize`](Self::optimize), but additionally replaces
+    /// computations that depend only on the shapes of values, such as
+    /// `Shape -> Gather -> Concat -> Reshape` chains, with constants. The
+    /// shapes of graph inputs are taken from their value nodes, so these
+    /// should have been updated to specify fixed sizes.
+    #[cfg(test)]
+    pub(crate) fn specialize(&self, graph: Graph) -> Result<Graph, OptimizeError> {
+        self.optimize_with_log(graph, true).map(|(graph, _)| graph)
+    }
+
+    /// Variant of [`optimize`](Self::optimize) and
+    /// [`specialize`](Self::specialize) which also returns a log of the
+    /// rewrites that were applied.
+    ///
+    /// The log does not include nodes which were removed only because they
+    /// became unused.
+    pub(crate) fn optimize_with_log(
+        &self,
+        graph: Graph,
+        specialize: bool,
+    ) -> Result<(Graph, Vec<Rewrite>), OptimizeError> {
+        if let Some(unknown) = self.disabled_passes.iter().find(|name| {
+            !BUILTIN_PASSES.contains(&name.as_str())
+                && !self.custom_passes.iter().any(|pass| pass.name() == *name)
+        }) {
+            return Err(OptimizeError::UnknownPass(unknown.clone()));
+        }
+
+        let mut graph_mut = GraphMutator::from_graph(graph);
+
+        self.run_pass(
+            &mut graph_mut,
+            "propagate_constants",
+            Self::propagate_constants,
+        )?;
+
+        if specialize {
+            self.run_pass(&mut graph_mut, "fold_shapes", Self::fold_shapes)?;
+        }
+
+        self.run_pass(
+            &mut graph_mut,
+            "eliminate_noop_ops",
+            Self::eliminate_noop_ops,
+        )?;
+        self.run_pass(&mut graph_mut, "cancel_transposes", Self::cancel_transposes)?;
+        self.run_pass(
+            &mut graph_mut,
+            "eliminate_common_subexpressions",
+            Self::eliminate_common_subexpressions,
+        )?;
+        self.run_pass(
+            &mut graph_mut,
+            "eliminate_dead_nodes",
+            Self::eliminate_dead_nodes,
+        )?;
+
+        for pass in &self.custom_passes {
+            if self.is_disabled(pass.name()) {
+                continue;
+            }
+            graph_mut.set_current_pass(pass.name());
+            pass.run(&mut graph_mut)?;
+        }
+
+        // Attention fusion must run before transpose fusion, as it matches
+        // the `MatMul` operators that transpose fusion replaces.
+        self.run_pass(&mut graph_mut, "fuse_attention", Self::fuse_attention)?;
+        self.run_pass(&mut graph_mut, "fuse_matmul_scale", Self::fuse_matmul_scale)?;
+        self.run_pass(&mut graph_mut, "fuse_matmul_bias", Self::fuse_matmul_bias)?;
+        self.run_pass(
+            &mut graph_mut,
+            "fuse_matmul_activation",
+            Self::fuse_matmul_activation,
+        )?;
+        self.run_pass(&mut graph_mut, "fuse_transpose", Self::fuse_transpose)?;
+        self.run_pass(&mut graph_mut, "fuse_silu", Self::fuse_silu)?;
+        self.run_pass(&mut graph_mut, "fuse_gelu", Self::fuse_gelu)?;
+        self.run_pass(&mut graph_mut, "fuse_layer_norm", Self::fuse_layer_norm)?;
+        self.run_pass(&mut graph_mut, "fuse_rms_norm", Self::fuse_rms_norm)?;
+        self.run_pass(
+            &mut graph_mut,
+            "fuse_rotary_embedding",
+            Self::fuse_rotary_embedding,
+        )?;
+        self.run_pass(&mut graph_mut, "fold_conv_affine", Self::fold_conv_affine)?;
+        self.run_pass(
+            &mut graph_mut,
+            "fuse_conv_activation",
+            Self::fuse_conv_activation,
+        )?;
+        self.run_pass(&mut graph_mut, "convert_to_nhwc", Self::convert_to_nhwc)?;
+
+        // Remove operators replaced by earlier fusions, as elementwise fusion
+        // only fuses operators whose outputs have a single use.
+        self.run_pass(
+            &mut graph_mut,
+            "eliminate_dead_nodes",
+            Self::eliminate_dead_nodes,
+        )?;
+        self.run_pass(&mut graph_mut, "fuse_elementwise", Self::fuse_elementwise)?;
+        self.run_pass(
+            &mut graph_mut,
+            "eliminate_dead_nodes",
+            Self::eliminate_dead_nodes,
+        )?;
+
+        Ok(graph_mut.finalize_graph())
+    }
+
+    fn is_disabled(&self, name: &str) -> bool {
+        self.disabled_passes.iter().any(|disabled| disabled == name)
+    }
+
+    /// Run a built-in pass, unless it has been disabled.
+    fn run_pass(
+        &self,
+        graph: &mut GraphMutator,
+        name: &str,
+        pass: PassFn,
+    ) -> Result<(), OptimizeError> {
+        debug_assert!(BUILTIN_PASSES.contains(&name));
+        if self.is_disabled(name) {
+            return Ok(());
+        }
+        graph.set_current_pass(name);
+        pass(self, graph)
+    }
+
+    /// Apply constant propagation to replace parts of the graph which depend
+    /// only on constant values with a pre-computed constant.
+    fn propagate_constants(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        // Do a partial run with no inputs. This evaluates all nodes that
+        // transitively depend only on constants.
+        let leaves = graph
+            .graph()
+            .partial_run(vec![], graph.output_ids(), None)
+            .map_err(OptimizeError::RunError)?;
+
+        // Take the resulting (value_node_id, value) list, create new constant
+        // nodes in the graph with the value and replace references to
+        // `value_node_id` in operator inputs and model outputs with the new
+        // constant.
+        for (value_node_id, output) in leaves {
+            let const_name = graph
+                .graph()
+                .get_node(value_node_id)
+                .and_then(|n| n.name())
+                .map(|name| name.to_string());
+            let const_id = match output {
+                Output::FloatTensor(tensor) => graph.add_constant(const_name.as_deref(), tensor),
+                Output::IntTensor(tensor) => graph.add_constant(const_name.as_deref(), tensor),
+            };
+            graph.replace_value(value_node_id, const_id);
+        }
+
+        Ok(())
+    }
+
+    /// Remove nodes which do not contribute to the graph's outputs, such as
+    /// operators replaced by fusions and constants that are no longer used.
+    ///
+    /// Graph inputs are always retained.
+    fn eliminate_dead_nodes(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        graph.remove_unused_nodes();
+        Ok(())
+    }
+
+    /// Remove operators which return their input unchanged, such as
+    /// `Identity`, `Transpose` with an identity permutation and `Reshape` to
+    /// the input's existing shape.
+    fn eliminate_noop_ops(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let shapes = infer_value_shapes(graph.graph());
+
+        let mut replacements: FxHashMap<NodeId, NodeId> = FxHashMap::default();
+        for (_, op_node) in graph.iter_operators() {
+            let (Some(Some(input_id)), Some(output_id)) =
+                (op_node.input_ids().first(), op_node.output_id())
+            else {
+                continue;
+            };
+
+            // Operators which produce graph outputs are kept, so the output
+            // retains its name.
+            if graph.output_ids().contains(&output_id) {
+                continue;
+            }
+
+            let op = op_node.operator();
+            let is_noop = if op.downcast_ref::<Identity>().is_some() {
+                true
+            } else if let Some(transpose) = op.downcast_ref::<Transpose>() {
+                transpose
+                    .perm
+                    .as_ref()
+                    .is_some_and(|perm| perm.iter().enumerate().all(|(i, &p)| i == p))
+            } else if let Some(reshape) = op.downcast_ref::<Reshape>() {
+                let target =
+                    op_node
+                        .input_ids()
+                        .get(1)
+                        .copied()
+                        .flatten()
+                        .and_then(|id| match graph.graph().get_node(id) {
+                            Some(Node::Constant(val)) => val.as_vector(),
+                            _ => None,
+                        });
+                let shape = value_shape(graph.graph(), &shapes, *input_id);
+                match (shape, target) {
+                    (Some(shape), Some(target)) => {
+                        reshape_is_noop(&shape, target, reshape.allow_zero)
+                    }
+                    _ => false,
+                }
+            } else {
+                false
+            };
+
+            if is_noop {
+                replacements.insert(output_id, *input_id);
+            }
+        }
+
+        for &output_id in replacements.keys() {
+            // Follow chains of no-op operators back to the first input.
+            let mut input_id = replacements[&output_id];
+            while let Some(&prev_input_id) = replacements.get(&input_id) {
+                input_id = prev_input_id;
+            }
+            graph.replace_value(output_id, input_id);
+        }
+
+        Ok(())
+    }
+
+    /// Replace `Transpose(Transpose(X, perm_a), perm_b)` with `X` if the
+    /// permutations cancel out, or with a single `Transpose` otherwise.
+    fn cancel_transposes(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let mut cancelled = Vec::new();
+        let mut merged = Vec::new();
+
+        for (_, op_node) in graph.iter_operators() {
+            let Some((outer, [outer_input], [outer_output])) =
+                op_node.match_type::<Transpose, 1, 1>()
+            else {
+                continue;
+            };
+            let Some((inner, [inner_input], _)) = graph
+                .graph()
+                .get_source_node(outer_input)
+                .and_then(|(_, inner_node)| inner_node.match_type::<Transpose, 1, 1>())
+            else {
+                continue;
+            };
+
+            let perm = match (inner.perm.as_deref(), outer.perm.as_deref()) {
+                // Reversing the dims twice is a no-op.
+                (None, None) => None,
+                (inner_perm, outer_perm) => {
+                    let Some(perm) = compose_permutations(inner_perm, outer_perm) else {
+                        continue;
+                    };
+                    Some(perm)
+                }
+            };
+            let is_identity = perm
+                .as_ref()
+                .is_none_or(|perm| perm.iter().enumerate().all(|(i, &p)| i == p));
+
+            if !is_identity {
+                merged.push(Fusion::from_op(
+                    op_node.name(),
+                    Transpose { perm },
+                    vec![Some(inner_input)],
+                    outer_output,
+                ));
+            } else if !graph.output_ids().contains(&outer_output) {
+                cancelled.push((outer_output, inner_input));
+            }
+        }
+
+        for fusion in merged {
+            fusion.apply(graph);
+        }
+        for (output_id, input_id) in cancelled {
+            graph.replace_value(output_id, input_id);
+        }
+
+        Ok(())
+    }
+
+    /// Merge operators which have the same type, attributes and inputs, so
+    /// that the computation is only performed once.
+    ///
+    /// Small constants with equal values are merged first, so that operators
+    /// which use them can be merged. Operators are compared using
+    /// [`Operator::is_equivalent`], so operators which don't implement it, or
+    /// which are not [deterministic](Operator::is_deterministic), are never
+    /// merged.
+    fn eliminate_common_subexpressions(
+        &self,
+        graph: &mut GraphMutator,
+    ) -> Result<(), OptimizeError> {
+        let mut constants: FxHashMap<ConstantKey, NodeId> = FxHashMap::default();
+        let mut replacements = Vec::new();
+        for (node_id, node) in graph.graph().iter() {
+            let Node::Constant(constant) = node else {
+                continue;
+            };
+            if graph.output_ids().contains(&node_id) {
+                continue;
+            }
+            let Some(key) = ConstantKey::new(constant) else {
+                continue;
+            };
+            if let Some(&first_id) = constants.get(&key) {
+                replacements.push((node_id, first_id));
+            } else {
+                constants.insert(key, node_id);
+            }
+        }
+        for (old_id, new_id) in replacements {
+            graph.replace_value(old_id, new_id);
+        }
+
+        // Merging operators can make the operators which use their outputs
+        // identical, so repeat until there are no more duplicates.
+        loop {
+            // Candidates for merging are grouped by operator name and inputs,
+            // then compared using `Operator::is_equivalent`.
+            let mut operators: FxHashMap<(&str, &[Option<NodeId>]), Vec<&OperatorNode>> =
+                FxHashMap::default();
+            let mut replacements = Vec::new();
+
+            for (_, op_node) in graph.iter_operators() {
+                let op = op_node.operator();
+                let output_ids = op_node.output_ids();
+
+                // Operators which don't implement `is_equivalent` are skipped.
+                if !op.is_deterministic()
+                    || !op.is_equivalent(op)
+                    || !output_ids.iter().flatten().any(|id| graph.is_used(*id))
+                {
+                    continue;
+                }
+
+                let candidates = operators
+                    .entry((op.name(), op_node.input_ids()))
+                    .or_default();
+                let first_op = candidates
+                    .iter()
+                    .find(|other| other.operator().is_equivalent(op))
+                    .copied();
+                if let Some(first_op) = first_op {
+                    if output_ids
+                        .iter()
+                        .flatten()
+                        .any(|id| graph.output_ids().contains(id))
+                    {
+                        continue;
+                    }
+                    for (old_id, new_id) in output_ids.iter().zip(first_op.output_ids()) {
+                        if let (Some(old_id), Some(new_id)) = (old_id, new_id) {
+                            replacements.push((*old_id, *new_id));
+                        }
+                    }
+                } else {
+                    candidates.push(op_node);
+                }
+            }
+
+            if replacements.is_empty() {
+                break;
+            }
+            for (old_id, new_id) in replacements {
+                graph.replace_value(old_id, new_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replace `Shape` and `Size` operators whose input shapes can be
+    /// inferred with constants, then propagate the constants through the
+    /// graph.
+    ///
+    /// This is repeated until no more operators can be replaced, as constant
+    /// propagation may allow the shapes of more values to be inferred.
+    fn fold_shapes(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        loop {
+            let shapes = infer_value_shapes(graph.graph());
+
+            let mut folded: Vec<(NodeId, Tensor<i32>)> = Vec::new();
+            for (_, op_node) in graph.iter_operators() {
+                let op = op_node.operator();
+                let (Some(Some(input_id)), Some(output_id)) =
+                    (op_node.input_ids().first(), op_node.output_id())
+                else {
+                    continue;
+                };
+                if !graph.is_used(output_id) {
+                    continue;
+                }
+                let Some(shape) = shapes.get(input_id) else {
+                    continue;
+                };
+                let shape_i32 = shape.iter().map(|&size| size as i32);
+
+                if op.downcast_ref::<Shape>().is_some() {
+                    folded.push((output_id, Tensor::from_vec(shape_i32.collect())));
+                } else if op.downcast_ref::<Size>().is_some() {
+                    folded.push((output_id, Tensor::from(shape_i32.product::<i32>())));
+                }
+            }
+
+            if folded.is_empty() {
+                break;
+            }
+
+            for (value_node_id, value) in folded {
+                let const_name = graph
+                    .graph()
+                    .get_node(value_node_id)
+                    .and_then(|n| n.name())
+                    .map(|name| name.to_string());
+                let const_id = graph.add_constant(const_name.as_deref(), value);
+                graph.replace_value(value_node_id, const_id);
+            }
+
+            self.propagate_constants(graph)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fold `BatchNormalization`, and `Mul` or `Add` with per-channel
+    /// constants, into the weights and bias of a preceding `Conv` or `Gemm`.
+    ///
+    /// This is repeated until no more operators can be folded, so that
+    /// chains such as `Conv -> Mul -> Add` are folded into a single `Conv`.
+    fn fold_conv_affine(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        loop {
+            let folds: Vec<WeightFold> = graph
+                .iter_operators()
+                .filter_map(|(_, op_node)| Self::weight_fold(graph, op_node))
+                .collect();
+            if folds.is_empty() {
+                break;
+            }
+            for fold in folds {
+                fold.apply(graph);
+            }
+        }
+        Ok(())
+    }
+
+    /// Create a fold for an affine operator `op_node` whose input is the
+    /// output of a `Conv` or `Gemm` with constant weights.
+    fn weight_fold(graph: &GraphMutator, op_node: &OperatorNode) -> Option<WeightFold> {
+        let op_output = op_node.output_id()?;
+        if !graph.is_used(op_output) {
+            return None;
+        }
+
+        // Find a `Conv` or `Gemm` input whose output is used only by this
+        // operator.
+        let (source_op, source_output) = op_node.input_ids().iter().flatten().find_map(|&id| {
+            let (_, source_op) = graph.graph().get_source_node(id)?;
+            let is_source = source_op.operator().downcast_ref::<Conv>().is_some()
+                || source_op.operator().downcast_ref::<Gemm>().is_some();
+            (is_source
+                && graph.find_operator_with_input(id).is_some()
+                && !graph.output_ids().contains(&id))
+            .then_some((source_op, id))
+        })?;
+
+        let source_inputs = source_op.input_ids();
+        let input_id = (*source_inputs.first()?)?;
+        let weight = float_const(graph.graph(), (*source_inputs.get(1)?)?)?;
+        let bias = match source_inputs.get(2).copied().flatten() {
+            Some(bias_id) => Some(float_const(graph.graph(), bias_id)?),
+            None => None,
+        };
+        let name = source_op.name().map(|name| name.to_string());
+
+        if let Some(conv_op) = source_op.operator().downcast_ref::<Conv>() {
+            let ndim = weight.ndim();
+            if ndim < 3 {
+                return None;
+            }
+            let channels = weight.size(0);
+            if bias.as_ref().is_some_and(|b| b.shape() != [channels]) {
+                return None;
+            }
+            let affine = ChannelAffine::from_operator(
+                graph.graph(),
+                op_node,
+                source_output,
+                ndim,
+                channels,
+            )?;
+            let bias: Vec<f32> = (0..channels)
+                .map(|c| {
+                    let bias = bias.as_ref().map(|b| b[[c]]).unwrap_or(0.);
+                    bias * affine.scale[c] + affine.shift[c]
+                })
+                .collect();
+
+            Some(WeightFold {
+                name,
+                op: Box::new(conv_op.clone()),
+                input_id,
+                weight: scale_weight(weight, 0, &affine.scale),
+                bias: Tensor::from(bias),
+                old_output_id: op_output,
+            })
+        } else {
+            let gemm_op = source_op.operator().downcast_ref::<Gemm>()?;
+            if weight.ndim() != 2 {
+                return None;
+            }
+            let channel_axis = if gemm_op.transpose_b { 0 } else { 1 };
+            let channels = weight.size(channel_axis);
+            let bias = match bias {
+                Some(bias) if gemm_op.beta != 0. => Some(per_channel_values(bias, 2, channels)?),
+                _ => None,
+            };
+            let affine =
+                ChannelAffine::from_operator(graph.graph(), op_node, source_output, 2, channels)?;
+            let bias: Vec<f32> = (0..channels)
+                .map(|c| {
+                    let bias = bias.as_ref().map(|b| gemm_op.beta * b[c]).unwrap_or(0.);
+                    bias * affine.scale[c] + affine.shift[c]
+                })
+                .collect();
+
+            Some(WeightFold {
+                name,
+                op: Box::new(Gemm {
+                    beta: 1.,
+                    ..gemm_op.clone()
+                }),
+                input_id,
+                weight: scale_weight(weight, channel_axis, &affine.scale),
+                bias: Tensor::from(bias),
+                old_output_id: op_output,
+            })
+        }
+    }
+
+    /// Fuse `Activation(Conv(X, W, B))` into `FusedConv(X, W, B)`, where
+    /// `Activation` is `Relu`, `LeakyRelu`, `Clip` or `Sigmoid`.
+    ///
+    /// The activation is applied to each output tile of the convolution as it
+    /// is computed, avoiding a separate pass over the output.
+    fn fuse_conv_activation(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        graph.apply_fusion(|graph, _op_node_id, op_node| {
+            let activation = fusable_activation(graph.graph(), op_node)?;
+            let conv_output = (*op_node.input_ids().first()?)?;
+            let (_, conv_node) = graph.graph().get_source_node(conv_output)?;
+            let conv_op = conv_node.operator().downcast_ref::<Conv>()?;
+
+            // The convolution output must not be used elsewhere.
+            graph.find_operator_with_input(conv_output)?;
+            if graph.output_ids().contains(&conv_output) {
+                return None;
+            }
+
+            let op_output = op_node.output_id()?;
+
+            Some(Fusion::from_op(
+                conv_node.name(),
+                FusedConv {
+                    conv: conv_op.clone(),
+                    activation,
+                },
+                conv_node.input_ids().to_vec(),
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Run convolutions, and the operators between them, in channels-last
+    /// (NHWC) layout.
+    ///
+    /// 2D convolutions with constant weights are replaced by `ConvNhwc`
+    /// operators, with their weights reordered and transposes added to
+    /// convert the input to NHWC layout and the output back to NCHW layout.
+    /// Only convolutions which are connected to another convolution, either
+    /// directly or via operators that can run in NHWC layout, are replaced.
+    /// For an isolated convolution the cost of the added transposes is not
+    /// offset by transposes that cancel out.
+    /// The transposes back to NCHW layout are then moved past operators which
+    /// can also run in NHWC layout, such as elementwise operators, pooling and
+    /// resizing, until they cancel out with the transposes to NHWC layout of
+    /// subsequent convolutions. This leaves transposes only at the boundaries
+    /// of NHWC subgraphs.
+    fn convert_to_nhwc(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let mut convs: Vec<NhwcRewrite> = graph
+            .iter_operators()
+            .filter_map(|(op_id, op_node)| NhwcRewrite::for_conv(graph, op_id, op_node))
+            .collect();
+
+        let conv_ids: FxHashSet<NodeId> = convs.iter().map(|conv| conv.op_id).collect();
+        let mut linked_convs = FxHashSet::default();
+        for conv in &convs {
+            let Some(Some(NhwcInput::FromNchw(input_id))) = conv.inputs.first() else {
+                continue;
+            };
+            for source_id in nhwc_source_convs(graph, *input_id, &conv_ids) {
+                linked_convs.insert(source_id);
+                linked_convs.insert(conv.op_id);
+            }
+        }
+        convs.retain(|conv| linked_convs.contains(&conv.op_id));
+
+        if convs.is_empty() {
+            return Ok(());
+        }
+
+        // Convolutions may use the outputs of other convolutions, which are
+        // replaced as each rewrite is applied.
+        let mut replaced_outputs = FxHashMap::default();
+        for mut conv in convs {
+            for input in conv.inputs.iter_mut().flatten() {
+                let (NhwcInput::Value(value_id) | NhwcInput::FromNchw(value_id)) = input else {
+                    continue;
+                };
+                if let Some(new_id) = replaced_outputs.get(value_id) {
+                    *value_id = *new_id;
+                }
+            }
+            let old_output_id = conv.old_output_id;
+            let new_output_id = conv.apply(graph);
+            replaced_outputs.insert(old_output_id, new_output_id);
+        }
+
+        loop {
+            self.cancel_transposes(graph)?;
+            graph.remove_unused_nodes();
+
+            let rewrites: Vec<NhwcRewrite> = graph
+                .iter_operators()
+                .filter_map(|(op_id, op_node)| {
+                    NhwcRewrite::for_transposed_inputs(graph, op_id, op_node)
+                })
+                .collect();
+
+            // Defer rewrites whose inputs are the outputs of other operators
+            // being rewritten, as those outputs will be replaced.
+            let replaced: FxHashSet<NodeId> = rewrites.iter().map(|r| r.old_output_id).collect();
+            let rewrites: Vec<NhwcRewrite> =
+                rewrites
+                    .into_iter()
+                    .filter(|rewrite| {
+                        !rewrite.inputs.iter().flatten().any(
+                            |input| matches!(input, NhwcInput::Value(id) if replaced.contains(id)),
+                        )
+                    })
+                    .collect();
+
+            if rewrites.is_empty() {
+                break;
+            }
+            for rewrite in rewrites {
+                rewrite.apply(graph);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fuse chains of elementwise operators into a `FusedElementwise` operator
+    /// which evaluates them in a single pass over the output.
+    ///
+    /// A chain is a subgraph of elementwise operators where the output of each
+    /// operator, except the last, is used only by one other operator in the
+    /// chain.
+    fn fuse_elementwise(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        graph.apply_fusion(|graph, _op_node_id, op_node| {
+            if !is_fusable_elementwise(op_node) {
+                return None;
+            }
+
+            // Skip operators which will be included in a chain that ends with
+            // the operator that uses their output.
+            let op_output = op_node.output_id()?;
+            if has_single_use(graph, op_output)
+                && graph
+                    .find_operator_with_input(op_output)
+                    .is_some_and(|user| {
+                        is_fusable_elementwise(user)
+                            && user.input_ids()[..elementwise_data_inputs(user)]
+                                .contains(&Some(op_output))
+                    })
+            {
+                return None;
+            }
+
+            let mut chain = ElementwiseChain::default();
+            chain.add_operator(graph, op_node);
+            if chain.steps.len() < 2 {
+                return None;
+            }
+
+            Some(Fusion::from_op(
+                op_node.name(),
+                FusedElementwise::new(chain.steps)?,
+                chain.input_ids.into_iter().map(Some).collect(),
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `MatMul(Softmax(MatMul(Q, K^T) * scale + mask), V)` into
+    /// `ScaledDotProductAttention(Q, K, V, mask)`.
+    ///
+    /// The scale and mask are optional. The scale can be applied by
+    /// multiplying or dividing the scores, or by scaling the query and key
+    /// separately before the first `MatMul`, as in PyTorch's export of
+    /// `scaled_dot_product_attention`.
+    ///
+    /// A constant causal mask, such as one produced by folding a
+    /// `Trilu`/`Where` subgraph, is replaced by the operator's `causal`
+    /// option.
+    fn fuse_attention(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let attention_pat = binary_op(
+            "MatMul",
+            unary_op_key("Softmax", symbol("scores"), "softmax"),
+            symbol("value"),
+        );
+
+        let qk = binary_op("MatMul", symbol("query"), symbol("key_t"));
+        let qk_prescaled = binary_op(
+            "MatMul",
+            symbol("query") * const_symbol("q_scale"),
+            symbol("key_t") * const_symbol("k_scale"),
+        );
+        let qk_div = qk.clone() / const_symbol("div_scale");
+        let qk_mul = qk.clone() * const_symbol("mul_scale");
+
+        // Patterns for the Softmax input, ordered so that masked variants are
+        // tried before the unmasked variants they contain.
+        let mask = symbol("mask");
+        let score_pats = [
+            qk_prescaled.clone() + mask.clone(),
+            qk_prescaled,
+            qk_div.clone() + mask.clone(),
+            qk_div,
+            qk_mul.clone() + mask.clone(),
+            qk_mul,
+            qk.clone() + mask,
+            qk,
+        ];
+
+        let shapes = infer_value_shapes(graph.graph());
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            // Get the value of a scalar constant. Single-item tensors with
+            // more than one dim are rejected as they can increase the rank of
+            // the scores when broadcast.
+            let scalar_const = |node_id| match graph.graph().get_node(node_id) {
+                Some(Node::Constant(val)) if val.as_input().ndim() <= 1 => val.as_scalar(),
+                _ => None,
+            };
+
+            let attention_match = attention_pat.test(op_node_id, graph)?;
+            let softmax_id = attention_match.resolved_symbol("softmax").unwrap();
+            let scores = attention_match.resolved_symbol("scores").unwrap();
+            let value = attention_match.resolved_symbol("value").unwrap();
+
+            let softmax_axis = match graph.graph().get_node(softmax_id) {
+                Some(Node::Operator(softmax_op)) => softmax_op
+                    .operator()
+                    .downcast_ref::<Softmax>()
+                    .map(|op| op.axis),
+                _ => None,
+            }?;
+            if softmax_axis != -1 {
+                return None;
+            }
+
+            let scores_match = score_pats.iter().find_map(|pat| pat.test(scores, graph))?;
+            let query = scores_match.resolved_symbol("query").unwrap();
+            let key_t = scores_match.resolved_symbol("key_t").unwrap();
+            let mask = scores_match.resolved_symbol("mask");
+
+            let scale = if let Some(scale) = scores_match.resolved_symbol("div_scale") {
+                1. / scalar_const(scale)?
+            } else if let Some(scale) = scores_match.resolved_symbol("mul_scale") {
+                scalar_const(scale)?
+            } else if let (Some(q_scale), Some(k_scale)) = (
+                scores_match.resolved_symbol("q_scale"),
+                scores_match.resolved_symbol("k_scale"),
+            ) {
+                scalar_const(q_scale)? * scalar_const(k_scale)?
+            } else {
+                1.
+            };
+
+            // If the key is transposed by swapping the last two dims, use
+            // the input to the transpose as the key.
+            let transposed_key = graph
+                .graph()
+                .get_source_node(key_t)
+                .and_then(|(_, op_node)| {
+                    let (transpose_op, [transpose_input], _) =
+                        op_node.match_type::<Transpose, 1, 1>()?;
+                    let perm = transpose_op.perm.as_deref()?;
+                    let ndim = perm.len();
+                    let swaps_last_two = ndim >= 2
+                        && perm[..ndim - 2].iter().enumerate().all(|(i, &p)| i == p)
+                        && perm[ndim - 2..] == [ndim - 1, ndim - 2];
+                    swaps_last_two.then_some(transpose_input)
+                });
+
+            let op_output = op_node.output_id()?;
+            let (key, key_transposed) = match transposed_key {
+                Some(key) => (key, false),
+                None => (key_t, true),
+            };
+
+            // Replace a constant causal mask with the `causal` option. Masks
+            // with more than two dims are only replaced if the query has at
+            // least as many, as otherwise they increase the rank of the
+            // scores.
+            let query_ndim = value_shape(graph.graph(), &shapes, query).map(|s| s.len());
+            let causal_mask = mask.filter(|&mask_id| match graph.graph().get_node(mask_id) {
+                Some(Node::Constant(val)) => match val.as_input() {
+                    Input::FloatTensor(mask) => {
+                        (mask.ndim() <= 2 || query_ndim.is_some_and(|n| n >= mask.ndim()))
+                            && is_causal_mask(mask)
+                    }
+                    _ => false,
+                },
+                _ => false,
+            });
+            let (mask, causal) = match causal_mask {
+                Some(_) => (None, true),
+                None => (mask, false),
+            };
+
+            Some(Fusion::from_op(
+                op_node.name(),
+                ScaledDotProductAttention {
+                    scale: Some(scale),
+                    causal,
+                    key_transposed,
+                },
+                vec![Some(query), Some(key), Some(value), mask],
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `MatMul(A, B) * scale` or `MatMul(A, B) / scale`, where `scale`
+    /// is a scalar constant, into `FusedMatMul(A, B)`.
+    fn fuse_matmul_scale(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let matmul = operator("MatMul", [symbol("a"), symbol("b")], Some("matmul"));
+        let patterns = [
+            matmul.clone() * const_symbol("mul_scale"),
+            matmul / const_symbol("div_scale"),
+        ];
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let scale_match = patterns
+                .iter()
+                .find_map(|pat| pat.test(op_node_id, graph))?;
+            let a = scale_match.resolved_symbol("a").unwrap();
+            let b = scale_match.resolved_symbol("b").unwrap();
+            let matmul_id = scale_match.resolved_symbol("matmul").unwrap();
+
+            let alpha = if let Some(scale) = scale_match.resolved_symbol("div_scale") {
+                1. / scalar_float_const(graph.graph(), scale)?
+            } else {
+                let scale = scale_match.resolved_symbol("mul_scale").unwrap();
+                scalar_float_const(graph.graph(), scale)?
+            };
+
+            let Some(Node::Operator(matmul_node)) = graph.graph().get_node(matmul_id) else {
+                return None;
+            };
+            if !has_single_use(graph, matmul_node.output_id()?) {
+                return None;
+            }
+
+            Some(Fusion::from_op(
+                matmul_node.name(),
+                FusedMatMul {
+                    alpha,
+                    activation: None,
+                },
+                vec![Some(a), Some(b)],
+                op_node.output_id()?,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `MatMul(A, B) + bias`, where `bias` is a constant vector, into
+    /// `FusedMatMul(A, B, bias)`.
+    ///
+    /// The bias length must be 1 or match the last dimension of `B`.
+    /// Otherwise the addition may broadcast the bias along another dimension
+    /// (eg. a `[M, 1]` output plus a bias of length `M`), which the fused
+    /// operator doesn't support.
+    ///
+    /// This runs after [`fuse_matmul_scale`](Self::fuse_matmul_scale) so that
+    /// `MatMul(A, B) * scale + bias` is fused into a single operator.
+    fn fuse_matmul_bias(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let (a, b, bias) = (symbol("a"), symbol("b"), const_symbol("bias"));
+        let patterns = [
+            operator("MatMul", [a.clone(), b.clone()], Some("matmul")) + bias.clone(),
+            operator("FusedMatMul", [a, b], Some("matmul")) + bias,
+        ];
+        let shapes = infer_value_shapes(graph.graph());
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let bias_match = patterns
+                .iter()
+                .find_map(|pat| pat.test(op_node_id, graph))?;
+            let a = bias_match.resolved_symbol("a").unwrap();
+            let b = bias_match.resolved_symbol("b").unwrap();
+            let bias = bias_match.resolved_symbol("bias").unwrap();
+            let matmul_id = bias_match.resolved_symbol("matmul").unwrap();
+
+            let &[bias_len] = float_const(graph.graph(), bias)?.shape() else {
+                return None;
+            };
+            if bias_len != 1 {
+                let b_shape = value_shape(graph.graph(), &shapes, b)?;
+                if b_shape.last() != Some(&Dimension::Fixed(bias_len)) {
+                    return None;
+                }
+            }
+
+            let Some(Node::Operator(matmul_node)) = graph.graph().get_node(matmul_id) else {
+                return None;
+            };
+            if !has_single_use(graph, matmul_node.output_id()?) {
+                return None;
+            }
+            let alpha = match matmul_node.operator().downcast_ref::<FusedMatMul>() {
+                // The bias must be added before any fused activation.
+                Some(FusedMatMul {
+                    activation: Some(_),
+                    ..
+                }) => return None,
+                Some(fused_op) => fused_op.alpha,
+                None => 1.,
+            };
+
+            Some(Fusion::from_op(
+                matmul_node.name(),
+                FusedMatMul {
+                    alpha,
+                    activation: None,
+                },
+                vec![Some(a), Some(b), Some(bias)],
+                op_node.output_id()?,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `Activation(MatMul(A, B))` into `FusedMatMul(A, B)` and
+    /// `Activation(Gemm(A, B, C))` into `FusedGemm(A, B, C)`, where
+    /// `Activation` is `Relu`, `LeakyRelu`, `Clip` or `Sigmoid`.
+    ///
+    /// This runs after [`fuse_matmul_bias`](Self::fuse_matmul_bias) so that
+    /// scaling, bias addition and the activation are all fused into a single
+    /// operator.
+    fn fuse_matmul_activation(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        graph.apply_fusion(|graph, _op_node_id, op_node| {
+            let activation = fusable_activation(graph.graph(), op_node)?;
+            let matmul_output = (*op_node.input_ids().first()?)?;
+            let (_, matmul_node) = graph.graph().get_source_node(matmul_output)?;
+
+            // The matmul output must not be used elsewhere.
+            if !has_single_use(graph, matmul_output) {
+                return None;
+            }
+
+            let op_output = op_node.output_id()?;
+            let matmul_op = matmul_node.operator();
+            let input_ids = matmul_node.input_ids().to_vec();
+
+            if let Some(gemm_op) = matmul_op.downcast_ref::<Gemm>() {
+                Some(Fusion::from_op(
+                    matmul_node.name(),
+                    FusedGemm {
+                        gemm: gemm_op.clone(),
+                        activation,
+                    },
+                    input_ids,
+                    op_output,
+                ))
+            } else {
+                let alpha = if matmul_op.downcast_ref::<MatMul>().is_some() {
+                    1.
+                } else {
+                    let fused_op = matmul_op.downcast_ref::<FusedMatMul>()?;
+                    if fused_op.activation.is_some() {
+                        return None;
+                    }
+                    fused_op.alpha
+                };
+                Some(Fusion::from_op(
+                    matmul_node.name(),
+                    FusedMatMul {
+                        alpha,
+                        activation: Some(activation),
+                    },
+                    input_ids,
+                    op_output,
+                ))
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `Op(Transpose(X), Y, ...) -> Z` into `FusedTranspose<Op>(X, Y, ...) -> Z`.
+    ///
+    /// This avoids materializing the transposed input for operators which can
+    /// efficiently handle the input being non-contiguous.
+    fn fuse_transpose(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        graph.apply_fusion(|edges, _op_node_id, op_node| {
+            let (transpose_op, [transpose_input], [transpose_output]) =
+                op_node.match_type::<Transpose, 1, 1>()?;
+
+            let transpose_target = edges.find_operator_with_input(transpose_output)?;
+
+            // Filter against a set of operators which are known to efficiently
+            // handle transposed inputs.
+            if !["MatMul", "FusedMatMul"].contains(&transpose_target.operator().name()) {
+                return None;
+            }
+
+            // Only single-output operators are currently supported.
+            let target_output = match transpose_target.output_ids() {
+                [Some(output)] => Some(*output),
+                _ => None,
+            }?;
+
+            // Replace transpose output with transpose input in the fused
+            // operator's inputs.
+            let fused_input_idx = transpose_target
+                .input_ids()
+                .iter()
+                .position(|&input_id| input_id == Some(transpose_output))
+                .expect("fused input missing");
+            let mut fused_input = transpose_target.input_ids().to_vec();
+            fused_input[fused_input_idx] = Some(transpose_input);
+
+            let fused_op = FusedTranspose::wrap(
+                transpose_target.clone_operator(),
+                fused_input_idx,
+                transpose_op.perm.as_deref(),
+            );
+
+            Some(Fusion::from_op(
+                transpose_target.name(),
+                fused_op,
+                fused_input,
+                target_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `x * Sigmoid(x)` into `Silu(x)`.
+    fn fuse_silu(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let x = symbol("x");
+        let silu_pattern = x.clone() * unary_op("Sigmoid", x.clone());
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let silu_match = silu_pattern.test(op_node_id, graph)?;
+            let silu_input = silu_match.resolved_symbol("x").expect("missing symbol");
+            let op_output = op_node.output_id()?;
+
+            Some(Fusion::from_op(
+                op_node.name(),
+                Silu {},
+                vec![Some(silu_input)],
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `0.5 * X * (1 + Erf(X / Sqrt(2)))` into `Gelu(X)`.
+    fn fuse_gelu(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        // The expression for GELU is usually written as `x * 0.5 * (...)`
+        // instead of `x * (...) * 0.5`. Ideally our graph pattern matcher
+        // would be smart enough to let us write one pattern and have it match
+        // either structure. However it isn't. The pattern used matches PyTorch's
+        // `nn.GELU`.
+        let x = symbol("x");
+        let gelu_pattern = x.clone() * (unary_op("Erf", x.clone() / (2.0f32).sqrt()) + 1.0) * 0.5;
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let gelu_match = gelu_pattern.test(op_node_id, graph)?;
+            let gelu_input = gelu_match.resolved_symbol("x").expect("missing symbol");
+            let op_output = op_node.output_id()?;
+
+            Some(Fusion::from_op(
+                op_node.name(),
+                Gelu {},
+                vec![Some(gelu_input)],
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Identify and fuse common patterns for `LayerNormalization(X)`.
+    fn fuse_layer_norm(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let x = symbol("x");
+
+        // LayerNormalization has three steps. Pattern matching only supports a
+        // single expression, so we use three patterns and match them in reverse
+        // order (ie. starting from the output of the final step).
+
+        // First step: Center values
+        let center_pat = x.clone() - unary_op_key("ReduceMean", x.clone(), "center_mean");
+
+        // Middle step: Normalize variance
+        let epsilon = const_symbol("epsilon");
+        let normalize_variance_pat = x.clone()
+            / unary_op(
+                "Sqrt",
+                epsilon + unary_op_key("ReduceMean", binary_op("Pow", x.clone(), 2.0), "norm_mean"),
+            );
+
+        // Final step: Shift and scale the normalized values
+        let bias = const_symbol("bias");
+        let scale = const_symbol("scale");
+        let shift_scale_pat = (x.clone() * scale) + bias;
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let mean_op_reduces_last_axis =
+                |node_id| reduce_mean_over_last_axis(graph.graph(), node_id);
+
+            let shift_scale_match = shift_scale_pat.test(op_node_id, graph)?;
+            let shift_scale_input = shift_scale_match.resolved_symbol("x").unwrap();
+            let bias_input = shift_scale_match.resolved_symbol("bias").unwrap();
+            let scale_input = shift_scale_match.resolved_symbol("scale").unwrap();
+
+            let norm_match = normalize_variance_pat.test(shift_scale_input, graph)?;
+            let norm_input = norm_match.resolved_symbol("x").unwrap();
+            let epsilon_input = norm_match.resolved_symbol("epsilon").unwrap();
+            let norm_mean = norm_match.resolved_symbol("norm_mean").unwrap();
+            if !mean_op_reduces_last_axis(norm_mean) {
+                // The LayerNormalization operator supports taking the mean over
+                // multiple trailing axes. However this fusion only supports the
+                // common case of taking the mean over one axis.
+                return None;
+            }
+
+            let center_match = center_pat.test(norm_input, graph)?;
+            let center_input = center_match.resolved_symbol("x").unwrap();
+            let center_mean = center_match.resolved_symbol("center_mean").unwrap();
+            if !mean_op_reduces_last_axis(center_mean) {
+                return None;
+            }
+
+            let op_output = op_node.output_id()?;
+
+            let epsilon = match graph.graph().get_node(epsilon_input) {
+                Some(Node::Constant(val)) => val.as_scalar(),
+                _ => None,
+            }?;
+
+            Some(Fusion::from_op(
+                op_node.name(),
+                LayerNormalization {
+                    axis: -1,
+                    epsilon: Some(epsilon),
+                },
+                vec![Some(center_input), Some(scale_input), Some(bias_input)],
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Identify and fuse common patterns for `RMSNormalization(X)`.
+    fn fuse_rms_norm(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        let x = symbol("x");
+        let epsilon = const_symbol("epsilon");
+        let rms = unary_op(
+            "Sqrt",
+            epsilon + unary_op_key("ReduceMean", binary_op("Pow", x.clone(), 2.0), "mean"),
+        );
+
+        // Models exported from PyTorch either divide by the RMS, or multiply
+        // by its reciprocal (from `torch.rsqrt`).
+        let scale = const_symbol("scale");
+        let rms_norm_div_pat = (x.clone() / rms.clone()) * scale.clone();
+        let rms_norm_recip_pat = (x.clone() * unary_op("Reciprocal", rms)) * scale;
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let rms_norm_match = rms_norm_div_pat
+                .test(op_node_id, graph)
+                .or_else(|| rms_norm_recip_pat.test(op_node_id, graph))?;
+            let input = rms_norm_match.resolved_symbol("x").unwrap();
+            let epsilon_input = rms_norm_match.resolved_symbol("epsilon").unwrap();
+            let scale_input = rms_norm_match.resolved_symbol("scale").unwrap();
+            let mean = rms_norm_match.resolved_symbol("mean").unwrap();
+
+            // Without `keep_dims`, the mean is broadcast against the input
+            // along a different axis.
+            let keeps_dims = match graph.graph().get_node(mean) {
+                Some(Node::Operator(op_node)) => op_node
+                    .operator()
+                    .downcast_ref::<ReduceMean>()
+                    .is_some_and(|op| op.keep_dims),
+                _ => false,
+            };
+            if !keeps_dims || !reduce_mean_over_last_axis(graph.graph(), mean) {
+                return None;
+            }
+
+            // The fused operator requires the scale to be broadcastable to the
+            // normalized shape. Higher-rank scales are not supported, as they
+            // might broadcast the input to a larger shape.
+            match graph.graph().get_node(scale_input) {
+                Some(Node::Constant(val)) if val.as_input().ndim() <= 1 => {}
+                _ => return None,
+            }
+
+            let epsilon = match graph.graph().get_node(epsilon_input) {
+                Some(Node::Constant(val)) => val.as_scalar(),
+                _ => None,
+            }?;
+            let op_output = op_node.output_id()?;
+
+            Some(Fusion::from_op(
+                op_node.name(),
+                RMSNormalization {
+                    axis: -1,
+                    epsilon: Some(epsilon),
+                },
+                vec![Some(input), Some(scale_input)],
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+
+    /// Fuse `X * cos + Concat(-X[..., half:], X[..., :half]) * sin` into
+    /// `RotaryEmbedding(X, cos, sin)`.
+    ///
+    /// This is the `rotate_half` form of rotary embeddings used by Llama,
+    /// Qwen, GPT-NeoX and related models. The shapes of `X`, `cos` and `sin`
+    /// must be known, as the fused operator broadcasts `cos` and `sin`
+    /// differently for some shapes.
+    fn fuse_rotary_embedding(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
+        // Exported `Slice` operators may or may not have a `steps` input.
+        let rope_pats = [false, true].map(|with_steps| {
+            let slice = |names: [&'static str; 4]| {
+                let [starts, ends, axes, steps] = names;
+                let mut inputs = vec![
+                    symbol("x"),
+                    const_symbol(starts),
+                    const_symbol(ends),
+                    const_symbol(axes),
+                ];
+                if with_steps {
+                    inputs.push(const_symbol(steps));
+                }
+                operator("Slice", inputs, None)
+            };
+            let x_lo = slice(["lo_starts", "lo_ends", "lo_axes", "lo_steps"]);
+            let x_hi = slice(["hi_starts", "hi_ends", "hi_axes", "hi_steps"]);
+            let rotated = operator("Concat", [unary_op("Neg", x_hi), x_lo], Some("concat"));
+
+            // The rotated term is listed first so that `x` is resolved using
+            // the slices.
+            rotated * symbol("sin") + symbol("x") * symbol("cos")
+        });
+
+        let shapes = infer_value_shapes(graph.graph());
+
+        graph.apply_fusion(|graph, op_node_id, op_node| {
+            let rope_match = rope_pats
+                .iter()
+                .find_map(|pat| pat.test(op_node_id, graph))?;
+
+            let int_vector = |name: &str| -> Option<Vec<i32>> {
+                let node_id = rope_match.resolved_symbol(name)?;
+                match graph.graph().get_node(node_id) {
+                    Some(Node::Constant(val)) => {
+                        let vec: Option<&[i32]> = val.as_vector();
+                        vec.map(|v| v.to_vec())
+                    }
+                    _ => None,
+                }
+            };
+
+            // Check the slices split the last axis into halves.
+            let half = match int_vector("lo_ends")?.as_slice() {
+                &[half] if half > 0 => half,
+                _ => return None,
+            };
+            let hi_end_ok = matches!(int_vector("hi_ends")?.as_slice(), &[end] if end >= 2 * half);
+            let slices_ok = int_vector("lo_starts")? == [0]
+                && int_vector("hi_starts")? == [half]
+                && hi_end_ok
+                && int_vector("lo_axes")? == [-1]
+                && int_vector("hi_axes")? == [-1]
+                && [int_vector("lo_steps"), int_vector("hi_steps")]
+                    .iter()
+                    .all(|steps| steps.is_none() || steps.as_deref() == Some(&[1]));
+            if !slices_ok {
+                return None;
+            }
+
+            let concat_id = rope_match.resolved_symbol("concat").unwrap();
+            let concat_axis = match graph.graph().get_node(concat_id) {
+                Some(Node::Operator(op)) => op.operator().downcast_ref::<Concat>().map(|c| c.axis),
+                _ => None,
+            }?;
+            if concat_axis != -1 {
+                return None;
+            }
+
+            let x = rope_match.resolved_symbol("x").unwrap();
+            let cos = rope_match.resolved_symbol("cos").unwrap();
+            let sin = rope_match.resolved_symbol("sin").unwrap();
+            let op_output = op_node.output_id()?;
+
+            // The fused operator only rotates the first `rotary_dim` elements
+            // of each row, so the slices must cover the whole last axis. It
+            // also supports only 3D and 4D inputs, and treats 3D `cos` and
+            // `sin` values as `[batch, seq, dim]` for 4D inputs, whereas
+            // `Mul` broadcasts them as `[heads, seq, dim]`.
+            let rotary_dim = 2 * half as usize;
+            let x_shape = value_shape(graph.graph(), &shapes, x)?;
+            let cos_shape = value_shape(graph.graph(), &shapes, cos)?;
+            let sin_shape = value_shape(graph.graph(), &shapes, sin)?;
+            let shapes_ok = matches!(x_shape.len(), 3 | 4)
+                && x_shape.last() == Some(&Dimension::Fixed(rotary_dim))
+                && cos_shape == sin_shape
+                && cos_shape.last() == Some(&Dimension::Fixed(rotary_dim))
+                && broadcasts_to(&cos_shape, &x_shape)
+                && !(x_shape.len() == 4
+                    && cos_shape.len() == 3
+                    && cos_shape[0] != Dimension::Fixed(1));
+            if !shapes_ok {
+                return None;
+            }
+
+            Some(Fusion::from_op(
+                op_node.name(),
+                RotaryEmbedding {
+                    interleaved: false,
+                    num_heads: Some(1),
+                    rotary_dim: Some(rotary_dim),
+                },
+                vec![Some(x), None, Some(cos), Some(sin)],
+                op_output,
+            ))
+        });
+
+        Ok(())
+    }
+}
+
+impl Default for GraphOptimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::sync::Arc;
+
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal_with_tolerance;
+    use rten_tensor::{Tensor, TensorView};
+
+    use super::pattern_matcher::{symbol, unary_op};
+    use super::{reshape_is_noop, GraphMutator, GraphOptimizer, OptimizeError, PatternFusion};
+    use crate::downcast::DowncastDyn;
+    use crate::gemm::Activation;
+    use crate::graph::{Constant, Dimension, Graph, Node};
+    use crate::ops::{
+        Abs, Add, BatchNormalization, Clip, Concat, Conv, Div, Equal, Erf, FusedConv, FusedGemm,
+        FusedMatMul, Gather, Gemm, Identity, InputList, IntoOpResult, LayerNormalization, MatMul,
+        MaxPool, Mul, Neg, OpError, Operator, OutputList, Padding, Pow, RMSNormalization,
+        Reciprocal, ReduceMean, Relu, Reshape, Resize, RotaryEmbedding, ScaledDotProductAttention,
+        Shape, Sigmoid, Size, Slice, Softmax, Sqrt, Sub, Transpose, Trilu, Unsqueeze, Where,
+    };
+    use crate::tensor_pool::TensorPool;
+
+    fn optimize_graph(graph: Graph) -> Result<Graph, OptimizeError> {
+        let optimizer = GraphOptimizer::new();
+        optimizer.optimize(graph)
+    }
+
+    #[test]
+    fn test_constant_propagation() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+
+        // Add an operator with constant inputs.
+        let const_a = graph.add_constant(Some("const_a"), Tensor::from([1, 2, 3]));
+        let const_b = graph.add_constant(Some("const_b"), Tensor::from([4, 5, 6]));
+        let (_, add_out) = graph.add_simple_op("add_1", Add {}, &[const_a, const_b]);
+
+        // Add an operator with a dynamic input and the output of the previous operator.
+        let input = graph.add_value(Some("input"), None);
+        let (_, add_2_out) = graph.add_simple_op("add_2", Add {}, &[add_out, input]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[add_out, add_2_out]);
+
+        // Optimize the graph. This should replace the first operator's output
+        // with a constant value.
+        let optimizer = GraphOptimizer::new();
+        let optimized_graph = optimizer.optimize(graph)?;
+
+        // Check that we got the expected inputs and outputs. The optimizer
+        // does not preserve IDs, since unused nodes are removed, so nodes
+        // are looked up by name.
+        let input = optimized_graph.get_node_id("input").unwrap();
+        let add_2_out = optimized_graph.get_node_id("add_2_out").unwrap();
+        let add_op_2 = optimized_graph.get_node_id("add_2").unwrap();
+        assert_eq!(optimized_graph.input_ids(), &[input]);
+        assert_eq!(optimized_graph.output_ids()[1], add_2_out);
+        assert!(optimized_graph.get_node_id("add_1").is_none());
+
+        // Check first output was replaced with constant.
+        let replaced_node = optimized_graph
+            .get_node(optimized_graph.output_ids()[0])
+            .and_then(|n| match &n {
+                Node::Constant(c) => Some(c),
+                _ => None,
+            })
+            .unwrap();
+        let Constant::Int(const_int) = replaced_node else {
+            return Err("constant not an int".into());
+        };
+        assert_eq!(const_int.view(), Tensor::from([5, 7, 9]));
+
+        // Check input to second operator was replaced with constant.
+        let op = optimized_graph
+            .get_node(add_op_2)
+            .and_then(|n| match &n {
+                Node::Operator(op) => Some(op),
+                _ => None,
+            })
+            .unwrap();
+        let input_ids: Vec<_> = op.input_ids().iter().map(|id| id.unwrap()).collect();
+        assert_eq!(input_ids.len(), 2);
+        assert_eq!(input_ids[0], optimized_graph.output_ids()[0]);
+        assert_eq!(input_ids[1], input);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_transpose() {
+        let mut graph = Graph::new();
+
+        let input_1 = graph.add_value(None, None);
+        let input_2 = graph.add_value(None, None);
+
+        let (_, transpose_out) =
+            graph.add_simple_op("transpose", Transpose { perm: None }, &[input_1]);
+        let (_, matmul_out) = graph.add_simple_op("matmul", MatMul {}, &[transpose_out, input_2]);
+        graph.set_input_ids(&[input_1, input_2]);
+        graph.set_output_ids(&[matmul_out]);
+
+        let graph = optimize_graph(graph).unwrap();
+
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.operator().name(), "FusedTranspose(MatMul)");
+        assert_eq!(op.name(), Some("matmul"));
+    }
+
+    #[test]
+    fn test_fuse_silu() {
+        let mut graph = Graph::new();
+
+        let input = graph.add_value(None, None);
+        let (_, sigmoid_out) = graph.add_simple_op("sigmoid", Sigmoid {}, &[input]);
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, sigmoid_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_out]);
+
+        let graph = optimize_graph(graph).unwrap();
+
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.operator().name(), "Silu");
+        assert_eq!(op.name(), Some("mul"));
+    }
+
+    #[test]
+    fn test_fuse_gelu() {
+        let mut graph = Graph::new();
+
+        let sqrt_2 = graph.add_constant(None, Tensor::from((2.0f32).sqrt()));
+        let one = graph.add_constant(None, Tensor::from(1.0));
+        let half = graph.add_constant(None, Tensor::from(0.5));
+
+        let input = graph.add_value(None, None);
+        let (_, div_out) = graph.add_simple_op("div", Div {}, &[input, sqrt_2]);
+        let (_, erf_out) = graph.add_simple_op("erf", Erf {}, &[div_out]);
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[erf_out, one]);
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, add_out]);
+        let (_, mul_half_out) = graph.add_simple_op("mul_half", Mul {}, &[mul_out, half]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_half_out]);
+
+        let graph = optimize_graph(graph).unwrap();
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.operator().name(), "Gelu");
+        assert_eq!(op.name(), Some("mul_half"));
+    }
+
+    fn layer_norm_graph() -> Graph {
+        let mut graph = Graph::new();
+        let input = graph.add_value(None, None);
+
+        // Center values
+        let (_, mean_out) = graph.add_simple_op(
+            "mean",
+            ReduceMean {
+                axes: Some(vec![-1]),
+                keep_dims: false,
+            },
+            &[input],
+        );
+        let (_, sub_out) = graph.add_simple_op("sub", Sub {}, &[input, mean_out]);
+
+        // Normalize variance
+        let two = graph.add_constant(None, Tensor::from(2.));
+        let (_, pow_out) = graph.add_simple_op("pow", Pow {}, &[sub_out, two]);
+        let (_, var_mean_out) = graph.add_simple_op(
+            "var_mean",
+            ReduceMean {
+                axes: Some(vec![-1]),
+                keep_dims: false,
+            },
+            &[pow_out],
+        );
+        let epsilon = graph.add_constant(None, Tensor::from(1e-6));
+        let (_, add_eps_out) = graph.add_simple_op("add_eps", Add {}, &[epsilon, var_mean_out]);
+        let (_, sqrt_out) = graph.add_simple_op("sqrt", Sqrt {}, &[add_eps_out]);
+        let (_, div_out) = graph.add_simple_op("div", Div {}, &[sub_out, sqrt_out]);
+
+        // Shift and scale
+        let bias = graph.add_constant(None, Tensor::from([1., 2., 3.]));
+        let scale = graph.add_constant(None, Tensor::from([3., 4., 5.]));
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[div_out, scale]);
+        let (_, add_out) = graph.add_simple_op("final_add", Add {}, &[mul_out, bias]);
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[add_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fuse_layer_norm() {
+        let graph = layer_norm_graph();
+        let graph = optimize_graph(graph).unwrap();
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.operator().name(), "LayerNormalization");
+        assert_eq!(op.name(), Some("final_add"));
+
+        let layer_norm = op.operator().downcast_ref::<LayerNormalization>().unwrap();
+        assert_eq!(layer_norm.epsilon, Some(1e-6));
+    }
+
+    /// Create a graph for RMS normalization.
+    ///
+    /// If `reciprocal` is true, the input is multiplied by the reciprocal of
+    /// the RMS rather than divided by it. `keep_dims` is the attribute of the
+    /// `ReduceMean` operator that computes the mean square.
+    fn rms_norm_graph(reciprocal: bool, keep_dims: bool) -> Graph {
+        let mut graph = Graph::new();
+        let input = graph.add_value(None, None);
+
+        let two = graph.add_constant(None, Tensor::from(2.));
+        let (_, pow_out) = graph.add_simple_op("pow", Pow {}, &[input, two]);
+        let (_, mean_out) = graph.add_simple_op(
+            "mean",
+            ReduceMean {
+                axes: Some(vec![-1]),
+                keep_dims,
+            },
+            &[pow_out],
+        );
+        let epsilon = graph.add_constant(None, Tensor::from(1e-6));
+        let (_, add_eps_out) = graph.add_simple_op("add_eps", Add {}, &[mean_out, epsilon]);
+        let (_, sqrt_out) = graph.add_simple_op("sqrt", Sqrt {}, &[add_eps_out]);
+        let (_, norm_out) = if reciprocal {
+            let (_, recip_out) = graph.add_simple_op("reciprocal", Reciprocal {}, &[sqrt_out]);
+            graph.add_simple_op("mul_recip", Mul {}, &[input, recip_out])
+        } else {
+            graph.add_simple_op("div", Div {}, &[input, sqrt_out])
+        };
+
+        let scale = graph.add_constant(None, Tensor::from([3., 4., 5.]));
+        let (_, mul_out) = graph.add_simple_op("scale", Mul {}, &[scale, norm_out]);
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fuse_rms_norm() -> Result<(), Box<dyn Error>> {
+        for reciprocal in [false, true] {
+            let graph = optimize_graph(rms_norm_graph(reciprocal, true))?;
+            let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+            assert_eq!(op.operator().name(), "RMSNormalization");
+            assert_eq!(op.name(), Some("scale"));
+
+            let rms_norm = op.operator().downcast_ref::<RMSNormalization>().unwrap();
+            assert_eq!(rms_norm.epsilon, Some(1e-6));
+
+            // Check the fused graph produces the same result as the original.
+            let input = Tensor::from([[1., 2., 3.], [-4., 0.5, 6.]]);
+            let run_graph = |graph: &Graph| -> Result<Tensor, Box<dyn Error>> {
+                let inputs = vec![(graph.input_ids()[0], input.view().into())];
+                let mut outputs = graph.run(inputs, graph.output_ids(), None)?;
+                Ok(outputs.remove(0).try_into()?)
+            };
+            let expected = run_graph(&rms_norm_graph(reciprocal, true))?;
+            let actual = run_graph(&graph)?;
+            expect_equal_with_tolerance(&actual, &expected, 1e-4, 0.)?;
+        }
+
+        // If the mean is computed without `keep_dims`, the subgraph is not
+        // equivalent to RMS normalization.
+        let graph = optimize_graph(rms_norm_graph(false, false))?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_ne!(op.operator().name(), "RMSNormalization");
+
+        Ok(())
+    }
+
+    /// Create a graph for rotary embeddings in the `x * cos + rotate_half(x) *
+    /// sin` form used by PyTorch models, where the rotated halves have size 2.
+    fn rotary_embedding_graph(
+        with_steps: bool,
+        x_shape: Vec<Dimension>,
+        cos_shape: Vec<Dimension>,
+    ) -> Graph {
+        let mut graph = Graph::new();
+        let x = graph.add_value(Some("x"), Some(x_shape));
+        let cos = graph.add_value(Some("cos"), Some(cos_shape.clone()));
+        let sin = graph.add_value(Some("sin"), Some(cos_shape));
+
+        let mut add_slice = |name, start: i32, end: i32| {
+            let starts = graph.add_constant(None, Tensor::from([start]));
+            let ends = graph.add_constant(None, Tensor::from([end]));
+            let axes = graph.add_constant(None, Tensor::from([-1]));
+            let mut inputs = vec![x, starts, ends, axes];
+            if with_steps {
+                inputs.push(graph.add_constant(None, Tensor::from([1])));
+            }
+            let (_, out) = graph.add_simple_op(name, Slice {}, &inputs);
+            out
+        };
+        let x_lo = add_slice("slice_lo", 0, 2);
+        let x_hi = add_slice("slice_hi", 2, i32::MAX);
+
+        let (_, neg_out) = graph.add_simple_op("neg", Neg {}, &[x_hi]);
+        let (_, rotated) = graph.add_simple_op("concat", Concat { axis: -1 }, &[neg_out, x_lo]);
+        let (_, x_cos) = graph.add_simple_op("mul_cos", Mul {}, &[x, cos]);
+        let (_, rotated_sin) = graph.add_simple_op("mul_sin", Mul {}, &[rotated, sin]);
+        let (_, out) = graph.add_simple_op("rope_add", Add {}, &[x_cos, rotated_sin]);
+
+        graph.set_input_ids(&[x, cos, sin]);
+        graph.set_output_ids(&[out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fuse_rotary_embedding() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(1234);
+
+        let fixed = |shape: &[usize]| shape.iter().copied().map(Dimension::Fixed).collect();
+        let seq = || Dimension::Symbolic("seq".to_string());
+
+        struct Case {
+            x_shape: Vec<Dimension>,
+            cos_shape: Vec<Dimension>,
+            fused: bool,
+        }
+
+        let cases = [
+            // 4D input with cos and sin broadcast over batch and heads.
+            Case {
+                x_shape: vec![
+                    Dimension::Fixed(1),
+                    Dimension::Fixed(2),
+                    seq(),
+                    Dimension::Fixed(4),
+                ],
+                cos_shape: vec![
+                    Dimension::Fixed(1),
+                    Dimension::Fixed(1),
+                    seq(),
+                    Dimension::Fixed(4),
+                ],
+                fused: true,
+            },
+            Case {
+                x_shape: fixed(&[1, 2, 3, 4]),
+                cos_shape: fixed(&[3, 4]),
+                fused: true,
+            },
+            // 3D input.
+            Case {
+                x_shape: fixed(&[2, 3, 4]),
+                cos_shape: fixed(&[2, 3, 4]),
+                fused: true,
+            },
+            // Last dim of input is larger than the rotated halves.
+            Case {
+                x_shape: fixed(&[1, 2, 3, 6]),
+                cos_shape: fixed(&[3, 6]),
+                fused: false,
+            },
+            // Input rank is not 3 or 4.
+            Case {
+                x_shape: fixed(&[3, 4]),
+                cos_shape: fixed(&[3, 4]),
+                fused: false,
+            },
+            // 3D cos and sin are broadcast over heads in the original graph,
+            // but would be broadcast over batch by the fused operator.
+            Case {
+                x_shape: fixed(&[1, 2, 3, 4]),
+                cos_shape: fixed(&[2, 3, 4]),
+                fused: false,
+            },
+            // cos and sin have fewer elements than the rotated dims.
+            Case {
+                x_shape: fixed(&[1, 2, 3, 4]),
+                cos_shape: fixed(&[3, 1]),
+                fused: false,
+            },
+            // Input sizes are not known to match cos and sin.
+            Case {
+                x_shape: vec![seq(), seq(), seq(), Dimension::Fixed(4)],
+                cos_shape: fixed(&[3, 4]),
+                fused: false,
+            },
+        ];
+
+        for Case {
+            x_shape,
+            cos_shape,
+            fused,
+        } in cases
+        {
+            let concrete_shape = |shape: &[Dimension]| -> Vec<usize> {
+                shape
+                    .iter()
+                    .map(|dim| match dim {
+                        Dimension::Fixed(size) => *size,
+                        Dimension::Symbolic(_) => 3,
+                    })
+                    .collect()
+            };
+            let x = Tensor::rand(&concrete_shape(&x_shape), &mut rng);
+            let cos = Tensor::rand(&concrete_shape(&cos_shape), &mut rng);
+            let sin = Tensor::rand(&concrete_shape(&cos_shape), &mut rng);
+
+            for with_steps in [false, true] {
+                let make_graph =
+                    || rotary_embedding_graph(with_steps, x_shape.clone(), cos_shape.clone());
+                let graph = optimize_graph(make_graph())?;
+                let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+                assert_eq!(op.operator().name() == "RotaryEmbedding", fused);
+
+                if fused {
+                    assert_eq!(op.name(), Some("rope_add"));
+                    let rope = op.operator().downcast_ref::<RotaryEmbedding>().unwrap();
+                    assert_eq!(rope.rotary_dim, Some(4));
+                    assert!(!rope.interleaved);
+                }
+
+                // Check the optimized graph produces the same result as the
+                // original.
+                let run_graph = |graph: &Graph| -> Result<Tensor, Box<dyn Error>> {
+                    let inputs = [&x, &cos, &sin]
+                        .into_iter()
+                        .zip(graph.input_ids())
+                        .map(|(tensor, &id)| (id, tensor.view().into()))
+                        .collect();
+                    let mut outputs = graph.run(inputs, graph.output_ids(), None)?;
+                    Ok(outputs.remove(0).try_into()?)
+                };
+                let expected = run_graph(&make_graph())?;
+                let actual = run_graph(&graph)?;
+                expect_equal_with_tolerance(&actual, &expected, 1e-4, 0.)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create a graph for `Softmax(Q @ Transpose(K) / sqrt(head_dim) + mask) @ V`.
+    fn attention_graph() -> Graph {
+        attention_graph_with_mask(None)
+    }
+
+    /// Create an attention graph where the mask is an input, or if
+    /// `causal_mask` is `Some((seq_q, seq_k, k))`, computed from constants
+    /// using `Where(Trilu(ones, k) == 0, -inf, 0)`, as in PyTorch exports.
+    fn attention_graph_with_mask(causal_mask: Option<(usize, usize, i32)>) -> Graph {
+        let mut graph = Graph::new();
+        let query = graph.add_value(Some("query"), None);
+        let key = graph.add_value(Some("key"), None);
+        let value = graph.add_value(Some("value"), None);
+        let mask = match causal_mask {
+            Some((seq_q, seq_k, k)) => {
+                let ones = graph.add_constant(None, Tensor::<f32>::full(&[seq_q, seq_k], 1.));
+                let k = graph.add_constant(None, Tensor::from(k));
+                let (_, tril) = graph.add_simple_op("trilu", Trilu { upper: false }, &[ones, k]);
+                let zero = graph.add_constant(None, Tensor::from(0.));
+                let (_, hidden) = graph.add_simple_op("equal", Equal {}, &[tril, zero]);
+                let neg_inf = graph.add_constant(None, Tensor::from(f32::NEG_INFINITY));
+                let (_, mask) = graph.add_simple_op("where", Where {}, &[hidden, neg_inf, zero]);
+                mask
+            }
+            None => graph.add_value(Some("mask"), None),
+        };
+
+        let (_, key_t) = graph.add_simple_op(
+            "transpose",
+            Transpose {
+                perm: Some(vec![0, 1, 3, 2]),
+            },
+            &[key],
+        );
+        let (_, qk) = graph.add_simple_op("qk", MatMul {}, &[query, key_t]);
+        let scale = graph.add_constant(None, Tensor::from(2.));
+        let (_, scaled) = graph.add_simple_op("div", Div {}, &[qk, scale]);
+        let (_, masked) = graph.add_simple_op("add_mask", Add {}, &[scaled, mask]);
+        let (_, probs) = graph.add_simple_op(
+            "softmax",
+            Softmax {
+                axis: -1,
+                flatten: false,
+            },
+            &[masked],
+        );
+        let (_, out) = graph.add_simple_op("attention_out", MatMul {}, &[probs, value]);
+
+        if causal_mask.is_some() {
+            graph.set_input_ids(&[query, key, value]);
+        } else {
+            graph.set_input_ids(&[query, key, value, mask]);
+        }
+        graph.set_output_ids(&[out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fuse_attention() -> Result<(), Box<dyn Error>> {
+        let graph = optimize_graph(attention_graph())?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.operator().name(), "ScaledDotProductAttention");
+        assert_eq!(op.name(), Some("attention_out"));
+
+        let attention = op
+            .operator()
+            .downcast_ref::<ScaledDotProductAttention>()
+            .unwrap();
+        assert_eq!(attention.scale, Some(0.5));
+        assert!(!attention.key_transposed);
+        assert!(!attention.causal);
+
+        // Check the fused graph produces the same result as the original.
+        let mut rng = XorShiftRng::new(1234);
+        let query = Tensor::rand(&[1, 2, 3, 4], &mut rng);
+        let key = Tensor::rand(&[1, 2, 5, 4], &mut rng);
+        let value = Tensor::rand(&[1, 2, 5, 4], &mut rng);
+        let mask = Tensor::rand(&[1, 1, 3, 5], &mut rng);
+
+        let run_graph = |graph: &Graph| -> Result<Tensor, Box<dyn Error>> {
+            let input_ids = graph.input_ids();
+            let inputs = [&query, &key, &value, &mask]
+                .into_iter()
+                .zip(input_ids)
+                .map(|(tensor, &id)| (id, tensor.view().into()))
+                .collect();
+            let mut outputs = graph.run(inputs, graph.output_ids(), None)?;
+            Ok(outputs.remove(0).try_into()?)
+        };
+        let expected = run_graph(&attention_graph())?;
+        let actual = run_graph(&graph)?;
+        expect_equal_with_tolerance(&actual, &expected, 1e-4, 0.)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_attention_causal_mask() -> Result<(), Box<dyn Error>> {
+        let (seq_q, seq_k) = (3, 5);
+        let mut rng = XorShiftRng::new(1234);
+        let query = Tensor::rand(&[1, 2, seq_q, 4], &mut rng);
+        let key = Tensor::rand(&[1, 2, seq_k, 4], &mut rng);
+        let value = Tensor::rand(&[1, 2, seq_k, 4], &mut rng);
+
+        let run_graph = |graph: &Graph| -> Result<Tensor, Box<dyn Error>> {
+            let input_ids = graph.input_ids();
+            let inputs = [&query, &key, &value]
+                .into_iter()
+                .zip(input_ids)
+                .map(|(tensor, &id)| (id, tensor.view().into()))
+                .collect();
+            let mut outputs = graph.run(inputs, graph.output_ids(), None)?;
+            Ok(outputs.remove(0).try_into()?)
+        };
+
+        // The diagonal offset which matches causal attention is
+        // `seq_k - seq_q`. Masks with other offsets are kept as inputs.
+        for (k, expect_causal) in [(2, true), (1, false), (3, false)] {
+            let original = attention_graph_with_mask(Some((seq_q, seq_k, k)));
+            let graph = optimize_graph(attention_graph_with_mask(Some((seq_q, seq_k, k))))?;
+            let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+            let attention = op
+                .operator()
+                .downcast_ref::<ScaledDotProductAttention>()
+                .unwrap();
+            assert_eq!(attention.causal, expect_causal);
+            assert_eq!(
+                op.input_ids().get(3).copied().flatten().is_some(),
+                !expect_causal
+            );
+
+            let expected = run_graph(&original)?;
+            let actual = run_graph(&graph)?;
+            expect_equal_with_tolerance(&actual, &expected, 1e-4, 0.)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a graph which reshapes its input to `[batch, -1]`, where the
+    /// batch size is computed using `Shape -> Gather -> Unsqueeze -> Concat`.
+    fn reshape_graph(input_shape: Option<Vec<Dimension>>) -> Graph {
+        let mut graph = Graph::new();
+
+        let input = graph.add_value(Some("input"), input_shape);
+        let (_, shape_out) = graph.add_simple_op("shape", Shape {}, &[input]);
+        let index = graph.add_constant(None, Tensor::from(0));
+        let (_, gather_out) =
+            graph.add_simple_op("gather", Gather { axis: 0 }, &[shape_out, index]);
+        let axes = graph.add_constant(None, Tensor::from([0]));
+        let (_, unsqueeze_out) =
+            graph.add_simple_op("unsqueeze", Unsqueeze {}, &[gather_out, axes]);
+        let rest = graph.add_constant(None, Tensor::from([-1]));
+        let (_, concat_out) =
+            graph.add_simple_op("concat", Concat { axis: 0 }, &[unsqueeze_out, rest]);
+        let (_, size_out) = graph.add_simple_op("size", Size {}, &[input]);
+        let (_, reshape_out) = graph.add_simple_op(
+            "reshape",
+            Reshape { allow_zero: false },
+            &[input, concat_out],
+        );
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[reshape_out, size_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fold_shapes() -> Result<(), Box<dyn Error>> {
+        let optimizer = GraphOptimizer::new();
+        let fixed_shape = [2, 3, 4].map(Dimension::Fixed).to_vec();
+        let graph = optimizer.specialize(reshape_graph(Some(fixed_shape)))?;
+
+        // Check the shape computation was replaced with a constant.
+        let (_, reshape_op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(reshape_op.name(), Some("reshape"));
+        let shape_id = reshape_op.input_ids()[1].unwrap();
+        let Some(Node::Constant(Constant::Int(shape))) = graph.get_node(shape_id) else {
+            return Err("reshape shape is not a constant".into());
+        };
+        assert_eq!(shape.view(), Tensor::from([2, -1]));
+
+        let Some(Node::Constant(Constant::Int(size))) = graph.get_node(graph.output_ids()[1])
+        else {
+            return Err("size output is not a constant".into());
+        };
+        assert_eq!(size.view(), Tensor::from(24));
+
+        // Check the specialized graph produces the same result as the
+        // original.
+        let input = Tensor::<f32>::arange(0., 24., None).into_shape([2, 3, 4].as_slice());
+        let run_graph = |graph: &Graph| -> Result<Tensor, Box<dyn Error>> {
+            let inputs = vec![(graph.input_ids()[0], input.view().into())];
+            let mut outputs = graph.run(inputs, &graph.output_ids()[..1], None)?;
+            Ok(outputs.remove(0).try_into()?)
+        };
+        let expected = run_graph(&reshape_graph(None))?;
+        let actual = run_graph(&graph)?;
+        assert_eq!(actual.shape(), &[2, 12]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_shapes_symbolic_input() -> Result<(), Box<dyn Error>> {
+        // Shape computations which depend on symbolic dimensions are not
+        // folded.
+        let optimizer = GraphOptimizer::new();
+        let shape = vec![
+            Dimension::Symbolic("batch".to_string()),
+            Dimension::Fixed(3),
+            Dimension::Fixed(4),
+        ];
+        let graph = optimizer.specialize(reshape_graph(Some(shape)))?;
+
+        let (_, reshape_op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        let shape_id = reshape_op.input_ids()[1].unwrap();
+        assert!(matches!(graph.get_node(shape_id), Some(Node::Value(_))));
+
+        Ok(())
+    }
+
+    /// Run a graph with a single input and output.
+    fn run_single_output(graph: &Graph, input: &Tensor) -> Result<Tensor, Box<dyn Error>> {
+        let inputs = vec![(graph.input_ids()[0], input.view().into())];
+        let mut outputs = graph.run(inputs, graph.output_ids(), None)?;
+        Ok(outputs.remove(0).try_into()?)
+    }
+
+    fn conv_op(padding: Padding) -> Conv {
+        Conv {
+            groups: 1,
+            dilations: vec![1, 1],
+            padding,
+            strides: vec![1, 1],
+        }
+    }
+
+    /// Create a `Conv -> BatchNormalization -> Mul -> Relu` graph.
+    fn conv_batch_norm_graph() -> Graph {
+        let mut rng = XorShiftRng::new(1234);
+        let mut graph = Graph::new();
 
-        graph.apply_fusion(|graph, op_node_id, op_node| {
-            let gelu_match = gelu_pattern.test(op_node_id, graph.graph())?;
-            let gelu_input = gelu_match.resolved_symbol("x").expect("missing symbol");
-            let op_output = op_node.output_id()?;
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::rand(&[4, 3, 3, 3], &mut rng));
+        let bias = graph.add_constant(None, Tensor::<f32>::rand(&[4], &mut rng));
+        let (_, conv_out) =
+            graph.add_simple_op("conv", conv_op([1, 1, 1, 1].into()), &[input, weight, bias]);
 
-            Some(Fusion::from_op(
-                op_node.name(),
-                Gelu {},
-                vec![Some(gelu_input)],
-                op_output,
-            ))
-        });
+        let bn_scale = graph.add_constant(None, Tensor::from([0.5, 1., 1.5, 2.]));
+        let bn_bias = graph.add_constant(None, Tensor::from([0.1, 0.2, -0.3, 0.]));
+        let bn_mean = graph.add_constant(None, Tensor::from([0.2, 0.4, 0.6, 0.8]));
+        let bn_var = graph.add_constant(None, Tensor::from([1., 2., 0.5, 1.5]));
+        let (_, bn_out) = graph.add_simple_op(
+            "batch_norm",
+            BatchNormalization { epsilon: 1e-5 },
+            &[conv_out, bn_scale, bn_bias, bn_mean, bn_var],
+        );
+
+        let scale = Tensor::from([2., -1., 0.5, 3.]).into_shape([4, 1, 1].as_slice());
+        let scale = graph.add_constant(None, scale);
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[scale, bn_out]);
+        let (_, relu_out) = graph.add_simple_op("relu", Relu {}, &[mul_out]);
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[relu_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fold_conv_batch_norm() -> Result<(), Box<dyn Error>> {
+        let graph = optimize_graph(conv_batch_norm_graph())?;
+
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("conv"));
+        let fused_conv = op.operator().downcast_ref::<FusedConv>().unwrap();
+        assert_eq!(fused_conv.activation, Activation::Relu);
+        for input_id in &op.input_ids()[1..] {
+            let input = graph.get_node(input_id.unwrap());
+            assert!(matches!(input, Some(Node::Constant(_))));
+        }
+
+        let mut rng = XorShiftRng::new(5678);
+        let input = Tensor::rand(&[1, 3, 5, 5], &mut rng);
+        let expected = run_single_output(&conv_batch_norm_graph(), &input)?;
+        let actual = run_single_output(&graph, &input)?;
+        expect_equal_with_tolerance(&actual, &expected, 1e-4, 0.)?;
 
         Ok(())
     }
 
-    /// Identify and fuse common patterns for `LayerNormalization(X)`.
-    fn fuse_layer_norm(&self, graph: &mut GraphMutator) -> Result<(), OptimizeError> {
-        let x = symbol("x");
+    /// Create a `Gemm -> Mul -> Add` graph.
+    fn gemm_affine_graph() -> Graph {
+        let mut rng = XorShiftRng::new(1234);
+        let mut graph = Graph::new();
 
-        // LayerNormalization has three steps. Pattern matching only supports a
-        // single expression, so we use three patterns and match them in reverse
-        // order (ie. starting from the output of the final step).
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::rand(&[3, 4], &mut rng));
+        let bias = graph.add_constant(None, Tensor::<f32>::rand(&[3], &mut rng));
+        let (_, gemm_out) = graph.add_simple_op(
+            "gemm",
+            Gemm {
+                alpha: 0.5,
+                beta: 2.,
+                transpose_a: false,
+                transpose_b: true,
+            },
+            &[input, weight, bias],
+        );
+        let scale = graph.add_constant(None, Tensor::from([1., -2., 3.]));
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[gemm_out, scale]);
+        let shift = graph.add_constant(None, Tensor::from([[0.5, 0.25, -1.]]));
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[mul_out, shift]);
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[add_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fold_gemm_affine() -> Result<(), Box<dyn Error>> {
+        let graph = optimize_graph(gemm_affine_graph())?;
+
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("gemm"));
+        assert!(op.operator().downcast_ref::<Gemm>().is_some());
+
+        let mut rng = XorShiftRng::new(5678);
+        let input = Tensor::rand(&[2, 4], &mut rng);
+        let expected = run_single_output(&gemm_affine_graph(), &input)?;
+        let actual = run_single_output(&graph, &input)?;
+        expect_equal_with_tolerance(&actual, &expected, 1e-5, 0.)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_conv_affine_not_per_channel() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::zeros(&[2, 1, 1, 1]));
+        let (_, conv_out) =
+            graph.add_simple_op("conv", conv_op(Padding::zero::<2>()), &[input, weight]);
+
+        // A scale which varies along the last axis can't be folded.
+        let scale = graph.add_constant(None, Tensor::from([1., 2., 3.]));
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[conv_out, scale]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_out]);
+
+        let graph = optimize_graph(graph)?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("mul"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_conv_activation_shared_output() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::zeros(&[2, 1, 1, 1]));
+        let (_, conv_out) =
+            graph.add_simple_op("conv", conv_op(Padding::zero::<2>()), &[input, weight]);
+        let (_, sigmoid_out) = graph.add_simple_op("sigmoid", Sigmoid {}, &[conv_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[sigmoid_out, conv_out]);
+
+        // The activation should not be fused if the convolution output is
+        // used elsewhere.
+        let graph = optimize_graph(graph)?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("sigmoid"));
+
+        Ok(())
+    }
+
+    /// Create a graph with a residual block of convolutions, followed by
+    /// pooling, resizing and a pair of pointwise convolutions whose outputs
+    /// are concatenated.
+    fn conv_block_graph() -> Graph {
+        let mut rng = XorShiftRng::new(1234);
+        let mut graph = Graph::new();
+        let mut conv = |graph: &mut Graph, name: &str, input, shape: [usize; 4], groups| {
+            let weight = graph.add_constant(None, Tensor::<f32>::rand(&shape, &mut rng));
+            let bias = graph.add_constant(None, Tensor::<f32>::rand(&[shape[0]], &mut rng));
+            let pad = shape[2] / 2;
+            let (_, conv_out) = graph.add_simple_op(
+                name,
+                Conv {
+                    groups,
+                    ..conv_op([pad, pad, pad, pad].into())
+                },
+                &[input, weight, bias],
+            );
+            conv_out
+        };
+
+        let input = graph.add_value(Some("input"), None);
+        let conv_out = conv(&mut graph, "conv", input, [8, 3, 3, 3], 1);
+        let (_, relu_out) = graph.add_simple_op("relu", Relu {}, &[conv_out]);
+        let dw_out = conv(&mut graph, "depthwise", relu_out, [8, 1, 3, 3], 8);
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[dw_out, relu_out]);
+        let scale = graph.add_constant(
+            None,
+            Tensor::from([1., 2., 3., 4., 5., 6., 7., 8.]).into_shape([8, 1, 1].as_slice()),
+        );
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[add_out, scale]);
+        let (_, pool_out) = graph.add_simple_op(
+            "max_pool",
+            MaxPool {
+                kernel_size: [2, 2].into(),
+                padding: Padding::zero::<2>(),
+                strides: [2, 2].into(),
+                dilations: [1, 1].into(),
+                ceil_mode: false,
+                return_indices: false,
+            },
+            &[mul_out],
+        );
+        let scales = graph.add_constant(None, Tensor::from([1., 1., 2., 2.]));
+        let resize_out = graph.add_value(Some("resize_out"), None);
+        graph.add_op(
+            Some("resize"),
+            Box::new(Resize::default()),
+            &[Some(pool_out), None, Some(scales)],
+            &[Some(resize_out)],
+        );
+        let pw_a_out = conv(&mut graph, "pointwise_a", resize_out, [4, 8, 1, 1], 1);
+        let pw_b_out = conv(&mut graph, "pointwise_b", resize_out, [4, 8, 1, 1], 1);
+        let (_, concat_out) =
+            graph.add_simple_op("concat", Concat { axis: 1 }, &[pw_a_out, pw_b_out]);
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[concat_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_convert_to_nhwc() -> Result<(), Box<dyn Error>> {
+        let op_types = |graph: &Graph| {
+            let mut op_types: Vec<String> = graph
+                .iter()
+                .filter_map(|(_, node)| match node {
+                    Node::Operator(op_node) => Some(op_node.operator().name().to_string()),
+                    _ => None,
+                })
+                .collect();
+            op_types.sort();
+            op_types
+        };
+
+        let (graph, log) = GraphOptimizer::new().optimize_with_log(conv_block_graph(), false)?;
+
+        // The subgraph should run in NHWC layout, with transposes only for
+        // the graph's input and output.
+        assert_eq!(
+            op_types(&graph),
+            [
+                "Concat",
+                "ConvNhwc",
+                "ConvNhwc",
+                "ConvNhwc",
+                "ConvNhwc",
+                "FusedElementwise",
+                "MaxPoolNhwc",
+                "ResizeNhwc",
+                "Transpose",
+                "Transpose",
+            ]
+        );
+        assert!(log
+            .iter()
+            .any(|rewrite| rewrite.to_string() == "convert_to_nhwc: depthwise -> ConvNhwc"));
+
+        // The output should be unchanged, and still be found by name.
+        assert_eq!(graph.node_name(graph.output_ids()[0]), "concat_out");
+
+        let mut rng = XorShiftRng::new(5678);
+        let input = Tensor::rand(&[1, 3, 10, 12], &mut rng);
+        let expected = run_single_output(&conv_block_graph(), &input)?;
+        let actual = run_single_output(&graph, &input)?;
+        expect_equal_with_tolerance(&actual, &expected, 1e-4, 1e-5)?;
+
+        // When the pass is disabled, operators should run in NCHW layout.
+        let mut optimizer = GraphOptimizer::new();
+        optimizer.disable_pass("convert_to_nhwc");
+        let graph = optimizer.optimize(conv_block_graph())?;
+        assert!(!op_types(&graph).iter().any(|op| op.ends_with("Nhwc")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_to_nhwc_linked_convs() -> Result<(), Box<dyn Error>> {
+        // Create a graph of `Conv -> {op} -> Conv`, where `op` is either an
+        // activation or `None`.
+        let conv_pair_graph = |op: Option<&str>| {
+            let mut rng = XorShiftRng::new(1234);
+            let mut graph = Graph::new();
+            let input = graph.add_value(Some("input"), None);
+            let weight = graph.add_constant(None, Tensor::<f32>::rand(&[4, 3, 3, 3], &mut rng));
+            let (_, mut out) =
+                graph.add_simple_op("conv_a", conv_op([1, 1, 1, 1].into()), &[input, weight]);
+            out = match op {
+                Some("sigmoid") => graph.add_simple_op("sigmoid", Sigmoid {}, &[out]).1,
+                Some("softmax") => {
+                    let softmax = Softmax {
+                        axis: 1,
+                        flatten: false,
+                    };
+                    graph.add_simple_op("softmax", softmax, &[out]).1
+                }
+                Some(_) => panic!("unsupported op"),
+                None => out,
+            };
+            let weight = graph.add_constant(None, Tensor::<f32>::rand(&[2, 4, 1, 1], &mut rng));
+            let (_, conv_out) =
+                graph.add_simple_op("conv_b", conv_op(Padding::zero::<2>()), &[out, weight]);
+            graph.set_input_ids(&[input]);
+            graph.set_output_ids(&[conv_out]);
+            graph
+        };
+        let count_nhwc_convs = |graph: &Graph| {
+            graph
+                .iter()
+                .filter(|(_, node)| {
+                    matches!(node, Node::Operator(op) if op.operator().name() == "ConvNhwc")
+                })
+                .count()
+        };
+
+        // Convolutions connected directly, or via operators that can run in
+        // NHWC layout, are converted.
+        for op in [None, Some("sigmoid")] {
+            let graph = optimize_graph(conv_pair_graph(op))?;
+            assert_eq!(count_nhwc_convs(&graph), 2);
+
+            let mut rng = XorShiftRng::new(5678);
+            let input = Tensor::rand(&[1, 3, 6, 7], &mut rng);
+            let expected = run_single_output(&conv_pair_graph(op), &input)?;
+            let actual = run_single_output(&graph, &input)?;
+            expect_equal_with_tolerance(&actual, &expected, 1e-4, 1e-5)?;
+        }
+
+        // Convolutions separated by an operator which only runs in NCHW
+        // layout are not converted, as the transposes would not cancel out.
+        let graph = optimize_graph(conv_pair_graph(Some("softmax")))?;
+        assert_eq!(count_nhwc_convs(&graph), 0);
+
+        // A single convolution is not converted.
+        let graph = optimize_graph(conv_batch_norm_graph())?;
+        assert_eq!(count_nhwc_convs(&graph), 0);
+
+        Ok(())
+    }
+
+    /// Create a graph for `MatMul(input, W) [* or / scale] + bias`.
+    fn matmul_bias_graph(scale_op: Option<&str>) -> Graph {
+        let mut rng = XorShiftRng::new(1234);
+        let mut graph = Graph::new();
+
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::rand(&[4, 3], &mut rng));
+        let (_, mut out) = graph.add_simple_op("matmul", MatMul {}, &[input, weight]);
+
+        if let Some(scale_op) = scale_op {
+            let scale = graph.add_constant(None, Tensor::from(2.));
+            out = match scale_op {
+                "mul" => graph.add_simple_op("scale", Mul {}, &[scale, out]).1,
+                "div" => graph.add_simple_op("scale", Div {}, &[out, scale]).1,
+                _ => panic!("unsupported scale op"),
+            };
+        }
+
+        let bias = graph.add_constant(None, Tensor::from([0.5, -1., 2.]));
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[out, bias]);
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[add_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fuse_matmul_bias() -> Result<(), Box<dyn Error>> {
+        for (scale_op, expected_alpha) in [(None, 1.), (Some("mul"), 2.), (Some("div"), 0.5)] {
+            let graph = optimize_graph(matmul_bias_graph(scale_op))?;
+
+            let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+            assert_eq!(op.name(), Some("matmul"));
+            assert_eq!(op.input_ids().len(), 3);
+            let fused_op = op.operator().downcast_ref::<FusedMatMul>().unwrap();
+            assert_eq!(fused_op.alpha, expected_alpha);
+
+            let mut rng = XorShiftRng::new(5678);
+            let input = Tensor::rand(&[2, 5, 4], &mut rng);
+            let expected = run_single_output(&matmul_bias_graph(scale_op), &input)?;
+            let actual = run_single_output(&graph, &input)?;
+            expect_equal_with_tolerance(&actual, &expected, 1e-5, 0.)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a graph with a `MatMul` or `Gemm` operator followed by an
+    /// activation.
+    ///
+    /// If `bias` is set, the `MatMul` is followed by a bias addition before
+    /// the activation.
+    fn matmul_activation_graph(gemm: bool, bias: bool, activation: &str) -> Graph {
+        let mut rng = XorShiftRng::new(1234);
+        let mut graph = Graph::new();
+
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::rand(&[3, 4], &mut rng));
+        let mut out = if gemm {
+            let gemm_op = Gemm {
+                alpha: 0.5,
+                beta: 1.,
+                transpose_a: false,
+                transpose_b: true,
+            };
+            graph.add_simple_op("matmul", gemm_op, &[input, weight]).1
+        } else {
+            let weight_t = graph.add_constant(None, Tensor::<f32>::rand(&[4, 3], &mut rng));
+            graph
+                .add_simple_op("matmul", MatMul {}, &[input, weight_t])
+                .1
+        };
+
+        if bias {
+            let bias = graph.add_constant(None, Tensor::from([0.5, -1., 2.]));
+            out = graph.add_simple_op("add", Add {}, &[out, bias]).1;
+        }
+
+        let (_, act_out) = match activation {
+            "relu" => graph.add_simple_op("act", Relu {}, &[out]),
+            "sigmoid" => graph.add_simple_op("act", Sigmoid {}, &[out]),
+            "clip" => {
+                let min = graph.add_constant(None, Tensor::from(0.2));
+                let max = graph.add_constant(None, Tensor::from(0.8));
+                graph.add_simple_op("act", Clip {}, &[out, min, max])
+            }
+            _ => panic!("unsupported activation"),
+        };
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[act_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fuse_matmul_activation() -> Result<(), Box<dyn Error>> {
+        struct Case {
+            gemm: bool,
+            bias: bool,
+            activation: &'static str,
+            expected: Activation,
+        }
+
+        let cases = [
+            Case {
+                gemm: false,
+                bias: false,
+                activation: "relu",
+                expected: Activation::Relu,
+            },
+            Case {
+                gemm: false,
+                bias: true,
+                activation: "clip",
+                expected: Activation::Clip { min: 0.2, max: 0.8 },
+            },
+            Case {
+                gemm: true,
+                bias: false,
+                activation: "sigmoid",
+                expected: Activation::Sigmoid,
+            },
+        ];
+
+        for Case {
+            gemm,
+            bias,
+            activation,
+            expected,
+        } in cases
+        {
+            let graph = optimize_graph(matmul_activation_graph(gemm, bias, activation))?;
+
+            let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+            assert_eq!(op.name(), Some("matmul"));
+            if gemm {
+                let fused_op = op.operator().downcast_ref::<FusedGemm>().unwrap();
+                assert_eq!(fused_op.activation, expected);
+            } else {
+                let fused_op = op.operator().downcast_ref::<FusedMatMul>().unwrap();
+                assert_eq!(fused_op.activation, Some(expected));
+                assert_eq!(op.input_ids().len(), if bias { 3 } else { 2 });
+            }
+
+            let mut rng = XorShiftRng::new(5678);
+            let mut input = Tensor::rand(&[5, 4], &mut rng);
+            input.apply(|x| x - 0.5);
+            let expected =
+                run_single_output(&matmul_activation_graph(gemm, bias, activation), &input)?;
+            let actual = run_single_output(&graph, &input)?;
+            expect_equal_with_tolerance(&actual, &expected, 1e-5, 0.)?;
+        }
+
+        // MatMul output which is also used elsewhere is not fused.
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::zeros(&[4, 3]));
+        let (_, matmul_out) = graph.add_simple_op("matmul", MatMul {}, &[input, weight]);
+        let (_, relu_out) = graph.add_simple_op("relu", Relu {}, &[matmul_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[relu_out, matmul_out]);
+
+        let graph = optimize_graph(graph)?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("relu"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_matmul_not_fused() -> Result<(), Box<dyn Error>> {
+        // Multiplication by a non-scalar can't be fused.
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::zeros(&[4, 3]));
+        let (_, matmul_out) = graph.add_simple_op("matmul", MatMul {}, &[input, weight]);
+        let scale = graph.add_constant(None, Tensor::from([1., 2., 3.]));
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[matmul_out, scale]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_out]);
+
+        let graph = optimize_graph(graph)?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("mul"));
+
+        // MatMul output which is also used elsewhere is not fused.
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let weight = graph.add_constant(None, Tensor::<f32>::zeros(&[4, 3]));
+        let (_, matmul_out) = graph.add_simple_op("matmul", MatMul {}, &[input, weight]);
+        let bias = graph.add_constant(None, Tensor::from([1., 2., 3.]));
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[matmul_out, bias]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[add_out, matmul_out]);
+
+        let graph = optimize_graph(graph)?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("add"));
+
+        // Bias which broadcasts against the rows, rather than the columns, of
+        // a MatMul output with one column is not fused.
+        let build_graph = || {
+            let mut rng = XorShiftRng::new(1234);
+            let mut graph = Graph::new();
+            let input = graph.add_value(Some("input"), None);
+            let weight = graph.add_constant(None, Tensor::<f32>::rand(&[4, 1], &mut rng));
+            let (_, matmul_out) = graph.add_simple_op("matmul", MatMul {}, &[input, weight]);
+            let bias = graph.add_constant(None, Tensor::from([1., 2., 3.]));
+            let (_, add_out) = graph.add_simple_op("add", Add {}, &[matmul_out, bias]);
+            graph.set_input_ids(&[input]);
+            graph.set_output_ids(&[add_out]);
+            graph
+        };
+
+        let graph = optimize_graph(build_graph())?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("add"));
+
+        let mut rng = XorShiftRng::new(5678);
+        let input = Tensor::rand(&[3, 4], &mut rng);
+        let expected = run_single_output(&build_graph(), &input)?;
+        let actual = run_single_output(&graph, &input)?;
+        assert_eq!(actual.shape(), &[3, 3]);
+        expect_equal_with_tolerance(&actual, &expected, 1e-5, 0.)?;
+
+        Ok(())
+    }
+
+    /// Create a graph for the decomposed form of `HardSwish(x)`, ie.
+    /// `x * Clip(x + 3, 0, 6) / 6`.
+    fn hard_swish_graph() -> Graph {
+        let mut graph = Graph::new();
+
+        let input = graph.add_value(Some("input"), None);
+        let three = graph.add_constant(None, Tensor::from(3.));
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[input, three]);
+        let zero = graph.add_constant(None, Tensor::from(0.));
+        let six = graph.add_constant(None, Tensor::from(6.));
+        let (_, clip_out) = graph.add_simple_op("clip", Clip {}, &[add_out, zero, six]);
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, clip_out]);
+        let (_, div_out) = graph.add_simple_op("div", Div {}, &[mul_out, six]);
+
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[div_out]);
+
+        graph
+    }
+
+    #[test]
+    fn test_fuse_elementwise() -> Result<(), Box<dyn Error>> {
+        let graph = optimize_graph(hard_swish_graph())?;
+
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("div"));
+        assert_eq!(op.operator().name(), "FusedElementwise");
+        assert_eq!(op.input_ids()[0], Some(graph.input_ids()[0]));
+
+        let mut rng = XorShiftRng::new(1234);
+        let mut input = Tensor::rand(&[2, 3, 50], &mut rng);
+        input.apply(|x| (x - 0.5) * 10.);
+        let expected = run_single_output(&hard_swish_graph(), &input)?;
+        let actual = run_single_output(&graph, &input)?;
+        expect_equal_with_tolerance(&actual, &expected, 1e-6, 0.)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_elementwise_shared_output() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let one = graph.add_constant(None, Tensor::from(1.));
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[input, one]);
+        let (_, sigmoid_out) = graph.add_simple_op("sigmoid", Sigmoid {}, &[add_out]);
+        let (_, relu_out) = graph.add_simple_op("relu", Relu {}, &[sigmoid_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[relu_out, add_out]);
+
+        // `add` is a graph output, so only `sigmoid` and `relu` are fused.
+        let graph = optimize_graph(graph)?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("relu"));
+        assert_eq!(op.operator().name(), "FusedElementwise");
+        assert_eq!(op.input_ids(), [Some(graph.output_ids()[1])]);
+
+        let (_, op) = graph.get_source_node(graph.output_ids()[1]).unwrap();
+        assert_eq!(op.name(), Some("add"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_elementwise_clip_bound_from_op() -> Result<(), Box<dyn Error>> {
+        let build_graph = || {
+            let mut graph = Graph::new();
+            let input = graph.add_value(Some("input"), None);
+            let neg_min = graph.add_value(Some("neg_min"), None);
+            let (_, abs_out) = graph.add_simple_op("abs", Abs {}, &[input]);
+            let (_, neg_out) = graph.add_simple_op("neg", Neg {}, &[neg_min]);
+            let (_, clip_out) = graph.add_simple_op("clip", Clip {}, &[abs_out, neg_out]);
+            graph.set_input_ids(&[input, neg_min]);
+            graph.set_output_ids(&[clip_out]);
+            graph
+        };
+
+        // The minimum is computed by a fusable operator, but must be passed to
+        // the fused operator as an input rather than computed by a step.
+        let graph = optimize_graph(build_graph())?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.name(), Some("clip"));
+        assert_eq!(op.operator().name(), "FusedElementwise");
+        let neg_out = graph.get_node_id("neg_out");
+        assert!(neg_out.is_some());
+        assert_eq!(op.input_ids(), [Some(graph.input_ids()[0]), neg_out]);
+
+        let input = Tensor::from([-3., -1., 0.5, 2.]);
+        let neg_min = Tensor::from(-1.5);
+        let run = |graph: &Graph| -> Result<Tensor, Box<dyn Error>> {
+            let inputs = vec![
+                (graph.input_ids()[0], input.view().into()),
+                (graph.input_ids()[1], neg_min.view().into()),
+            ];
+            let mut outputs = graph.run(inputs, graph.output_ids(), None)?;
+            Ok(outputs.remove(0).try_into()?)
+        };
+        let expected = run(&build_graph())?;
+        let actual = run(&graph)?;
+        assert_eq!(actual, expected);
+        assert_eq!(actual, Tensor::from([3., 1.5, 1.5, 2.]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_eliminate_dead_nodes() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let unused_const = graph.add_constant(Some("unused_const"), Tensor::from(1.));
+        let (_, unused_out) = graph.add_simple_op("unused", Add {}, &[input, unused_const]);
+        graph.add_simple_op("unused_2", Relu {}, &[unused_out]);
+        let (_, sigmoid_out) = graph.add_simple_op("sigmoid", Sigmoid {}, &[input]);
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, sigmoid_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_out]);
+
+        // Nodes which don't contribute to the output, and operators replaced
+        // by fusions, should be removed.
+        let graph = optimize_graph(graph)?;
+        for name in [
+            "unused_const",
+            "unused",
+            "unused_out",
+            "unused_2",
+            "sigmoid",
+        ] {
+            assert!(graph.get_node_id(name).is_none(), "{name} not removed");
+        }
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.operator().name(), "Silu");
+        assert_eq!(op.input_ids(), [graph.get_node_id("input")]);
+        assert_eq!(graph.input_ids(), [graph.get_node_id("input").unwrap()]);
+
+        Ok(())
+    }
 
-        // First step: Center values
-        let center_pat = x.clone() - unary_op_key("ReduceMean", x.clone(), "center_mean");
+    #[test]
+    fn test_eliminate_common_subexpressions() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
 
-        // Middle step: Normalize variance
-        let epsilon = const_symbol("epsilon");
-        let normalize_variance_pat = x.clone()
-            / unary_op(
-                "Sqrt",
-                epsilon + unary_op_key("ReduceMean", binary_op("Pow", x.clone(), 2.0), "norm_mean"),
+        // Two copies of the same computation, using different but equal
+        // constants.
+        let mut gather_outs = Vec::new();
+        for i in 0..2 {
+            let (_, shape_out) = graph.add_simple_op(&format!("shape_{i}"), Shape {}, &[input]);
+            let index = graph.add_constant(None, Tensor::from(0));
+            let (_, gather_out) = graph.add_simple_op(
+                &format!("gather_{i}"),
+                Gather { axis: 0 },
+                &[shape_out, index],
             );
+            gather_outs.push(gather_out);
+        }
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &gather_outs);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[add_out]);
 
-        // Final step: Shift and scale the normalized values
-        let bias = const_symbol("bias");
-        let scale = const_symbol("scale");
-        let shift_scale_pat = (x.clone() * scale) + bias;
+        let graph = optimize_graph(graph)?;
 
-        graph.apply_fusion(|graph, op_node_id, op_node| {
-            // Test if a node is a `ReduceMean` operator that reduces over its
-            // last axis.
-            let mean_op_reduces_last_axis = |node_id| match graph.graph().get_node(node_id) {
-                Some(Node::Operator(op_node)) => {
-                    let Some(mean_op) = op_node.operator().downcast_ref::<ReduceMean>() else {
-                        return false;
-                    };
+        let (_, add_op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        let [Some(lhs), Some(rhs)] = add_op.input_ids() else {
+            return Err("unexpected add inputs".into());
+        };
+        assert_eq!(lhs, rhs);
+        let (_, gather_op) = graph.get_source_node(*lhs).unwrap();
+        assert_eq!(gather_op.name(), Some("gather_0"));
+        assert!(graph.get_node_id("shape_1").is_none());
+        assert!(graph.get_node_id("gather_1").is_none());
 
-                    // The last axis can be specified with either a positive or
-                    // negative value. We only support the negative case as that
-                    // is easier to handle and used in popular models.
-                    if mean_op.axes.as_deref() == Some(&[-1]) {
-                        true
-                    } else if let Some(axes_input) = op_node.input_ids().get(1).copied().flatten() {
-                        match graph.graph().get_node(axes_input) {
-                            Some(Node::Constant(val)) => val.as_vector() == Some(&[-1]),
-                            _ => false,
-                        }
-                    } else {
-                        false
-                    }
-                }
-                _ => false,
-            };
+        Ok(())
+    }
 
-            let shift_scale_match = shift_scale_pat.test(op_node_id, graph.graph())?;
-            let shift_scale_input = shift_scale_match.resolved_symbol("x").unwrap();
-            let bias_input = shift_scale_match.resolved_symbol("bias").unwrap();
-            let scale_input = shift_scale_match.resolved_symbol("scale").unwrap();
+    #[test]
+    fn test_eliminate_common_subexpressions_not_equivalent() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let index = graph.add_constant(None, Tensor::from(0));
 
-            let norm_match = normalize_variance_pat.test(shift_scale_input, graph.graph())?;
-            let norm_input = norm_match.resolved_symbol("x").unwrap();
-            let epsilon_input = norm_match.resolved_symbol("epsilon").unwrap();
-            let norm_mean = norm_match.resolved_symbol("norm_mean").unwrap();
-            if !mean_op_reduces_last_axis(norm_mean) {
-                // The LayerNormalization operator supports taking the mean over
-                // multiple trailing axes. However this fusion only supports the
-                // common case of taking the mean over one axis.
-                return None;
-            }
+        // Operators of the same type and inputs, but different attributes.
+        let (_, gather_0) = graph.add_simple_op("gather_0", Gather { axis: 0 }, &[input, index]);
+        let (_, gather_1) = graph.add_simple_op("gather_1", Gather { axis: 1 }, &[input, index]);
+        let (_, gather_add) = graph.add_simple_op("gather_add", Add {}, &[gather_0, gather_1]);
 
-            let center_match = center_pat.test(norm_input, graph.graph())?;
-            let center_input = center_match.resolved_symbol("x").unwrap();
-            let center_mean = center_match.resolved_symbol("center_mean").unwrap();
-            if !mean_op_reduces_last_axis(center_mean) {
-                return None;
-            }
+        // Operators which don't implement `Operator::is_equivalent`.
+        let (_, softsign_0) = graph.add_simple_op("softsign_0", Softsign {}, &[input]);
+        let (_, softsign_1) = graph.add_simple_op("softsign_1", Softsign {}, &[input]);
+        let (_, softsign_add) =
+            graph.add_simple_op("softsign_add", Add {}, &[softsign_0, softsign_1]);
 
-            let op_output = op_node.output_id()?;
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[gather_add, softsign_add]);
 
-            let epsilon = match graph.graph().get_node(epsilon_input) {
-                Some(Node::Constant(val)) => val.as_scalar(),
-                _ => None,
-            }?;
+        let graph = optimize_graph(graph)?;
 
-            Some(Fusion::from_op(
-                op_node.name(),
-                LayerNormalization {
-                    axis: -1,
-                    epsilon: Some(epsilon),
-                },
-                vec![Some(center_input), Some(scale_input), Some(bias_input)],
-                op_output,
-            ))
-        });
+        for name in ["gather_0", "gather_1", "softsign_0", "softsign_1"] {
+            assert!(graph.get_node_id(name).is_some(), "{name} was removed");
+        }
 
         Ok(())
     }
-}
 
-impl Default for GraphOptimizer {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn test_eliminate_noop_ops() -> Result<(), Box<dyn Error>> {
+        let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), Some([2, 3].map(Dimension::Fixed).to_vec()));
+        let (_, identity_out) = graph.add_simple_op("identity", Identity {}, &[input]);
+        let shape = graph.add_constant(None, Tensor::from([0, -1]));
+        let (_, reshape_out) = graph.add_simple_op(
+            "reshape",
+            Reshape { allow_zero: false },
+            &[identity_out, shape],
+        );
+        let (_, transpose_out) = graph.add_simple_op(
+            "transpose",
+            Transpose {
+                perm: Some(vec![0, 1]),
+            },
+            &[reshape_out],
+        );
+        let (_, relu_out) = graph.add_simple_op("relu", Relu {}, &[transpose_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[relu_out]);
+
+        let graph = optimize_graph(graph)?;
+
+        let (_, relu_op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(relu_op.input_ids(), [graph.get_node_id("input")]);
+        for name in ["identity", "reshape", "transpose"] {
+            assert!(graph.get_node_id(name).is_none(), "{name} not removed");
+        }
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::error::Error;
+    #[test]
+    fn test_reshape_is_noop() {
+        let shape = [2, 3, 4].map(Dimension::Fixed);
+        let symbolic_shape = [
+            Dimension::Symbolic("batch".to_string()),
+            Dimension::Fixed(3),
+        ];
 
-    use rten_tensor::Tensor;
+        #[derive(Debug)]
+        struct Case<'a> {
+            shape: &'a [Dimension],
+            target: &'a [i32],
+            allow_zero: bool,
+            expected: bool,
+        }
 
-    use super::{GraphOptimizer, OptimizeError};
-    use crate::downcast::DowncastDyn;
-    use crate::graph::{Constant, Graph, Node};
-    use crate::ops::{
-        Add, Div, Erf, LayerNormalization, MatMul, Mul, Pow, ReduceMean, Sigmoid, Sqrt, Sub,
-        Transpose,
-    };
+        let cases = [
+            Case {
+                shape: &shape,
+                target: &[2, 3, 4],
+                allow_zero: false,
+                expected: true,
+            },
+            Case {
+                shape: &shape,
+                target: &[0, -1, 4],
+                allow_zero: false,
+                expected: true,
+            },
+            Case {
+                shape: &shape,
+                target: &[0, 3, 4],
+                allow_zero: true,
+                expected: false,
+            },
+            Case {
+                shape: &shape,
+                target: &[-1, -1, 4],
+                allow_zero: false,
+                expected: false,
+            },
+            Case {
+                shape: &shape,
+                target: &[6, 4],
+                allow_zero: false,
+                expected: false,
+            },
+            Case {
+                shape: &symbolic_shape,
+                target: &[-1, 3],
+                allow_zero: false,
+                expected: true,
+            },
+            Case {
+                shape: &symbolic_shape,
+                target: &[2, 3],
+                allow_zero: false,
+                expected: false,
+            },
+        ];
 
-    fn optimize_graph(graph: Graph) -> Result<Graph, OptimizeError> {
-        let optimizer = GraphOptimizer::new();
-        optimizer.optimize(graph)
+        for case in cases {
+            assert_eq!(
+                reshape_is_noop(case.shape, case.target, case.allow_zero),
+                case.expected,
+                "{:?}",
+                case
+            );
+        }
     }
 
     #[test]
-    fn test_constant_propagation() -> Result<(), Box<dyn Error>> {
+    fn test_cancel_transposes() -> Result<(), Box<dyn Error>> {
         let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
 
-        // Add an operator with constant inputs.
-        let const_a = graph.add_constant(Some("const_a"), Tensor::from([1, 2, 3]));
-        let const_b = graph.add_constant(Some("const_b"), Tensor::from([4, 5, 6]));
-        let (_, add_out) = graph.add_simple_op("add_1", Add {}, &[const_a, const_b]);
+        // Transposes which cancel out.
+        let swap = || Transpose {
+            perm: Some(vec![1, 0, 2]),
+        };
+        let (_, swap_out) = graph.add_simple_op("swap", swap(), &[input]);
+        let (_, unswap_out) = graph.add_simple_op("unswap", swap(), &[swap_out]);
+        let (_, relu_out) = graph.add_simple_op("relu", Relu {}, &[unswap_out]);
+
+        // Transposes which can be combined.
+        let rotate = || Transpose {
+            perm: Some(vec![1, 2, 0]),
+        };
+        let (_, rotate_out) = graph.add_simple_op("rotate", rotate(), &[input]);
+        let (_, rotate_2_out) = graph.add_simple_op("rotate_2", rotate(), &[rotate_out]);
 
-        // Add an operator with a dynamic input and the output of the previous operator.
-        let input = graph.add_value(Some("input"), None);
-        let (add_op_2, add_2_out) = graph.add_simple_op("add_2", Add {}, &[add_out, input]);
         graph.set_input_ids(&[input]);
-        graph.set_output_ids(&[add_out, add_2_out]);
+        graph.set_output_ids(&[relu_out, rotate_2_out]);
 
-        // Optimize the graph. This should replace the first operator's output
-        // with a constant value.
-        let optimizer = GraphOptimizer::new();
-        let optimized_graph = optimizer.optimize(graph)?;
+        let input_val = Tensor::<f32>::arange(0., 24., None).into_shape([2, 3, 4].as_slice());
+        let run_graph = |graph: &Graph| -> Result<Vec<Tensor>, Box<dyn Error>> {
+            let inputs = vec![(graph.input_ids()[0], input_val.view().into())];
+            let outputs = graph.run(inputs, graph.output_ids(), None)?;
+            Ok(outputs
+                .into_iter()
+                .map(|output| output.try_into().unwrap())
+                .collect())
+        };
+        let expected = run_graph(&graph)?;
 
-        // Check that we got the expected inputs and outputs. The optimizer
-        // does not promise to preserve IDs for unmodified parts of the graph,
-        // but the current implementation does.
-        assert_eq!(optimized_graph.input_ids(), &[input]);
-        assert_ne!(optimized_graph.output_ids()[0], add_out);
-        assert_eq!(optimized_graph.output_ids()[1], add_2_out);
+        let graph = optimize_graph(graph)?;
 
-        // Check first output was replaced with constant.
-        let replaced_node = optimized_graph
-            .get_node(optimized_graph.output_ids()[0])
-            .and_then(|n| match &n {
-                Node::Constant(c) => Some(c),
-                _ => None,
-            })
-            .unwrap();
-        let Constant::Int(const_int) = replaced_node else {
-            return Err("constant not an int".into());
-        };
-        assert_eq!(const_int.view(), Tensor::from([5, 7, 9]));
+        let (_, relu_op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(relu_op.input_ids(), [graph.get_node_id("input")]);
 
-        // Check input to second operator was replaced with constant.
-        let op = optimized_graph
-            .get_node(add_op_2)
-            .and_then(|n| match &n {
-                Node::Operator(op) => Some(op),
-                _ => None,
-            })
-            .unwrap();
-        let input_ids: Vec<_> = op.input_ids().iter().map(|id| id.unwrap()).collect();
-        assert_eq!(input_ids.len(), 2);
-        assert_ne!(input_ids[0], add_out);
-        assert_eq!(input_ids[0], optimized_graph.output_ids()[0]);
-        assert_eq!(input_ids[1], input);
+        let (_, rotate_op) = graph.get_source_node(graph.output_ids()[1]).unwrap();
+        assert_eq!(rotate_op.name(), Some("rotate_2"));
+        assert_eq!(rotate_op.input_ids(), [graph.get_node_id("input")]);
+        let transpose = rotate_op.operator().downcast_ref::<Transpose>().unwrap();
+        assert_eq!(transpose.perm.as_deref(), Some([2, 0, 1].as_slice()));
+
+        assert_eq!(run_graph(&graph)?, expected);
 
         Ok(())
     }
 
     #[test]
-    fn test_fuse_transpose() {
+    fn test_graph_mutator_accessors() {
         let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let two = graph.add_constant(Some("two"), Tensor::from(2.));
+        let (mul_id, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, two]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_out]);
 
-        let input_1 = graph.add_value(None, None);
-        let input_2 = graph.add_value(None, None);
+        let graph = GraphMutator::from_graph(graph);
 
-        let (_, transpose_out) =
-            graph.add_simple_op("transpose", Transpose { perm: None }, &[input_1]);
-        let (_, matmul_out) = graph.add_simple_op("matmul", MatMul {}, &[transpose_out, input_2]);
-        graph.set_input_ids(&[input_1, input_2]);
-        graph.set_output_ids(&[matmul_out]);
+        let mul_op = graph.get_operator(mul_id).unwrap();
+        assert_eq!(mul_op.operator().name(), "Mul");
+        assert!(graph.get_operator(two).is_none());
 
-        let graph = optimize_graph(graph).unwrap();
+        let (source_id, _) = graph.get_source_operator(mul_out).unwrap();
+        assert_eq!(source_id, mul_id);
+        assert!(graph.get_source_operator(input).is_none());
 
-        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
-        assert_eq!(op.operator().name(), "FusedTranspose(MatMul)");
-        assert_eq!(op.name(), Some("matmul"));
-    }
+        let value: TensorView = graph.get_constant(two).unwrap().try_into().unwrap();
+        assert_eq!(value.item(), Some(&2.));
+        assert!(graph.get_constant(input).is_none());
 
-    #[test]
-    fn test_fuse_silu() {
-        let mut graph = Graph::new();
+        assert_eq!(graph.node_name(mul_id), Some("mul"));
+        assert_eq!(graph.node_name(two), Some("two"));
+    }
 
-        let input = graph.add_value(None, None);
-        let (_, sigmoid_out) = graph.add_simple_op("sigmoid", Sigmoid {}, &[input]);
-        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, sigmoid_out]);
-        graph.set_input_ids(&[input]);
-        graph.set_output_ids(&[mul_out]);
+    /// Operator used to test custom fusions.
+    #[derive(Debug)]
+    struct Softsign {}
 
-        let graph = optimize_graph(graph).unwrap();
+    impl Operator for Softsign {
+        fn name(&self) -> &str {
+            "Softsign"
+        }
 
-        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
-        assert_eq!(op.operator().name(), "Silu");
-        assert_eq!(op.name(), Some("mul"));
+        fn run(&self, _pool: &TensorPool, inputs: InputList) -> Result<OutputList, OpError> {
+            let x: TensorView = inputs.require_as(0)?;
+            x.map(|x| x / (1. + x.abs())).into_op_result()
+        }
     }
 
     #[test]
-    fn test_fuse_gelu() {
+    fn test_custom_pass() -> Result<(), Box<dyn Error>> {
         let mut graph = Graph::new();
+        let input = graph.add_value(Some("input"), None);
+        let (_, abs_out) = graph.add_simple_op("abs", Abs {}, &[input]);
+        let one = graph.add_constant(None, Tensor::from(1.));
+        let (_, add_out) = graph.add_simple_op("add", Add {}, &[one, abs_out]);
+        let (_, div_out) = graph.add_simple_op("div", Div {}, &[input, add_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[div_out]);
 
-        let sqrt_2 = graph.add_constant(None, Tensor::from((2.0f32).sqrt()));
-        let one = graph.add_constant(None, Tensor::from(1.0));
-        let half = graph.add_constant(None, Tensor::from(0.5));
+        let input_val = Tensor::from([-2., -0.5, 0., 0.5, 2.]);
+        let run_graph = |graph: &Graph| -> Result<Tensor, Box<dyn Error>> {
+            let inputs = vec![(graph.input_ids()[0], input_val.view().into())];
+            let mut outputs = graph.run(inputs, graph.output_ids(), None)?;
+            Ok(outputs.remove(0).try_into()?)
+        };
+        let expected = run_graph(&graph)?;
 
-        let input = graph.add_value(None, None);
-        let (_, div_out) = graph.add_simple_op("div", Div {}, &[input, sqrt_2]);
-        let (_, erf_out) = graph.add_simple_op("erf", Erf {}, &[div_out]);
-        let (_, add_out) = graph.add_simple_op("add", Add {}, &[erf_out, one]);
-        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, add_out]);
-        let (_, mul_half_out) = graph.add_simple_op("mul_half", Mul {}, &[mul_out, half]);
-        graph.set_input_ids(&[input]);
-        graph.set_output_ids(&[mul_half_out]);
+        let x = symbol("x");
+        let softsign = PatternFusion::new(
+            "softsign",
+            x.clone() / (1.0 + unary_op("Abs", x)),
+            &["x"],
+            |_, _| Some(Box::new(Softsign {})),
+        );
+        let mut optimizer = GraphOptimizer::new();
+        optimizer.add_pass(Arc::new(softsign));
+        let graph = optimizer.optimize(graph)?;
 
-        let graph = optimize_graph(graph).unwrap();
         let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
-        assert_eq!(op.operator().name(), "Gelu");
-        assert_eq!(op.name(), Some("mul_half"));
+        assert_eq!(op.operator().name(), "Softsign");
+        assert_eq!(op.name(), Some("div"));
+        assert_eq!(op.input_ids(), [graph.get_node_id("input")]);
+        assert_eq!(run_graph(&graph)?, expected);
+
+        Ok(())
     }
 
-    fn layer_norm_graph() -> Graph {
+    fn silu_graph() -> Graph {
         let mut graph = Graph::new();
         let input = graph.add_value(None, None);
+        let (_, sigmoid_out) = graph.add_simple_op("sigmoid", Sigmoid {}, &[input]);
+        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[input, sigmoid_out]);
+        graph.set_input_ids(&[input]);
+        graph.set_output_ids(&[mul_out]);
+        graph
+    }
 
-        // Center values
-        let (_, mean_out) = graph.add_simple_op(
-            "mean",
-            ReduceMean {
-                axes: Some(vec![-1]),
-                keep_dims: false,
-            },
-            &[input],
-        );
-        let (_, sub_out) = graph.add_simple_op("sub", Sub {}, &[input, mean_out]);
+    #[test]
+    fn test_disable_pass() -> Result<(), Box<dyn Error>> {
+        let mut optimizer = GraphOptimizer::new();
+        optimizer
+            .disable_pass("fuse_silu")
+            .disable_pass("fuse_elementwise");
+        let graph = optimizer.optimize(silu_graph())?;
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        assert_eq!(op.operator().name(), "Mul");
 
-        // Normalize variance
-        let two = graph.add_constant(None, Tensor::from(2.));
-        let (_, pow_out) = graph.add_simple_op("pow", Pow {}, &[sub_out, two]);
-        let (_, var_mean_out) = graph.add_simple_op(
-            "var_mean",
-            ReduceMean {
-                axes: Some(vec![-1]),
-                keep_dims: false,
-            },
-            &[pow_out],
+        let mut optimizer = GraphOptimizer::new();
+        optimizer.disable_pass("fuse_slu");
+        let result = optimizer.optimize(silu_graph());
+        assert_eq!(
+            result.err(),
+            Some(OptimizeError::UnknownPass("fuse_slu".to_string()))
         );
-        let epsilon = graph.add_constant(None, Tensor::from(1e-6));
-        let (_, add_eps_out) = graph.add_simple_op("add_eps", Add {}, &[epsilon, var_mean_out]);
-        let (_, sqrt_out) = graph.add_simple_op("sqrt", Sqrt {}, &[add_eps_out]);
-        let (_, div_out) = graph.add_simple_op("div", Div {}, &[sub_out, sqrt_out]);
-
-        // Shift and scale
-        let bias = graph.add_constant(None, Tensor::from([1., 2., 3.]));
-        let scale = graph.add_constant(None, Tensor::from([3., 4., 5.]));
-        let (_, mul_out) = graph.add_simple_op("mul", Mul {}, &[div_out, scale]);
-        let (_, add_out) = graph.add_simple_op("final_add", Add {}, &[mul_out, bias]);
-
-        graph.set_input_ids(&[input]);
-        graph.set_output_ids(&[add_out]);
 
-        graph
+        Ok(())
     }
 
     #[test]
-    fn test_fuse_layer_norm() {
-        let graph = layer_norm_graph();
-        let graph = optimize_graph(graph).unwrap();
-        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
-        assert_eq!(op.operator().name(), "LayerNormalization");
-        assert_eq!(op.name(), Some("final_add"));
+    fn test_rewrite_log() -> Result<(), Box<dyn Error>> {
+        let mut graph = silu_graph();
+        let silu_out = graph.output_ids()[0];
+        let (_, identity_out) = graph.add_simple_op("identity", Identity {}, &[silu_out]);
+        let (_, neg_out) = graph.add_simple_op("neg", Neg {}, &[identity_out]);
+        graph.set_output_ids(&[neg_out]);
 
-        let layer_norm = op.operator().downcast_ref::<LayerNormalization>().unwrap();
-        assert_eq!(layer_norm.epsilon, Some(1e-6));
+        let (_, log) = GraphOptimizer::new().optimize_with_log(graph, false)?;
+
+        let log: Vec<String> = log.iter().map(|rewrite| rewrite.to_string()).collect();
+        assert_eq!(
+            log,
+            [
+                "eliminate_noop_ops: identity",
+                "fuse_silu: sigmoid, mul -> Silu",
+                "fuse_elementwise: mul, neg -> FusedElementwise",
+            ]
+        );
+
+        Ok(())
     }
 
     #[test]
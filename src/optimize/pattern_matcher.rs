@@ -1,7 +1,15 @@
+//! Symbolic patterns for finding subgraphs in a model graph.
+//!
+//! Patterns are written as expressions, where operators such as `+` and `*`
+//! match the corresponding ONNX operators (`Add`, `Mul`). For example
+//! `x.clone() * unary_op("Sigmoid", x)` matches the SiLU activation
+//! function, where `x = symbol("x")`.
+
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 use crate::graph::{Constant, Graph, Node, NodeId, OperatorNode};
 use crate::ops::Input;
+use crate::optimize::GraphMutator;
 
 /// Tracks an association between named symbols (variables) in a pattern and
 /// the node IDs they have been resolved to.
@@ -60,7 +68,10 @@ pub struct Match {
 }
 
 impl Match {
-    /// Return the value node ID that a symbol was resolved to.
+    /// Return the node ID that a symbol was resolved to.
+    ///
+    /// For operator patterns created with a key, such as [`unary_op_key`],
+    /// this is the ID of the operator node.
     pub fn resolved_symbol(&self, name: &str) -> Option<NodeId> {
         self.symbols.find(name)
     }
@@ -69,6 +80,7 @@ impl Match {
 /// Absolute tolerance for matching float constants against constant patterns.
 const CONST_TOLERANCE: f32 = 1e-4;
 
+/// Pattern which matches a scalar float constant. See [`constant`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConstantPattern {
     value: f32,
@@ -85,6 +97,7 @@ impl ConstantPattern {
     }
 }
 
+/// Pattern which matches an operator and its inputs. See [`operator`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct OpPattern {
     /// Name of the operator (eg. "MatMul")
@@ -137,6 +150,7 @@ impl OpPattern {
     }
 }
 
+/// Pattern which matches any value or constant. See [`symbol`].
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SymbolPattern {
     name: &'static str,
@@ -145,16 +159,16 @@ pub struct SymbolPattern {
     constant: bool,
 }
 
-/// Specifies a pattern for a subgraph within a [`Graph`].
+/// Specifies a pattern for a subgraph within a graph.
 ///
 /// Patterns consist of matchers for operators, constants and symbols
-/// (variables). These are matched against a node in in a [`Graph`]. The node
+/// (variables). These are matched against a node in in a graph. The node
 /// matches if it is the output of a subgraph that matches the pattern.
 ///
 /// Patterns are created using functions in this module such as [`constant`],
 /// [`symbol`], [`binary_op`] and [`unary_op`]. They are combined using either
 /// the `_op` functions or using mathematical expressions. For example
-/// [`constant(1.0) + symbol("x")`] describes a graph with an `Add` operator
+/// `constant(1.0) + symbol("x")` describes a graph with an `Add` operator
 /// that takes the float constant `1.0` and a free variable `x` as inputs.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Pattern {
@@ -174,9 +188,9 @@ impl Pattern {
     ///
     /// If the pattern matches, this returns a [`Match`] which allows looking
     /// up the node IDs that any symbols in the pattern were resolved to.
-    pub fn test(&self, node_id: NodeId, graph: &Graph) -> Option<Match> {
+    pub fn test(&self, node_id: NodeId, graph: &GraphMutator) -> Option<Match> {
         let mut symbols = SymbolMap::new();
-        if self.test_impl(node_id, graph, &mut symbols) {
+        if self.test_impl(node_id, graph.graph(), &mut symbols) {
             Some(Match { symbols })
         } else {
             None
@@ -277,6 +291,11 @@ impl Neg for Pattern {
 }
 
 /// Create a pattern that matches an operator.
+///
+/// `name` is the operator name returned by
+/// [`Operator::name`](crate::ops::Operator::name). The operator must have
+/// exactly as many inputs as `inputs`. If `key` is specified, the ID of the
+/// matched operator node can be looked up using [`Match::resolved_symbol`].
 pub fn operator<I: Into<Vec<Pattern>>>(
     name: &'static str,
     inputs: I,
@@ -344,8 +363,9 @@ mod tests {
     use rten_tensor::Tensor;
 
     use super::{const_symbol, symbol, unary_op, unary_op_key, Pattern};
-    use crate::graph::{Graph, Node, NodeId};
+    use crate::graph::{Graph, NodeId};
     use crate::ops::{Abs, Add, Div};
+    use crate::optimize::GraphMutator;
 
     /// Create a graph that implements the softsign function `x / 1 + |x|`.
     fn softsign_graph() -> (Graph, NodeId, NodeId) {
@@ -440,6 +460,7 @@ mod tests {
         ) in cases.into_iter().enumerate()
         {
             let (graph, input, output) = graph;
+            let graph = GraphMutator::from_graph(graph);
             let pat_match = pattern.test(output, &graph);
 
             assert_eq!(pat_match.is_some(), expect_match, "mismatch for case {}", i);
@@ -452,11 +473,12 @@ mod tests {
     #[test]
     fn test_operator_with_key() {
         let (graph, _input, output) = softsign_graph();
+        let graph = GraphMutator::from_graph(graph);
         let x = symbol("x");
         let pat = x.clone() / (1.0 + unary_op_key("Abs", x.clone(), "abs_op"));
         let pat_match = pat.test(output, &graph).unwrap();
         let abs_node_id = pat_match.resolved_symbol("abs_op").unwrap();
-        let abs_op = graph.get_node(abs_node_id).unwrap();
-        assert!(matches!(abs_op, Node::Operator(op) if op.operator().name() == "Abs"));
+        let abs_op = graph.get_operator(abs_node_id).unwrap();
+        assert_eq!(abs_op.operator().name(), "Abs");
     }
 }
@@ -18,13 +18,13 @@ pub const ENUM_MIN_OPERATOR_TYPE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_TYPE: u8 = 103;
+pub const ENUM_MAX_OPERATOR_TYPE: u8 = 143;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 104] = [
+pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 144] = [
     OperatorType::Add,
     OperatorType::ArgMin,
     OperatorType::ArgMax,
@@ -129,6 +129,46 @@ pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 104] = [
     OperatorType::GatherND,
     OperatorType::Gelu,
     OperatorType::Einsum,
+    OperatorType::DepthToSpace,
+    OperatorType::SpaceToDepth,
+    OperatorType::RoiAlign,
+    OperatorType::DFT,
+    OperatorType::STFT,
+    OperatorType::HannWindow,
+    OperatorType::HammingWindow,
+    OperatorType::BlackmanWindow,
+    OperatorType::MelWeightMatrix,
+    OperatorType::RMSNormalization,
+    OperatorType::RotaryEmbedding,
+    OperatorType::GlobalMaxPool,
+    OperatorType::LpPool,
+    OperatorType::GlobalLpPool,
+    OperatorType::MaxUnpool,
+    OperatorType::ReduceL1,
+    OperatorType::ReduceLogSum,
+    OperatorType::ReduceLogSumExp,
+    OperatorType::Celu,
+    OperatorType::Hardmax,
+    OperatorType::Mish,
+    OperatorType::PRelu,
+    OperatorType::Selu,
+    OperatorType::Shrink,
+    OperatorType::Softsign,
+    OperatorType::ThresholdedRelu,
+    OperatorType::BitShift,
+    OperatorType::BitwiseAnd,
+    OperatorType::BitwiseNot,
+    OperatorType::BitwiseOr,
+    OperatorType::BitwiseXor,
+    OperatorType::IsInf,
+    OperatorType::IsNaN,
+    OperatorType::CenterCropPad,
+    OperatorType::Compress,
+    OperatorType::ReverseSequence,
+    OperatorType::Unique,
+    OperatorType::RNN,
+    OperatorType::Bernoulli,
+    OperatorType::Multinomial,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -240,9 +280,49 @@ impl OperatorType {
     pub const GatherND: Self = Self(101);
     pub const Gelu: Self = Self(102);
     pub const Einsum: Self = Self(103);
+    pub const DepthToSpace: Self = Self(104);
+    pub const SpaceToDepth: Self = Self(105);
+    pub const RoiAlign: Self = Self(106);
+    pub const DFT: Self = Self(107);
+    pub const STFT: Self = Self(108);
+    pub const HannWindow: Self = Self(109);
+    pub const HammingWindow: Self = Self(110);
+    pub const BlackmanWindow: Self = Self(111);
+    pub const MelWeightMatrix: Self = Self(112);
+    pub const RMSNormalization: Self = Self(113);
+    pub const RotaryEmbedding: Self = Self(114);
+    pub const GlobalMaxPool: Self = Self(115);
+    pub const LpPool: Self = Self(116);
+    pub const GlobalLpPool: Self = Self(117);
+    pub const MaxUnpool: Self = Self(118);
+    pub const ReduceL1: Self = Self(119);
+    pub const ReduceLogSum: Self = Self(120);
+    pub const ReduceLogSumExp: Self = Self(121);
+    pub const Celu: Self = Self(122);
+    pub const Hardmax: Self = Self(123);
+    pub const Mish: Self = Self(124);
+    pub const PRelu: Self = Self(125);
+    pub const Selu: Self = Self(126);
+    pub const Shrink: Self = Self(127);
+    pub const Softsign: Self = Self(128);
+    pub const ThresholdedRelu: Self = Self(129);
+    pub const BitShift: Self = Self(130);
+    pub const BitwiseAnd: Self = Self(131);
+    pub const BitwiseNot: Self = Self(132);
+    pub const BitwiseOr: Self = Self(133);
+    pub const BitwiseXor: Self = Self(134);
+    pub const IsInf: Self = Self(135);
+    pub const IsNaN: Self = Self(136);
+    pub const CenterCropPad: Self = Self(137);
+    pub const Compress: Self = Self(138);
+    pub const ReverseSequence: Self = Self(139);
+    pub const Unique: Self = Self(140);
+    pub const RNN: Self = Self(141);
+    pub const Bernoulli: Self = Self(142);
+    pub const Multinomial: Self = Self(143);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 103;
+    pub const ENUM_MAX: u8 = 143;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::Add,
         Self::ArgMin,
@@ -348,6 +428,46 @@ impl OperatorType {
         Self::GatherND,
         Self::Gelu,
         Self::Einsum,
+        Self::DepthToSpace,
+        Self::SpaceToDepth,
+        Self::RoiAlign,
+        Self::DFT,
+        Self::STFT,
+        Self::HannWindow,
+        Self::HammingWindow,
+        Self::BlackmanWindow,
+        Self::MelWeightMatrix,
+        Self::RMSNormalization,
+        Self::RotaryEmbedding,
+        Self::GlobalMaxPool,
+        Self::LpPool,
+        Self::GlobalLpPool,
+        Self::MaxUnpool,
+        Self::ReduceL1,
+        Self::ReduceLogSum,
+        Self::ReduceLogSumExp,
+        Self::Celu,
+        Self::Hardmax,
+        Self::Mish,
+        Self::PRelu,
+        Self::Selu,
+        Self::Shrink,
+        Self::Softsign,
+        Self::ThresholdedRelu,
+        Self::BitShift,
+        Self::BitwiseAnd,
+        Self::BitwiseNot,
+        Self::BitwiseOr,
+        Self::BitwiseXor,
+        Self::IsInf,
+        Self::IsNaN,
+        Self::CenterCropPad,
+        Self::Compress,
+        Self::ReverseSequence,
+        Self::Unique,
+        Self::RNN,
+        Self::Bernoulli,
+        Self::Multinomial,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -456,6 +576,46 @@ impl OperatorType {
             Self::GatherND => Some("GatherND"),
             Self::Gelu => Some("Gelu"),
             Self::Einsum => Some("Einsum"),
+            Self::DepthToSpace => Some("DepthToSpace"),
+            Self::SpaceToDepth => Some("SpaceToDepth"),
+            Self::RoiAlign => Some("RoiAlign"),
+            Self::DFT => Some("DFT"),
+            Self::STFT => Some("STFT"),
+            Self::HannWindow => Some("HannWindow"),
+            Self::HammingWindow => Some("HammingWindow"),
+            Self::BlackmanWindow => Some("BlackmanWindow"),
+            Self::MelWeightMatrix => Some("MelWeightMatrix"),
+            Self::RMSNormalization => Some("RMSNormalization"),
+            Self::RotaryEmbedding => Some("RotaryEmbedding"),
+            Self::GlobalMaxPool => Some("GlobalMaxPool"),
+            Self::LpPool => Some("LpPool"),
+            Self::GlobalLpPool => Some("GlobalLpPool"),
+            Self::MaxUnpool => Some("MaxUnpool"),
+            Self::ReduceL1 => Some("ReduceL1"),
+            Self::ReduceLogSum => Some("ReduceLogSum"),
+            Self::ReduceLogSumExp => Some("ReduceLogSumExp"),
+            Self::Celu => Some("Celu"),
+            Self::Hardmax => Some("Hardmax"),
+            Self::Mish => Some("Mish"),
+            Self::PRelu => Some("PRelu"),
+            Self::Selu => Some("Selu"),
+            Self::Shrink => Some("Shrink"),
+            Self::Softsign => Some("Softsign"),
+            Self::ThresholdedRelu => Some("ThresholdedRelu"),
+            Self::BitShift => Some("BitShift"),
+            Self::BitwiseAnd => Some("BitwiseAnd"),
+            Self::BitwiseNot => Some("BitwiseNot"),
+            Self::BitwiseOr => Some("BitwiseOr"),
+            Self::BitwiseXor => Some("BitwiseXor"),
+            Self::IsInf => Some("IsInf"),
+            Self::IsNaN => Some("IsNaN"),
+            Self::CenterCropPad => Some("CenterCropPad"),
+            Self::Compress => Some("Compress"),
+            Self::ReverseSequence => Some("ReverseSequence"),
+            Self::Unique => Some("Unique"),
+            Self::RNN => Some("RNN"),
+            Self::Bernoulli => Some("Bernoulli"),
+            Self::Multinomial => Some("Multinomial"),
             _ => None,
         }
     }
@@ -611,6 +771,137 @@ impl flatbuffers::SimpleToVerifyInSlice for RNNDirection {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
+pub const ENUM_MIN_RNNACTIVATION: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_RNNACTIVATION: u8 = 10;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_RNNACTIVATION: [RNNActivation; 11] = [
+    RNNActivation::Affine,
+    RNNActivation::Elu,
+    RNNActivation::HardSigmoid,
+    RNNActivation::LeakyRelu,
+    RNNActivation::Relu,
+    RNNActivation::ScaledTanh,
+    RNNActivation::Sigmoid,
+    RNNActivation::Softplus,
+    RNNActivation::Softsign,
+    RNNActivation::Tanh,
+    RNNActivation::ThresholdedRelu,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct RNNActivation(pub u8);
+#[allow(non_upper_case_globals)]
+impl RNNActivation {
+    pub const Affine: Self = Self(0);
+    pub const Elu: Self = Self(1);
+    pub const HardSigmoid: Self = Self(2);
+    pub const LeakyRelu: Self = Self(3);
+    pub const Relu: Self = Self(4);
+    pub const ScaledTanh: Self = Self(5);
+    pub const Sigmoid: Self = Self(6);
+    pub const Softplus: Self = Self(7);
+    pub const Softsign: Self = Self(8);
+    pub const Tanh: Self = Self(9);
+    pub const ThresholdedRelu: Self = Self(10);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 10;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Affine,
+        Self::Elu,
+        Self::HardSigmoid,
+        Self::LeakyRelu,
+        Self::Relu,
+        Self::ScaledTanh,
+        Self::Sigmoid,
+        Self::Softplus,
+        Self::Softsign,
+        Self::Tanh,
+        Self::ThresholdedRelu,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Affine => Some("Affine"),
+            Self::Elu => Some("Elu"),
+            Self::HardSigmoid => Some("HardSigmoid"),
+            Self::LeakyRelu => Some("LeakyRelu"),
+            Self::Relu => Some("Relu"),
+            Self::ScaledTanh => Some("ScaledTanh"),
+            Self::Sigmoid => Some("Sigmoid"),
+            Self::Softplus => Some("Softplus"),
+            Self::Softsign => Some("Softsign"),
+            Self::Tanh => Some("Tanh"),
+            Self::ThresholdedRelu => Some("ThresholdedRelu"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for RNNActivation {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for RNNActivation {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for RNNActivation {
+    type Output = RNNActivation;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for RNNActivation {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for RNNActivation {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for RNNActivation {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
 pub const ENUM_MIN_AUTO_PAD: u8 = 0;
 #[deprecated(
     since = "2.0.0",
@@ -700,6 +991,96 @@ impl flatbuffers::SimpleToVerifyInSlice for AutoPad {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
+pub const ENUM_MIN_BIT_SHIFT_DIRECTION: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_BIT_SHIFT_DIRECTION: u8 = 1;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_BIT_SHIFT_DIRECTION: [BitShiftDirection; 2] =
+    [BitShiftDirection::Left, BitShiftDirection::Right];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct BitShiftDirection(pub u8);
+#[allow(non_upper_case_globals)]
+impl BitShiftDirection {
+    pub const Left: Self = Self(0);
+    pub const Right: Self = Self(1);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Left, Self::Right];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Left => Some("Left"),
+            Self::Right => Some("Right"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for BitShiftDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for BitShiftDirection {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for BitShiftDirection {
+    type Output = BitShiftDirection;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for BitShiftDirection {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for BitShiftDirection {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for BitShiftDirection {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
 pub const ENUM_MIN_DATA_TYPE: u8 = 0;
 #[deprecated(
     since = "2.0.0",
@@ -1082,13 +1463,13 @@ pub const ENUM_MIN_OPERATOR_ATTRS: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 38;
+pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 60;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 39] = [
+pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 61] = [
     OperatorAttrs::NONE,
     OperatorAttrs::ArgMaxAttrs,
     OperatorAttrs::AveragePoolAttrs,
@@ -1128,6 +1509,28 @@ pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 39] = [
     OperatorAttrs::GatherNDAttrs,
     OperatorAttrs::GeluAttrs,
     OperatorAttrs::EinsumAttrs,
+    OperatorAttrs::DepthToSpaceAttrs,
+    OperatorAttrs::SpaceToDepthAttrs,
+    OperatorAttrs::RoiAlignAttrs,
+    OperatorAttrs::DFTAttrs,
+    OperatorAttrs::STFTAttrs,
+    OperatorAttrs::WindowAttrs,
+    OperatorAttrs::RotaryEmbeddingAttrs,
+    OperatorAttrs::LpPoolAttrs,
+    OperatorAttrs::GlobalLpPoolAttrs,
+    OperatorAttrs::MaxUnpoolAttrs,
+    OperatorAttrs::CumSumAttrs,
+    OperatorAttrs::SeluAttrs,
+    OperatorAttrs::ShrinkAttrs,
+    OperatorAttrs::BitShiftAttrs,
+    OperatorAttrs::IsInfAttrs,
+    OperatorAttrs::CenterCropPadAttrs,
+    OperatorAttrs::CompressAttrs,
+    OperatorAttrs::ReverseSequenceAttrs,
+    OperatorAttrs::UniqueAttrs,
+    OperatorAttrs::RNNAttrs,
+    OperatorAttrs::BernoulliAttrs,
+    OperatorAttrs::MultinomialAttrs,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1174,9 +1577,31 @@ impl OperatorAttrs {
     pub const GatherNDAttrs: Self = Self(36);
     pub const GeluAttrs: Self = Self(37);
     pub const EinsumAttrs: Self = Self(38);
+    pub const DepthToSpaceAttrs: Self = Self(39);
+    pub const SpaceToDepthAttrs: Self = Self(40);
+    pub const RoiAlignAttrs: Self = Self(41);
+    pub const DFTAttrs: Self = Self(42);
+    pub const STFTAttrs: Self = Self(43);
+    pub const WindowAttrs: Self = Self(44);
+    pub const RotaryEmbeddingAttrs: Self = Self(45);
+    pub const LpPoolAttrs: Self = Self(46);
+    pub const GlobalLpPoolAttrs: Self = Self(47);
+    pub const MaxUnpoolAttrs: Self = Self(48);
+    pub const CumSumAttrs: Self = Self(49);
+    pub const SeluAttrs: Self = Self(50);
+    pub const ShrinkAttrs: Self = Self(51);
+    pub const BitShiftAttrs: Self = Self(52);
+    pub const IsInfAttrs: Self = Self(53);
+    pub const CenterCropPadAttrs: Self = Self(54);
+    pub const CompressAttrs: Self = Self(55);
+    pub const ReverseSequenceAttrs: Self = Self(56);
+    pub const UniqueAttrs: Self = Self(57);
+    pub const RNNAttrs: Self = Self(58);
+    pub const BernoulliAttrs: Self = Self(59);
+    pub const MultinomialAttrs: Self = Self(60);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 38;
+    pub const ENUM_MAX: u8 = 60;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::ArgMaxAttrs,
@@ -1217,6 +1642,28 @@ impl OperatorAttrs {
         Self::GatherNDAttrs,
         Self::GeluAttrs,
         Self::EinsumAttrs,
+        Self::DepthToSpaceAttrs,
+        Self::SpaceToDepthAttrs,
+        Self::RoiAlignAttrs,
+        Self::DFTAttrs,
+        Self::STFTAttrs,
+        Self::WindowAttrs,
+        Self::RotaryEmbeddingAttrs,
+        Self::LpPoolAttrs,
+        Self::GlobalLpPoolAttrs,
+        Self::MaxUnpoolAttrs,
+        Self::CumSumAttrs,
+        Self::SeluAttrs,
+        Self::ShrinkAttrs,
+        Self::BitShiftAttrs,
+        Self::IsInfAttrs,
+        Self::CenterCropPadAttrs,
+        Self::CompressAttrs,
+        Self::ReverseSequenceAttrs,
+        Self::UniqueAttrs,
+        Self::RNNAttrs,
+        Self::BernoulliAttrs,
+        Self::MultinomialAttrs,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -1260,6 +1707,28 @@ impl OperatorAttrs {
             Self::GatherNDAttrs => Some("GatherNDAttrs"),
             Self::GeluAttrs => Some("GeluAttrs"),
             Self::EinsumAttrs => Some("EinsumAttrs"),
+            Self::DepthToSpaceAttrs => Some("DepthToSpaceAttrs"),
+            Self::SpaceToDepthAttrs => Some("SpaceToDepthAttrs"),
+            Self::RoiAlignAttrs => Some("RoiAlignAttrs"),
+            Self::DFTAttrs => Some("DFTAttrs"),
+            Self::STFTAttrs => Some("STFTAttrs"),
+            Self::WindowAttrs => Some("WindowAttrs"),
+            Self::RotaryEmbeddingAttrs => Some("RotaryEmbeddingAttrs"),
+            Self::LpPoolAttrs => Some("LpPoolAttrs"),
+            Self::GlobalLpPoolAttrs => Some("GlobalLpPoolAttrs"),
+            Self::MaxUnpoolAttrs => Some("MaxUnpoolAttrs"),
+            Self::CumSumAttrs => Some("CumSumAttrs"),
+            Self::SeluAttrs => Some("SeluAttrs"),
+            Self::ShrinkAttrs => Some("ShrinkAttrs"),
+            Self::BitShiftAttrs => Some("BitShiftAttrs"),
+            Self::IsInfAttrs => Some("IsInfAttrs"),
+            Self::CenterCropPadAttrs => Some("CenterCropPadAttrs"),
+            Self::CompressAttrs => Some("CompressAttrs"),
+            Self::ReverseSequenceAttrs => Some("ReverseSequenceAttrs"),
+            Self::UniqueAttrs => Some("UniqueAttrs"),
+            Self::RNNAttrs => Some("RNNAttrs"),
+            Self::BernoulliAttrs => Some("BernoulliAttrs"),
+            Self::MultinomialAttrs => Some("MultinomialAttrs"),
             _ => None,
         }
     }
@@ -1415,43 +1884,41 @@ pub struct ScalarUnionTableOffset {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_NMSBOX_ORDER: u8 = 0;
+pub const ENUM_MIN_DEPTH_TO_SPACE_MODE: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_NMSBOX_ORDER: u8 = 1;
+pub const ENUM_MAX_DEPTH_TO_SPACE_MODE: u8 = 1;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_NMSBOX_ORDER: [NMSBoxOrder; 2] = [
-    NMSBoxOrder::TopLeftBottomRight,
-    NMSBoxOrder::CenterWidthHeight,
-];
+pub const ENUM_VALUES_DEPTH_TO_SPACE_MODE: [DepthToSpaceMode; 2] =
+    [DepthToSpaceMode::DCR, DepthToSpaceMode::CRD];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct NMSBoxOrder(pub u8);
+pub struct DepthToSpaceMode(pub u8);
 #[allow(non_upper_case_globals)]
-impl NMSBoxOrder {
-    pub const TopLeftBottomRight: Self = Self(0);
-    pub const CenterWidthHeight: Self = Self(1);
+impl DepthToSpaceMode {
+    pub const DCR: Self = Self(0);
+    pub const CRD: Self = Self(1);
 
     pub const ENUM_MIN: u8 = 0;
     pub const ENUM_MAX: u8 = 1;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::TopLeftBottomRight, Self::CenterWidthHeight];
+    pub const ENUM_VALUES: &'static [Self] = &[Self::DCR, Self::CRD];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::TopLeftBottomRight => Some("TopLeftBottomRight"),
-            Self::CenterWidthHeight => Some("CenterWidthHeight"),
+            Self::DCR => Some("DCR"),
+            Self::CRD => Some("CRD"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for NMSBoxOrder {
+impl core::fmt::Debug for DepthToSpaceMode {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1460,7 +1927,7 @@ impl core::fmt::Debug for NMSBoxOrder {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for NMSBoxOrder {
+impl<'a> flatbuffers::Follow<'a> for DepthToSpaceMode {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1469,15 +1936,15 @@ impl<'a> flatbuffers::Follow<'a> for NMSBoxOrder {
     }
 }
 
-impl flatbuffers::Push for NMSBoxOrder {
-    type Output = NMSBoxOrder;
+impl flatbuffers::Push for DepthToSpaceMode {
+    type Output = DepthToSpaceMode;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for NMSBoxOrder {
+impl flatbuffers::EndianScalar for DepthToSpaceMode {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1491,7 +1958,7 @@ impl flatbuffers::EndianScalar for NMSBoxOrder {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for NMSBoxOrder {
+impl<'a> flatbuffers::Verifiable for DepthToSpaceMode {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1502,58 +1969,48 @@ impl<'a> flatbuffers::Verifiable for NMSBoxOrder {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for NMSBoxOrder {}
+impl flatbuffers::SimpleToVerifyInSlice for DepthToSpaceMode {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_SCATTER_REDUCTION: u8 = 0;
+pub const ENUM_MIN_NMSBOX_ORDER: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_SCATTER_REDUCTION: u8 = 4;
+pub const ENUM_MAX_NMSBOX_ORDER: u8 = 1;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_SCATTER_REDUCTION: [ScatterReduction; 5] = [
-    ScatterReduction::None,
-    ScatterReduction::Add,
-    ScatterReduction::Mul,
-    ScatterReduction::Min,
-    ScatterReduction::Max,
+pub const ENUM_VALUES_NMSBOX_ORDER: [NMSBoxOrder; 2] = [
+    NMSBoxOrder::TopLeftBottomRight,
+    NMSBoxOrder::CenterWidthHeight,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct ScatterReduction(pub u8);
+pub struct NMSBoxOrder(pub u8);
 #[allow(non_upper_case_globals)]
-impl ScatterReduction {
-    pub const None: Self = Self(0);
-    pub const Add: Self = Self(1);
-    pub const Mul: Self = Self(2);
-    pub const Min: Self = Self(3);
-    pub const Max: Self = Self(4);
+impl NMSBoxOrder {
+    pub const TopLeftBottomRight: Self = Self(0);
+    pub const CenterWidthHeight: Self = Self(1);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 4;
-    pub const ENUM_VALUES: &'static [Self] =
-        &[Self::None, Self::Add, Self::Mul, Self::Min, Self::Max];
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::TopLeftBottomRight, Self::CenterWidthHeight];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::None => Some("None"),
-            Self::Add => Some("Add"),
-            Self::Mul => Some("Mul"),
-            Self::Min => Some("Min"),
-            Self::Max => Some("Max"),
+            Self::TopLeftBottomRight => Some("TopLeftBottomRight"),
+            Self::CenterWidthHeight => Some("CenterWidthHeight"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for ScatterReduction {
+impl core::fmt::Debug for NMSBoxOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1562,7 +2019,7 @@ impl core::fmt::Debug for ScatterReduction {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for ScatterReduction {
+impl<'a> flatbuffers::Follow<'a> for NMSBoxOrder {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1571,15 +2028,15 @@ impl<'a> flatbuffers::Follow<'a> for ScatterReduction {
     }
 }
 
-impl flatbuffers::Push for ScatterReduction {
-    type Output = ScatterReduction;
+impl flatbuffers::Push for NMSBoxOrder {
+    type Output = NMSBoxOrder;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for ScatterReduction {
+impl flatbuffers::EndianScalar for NMSBoxOrder {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1593,7 +2050,7 @@ impl flatbuffers::EndianScalar for ScatterReduction {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for ScatterReduction {
+impl<'a> flatbuffers::Verifiable for NMSBoxOrder {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1604,59 +2061,45 @@ impl<'a> flatbuffers::Verifiable for ScatterReduction {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for ScatterReduction {}
+impl flatbuffers::SimpleToVerifyInSlice for NMSBoxOrder {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_NODE_KIND: u8 = 0;
+pub const ENUM_MIN_ROI_ALIGN_MODE: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_NODE_KIND: u8 = 3;
+pub const ENUM_MAX_ROI_ALIGN_MODE: u8 = 1;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_NODE_KIND: [NodeKind; 4] = [
-    NodeKind::NONE,
-    NodeKind::OperatorNode,
-    NodeKind::ConstantNode,
-    NodeKind::ValueNode,
-];
+pub const ENUM_VALUES_ROI_ALIGN_MODE: [RoiAlignMode; 2] = [RoiAlignMode::Avg, RoiAlignMode::Max];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct NodeKind(pub u8);
+pub struct RoiAlignMode(pub u8);
 #[allow(non_upper_case_globals)]
-impl NodeKind {
-    pub const NONE: Self = Self(0);
-    pub const OperatorNode: Self = Self(1);
-    pub const ConstantNode: Self = Self(2);
-    pub const ValueNode: Self = Self(3);
+impl RoiAlignMode {
+    pub const Avg: Self = Self(0);
+    pub const Max: Self = Self(1);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 3;
-    pub const ENUM_VALUES: &'static [Self] = &[
-        Self::NONE,
-        Self::OperatorNode,
-        Self::ConstantNode,
-        Self::ValueNode,
-    ];
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Avg, Self::Max];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::NONE => Some("NONE"),
-            Self::OperatorNode => Some("OperatorNode"),
-            Self::ConstantNode => Some("ConstantNode"),
-            Self::ValueNode => Some("ValueNode"),
+            Self::Avg => Some("Avg"),
+            Self::Max => Some("Max"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for NodeKind {
+impl core::fmt::Debug for RoiAlignMode {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1665,7 +2108,7 @@ impl core::fmt::Debug for NodeKind {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for NodeKind {
+impl<'a> flatbuffers::Follow<'a> for RoiAlignMode {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1674,15 +2117,15 @@ impl<'a> flatbuffers::Follow<'a> for NodeKind {
     }
 }
 
-impl flatbuffers::Push for NodeKind {
-    type Output = NodeKind;
+impl flatbuffers::Push for RoiAlignMode {
+    type Output = RoiAlignMode;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for NodeKind {
+impl flatbuffers::EndianScalar for RoiAlignMode {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1696,7 +2139,7 @@ impl flatbuffers::EndianScalar for NodeKind {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for NodeKind {
+impl<'a> flatbuffers::Verifiable for RoiAlignMode {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1707,53 +2150,48 @@ impl<'a> flatbuffers::Verifiable for NodeKind {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for NodeKind {}
-pub struct NodeKindUnionTableOffset {}
-
+impl flatbuffers::SimpleToVerifyInSlice for RoiAlignMode {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_CONSTANT_DATA: u8 = 0;
+pub const ENUM_MIN_ROI_ALIGN_COORD_MODE: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_CONSTANT_DATA: u8 = 2;
+pub const ENUM_MAX_ROI_ALIGN_COORD_MODE: u8 = 1;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_CONSTANT_DATA: [ConstantData; 3] = [
-    ConstantData::NONE,
-    ConstantData::FloatData,
-    ConstantData::IntData,
+pub const ENUM_VALUES_ROI_ALIGN_COORD_MODE: [RoiAlignCoordMode; 2] = [
+    RoiAlignCoordMode::HalfPixel,
+    RoiAlignCoordMode::OutputHalfPixel,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct ConstantData(pub u8);
+pub struct RoiAlignCoordMode(pub u8);
 #[allow(non_upper_case_globals)]
-impl ConstantData {
-    pub const NONE: Self = Self(0);
-    pub const FloatData: Self = Self(1);
-    pub const IntData: Self = Self(2);
+impl RoiAlignCoordMode {
+    pub const HalfPixel: Self = Self(0);
+    pub const OutputHalfPixel: Self = Self(1);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 2;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::FloatData, Self::IntData];
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::HalfPixel, Self::OutputHalfPixel];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::NONE => Some("NONE"),
-            Self::FloatData => Some("FloatData"),
-            Self::IntData => Some("IntData"),
+            Self::HalfPixel => Some("HalfPixel"),
+            Self::OutputHalfPixel => Some("OutputHalfPixel"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for ConstantData {
+impl core::fmt::Debug for RoiAlignCoordMode {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1762,7 +2200,7 @@ impl core::fmt::Debug for ConstantData {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for ConstantData {
+impl<'a> flatbuffers::Follow<'a> for RoiAlignCoordMode {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
@@ -1771,15 +2209,15 @@ impl<'a> flatbuffers::Follow<'a> for ConstantData {
     }
 }
 
-impl flatbuffers::Push for ConstantData {
-    type Output = ConstantData;
+impl flatbuffers::Push for RoiAlignCoordMode {
+    type Output = RoiAlignCoordMode;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
         flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for ConstantData {
+impl flatbuffers::EndianScalar for RoiAlignCoordMode {
     type Scalar = u8;
     #[inline]
     fn to_little_endian(self) -> u8 {
@@ -1793,7 +2231,7 @@ impl flatbuffers::EndianScalar for ConstantData {
     }
 }
 
-impl<'a> flatbuffers::Verifiable for ConstantData {
+impl<'a> flatbuffers::Verifiable for RoiAlignCoordMode {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -1804,48 +2242,58 @@ impl<'a> flatbuffers::Verifiable for ConstantData {
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for ConstantData {}
-pub struct ConstantDataUnionTableOffset {}
-
+impl flatbuffers::SimpleToVerifyInSlice for RoiAlignCoordMode {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_CONSTANT_DATA_TYPE: u16 = 0;
+pub const ENUM_MIN_SCATTER_REDUCTION: u8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_CONSTANT_DATA_TYPE: u16 = 1;
+pub const ENUM_MAX_SCATTER_REDUCTION: u8 = 4;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_CONSTANT_DATA_TYPE: [ConstantDataType; 2] =
-    [ConstantDataType::Int32, ConstantDataType::Float32];
+pub const ENUM_VALUES_SCATTER_REDUCTION: [ScatterReduction; 5] = [
+    ScatterReduction::None,
+    ScatterReduction::Add,
+    ScatterReduction::Mul,
+    ScatterReduction::Min,
+    ScatterReduction::Max,
+];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct ConstantDataType(pub u16);
+pub struct ScatterReduction(pub u8);
 #[allow(non_upper_case_globals)]
-impl ConstantDataType {
-    pub const Int32: Self = Self(0);
-    pub const Float32: Self = Self(1);
+impl ScatterReduction {
+    pub const None: Self = Self(0);
+    pub const Add: Self = Self(1);
+    pub const Mul: Self = Self(2);
+    pub const Min: Self = Self(3);
+    pub const Max: Self = Self(4);
 
-    pub const ENUM_MIN: u16 = 0;
-    pub const ENUM_MAX: u16 = 1;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::Int32, Self::Float32];
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] =
+        &[Self::None, Self::Add, Self::Mul, Self::Min, Self::Max];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::Int32 => Some("Int32"),
-            Self::Float32 => Some("Float32"),
+            Self::None => Some("None"),
+            Self::Add => Some("Add"),
+            Self::Mul => Some("Mul"),
+            Self::Min => Some("Min"),
+            Self::Max => Some("Max"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for ConstantDataType {
+impl core::fmt::Debug for ScatterReduction {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -1854,181 +2302,350 @@ impl core::fmt::Debug for ConstantDataType {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for ConstantDataType {
+impl<'a> flatbuffers::Follow<'a> for ScatterReduction {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        let b = flatbuffers::read_scalar_at::<u16>(buf, loc);
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
         Self(b)
     }
 }
 
-impl flatbuffers::Push for ConstantDataType {
-    type Output = ConstantDataType;
+impl flatbuffers::Push for ScatterReduction {
+    type Output = ScatterReduction;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        flatbuffers::emplace_scalar::<u16>(dst, self.0);
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for ConstantDataType {
-    type Scalar = u16;
+impl flatbuffers::EndianScalar for ScatterReduction {
+    type Scalar = u8;
     #[inline]
-    fn to_little_endian(self) -> u16 {
+    fn to_little_endian(self) -> u8 {
         self.0.to_le()
     }
     #[inline]
     #[allow(clippy::wrong_self_convention)]
-    fn from_little_endian(v: u16) -> Self {
-        let b = u16::from_le(v);
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
         Self(b)
     }
 }
 
-impl<'a> flatbuffers::Verifiable for ConstantDataType {
+impl<'a> flatbuffers::Verifiable for ScatterReduction {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        u16::run_verifier(v, pos)
+        u8::run_verifier(v, pos)
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for ConstantDataType {}
-pub enum ArgMaxAttrsOffset {}
-#[derive(Copy, Clone, PartialEq)]
+impl flatbuffers::SimpleToVerifyInSlice for ScatterReduction {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_NODE_KIND: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_NODE_KIND: u8 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_NODE_KIND: [NodeKind; 4] = [
+    NodeKind::NONE,
+    NodeKind::OperatorNode,
+    NodeKind::ConstantNode,
+    NodeKind::ValueNode,
+];
 
-pub struct ArgMaxAttrs<'a> {
-    pub _tab: flatbuffers::Table<'a>,
-}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct NodeKind(pub u8);
+#[allow(non_upper_case_globals)]
+impl NodeKind {
+    pub const NONE: Self = Self(0);
+    pub const OperatorNode: Self = Self(1);
+    pub const ConstantNode: Self = Self(2);
+    pub const ValueNode: Self = Self(3);
 
-impl<'a> flatbuffers::Follow<'a> for ArgMaxAttrs<'a> {
-    type Inner = ArgMaxAttrs<'a>;
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 3;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::OperatorNode,
+        Self::ConstantNode,
+        Self::ValueNode,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::OperatorNode => Some("OperatorNode"),
+            Self::ConstantNode => Some("ConstantNode"),
+            Self::ValueNode => Some("ValueNode"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for NodeKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for NodeKind {
+    type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
-        }
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
     }
 }
 
-impl<'a> ArgMaxAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
-
+impl flatbuffers::Push for NodeKind {
+    type Output = NodeKind;
     #[inline]
-    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ArgMaxAttrs { _tab: table }
-    }
-    #[allow(unused_mut)]
-    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ArgMaxAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ArgMaxAttrs<'bldr>> {
-        let mut builder = ArgMaxAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        builder.add_keep_dims(args.keep_dims);
-        builder.finish()
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
+}
 
+impl flatbuffers::EndianScalar for NodeKind {
+    type Scalar = u8;
     #[inline]
-    pub fn axis(&self) -> i32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(ArgMaxAttrs::VT_AXIS, Some(0)).unwrap() }
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
     }
     #[inline]
-    pub fn keep_dims(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, Some(false))
-                .unwrap()
-        }
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
     }
 }
 
-impl flatbuffers::Verifiable for ArgMaxAttrs<'_> {
+impl<'a> flatbuffers::Verifiable for NodeKind {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
-            .finish();
-        Ok(())
-    }
-}
-pub struct ArgMaxAttrsArgs {
-    pub axis: i32,
-    pub keep_dims: bool,
-}
-impl<'a> Default for ArgMaxAttrsArgs {
-    #[inline]
-    fn default() -> Self {
-        ArgMaxAttrsArgs {
-            axis: 0,
-            keep_dims: false,
-        }
+        u8::run_verifier(v, pos)
     }
 }
 
-pub struct ArgMaxAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArgMaxAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(ArgMaxAttrs::VT_AXIS, axis, 0);
-    }
-    #[inline]
-    pub fn add_keep_dims(&mut self, keep_dims: bool) {
-        self.fbb_
-            .push_slot::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, keep_dims, false);
-    }
-    #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ArgMaxAttrsBuilder<'a, 'b, A> {
-        let start = _fbb.start_table();
-        ArgMaxAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
+impl flatbuffers::SimpleToVerifyInSlice for NodeKind {}
+pub struct NodeKindUnionTableOffset {}
+
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_CONSTANT_DATA: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_CONSTANT_DATA: u8 = 2;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CONSTANT_DATA: [ConstantData; 3] = [
+    ConstantData::NONE,
+    ConstantData::FloatData,
+    ConstantData::IntData,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ConstantData(pub u8);
+#[allow(non_upper_case_globals)]
+impl ConstantData {
+    pub const NONE: Self = Self(0);
+    pub const FloatData: Self = Self(1);
+    pub const IntData: Self = Self(2);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 2;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::FloatData, Self::IntData];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::FloatData => Some("FloatData"),
+            Self::IntData => Some("IntData"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for ConstantData {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
         }
     }
+}
+impl<'a> flatbuffers::Follow<'a> for ConstantData {
+    type Inner = Self;
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ArgMaxAttrs<'a>> {
-        let o = self.fbb_.end_table(self.start_);
-        flatbuffers::WIPOffset::new(o.value())
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
     }
 }
 
-impl core::fmt::Debug for ArgMaxAttrs<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ArgMaxAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("keep_dims", &self.keep_dims());
-        ds.finish()
+impl flatbuffers::Push for ConstantData {
+    type Output = ConstantData;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
     }
 }
-pub enum AveragePoolAttrsOffset {}
+
+impl flatbuffers::EndianScalar for ConstantData {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ConstantData {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ConstantData {}
+pub struct ConstantDataUnionTableOffset {}
+
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_CONSTANT_DATA_TYPE: u16 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_CONSTANT_DATA_TYPE: u16 = 1;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CONSTANT_DATA_TYPE: [ConstantDataType; 2] =
+    [ConstantDataType::Int32, ConstantDataType::Float32];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ConstantDataType(pub u16);
+#[allow(non_upper_case_globals)]
+impl ConstantDataType {
+    pub const Int32: Self = Self(0);
+    pub const Float32: Self = Self(1);
+
+    pub const ENUM_MIN: u16 = 0;
+    pub const ENUM_MAX: u16 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Int32, Self::Float32];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Int32 => Some("Int32"),
+            Self::Float32 => Some("Float32"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for ConstantDataType {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for ConstantDataType {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u16>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for ConstantDataType {
+    type Output = ConstantDataType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u16>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for ConstantDataType {
+    type Scalar = u16;
+    #[inline]
+    fn to_little_endian(self) -> u16 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u16) -> Self {
+        let b = u16::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ConstantDataType {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u16::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ConstantDataType {}
+pub enum ArgMaxAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AveragePoolAttrs<'a> {
+pub struct ArgMaxAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
-    type Inner = AveragePoolAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ArgMaxAttrs<'a> {
+    type Inner = ArgMaxAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2037,73 +2654,224 @@ impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
     }
 }
 
-impl<'a> AveragePoolAttrs<'a> {
-    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
-    pub const VT_COUNT_INCLUDE_PAD: flatbuffers::VOffsetT = 12;
+impl<'a> ArgMaxAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+    pub const VT_SELECT_LAST_INDEX: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        AveragePoolAttrs { _tab: table }
+        ArgMaxAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args AveragePoolAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<AveragePoolAttrs<'bldr>> {
-        let mut builder = AveragePoolAttrsBuilder::new(_fbb);
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.kernel_size {
-            builder.add_kernel_size(x);
-        }
-        builder.add_count_include_pad(args.count_include_pad);
-        builder.add_auto_pad(args.auto_pad);
+        args: &'args ArgMaxAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ArgMaxAttrs<'bldr>> {
+        let mut builder = ArgMaxAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_select_last_index(args.select_last_index);
+        builder.add_keep_dims(args.keep_dims);
         builder.finish()
     }
 
     #[inline]
-    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    AveragePoolAttrs::VT_KERNEL_SIZE,
-                    None,
-                )
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(ArgMaxAttrs::VT_AXIS, Some(0)).unwrap() }
     }
     #[inline]
-    pub fn auto_pad(&self) -> AutoPad {
+    pub fn keep_dims(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<AutoPad>(AveragePoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
+                .get::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, Some(false))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn select_last_index(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    AveragePoolAttrs::VT_PADS,
-                    None,
-                )
+                .get::<bool>(ArgMaxAttrs::VT_SELECT_LAST_INDEX, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ArgMaxAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .visit_field::<bool>("select_last_index", Self::VT_SELECT_LAST_INDEX, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ArgMaxAttrsArgs {
+    pub axis: i32,
+    pub keep_dims: bool,
+    pub select_last_index: bool,
+}
+impl<'a> Default for ArgMaxAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ArgMaxAttrsArgs {
+            axis: 0,
+            keep_dims: false,
+            select_last_index: false,
+        }
+    }
+}
+
+pub struct ArgMaxAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArgMaxAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(ArgMaxAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(ArgMaxAttrs::VT_KEEP_DIMS, keep_dims, false);
+    }
+    #[inline]
+    pub fn add_select_last_index(&mut self, select_last_index: bool) {
+        self.fbb_
+            .push_slot::<bool>(ArgMaxAttrs::VT_SELECT_LAST_INDEX, select_last_index, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ArgMaxAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ArgMaxAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ArgMaxAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ArgMaxAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ArgMaxAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("keep_dims", &self.keep_dims());
+        ds.field("select_last_index", &self.select_last_index());
+        ds.finish()
+    }
+}
+pub enum AveragePoolAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AveragePoolAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
+    type Inner = AveragePoolAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> AveragePoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_COUNT_INCLUDE_PAD: flatbuffers::VOffsetT = 12;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 14;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 16;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        AveragePoolAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args AveragePoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<AveragePoolAttrs<'bldr>> {
+        let mut builder = AveragePoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_count_include_pad(args.count_include_pad);
+        builder.add_auto_pad(args.auto_pad);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn auto_pad(&self) -> AutoPad {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<AutoPad>(AveragePoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_PADS,
+                    None,
+                )
         }
     }
     #[inline]
@@ -2130,6 +2898,30 @@ impl<'a> AveragePoolAttrs<'a> {
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(AveragePoolAttrs::VT_CEIL_MODE, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    AveragePoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for AveragePoolAttrs<'_> {
@@ -2157,6 +2949,12 @@ impl flatbuffers::Verifiable for AveragePoolAttrs<'_> {
                 false,
             )?
             .visit_field::<bool>("count_include_pad", Self::VT_COUNT_INCLUDE_PAD, false)?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
             .finish();
         Ok(())
     }
@@ -2167,6 +2965,8 @@ pub struct AveragePoolAttrsArgs<'a> {
     pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
     pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
 impl<'a> Default for AveragePoolAttrsArgs<'a> {
     #[inline]
@@ -2177,6 +2977,8 @@ impl<'a> Default for AveragePoolAttrsArgs<'a> {
             pads: None,
             strides: None,
             count_include_pad: false,
+            ceil_mode: false,
+            dilations: None,
         }
     }
 }
@@ -2220,6 +3022,21 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AveragePoolAttrsBuilder<'a, 'b,
         );
     }
     #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(AveragePoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            AveragePoolAttrs::VT_DILATIONS,
+            dilations,
+        );
+    }
+    #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     ) -> AveragePoolAttrsBuilder<'a, 'b, A> {
@@ -2246,6 +3063,8 @@ impl core::fmt::Debug for AveragePoolAttrs<'_> {
         ds.field("pads", &self.pads());
         ds.field("strides", &self.strides());
         ds.field("count_include_pad", &self.count_include_pad());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("dilations", &self.dilations());
         ds.finish()
     }
 }
@@ -2353,15 +3172,15 @@ impl core::fmt::Debug for BatchNormalizationAttrs<'_> {
         ds.finish()
     }
 }
-pub enum CastAttrsOffset {}
+pub enum BernoulliAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct CastAttrs<'a> {
+pub struct BernoulliAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for CastAttrs<'a> {
-    type Inner = CastAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for BernoulliAttrs<'a> {
+    type Inner = BernoulliAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2370,37 +3189,48 @@ impl<'a> flatbuffers::Follow<'a> for CastAttrs<'a> {
     }
 }
 
-impl<'a> CastAttrs<'a> {
-    pub const VT_TO: flatbuffers::VOffsetT = 4;
+impl<'a> BernoulliAttrs<'a> {
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_SEED: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        CastAttrs { _tab: table }
+        BernoulliAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args CastAttrsArgs,
-    ) -> flatbuffers::WIPOffset<CastAttrs<'bldr>> {
-        let mut builder = CastAttrsBuilder::new(_fbb);
-        builder.add_to(args.to);
+        args: &'args BernoulliAttrsArgs,
+    ) -> flatbuffers::WIPOffset<BernoulliAttrs<'bldr>> {
+        let mut builder = BernoulliAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_dtype(args.dtype);
         builder.finish()
     }
 
     #[inline]
-    pub fn to(&self) -> DataType {
+    pub fn dtype(&self) -> DataType {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<DataType>(CastAttrs::VT_TO, Some(DataType::Int32))
+                .get::<DataType>(BernoulliAttrs::VT_DTYPE, Some(DataType::Float))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(BernoulliAttrs::VT_SEED, None) }
+    }
 }
 
-impl flatbuffers::Verifiable for CastAttrs<'_> {
+impl flatbuffers::Verifiable for BernoulliAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2408,64 +3238,75 @@ impl flatbuffers::Verifiable for CastAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<DataType>("to", Self::VT_TO, false)?
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct CastAttrsArgs {
-    pub to: DataType,
+pub struct BernoulliAttrsArgs {
+    pub dtype: DataType,
+    pub seed: Option<f32>,
 }
-impl<'a> Default for CastAttrsArgs {
+impl<'a> Default for BernoulliAttrsArgs {
     #[inline]
     fn default() -> Self {
-        CastAttrsArgs {
-            to: DataType::Int32,
+        BernoulliAttrsArgs {
+            dtype: DataType::Float,
+            seed: None,
         }
     }
 }
 
-pub struct CastAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct BernoulliAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CastAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BernoulliAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_to(&mut self, to: DataType) {
+    pub fn add_dtype(&mut self, dtype: DataType) {
         self.fbb_
-            .push_slot::<DataType>(CastAttrs::VT_TO, to, DataType::Int32);
+            .push_slot::<DataType>(BernoulliAttrs::VT_DTYPE, dtype, DataType::Float);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CastAttrsBuilder<'a, 'b, A> {
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(BernoulliAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> BernoulliAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        CastAttrsBuilder {
+        BernoulliAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<CastAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<BernoulliAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for CastAttrs<'_> {
+impl core::fmt::Debug for BernoulliAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("CastAttrs");
-        ds.field("to", &self.to());
+        let mut ds = f.debug_struct("BernoulliAttrs");
+        ds.field("dtype", &self.dtype());
+        ds.field("seed", &self.seed());
         ds.finish()
     }
 }
-pub enum ConcatAttrsOffset {}
+pub enum BitShiftAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConcatAttrs<'a> {
+pub struct BitShiftAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
-    type Inner = ConcatAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for BitShiftAttrs<'a> {
+    type Inner = BitShiftAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2474,33 +3315,40 @@ impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
     }
 }
 
-impl<'a> ConcatAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> BitShiftAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConcatAttrs { _tab: table }
+        BitShiftAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConcatAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ConcatAttrs<'bldr>> {
-        let mut builder = ConcatAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args BitShiftAttrsArgs,
+    ) -> flatbuffers::WIPOffset<BitShiftAttrs<'bldr>> {
+        let mut builder = BitShiftAttrsBuilder::new(_fbb);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn direction(&self) -> BitShiftDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(ConcatAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<BitShiftDirection>(
+                    BitShiftAttrs::VT_DIRECTION,
+                    Some(BitShiftDirection::Left),
+                )
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for ConcatAttrs<'_> {
+impl flatbuffers::Verifiable for BitShiftAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2508,63 +3356,69 @@ impl flatbuffers::Verifiable for ConcatAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<BitShiftDirection>("direction", Self::VT_DIRECTION, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConcatAttrsArgs {
-    pub axis: i32,
+pub struct BitShiftAttrsArgs {
+    pub direction: BitShiftDirection,
 }
-impl<'a> Default for ConcatAttrsArgs {
+impl<'a> Default for BitShiftAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConcatAttrsArgs { axis: 0 }
+        BitShiftAttrsArgs {
+            direction: BitShiftDirection::Left,
+        }
     }
 }
 
-pub struct ConcatAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct BitShiftAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConcatAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BitShiftAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(ConcatAttrs::VT_AXIS, axis, 0);
+    pub fn add_direction(&mut self, direction: BitShiftDirection) {
+        self.fbb_.push_slot::<BitShiftDirection>(
+            BitShiftAttrs::VT_DIRECTION,
+            direction,
+            BitShiftDirection::Left,
+        );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ConcatAttrsBuilder<'a, 'b, A> {
+    ) -> BitShiftAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConcatAttrsBuilder {
+        BitShiftAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<BitShiftAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConcatAttrs<'_> {
+impl core::fmt::Debug for BitShiftAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConcatAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("BitShiftAttrs");
+        ds.field("direction", &self.direction());
         ds.finish()
     }
 }
-pub enum IntScalarOffset {}
+pub enum CastAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct IntScalar<'a> {
+pub struct CastAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
-    type Inner = IntScalar<'a>;
+impl<'a> flatbuffers::Follow<'a> for CastAttrs<'a> {
+    type Inner = CastAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2573,33 +3427,37 @@ impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
     }
 }
 
-impl<'a> IntScalar<'a> {
-    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+impl<'a> CastAttrs<'a> {
+    pub const VT_TO: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        IntScalar { _tab: table }
+        CastAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args IntScalarArgs,
-    ) -> flatbuffers::WIPOffset<IntScalar<'bldr>> {
-        let mut builder = IntScalarBuilder::new(_fbb);
-        builder.add_value(args.value);
+        args: &'args CastAttrsArgs,
+    ) -> flatbuffers::WIPOffset<CastAttrs<'bldr>> {
+        let mut builder = CastAttrsBuilder::new(_fbb);
+        builder.add_to(args.to);
         builder.finish()
     }
 
     #[inline]
-    pub fn value(&self) -> i32 {
+    pub fn to(&self) -> DataType {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(IntScalar::VT_VALUE, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<DataType>(CastAttrs::VT_TO, Some(DataType::Int32))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for IntScalar<'_> {
+impl flatbuffers::Verifiable for CastAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2607,61 +3465,64 @@ impl flatbuffers::Verifiable for IntScalar<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("value", Self::VT_VALUE, false)?
+            .visit_field::<DataType>("to", Self::VT_TO, false)?
             .finish();
         Ok(())
     }
 }
-pub struct IntScalarArgs {
-    pub value: i32,
+pub struct CastAttrsArgs {
+    pub to: DataType,
 }
-impl<'a> Default for IntScalarArgs {
+impl<'a> Default for CastAttrsArgs {
     #[inline]
     fn default() -> Self {
-        IntScalarArgs { value: 0 }
+        CastAttrsArgs {
+            to: DataType::Int32,
+        }
     }
 }
 
-pub struct IntScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CastAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IntScalarBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CastAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_value(&mut self, value: i32) {
-        self.fbb_.push_slot::<i32>(IntScalar::VT_VALUE, value, 0);
+    pub fn add_to(&mut self, to: DataType) {
+        self.fbb_
+            .push_slot::<DataType>(CastAttrs::VT_TO, to, DataType::Int32);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IntScalarBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CastAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        IntScalarBuilder {
+        CastAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<IntScalar<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CastAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for IntScalar<'_> {
+impl core::fmt::Debug for CastAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("IntScalar");
-        ds.field("value", &self.value());
+        let mut ds = f.debug_struct("CastAttrs");
+        ds.field("to", &self.to());
         ds.finish()
     }
 }
-pub enum FloatScalarOffset {}
+pub enum CenterCropPadAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FloatScalar<'a> {
+pub struct CenterCropPadAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
-    type Inner = FloatScalar<'a>;
+impl<'a> flatbuffers::Follow<'a> for CenterCropPadAttrs<'a> {
+    type Inner = CenterCropPadAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2670,37 +3531,41 @@ impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
     }
 }
 
-impl<'a> FloatScalar<'a> {
-    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+impl<'a> CenterCropPadAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FloatScalar { _tab: table }
+        CenterCropPadAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args FloatScalarArgs,
-    ) -> flatbuffers::WIPOffset<FloatScalar<'bldr>> {
-        let mut builder = FloatScalarBuilder::new(_fbb);
-        builder.add_value(args.value);
+        args: &'args CenterCropPadAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CenterCropPadAttrs<'bldr>> {
+        let mut builder = CenterCropPadAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn value(&self) -> f32 {
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(FloatScalar::VT_VALUE, Some(0.0))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    CenterCropPadAttrs::VT_AXES,
+                    None,
+                )
         }
     }
 }
 
-impl flatbuffers::Verifiable for FloatScalar<'_> {
+impl flatbuffers::Verifiable for CenterCropPadAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2708,64 +3573,68 @@ impl flatbuffers::Verifiable for FloatScalar<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("value", Self::VT_VALUE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct FloatScalarArgs {
-    pub value: f32,
+pub struct CenterCropPadAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
 }
-impl<'a> Default for FloatScalarArgs {
+impl<'a> Default for CenterCropPadAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FloatScalarArgs { value: 0.0 }
+        CenterCropPadAttrsArgs { axes: None }
     }
 }
 
-pub struct FloatScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CenterCropPadAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FloatScalarBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CenterCropPadAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_value(&mut self, value: f32) {
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
         self.fbb_
-            .push_slot::<f32>(FloatScalar::VT_VALUE, value, 0.0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CenterCropPadAttrs::VT_AXES, axes);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> FloatScalarBuilder<'a, 'b, A> {
+    ) -> CenterCropPadAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        FloatScalarBuilder {
+        CenterCropPadAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FloatScalar<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CenterCropPadAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FloatScalar<'_> {
+impl core::fmt::Debug for CenterCropPadAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FloatScalar");
-        ds.field("value", &self.value());
+        let mut ds = f.debug_struct("CenterCropPadAttrs");
+        ds.field("axes", &self.axes());
         ds.finish()
     }
 }
-pub enum ConstantOfShapeAttrsOffset {}
+pub enum CompressAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConstantOfShapeAttrs<'a> {
+pub struct CompressAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
-    type Inner = ConstantOfShapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CompressAttrs<'a> {
+    type Inner = CompressAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2774,82 +3643,35 @@ impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
     }
 }
 
-impl<'a> ConstantOfShapeAttrs<'a> {
-    pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 4;
-    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+impl<'a> CompressAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConstantOfShapeAttrs { _tab: table }
+        CompressAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConstantOfShapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'bldr>> {
-        let mut builder = ConstantOfShapeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.value {
-            builder.add_value(x);
+        args: &'args CompressAttrsArgs,
+    ) -> flatbuffers::WIPOffset<CompressAttrs<'bldr>> {
+        let mut builder = CompressAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axis {
+            builder.add_axis(x);
         }
-        builder.add_value_type(args.value_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn value_type(&self) -> Scalar {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<Scalar>(ConstantOfShapeAttrs::VT_VALUE_TYPE, Some(Scalar::NONE))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn value(&self) -> flatbuffers::Table<'a> {
+    pub fn axis(&self) -> Option<i32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    ConstantOfShapeAttrs::VT_VALUE,
-                    None,
-                )
-                .unwrap()
-        }
-    }
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn value_as_int_scalar(&self) -> Option<IntScalar<'a>> {
-        if self.value_type() == Scalar::IntScalar {
-            let u = self.value();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { IntScalar::init_from_table(u) })
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn value_as_float_scalar(&self) -> Option<FloatScalar<'a>> {
-        if self.value_type() == Scalar::FloatScalar {
-            let u = self.value();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { FloatScalar::init_from_table(u) })
-        } else {
-            None
-        }
+        unsafe { self._tab.get::<i32>(CompressAttrs::VT_AXIS, None) }
     }
 }
 
-impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
+impl flatbuffers::Verifiable for CompressAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2857,123 +3679,64 @@ impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_union::<Scalar, _>(
-                "value_type",
-                Self::VT_VALUE_TYPE,
-                "value",
-                Self::VT_VALUE,
-                true,
-                |key, v, pos| match key {
-                    Scalar::IntScalar => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntScalar>>(
-                            "Scalar::IntScalar",
-                            pos,
-                        ),
-                    Scalar::FloatScalar => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatScalar>>(
-                            "Scalar::FloatScalar",
-                            pos,
-                        ),
-                    _ => Ok(()),
-                },
-            )?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConstantOfShapeAttrsArgs {
-    pub value_type: Scalar,
-    pub value: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct CompressAttrsArgs {
+    pub axis: Option<i32>,
 }
-impl<'a> Default for ConstantOfShapeAttrsArgs {
+impl<'a> Default for CompressAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConstantOfShapeAttrsArgs {
-            value_type: Scalar::NONE,
-            value: None, // required field
-        }
+        CompressAttrsArgs { axis: None }
     }
 }
 
-pub struct ConstantOfShapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CompressAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CompressAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_value_type(&mut self, value_type: Scalar) {
-        self.fbb_.push_slot::<Scalar>(
-            ConstantOfShapeAttrs::VT_VALUE_TYPE,
-            value_type,
-            Scalar::NONE,
-        );
-    }
-    #[inline]
-    pub fn add_value(&mut self, value: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    pub fn add_axis(&mut self, axis: i32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantOfShapeAttrs::VT_VALUE, value);
+            .push_slot_always::<i32>(CompressAttrs::VT_AXIS, axis);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
+    ) -> CompressAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConstantOfShapeAttrsBuilder {
+        CompressAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CompressAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_
-            .required(o, ConstantOfShapeAttrs::VT_VALUE, "value");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConstantOfShapeAttrs<'_> {
+impl core::fmt::Debug for CompressAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConstantOfShapeAttrs");
-        ds.field("value_type", &self.value_type());
-        match self.value_type() {
-            Scalar::IntScalar => {
-                if let Some(x) = self.value_as_int_scalar() {
-                    ds.field("value", &x)
-                } else {
-                    ds.field(
-                        "value",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            Scalar::FloatScalar => {
-                if let Some(x) = self.value_as_float_scalar() {
-                    ds.field("value", &x)
-                } else {
-                    ds.field(
-                        "value",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("value", &x)
-            }
-        };
+        let mut ds = f.debug_struct("CompressAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum ConvAttrsOffset {}
+pub enum ConcatAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConvAttrs<'a> {
+pub struct ConcatAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
-    type Inner = ConvAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
+    type Inner = ConcatAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2982,97 +3745,33 @@ impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
     }
 }
 
-impl<'a> ConvAttrs<'a> {
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 4;
-    pub const VT_PADS: flatbuffers::VOffsetT = 6;
-    pub const VT_GROUPS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
-    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+impl<'a> ConcatAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConvAttrs { _tab: table }
+        ConcatAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConvAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ConvAttrs<'bldr>> {
-        let mut builder = ConvAttrsBuilder::new(_fbb);
-        if let Some(x) = args.dilations {
-            builder.add_dilations(x);
-        }
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        builder.add_groups(args.groups);
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        builder.add_auto_pad(args.auto_pad);
+        args: &'args ConcatAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConcatAttrs<'bldr>> {
+        let mut builder = ConcatAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn auto_pad(&self) -> AutoPad {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<AutoPad>(ConvAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn groups(&self) -> u32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<u32>(ConvAttrs::VT_GROUPS, Some(0)).unwrap() }
-    }
-    #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_STRIDES,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_DILATIONS,
-                    None,
-                )
-        }
+        unsafe { self._tab.get::<i32>(ConcatAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for ConvAttrs<'_> {
+impl flatbuffers::Verifiable for ConcatAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3080,114 +3779,63 @@ impl flatbuffers::Verifiable for ConvAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "dilations",
-                Self::VT_DILATIONS,
-                false,
-            )?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConvAttrsArgs<'a> {
-    pub auto_pad: AutoPad,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub groups: u32,
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct ConcatAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for ConvAttrsArgs<'a> {
+impl<'a> Default for ConcatAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConvAttrsArgs {
-            auto_pad: AutoPad::Same,
-            pads: None,
-            groups: 0,
-            strides: None,
-            dilations: None,
-        }
+        ConcatAttrsArgs { axis: 0 }
     }
 }
 
-pub struct ConvAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConcatAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
-        self.fbb_
-            .push_slot::<AutoPad>(ConvAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_PADS, pads);
-    }
-    #[inline]
-    pub fn add_groups(&mut self, groups: u32) {
-        self.fbb_.push_slot::<u32>(ConvAttrs::VT_GROUPS, groups, 0);
-    }
-    #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_STRIDES, strides);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConcatAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_dilations(
-        &mut self,
-        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_DILATIONS, dilations);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(ConcatAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ConvAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ConcatAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConvAttrsBuilder {
+        ConcatAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConvAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConvAttrs<'_> {
+impl core::fmt::Debug for ConcatAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConvAttrs");
-        ds.field("auto_pad", &self.auto_pad());
-        ds.field("pads", &self.pads());
-        ds.field("groups", &self.groups());
-        ds.field("strides", &self.strides());
-        ds.field("dilations", &self.dilations());
+        let mut ds = f.debug_struct("ConcatAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum ConvTransposeAttrsOffset {}
+pub enum IntScalarOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConvTransposeAttrs<'a> {
+pub struct IntScalar<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
-    type Inner = ConvTransposeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
+    type Inner = IntScalar<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3196,71 +3844,33 @@ impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
     }
 }
 
-impl<'a> ConvTransposeAttrs<'a> {
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 4;
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+impl<'a> IntScalar<'a> {
+    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConvTransposeAttrs { _tab: table }
+        IntScalar { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConvTransposeAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'bldr>> {
-        let mut builder = ConvTransposeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        builder.add_auto_pad(args.auto_pad);
+        args: &'args IntScalarArgs,
+    ) -> flatbuffers::WIPOffset<IntScalar<'bldr>> {
+        let mut builder = IntScalarBuilder::new(_fbb);
+        builder.add_value(args.value);
         builder.finish()
     }
 
     #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn value(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvTransposeAttrs::VT_STRIDES,
-                    None,
-                )
-        }
+        unsafe { self._tab.get::<i32>(IntScalar::VT_VALUE, Some(0)).unwrap() }
     }
-    #[inline]
-    pub fn auto_pad(&self) -> AutoPad {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, Some(AutoPad::NotSet))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvTransposeAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-}
-
-impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
+}
+
+impl flatbuffers::Verifiable for IntScalar<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3268,92 +3878,61 @@ impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
+            .visit_field::<i32>("value", Self::VT_VALUE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConvTransposeAttrsArgs<'a> {
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub auto_pad: AutoPad,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct IntScalarArgs {
+    pub value: i32,
 }
-impl<'a> Default for ConvTransposeAttrsArgs<'a> {
+impl<'a> Default for IntScalarArgs {
     #[inline]
     fn default() -> Self {
-        ConvTransposeAttrsArgs {
-            strides: None,
-            auto_pad: AutoPad::NotSet,
-            pads: None,
-        }
+        IntScalarArgs { value: 0 }
     }
 }
 
-pub struct ConvTransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct IntScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvTransposeAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_STRIDES, strides);
-    }
-    #[inline]
-    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
-        self.fbb_
-            .push_slot::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, auto_pad, AutoPad::NotSet);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IntScalarBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_PADS, pads);
+    pub fn add_value(&mut self, value: i32) {
+        self.fbb_.push_slot::<i32>(IntScalar::VT_VALUE, value, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ConvTransposeAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IntScalarBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConvTransposeAttrsBuilder {
+        IntScalarBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<IntScalar<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConvTransposeAttrs<'_> {
+impl core::fmt::Debug for IntScalar<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConvTransposeAttrs");
-        ds.field("strides", &self.strides());
-        ds.field("auto_pad", &self.auto_pad());
-        ds.field("pads", &self.pads());
+        let mut ds = f.debug_struct("IntScalar");
+        ds.field("value", &self.value());
         ds.finish()
     }
 }
-pub enum EinsumAttrsOffset {}
+pub enum FloatScalarOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct EinsumAttrs<'a> {
+pub struct FloatScalar<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for EinsumAttrs<'a> {
-    type Inner = EinsumAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
+    type Inner = FloatScalar<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3362,38 +3941,37 @@ impl<'a> flatbuffers::Follow<'a> for EinsumAttrs<'a> {
     }
 }
 
-impl<'a> EinsumAttrs<'a> {
-    pub const VT_EQUATION: flatbuffers::VOffsetT = 4;
+impl<'a> FloatScalar<'a> {
+    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        EinsumAttrs { _tab: table }
+        FloatScalar { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args EinsumAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<EinsumAttrs<'bldr>> {
-        let mut builder = EinsumAttrsBuilder::new(_fbb);
-        if let Some(x) = args.equation {
-            builder.add_equation(x);
-        }
+        args: &'args FloatScalarArgs,
+    ) -> flatbuffers::WIPOffset<FloatScalar<'bldr>> {
+        let mut builder = FloatScalarBuilder::new(_fbb);
+        builder.add_value(args.value);
         builder.finish()
     }
 
     #[inline]
-    pub fn equation(&self) -> Option<&'a str> {
+    pub fn value(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(EinsumAttrs::VT_EQUATION, None)
+                .get::<f32>(FloatScalar::VT_VALUE, Some(0.0))
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for EinsumAttrs<'_> {
+impl flatbuffers::Verifiable for FloatScalar<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3401,68 +3979,64 @@ impl flatbuffers::Verifiable for EinsumAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                "equation",
-                Self::VT_EQUATION,
-                false,
-            )?
+            .visit_field::<f32>("value", Self::VT_VALUE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct EinsumAttrsArgs<'a> {
-    pub equation: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct FloatScalarArgs {
+    pub value: f32,
 }
-impl<'a> Default for EinsumAttrsArgs<'a> {
+impl<'a> Default for FloatScalarArgs {
     #[inline]
     fn default() -> Self {
-        EinsumAttrsArgs { equation: None }
+        FloatScalarArgs { value: 0.0 }
     }
 }
 
-pub struct EinsumAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct FloatScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EinsumAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FloatScalarBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_equation(&mut self, equation: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_value(&mut self, value: f32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(EinsumAttrs::VT_EQUATION, equation);
+            .push_slot::<f32>(FloatScalar::VT_VALUE, value, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> EinsumAttrsBuilder<'a, 'b, A> {
+    ) -> FloatScalarBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        EinsumAttrsBuilder {
+        FloatScalarBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<EinsumAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FloatScalar<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for EinsumAttrs<'_> {
+impl core::fmt::Debug for FloatScalar<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("EinsumAttrs");
-        ds.field("equation", &self.equation());
+        let mut ds = f.debug_struct("FloatScalar");
+        ds.field("value", &self.value());
         ds.finish()
     }
 }
-pub enum EluAttrsOffset {}
+pub enum ConstantOfShapeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct EluAttrs<'a> {
+pub struct ConstantOfShapeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
-    type Inner = EluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
+    type Inner = ConstantOfShapeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3471,33 +4045,82 @@ impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
     }
 }
 
-impl<'a> EluAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+impl<'a> ConstantOfShapeAttrs<'a> {
+    pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        EluAttrs { _tab: table }
+        ConstantOfShapeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args EluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<EluAttrs<'bldr>> {
-        let mut builder = EluAttrsBuilder::new(_fbb);
-        builder.add_alpha(args.alpha);
+        args: &'args ConstantOfShapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'bldr>> {
+        let mut builder = ConstantOfShapeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.value {
+            builder.add_value(x);
+        }
+        builder.add_value_type(args.value_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn value_type(&self) -> Scalar {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(EluAttrs::VT_ALPHA, Some(0.0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<Scalar>(ConstantOfShapeAttrs::VT_VALUE_TYPE, Some(Scalar::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn value(&self) -> flatbuffers::Table<'a> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    ConstantOfShapeAttrs::VT_VALUE,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn value_as_int_scalar(&self) -> Option<IntScalar<'a>> {
+        if self.value_type() == Scalar::IntScalar {
+            let u = self.value();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { IntScalar::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn value_as_float_scalar(&self) -> Option<FloatScalar<'a>> {
+        if self.value_type() == Scalar::FloatScalar {
+            let u = self.value();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { FloatScalar::init_from_table(u) })
+        } else {
+            None
+        }
     }
 }
 
-impl flatbuffers::Verifiable for EluAttrs<'_> {
+impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3505,61 +4128,123 @@ impl flatbuffers::Verifiable for EluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_union::<Scalar, _>(
+                "value_type",
+                Self::VT_VALUE_TYPE,
+                "value",
+                Self::VT_VALUE,
+                true,
+                |key, v, pos| match key {
+                    Scalar::IntScalar => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntScalar>>(
+                            "Scalar::IntScalar",
+                            pos,
+                        ),
+                    Scalar::FloatScalar => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatScalar>>(
+                            "Scalar::FloatScalar",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct EluAttrsArgs {
-    pub alpha: f32,
+pub struct ConstantOfShapeAttrsArgs {
+    pub value_type: Scalar,
+    pub value: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for EluAttrsArgs {
+impl<'a> Default for ConstantOfShapeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        EluAttrsArgs { alpha: 0.0 }
-    }
-}
-
-pub struct EluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        ConstantOfShapeAttrsArgs {
+            value_type: Scalar::NONE,
+            value: None, // required field
+        }
+    }
+}
+
+pub struct ConstantOfShapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_.push_slot::<f32>(EluAttrs::VT_ALPHA, alpha, 0.0);
+    pub fn add_value_type(&mut self, value_type: Scalar) {
+        self.fbb_.push_slot::<Scalar>(
+            ConstantOfShapeAttrs::VT_VALUE_TYPE,
+            value_type,
+            Scalar::NONE,
+        );
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EluAttrsBuilder<'a, 'b, A> {
+    pub fn add_value(&mut self, value: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantOfShapeAttrs::VT_VALUE, value);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        EluAttrsBuilder {
+        ConstantOfShapeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<EluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, ConstantOfShapeAttrs::VT_VALUE, "value");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for EluAttrs<'_> {
+impl core::fmt::Debug for ConstantOfShapeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("EluAttrs");
-        ds.field("alpha", &self.alpha());
+        let mut ds = f.debug_struct("ConstantOfShapeAttrs");
+        ds.field("value_type", &self.value_type());
+        match self.value_type() {
+            Scalar::IntScalar => {
+                if let Some(x) = self.value_as_int_scalar() {
+                    ds.field("value", &x)
+                } else {
+                    ds.field(
+                        "value",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            Scalar::FloatScalar => {
+                if let Some(x) = self.value_as_float_scalar() {
+                    ds.field("value", &x)
+                } else {
+                    ds.field(
+                        "value",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("value", &x)
+            }
+        };
         ds.finish()
     }
 }
-pub enum FlattenAttrsOffset {}
+pub enum ConvAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FlattenAttrs<'a> {
+pub struct ConvAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
-    type Inner = FlattenAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
+    type Inner = ConvAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3568,37 +4253,97 @@ impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
     }
 }
 
-impl<'a> FlattenAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> ConvAttrs<'a> {
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 4;
+    pub const VT_PADS: flatbuffers::VOffsetT = 6;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FlattenAttrs { _tab: table }
+        ConvAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args FlattenAttrsArgs,
-    ) -> flatbuffers::WIPOffset<FlattenAttrs<'bldr>> {
-        let mut builder = FlattenAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args ConvAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ConvAttrs<'bldr>> {
+        let mut builder = ConvAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn auto_pad(&self) -> AutoPad {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(FlattenAttrs::VT_AXIS, Some(0))
+                .get::<AutoPad>(ConvAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(ConvAttrs::VT_GROUPS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for FlattenAttrs<'_> {
+impl flatbuffers::Verifiable for ConvAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3606,63 +4351,114 @@ impl flatbuffers::Verifiable for FlattenAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct FlattenAttrsArgs {
-    pub axis: i32,
+pub struct ConvAttrsArgs<'a> {
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for FlattenAttrsArgs {
+impl<'a> Default for ConvAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        FlattenAttrsArgs { axis: 0 }
+        ConvAttrsArgs {
+            auto_pad: AutoPad::Same,
+            pads: None,
+            groups: 0,
+            strides: None,
+            dilations: None,
+        }
     }
 }
 
-pub struct FlattenAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConvAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FlattenAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(FlattenAttrs::VT_AXIS, axis, 0);
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
+        self.fbb_
+            .push_slot::<AutoPad>(ConvAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> FlattenAttrsBuilder<'a, 'b, A> {
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_.push_slot::<u32>(ConvAttrs::VT_GROUPS, groups, 0);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_DILATIONS, dilations);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ConvAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        FlattenAttrsBuilder {
+        ConvAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FlattenAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConvAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FlattenAttrs<'_> {
+impl core::fmt::Debug for ConvAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FlattenAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("ConvAttrs");
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("groups", &self.groups());
+        ds.field("strides", &self.strides());
+        ds.field("dilations", &self.dilations());
         ds.finish()
     }
 }
-pub enum LayerNormalizationAttrsOffset {}
+pub enum ConvTransposeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LayerNormalizationAttrs<'a> {
+pub struct ConvTransposeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
-    type Inner = LayerNormalizationAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
+    type Inner = ConvTransposeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3671,50 +4467,101 @@ impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
     }
 }
 
-impl<'a> LayerNormalizationAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
+impl<'a> ConvTransposeAttrs<'a> {
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LayerNormalizationAttrs { _tab: table }
+        ConvTransposeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args LayerNormalizationAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'bldr>> {
-        let mut builder = LayerNormalizationAttrsBuilder::new(_fbb);
-        builder.add_epsilon(args.epsilon);
-        builder.add_axis(args.axis);
+        args: &'args ConvTransposeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'bldr>> {
+        let mut builder = ConvTransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(LayerNormalizationAttrs::VT_AXIS, Some(0))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn auto_pad(&self) -> AutoPad {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, Some(AutoPad::NotSet))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn epsilon(&self) -> f32 {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(LayerNormalizationAttrs::VT_EPSILON, Some(0.0))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ConvTransposeAttrs::VT_GROUPS, Some(1))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
+impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3722,75 +4569,119 @@ impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct LayerNormalizationAttrsArgs {
-    pub axis: i32,
-    pub epsilon: f32,
+pub struct ConvTransposeAttrsArgs<'a> {
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for LayerNormalizationAttrsArgs {
+impl<'a> Default for ConvTransposeAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        LayerNormalizationAttrsArgs {
-            axis: 0,
-            epsilon: 0.0,
+        ConvTransposeAttrsArgs {
+            strides: None,
+            auto_pad: AutoPad::NotSet,
+            pads: None,
+            groups: 1,
+            dilations: None,
         }
     }
 }
 
-pub struct LayerNormalizationAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConvTransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LayerNormalizationAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvTransposeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot::<i32>(LayerNormalizationAttrs::VT_AXIS, axis, 0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_STRIDES, strides);
     }
     #[inline]
-    pub fn add_epsilon(&mut self, epsilon: f32) {
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
         self.fbb_
-            .push_slot::<f32>(LayerNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
+            .push_slot::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, auto_pad, AutoPad::NotSet);
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_
+            .push_slot::<u32>(ConvTransposeAttrs::VT_GROUPS, groups, 1);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_DILATIONS,
+            dilations,
+        );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> LayerNormalizationAttrsBuilder<'a, 'b, A> {
+    ) -> ConvTransposeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        LayerNormalizationAttrsBuilder {
+        ConvTransposeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LayerNormalizationAttrs<'_> {
+impl core::fmt::Debug for ConvTransposeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LayerNormalizationAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("epsilon", &self.epsilon());
+        let mut ds = f.debug_struct("ConvTransposeAttrs");
+        ds.field("strides", &self.strides());
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("groups", &self.groups());
+        ds.field("dilations", &self.dilations());
         ds.finish()
     }
 }
-pub enum GatherAttrsOffset {}
+pub enum CumSumAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GatherAttrs<'a> {
+pub struct CumSumAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
-    type Inner = GatherAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CumSumAttrs<'a> {
+    type Inner = CumSumAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3799,33 +4690,50 @@ impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
     }
 }
 
-impl<'a> GatherAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> CumSumAttrs<'a> {
+    pub const VT_EXCLUSIVE: flatbuffers::VOffsetT = 4;
+    pub const VT_REVERSE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GatherAttrs { _tab: table }
+        CumSumAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GatherAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GatherAttrs<'bldr>> {
-        let mut builder = GatherAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args CumSumAttrsArgs,
+    ) -> flatbuffers::WIPOffset<CumSumAttrs<'bldr>> {
+        let mut builder = CumSumAttrsBuilder::new(_fbb);
+        builder.add_reverse(args.reverse);
+        builder.add_exclusive(args.exclusive);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn exclusive(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(GatherAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<bool>(CumSumAttrs::VT_EXCLUSIVE, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn reverse(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(CumSumAttrs::VT_REVERSE, Some(false))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for GatherAttrs<'_> {
+impl flatbuffers::Verifiable for CumSumAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3833,63 +4741,75 @@ impl flatbuffers::Verifiable for GatherAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("exclusive", Self::VT_EXCLUSIVE, false)?
+            .visit_field::<bool>("reverse", Self::VT_REVERSE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GatherAttrsArgs {
-    pub axis: i32,
+pub struct CumSumAttrsArgs {
+    pub exclusive: bool,
+    pub reverse: bool,
 }
-impl<'a> Default for GatherAttrsArgs {
+impl<'a> Default for CumSumAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GatherAttrsArgs { axis: 0 }
+        CumSumAttrsArgs {
+            exclusive: false,
+            reverse: false,
+        }
     }
 }
 
-pub struct GatherAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CumSumAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CumSumAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(GatherAttrs::VT_AXIS, axis, 0);
+    pub fn add_exclusive(&mut self, exclusive: bool) {
+        self.fbb_
+            .push_slot::<bool>(CumSumAttrs::VT_EXCLUSIVE, exclusive, false);
+    }
+    #[inline]
+    pub fn add_reverse(&mut self, reverse: bool) {
+        self.fbb_
+            .push_slot::<bool>(CumSumAttrs::VT_REVERSE, reverse, false);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> GatherAttrsBuilder<'a, 'b, A> {
+    ) -> CumSumAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GatherAttrsBuilder {
+        CumSumAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GatherAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CumSumAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GatherAttrs<'_> {
+impl core::fmt::Debug for CumSumAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GatherAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("CumSumAttrs");
+        ds.field("exclusive", &self.exclusive());
+        ds.field("reverse", &self.reverse());
         ds.finish()
     }
 }
-pub enum GatherNDAttrsOffset {}
+pub enum DepthToSpaceAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GatherNDAttrs<'a> {
+pub struct DepthToSpaceAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
-    type Inner = GatherNDAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for DepthToSpaceAttrs<'a> {
+    type Inner = DepthToSpaceAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3898,37 +4818,50 @@ impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
     }
 }
 
-impl<'a> GatherNDAttrs<'a> {
-    pub const VT_BATCH_DIMS: flatbuffers::VOffsetT = 4;
+impl<'a> DepthToSpaceAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GatherNDAttrs { _tab: table }
+        DepthToSpaceAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GatherNDAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GatherNDAttrs<'bldr>> {
-        let mut builder = GatherNDAttrsBuilder::new(_fbb);
-        builder.add_batch_dims(args.batch_dims);
+        args: &'args DepthToSpaceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<DepthToSpaceAttrs<'bldr>> {
+        let mut builder = DepthToSpaceAttrsBuilder::new(_fbb);
+        builder.add_block_size(args.block_size);
+        builder.add_mode(args.mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn batch_dims(&self) -> i32 {
+    pub fn mode(&self) -> DepthToSpaceMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(GatherNDAttrs::VT_BATCH_DIMS, Some(0))
+                .get::<DepthToSpaceMode>(DepthToSpaceAttrs::VT_MODE, Some(DepthToSpaceMode::DCR))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn block_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(DepthToSpaceAttrs::VT_BLOCK_SIZE, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
+impl flatbuffers::Verifiable for DepthToSpaceAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3936,64 +4869,78 @@ impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("batch_dims", Self::VT_BATCH_DIMS, false)?
+            .visit_field::<DepthToSpaceMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GatherNDAttrsArgs {
-    pub batch_dims: i32,
+pub struct DepthToSpaceAttrsArgs {
+    pub mode: DepthToSpaceMode,
+    pub block_size: u32,
 }
-impl<'a> Default for GatherNDAttrsArgs {
+impl<'a> Default for DepthToSpaceAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GatherNDAttrsArgs { batch_dims: 0 }
+        DepthToSpaceAttrsArgs {
+            mode: DepthToSpaceMode::DCR,
+            block_size: 0,
+        }
     }
 }
 
-pub struct GatherNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct DepthToSpaceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherNDAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DepthToSpaceAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_batch_dims(&mut self, batch_dims: i32) {
+    pub fn add_mode(&mut self, mode: DepthToSpaceMode) {
+        self.fbb_.push_slot::<DepthToSpaceMode>(
+            DepthToSpaceAttrs::VT_MODE,
+            mode,
+            DepthToSpaceMode::DCR,
+        );
+    }
+    #[inline]
+    pub fn add_block_size(&mut self, block_size: u32) {
         self.fbb_
-            .push_slot::<i32>(GatherNDAttrs::VT_BATCH_DIMS, batch_dims, 0);
+            .push_slot::<u32>(DepthToSpaceAttrs::VT_BLOCK_SIZE, block_size, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> GatherNDAttrsBuilder<'a, 'b, A> {
+    ) -> DepthToSpaceAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GatherNDAttrsBuilder {
+        DepthToSpaceAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GatherNDAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<DepthToSpaceAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GatherNDAttrs<'_> {
+impl core::fmt::Debug for DepthToSpaceAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GatherNDAttrs");
-        ds.field("batch_dims", &self.batch_dims());
+        let mut ds = f.debug_struct("DepthToSpaceAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("block_size", &self.block_size());
         ds.finish()
     }
 }
-pub enum GeluAttrsOffset {}
+pub enum DFTAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GeluAttrs<'a> {
+pub struct DFTAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
-    type Inner = GeluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for DFTAttrs<'a> {
+    type Inner = DFTAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4002,75 +4949,141 @@ impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
     }
 }
 
-impl<'a> GeluAttrs<'a> {
+impl<'a> DFTAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_INVERSE: flatbuffers::VOffsetT = 6;
+    pub const VT_ONESIDED: flatbuffers::VOffsetT = 8;
+
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GeluAttrs { _tab: table }
+        DFTAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        _args: &'args GeluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GeluAttrs<'bldr>> {
-        let mut builder = GeluAttrsBuilder::new(_fbb);
+        args: &'args DFTAttrsArgs,
+    ) -> flatbuffers::WIPOffset<DFTAttrs<'bldr>> {
+        let mut builder = DFTAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_onesided(args.onesided);
+        builder.add_inverse(args.inverse);
         builder.finish()
     }
-}
 
-impl flatbuffers::Verifiable for GeluAttrs<'_> {
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(DFTAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn inverse(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(DFTAttrs::VT_INVERSE, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn onesided(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(DFTAttrs::VT_ONESIDED, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for DFTAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?.finish();
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("inverse", Self::VT_INVERSE, false)?
+            .visit_field::<bool>("onesided", Self::VT_ONESIDED, false)?
+            .finish();
         Ok(())
     }
 }
-pub struct GeluAttrsArgs {}
-impl<'a> Default for GeluAttrsArgs {
+pub struct DFTAttrsArgs {
+    pub axis: i32,
+    pub inverse: bool,
+    pub onesided: bool,
+}
+impl<'a> Default for DFTAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GeluAttrsArgs {}
+        DFTAttrsArgs {
+            axis: 0,
+            inverse: false,
+            onesided: false,
+        }
     }
 }
 
-pub struct GeluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct DFTAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GeluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DFTAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GeluAttrsBuilder<'a, 'b, A> {
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(DFTAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_inverse(&mut self, inverse: bool) {
+        self.fbb_
+            .push_slot::<bool>(DFTAttrs::VT_INVERSE, inverse, false);
+    }
+    #[inline]
+    pub fn add_onesided(&mut self, onesided: bool) {
+        self.fbb_
+            .push_slot::<bool>(DFTAttrs::VT_ONESIDED, onesided, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DFTAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GeluAttrsBuilder {
+        DFTAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GeluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<DFTAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GeluAttrs<'_> {
+impl core::fmt::Debug for DFTAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GeluAttrs");
+        let mut ds = f.debug_struct("DFTAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("inverse", &self.inverse());
+        ds.field("onesided", &self.onesided());
         ds.finish()
     }
 }
-pub enum GemmAttrsOffset {}
+pub enum EinsumAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GemmAttrs<'a> {
+pub struct EinsumAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
-    type Inner = GemmAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for EinsumAttrs<'a> {
+    type Inner = EinsumAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4079,72 +5092,38 @@ impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
     }
 }
 
-impl<'a> GemmAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
-    pub const VT_BETA: flatbuffers::VOffsetT = 6;
-    pub const VT_TRANSPOSE_A: flatbuffers::VOffsetT = 8;
-    pub const VT_TRANSPOSE_B: flatbuffers::VOffsetT = 10;
+impl<'a> EinsumAttrs<'a> {
+    pub const VT_EQUATION: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GemmAttrs { _tab: table }
+        EinsumAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GemmAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GemmAttrs<'bldr>> {
-        let mut builder = GemmAttrsBuilder::new(_fbb);
-        builder.add_beta(args.beta);
-        builder.add_alpha(args.alpha);
-        builder.add_transpose_b(args.transpose_b);
-        builder.add_transpose_a(args.transpose_a);
+        args: &'args EinsumAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<EinsumAttrs<'bldr>> {
+        let mut builder = EinsumAttrsBuilder::new(_fbb);
+        if let Some(x) = args.equation {
+            builder.add_equation(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(GemmAttrs::VT_ALPHA, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn beta(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(GemmAttrs::VT_BETA, Some(0.0)).unwrap() }
-    }
-    #[inline]
-    pub fn transpose_a(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(GemmAttrs::VT_TRANSPOSE_A, Some(false))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn transpose_b(&self) -> bool {
+    pub fn equation(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(GemmAttrs::VT_TRANSPOSE_B, Some(false))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<&str>>(EinsumAttrs::VT_EQUATION, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for GemmAttrs<'_> {
+impl flatbuffers::Verifiable for EinsumAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4152,89 +5131,68 @@ impl flatbuffers::Verifiable for GemmAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
-            .visit_field::<f32>("beta", Self::VT_BETA, false)?
-            .visit_field::<bool>("transpose_a", Self::VT_TRANSPOSE_A, false)?
-            .visit_field::<bool>("transpose_b", Self::VT_TRANSPOSE_B, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "equation",
+                Self::VT_EQUATION,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct GemmAttrsArgs {
-    pub alpha: f32,
-    pub beta: f32,
-    pub transpose_a: bool,
-    pub transpose_b: bool,
+pub struct EinsumAttrsArgs<'a> {
+    pub equation: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for GemmAttrsArgs {
+impl<'a> Default for EinsumAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        GemmAttrsArgs {
-            alpha: 0.0,
-            beta: 0.0,
-            transpose_a: false,
-            transpose_b: false,
-        }
+        EinsumAttrsArgs { equation: None }
     }
 }
 
-pub struct GemmAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct EinsumAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GemmAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_.push_slot::<f32>(GemmAttrs::VT_ALPHA, alpha, 0.0);
-    }
-    #[inline]
-    pub fn add_beta(&mut self, beta: f32) {
-        self.fbb_.push_slot::<f32>(GemmAttrs::VT_BETA, beta, 0.0);
-    }
-    #[inline]
-    pub fn add_transpose_a(&mut self, transpose_a: bool) {
-        self.fbb_
-            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_A, transpose_a, false);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EinsumAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_transpose_b(&mut self, transpose_b: bool) {
+    pub fn add_equation(&mut self, equation: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_B, transpose_b, false);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(EinsumAttrs::VT_EQUATION, equation);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GemmAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> EinsumAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GemmAttrsBuilder {
+        EinsumAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GemmAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<EinsumAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GemmAttrs<'_> {
+impl core::fmt::Debug for EinsumAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GemmAttrs");
-        ds.field("alpha", &self.alpha());
-        ds.field("beta", &self.beta());
-        ds.field("transpose_a", &self.transpose_a());
-        ds.field("transpose_b", &self.transpose_b());
+        let mut ds = f.debug_struct("EinsumAttrs");
+        ds.field("equation", &self.equation());
         ds.finish()
     }
 }
-pub enum GRUAttrsOffset {}
+pub enum EluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GRUAttrs<'a> {
+pub struct EluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
-    type Inner = GRUAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
+    type Inner = EluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4243,63 +5201,33 @@ impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
     }
 }
 
-impl<'a> GRUAttrs<'a> {
-    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
-    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
-    pub const VT_LINEAR_BEFORE_RESET: flatbuffers::VOffsetT = 8;
+impl<'a> EluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GRUAttrs { _tab: table }
+        EluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GRUAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GRUAttrs<'bldr>> {
-        let mut builder = GRUAttrsBuilder::new(_fbb);
-        builder.add_hidden_size(args.hidden_size);
-        builder.add_linear_before_reset(args.linear_before_reset);
-        builder.add_direction(args.direction);
+        args: &'args EluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<EluAttrs<'bldr>> {
+        let mut builder = EluAttrsBuilder::new(_fbb);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn direction(&self) -> RNNDirection {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<RNNDirection>(GRUAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn hidden_size(&self) -> u32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<u32>(GRUAttrs::VT_HIDDEN_SIZE, Some(0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn linear_before_reset(&self) -> bool {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, Some(false))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<f32>(EluAttrs::VT_ALPHA, Some(0.0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for GRUAttrs<'_> {
+impl flatbuffers::Verifiable for EluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4307,85 +5235,61 @@ impl flatbuffers::Verifiable for GRUAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
-            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
-            .visit_field::<bool>("linear_before_reset", Self::VT_LINEAR_BEFORE_RESET, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GRUAttrsArgs {
-    pub direction: RNNDirection,
-    pub hidden_size: u32,
-    pub linear_before_reset: bool,
+pub struct EluAttrsArgs {
+    pub alpha: f32,
 }
-impl<'a> Default for GRUAttrsArgs {
+impl<'a> Default for EluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GRUAttrsArgs {
-            direction: RNNDirection::Forward,
-            hidden_size: 0,
-            linear_before_reset: false,
-        }
+        EluAttrsArgs { alpha: 0.0 }
     }
 }
 
-pub struct GRUAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct EluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GRUAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_direction(&mut self, direction: RNNDirection) {
-        self.fbb_.push_slot::<RNNDirection>(
-            GRUAttrs::VT_DIRECTION,
-            direction,
-            RNNDirection::Forward,
-        );
-    }
-    #[inline]
-    pub fn add_hidden_size(&mut self, hidden_size: u32) {
-        self.fbb_
-            .push_slot::<u32>(GRUAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EluAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_linear_before_reset(&mut self, linear_before_reset: bool) {
-        self.fbb_
-            .push_slot::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, linear_before_reset, false);
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(EluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GRUAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EluAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GRUAttrsBuilder {
+        EluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GRUAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<EluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GRUAttrs<'_> {
+impl core::fmt::Debug for EluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GRUAttrs");
-        ds.field("direction", &self.direction());
-        ds.field("hidden_size", &self.hidden_size());
-        ds.field("linear_before_reset", &self.linear_before_reset());
+        let mut ds = f.debug_struct("EluAttrs");
+        ds.field("alpha", &self.alpha());
         ds.finish()
     }
 }
-pub enum HardSigmoidAttrsOffset {}
+pub enum FlattenAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct HardSigmoidAttrs<'a> {
+pub struct FlattenAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
-    type Inner = HardSigmoidAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
+    type Inner = FlattenAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4394,50 +5298,37 @@ impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
     }
 }
 
-impl<'a> HardSigmoidAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
-    pub const VT_BETA: flatbuffers::VOffsetT = 6;
+impl<'a> FlattenAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        HardSigmoidAttrs { _tab: table }
+        FlattenAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args HardSigmoidAttrsArgs,
-    ) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'bldr>> {
-        let mut builder = HardSigmoidAttrsBuilder::new(_fbb);
-        builder.add_beta(args.beta);
-        builder.add_alpha(args.alpha);
+        args: &'args FlattenAttrsArgs,
+    ) -> flatbuffers::WIPOffset<FlattenAttrs<'bldr>> {
+        let mut builder = FlattenAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(HardSigmoidAttrs::VT_ALPHA, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn beta(&self) -> f32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(HardSigmoidAttrs::VT_BETA, Some(0.0))
+                .get::<i32>(FlattenAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
+impl flatbuffers::Verifiable for FlattenAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4445,75 +5336,63 @@ impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
-            .visit_field::<f32>("beta", Self::VT_BETA, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct HardSigmoidAttrsArgs {
-    pub alpha: f32,
-    pub beta: f32,
+pub struct FlattenAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for HardSigmoidAttrsArgs {
+impl<'a> Default for FlattenAttrsArgs {
     #[inline]
     fn default() -> Self {
-        HardSigmoidAttrsArgs {
-            alpha: 0.0,
-            beta: 0.0,
-        }
+        FlattenAttrsArgs { axis: 0 }
     }
 }
 
-pub struct HardSigmoidAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct FlattenAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HardSigmoidAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_
-            .push_slot::<f32>(HardSigmoidAttrs::VT_ALPHA, alpha, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FlattenAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_beta(&mut self, beta: f32) {
-        self.fbb_
-            .push_slot::<f32>(HardSigmoidAttrs::VT_BETA, beta, 0.0);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(FlattenAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> HardSigmoidAttrsBuilder<'a, 'b, A> {
+    ) -> FlattenAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        HardSigmoidAttrsBuilder {
+        FlattenAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FlattenAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for HardSigmoidAttrs<'_> {
+impl core::fmt::Debug for FlattenAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("HardSigmoidAttrs");
-        ds.field("alpha", &self.alpha());
-        ds.field("beta", &self.beta());
+        let mut ds = f.debug_struct("FlattenAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum LeakyReluAttrsOffset {}
+pub enum LayerNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LeakyReluAttrs<'a> {
+pub struct LayerNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
-    type Inner = LeakyReluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
+    type Inner = LayerNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4522,37 +5401,50 @@ impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
     }
 }
 
-impl<'a> LeakyReluAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+impl<'a> LayerNormalizationAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LeakyReluAttrs { _tab: table }
+        LayerNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args LeakyReluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LeakyReluAttrs<'bldr>> {
-        let mut builder = LeakyReluAttrsBuilder::new(_fbb);
-        builder.add_alpha(args.alpha);
+        args: &'args LayerNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'bldr>> {
+        let mut builder = LayerNormalizationAttrsBuilder::new(_fbb);
+        builder.add_epsilon(args.epsilon);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(LeakyReluAttrs::VT_ALPHA, Some(0.0))
+                .get::<i32>(LayerNormalizationAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn epsilon(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(LayerNormalizationAttrs::VT_EPSILON, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
+impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4560,64 +5452,75 @@ impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
             .finish();
         Ok(())
     }
 }
-pub struct LeakyReluAttrsArgs {
-    pub alpha: f32,
+pub struct LayerNormalizationAttrsArgs {
+    pub axis: i32,
+    pub epsilon: f32,
 }
-impl<'a> Default for LeakyReluAttrsArgs {
+impl<'a> Default for LayerNormalizationAttrsArgs {
     #[inline]
     fn default() -> Self {
-        LeakyReluAttrsArgs { alpha: 0.0 }
+        LayerNormalizationAttrsArgs {
+            axis: 0,
+            epsilon: 0.0,
+        }
     }
 }
 
-pub struct LeakyReluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LayerNormalizationAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LeakyReluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LayerNormalizationAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
+    pub fn add_axis(&mut self, axis: i32) {
         self.fbb_
-            .push_slot::<f32>(LeakyReluAttrs::VT_ALPHA, alpha, 0.0);
+            .push_slot::<i32>(LayerNormalizationAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_epsilon(&mut self, epsilon: f32) {
+        self.fbb_
+            .push_slot::<f32>(LayerNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> LeakyReluAttrsBuilder<'a, 'b, A> {
+    ) -> LayerNormalizationAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        LeakyReluAttrsBuilder {
+        LayerNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LeakyReluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LeakyReluAttrs<'_> {
+impl core::fmt::Debug for LayerNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LeakyReluAttrs");
-        ds.field("alpha", &self.alpha());
+        let mut ds = f.debug_struct("LayerNormalizationAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("epsilon", &self.epsilon());
         ds.finish()
     }
 }
-pub enum LSTMAttrsOffset {}
+pub enum GatherAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LSTMAttrs<'a> {
+pub struct GatherAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
-    type Inner = LSTMAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
+    type Inner = GatherAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4626,50 +5529,33 @@ impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
     }
 }
 
-impl<'a> LSTMAttrs<'a> {
-    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
-    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+impl<'a> GatherAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LSTMAttrs { _tab: table }
+        GatherAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args LSTMAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LSTMAttrs<'bldr>> {
-        let mut builder = LSTMAttrsBuilder::new(_fbb);
-        builder.add_hidden_size(args.hidden_size);
-        builder.add_direction(args.direction);
+        args: &'args GatherAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GatherAttrs<'bldr>> {
+        let mut builder = GatherAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn direction(&self) -> RNNDirection {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<RNNDirection>(LSTMAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn hidden_size(&self) -> u32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, Some(0))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(GatherAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for LSTMAttrs<'_> {
+impl flatbuffers::Verifiable for GatherAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4677,76 +5563,63 @@ impl flatbuffers::Verifiable for LSTMAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
-            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct LSTMAttrsArgs {
-    pub direction: RNNDirection,
-    pub hidden_size: u32,
+pub struct GatherAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for LSTMAttrsArgs {
+impl<'a> Default for GatherAttrsArgs {
     #[inline]
     fn default() -> Self {
-        LSTMAttrsArgs {
-            direction: RNNDirection::Forward,
-            hidden_size: 0,
-        }
+        GatherAttrsArgs { axis: 0 }
     }
 }
 
-pub struct LSTMAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GatherAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LSTMAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_direction(&mut self, direction: RNNDirection) {
-        self.fbb_.push_slot::<RNNDirection>(
-            LSTMAttrs::VT_DIRECTION,
-            direction,
-            RNNDirection::Forward,
-        );
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_hidden_size(&mut self, hidden_size: u32) {
-        self.fbb_
-            .push_slot::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(GatherAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LSTMAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> GatherAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        LSTMAttrsBuilder {
+        GatherAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LSTMAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GatherAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LSTMAttrs<'_> {
+impl core::fmt::Debug for GatherAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LSTMAttrs");
-        ds.field("direction", &self.direction());
-        ds.field("hidden_size", &self.hidden_size());
+        let mut ds = f.debug_struct("GatherAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum MaxPoolAttrsOffset {}
+pub enum GatherNDAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MaxPoolAttrs<'a> {
+pub struct GatherNDAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
-    type Inner = MaxPoolAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
+    type Inner = GatherNDAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4755,89 +5628,37 @@ impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
     }
 }
 
-impl<'a> MaxPoolAttrs<'a> {
-    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+impl<'a> GatherNDAttrs<'a> {
+    pub const VT_BATCH_DIMS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MaxPoolAttrs { _tab: table }
+        GatherNDAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args MaxPoolAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
-        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.kernel_size {
-            builder.add_kernel_size(x);
-        }
-        builder.add_auto_pad(args.auto_pad);
+        args: &'args GatherNDAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GatherNDAttrs<'bldr>> {
+        let mut builder = GatherNDAttrsBuilder::new(_fbb);
+        builder.add_batch_dims(args.batch_dims);
         builder.finish()
     }
 
     #[inline]
-    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_KERNEL_SIZE,
-                    None,
-                )
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn auto_pad(&self) -> AutoPad {
+    pub fn batch_dims(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
+                .get::<i32>(GatherNDAttrs::VT_BATCH_DIMS, Some(0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_STRIDES,
-                    None,
-                )
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
+impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4845,112 +5666,64 @@ impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "kernel_size",
-                Self::VT_KERNEL_SIZE,
-                true,
-            )?
-            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
+            .visit_field::<i32>("batch_dims", Self::VT_BATCH_DIMS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct MaxPoolAttrsArgs<'a> {
-    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub auto_pad: AutoPad,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct GatherNDAttrsArgs {
+    pub batch_dims: i32,
 }
-impl<'a> Default for MaxPoolAttrsArgs<'a> {
+impl<'a> Default for GatherNDAttrsArgs {
     #[inline]
     fn default() -> Self {
-        MaxPoolAttrsArgs {
-            kernel_size: None, // required field
-            auto_pad: AutoPad::Same,
-            pads: None,
-            strides: None,
-        }
+        GatherNDAttrsArgs { batch_dims: 0 }
     }
 }
 
-pub struct MaxPoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GatherNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MaxPoolAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_kernel_size(
-        &mut self,
-        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            MaxPoolAttrs::VT_KERNEL_SIZE,
-            kernel_size,
-        );
-    }
-    #[inline]
-    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
-        self.fbb_
-            .push_slot::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_PADS, pads);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherNDAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_batch_dims(&mut self, batch_dims: i32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_STRIDES, strides);
+            .push_slot::<i32>(GatherNDAttrs::VT_BATCH_DIMS, batch_dims, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> MaxPoolAttrsBuilder<'a, 'b, A> {
+    ) -> GatherNDAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        MaxPoolAttrsBuilder {
+        GatherNDAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<MaxPoolAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GatherNDAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_
-            .required(o, MaxPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for MaxPoolAttrs<'_> {
+impl core::fmt::Debug for GatherNDAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("MaxPoolAttrs");
-        ds.field("kernel_size", &self.kernel_size());
-        ds.field("auto_pad", &self.auto_pad());
-        ds.field("pads", &self.pads());
-        ds.field("strides", &self.strides());
+        let mut ds = f.debug_struct("GatherNDAttrs");
+        ds.field("batch_dims", &self.batch_dims());
         ds.finish()
     }
 }
-pub enum ModAttrsOffset {}
+pub enum GeluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ModAttrs<'a> {
+pub struct GeluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
-    type Inner = ModAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
+    type Inner = GeluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4959,99 +5732,75 @@ impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
     }
 }
 
-impl<'a> ModAttrs<'a> {
-    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
-
+impl<'a> GeluAttrs<'a> {
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ModAttrs { _tab: table }
+        GeluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ModAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
-        let mut builder = ModAttrsBuilder::new(_fbb);
-        builder.add_fmod(args.fmod);
+        _args: &'args GeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GeluAttrs<'bldr>> {
+        let mut builder = GeluAttrsBuilder::new(_fbb);
         builder.finish()
     }
-
-    #[inline]
-    pub fn fmod(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
-                .unwrap()
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for ModAttrs<'_> {
+impl flatbuffers::Verifiable for GeluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
-            .finish();
+        v.visit_table(pos)?.finish();
         Ok(())
     }
 }
-pub struct ModAttrsArgs {
-    pub fmod: bool,
-}
-impl<'a> Default for ModAttrsArgs {
+pub struct GeluAttrsArgs {}
+impl<'a> Default for GeluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ModAttrsArgs { fmod: false }
+        GeluAttrsArgs {}
     }
 }
 
-pub struct ModAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GeluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ModAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_fmod(&mut self, fmod: bool) {
-        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GeluAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ModAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GeluAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ModAttrsBuilder {
+        GeluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GeluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ModAttrs<'_> {
+impl core::fmt::Debug for GeluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ModAttrs");
-        ds.field("fmod", &self.fmod());
+        let mut ds = f.debug_struct("GeluAttrs");
         ds.finish()
     }
 }
-pub enum NonMaxSuppressionAttrsOffset {}
+pub enum GemmAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct NonMaxSuppressionAttrs<'a> {
+pub struct GemmAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
-    type Inner = NonMaxSuppressionAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
+    type Inner = GemmAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5060,40 +5809,72 @@ impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
     }
 }
 
-impl<'a> NonMaxSuppressionAttrs<'a> {
-    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
+impl<'a> GemmAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_BETA: flatbuffers::VOffsetT = 6;
+    pub const VT_TRANSPOSE_A: flatbuffers::VOffsetT = 8;
+    pub const VT_TRANSPOSE_B: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        NonMaxSuppressionAttrs { _tab: table }
+        GemmAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args NonMaxSuppressionAttrsArgs,
-    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
-        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
-        builder.add_box_order(args.box_order);
+        args: &'args GemmAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GemmAttrs<'bldr>> {
+        let mut builder = GemmAttrsBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.add_alpha(args.alpha);
+        builder.add_transpose_b(args.transpose_b);
+        builder.add_transpose_a(args.transpose_a);
         builder.finish()
     }
 
     #[inline]
-    pub fn box_order(&self) -> NMSBoxOrder {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NMSBoxOrder>(
-                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
-                    Some(NMSBoxOrder::TopLeftBottomRight),
-                )
+                .get::<f32>(GemmAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn beta(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(GemmAttrs::VT_BETA, Some(0.0)).unwrap() }
+    }
+    #[inline]
+    pub fn transpose_a(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GemmAttrs::VT_TRANSPOSE_A, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn transpose_b(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GemmAttrs::VT_TRANSPOSE_B, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
+impl flatbuffers::Verifiable for GemmAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5101,69 +5882,89 @@ impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
+            .visit_field::<bool>("transpose_a", Self::VT_TRANSPOSE_A, false)?
+            .visit_field::<bool>("transpose_b", Self::VT_TRANSPOSE_B, false)?
             .finish();
         Ok(())
     }
 }
-pub struct NonMaxSuppressionAttrsArgs {
-    pub box_order: NMSBoxOrder,
+pub struct GemmAttrsArgs {
+    pub alpha: f32,
+    pub beta: f32,
+    pub transpose_a: bool,
+    pub transpose_b: bool,
 }
-impl<'a> Default for NonMaxSuppressionAttrsArgs {
+impl<'a> Default for GemmAttrsArgs {
     #[inline]
     fn default() -> Self {
-        NonMaxSuppressionAttrsArgs {
-            box_order: NMSBoxOrder::TopLeftBottomRight,
+        GemmAttrsArgs {
+            alpha: 0.0,
+            beta: 0.0,
+            transpose_a: false,
+            transpose_b: false,
         }
     }
 }
 
-pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GemmAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GemmAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
-        self.fbb_.push_slot::<NMSBoxOrder>(
-            NonMaxSuppressionAttrs::VT_BOX_ORDER,
-            box_order,
-            NMSBoxOrder::TopLeftBottomRight,
-        );
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(GemmAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
+    pub fn add_beta(&mut self, beta: f32) {
+        self.fbb_.push_slot::<f32>(GemmAttrs::VT_BETA, beta, 0.0);
+    }
+    #[inline]
+    pub fn add_transpose_a(&mut self, transpose_a: bool) {
+        self.fbb_
+            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_A, transpose_a, false);
+    }
+    #[inline]
+    pub fn add_transpose_b(&mut self, transpose_b: bool) {
+        self.fbb_
+            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_B, transpose_b, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GemmAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        NonMaxSuppressionAttrsBuilder {
+        GemmAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GemmAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
+impl core::fmt::Debug for GemmAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
-        ds.field("box_order", &self.box_order());
+        let mut ds = f.debug_struct("GemmAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("beta", &self.beta());
+        ds.field("transpose_a", &self.transpose_a());
+        ds.field("transpose_b", &self.transpose_b());
         ds.finish()
     }
 }
-pub enum OneHotAttrsOffset {}
+pub enum GlobalLpPoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OneHotAttrs<'a> {
+pub struct GlobalLpPoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
-    type Inner = OneHotAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GlobalLpPoolAttrs<'a> {
+    type Inner = GlobalLpPoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5172,33 +5973,37 @@ impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
     }
 }
 
-impl<'a> OneHotAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> GlobalLpPoolAttrs<'a> {
+    pub const VT_P: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        OneHotAttrs { _tab: table }
+        GlobalLpPoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args OneHotAttrsArgs,
-    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
-        let mut builder = OneHotAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args GlobalLpPoolAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GlobalLpPoolAttrs<'bldr>> {
+        let mut builder = GlobalLpPoolAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn p(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<u32>(GlobalLpPoolAttrs::VT_P, Some(2))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for OneHotAttrs<'_> {
+impl flatbuffers::Verifiable for GlobalLpPoolAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5206,63 +6011,63 @@ impl flatbuffers::Verifiable for OneHotAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<u32>("p", Self::VT_P, false)?
             .finish();
         Ok(())
     }
 }
-pub struct OneHotAttrsArgs {
-    pub axis: i32,
+pub struct GlobalLpPoolAttrsArgs {
+    pub p: u32,
 }
-impl<'a> Default for OneHotAttrsArgs {
+impl<'a> Default for GlobalLpPoolAttrsArgs {
     #[inline]
     fn default() -> Self {
-        OneHotAttrsArgs { axis: 0 }
+        GlobalLpPoolAttrsArgs { p: 2 }
     }
 }
 
-pub struct OneHotAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GlobalLpPoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OneHotAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GlobalLpPoolAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
+    pub fn add_p(&mut self, p: u32) {
+        self.fbb_.push_slot::<u32>(GlobalLpPoolAttrs::VT_P, p, 2);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> OneHotAttrsBuilder<'a, 'b, A> {
+    ) -> GlobalLpPoolAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        OneHotAttrsBuilder {
+        GlobalLpPoolAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GlobalLpPoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for OneHotAttrs<'_> {
+impl core::fmt::Debug for GlobalLpPoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("OneHotAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("GlobalLpPoolAttrs");
+        ds.field("p", &self.p());
         ds.finish()
     }
 }
-pub enum RandomNormalAttrsOffset {}
+pub enum GRUAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomNormalAttrs<'a> {
+pub struct GRUAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
-    type Inner = RandomNormalAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
+    type Inner = GRUAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5271,78 +6076,104 @@ impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
     }
 }
 
-impl<'a> RandomNormalAttrs<'a> {
-    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
-    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 10;
+impl<'a> GRUAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_LINEAR_BEFORE_RESET: flatbuffers::VOffsetT = 8;
+    pub const VT_ACTIVATIONS: flatbuffers::VOffsetT = 10;
+    pub const VT_CLIP: flatbuffers::VOffsetT = 12;
+    pub const VT_BATCH_FIRST: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomNormalAttrs { _tab: table }
+        GRUAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomNormalAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<RandomNormalAttrs<'bldr>> {
-        let mut builder = RandomNormalAttrsBuilder::new(_fbb);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
+        args: &'args GRUAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<GRUAttrs<'bldr>> {
+        let mut builder = GRUAttrsBuilder::new(_fbb);
+        if let Some(x) = args.clip {
+            builder.add_clip(x);
         }
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
+        if let Some(x) = args.activations {
+            builder.add_activations(x);
         }
-        builder.add_scale(args.scale);
-        builder.add_mean(args.mean);
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_batch_first(args.batch_first);
+        builder.add_linear_before_reset(args.linear_before_reset);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn mean(&self) -> f32 {
+    pub fn direction(&self) -> RNNDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalAttrs::VT_MEAN, Some(0.0))
+                .get::<RNNDirection>(GRUAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn scale(&self) -> f32 {
+    pub fn hidden_size(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalAttrs::VT_SCALE, Some(0.0))
+                .get::<u32>(GRUAttrs::VT_HIDDEN_SIZE, Some(0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn seed(&self) -> Option<f32> {
+    pub fn linear_before_reset(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomNormalAttrs::VT_SEED, None) }
+        unsafe {
+            self._tab
+                .get::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, Some(false))
+                .unwrap()
+        }
     }
     #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn activations(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn>>,
+            >>(GRUAttrs::VT_ACTIVATIONS, None)
+        }
+    }
+    #[inline]
+    pub fn clip(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(GRUAttrs::VT_CLIP, None) }
+    }
+    #[inline]
+    pub fn batch_first(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    RandomNormalAttrs::VT_SHAPE,
-                    None,
-                )
+                .get::<bool>(GRUAttrs::VT_BATCH_FIRST, Some(false))
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
+impl flatbuffers::Verifiable for GRUAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5350,97 +6181,122 @@ impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
-            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
-                false,
-            )?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<bool>("linear_before_reset", Self::VT_LINEAR_BEFORE_RESET, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RNNActivationFn>>,
+            >>("activations", Self::VT_ACTIVATIONS, false)?
+            .visit_field::<f32>("clip", Self::VT_CLIP, false)?
+            .visit_field::<bool>("batch_first", Self::VT_BATCH_FIRST, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomNormalAttrsArgs<'a> {
-    pub mean: f32,
-    pub scale: f32,
-    pub seed: Option<f32>,
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-}
-impl<'a> Default for RandomNormalAttrsArgs<'a> {
-    #[inline]
+pub struct GRUAttrsArgs<'a> {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
+    pub linear_before_reset: bool,
+    pub activations: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn<'a>>>,
+        >,
+    >,
+    pub clip: Option<f32>,
+    pub batch_first: bool,
+}
+impl<'a> Default for GRUAttrsArgs<'a> {
+    #[inline]
     fn default() -> Self {
-        RandomNormalAttrsArgs {
-            mean: 0.0,
-            scale: 0.0,
-            seed: None,
-            shape: None,
+        GRUAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+            linear_before_reset: false,
+            activations: None,
+            clip: None,
+            batch_first: false,
         }
     }
 }
 
-pub struct RandomNormalAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GRUAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GRUAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_mean(&mut self, mean: f32) {
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            GRUAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
+    }
+    #[inline]
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
         self.fbb_
-            .push_slot::<f32>(RandomNormalAttrs::VT_MEAN, mean, 0.0);
+            .push_slot::<u32>(GRUAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
     }
     #[inline]
-    pub fn add_scale(&mut self, scale: f32) {
+    pub fn add_linear_before_reset(&mut self, linear_before_reset: bool) {
         self.fbb_
-            .push_slot::<f32>(RandomNormalAttrs::VT_SCALE, scale, 0.0);
+            .push_slot::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, linear_before_reset, false);
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_activations(
+        &mut self,
+        activations: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<RNNActivationFn<'b>>>,
+        >,
+    ) {
         self.fbb_
-            .push_slot_always::<f32>(RandomNormalAttrs::VT_SEED, seed);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(GRUAttrs::VT_ACTIVATIONS, activations);
     }
     #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_clip(&mut self, clip: f32) {
+        self.fbb_.push_slot_always::<f32>(GRUAttrs::VT_CLIP, clip);
+    }
+    #[inline]
+    pub fn add_batch_first(&mut self, batch_first: bool) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomNormalAttrs::VT_SHAPE, shape);
+            .push_slot::<bool>(GRUAttrs::VT_BATCH_FIRST, batch_first, false);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomNormalAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GRUAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomNormalAttrsBuilder {
+        GRUAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GRUAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomNormalAttrs<'_> {
+impl core::fmt::Debug for GRUAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomNormalAttrs");
-        ds.field("mean", &self.mean());
-        ds.field("scale", &self.scale());
-        ds.field("seed", &self.seed());
-        ds.field("shape", &self.shape());
+        let mut ds = f.debug_struct("GRUAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
+        ds.field("linear_before_reset", &self.linear_before_reset());
+        ds.field("activations", &self.activations());
+        ds.field("clip", &self.clip());
+        ds.field("batch_first", &self.batch_first());
         ds.finish()
     }
 }
-pub enum RandomNormalLikeAttrsOffset {}
+pub enum HardSigmoidAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomNormalLikeAttrs<'a> {
+pub struct HardSigmoidAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
-    type Inner = RandomNormalLikeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
+    type Inner = HardSigmoidAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5449,61 +6305,50 @@ impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
     }
 }
 
-impl<'a> RandomNormalLikeAttrs<'a> {
-    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
-    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+impl<'a> HardSigmoidAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_BETA: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomNormalLikeAttrs { _tab: table }
+        HardSigmoidAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomNormalLikeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'bldr>> {
-        let mut builder = RandomNormalLikeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_scale(args.scale);
-        builder.add_mean(args.mean);
+        args: &'args HardSigmoidAttrsArgs,
+    ) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'bldr>> {
+        let mut builder = HardSigmoidAttrsBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn mean(&self) -> f32 {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalLikeAttrs::VT_MEAN, Some(0.0))
+                .get::<f32>(HardSigmoidAttrs::VT_ALPHA, Some(0.0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn scale(&self) -> f32 {
+    pub fn beta(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalLikeAttrs::VT_SCALE, Some(0.0))
+                .get::<f32>(HardSigmoidAttrs::VT_BETA, Some(0.0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomNormalLikeAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
+impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5511,84 +6356,75 @@ impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
-            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomNormalLikeAttrsArgs {
-    pub mean: f32,
-    pub scale: f32,
-    pub seed: Option<f32>,
+pub struct HardSigmoidAttrsArgs {
+    pub alpha: f32,
+    pub beta: f32,
 }
-impl<'a> Default for RandomNormalLikeAttrsArgs {
+impl<'a> Default for HardSigmoidAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomNormalLikeAttrsArgs {
-            mean: 0.0,
-            scale: 0.0,
-            seed: None,
+        HardSigmoidAttrsArgs {
+            alpha: 0.0,
+            beta: 0.0,
         }
     }
 }
 
-pub struct RandomNormalLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct HardSigmoidAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_mean(&mut self, mean: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalLikeAttrs::VT_MEAN, mean, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HardSigmoidAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_scale(&mut self, scale: f32) {
+    pub fn add_alpha(&mut self, alpha: f32) {
         self.fbb_
-            .push_slot::<f32>(RandomNormalLikeAttrs::VT_SCALE, scale, 0.0);
+            .push_slot::<f32>(HardSigmoidAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_beta(&mut self, beta: f32) {
         self.fbb_
-            .push_slot_always::<f32>(RandomNormalLikeAttrs::VT_SEED, seed);
+            .push_slot::<f32>(HardSigmoidAttrs::VT_BETA, beta, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
+    ) -> HardSigmoidAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomNormalLikeAttrsBuilder {
+        HardSigmoidAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomNormalLikeAttrs<'_> {
+impl core::fmt::Debug for HardSigmoidAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomNormalLikeAttrs");
-        ds.field("mean", &self.mean());
-        ds.field("scale", &self.scale());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("HardSigmoidAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("beta", &self.beta());
         ds.finish()
     }
 }
-pub enum RandomUniformAttrsOffset {}
+pub enum IsInfAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomUniformAttrs<'a> {
+pub struct IsInfAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
-    type Inner = RandomUniformAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for IsInfAttrs<'a> {
+    type Inner = IsInfAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5597,78 +6433,50 @@ impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
     }
 }
 
-impl<'a> RandomUniformAttrs<'a> {
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
-    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
-    pub const VT_LOW: flatbuffers::VOffsetT = 8;
-    pub const VT_SEED: flatbuffers::VOffsetT = 10;
+impl<'a> IsInfAttrs<'a> {
+    pub const VT_DETECT_NEGATIVE: flatbuffers::VOffsetT = 4;
+    pub const VT_DETECT_POSITIVE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomUniformAttrs { _tab: table }
+        IsInfAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomUniformAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
-        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_low(args.low);
-        builder.add_high(args.high);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
-        }
+        args: &'args IsInfAttrsArgs,
+    ) -> flatbuffers::WIPOffset<IsInfAttrs<'bldr>> {
+        let mut builder = IsInfAttrsBuilder::new(_fbb);
+        builder.add_detect_positive(args.detect_positive);
+        builder.add_detect_negative(args.detect_negative);
         builder.finish()
     }
 
     #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    RandomUniformAttrs::VT_SHAPE,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn high(&self) -> f32 {
+    pub fn detect_negative(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
+                .get::<bool>(IsInfAttrs::VT_DETECT_NEGATIVE, Some(true))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn low(&self) -> f32 {
+    pub fn detect_positive(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
+                .get::<bool>(IsInfAttrs::VT_DETECT_POSITIVE, Some(true))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
+impl flatbuffers::Verifiable for IsInfAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5676,97 +6484,75 @@ impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
-                false,
-            )?
-            .visit_field::<f32>("high", Self::VT_HIGH, false)?
-            .visit_field::<f32>("low", Self::VT_LOW, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<bool>("detect_negative", Self::VT_DETECT_NEGATIVE, false)?
+            .visit_field::<bool>("detect_positive", Self::VT_DETECT_POSITIVE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomUniformAttrsArgs<'a> {
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub high: f32,
-    pub low: f32,
-    pub seed: Option<f32>,
+pub struct IsInfAttrsArgs {
+    pub detect_negative: bool,
+    pub detect_positive: bool,
 }
-impl<'a> Default for RandomUniformAttrsArgs<'a> {
+impl<'a> Default for IsInfAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomUniformAttrsArgs {
-            shape: None,
-            high: 0.0,
-            low: 0.0,
-            seed: None,
+        IsInfAttrsArgs {
+            detect_negative: true,
+            detect_positive: true,
         }
     }
 }
 
-pub struct RandomUniformAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct IsInfAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
-    }
-    #[inline]
-    pub fn add_high(&mut self, high: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IsInfAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_low(&mut self, low: f32) {
+    pub fn add_detect_negative(&mut self, detect_negative: bool) {
         self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+            .push_slot::<bool>(IsInfAttrs::VT_DETECT_NEGATIVE, detect_negative, true);
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_detect_positive(&mut self, detect_positive: bool) {
         self.fbb_
-            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+            .push_slot::<bool>(IsInfAttrs::VT_DETECT_POSITIVE, detect_positive, true);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomUniformAttrsBuilder<'a, 'b, A> {
+    ) -> IsInfAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomUniformAttrsBuilder {
+        IsInfAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<IsInfAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomUniformAttrs<'_> {
+impl core::fmt::Debug for IsInfAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomUniformAttrs");
-        ds.field("shape", &self.shape());
-        ds.field("high", &self.high());
-        ds.field("low", &self.low());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("IsInfAttrs");
+        ds.field("detect_negative", &self.detect_negative());
+        ds.field("detect_positive", &self.detect_positive());
         ds.finish()
     }
 }
-pub enum RandomUniformLikeAttrsOffset {}
+pub enum LeakyReluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomUniformLikeAttrs<'a> {
+pub struct LeakyReluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
-    type Inner = RandomUniformLikeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
+    type Inner = LeakyReluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5775,61 +6561,37 @@ impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
     }
 }
 
-impl<'a> RandomUniformLikeAttrs<'a> {
-    pub const VT_HIGH: flatbuffers::VOffsetT = 4;
-    pub const VT_LOW: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+impl<'a> LeakyReluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomUniformLikeAttrs { _tab: table }
+        LeakyReluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomUniformLikeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'bldr>> {
-        let mut builder = RandomUniformLikeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_low(args.low);
-        builder.add_high(args.high);
+        args: &'args LeakyReluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LeakyReluAttrs<'bldr>> {
+        let mut builder = LeakyReluAttrsBuilder::new(_fbb);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn high(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(RandomUniformLikeAttrs::VT_HIGH, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn low(&self) -> f32 {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformLikeAttrs::VT_LOW, Some(0.0))
+                .get::<f32>(LeakyReluAttrs::VT_ALPHA, Some(0.0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomUniformLikeAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
+impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5837,84 +6599,64 @@ impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("high", Self::VT_HIGH, false)?
-            .visit_field::<f32>("low", Self::VT_LOW, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomUniformLikeAttrsArgs {
-    pub high: f32,
-    pub low: f32,
-    pub seed: Option<f32>,
+pub struct LeakyReluAttrsArgs {
+    pub alpha: f32,
 }
-impl<'a> Default for RandomUniformLikeAttrsArgs {
+impl<'a> Default for LeakyReluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomUniformLikeAttrsArgs {
-            high: 0.0,
-            low: 0.0,
-            seed: None,
-        }
+        LeakyReluAttrsArgs { alpha: 0.0 }
     }
 }
 
-pub struct RandomUniformLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LeakyReluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_high(&mut self, high: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformLikeAttrs::VT_HIGH, high, 0.0);
-    }
-    #[inline]
-    pub fn add_low(&mut self, low: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformLikeAttrs::VT_LOW, low, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LeakyReluAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_alpha(&mut self, alpha: f32) {
         self.fbb_
-            .push_slot_always::<f32>(RandomUniformLikeAttrs::VT_SEED, seed);
+            .push_slot::<f32>(LeakyReluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
+    ) -> LeakyReluAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomUniformLikeAttrsBuilder {
+        LeakyReluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LeakyReluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomUniformLikeAttrs<'_> {
+impl core::fmt::Debug for LeakyReluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomUniformLikeAttrs");
-        ds.field("high", &self.high());
-        ds.field("low", &self.low());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("LeakyReluAttrs");
+        ds.field("alpha", &self.alpha());
         ds.finish()
     }
 }
-pub enum ReduceMeanAttrsOffset {}
+pub enum LpPoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReduceMeanAttrs<'a> {
+pub struct LpPoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
-    type Inner = ReduceMeanAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LpPoolAttrs<'a> {
+    type Inner = LpPoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5923,54 +6665,128 @@ impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
     }
 }
 
-impl<'a> ReduceMeanAttrs<'a> {
-    pub const VT_AXES: flatbuffers::VOffsetT = 4;
-    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+impl<'a> LpPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 14;
+    pub const VT_P: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReduceMeanAttrs { _tab: table }
+        LpPoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ReduceMeanAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
-        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
-        if let Some(x) = args.axes {
-            builder.add_axes(x);
+        args: &'args LpPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<LpPoolAttrs<'bldr>> {
+        let mut builder = LpPoolAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
         }
-        builder.add_keep_dims(args.keep_dims);
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    ReduceMeanAttrs::VT_AXES,
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_KERNEL_SIZE,
                     None,
                 )
+                .unwrap()
         }
     }
     #[inline]
-    pub fn keep_dims(&self) -> bool {
+    pub fn auto_pad(&self) -> AutoPad {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
+                .get::<AutoPad>(LpPoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(LpPoolAttrs::VT_CEIL_MODE, Some(false))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn p(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(LpPoolAttrs::VT_P, Some(2)).unwrap() }
+    }
 }
 
-impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
+impl flatbuffers::Verifiable for LpPoolAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5978,79 +6794,145 @@ impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
-                "axes",
-                Self::VT_AXES,
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
                 false,
             )?
-            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<u32>("p", Self::VT_P, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReduceMeanAttrsArgs<'a> {
-    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
-    pub keep_dims: bool,
+pub struct LpPoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
+    pub p: u32,
 }
-impl<'a> Default for ReduceMeanAttrsArgs<'a> {
+impl<'a> Default for LpPoolAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ReduceMeanAttrsArgs {
-            axes: None,
-            keep_dims: false,
+        LpPoolAttrsArgs {
+            kernel_size: None, // required field
+            auto_pad: AutoPad::Same,
+            pads: None,
+            strides: None,
+            dilations: None,
+            ceil_mode: false,
+            p: 2,
         }
     }
 }
 
-pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LpPoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReduceMeanAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LpPoolAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            LpPoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
     }
     #[inline]
-    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
         self.fbb_
-            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
+            .push_slot::<AutoPad>(LpPoolAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ReduceMeanAttrsBuilder<'a, 'b, A> {
-        let start = _fbb.start_table();
-        ReduceMeanAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
-        }
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_PADS, pads);
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_DILATIONS, dilations);
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(LpPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
+    pub fn add_p(&mut self, p: u32) {
+        self.fbb_.push_slot::<u32>(LpPoolAttrs::VT_P, p, 2);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> LpPoolAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        LpPoolAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<LpPoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, LpPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReduceMeanAttrs<'_> {
+impl core::fmt::Debug for LpPoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReduceMeanAttrs");
-        ds.field("axes", &self.axes());
-        ds.field("keep_dims", &self.keep_dims());
+        let mut ds = f.debug_struct("LpPoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("dilations", &self.dilations());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("p", &self.p());
         ds.finish()
     }
 }
-pub enum ReshapeAttrsOffset {}
+pub enum LSTMAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReshapeAttrs<'a> {
+pub struct LSTMAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
-    type Inner = ReshapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
+    type Inner = LSTMAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6059,37 +6941,104 @@ impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
     }
 }
 
-impl<'a> ReshapeAttrs<'a> {
-    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
+impl<'a> LSTMAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_ACTIVATIONS: flatbuffers::VOffsetT = 8;
+    pub const VT_CLIP: flatbuffers::VOffsetT = 10;
+    pub const VT_BATCH_FIRST: flatbuffers::VOffsetT = 12;
+    pub const VT_INPUT_FORGET: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReshapeAttrs { _tab: table }
+        LSTMAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ReshapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
-        let mut builder = ReshapeAttrsBuilder::new(_fbb);
-        builder.add_allow_zero(args.allow_zero);
+        args: &'args LSTMAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<LSTMAttrs<'bldr>> {
+        let mut builder = LSTMAttrsBuilder::new(_fbb);
+        if let Some(x) = args.clip {
+            builder.add_clip(x);
+        }
+        if let Some(x) = args.activations {
+            builder.add_activations(x);
+        }
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_input_forget(args.input_forget);
+        builder.add_batch_first(args.batch_first);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn allow_zero(&self) -> bool {
+    pub fn direction(&self) -> RNNDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .get::<RNNDirection>(LSTMAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn hidden_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn activations(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn>>,
+            >>(LSTMAttrs::VT_ACTIVATIONS, None)
+        }
+    }
+    #[inline]
+    pub fn clip(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(LSTMAttrs::VT_CLIP, None) }
+    }
+    #[inline]
+    pub fn batch_first(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(LSTMAttrs::VT_BATCH_FIRST, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn input_forget(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(LSTMAttrs::VT_INPUT_FORGET, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
+impl flatbuffers::Verifiable for LSTMAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6097,64 +7046,122 @@ impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RNNActivationFn>>,
+            >>("activations", Self::VT_ACTIVATIONS, false)?
+            .visit_field::<f32>("clip", Self::VT_CLIP, false)?
+            .visit_field::<bool>("batch_first", Self::VT_BATCH_FIRST, false)?
+            .visit_field::<bool>("input_forget", Self::VT_INPUT_FORGET, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReshapeAttrsArgs {
-    pub allow_zero: bool,
+pub struct LSTMAttrsArgs<'a> {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
+    pub activations: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn<'a>>>,
+        >,
+    >,
+    pub clip: Option<f32>,
+    pub batch_first: bool,
+    pub input_forget: bool,
 }
-impl<'a> Default for ReshapeAttrsArgs {
+impl<'a> Default for LSTMAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ReshapeAttrsArgs { allow_zero: false }
+        LSTMAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+            activations: None,
+            clip: None,
+            batch_first: false,
+            input_forget: false,
+        }
     }
 }
 
-pub struct ReshapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LSTMAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReshapeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LSTMAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_allow_zero(&mut self, allow_zero: bool) {
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            LSTMAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
+    }
+    #[inline]
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
         self.fbb_
-            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
+            .push_slot::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ReshapeAttrsBuilder<'a, 'b, A> {
+    pub fn add_activations(
+        &mut self,
+        activations: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<RNNActivationFn<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LSTMAttrs::VT_ACTIVATIONS, activations);
+    }
+    #[inline]
+    pub fn add_clip(&mut self, clip: f32) {
+        self.fbb_.push_slot_always::<f32>(LSTMAttrs::VT_CLIP, clip);
+    }
+    #[inline]
+    pub fn add_batch_first(&mut self, batch_first: bool) {
+        self.fbb_
+            .push_slot::<bool>(LSTMAttrs::VT_BATCH_FIRST, batch_first, false);
+    }
+    #[inline]
+    pub fn add_input_forget(&mut self, input_forget: bool) {
+        self.fbb_
+            .push_slot::<bool>(LSTMAttrs::VT_INPUT_FORGET, input_forget, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LSTMAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ReshapeAttrsBuilder {
+        LSTMAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LSTMAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReshapeAttrs<'_> {
+impl core::fmt::Debug for LSTMAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReshapeAttrs");
-        ds.field("allow_zero", &self.allow_zero());
+        let mut ds = f.debug_struct("LSTMAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
+        ds.field("activations", &self.activations());
+        ds.field("clip", &self.clip());
+        ds.field("batch_first", &self.batch_first());
+        ds.field("input_forget", &self.input_forget());
         ds.finish()
     }
 }
-pub enum ResizeAttrsOffset {}
+pub enum MaxPoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ResizeAttrs<'a> {
+pub struct MaxPoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
-    type Inner = ResizeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
+    type Inner = MaxPoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6163,157 +7170,279 @@ impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
     }
 }
 
-impl<'a> ResizeAttrs<'a> {
-    pub const VT_MODE: flatbuffers::VOffsetT = 4;
-    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+impl<'a> MaxPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 12;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 14;
+    pub const VT_RETURN_INDICES: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ResizeAttrs { _tab: table }
+        MaxPoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ResizeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
-        let mut builder = ResizeAttrsBuilder::new(_fbb);
-        builder.add_nearest_mode(args.nearest_mode);
-        builder.add_coord_mode(args.coord_mode);
-        builder.add_mode(args.mode);
+        args: &'args MaxPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
+        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_return_indices(args.return_indices);
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn mode(&self) -> ResizeMode {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
                 .unwrap()
         }
     }
     #[inline]
-    pub fn coord_mode(&self) -> CoordTransformMode {
+    pub fn auto_pad(&self) -> AutoPad {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<CoordTransformMode>(
-                    ResizeAttrs::VT_COORD_MODE,
-                    Some(CoordTransformMode::HalfPixel),
-                )
+                .get::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn nearest_mode(&self) -> NearestMode {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(MaxPoolAttrs::VT_CEIL_MODE, Some(false))
                 .unwrap()
         }
     }
-}
-
-impl flatbuffers::Verifiable for ResizeAttrs<'_> {
     #[inline]
-    fn run_verifier(
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn return_indices(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(MaxPoolAttrs::VT_RETURN_INDICES, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
+    #[inline]
+    fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
-            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
-            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<bool>("return_indices", Self::VT_RETURN_INDICES, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ResizeAttrsArgs {
-    pub mode: ResizeMode,
-    pub coord_mode: CoordTransformMode,
-    pub nearest_mode: NearestMode,
+pub struct MaxPoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub return_indices: bool,
 }
-impl<'a> Default for ResizeAttrsArgs {
+impl<'a> Default for MaxPoolAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ResizeAttrsArgs {
-            mode: ResizeMode::Nearest,
-            coord_mode: CoordTransformMode::HalfPixel,
-            nearest_mode: NearestMode::Floor,
+        MaxPoolAttrsArgs {
+            kernel_size: None, // required field
+            auto_pad: AutoPad::Same,
+            pads: None,
+            strides: None,
+            ceil_mode: false,
+            dilations: None,
+            return_indices: false,
         }
     }
 }
 
-pub struct ResizeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MaxPoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResizeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MaxPoolAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_mode(&mut self, mode: ResizeMode) {
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MaxPoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
+    }
+    #[inline]
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
         self.fbb_
-            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
+            .push_slot::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
     }
     #[inline]
-    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
-        self.fbb_.push_slot::<CoordTransformMode>(
-            ResizeAttrs::VT_COORD_MODE,
-            coord_mode,
-            CoordTransformMode::HalfPixel,
-        );
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_PADS, pads);
     }
     #[inline]
-    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
-        self.fbb_.push_slot::<NearestMode>(
-            ResizeAttrs::VT_NEAREST_MODE,
-            nearest_mode,
-            NearestMode::Floor,
-        );
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(MaxPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_DILATIONS, dilations);
+    }
+    #[inline]
+    pub fn add_return_indices(&mut self, return_indices: bool) {
+        self.fbb_
+            .push_slot::<bool>(MaxPoolAttrs::VT_RETURN_INDICES, return_indices, false);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ResizeAttrsBuilder<'a, 'b, A> {
+    ) -> MaxPoolAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ResizeAttrsBuilder {
+        MaxPoolAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MaxPoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, MaxPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ResizeAttrs<'_> {
+impl core::fmt::Debug for MaxPoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ResizeAttrs");
-        ds.field("mode", &self.mode());
-        ds.field("coord_mode", &self.coord_mode());
-        ds.field("nearest_mode", &self.nearest_mode());
+        let mut ds = f.debug_struct("MaxPoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("dilations", &self.dilations());
+        ds.field("return_indices", &self.return_indices());
         ds.finish()
     }
 }
-pub enum ScatterElementsAttrsOffset {}
+pub enum MaxUnpoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterElementsAttrs<'a> {
+pub struct MaxUnpoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
-    type Inner = ScatterElementsAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for MaxUnpoolAttrs<'a> {
+    type Inner = MaxUnpoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6322,53 +7451,76 @@ impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
     }
 }
 
-impl<'a> ScatterElementsAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
+impl<'a> MaxUnpoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_PADS: flatbuffers::VOffsetT = 6;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterElementsAttrs { _tab: table }
+        MaxUnpoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ScatterElementsAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
-        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        builder.add_reduction(args.reduction);
+        args: &'args MaxUnpoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MaxUnpoolAttrs<'bldr>> {
+        let mut builder = MaxUnpoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxUnpoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
                 .unwrap()
         }
     }
     #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ScatterReduction>(
-                    ScatterElementsAttrs::VT_REDUCTION,
-                    Some(ScatterReduction::None),
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxUnpoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxUnpoolAttrs::VT_STRIDES,
+                    None,
                 )
-                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
+impl flatbuffers::Verifiable for MaxUnpoolAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6376,78 +7528,103 @@ impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterElementsAttrsArgs {
-    pub axis: i32,
-    pub reduction: ScatterReduction,
+pub struct MaxUnpoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for ScatterElementsAttrsArgs {
+impl<'a> Default for MaxUnpoolAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ScatterElementsAttrsArgs {
-            axis: 0,
-            reduction: ScatterReduction::None,
+        MaxUnpoolAttrsArgs {
+            kernel_size: None, // required field
+            pads: None,
+            strides: None,
         }
     }
 }
 
-pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MaxUnpoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterElementsAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MaxUnpoolAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MaxUnpoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxUnpoolAttrs::VT_PADS, pads);
     }
     #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterElementsAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxUnpoolAttrs::VT_STRIDES, strides);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ScatterElementsAttrsBuilder<'a, 'b, A> {
+    ) -> MaxUnpoolAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ScatterElementsAttrsBuilder {
+        MaxUnpoolAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MaxUnpoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, MaxUnpoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterElementsAttrs<'_> {
+impl core::fmt::Debug for MaxUnpoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterElementsAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("reduction", &self.reduction());
+        let mut ds = f.debug_struct("MaxUnpoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
         ds.finish()
     }
 }
-pub enum ScatterNDAttrsOffset {}
+pub enum ModAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterNDAttrs<'a> {
+pub struct ModAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
-    type Inner = ScatterNDAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
+    type Inner = ModAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6456,37 +7633,37 @@ impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
     }
 }
 
-impl<'a> ScatterNDAttrs<'a> {
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
+impl<'a> ModAttrs<'a> {
+    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterNDAttrs { _tab: table }
+        ModAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ScatterNDAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
-        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
-        builder.add_reduction(args.reduction);
+        args: &'args ModAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
+        let mut builder = ModAttrsBuilder::new(_fbb);
+        builder.add_fmod(args.fmod);
         builder.finish()
     }
 
     #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
+    pub fn fmod(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
+                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
+impl flatbuffers::Verifiable for ModAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6494,69 +7671,61 @@ impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterNDAttrsArgs {
-    pub reduction: ScatterReduction,
+pub struct ModAttrsArgs {
+    pub fmod: bool,
 }
-impl<'a> Default for ScatterNDAttrsArgs {
+impl<'a> Default for ModAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ScatterNDAttrsArgs {
-            reduction: ScatterReduction::None,
-        }
+        ModAttrsArgs { fmod: false }
     }
 }
 
-pub struct ScatterNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ModAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterNDAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ModAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterNDAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+    pub fn add_fmod(&mut self, fmod: bool) {
+        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ScatterNDAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ModAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ScatterNDAttrsBuilder {
+        ModAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterNDAttrs<'_> {
+impl core::fmt::Debug for ModAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterNDAttrs");
-        ds.field("reduction", &self.reduction());
+        let mut ds = f.debug_struct("ModAttrs");
+        ds.field("fmod", &self.fmod());
         ds.finish()
     }
 }
-pub enum SoftmaxAttrsOffset {}
+pub enum MultinomialAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SoftmaxAttrs<'a> {
+pub struct MultinomialAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
-    type Inner = SoftmaxAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for MultinomialAttrs<'a> {
+    type Inner = MultinomialAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6565,37 +7734,48 @@ impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
     }
 }
 
-impl<'a> SoftmaxAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> MultinomialAttrs<'a> {
+    pub const VT_SAMPLE_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_SEED: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SoftmaxAttrs { _tab: table }
+        MultinomialAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args SoftmaxAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
-        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args MultinomialAttrsArgs,
+    ) -> flatbuffers::WIPOffset<MultinomialAttrs<'bldr>> {
+        let mut builder = MultinomialAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_sample_size(args.sample_size);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn sample_size(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
+                .get::<u32>(MultinomialAttrs::VT_SAMPLE_SIZE, Some(1))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(MultinomialAttrs::VT_SEED, None) }
+    }
 }
 
-impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
+impl flatbuffers::Verifiable for MultinomialAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6603,63 +7783,75 @@ impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<u32>("sample_size", Self::VT_SAMPLE_SIZE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SoftmaxAttrsArgs {
-    pub axis: i32,
+pub struct MultinomialAttrsArgs {
+    pub sample_size: u32,
+    pub seed: Option<f32>,
 }
-impl<'a> Default for SoftmaxAttrsArgs {
+impl<'a> Default for MultinomialAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SoftmaxAttrsArgs { axis: 0 }
+        MultinomialAttrsArgs {
+            sample_size: 1,
+            seed: None,
+        }
     }
 }
 
-pub struct SoftmaxAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MultinomialAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SoftmaxAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MultinomialAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
+    pub fn add_sample_size(&mut self, sample_size: u32) {
+        self.fbb_
+            .push_slot::<u32>(MultinomialAttrs::VT_SAMPLE_SIZE, sample_size, 1);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(MultinomialAttrs::VT_SEED, seed);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> SoftmaxAttrsBuilder<'a, 'b, A> {
+    ) -> MultinomialAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        SoftmaxAttrsBuilder {
+        MultinomialAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MultinomialAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SoftmaxAttrs<'_> {
+impl core::fmt::Debug for MultinomialAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SoftmaxAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("MultinomialAttrs");
+        ds.field("sample_size", &self.sample_size());
+        ds.field("seed", &self.seed());
         ds.finish()
     }
 }
-pub enum SplitAttrsOffset {}
+pub enum NonMaxSuppressionAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SplitAttrs<'a> {
+pub struct NonMaxSuppressionAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
-    type Inner = SplitAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
+    type Inner = NonMaxSuppressionAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6668,33 +7860,40 @@ impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
     }
 }
 
-impl<'a> SplitAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> NonMaxSuppressionAttrs<'a> {
+    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SplitAttrs { _tab: table }
+        NonMaxSuppressionAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args SplitAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
-        let mut builder = SplitAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args NonMaxSuppressionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
+        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
+        builder.add_box_order(args.box_order);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn box_order(&self) -> NMSBoxOrder {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<NMSBoxOrder>(
+                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
+                    Some(NMSBoxOrder::TopLeftBottomRight),
+                )
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for SplitAttrs<'_> {
+impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6702,63 +7901,69 @@ impl flatbuffers::Verifiable for SplitAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SplitAttrsArgs {
-    pub axis: i32,
+pub struct NonMaxSuppressionAttrsArgs {
+    pub box_order: NMSBoxOrder,
 }
-impl<'a> Default for SplitAttrsArgs {
+impl<'a> Default for NonMaxSuppressionAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SplitAttrsArgs { axis: 0 }
+        NonMaxSuppressionAttrsArgs {
+            box_order: NMSBoxOrder::TopLeftBottomRight,
+        }
     }
 }
 
-pub struct SplitAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SplitAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
+    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
+        self.fbb_.push_slot::<NMSBoxOrder>(
+            NonMaxSuppressionAttrs::VT_BOX_ORDER,
+            box_order,
+            NMSBoxOrder::TopLeftBottomRight,
+        );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> SplitAttrsBuilder<'a, 'b, A> {
+    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        SplitAttrsBuilder {
+        NonMaxSuppressionAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SplitAttrs<'_> {
+impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SplitAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
+        ds.field("box_order", &self.box_order());
         ds.finish()
     }
 }
-pub enum TopKAttrsOffset {}
+pub enum OneHotAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TopKAttrs<'a> {
+pub struct OneHotAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
-    type Inner = TopKAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
+    type Inner = OneHotAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6767,24 +7972,20 @@ impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
     }
 }
 
-impl<'a> TopKAttrs<'a> {
+impl<'a> OneHotAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
-    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TopKAttrs { _tab: table }
+        OneHotAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args TopKAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
-        let mut builder = TopKAttrsBuilder::new(_fbb);
+        args: &'args OneHotAttrsArgs,
+    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
+        let mut builder = OneHotAttrsBuilder::new(_fbb);
         builder.add_axis(args.axis);
-        builder.add_sorted(args.sorted);
-        builder.add_largest(args.largest);
         builder.finish()
     }
 
@@ -6793,33 +7994,11 @@ impl<'a> TopKAttrs<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
-    }
-    #[inline]
-    pub fn largest(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn sorted(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for TopKAttrs<'_> {
+impl flatbuffers::Verifiable for OneHotAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6828,80 +8007,62 @@ impl flatbuffers::Verifiable for TopKAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
-            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TopKAttrsArgs {
+pub struct OneHotAttrsArgs {
     pub axis: i32,
-    pub largest: bool,
-    pub sorted: bool,
 }
-impl<'a> Default for TopKAttrsArgs {
+impl<'a> Default for OneHotAttrsArgs {
     #[inline]
     fn default() -> Self {
-        TopKAttrsArgs {
-            axis: 0,
-            largest: false,
-            sorted: false,
-        }
+        OneHotAttrsArgs { axis: 0 }
     }
 }
 
-pub struct TopKAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct OneHotAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TopKAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OneHotAttrsBuilder<'a, 'b, A> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
-    }
-    #[inline]
-    pub fn add_largest(&mut self, largest: bool) {
-        self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
+        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn add_sorted(&mut self, sorted: bool) {
-        self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
-    }
-    #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TopKAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> OneHotAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        TopKAttrsBuilder {
+        OneHotAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TopKAttrs<'_> {
+impl core::fmt::Debug for OneHotAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TopKAttrs");
+        let mut ds = f.debug_struct("OneHotAttrs");
         ds.field("axis", &self.axis());
-        ds.field("largest", &self.largest());
-        ds.field("sorted", &self.sorted());
         ds.finish()
     }
 }
-pub enum TransposeAttrsOffset {}
+pub enum RandomNormalAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TransposeAttrs<'a> {
+pub struct RandomNormalAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
-    type Inner = TransposeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
+    type Inner = RandomNormalAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6910,41 +8071,78 @@ impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
     }
 }
 
-impl<'a> TransposeAttrs<'a> {
-    pub const VT_PERM: flatbuffers::VOffsetT = 4;
+impl<'a> RandomNormalAttrs<'a> {
+    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TransposeAttrs { _tab: table }
+        RandomNormalAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args TransposeAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
-        let mut builder = TransposeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.perm {
-            builder.add_perm(x);
+        args: &'args RandomNormalAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RandomNormalAttrs<'bldr>> {
+        let mut builder = RandomNormalAttrsBuilder::new(_fbb);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
         }
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_scale(args.scale);
+        builder.add_mean(args.mean);
         builder.finish()
     }
 
     #[inline]
-    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn mean(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalAttrs::VT_MEAN, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalAttrs::VT_SCALE, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomNormalAttrs::VT_SEED, None) }
+    }
+    #[inline]
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
                 .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    TransposeAttrs::VT_PERM,
+                    RandomNormalAttrs::VT_SHAPE,
                     None,
                 )
         }
     }
 }
 
-impl flatbuffers::Verifiable for TransposeAttrs<'_> {
+impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6952,68 +8150,97 @@ impl flatbuffers::Verifiable for TransposeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
+            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "perm",
-                Self::VT_PERM,
+                "shape",
+                Self::VT_SHAPE,
                 false,
             )?
             .finish();
         Ok(())
     }
 }
-pub struct TransposeAttrsArgs<'a> {
-    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct RandomNormalAttrsArgs<'a> {
+    pub mean: f32,
+    pub scale: f32,
+    pub seed: Option<f32>,
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for TransposeAttrsArgs<'a> {
+impl<'a> Default for RandomNormalAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        TransposeAttrsArgs { perm: None }
+        RandomNormalAttrsArgs {
+            mean: 0.0,
+            scale: 0.0,
+            seed: None,
+            shape: None,
+        }
     }
 }
 
-pub struct TransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct RandomNormalAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransposeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_mean(&mut self, mean: f32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
+            .push_slot::<f32>(RandomNormalAttrs::VT_MEAN, mean, 0.0);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalAttrs::VT_SCALE, scale, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomNormalAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomNormalAttrs::VT_SHAPE, shape);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> TransposeAttrsBuilder<'a, 'b, A> {
+    ) -> RandomNormalAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        TransposeAttrsBuilder {
+        RandomNormalAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TransposeAttrs<'_> {
+impl core::fmt::Debug for RandomNormalAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TransposeAttrs");
-        ds.field("perm", &self.perm());
+        let mut ds = f.debug_struct("RandomNormalAttrs");
+        ds.field("mean", &self.mean());
+        ds.field("scale", &self.scale());
+        ds.field("seed", &self.seed());
+        ds.field("shape", &self.shape());
         ds.finish()
     }
 }
-pub enum TriluAttrsOffset {}
+pub enum RandomNormalLikeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TriluAttrs<'a> {
+pub struct RandomNormalLikeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
-    type Inner = TriluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
+    type Inner = RandomNormalLikeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7022,37 +8249,61 @@ impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
     }
 }
 
-impl<'a> TriluAttrs<'a> {
-    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
+impl<'a> RandomNormalLikeAttrs<'a> {
+    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TriluAttrs { _tab: table }
+        RandomNormalLikeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args TriluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
-        let mut builder = TriluAttrsBuilder::new(_fbb);
-        builder.add_upper(args.upper);
+        args: &'args RandomNormalLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'bldr>> {
+        let mut builder = RandomNormalLikeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_scale(args.scale);
+        builder.add_mean(args.mean);
         builder.finish()
     }
 
     #[inline]
-    pub fn upper(&self) -> bool {
+    pub fn mean(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
+                .get::<f32>(RandomNormalLikeAttrs::VT_MEAN, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalLikeAttrs::VT_SCALE, Some(0.0))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomNormalLikeAttrs::VT_SEED, None) }
+    }
 }
 
-impl flatbuffers::Verifiable for TriluAttrs<'_> {
+impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7060,173 +8311,3645 @@ impl flatbuffers::Verifiable for TriluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
+            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TriluAttrsArgs {
-    pub upper: bool,
+pub struct RandomNormalLikeAttrsArgs {
+    pub mean: f32,
+    pub scale: f32,
+    pub seed: Option<f32>,
 }
-impl<'a> Default for TriluAttrsArgs {
+impl<'a> Default for RandomNormalLikeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        TriluAttrsArgs { upper: false }
+        RandomNormalLikeAttrsArgs {
+            mean: 0.0,
+            scale: 0.0,
+            seed: None,
+        }
     }
 }
 
-pub struct TriluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct RandomNormalLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TriluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_upper(&mut self, upper: bool) {
+    pub fn add_mean(&mut self, mean: f32) {
         self.fbb_
-            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
+            .push_slot::<f32>(RandomNormalLikeAttrs::VT_MEAN, mean, 0.0);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalLikeAttrs::VT_SCALE, scale, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomNormalLikeAttrs::VT_SEED, seed);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> TriluAttrsBuilder<'a, 'b, A> {
+    ) -> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        TriluAttrsBuilder {
+        RandomNormalLikeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TriluAttrs<'_> {
+impl core::fmt::Debug for RandomNormalLikeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TriluAttrs");
-        ds.field("upper", &self.upper());
+        let mut ds = f.debug_struct("RandomNormalLikeAttrs");
+        ds.field("mean", &self.mean());
+        ds.field("scale", &self.scale());
+        ds.field("seed", &self.seed());
         ds.finish()
     }
 }
-pub enum OperatorNodeOffset {}
+pub enum RandomUniformAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OperatorNode<'a> {
+pub struct RandomUniformAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
-    type Inner = OperatorNode<'a>;
+impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
+    type Inner = RandomUniformAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RandomUniformAttrs<'a> {
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
+    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
+    pub const VT_LOW: flatbuffers::VOffsetT = 8;
+    pub const VT_SEED: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RandomUniformAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RandomUniformAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
+        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_low(args.low);
+        builder.add_high(args.high);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    RandomUniformAttrs::VT_SHAPE,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn high(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn low(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "shape",
+                Self::VT_SHAPE,
+                false,
+            )?
+            .visit_field::<f32>("high", Self::VT_HIGH, false)?
+            .visit_field::<f32>("low", Self::VT_LOW, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomUniformAttrsArgs<'a> {
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub high: f32,
+    pub low: f32,
+    pub seed: Option<f32>,
+}
+impl<'a> Default for RandomUniformAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        RandomUniformAttrsArgs {
+            shape: None,
+            high: 0.0,
+            low: 0.0,
+            seed: None,
+        }
+    }
+}
+
+pub struct RandomUniformAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
+    }
+    #[inline]
+    pub fn add_high(&mut self, high: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
+    }
+    #[inline]
+    pub fn add_low(&mut self, low: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RandomUniformAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RandomUniformAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomUniformAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomUniformAttrs");
+        ds.field("shape", &self.shape());
+        ds.field("high", &self.high());
+        ds.field("low", &self.low());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum RandomUniformLikeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RandomUniformLikeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
+    type Inner = RandomUniformLikeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RandomUniformLikeAttrs<'a> {
+    pub const VT_HIGH: flatbuffers::VOffsetT = 4;
+    pub const VT_LOW: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RandomUniformLikeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RandomUniformLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'bldr>> {
+        let mut builder = RandomUniformLikeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_low(args.low);
+        builder.add_high(args.high);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn high(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformLikeAttrs::VT_HIGH, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn low(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformLikeAttrs::VT_LOW, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomUniformLikeAttrs::VT_SEED, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("high", Self::VT_HIGH, false)?
+            .visit_field::<f32>("low", Self::VT_LOW, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomUniformLikeAttrsArgs {
+    pub high: f32,
+    pub low: f32,
+    pub seed: Option<f32>,
+}
+impl<'a> Default for RandomUniformLikeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RandomUniformLikeAttrsArgs {
+            high: 0.0,
+            low: 0.0,
+            seed: None,
+        }
+    }
+}
+
+pub struct RandomUniformLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_high(&mut self, high: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformLikeAttrs::VT_HIGH, high, 0.0);
+    }
+    #[inline]
+    pub fn add_low(&mut self, low: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformLikeAttrs::VT_LOW, low, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomUniformLikeAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RandomUniformLikeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomUniformLikeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomUniformLikeAttrs");
+        ds.field("high", &self.high());
+        ds.field("low", &self.low());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum ReduceMeanAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReduceMeanAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
+    type Inner = ReduceMeanAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReduceMeanAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReduceMeanAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ReduceMeanAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
+        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
+        }
+        builder.add_keep_dims(args.keep_dims);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ReduceMeanAttrs::VT_AXES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReduceMeanAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub keep_dims: bool,
+}
+impl<'a> Default for ReduceMeanAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ReduceMeanAttrsArgs {
+            axes: None,
+            keep_dims: false,
+        }
+    }
+}
+
+pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReduceMeanAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
+    }
+    #[inline]
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ReduceMeanAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ReduceMeanAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReduceMeanAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReduceMeanAttrs");
+        ds.field("axes", &self.axes());
+        ds.field("keep_dims", &self.keep_dims());
+        ds.finish()
+    }
+}
+pub enum ReshapeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReshapeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
+    type Inner = ReshapeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReshapeAttrs<'a> {
+    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReshapeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ReshapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
+        let mut builder = ReshapeAttrsBuilder::new(_fbb);
+        builder.add_allow_zero(args.allow_zero);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn allow_zero(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReshapeAttrsArgs {
+    pub allow_zero: bool,
+}
+impl<'a> Default for ReshapeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ReshapeAttrsArgs { allow_zero: false }
+    }
+}
+
+pub struct ReshapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReshapeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_allow_zero(&mut self, allow_zero: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ReshapeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ReshapeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReshapeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReshapeAttrs");
+        ds.field("allow_zero", &self.allow_zero());
+        ds.finish()
+    }
+}
+pub enum ResizeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ResizeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
+    type Inner = ResizeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ResizeAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ResizeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ResizeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
+        let mut builder = ResizeAttrsBuilder::new(_fbb);
+        builder.add_nearest_mode(args.nearest_mode);
+        builder.add_coord_mode(args.coord_mode);
+        builder.add_mode(args.mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> ResizeMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn coord_mode(&self) -> CoordTransformMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<CoordTransformMode>(
+                    ResizeAttrs::VT_COORD_MODE,
+                    Some(CoordTransformMode::HalfPixel),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn nearest_mode(&self) -> NearestMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ResizeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
+            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ResizeAttrsArgs {
+    pub mode: ResizeMode,
+    pub coord_mode: CoordTransformMode,
+    pub nearest_mode: NearestMode,
+}
+impl<'a> Default for ResizeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ResizeAttrsArgs {
+            mode: ResizeMode::Nearest,
+            coord_mode: CoordTransformMode::HalfPixel,
+            nearest_mode: NearestMode::Floor,
+        }
+    }
+}
+
+pub struct ResizeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResizeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_mode(&mut self, mode: ResizeMode) {
+        self.fbb_
+            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
+    }
+    #[inline]
+    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
+        self.fbb_.push_slot::<CoordTransformMode>(
+            ResizeAttrs::VT_COORD_MODE,
+            coord_mode,
+            CoordTransformMode::HalfPixel,
+        );
+    }
+    #[inline]
+    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
+        self.fbb_.push_slot::<NearestMode>(
+            ResizeAttrs::VT_NEAREST_MODE,
+            nearest_mode,
+            NearestMode::Floor,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ResizeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ResizeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ResizeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ResizeAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("coord_mode", &self.coord_mode());
+        ds.field("nearest_mode", &self.nearest_mode());
+        ds.finish()
+    }
+}
+pub enum ReverseSequenceAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReverseSequenceAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReverseSequenceAttrs<'a> {
+    type Inner = ReverseSequenceAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReverseSequenceAttrs<'a> {
+    pub const VT_BATCH_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_TIME_AXIS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReverseSequenceAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ReverseSequenceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ReverseSequenceAttrs<'bldr>> {
+        let mut builder = ReverseSequenceAttrsBuilder::new(_fbb);
+        builder.add_time_axis(args.time_axis);
+        builder.add_batch_axis(args.batch_axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn batch_axis(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ReverseSequenceAttrs::VT_BATCH_AXIS, Some(1))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn time_axis(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ReverseSequenceAttrs::VT_TIME_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReverseSequenceAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("batch_axis", Self::VT_BATCH_AXIS, false)?
+            .visit_field::<u32>("time_axis", Self::VT_TIME_AXIS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReverseSequenceAttrsArgs {
+    pub batch_axis: u32,
+    pub time_axis: u32,
+}
+impl<'a> Default for ReverseSequenceAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ReverseSequenceAttrsArgs {
+            batch_axis: 1,
+            time_axis: 0,
+        }
+    }
+}
+
+pub struct ReverseSequenceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReverseSequenceAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_batch_axis(&mut self, batch_axis: u32) {
+        self.fbb_
+            .push_slot::<u32>(ReverseSequenceAttrs::VT_BATCH_AXIS, batch_axis, 1);
+    }
+    #[inline]
+    pub fn add_time_axis(&mut self, time_axis: u32) {
+        self.fbb_
+            .push_slot::<u32>(ReverseSequenceAttrs::VT_TIME_AXIS, time_axis, 0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ReverseSequenceAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ReverseSequenceAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReverseSequenceAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReverseSequenceAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReverseSequenceAttrs");
+        ds.field("batch_axis", &self.batch_axis());
+        ds.field("time_axis", &self.time_axis());
+        ds.finish()
+    }
+}
+pub enum RNNAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RNNAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RNNAttrs<'a> {
+    type Inner = RNNAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RNNAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_ACTIVATIONS: flatbuffers::VOffsetT = 8;
+    pub const VT_CLIP: flatbuffers::VOffsetT = 10;
+    pub const VT_BATCH_FIRST: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RNNAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RNNAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RNNAttrs<'bldr>> {
+        let mut builder = RNNAttrsBuilder::new(_fbb);
+        if let Some(x) = args.clip {
+            builder.add_clip(x);
+        }
+        if let Some(x) = args.activations {
+            builder.add_activations(x);
+        }
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_batch_first(args.batch_first);
+        builder.add_direction(args.direction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn direction(&self) -> RNNDirection {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<RNNDirection>(RNNAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn hidden_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RNNAttrs::VT_HIDDEN_SIZE, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn activations(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn>>,
+            >>(RNNAttrs::VT_ACTIVATIONS, None)
+        }
+    }
+    #[inline]
+    pub fn clip(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RNNAttrs::VT_CLIP, None) }
+    }
+    #[inline]
+    pub fn batch_first(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(RNNAttrs::VT_BATCH_FIRST, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RNNAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<RNNActivationFn>>,
+            >>("activations", Self::VT_ACTIVATIONS, false)?
+            .visit_field::<f32>("clip", Self::VT_CLIP, false)?
+            .visit_field::<bool>("batch_first", Self::VT_BATCH_FIRST, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RNNAttrsArgs<'a> {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
+    pub activations: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<RNNActivationFn<'a>>>,
+        >,
+    >,
+    pub clip: Option<f32>,
+    pub batch_first: bool,
+}
+impl<'a> Default for RNNAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        RNNAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+            activations: None,
+            clip: None,
+            batch_first: false,
+        }
+    }
+}
+
+pub struct RNNAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RNNAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            RNNAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
+    }
+    #[inline]
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
+        self.fbb_
+            .push_slot::<u32>(RNNAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
+    }
+    #[inline]
+    pub fn add_activations(
+        &mut self,
+        activations: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<RNNActivationFn<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RNNAttrs::VT_ACTIVATIONS, activations);
+    }
+    #[inline]
+    pub fn add_clip(&mut self, clip: f32) {
+        self.fbb_.push_slot_always::<f32>(RNNAttrs::VT_CLIP, clip);
+    }
+    #[inline]
+    pub fn add_batch_first(&mut self, batch_first: bool) {
+        self.fbb_
+            .push_slot::<bool>(RNNAttrs::VT_BATCH_FIRST, batch_first, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RNNAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RNNAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RNNAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RNNAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RNNAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
+        ds.field("activations", &self.activations());
+        ds.field("clip", &self.clip());
+        ds.field("batch_first", &self.batch_first());
+        ds.finish()
+    }
+}
+pub enum RNNActivationFnOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RNNActivationFn<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RNNActivationFn<'a> {
+    type Inner = RNNActivationFn<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RNNActivationFn<'a> {
+    pub const VT_KIND: flatbuffers::VOffsetT = 4;
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 6;
+    pub const VT_BETA: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RNNActivationFn { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RNNActivationFnArgs,
+    ) -> flatbuffers::WIPOffset<RNNActivationFn<'bldr>> {
+        let mut builder = RNNActivationFnBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.add_alpha(args.alpha);
+        builder.add_kind(args.kind);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn kind(&self) -> RNNActivation {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<RNNActivation>(RNNActivationFn::VT_KIND, Some(RNNActivation::Affine))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RNNActivationFn::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn beta(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RNNActivationFn::VT_BETA, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RNNActivationFn<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<RNNActivation>("kind", Self::VT_KIND, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RNNActivationFnArgs {
+    pub kind: RNNActivation,
+    pub alpha: f32,
+    pub beta: f32,
+}
+impl<'a> Default for RNNActivationFnArgs {
+    #[inline]
+    fn default() -> Self {
+        RNNActivationFnArgs {
+            kind: RNNActivation::Affine,
+            alpha: 0.0,
+            beta: 0.0,
+        }
+    }
+}
+
+pub struct RNNActivationFnBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RNNActivationFnBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_kind(&mut self, kind: RNNActivation) {
+        self.fbb_
+            .push_slot::<RNNActivation>(RNNActivationFn::VT_KIND, kind, RNNActivation::Affine);
+    }
+    #[inline]
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_
+            .push_slot::<f32>(RNNActivationFn::VT_ALPHA, alpha, 0.0);
+    }
+    #[inline]
+    pub fn add_beta(&mut self, beta: f32) {
+        self.fbb_
+            .push_slot::<f32>(RNNActivationFn::VT_BETA, beta, 0.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RNNActivationFnBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RNNActivationFnBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RNNActivationFn<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RNNActivationFn<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RNNActivationFn");
+        ds.field("kind", &self.kind());
+        ds.field("alpha", &self.alpha());
+        ds.field("beta", &self.beta());
+        ds.finish()
+    }
+}
+pub enum RoiAlignAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RoiAlignAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RoiAlignAttrs<'a> {
+    type Inner = RoiAlignAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RoiAlignAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_OUTPUT_HEIGHT: flatbuffers::VOffsetT = 8;
+    pub const VT_OUTPUT_WIDTH: flatbuffers::VOffsetT = 10;
+    pub const VT_SAMPLING_RATIO: flatbuffers::VOffsetT = 12;
+    pub const VT_SPATIAL_SCALE: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RoiAlignAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RoiAlignAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RoiAlignAttrs<'bldr>> {
+        let mut builder = RoiAlignAttrsBuilder::new(_fbb);
+        builder.add_spatial_scale(args.spatial_scale);
+        builder.add_sampling_ratio(args.sampling_ratio);
+        builder.add_output_width(args.output_width);
+        builder.add_output_height(args.output_height);
+        builder.add_coord_mode(args.coord_mode);
+        builder.add_mode(args.mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> RoiAlignMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<RoiAlignMode>(RoiAlignAttrs::VT_MODE, Some(RoiAlignMode::Avg))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn coord_mode(&self) -> RoiAlignCoordMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<RoiAlignCoordMode>(
+                    RoiAlignAttrs::VT_COORD_MODE,
+                    Some(RoiAlignCoordMode::HalfPixel),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn output_height(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RoiAlignAttrs::VT_OUTPUT_HEIGHT, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn output_width(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RoiAlignAttrs::VT_OUTPUT_WIDTH, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn sampling_ratio(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RoiAlignAttrs::VT_SAMPLING_RATIO, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn spatial_scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RoiAlignAttrs::VT_SPATIAL_SCALE, Some(1.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RoiAlignAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<RoiAlignMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<RoiAlignCoordMode>("coord_mode", Self::VT_COORD_MODE, false)?
+            .visit_field::<u32>("output_height", Self::VT_OUTPUT_HEIGHT, false)?
+            .visit_field::<u32>("output_width", Self::VT_OUTPUT_WIDTH, false)?
+            .visit_field::<u32>("sampling_ratio", Self::VT_SAMPLING_RATIO, false)?
+            .visit_field::<f32>("spatial_scale", Self::VT_SPATIAL_SCALE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RoiAlignAttrsArgs {
+    pub mode: RoiAlignMode,
+    pub coord_mode: RoiAlignCoordMode,
+    pub output_height: u32,
+    pub output_width: u32,
+    pub sampling_ratio: u32,
+    pub spatial_scale: f32,
+}
+impl<'a> Default for RoiAlignAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RoiAlignAttrsArgs {
+            mode: RoiAlignMode::Avg,
+            coord_mode: RoiAlignCoordMode::HalfPixel,
+            output_height: 0,
+            output_width: 0,
+            sampling_ratio: 0,
+            spatial_scale: 1.0,
+        }
+    }
+}
+
+pub struct RoiAlignAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RoiAlignAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_mode(&mut self, mode: RoiAlignMode) {
+        self.fbb_
+            .push_slot::<RoiAlignMode>(RoiAlignAttrs::VT_MODE, mode, RoiAlignMode::Avg);
+    }
+    #[inline]
+    pub fn add_coord_mode(&mut self, coord_mode: RoiAlignCoordMode) {
+        self.fbb_.push_slot::<RoiAlignCoordMode>(
+            RoiAlignAttrs::VT_COORD_MODE,
+            coord_mode,
+            RoiAlignCoordMode::HalfPixel,
+        );
+    }
+    #[inline]
+    pub fn add_output_height(&mut self, output_height: u32) {
+        self.fbb_
+            .push_slot::<u32>(RoiAlignAttrs::VT_OUTPUT_HEIGHT, output_height, 0);
+    }
+    #[inline]
+    pub fn add_output_width(&mut self, output_width: u32) {
+        self.fbb_
+            .push_slot::<u32>(RoiAlignAttrs::VT_OUTPUT_WIDTH, output_width, 0);
+    }
+    #[inline]
+    pub fn add_sampling_ratio(&mut self, sampling_ratio: u32) {
+        self.fbb_
+            .push_slot::<u32>(RoiAlignAttrs::VT_SAMPLING_RATIO, sampling_ratio, 0);
+    }
+    #[inline]
+    pub fn add_spatial_scale(&mut self, spatial_scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RoiAlignAttrs::VT_SPATIAL_SCALE, spatial_scale, 1.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RoiAlignAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RoiAlignAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RoiAlignAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RoiAlignAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RoiAlignAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("coord_mode", &self.coord_mode());
+        ds.field("output_height", &self.output_height());
+        ds.field("output_width", &self.output_width());
+        ds.field("sampling_ratio", &self.sampling_ratio());
+        ds.field("spatial_scale", &self.spatial_scale());
+        ds.finish()
+    }
+}
+pub enum RotaryEmbeddingAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RotaryEmbeddingAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RotaryEmbeddingAttrs<'a> {
+    type Inner = RotaryEmbeddingAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> RotaryEmbeddingAttrs<'a> {
+    pub const VT_INTERLEAVED: flatbuffers::VOffsetT = 4;
+    pub const VT_NUM_HEADS: flatbuffers::VOffsetT = 6;
+    pub const VT_ROTARY_EMBEDDING_DIM: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RotaryEmbeddingAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RotaryEmbeddingAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RotaryEmbeddingAttrs<'bldr>> {
+        let mut builder = RotaryEmbeddingAttrsBuilder::new(_fbb);
+        builder.add_rotary_embedding_dim(args.rotary_embedding_dim);
+        builder.add_num_heads(args.num_heads);
+        builder.add_interleaved(args.interleaved);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn interleaved(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(RotaryEmbeddingAttrs::VT_INTERLEAVED, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn num_heads(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RotaryEmbeddingAttrs::VT_NUM_HEADS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn rotary_embedding_dim(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RotaryEmbeddingAttrs::VT_ROTARY_EMBEDDING_DIM, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RotaryEmbeddingAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("interleaved", Self::VT_INTERLEAVED, false)?
+            .visit_field::<u32>("num_heads", Self::VT_NUM_HEADS, false)?
+            .visit_field::<u32>("rotary_embedding_dim", Self::VT_ROTARY_EMBEDDING_DIM, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RotaryEmbeddingAttrsArgs {
+    pub interleaved: bool,
+    pub num_heads: u32,
+    pub rotary_embedding_dim: u32,
+}
+impl<'a> Default for RotaryEmbeddingAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RotaryEmbeddingAttrsArgs {
+            interleaved: false,
+            num_heads: 0,
+            rotary_embedding_dim: 0,
+        }
+    }
+}
+
+pub struct RotaryEmbeddingAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RotaryEmbeddingAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_interleaved(&mut self, interleaved: bool) {
+        self.fbb_
+            .push_slot::<bool>(RotaryEmbeddingAttrs::VT_INTERLEAVED, interleaved, false);
+    }
+    #[inline]
+    pub fn add_num_heads(&mut self, num_heads: u32) {
+        self.fbb_
+            .push_slot::<u32>(RotaryEmbeddingAttrs::VT_NUM_HEADS, num_heads, 0);
+    }
+    #[inline]
+    pub fn add_rotary_embedding_dim(&mut self, rotary_embedding_dim: u32) {
+        self.fbb_.push_slot::<u32>(
+            RotaryEmbeddingAttrs::VT_ROTARY_EMBEDDING_DIM,
+            rotary_embedding_dim,
+            0,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RotaryEmbeddingAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RotaryEmbeddingAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RotaryEmbeddingAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RotaryEmbeddingAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RotaryEmbeddingAttrs");
+        ds.field("interleaved", &self.interleaved());
+        ds.field("num_heads", &self.num_heads());
+        ds.field("rotary_embedding_dim", &self.rotary_embedding_dim());
+        ds.finish()
+    }
+}
+pub enum ScatterElementsAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScatterElementsAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
+    type Inner = ScatterElementsAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ScatterElementsAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScatterElementsAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ScatterElementsAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
+        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_reduction(args.reduction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn reduction(&self) -> ScatterReduction {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ScatterReduction>(
+                    ScatterElementsAttrs::VT_REDUCTION,
+                    Some(ScatterReduction::None),
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScatterElementsAttrsArgs {
+    pub axis: i32,
+    pub reduction: ScatterReduction,
+}
+impl<'a> Default for ScatterElementsAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ScatterElementsAttrsArgs {
+            axis: 0,
+            reduction: ScatterReduction::None,
+        }
+    }
+}
+
+pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterElementsAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterElementsAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ScatterElementsAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ScatterElementsAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScatterElementsAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScatterElementsAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("reduction", &self.reduction());
+        ds.finish()
+    }
+}
+pub enum ScatterNDAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScatterNDAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
+    type Inner = ScatterNDAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ScatterNDAttrs<'a> {
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScatterNDAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ScatterNDAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
+        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
+        builder.add_reduction(args.reduction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn reduction(&self) -> ScatterReduction {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScatterNDAttrsArgs {
+    pub reduction: ScatterReduction,
+}
+impl<'a> Default for ScatterNDAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ScatterNDAttrsArgs {
+            reduction: ScatterReduction::None,
+        }
+    }
+}
+
+pub struct ScatterNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterNDAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterNDAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ScatterNDAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ScatterNDAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScatterNDAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScatterNDAttrs");
+        ds.field("reduction", &self.reduction());
+        ds.finish()
+    }
+}
+pub enum SeluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SeluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SeluAttrs<'a> {
+    type Inner = SeluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SeluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_GAMMA: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SeluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SeluAttrs<'bldr>> {
+        let mut builder = SeluAttrsBuilder::new(_fbb);
+        builder.add_gamma(args.gamma);
+        builder.add_alpha(args.alpha);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(SeluAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn gamma(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(SeluAttrs::VT_GAMMA, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SeluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("gamma", Self::VT_GAMMA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SeluAttrsArgs {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+impl<'a> Default for SeluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SeluAttrsArgs {
+            alpha: 0.0,
+            gamma: 0.0,
+        }
+    }
+}
+
+pub struct SeluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SeluAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_ALPHA, alpha, 0.0);
+    }
+    #[inline]
+    pub fn add_gamma(&mut self, gamma: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_GAMMA, gamma, 0.0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SeluAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SeluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SeluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SeluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SeluAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("gamma", &self.gamma());
+        ds.finish()
+    }
+}
+pub enum ShrinkAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ShrinkAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ShrinkAttrs<'a> {
+    type Inner = ShrinkAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ShrinkAttrs<'a> {
+    pub const VT_BIAS: flatbuffers::VOffsetT = 4;
+    pub const VT_LAMBD: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ShrinkAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ShrinkAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ShrinkAttrs<'bldr>> {
+        let mut builder = ShrinkAttrsBuilder::new(_fbb);
+        builder.add_lambd(args.lambd);
+        builder.add_bias(args.bias);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn bias(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ShrinkAttrs::VT_BIAS, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn lambd(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ShrinkAttrs::VT_LAMBD, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ShrinkAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("bias", Self::VT_BIAS, false)?
+            .visit_field::<f32>("lambd", Self::VT_LAMBD, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ShrinkAttrsArgs {
+    pub bias: f32,
+    pub lambd: f32,
+}
+impl<'a> Default for ShrinkAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ShrinkAttrsArgs {
+            bias: 0.0,
+            lambd: 0.0,
+        }
+    }
+}
+
+pub struct ShrinkAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ShrinkAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_bias(&mut self, bias: f32) {
+        self.fbb_.push_slot::<f32>(ShrinkAttrs::VT_BIAS, bias, 0.0);
+    }
+    #[inline]
+    pub fn add_lambd(&mut self, lambd: f32) {
+        self.fbb_
+            .push_slot::<f32>(ShrinkAttrs::VT_LAMBD, lambd, 0.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ShrinkAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ShrinkAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ShrinkAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ShrinkAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ShrinkAttrs");
+        ds.field("bias", &self.bias());
+        ds.field("lambd", &self.lambd());
+        ds.finish()
+    }
+}
+pub enum SoftmaxAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SoftmaxAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
+    type Inner = SoftmaxAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SoftmaxAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_FLATTEN: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SoftmaxAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SoftmaxAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
+        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_flatten(args.flatten);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn flatten(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(SoftmaxAttrs::VT_FLATTEN, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("flatten", Self::VT_FLATTEN, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SoftmaxAttrsArgs {
+    pub axis: i32,
+    pub flatten: bool,
+}
+impl<'a> Default for SoftmaxAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SoftmaxAttrsArgs {
+            axis: 0,
+            flatten: false,
+        }
+    }
+}
+
+pub struct SoftmaxAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SoftmaxAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_flatten(&mut self, flatten: bool) {
+        self.fbb_
+            .push_slot::<bool>(SoftmaxAttrs::VT_FLATTEN, flatten, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SoftmaxAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SoftmaxAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SoftmaxAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SoftmaxAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("flatten", &self.flatten());
+        ds.finish()
+    }
+}
+pub enum STFTAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct STFTAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for STFTAttrs<'a> {
+    type Inner = STFTAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> STFTAttrs<'a> {
+    pub const VT_ONESIDED: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        STFTAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args STFTAttrsArgs,
+    ) -> flatbuffers::WIPOffset<STFTAttrs<'bldr>> {
+        let mut builder = STFTAttrsBuilder::new(_fbb);
+        builder.add_onesided(args.onesided);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn onesided(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(STFTAttrs::VT_ONESIDED, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for STFTAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("onesided", Self::VT_ONESIDED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct STFTAttrsArgs {
+    pub onesided: bool,
+}
+impl<'a> Default for STFTAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        STFTAttrsArgs { onesided: false }
+    }
+}
+
+pub struct STFTAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> STFTAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_onesided(&mut self, onesided: bool) {
+        self.fbb_
+            .push_slot::<bool>(STFTAttrs::VT_ONESIDED, onesided, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> STFTAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        STFTAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<STFTAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for STFTAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("STFTAttrs");
+        ds.field("onesided", &self.onesided());
+        ds.finish()
+    }
+}
+pub enum SpaceToDepthAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpaceToDepthAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpaceToDepthAttrs<'a> {
+    type Inner = SpaceToDepthAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SpaceToDepthAttrs<'a> {
+    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SpaceToDepthAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SpaceToDepthAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SpaceToDepthAttrs<'bldr>> {
+        let mut builder = SpaceToDepthAttrsBuilder::new(_fbb);
+        builder.add_block_size(args.block_size);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn block_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(SpaceToDepthAttrs::VT_BLOCK_SIZE, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SpaceToDepthAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SpaceToDepthAttrsArgs {
+    pub block_size: u32,
+}
+impl<'a> Default for SpaceToDepthAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SpaceToDepthAttrsArgs { block_size: 0 }
+    }
+}
+
+pub struct SpaceToDepthAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpaceToDepthAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_block_size(&mut self, block_size: u32) {
+        self.fbb_
+            .push_slot::<u32>(SpaceToDepthAttrs::VT_BLOCK_SIZE, block_size, 0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SpaceToDepthAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SpaceToDepthAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SpaceToDepthAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SpaceToDepthAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SpaceToDepthAttrs");
+        ds.field("block_size", &self.block_size());
+        ds.finish()
+    }
+}
+pub enum SplitAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SplitAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
+    type Inner = SplitAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> SplitAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_NUM_OUTPUTS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SplitAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SplitAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
+        let mut builder = SplitAttrsBuilder::new(_fbb);
+        if let Some(x) = args.num_outputs {
+            builder.add_num_outputs(x);
+        }
+        builder.add_axis(args.axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn num_outputs(&self) -> Option<u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(SplitAttrs::VT_NUM_OUTPUTS, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for SplitAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<u32>("num_outputs", Self::VT_NUM_OUTPUTS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SplitAttrsArgs {
+    pub axis: i32,
+    pub num_outputs: Option<u32>,
+}
+impl<'a> Default for SplitAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SplitAttrsArgs {
+            axis: 0,
+            num_outputs: None,
+        }
+    }
+}
+
+pub struct SplitAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SplitAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_num_outputs(&mut self, num_outputs: u32) {
+        self.fbb_
+            .push_slot_always::<u32>(SplitAttrs::VT_NUM_OUTPUTS, num_outputs);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SplitAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SplitAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SplitAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SplitAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("num_outputs", &self.num_outputs());
+        ds.finish()
+    }
+}
+pub enum TopKAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TopKAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
+    type Inner = TopKAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TopKAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
+    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TopKAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args TopKAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
+        let mut builder = TopKAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_sorted(args.sorted);
+        builder.add_largest(args.largest);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn largest(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn sorted(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TopKAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
+            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TopKAttrsArgs {
+    pub axis: i32,
+    pub largest: bool,
+    pub sorted: bool,
+}
+impl<'a> Default for TopKAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        TopKAttrsArgs {
+            axis: 0,
+            largest: false,
+            sorted: false,
+        }
+    }
+}
+
+pub struct TopKAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TopKAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_largest(&mut self, largest: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
+    }
+    #[inline]
+    pub fn add_sorted(&mut self, sorted: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TopKAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        TopKAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TopKAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TopKAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("largest", &self.largest());
+        ds.field("sorted", &self.sorted());
+        ds.finish()
+    }
+}
+pub enum TransposeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TransposeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
+    type Inner = TransposeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TransposeAttrs<'a> {
+    pub const VT_PERM: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TransposeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args TransposeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
+        let mut builder = TransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.perm {
+            builder.add_perm(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    TransposeAttrs::VT_PERM,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TransposeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "perm",
+                Self::VT_PERM,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TransposeAttrsArgs<'a> {
+    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for TransposeAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TransposeAttrsArgs { perm: None }
+    }
+}
+
+pub struct TransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransposeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> TransposeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        TransposeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TransposeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TransposeAttrs");
+        ds.field("perm", &self.perm());
+        ds.finish()
+    }
+}
+pub enum TriluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TriluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
+    type Inner = TriluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TriluAttrs<'a> {
+    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TriluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args TriluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
+        let mut builder = TriluAttrsBuilder::new(_fbb);
+        builder.add_upper(args.upper);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn upper(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TriluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TriluAttrsArgs {
+    pub upper: bool,
+}
+impl<'a> Default for TriluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        TriluAttrsArgs { upper: false }
+    }
+}
+
+pub struct TriluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TriluAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_upper(&mut self, upper: bool) {
+        self.fbb_
+            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> TriluAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        TriluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TriluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TriluAttrs");
+        ds.field("upper", &self.upper());
+        ds.finish()
+    }
+}
+pub enum UniqueAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct UniqueAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for UniqueAttrs<'a> {
+    type Inner = UniqueAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> UniqueAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_SORTED: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        UniqueAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args UniqueAttrsArgs,
+    ) -> flatbuffers::WIPOffset<UniqueAttrs<'bldr>> {
+        let mut builder = UniqueAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axis {
+            builder.add_axis(x);
+        }
+        builder.add_sorted(args.sorted);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> Option<i32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(UniqueAttrs::VT_AXIS, None) }
+    }
+    #[inline]
+    pub fn sorted(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(UniqueAttrs::VT_SORTED, Some(true))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for UniqueAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct UniqueAttrsArgs {
+    pub axis: Option<i32>,
+    pub sorted: bool,
+}
+impl<'a> Default for UniqueAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        UniqueAttrsArgs {
+            axis: None,
+            sorted: true,
+        }
+    }
+}
+
+pub struct UniqueAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UniqueAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot_always::<i32>(UniqueAttrs::VT_AXIS, axis);
+    }
+    #[inline]
+    pub fn add_sorted(&mut self, sorted: bool) {
+        self.fbb_
+            .push_slot::<bool>(UniqueAttrs::VT_SORTED, sorted, true);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> UniqueAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        UniqueAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<UniqueAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for UniqueAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("UniqueAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("sorted", &self.sorted());
+        ds.finish()
+    }
+}
+pub enum WindowAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WindowAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WindowAttrs<'a> {
+    type Inner = WindowAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> WindowAttrs<'a> {
+    pub const VT_PERIODIC: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        WindowAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args WindowAttrsArgs,
+    ) -> flatbuffers::WIPOffset<WindowAttrs<'bldr>> {
+        let mut builder = WindowAttrsBuilder::new(_fbb);
+        builder.add_periodic(args.periodic);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn periodic(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(WindowAttrs::VT_PERIODIC, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for WindowAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("periodic", Self::VT_PERIODIC, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct WindowAttrsArgs {
+    pub periodic: bool,
+}
+impl<'a> Default for WindowAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        WindowAttrsArgs { periodic: false }
+    }
+}
+
+pub struct WindowAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> WindowAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_periodic(&mut self, periodic: bool) {
+        self.fbb_
+            .push_slot::<bool>(WindowAttrs::VT_PERIODIC, periodic, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> WindowAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        WindowAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<WindowAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for WindowAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("WindowAttrs");
+        ds.field("periodic", &self.periodic());
+        ds.finish()
+    }
+}
+pub enum OperatorNodeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OperatorNode<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
+    type Inner = OperatorNode<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> OperatorNode<'a> {
+    pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
+    pub const VT_ATTRS_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
+    pub const VT_INPUTS: flatbuffers::VOffsetT = 10;
+    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        OperatorNode { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args OperatorNodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<OperatorNode<'bldr>> {
+        let mut builder = OperatorNodeBuilder::new(_fbb);
+        if let Some(x) = args.outputs {
+            builder.add_outputs(x);
+        }
+        if let Some(x) = args.inputs {
+            builder.add_inputs(x);
+        }
+        if let Some(x) = args.attrs {
+            builder.add_attrs(x);
+        }
+        builder.add_attrs_type(args.attrs_type);
+        builder.add_type_(args.type_);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn type_(&self) -> OperatorType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<OperatorType>(OperatorNode::VT_TYPE_, Some(OperatorType::Add))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn attrs_type(&self) -> OperatorAttrs {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<OperatorAttrs>(OperatorNode::VT_ATTRS_TYPE, Some(OperatorAttrs::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn attrs(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    OperatorNode::VT_ATTRS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    OperatorNode::VT_INPUTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    OperatorNode::VT_OUTPUTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_arg_max_attrs(&self) -> Option<ArgMaxAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ArgMaxAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ArgMaxAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_average_pool_attrs(&self) -> Option<AveragePoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::AveragePoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { AveragePoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_batch_normalization_attrs(&self) -> Option<BatchNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::BatchNormalizationAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { BatchNormalizationAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_cast_attrs(&self) -> Option<CastAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CastAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { CastAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_concat_attrs(&self) -> Option<ConcatAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConcatAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConcatAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_constant_of_shape_attrs(&self) -> Option<ConstantOfShapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConstantOfShapeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConstantOfShapeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_conv_attrs(&self) -> Option<ConvAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConvAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConvAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_conv_transpose_attrs(&self) -> Option<ConvTransposeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConvTransposeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConvTransposeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_flatten_attrs(&self) -> Option<FlattenAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::FlattenAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FlattenAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gather_attrs(&self) -> Option<GatherAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GatherAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GatherAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gemm_attrs(&self) -> Option<GemmAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GemmAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GemmAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gruattrs(&self) -> Option<GRUAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GRUAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GRUAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_leaky_relu_attrs(&self) -> Option<LeakyReluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LeakyReluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LeakyReluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_lstmattrs(&self) -> Option<LSTMAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LSTMAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LSTMAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
     #[inline]
-    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
+    #[allow(non_snake_case)]
+    pub fn attrs_as_max_pool_attrs(&self) -> Option<MaxPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MaxPoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MaxPoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
-}
-
-impl<'a> OperatorNode<'a> {
-    pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
-    pub const VT_ATTRS_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
-    pub const VT_INPUTS: flatbuffers::VOffsetT = 10;
-    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 12;
 
     #[inline]
-    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        OperatorNode { _tab: table }
-    }
-    #[allow(unused_mut)]
-    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args OperatorNodeArgs<'args>,
-    ) -> flatbuffers::WIPOffset<OperatorNode<'bldr>> {
-        let mut builder = OperatorNodeBuilder::new(_fbb);
-        if let Some(x) = args.outputs {
-            builder.add_outputs(x);
-        }
-        if let Some(x) = args.inputs {
-            builder.add_inputs(x);
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reduce_mean_attrs(&self) -> Option<ReduceMeanAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReduceMeanAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReduceMeanAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
-        if let Some(x) = args.attrs {
-            builder.add_attrs(x);
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reshape_attrs(&self) -> Option<ReshapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReshapeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReshapeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
-        builder.add_attrs_type(args.attrs_type);
-        builder.add_type_(args.type_);
-        builder.finish()
     }
 
     #[inline]
-    pub fn type_(&self) -> OperatorType {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<OperatorType>(OperatorNode::VT_TYPE_, Some(OperatorType::Add))
-                .unwrap()
+    #[allow(non_snake_case)]
+    pub fn attrs_as_resize_attrs(&self) -> Option<ResizeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ResizeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ResizeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
+
     #[inline]
-    pub fn attrs_type(&self) -> OperatorAttrs {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<OperatorAttrs>(OperatorNode::VT_ATTRS_TYPE, Some(OperatorAttrs::NONE))
-                .unwrap()
+    #[allow(non_snake_case)]
+    pub fn attrs_as_split_attrs(&self) -> Option<SplitAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SplitAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SplitAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
+
     #[inline]
-    pub fn attrs(&self) -> Option<flatbuffers::Table<'a>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    OperatorNode::VT_ATTRS,
-                    None,
-                )
+    #[allow(non_snake_case)]
+    pub fn attrs_as_softmax_attrs(&self) -> Option<SoftmaxAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SoftmaxAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SoftmaxAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
+
     #[inline]
-    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    OperatorNode::VT_INPUTS,
-                    None,
-                )
+    #[allow(non_snake_case)]
+    pub fn attrs_as_transpose_attrs(&self) -> Option<TransposeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TransposeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TransposeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
+
     #[inline]
-    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    OperatorNode::VT_OUTPUTS,
-                    None,
-                )
+    #[allow(non_snake_case)]
+    pub fn attrs_as_mod_attrs(&self) -> Option<ModAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ModAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ModAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
+
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_arg_max_attrs(&self) -> Option<ArgMaxAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ArgMaxAttrs {
+    pub fn attrs_as_scatter_elements_attrs(&self) -> Option<ScatterElementsAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScatterElementsAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ArgMaxAttrs::init_from_table(t) }
+                unsafe { ScatterElementsAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7235,13 +11958,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_average_pool_attrs(&self) -> Option<AveragePoolAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::AveragePoolAttrs {
+    pub fn attrs_as_one_hot_attrs(&self) -> Option<OneHotAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::OneHotAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { AveragePoolAttrs::init_from_table(t) }
+                unsafe { OneHotAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7250,13 +11973,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_batch_normalization_attrs(&self) -> Option<BatchNormalizationAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::BatchNormalizationAttrs {
+    pub fn attrs_as_top_kattrs(&self) -> Option<TopKAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TopKAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { BatchNormalizationAttrs::init_from_table(t) }
+                unsafe { TopKAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7265,13 +11988,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_cast_attrs(&self) -> Option<CastAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::CastAttrs {
+    pub fn attrs_as_hard_sigmoid_attrs(&self) -> Option<HardSigmoidAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::HardSigmoidAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { CastAttrs::init_from_table(t) }
+                unsafe { HardSigmoidAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7280,13 +12003,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_concat_attrs(&self) -> Option<ConcatAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConcatAttrs {
+    pub fn attrs_as_trilu_attrs(&self) -> Option<TriluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TriluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConcatAttrs::init_from_table(t) }
+                unsafe { TriluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7295,13 +12018,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_constant_of_shape_attrs(&self) -> Option<ConstantOfShapeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConstantOfShapeAttrs {
+    pub fn attrs_as_scatter_ndattrs(&self) -> Option<ScatterNDAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScatterNDAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConstantOfShapeAttrs::init_from_table(t) }
+                unsafe { ScatterNDAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7310,13 +12033,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_conv_attrs(&self) -> Option<ConvAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConvAttrs {
+    pub fn attrs_as_non_max_suppression_attrs(&self) -> Option<NonMaxSuppressionAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::NonMaxSuppressionAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConvAttrs::init_from_table(t) }
+                unsafe { NonMaxSuppressionAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7325,13 +12048,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_conv_transpose_attrs(&self) -> Option<ConvTransposeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConvTransposeAttrs {
+    pub fn attrs_as_layer_normalization_attrs(&self) -> Option<LayerNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LayerNormalizationAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConvTransposeAttrs::init_from_table(t) }
+                unsafe { LayerNormalizationAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7340,13 +12063,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_flatten_attrs(&self) -> Option<FlattenAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::FlattenAttrs {
+    pub fn attrs_as_random_uniform_attrs(&self) -> Option<RandomUniformAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomUniformAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { FlattenAttrs::init_from_table(t) }
+                unsafe { RandomUniformAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7355,13 +12078,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gather_attrs(&self) -> Option<GatherAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GatherAttrs {
+    pub fn attrs_as_elu_attrs(&self) -> Option<EluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::EluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GatherAttrs::init_from_table(t) }
+                unsafe { EluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7370,13 +12093,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gemm_attrs(&self) -> Option<GemmAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GemmAttrs {
+    pub fn attrs_as_random_uniform_like_attrs(&self) -> Option<RandomUniformLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomUniformLikeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GemmAttrs::init_from_table(t) }
+                unsafe { RandomUniformLikeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7385,13 +12108,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gruattrs(&self) -> Option<GRUAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GRUAttrs {
+    pub fn attrs_as_random_normal_attrs(&self) -> Option<RandomNormalAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomNormalAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GRUAttrs::init_from_table(t) }
+                unsafe { RandomNormalAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7400,13 +12123,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_leaky_relu_attrs(&self) -> Option<LeakyReluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LeakyReluAttrs {
+    pub fn attrs_as_random_normal_like_attrs(&self) -> Option<RandomNormalLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomNormalLikeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { LeakyReluAttrs::init_from_table(t) }
+                unsafe { RandomNormalLikeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7415,13 +12138,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_lstmattrs(&self) -> Option<LSTMAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LSTMAttrs {
+    pub fn attrs_as_gather_ndattrs(&self) -> Option<GatherNDAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GatherNDAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { LSTMAttrs::init_from_table(t) }
+                unsafe { GatherNDAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7430,13 +12153,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_max_pool_attrs(&self) -> Option<MaxPoolAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::MaxPoolAttrs {
+    pub fn attrs_as_gelu_attrs(&self) -> Option<GeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GeluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { MaxPoolAttrs::init_from_table(t) }
+                unsafe { GeluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7445,13 +12168,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_reduce_mean_attrs(&self) -> Option<ReduceMeanAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ReduceMeanAttrs {
+    pub fn attrs_as_einsum_attrs(&self) -> Option<EinsumAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::EinsumAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ReduceMeanAttrs::init_from_table(t) }
+                unsafe { EinsumAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7460,13 +12183,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_reshape_attrs(&self) -> Option<ReshapeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ReshapeAttrs {
+    pub fn attrs_as_depth_to_space_attrs(&self) -> Option<DepthToSpaceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::DepthToSpaceAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ReshapeAttrs::init_from_table(t) }
+                unsafe { DepthToSpaceAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7475,13 +12198,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_resize_attrs(&self) -> Option<ResizeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ResizeAttrs {
+    pub fn attrs_as_space_to_depth_attrs(&self) -> Option<SpaceToDepthAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SpaceToDepthAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ResizeAttrs::init_from_table(t) }
+                unsafe { SpaceToDepthAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7490,13 +12213,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_split_attrs(&self) -> Option<SplitAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SplitAttrs {
+    pub fn attrs_as_roi_align_attrs(&self) -> Option<RoiAlignAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RoiAlignAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { SplitAttrs::init_from_table(t) }
+                unsafe { RoiAlignAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7505,13 +12228,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_softmax_attrs(&self) -> Option<SoftmaxAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SoftmaxAttrs {
+    pub fn attrs_as_dftattrs(&self) -> Option<DFTAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::DFTAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { SoftmaxAttrs::init_from_table(t) }
+                unsafe { DFTAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7520,13 +12243,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_transpose_attrs(&self) -> Option<TransposeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TransposeAttrs {
+    pub fn attrs_as_stftattrs(&self) -> Option<STFTAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::STFTAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { TransposeAttrs::init_from_table(t) }
+                unsafe { STFTAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7535,13 +12258,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_mod_attrs(&self) -> Option<ModAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ModAttrs {
+    pub fn attrs_as_window_attrs(&self) -> Option<WindowAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::WindowAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ModAttrs::init_from_table(t) }
+                unsafe { WindowAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7550,13 +12273,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_scatter_elements_attrs(&self) -> Option<ScatterElementsAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ScatterElementsAttrs {
+    pub fn attrs_as_rotary_embedding_attrs(&self) -> Option<RotaryEmbeddingAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RotaryEmbeddingAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ScatterElementsAttrs::init_from_table(t) }
+                unsafe { RotaryEmbeddingAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7565,13 +12288,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_one_hot_attrs(&self) -> Option<OneHotAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::OneHotAttrs {
+    pub fn attrs_as_lp_pool_attrs(&self) -> Option<LpPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LpPoolAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { OneHotAttrs::init_from_table(t) }
+                unsafe { LpPoolAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7580,13 +12303,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_top_kattrs(&self) -> Option<TopKAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TopKAttrs {
+    pub fn attrs_as_global_lp_pool_attrs(&self) -> Option<GlobalLpPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GlobalLpPoolAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { TopKAttrs::init_from_table(t) }
+                unsafe { GlobalLpPoolAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7595,13 +12318,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_hard_sigmoid_attrs(&self) -> Option<HardSigmoidAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::HardSigmoidAttrs {
+    pub fn attrs_as_max_unpool_attrs(&self) -> Option<MaxUnpoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MaxUnpoolAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { HardSigmoidAttrs::init_from_table(t) }
+                unsafe { MaxUnpoolAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7610,13 +12333,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_trilu_attrs(&self) -> Option<TriluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TriluAttrs {
+    pub fn attrs_as_cum_sum_attrs(&self) -> Option<CumSumAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CumSumAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { TriluAttrs::init_from_table(t) }
+                unsafe { CumSumAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7625,13 +12348,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_scatter_ndattrs(&self) -> Option<ScatterNDAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ScatterNDAttrs {
+    pub fn attrs_as_selu_attrs(&self) -> Option<SeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SeluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ScatterNDAttrs::init_from_table(t) }
+                unsafe { SeluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7640,13 +12363,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_non_max_suppression_attrs(&self) -> Option<NonMaxSuppressionAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::NonMaxSuppressionAttrs {
+    pub fn attrs_as_shrink_attrs(&self) -> Option<ShrinkAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ShrinkAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { NonMaxSuppressionAttrs::init_from_table(t) }
+                unsafe { ShrinkAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7655,13 +12378,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_layer_normalization_attrs(&self) -> Option<LayerNormalizationAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LayerNormalizationAttrs {
+    pub fn attrs_as_bit_shift_attrs(&self) -> Option<BitShiftAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::BitShiftAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { LayerNormalizationAttrs::init_from_table(t) }
+                unsafe { BitShiftAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7670,13 +12393,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_uniform_attrs(&self) -> Option<RandomUniformAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomUniformAttrs {
+    pub fn attrs_as_is_inf_attrs(&self) -> Option<IsInfAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::IsInfAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomUniformAttrs::init_from_table(t) }
+                unsafe { IsInfAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7685,13 +12408,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_elu_attrs(&self) -> Option<EluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::EluAttrs {
+    pub fn attrs_as_center_crop_pad_attrs(&self) -> Option<CenterCropPadAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CenterCropPadAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { EluAttrs::init_from_table(t) }
+                unsafe { CenterCropPadAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7700,13 +12423,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_uniform_like_attrs(&self) -> Option<RandomUniformLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomUniformLikeAttrs {
+    pub fn attrs_as_compress_attrs(&self) -> Option<CompressAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CompressAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomUniformLikeAttrs::init_from_table(t) }
+                unsafe { CompressAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7715,13 +12438,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_normal_attrs(&self) -> Option<RandomNormalAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomNormalAttrs {
+    pub fn attrs_as_reverse_sequence_attrs(&self) -> Option<ReverseSequenceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReverseSequenceAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomNormalAttrs::init_from_table(t) }
+                unsafe { ReverseSequenceAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7730,13 +12453,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_normal_like_attrs(&self) -> Option<RandomNormalLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomNormalLikeAttrs {
+    pub fn attrs_as_unique_attrs(&self) -> Option<UniqueAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::UniqueAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomNormalLikeAttrs::init_from_table(t) }
+                unsafe { UniqueAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7745,13 +12468,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gather_ndattrs(&self) -> Option<GatherNDAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GatherNDAttrs {
+    pub fn attrs_as_rnnattrs(&self) -> Option<RNNAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RNNAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GatherNDAttrs::init_from_table(t) }
+                unsafe { RNNAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7760,13 +12483,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gelu_attrs(&self) -> Option<GeluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GeluAttrs {
+    pub fn attrs_as_bernoulli_attrs(&self) -> Option<BernoulliAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::BernoulliAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GeluAttrs::init_from_table(t) }
+                unsafe { BernoulliAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7775,13 +12498,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_einsum_attrs(&self) -> Option<EinsumAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::EinsumAttrs {
+    pub fn attrs_as_multinomial_attrs(&self) -> Option<MultinomialAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MultinomialAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { EinsumAttrs::init_from_table(t) }
+                unsafe { MultinomialAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -7838,6 +12561,28 @@ impl flatbuffers::Verifiable for OperatorNode<'_> {
           OperatorAttrs::GatherNDAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GatherNDAttrs>>("OperatorAttrs::GatherNDAttrs", pos),
           OperatorAttrs::GeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GeluAttrs>>("OperatorAttrs::GeluAttrs", pos),
           OperatorAttrs::EinsumAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<EinsumAttrs>>("OperatorAttrs::EinsumAttrs", pos),
+          OperatorAttrs::DepthToSpaceAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DepthToSpaceAttrs>>("OperatorAttrs::DepthToSpaceAttrs", pos),
+          OperatorAttrs::SpaceToDepthAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpaceToDepthAttrs>>("OperatorAttrs::SpaceToDepthAttrs", pos),
+          OperatorAttrs::RoiAlignAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RoiAlignAttrs>>("OperatorAttrs::RoiAlignAttrs", pos),
+          OperatorAttrs::DFTAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DFTAttrs>>("OperatorAttrs::DFTAttrs", pos),
+          OperatorAttrs::STFTAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<STFTAttrs>>("OperatorAttrs::STFTAttrs", pos),
+          OperatorAttrs::WindowAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WindowAttrs>>("OperatorAttrs::WindowAttrs", pos),
+          OperatorAttrs::RotaryEmbeddingAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RotaryEmbeddingAttrs>>("OperatorAttrs::RotaryEmbeddingAttrs", pos),
+          OperatorAttrs::LpPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LpPoolAttrs>>("OperatorAttrs::LpPoolAttrs", pos),
+          OperatorAttrs::GlobalLpPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GlobalLpPoolAttrs>>("OperatorAttrs::GlobalLpPoolAttrs", pos),
+          OperatorAttrs::MaxUnpoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MaxUnpoolAttrs>>("OperatorAttrs::MaxUnpoolAttrs", pos),
+          OperatorAttrs::CumSumAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CumSumAttrs>>("OperatorAttrs::CumSumAttrs", pos),
+          OperatorAttrs::SeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SeluAttrs>>("OperatorAttrs::SeluAttrs", pos),
+          OperatorAttrs::ShrinkAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ShrinkAttrs>>("OperatorAttrs::ShrinkAttrs", pos),
+          OperatorAttrs::BitShiftAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BitShiftAttrs>>("OperatorAttrs::BitShiftAttrs", pos),
+          OperatorAttrs::IsInfAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<IsInfAttrs>>("OperatorAttrs::IsInfAttrs", pos),
+          OperatorAttrs::CenterCropPadAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CenterCropPadAttrs>>("OperatorAttrs::CenterCropPadAttrs", pos),
+          OperatorAttrs::CompressAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CompressAttrs>>("OperatorAttrs::CompressAttrs", pos),
+          OperatorAttrs::ReverseSequenceAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReverseSequenceAttrs>>("OperatorAttrs::ReverseSequenceAttrs", pos),
+          OperatorAttrs::UniqueAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<UniqueAttrs>>("OperatorAttrs::UniqueAttrs", pos),
+          OperatorAttrs::RNNAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RNNAttrs>>("OperatorAttrs::RNNAttrs", pos),
+          OperatorAttrs::BernoulliAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BernoulliAttrs>>("OperatorAttrs::BernoulliAttrs", pos),
+          OperatorAttrs::MultinomialAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MultinomialAttrs>>("OperatorAttrs::MultinomialAttrs", pos),
           _ => Ok(()),
         }
      })?
@@ -8303,6 +13048,226 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
+            OperatorAttrs::DepthToSpaceAttrs => {
+                if let Some(x) = self.attrs_as_depth_to_space_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SpaceToDepthAttrs => {
+                if let Some(x) = self.attrs_as_space_to_depth_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RoiAlignAttrs => {
+                if let Some(x) = self.attrs_as_roi_align_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::DFTAttrs => {
+                if let Some(x) = self.attrs_as_dftattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::STFTAttrs => {
+                if let Some(x) = self.attrs_as_stftattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::WindowAttrs => {
+                if let Some(x) = self.attrs_as_window_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RotaryEmbeddingAttrs => {
+                if let Some(x) = self.attrs_as_rotary_embedding_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::LpPoolAttrs => {
+                if let Some(x) = self.attrs_as_lp_pool_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GlobalLpPoolAttrs => {
+                if let Some(x) = self.attrs_as_global_lp_pool_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::MaxUnpoolAttrs => {
+                if let Some(x) = self.attrs_as_max_unpool_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::CumSumAttrs => {
+                if let Some(x) = self.attrs_as_cum_sum_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SeluAttrs => {
+                if let Some(x) = self.attrs_as_selu_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ShrinkAttrs => {
+                if let Some(x) = self.attrs_as_shrink_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::BitShiftAttrs => {
+                if let Some(x) = self.attrs_as_bit_shift_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::IsInfAttrs => {
+                if let Some(x) = self.attrs_as_is_inf_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::CenterCropPadAttrs => {
+                if let Some(x) = self.attrs_as_center_crop_pad_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::CompressAttrs => {
+                if let Some(x) = self.attrs_as_compress_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ReverseSequenceAttrs => {
+                if let Some(x) = self.attrs_as_reverse_sequence_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::UniqueAttrs => {
+                if let Some(x) = self.attrs_as_unique_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RNNAttrs => {
+                if let Some(x) = self.attrs_as_rnnattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::BernoulliAttrs => {
+                if let Some(x) = self.attrs_as_bernoulli_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::MultinomialAttrs => {
+                if let Some(x) = self.attrs_as_multinomial_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("attrs", &x)